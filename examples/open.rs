@@ -1,31 +1,20 @@
 use std::time::Instant;
-use image::imageops::{self, FilterType};
 
 fn main() {
     let img = image::open("data/4.jpg").unwrap();
     let light = img.to_luma8();
 
     let (w, h) = light.dimensions();
-    let scale = 400.0 / u32::max(w, h) as f32;
+    let (w, h) = (w as usize, h as usize);
 
-    let sw = (scale * w as f32) as usize;
-    let sh = (scale * h as f32) as usize;
+    let data = light.into_vec();
+    let view = dauntless::ImageView::new(&data, w, h).unwrap();
 
-    let resized = imageops::resize(
-        &light,
-        sw as u32,
-        sh as u32,
-        FilterType::Triangle,
-    );
-
-    let data =
-        &resized
-            .into_vec()
-            .into_iter()
-            .map(|l| l as f32 / 255.0)
-            .collect();
-
-    let mut detector = dauntless::Detector::default();
+    let config = dauntless::Config {
+        decimate: (w.max(h) / 400).max(1),
+        ..Default::default()
+    };
+    let mut detector = dauntless::Detector::with_config(&config).unwrap();
 
     let runs = 1000;
 
@@ -33,7 +22,7 @@ fn main() {
 
     for i in 0..runs {
         let start = Instant::now();
-        let _ = detector.tags(sw, sh, &data);
+        let _ = detector.detect(&config, &view);
         let ms = start.elapsed().as_secs_f32() * 1000.0;
 
         print!("\r{:.2} ms", ms);
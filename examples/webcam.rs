@@ -12,7 +12,8 @@ fn main() -> opencv::Result<()> {
     let mut last = Instant::now();
     let mut fps = 0.0;
 
-    let mut detector = dauntless::Detector::default();
+    let mut config = dauntless::Config::default();
+    let mut detector = dauntless::Detector::with_config(&config).unwrap();
 
     loop {
         let mut frame = Mat::default();
@@ -43,37 +44,16 @@ fn main() -> opencv::Result<()> {
             core::AlgorithmHint::ALGO_HINT_DEFAULT,
         )?;
 
-        let w = light.cols();
-        let h = light.rows();
+        let w = light.cols() as usize;
+        let h = light.rows() as usize;
 
-        let scale = 400.0 / i32::max(w, h) as f32;
+        config.decimate = (w.max(h) / 400).max(1);
 
-        let sw = (w as f32 * scale) as i32;
-        let sh = (h as f32 * scale) as i32;
-
-        let mut resized = Mat::default();
-
-        imgproc::resize(
-            &light,
-            &mut resized,
-            core::Size::new(sw, sh),
-            0.0,
-            0.0,
-            imgproc::INTER_LINEAR,
-        )?;
-
-        let data =
-            &resized
-                .data_bytes()?
-                .to_vec()
-                .into_iter()
-                .map(|l| l as f32 / 255.0)
-                .collect();
-
-        let tags = detector.tags(sw as usize, sh as usize, data);
+        let view = dauntless::ImageView::new(light.data_bytes()?, w, h).unwrap();
+        let tags = detector.detect(&config, &view).unwrap_or_default();
 
         for tag in tags {
-            let Tag { id, rot, pos, corners: (tl, tr, bl, br) } = tag;
+            let Tag { id, rot, pos, corners: (tl, tr, bl, br), .. } = tag;
 
             let corners = [tl, tr, br, bl];
 
@@ -98,26 +78,14 @@ fn main() -> opencv::Result<()> {
                 format!("{}", rot.to_degrees() as i32)
             };
 
-            show_text(
-                &mut frame,
-                &label,
-                (x as f32 / scale) as i32,
-                (y as f32 / scale) as i32,
-            )?;
+            show_text(&mut frame, &label, x as i32, y as i32)?;
 
             for i in 0..4 {
                 let c0 = corners[i];
                 let c1 = corners[(i + 1) % 4];
 
-                let p1 = core::Point::new(
-                    (c0.0 as f32 / scale) as i32,
-                    (c0.1 as f32 / scale) as i32,
-                );
-
-                let p2 = core::Point::new(
-                    (c1.0 as f32 / scale) as i32,
-                    (c1.1 as f32 / scale) as i32,
-                );
+                let p1 = core::Point::new(c0.0 as i32, c0.1 as i32);
+                let p2 = core::Point::new(c1.0 as i32, c1.1 as i32);
 
                 imgproc::line(
                     &mut frame,
@@ -130,18 +98,10 @@ fn main() -> opencv::Result<()> {
                 )?;
             }
 
-            let tlr = (tl.0 as f32, tl.1 as f32);
-            let trr = (tr.0 as f32, tr.1 as f32);
-            let blr = (bl.0 as f32, bl.1 as f32);
-            let brr = (br.0 as f32, br.1 as f32);
-
-            let sx = w as f32 / sw as f32;
-            let sy = h as f32 / sh as f32;
-
-            let tlf = (tlr.0 * sx, tlr.1 * sy);
-            let trf = (trr.0 * sx, trr.1 * sy);
-            let blf = (blr.0 * sx, blr.1 * sy);
-            let brf = (brr.0 * sx, brr.1 * sy);
+            let tlf = (tl.0 as f32, tl.1 as f32);
+            let trf = (tr.0 as f32, tr.1 as f32);
+            let blf = (bl.0 as f32, bl.1 as f32);
+            let brf = (br.0 as f32, br.1 as f32);
 
             let hm = Homography::from_corners((tlf, trf, blf, brf));
 
@@ -0,0 +1,56 @@
+use dauntless::Generator;
+
+use std::env;
+use std::io::Write;
+use std::process;
+
+const USAGE: &str = "usage: dauntless-gen <grid> <min-hamming> <out> [--complexity N] [--seed N] [--limit N] [--candidates N] [--name NAME]";
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    if args.len() < 3 {
+        fail(USAGE);
+    }
+
+    let grid = parse(&args[0]);
+    let min_hamming = parse(&args[1]);
+    let out = &args[2];
+
+    let mut generator = Generator::new(grid, min_hamming);
+
+    for opt in args[3..].chunks(2) {
+        let [flag, value] = opt else { fail(USAGE) };
+
+        match flag.as_str() {
+            "--complexity" => generator.min_complexity = parse(value),
+            "--seed" => generator.seed = parse(value),
+            "--limit" => generator.limit = parse(value),
+            "--candidates" => generator.candidates = parse(value),
+            "--name" => generator.name = Some(value.clone()),
+            _ => fail(USAGE),
+        }
+    }
+
+    let family = generator.generate_with(|codes| {
+        print!("\r{} codes", codes.len());
+        std::io::stdout().flush().unwrap();
+    });
+
+    let family = family.unwrap_or_else(|e| fail(&e.to_string()));
+
+    println!("\r{} codes in {}", family.codes.len(), family.name);
+
+    if let Err(e) = family.save(out) {
+        fail(&e.to_string());
+    }
+}
+
+fn parse<T: std::str::FromStr>(s: &str) -> T {
+    s.parse().unwrap_or_else(|_| fail(&format!("invalid number `{s}`")))
+}
+
+fn fail(msg: &str) -> ! {
+    eprintln!("{msg}");
+    process::exit(1);
+}
@@ -0,0 +1,77 @@
+use dauntless::{CustomFamily, Render, TagFamily};
+
+use std::env;
+use std::fs;
+use std::process;
+use std::sync::Arc;
+
+const USAGE: &str = "usage: dauntless-render <family|file.fam> <ids> <out.png|out.svg|out.pdf> \
+                     [--cell N] [--quiet N] [--border N] [--columns N] [--captions]";
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    if args.len() < 3 {
+        fail(USAGE);
+    }
+
+    let family: Arc<dyn TagFamily> = match dauntless::builtin_family(&args[0]) {
+        Some(family) => family,
+        None => match CustomFamily::load(&args[0]) {
+            Ok(family) => Arc::new(family),
+            Err(e) => fail(&format!("{}: {e}", args[0])),
+        },
+    };
+
+    let ids = parse_ids(&args[1]);
+    let out = &args[2];
+
+    let mut render = Render::default();
+    let mut rest = args[3..].iter();
+
+    while let Some(flag) = rest.next() {
+        match flag.as_str() {
+            "--captions" => render.captions = true,
+            "--cell" => render.cell = parse(rest.next()),
+            "--quiet" => render.quiet = parse(rest.next()),
+            "--border" => render.border = parse(rest.next()),
+            "--columns" => render.columns = parse(rest.next()),
+            _ => fail(USAGE),
+        }
+    }
+
+    let data = match out.rsplit('.').next() {
+        Some("png") => render.png(family.as_ref(), &ids),
+        Some("svg") => render.svg(family.as_ref(), &ids).map(String::into_bytes),
+        Some("pdf") => render.pdf(family.as_ref(), &ids),
+        _ => fail(USAGE),
+    };
+
+    let data = data.unwrap_or_else(|e| fail(&e.to_string()));
+
+    if let Err(e) = fs::write(out, data) {
+        fail(&format!("{out}: {e}"));
+    }
+}
+
+fn parse_ids(s: &str) -> Vec<u32> {
+    s.split(',')
+        .flat_map(|part| match part.split_once('-') {
+            Some((a, b)) => parse::<u32>(Some(&a.to_string()))..=parse(Some(&b.to_string())),
+            None => {
+                let id = parse(Some(&part.to_string()));
+                id..=id
+            }
+        })
+        .collect()
+}
+
+fn parse<T: std::str::FromStr>(s: Option<&String>) -> T {
+    let s = s.unwrap_or_else(|| fail(USAGE));
+    s.parse().unwrap_or_else(|_| fail(&format!("invalid number `{s}`")))
+}
+
+fn fail(msg: &str) -> ! {
+    eprintln!("{msg}");
+    process::exit(1);
+}
@@ -1,25 +1,202 @@
-#[derive(Debug, Clone, Copy)]
+use crate::family::{self, TagFamily};
+
+use std::{error, fmt};
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Config {
     pub fov: f32,
+    pub decimate: usize,
+    pub pyramid: usize,
+
+    pub segmentation: Segmentation,
+    pub prefilters: Vec<Prefilter>,
+
+    pub blur: Blur,
 
     pub hyst_high: f32,
     pub hyst_low: f32,
+    pub hyst_mode: Hysteresis,
+    pub hyst_tile: usize,
+    pub hyst_percentile: f32,
+    pub hyst_floor: f32,
+    pub hyst_smoothing: f32,
+
+    pub adaptive_tile: usize,
+    pub adaptive_contrast: f32,
 
     pub filter_ratios: bool,
     pub filter_angles: bool,
+    pub subpixel: bool,
+
+    pub max_hamming: u32,
+    pub n_means: usize,
+    pub min_border: f32,
+    pub spatial_threshold: bool,
+    pub sampling: Sampling,
+
+    pub inverted: bool,
+    pub mirrored: bool,
+
+    #[cfg_attr(feature = "serde", serde(with = "family::by_name"))]
+    pub families: Vec<Arc<dyn TagFamily>>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             fov: 75.0_f32,
+            decimate: 1,
+            pyramid: 1,
+
+            segmentation: Segmentation::Canny,
+            prefilters: Vec::new(),
+
+            blur: Blur::Binomial,
 
             hyst_high: 0.05,
             hyst_low: 0.025,
+            hyst_mode: Hysteresis::Global,
+            hyst_tile: 32,
+            hyst_percentile: 0.99,
+            hyst_floor: 4.0,
+            hyst_smoothing: 0.0,
+
+            adaptive_tile: 4,
+            adaptive_contrast: 0.02,
 
             filter_ratios: true,
             filter_angles: true,
+            subpixel: false,
+
+            max_hamming: 2,
+            n_means: 5,
+            min_border: 0.3,
+            spatial_threshold: false,
+            sampling: Sampling::Box,
+
+            inverted: false,
+            mirrored: false,
+
+            families: vec![Arc::new(family::TAG36H11)],
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Segmentation {
+    Canny,
+    Adaptive,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Blur {
+    Binomial,
+    Gaussian { sigma: f32, radius: usize },
+    Off,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Prefilter {
+    Median { radius: usize },
+    Unsharp { sigma: f32, amount: f32 },
+    Clahe { tile: usize, clip: f32 },
+    Gamma(f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Hysteresis {
+    Global,
+    Tiled,
+    Percentile,
+    Otsu,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Sampling {
+    Box,
+    Bilinear,
+    Area,
+}
+
+impl Config {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.decimate == 0 {
+            return Err(ConfigError::Decimate);
+        }
+
+        if self.pyramid == 0 {
+            return Err(ConfigError::Pyramid);
+        }
+
+        if matches!(self.blur, Blur::Gaussian { sigma, .. } if !(sigma > 0.0 && sigma.is_finite())) {
+            return Err(ConfigError::Blur);
+        }
+
+        for &filter in &self.prefilters {
+            let valid =
+                match filter {
+                    Prefilter::Median { .. } => true,
+                    Prefilter::Unsharp { sigma, amount } => sigma > 0.0 && amount.is_finite(),
+                    Prefilter::Clahe { tile, clip } => tile > 0 && clip >= 1.0,
+                    Prefilter::Gamma(g) => g > 0.0 && g.is_finite(),
+                };
+
+            if !valid {
+                return Err(ConfigError::Prefilter(filter));
+            }
+        }
+
+        for family in &self.families {
+            if self.max_hamming > family.max_correctable() {
+                return Err(ConfigError::HammingBudget {
+                    family: family.name().to_string(),
+                    max: family.max_correctable(),
+                });
+            }
+
+            if self.n_means == 0 || 2 * self.n_means > family.bits().len() {
+                return Err(ConfigError::Means {
+                    family: family.name().to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    HammingBudget { family: String, max: u32 },
+    Means { family: String },
+    Decimate,
+    Pyramid,
+    Blur,
+    Prefilter(Prefilter),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::HammingBudget { family, max } => {
+                write!(f, "{family} can only safely correct {max} bit errors")
+            }
+            ConfigError::Means { family } => {
+                write!(f, "n_means does not fit in the bits of {family}")
+            }
+            ConfigError::Decimate => f.write_str("decimate must be at least 1"),
+            ConfigError::Pyramid => f.write_str("pyramid must have at least 1 level"),
+            ConfigError::Blur => f.write_str("gaussian blur sigma must be positive"),
+            ConfigError::Prefilter(filter) => write!(f, "invalid prefilter parameters: {filter:?}"),
+        }
+    }
+}
+
+impl error::Error for ConfigError {}
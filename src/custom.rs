@@ -0,0 +1,284 @@
+use crate::family::TagFamily;
+
+use std::{error, fmt, fs, io};
+use std::path::Path;
+
+const MAGIC: &[u8; 4] = b"DTF1";
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawFamily"))]
+pub struct CustomFamily {
+    pub name: String,
+    pub grid: usize,
+    pub border: usize,
+    pub bits: Vec<(i32, i32)>,
+    pub codes: Vec<u64>,
+    pub min_hamming: u32,
+    pub reversed_border: bool,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawFamily {
+    name: String,
+    grid: usize,
+    border: usize,
+    bits: Vec<(i32, i32)>,
+    codes: Vec<u64>,
+    min_hamming: u32,
+    reversed_border: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawFamily> for CustomFamily {
+    type Error = FamilyError;
+
+    fn try_from(raw: RawFamily) -> Result<Self, FamilyError> {
+        let family = CustomFamily {
+            name: raw.name,
+            grid: raw.grid,
+            border: raw.border,
+            bits: raw.bits,
+            codes: raw.codes,
+            min_hamming: raw.min_hamming,
+            reversed_border: raw.reversed_border,
+        };
+        family.validate()?;
+
+        Ok(family)
+    }
+}
+
+#[derive(Debug)]
+pub enum FamilyError {
+    Io(io::Error),
+    Malformed,
+    Layout,
+    Code(usize),
+    Range(&'static str),
+}
+
+impl fmt::Display for FamilyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FamilyError::Io(e) => write!(f, "failed to read family: {e}"),
+            FamilyError::Malformed => f.write_str("malformed family file"),
+            FamilyError::Layout => f.write_str("bit layout is not closed under rotation and mirroring"),
+            FamilyError::Code(i) => write!(f, "code {i} does not fit in the bit layout"),
+            FamilyError::Range(field) => write!(f, "{field} is out of range for a family"),
+        }
+    }
+}
+
+impl error::Error for FamilyError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            FamilyError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FamilyError {
+    fn from(e: io::Error) -> Self {
+        FamilyError::Io(e)
+    }
+}
+
+impl CustomFamily {
+    pub fn from_family(family: &dyn TagFamily) -> Self {
+        CustomFamily {
+            name: family.name().to_string(),
+            grid: family.grid(),
+            border: family.border(),
+            bits: family.bits(),
+            codes: family.codes().to_vec(),
+            min_hamming: family.min_hamming(),
+            reversed_border: family.reversed_border(),
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, FamilyError> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), FamilyError> {
+        Ok(fs::write(path, self.to_bytes()?)?)
+    }
+
+    pub fn validate(&self) -> Result<(), FamilyError> {
+        check(self)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, FamilyError> {
+        self.validate()?;
+
+        let n = self.bits.len();
+        let code_len = n.div_ceil(8);
+
+        let mut out = Vec::with_capacity(16 + self.name.len() + 2 * n + code_len * self.codes.len());
+
+        out.extend_from_slice(MAGIC);
+
+        let end = (0..=self.name.len().min(255)).rev().find(|&i| self.name.is_char_boundary(i)).unwrap_or(0);
+        let name = &self.name.as_bytes()[..end];
+        out.push(name.len() as u8);
+        out.extend_from_slice(name);
+
+        out.push(self.grid as u8);
+        out.push(self.border as u8);
+        out.push(self.min_hamming as u8);
+        out.push(self.reversed_border as u8);
+
+        out.push(n as u8);
+        for &(x, y) in &self.bits {
+            out.push(x as i8 as u8);
+            out.push(y as i8 as u8);
+        }
+
+        out.extend_from_slice(&(self.codes.len() as u32).to_le_bytes());
+        for code in &self.codes {
+            out.extend_from_slice(&code.to_le_bytes()[..code_len]);
+        }
+
+        Ok(out)
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self, FamilyError> {
+        let mut r = Reader { data };
+
+        if r.take(4)? != MAGIC {
+            return Err(FamilyError::Malformed);
+        }
+
+        let name_len = r.byte()? as usize;
+        let name = String::from_utf8(r.take(name_len)?.to_vec()).map_err(|_| FamilyError::Malformed)?;
+
+        let grid = r.byte()? as usize;
+        let border = r.byte()? as usize;
+        let min_hamming = r.byte()? as u32;
+        let reversed_border = r.byte()? != 0;
+
+        let n = r.byte()? as usize;
+        let bits =
+            r.take(2 * n)?
+                .chunks_exact(2)
+                .map(|p| (p[0] as i8 as i32, p[1] as i8 as i32))
+                .collect();
+
+        let count = u32::from_le_bytes(r.take(4)?.try_into().unwrap()) as usize;
+        let code_len = n.div_ceil(8);
+
+        let codes =
+            r.take(count.checked_mul(code_len).ok_or(FamilyError::Malformed)?)?
+                .chunks_exact(code_len.max(1))
+                .map(|c| {
+                    let mut buf = [0; 8];
+                    buf[..c.len()].copy_from_slice(c);
+                    u64::from_le_bytes(buf)
+                })
+                .collect();
+
+        if !r.data.is_empty() {
+            return Err(FamilyError::Malformed);
+        }
+
+        let family = CustomFamily { name, grid, border, bits, codes, min_hamming, reversed_border };
+        family.validate()?;
+
+        Ok(family)
+    }
+}
+
+pub fn check(family: &dyn TagFamily) -> Result<(), FamilyError> {
+    let bits = family.bits();
+    let n = bits.len();
+
+    if n == 0 || n > 64 {
+        return Err(FamilyError::Layout);
+    }
+
+    if family.width() > i8::MAX as usize {
+        return Err(FamilyError::Range("grid and border"));
+    }
+
+    if family.min_hamming() > 64 {
+        return Err(FamilyError::Range("min_hamming"));
+    }
+
+    if family.codes().len() > u32::MAX as usize {
+        return Err(FamilyError::Range("codes"));
+    }
+
+    let last = family.width() as i32 - 1;
+
+    if bits.iter().any(|&(x, y)| i8::try_from(x).is_err() || i8::try_from(y).is_err()) {
+        return Err(FamilyError::Range("bits"));
+    }
+
+    let closed =
+        bits.iter().all(|&(x, y)| {
+            bits.contains(&(last - y, x)) && bits.contains(&(last - x, y))
+        });
+
+    if !closed {
+        return Err(FamilyError::Layout);
+    }
+
+    match family.codes().iter().position(|&c| n < 64 && c >> n != 0) {
+        Some(i) => Err(FamilyError::Code(i)),
+        None => Ok(()),
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], FamilyError> {
+        if self.data.len() < n {
+            return Err(FamilyError::Malformed);
+        }
+
+        let (head, tail) = self.data.split_at(n);
+        self.data = tail;
+
+        Ok(head)
+    }
+
+    fn byte(&mut self) -> Result<u8, FamilyError> {
+        Ok(self.take(1)?[0])
+    }
+}
+
+impl TagFamily for CustomFamily {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn grid(&self) -> usize {
+        self.grid
+    }
+
+    fn border(&self) -> usize {
+        self.border
+    }
+
+    fn codes(&self) -> &[u64] {
+        &self.codes
+    }
+
+    fn min_hamming(&self) -> u32 {
+        self.min_hamming
+    }
+
+    fn bits(&self) -> Vec<(i32, i32)> {
+        self.bits.clone()
+    }
+
+    fn reversed_border(&self) -> bool {
+        self.reversed_border
+    }
+}
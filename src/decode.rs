@@ -1,77 +1,264 @@
+use crate::config::{Config, Sampling};
 use crate::hm::Homography;
-use crate::types::{Bits, Corners, Dim, Lightness, Point2D};
+use crate::image::{ImageView, Pixel};
+use crate::table::CodeTable;
+use crate::types::{Bits, Corners, Lightness, Point2D};
 
 use std::cmp::Ordering;
 
-const ERR_THRESH: u32 = 2;
-const N_MEANS: usize = 5;
+pub struct Decoded {
+    pub id: u32,
+    pub hamming: u32,
+    pub rotation: u8,
+    pub margin: f32,
+    pub border: f32,
+    pub inverted: bool,
+    pub mirrored: bool,
+}
+
+pub fn decode<T: Pixel>(config: &Config, img: &ImageView<T>, corners: Corners, table: &CodeTable) -> Option<Decoded> {
+    let mode = config.sampling;
 
-const CODES: [u64; 33] = [
-    57401312644, 58383764297, 59366215950, 61331119256,
-    63296022562, 65260925868,  1453707397,  4401062356,
-     9313320621, 10295772274, 14225578886, 17172933845,
-    18155385498, 19137837151, 21102740457, 22085192110,
-    24050095416, 27979902028, 28962353681, 33874611946,
-    34857063599, 35839515252, 37804418558, 42716676823,
-    43699128476, 46646483435, 47628935088, 49593838394,
-    56470999965, 57453451618, 61383258230, 12312814554,
-    18207524472,
-];
+    let tag = sample(mode, img, corners, table.width, &table.layout)?;
+    let dark = sample(mode, img, corners, table.width, &table.dark)?;
 
-pub fn decode(dim: Dim, img: &Lightness, corners: Corners) -> Option<u32> {
-    let tag = sample(dim, img, corners)?;
+    let light =
+        table.light
+            .iter()
+            .zip(sample_cells(mode, img, corners, table.width, &table.light))
+            .filter_map(|(&cell, val)| Some((cell, val?)))
+            .collect::<Vec<_>>();
 
     let mut vals = tag.clone().to_vec();
     vals.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
 
-    let min = vals[..N_MEANS].iter().sum::<f32>() / N_MEANS as f32;
-    let max = vals[vals.len() - N_MEANS..].iter().sum::<f32>() / N_MEANS as f32;
+    let n_means = config.n_means.clamp(1, vals.len());
 
-    let mut bits =
-        tag
-            .into_iter()
-            .map(|x| (x - min) / (max - min) > 0.5)
-            .collect::<Bits>();
+    let min = vals[..n_means].iter().sum::<f32>() / n_means as f32;
+    let max = vals[vals.len() - n_means..].iter().sum::<f32>() / n_means as f32;
 
-    let mut best: Option<(usize, u32)> = None;
+    let model =
+        if config.spatial_threshold {
+            let ring = fit(table.dark.iter().copied().zip(dark.iter().copied()), table.width);
+            let quiet = fit(light.iter().copied(), table.width);
 
-    for _ in 0..4 {
-        let bin: u64 =
-            bits
-                .iter()
-                .fold(0, |n, &t| (n << 1) | if t { 1 } else { 0 });
+            ring.zip(quiet)
+        } else {
+            None
+        };
+
+    let norm =
+        match model {
+            Some((ring, quiet)) => {
+                table.layout
+                    .iter()
+                    .zip(&tag)
+                    .map(|(&cell, &x)| {
+                        let r = eval(&ring, cell, table.width);
+                        let q = eval(&quiet, cell, table.width);
+
+                        0.5 + (x - (r + q) / 2.0) / (q - r).abs().max(f32::EPSILON)
+                    })
+                    .collect::<Lightness>()
+            }
+            None => tag.iter().map(|x| (x - min) / (max - min)).collect(),
+        };
+
+    let margin = margin(&norm);
+
+    let polarities: &[bool] = if config.inverted { &[false, true] } else { &[false] };
+    let mirrors: &[bool] = if config.mirrored { &[false, true] } else { &[false] };
+
+    let mut best: Option<Decoded> = None;
+
+    for &inverted in polarities {
+        let (lo, hi) = if inverted { (max, min) } else { (min, max) };
 
-        for (i, code) in CODES.iter().enumerate() {
-            let dist = (bin ^ code).count_ones();
+        let border = border(&dark, &light, lo, hi);
 
-            if dist == 0 {
-                return Some(i as u32);
+        if border < config.min_border {
+            continue;
+        }
+
+        let bits = norm.iter().map(|&x| (x > 0.5) != inverted).collect::<Bits>();
+
+        for &mirrored in mirrors {
+            let bits = if mirrored { permute(bits.clone(), &table.mirror) } else { bits.clone() };
+
+            let Some(entry) = table.get(to_code(&bits)) else {
+                continue;
+            };
+
+            if best.as_ref().is_none_or(|b| (entry.hamming as u32) < b.hamming) {
+                best = Some(Decoded {
+                    id: entry.id,
+                    hamming: entry.hamming as u32,
+                    rotation: entry.rotation,
+                    margin,
+                    border,
+                    inverted,
+                    mirrored,
+                });
             }
+        }
+    }
+
+    best
+}
+
+fn border(dark: &[f32], light: &[((i32, i32), f32)], min: f32, max: f32) -> f32 {
+    let norm = |x: f32| (x - min) / (max - min);
 
-            if dist <= ERR_THRESH && best.is_none_or(|(_, best_dist)| dist < best_dist) {
-                best = Some((i, dist));
+    let worst_ring = dark.iter().map(|&x| norm(x)).fold(f32::MIN, f32::max);
+    let quiet =
+        if light.is_empty() {
+            1.0
+        } else {
+            light.iter().map(|&(_, x)| norm(x)).sum::<f32>() / light.len() as f32
+        };
+
+    quiet - worst_ring
+}
+
+fn fit(samples: impl Iterator<Item = ((i32, i32), f32)>, width: usize) -> Option<[f64; 4]> {
+    let mut a = [[0.0; 5]; 4];
+    let mut n = 0;
+
+    for (cell, val) in samples {
+        let row = terms(cell, width);
+
+        for i in 0..4 {
+            for j in 0..4 {
+                a[i][j] += row[i] * row[j];
             }
+            a[i][4] += row[i] * val as f64;
+        }
+
+        n += 1;
+    }
+
+    if n < 4 {
+        return None;
+    }
+
+    for col in 0..4 {
+        let pivot = (col..4).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+
+        if a[pivot][col].abs() < 1e-9 {
+            return None;
         }
+        a.swap(col, pivot);
+        let top = a[col];
+
+        for (i, row) in a.iter_mut().enumerate() {
+            if i != col {
+                let f = row[col] / top[col];
 
-        bits = rot90(bits, 6);
+                for (x, t) in row.iter_mut().zip(top).skip(col) {
+                    *x -= f * t;
+                }
+            }
+        }
     }
 
-    best.map(|(i, _)| i as u32)
+    Some([
+        a[0][4] / a[0][0],
+        a[1][4] / a[1][1],
+        a[2][4] / a[2][2],
+        a[3][4] / a[3][3],
+    ])
+}
+
+fn eval(model: &[f64; 4], cell: (i32, i32), width: usize) -> f32 {
+    terms(cell, width)
+        .iter()
+        .zip(model)
+        .map(|(t, m)| t * m)
+        .sum::<f64>() as f32
+}
+
+fn terms((x, y): (i32, i32), width: usize) -> [f64; 4] {
+    let u = (x as f64 + 0.5) / width as f64;
+    let v = (y as f64 + 0.5) / width as f64;
+
+    [1.0, u, v, u * v]
 }
 
-fn rot90(a: Bits, n: usize) -> Bits {
-    let mut out = vec![false; n * n];
+fn margin(norm: &[f32]) -> f32 {
+    let (mut white, mut n_white) = (0.0, 0);
+    let (mut black, mut n_black) = (0.0, 0);
 
-    for y in 0..n {
-        for x in 0..n {
-            out[x * n + (n - 1 - y)] = a[y * n + x];
+    for &x in norm {
+        if x > 0.5 {
+            white += x - 0.5;
+            n_white += 1;
+        } else {
+            black += 0.5 - x;
+            n_black += 1;
         }
     }
 
+    (white / n_white.max(1) as f32).min(black / n_black.max(1) as f32)
+}
+
+pub fn rot90_code(code: u64, perm: &[usize]) -> u64 {
+    let n = perm.len();
+    let bits = (0..n).map(|i| (code >> (n - 1 - i)) & 1 == 1).collect();
+
+    to_code(&permute(bits, perm))
+}
+
+fn to_code(bits: &[bool]) -> u64 {
+    bits
+        .iter()
+        .fold(0, |n, &t| (n << 1) | if t { 1 } else { 0 })
+}
+
+fn permute(a: Bits, perm: &[usize]) -> Bits {
+    let mut out = vec![false; a.len()];
+
+    for (i, &j) in perm.iter().enumerate() {
+        out[j] = a[i];
+    }
+
     out
 }
 
-fn sample(dim: Dim, img: &Lightness, corners: Corners) -> Option<Lightness> {
+pub fn rot90_perm(layout: &[(i32, i32)], width: usize) -> Vec<usize> {
+    let n = width as i32 - 1;
+
+    layout
+        .iter()
+        .enumerate()
+        .map(|(i, &(x, y))| {
+            layout
+                .iter()
+                .position(|&cell| cell == (n - y, x))
+                .unwrap_or(i)
+        })
+        .collect()
+}
+
+pub fn mirror_perm(layout: &[(i32, i32)], width: usize) -> Vec<usize> {
+    let n = width as i32 - 1;
+
+    layout
+        .iter()
+        .enumerate()
+        .map(|(i, &(x, y))| {
+            layout
+                .iter()
+                .position(|&cell| cell == (n - x, y))
+                .unwrap_or(i)
+        })
+        .collect()
+}
+
+fn sample<T: Pixel>(mode: Sampling, img: &ImageView<T>, corners: Corners, width: usize, layout: &[(i32, i32)]) -> Option<Lightness> {
+    sample_cells(mode, img, corners, width, layout).into_iter().collect()
+}
+
+fn sample_cells<T: Pixel>(mode: Sampling, img: &ImageView<T>, corners: Corners, width: usize, layout: &[(i32, i32)]) -> Vec<Option<f32>> {
     let hm = Homography::from_corners((
         Point2D(corners.0.0, corners.0.1),
         Point2D(corners.1.0, corners.1.1),
@@ -79,42 +266,95 @@ fn sample(dim: Dim, img: &Lightness, corners: Corners) -> Option<Lightness> {
         Point2D(corners.3.0, corners.3.1),
     ));
 
-    let mut out = vec![0.0; 36];
-
-    let w = dim.w;
-    let h = dim.h;
-
     let dx = corners.0.0 - corners.1.0;
     let dy = corners.0.1 - corners.1.1;
-    let neighbors = dx * dx + dy * dy >= 256.0;
+    let width = width as f64;
+    let neighbors = dx * dx + dy * dy >= (2.0 * width).powi(2);
+
+    let cell = (dx * dx + dy * dy).sqrt() / width;
+    let n = (cell.ceil() as usize).clamp(1, 4);
 
-    for y in 0..6 {
-        for x in 0..6 {
-            let u = (x as f64 + 1.5) / 8.0;
-            let v = (y as f64 + 1.5) / 8.0;
+    layout
+        .iter()
+        .map(|&(x, y)| {
+            let (x, y) = (x as f64, y as f64);
 
-            let Point2D(ix, iy) = hm.map(u, v);
+            match mode {
+                Sampling::Box => {
+                    boxed(img, hm.map((x + 0.5) / width, (y + 0.5) / width), neighbors)
+                }
+                Sampling::Bilinear => {
+                    bilinear(img, hm.map((x + 0.5) / width, (y + 0.5) / width))
+                }
+                Sampling::Area => {
+                    let mut sum = 0.0;
 
-            let ixf = ix.floor() as usize;
-            let iyf = iy.floor() as usize;
+                    for j in 0..n {
+                        for i in 0..n {
+                            let u = (x + (i as f64 + 0.5) / n as f64) / width;
+                            let v = (y + (j as f64 + 0.5) / n as f64) / width;
 
-            if neighbors && (ixf < 1 || ixf > w - 1 || iyf < 1 || iyf > h - 1) {
-                return None;
+                            sum += bilinear(img, hm.map(u, v))?;
+                        }
+                    }
+
+                    Some(sum / (n * n) as f32)
+                }
             }
-            let i = iyf * w + ixf;
-
-            let val =
-                if neighbors {
-                      img[i - 1 - w] + img[i - w] + img[i + 1 - w]
-                    + img[i - 1]     + img[i]     + img[i + 1]
-                    + img[i - 1 + w] + img[i + w] + img[i + 1 + w]
-                } else {
-                    img[i]
-                };
-
-            out[x + y * 6] = val / 9_f32;
-        }
+        })
+        .collect()
+}
+
+fn boxed<T: Pixel>(img: &ImageView<T>, Point2D(ix, iy): Point2D, neighbors: bool) -> Option<f32> {
+    let w = img.width();
+    let h = img.height();
+
+    let margin = if neighbors { 1 } else { 0 };
+
+    let inside =
+        ix >= margin as f64
+            && iy >= margin as f64
+            && ix < w.saturating_sub(margin) as f64
+            && iy < h.saturating_sub(margin) as f64;
+
+    if !inside {
+        return None;
     }
 
-    Some(out)
+    let x = ix.floor() as usize;
+    let y = iy.floor() as usize;
+
+    let val =
+        if neighbors {
+              img.get(x - 1, y - 1) + img.get(x, y - 1) + img.get(x + 1, y - 1)
+            + img.get(x - 1, y)     + img.get(x, y)     + img.get(x + 1, y)
+            + img.get(x - 1, y + 1) + img.get(x, y + 1) + img.get(x + 1, y + 1)
+        } else {
+            img.get(x, y)
+        };
+
+    Some(val / 9_f32)
+}
+
+pub(crate) fn bilinear<T: Pixel>(img: &ImageView<T>, Point2D(fx, fy): Point2D) -> Option<f32> {
+    let inside =
+        fx >= 0.0
+            && fy >= 0.0
+            && fx < img.width().saturating_sub(1) as f64
+            && fy < img.height().saturating_sub(1) as f64;
+
+    if !inside {
+        return None;
+    }
+
+    let x0 = fx.floor() as usize;
+    let y0 = fy.floor() as usize;
+
+    let tx = (fx - x0 as f64) as f32;
+    let ty = (fy - y0 as f64) as f32;
+
+    let top = img.get(x0, y0) * (1.0 - tx) + img.get(x0 + 1, y0) * tx;
+    let bot = img.get(x0, y0 + 1) * (1.0 - tx) + img.get(x0 + 1, y0 + 1) * tx;
+
+    Some(top * (1.0 - ty) + bot * ty)
 }
@@ -1,20 +1,117 @@
-use crate::types::{Dim, Lightness, Mask};
+use crate::config::Config;
+use crate::custom;
+use crate::process::DetectError;
+use crate::table::{self, CodeTable};
+use crate::types::{Dim, Lightness, Mask, Thresholds};
 
 use std::collections::VecDeque;
+use std::iter;
 
 #[derive(Default)]
 pub struct Detector {
     pub ws: Workspace,
+    pyramid: Vec<Workspace>,
+    tables: Vec<CodeTable>,
 }
 
 impl Detector {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn with_config(config: &Config) -> Result<Self, DetectError> {
+        let mut detector = Self::new();
+        detector.prepare(config)?;
+
+        Ok(detector)
+    }
+
+    pub fn prepare(&mut self, config: &Config) -> Result<(), DetectError> {
+        config.validate()?;
+        self.ensure_tables(config)?;
+
+        Ok(())
+    }
+
+    pub fn table_memory(&self) -> Vec<(String, usize)> {
+        self.tables
+            .iter()
+            .map(|t| (t.name.clone(), t.memory()))
+            .collect()
+    }
+
+    pub fn thresholds(&self) -> Vec<Option<Thresholds>> {
+        iter::once(&self.ws)
+            .chain(&self.pyramid)
+            .map(|ws| ws.thresholds)
+            .collect()
+    }
+
+    pub(crate) fn reset_levels(&mut self, from: usize) {
+        for level in from..=self.pyramid.len() {
+            let ws = self.workspace(level);
+
+            ws.thresholds = None;
+            ws.smooth = None;
+        }
+    }
+
+    pub(crate) fn workspace(&mut self, level: usize) -> &mut Workspace {
+        if level == 0 {
+            return &mut self.ws;
+        }
+
+        if self.pyramid.len() < level {
+            self.pyramid.resize_with(level, Workspace::default);
+        }
+
+        &mut self.pyramid[level - 1]
+    }
+
+    pub(crate) fn ensure_tables(&mut self, config: &Config) -> Result<Vec<usize>, DetectError> {
+        let keys =
+            config.families
+                .iter()
+                .map(|f| (table::fingerprint(f.as_ref()), table::budget(config, f.as_ref())))
+                .collect::<Vec<_>>();
+
+        self.tables.retain(|t| keys.contains(&(t.fingerprint, t.max_hamming)));
+
+        let mut indices = Vec::with_capacity(keys.len());
+
+        for (family, &(fingerprint, budget)) in config.families.iter().zip(&keys) {
+            let found = self.tables.iter().position(|t| t.fingerprint == fingerprint && t.max_hamming == budget);
+
+            let index =
+                match found {
+                    Some(i) => i,
+                    None => {
+                        custom::check(family.as_ref()).map_err(|e| DetectError::Family {
+                            family: family.name().to_string(),
+                            reason: e.to_string(),
+                        })?;
+
+                        self.tables.push(CodeTable::new(family.as_ref(), budget));
+                        self.tables.len() - 1
+                    }
+                };
+
+            indices.push(index);
+        }
+
+        Ok(indices)
+    }
+
+    pub(crate) fn table(&self, index: usize) -> &CodeTable {
+        &self.tables[index]
+    }
 }
 
 #[derive(Default)]
 pub struct Workspace {
+    pub input: Lightness,
+    pub small: Lightness,
+    pub pre: Lightness,
     pub bh: Lightness,
     pub blur: Lightness,
     pub gx: Lightness,
@@ -25,7 +122,14 @@ pub struct Workspace {
     pub strong: Mask,
     pub weak: Mask,
     pub edges: Mask,
+    pub binary: Mask,
+    pub tmin: Lightness,
+    pub tmax: Lightness,
+    pub lmin: Lightness,
+    pub lmax: Lightness,
     pub dq: VecDeque<(usize, usize)>,
+    pub thresholds: Option<Thresholds>,
+    pub smooth: Option<(usize, usize, Thresholds)>,
 }
 
 impl Workspace {
@@ -43,6 +147,7 @@ impl Workspace {
         ensure_u8(&mut self.strong, l);
         ensure_u8(&mut self.weak, l);
         ensure_u8(&mut self.edges, l);
+        ensure_u8(&mut self.binary, l);
     }
 }
 
@@ -0,0 +1,405 @@
+mod aruco;
+mod tag16h5;
+mod tag25h9;
+mod tag36h11;
+mod tag_circle21h7;
+mod tag_standard41h12;
+mod tag_standard52h13;
+
+use std::fmt;
+use std::sync::Arc;
+
+pub trait TagFamily: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn grid(&self) -> usize;
+    fn border(&self) -> usize;
+
+    fn codes(&self) -> &[u64];
+    fn min_hamming(&self) -> u32;
+
+    fn max_correctable(&self) -> u32 {
+        self.min_hamming().saturating_sub(1) / 2
+    }
+
+    fn width(&self) -> usize {
+        self.grid() + 2 * self.border()
+    }
+
+    fn bits(&self) -> Vec<(i32, i32)> {
+        grid_bits(self.grid(), self.border())
+    }
+
+    fn reversed_border(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Debug for dyn TagFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+pub fn grid_bits(grid: usize, border: usize) -> Vec<(i32, i32)> {
+    let grid = grid as i32;
+    let border = border as i32;
+
+    (0..grid)
+        .flat_map(|y| (0..grid).map(move |x| (x + border, y + border)))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Family {
+    pub name: &'static str,
+    pub grid: usize,
+    pub border: usize,
+    pub codes: &'static [u64],
+    pub min_hamming: u32,
+    pub bits: Option<&'static [(i32, i32)]>,
+    pub reversed_border: bool,
+}
+
+impl TagFamily for Family {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn grid(&self) -> usize {
+        self.grid
+    }
+
+    fn border(&self) -> usize {
+        self.border
+    }
+
+    fn codes(&self) -> &[u64] {
+        self.codes
+    }
+
+    fn min_hamming(&self) -> u32 {
+        self.min_hamming
+    }
+
+    fn bits(&self) -> Vec<(i32, i32)> {
+        match self.bits {
+            Some(bits) => bits.to_vec(),
+            None => grid_bits(self.grid, self.border),
+        }
+    }
+
+    fn reversed_border(&self) -> bool {
+        self.reversed_border
+    }
+}
+
+pub const TAG16H5: Family = Family {
+    name: "tag16h5",
+    grid: 4,
+    border: 1,
+    codes: &tag16h5::CODES,
+    min_hamming: 5,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const TAG25H9: Family = Family {
+    name: "tag25h9",
+    grid: 5,
+    border: 1,
+    codes: &tag25h9::CODES,
+    min_hamming: 9,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const TAG36H11: Family = Family {
+    name: "tag36h11",
+    grid: 6,
+    border: 1,
+    codes: &tag36h11::CODES,
+    min_hamming: 11,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const TAG_STANDARD41H12: Family = Family {
+    name: "tagStandard41h12",
+    grid: 3,
+    border: 1,
+    codes: &tag_standard41h12::CODES,
+    min_hamming: 12,
+    bits: Some(&tag_standard41h12::BITS),
+    reversed_border: true,
+};
+
+pub const TAG_STANDARD52H13: Family = Family {
+    name: "tagStandard52h13",
+    grid: 4,
+    border: 1,
+    codes: &tag_standard52h13::CODES,
+    min_hamming: 13,
+    bits: Some(&tag_standard52h13::BITS),
+    reversed_border: true,
+};
+
+pub const TAG_CIRCLE21H7: Family = Family {
+    name: "tagCircle21h7",
+    grid: 3,
+    border: 1,
+    codes: &tag_circle21h7::CODES,
+    min_hamming: 7,
+    bits: Some(&tag_circle21h7::BITS),
+    reversed_border: true,
+};
+
+pub const DICT_4X4_50: Family = Family {
+    name: "DICT_4X4_50",
+    grid: 4,
+    border: 1,
+    codes: first(&aruco::DICT_4X4, 50),
+    min_hamming: 4,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_4X4_100: Family = Family {
+    name: "DICT_4X4_100",
+    grid: 4,
+    border: 1,
+    codes: first(&aruco::DICT_4X4, 100),
+    min_hamming: 3,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_4X4_250: Family = Family {
+    name: "DICT_4X4_250",
+    grid: 4,
+    border: 1,
+    codes: first(&aruco::DICT_4X4, 250),
+    min_hamming: 3,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_4X4_1000: Family = Family {
+    name: "DICT_4X4_1000",
+    grid: 4,
+    border: 1,
+    codes: &aruco::DICT_4X4,
+    min_hamming: 2,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_5X5_50: Family = Family {
+    name: "DICT_5X5_50",
+    grid: 5,
+    border: 1,
+    codes: first(&aruco::DICT_5X5, 50),
+    min_hamming: 8,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_5X5_100: Family = Family {
+    name: "DICT_5X5_100",
+    grid: 5,
+    border: 1,
+    codes: first(&aruco::DICT_5X5, 100),
+    min_hamming: 7,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_5X5_250: Family = Family {
+    name: "DICT_5X5_250",
+    grid: 5,
+    border: 1,
+    codes: first(&aruco::DICT_5X5, 250),
+    min_hamming: 6,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_5X5_1000: Family = Family {
+    name: "DICT_5X5_1000",
+    grid: 5,
+    border: 1,
+    codes: &aruco::DICT_5X5,
+    min_hamming: 5,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_6X6_50: Family = Family {
+    name: "DICT_6X6_50",
+    grid: 6,
+    border: 1,
+    codes: first(&aruco::DICT_6X6, 50),
+    min_hamming: 13,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_6X6_100: Family = Family {
+    name: "DICT_6X6_100",
+    grid: 6,
+    border: 1,
+    codes: first(&aruco::DICT_6X6, 100),
+    min_hamming: 12,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_6X6_250: Family = Family {
+    name: "DICT_6X6_250",
+    grid: 6,
+    border: 1,
+    codes: first(&aruco::DICT_6X6, 250),
+    min_hamming: 11,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_6X6_1000: Family = Family {
+    name: "DICT_6X6_1000",
+    grid: 6,
+    border: 1,
+    codes: &aruco::DICT_6X6,
+    min_hamming: 9,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_7X7_50: Family = Family {
+    name: "DICT_7X7_50",
+    grid: 7,
+    border: 1,
+    codes: first(&aruco::DICT_7X7, 50),
+    min_hamming: 19,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_7X7_100: Family = Family {
+    name: "DICT_7X7_100",
+    grid: 7,
+    border: 1,
+    codes: first(&aruco::DICT_7X7, 100),
+    min_hamming: 18,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_7X7_250: Family = Family {
+    name: "DICT_7X7_250",
+    grid: 7,
+    border: 1,
+    codes: first(&aruco::DICT_7X7, 250),
+    min_hamming: 17,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_7X7_1000: Family = Family {
+    name: "DICT_7X7_1000",
+    grid: 7,
+    border: 1,
+    codes: &aruco::DICT_7X7,
+    min_hamming: 14,
+    bits: None,
+    reversed_border: false,
+};
+
+pub const DICT_ARUCO_ORIGINAL: Family = Family {
+    name: "DICT_ARUCO_ORIGINAL",
+    grid: 5,
+    border: 1,
+    codes: &aruco::ARUCO_ORIGINAL,
+    min_hamming: 1,
+    bits: None,
+    reversed_border: false,
+};
+
+pub(crate) const BUILTINS: [Family; 23] = [
+    TAG16H5,
+    TAG25H9,
+    TAG36H11,
+    TAG_STANDARD41H12,
+    TAG_STANDARD52H13,
+    TAG_CIRCLE21H7,
+    DICT_4X4_50,
+    DICT_4X4_100,
+    DICT_4X4_250,
+    DICT_4X4_1000,
+    DICT_5X5_50,
+    DICT_5X5_100,
+    DICT_5X5_250,
+    DICT_5X5_1000,
+    DICT_6X6_50,
+    DICT_6X6_100,
+    DICT_6X6_250,
+    DICT_6X6_1000,
+    DICT_7X7_50,
+    DICT_7X7_100,
+    DICT_7X7_250,
+    DICT_7X7_1000,
+    DICT_ARUCO_ORIGINAL,
+];
+
+const fn first(codes: &'static [u64], n: usize) -> &'static [u64] {
+    codes.split_at(n).0
+}
+
+pub fn builtin_family(name: &str) -> Option<Arc<dyn TagFamily>> {
+    BUILTINS
+        .iter()
+        .find(|f| f.name == name)
+        .map(|&f| Arc::new(f) as Arc<dyn TagFamily>)
+}
+
+#[cfg(feature = "serde")]
+pub(crate) mod by_name {
+    use super::TagFamily;
+    use crate::custom::CustomFamily;
+    use crate::table;
+
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use std::sync::Arc;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum Entry {
+        Name(String),
+        Custom(CustomFamily),
+    }
+
+    pub fn serialize<S: Serializer>(families: &[Arc<dyn TagFamily>], s: S) -> Result<S::Ok, S::Error> {
+        s.collect_seq(families.iter().map(|f| {
+            match super::builtin_family(f.name()) {
+                Some(b) if table::fingerprint(b.as_ref()) == table::fingerprint(f.as_ref()) => {
+                    Entry::Name(f.name().to_string())
+                }
+                _ => Entry::Custom(CustomFamily::from_family(f.as_ref())),
+            }
+        }))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<Arc<dyn TagFamily>>, D::Error> {
+        Vec::<Entry>::deserialize(d)?
+            .into_iter()
+            .map(|entry| match entry {
+                Entry::Name(name) => {
+                    super::builtin_family(&name)
+                        .ok_or_else(|| D::Error::custom(format!("unknown tag family `{name}`")))
+                }
+                Entry::Custom(family) => Ok(Arc::new(family) as Arc<dyn TagFamily>),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,1270 @@
+pub static DICT_4X4: [u64; 1000] = [
+    46386,  3994, 13101, 39238,
+    21662, 31181, 40494, 50418,
+    65242, 53078, 63889,  4519,
+     3767, 10767,  9393,  9790,
+    18021, 26112, 27742, 30383,
+    34443, 45099, 52437, 56706,
+    65095, 38001, 44260, 42324,
+     8483, 13423, 17429, 22450,
+    40655, 61643,  2222,  2345,
+     6261,  1279,  3574,  7258,
+     5912, 10792, 12940, 14514,
+     9448, 12011, 11583, 19300,
+    20526, 20499, 20884, 21864,
+    23873, 24471, 26625, 26727,
+    24868, 25065, 27410, 28645,
+    26591, 32283, 32928, 33604,
+    35746, 37754, 33900, 34090,
+    34204, 40073, 40865, 47996,
+    48132, 46683, 49096, 47019,
+    51743, 51554, 55640, 54229,
+    52376, 51104, 50487, 59741,
+    63781, 64443, 60970, 63309,
+    13685, 35501, 30231,  2767,
+     1611, 11713, 18904, 17396,
+    20278, 20435, 27108, 28871,
+    31342, 46314, 60751, 64743,
+    65190,    37,    67,  2696,
+     2694,   623,    28,   151,
+     2103,  2609,  2502,  2817,
+     2555,  2904,  4226,  6189,
+     4216,  4211,  4724,  4785,
+     6905,  4870,  3086,  3313,
+     1075,  3231,  3826,  3837,
+     1868,  4004,  1839,  1461,
+     3985,  2011,  7908,  5177,
+     7552,  5576,  8075,  5562,
+     7601,  8320, 10473,  8866,
+    10323, 10992,  8951, 10560,
+     8518, 10681, 11164, 11186,
+    14538, 14382, 12295, 14567,
+    14921, 14949, 12893, 15240,
+    14621, 15315,  9799, 10112,
+    12202, 11540,  9694,  9555,
+    12151, 13384, 15528, 15425,
+    13325, 13563, 13978, 15840,
+    13674, 15625, 15853, 16324,
+    16236, 14286, 15708, 15734,
+    14256, 16151, 16383, 18661,
+    17000, 18989, 16736, 18769,
+    16861, 19423, 22607, 23112,
+    22550, 20573, 23290, 23221,
+    20771, 23434, 22809, 20789,
+    19561, 18113, 19979, 17503,
+    20057, 19843, 19837, 18392,
+    18291, 23685, 24132, 22059,
+    23739, 21955, 24430, 24555,
+    23826, 21854, 25200, 25109,
+    25026, 27424, 25413, 27484,
+    27483, 30732, 31439, 30847,
+    31104, 29157, 29044, 31158,
+    29139, 31539, 25706, 26280,
+    28327, 28305, 25890, 28107,
+    26509, 27953, 32384, 32482,
+    32397, 29906, 31794, 32309,
+    30123, 30469, 32555, 32218,
+    32658, 32885, 33011, 33190,
+    35309, 33276, 39078, 39456,
+    37187, 39417, 37267, 39892,
+    33801, 33899, 34500, 36452,
+    34330, 34126, 36299, 34151,
+    34223, 34263, 34739, 40161,
+    40178, 37911, 38144, 38306,
+    40227, 40802, 40274, 38362,
+    41157, 43725, 41688, 41559,
+    43325, 43351, 43858, 41782,
+    41817, 45300, 47122, 45247,
+    45725, 48109, 47474, 47510,
+    42179, 44242, 44721, 42370,
+    44901, 42363, 45050, 46180,
+    48226, 46209, 46752, 48878,
+    48653, 48345, 48888, 46376,
+    46857, 47058, 49386, 49177,
+    49405, 51411, 51802, 49485,
+    51636, 49495, 50072, 49949,
+    55424, 55535, 55851, 53278,
+    53509, 54189, 56231, 50377,
+    52344, 52549, 50443, 53199,
+    56492, 54274, 56419, 54311,
+    54517, 54904, 57016, 56806,
+    54621, 56765, 57117, 58058,
+    60011, 57524, 57912, 58068,
+    58146, 57816, 61443, 62156,
+    63734, 61769, 62442, 61852,
+    63989, 61755, 60557, 61129,
+    58895, 58615, 59232, 61416,
+    60850, 58645, 61393, 62598,
+    64513, 63171, 62588, 64659,
+    62786, 64920, 62781,   701,
+      225,   738,   686,  2168,
+      116,  2206,  2257,  2173,
+     2610,  2782,   593,   418,
+      896,  2947,  2891,  2855,
+     3055,  2486,  2393,  2451,
+     3064,   985,  1009,  4292,
+     6315,  6816,  6660,  6764,
+     6830,  4745,  4119,  6899,
+     6464,  4354,  4395,  4559,
+     6946,  4910,  4373,  5051,
+     3104,  3273,  3292,  3126,
+     1556,  1650,  3425,  1293,
+     3471,  4064,  3913,  1925,
+     1424,  3379,  3990,  3958,
+     5216,  7309,  5338,  7283,
+     7828,  7866,  5849,  7741,
+     5883,  7657,  7678,  8095,
+    10379,  8367,  8718,  8873,
+    10893, 10915, 10991, 10384,
+    10299, 10840,  8755,  8608,
+     8450,  8613,  8647, 11011,
+     9063, 10544, 10706, 11033,
+    11163, 11159, 14376, 14501,
+    14982, 12801, 14495, 13010,
+    15001, 15061, 14824, 15297,
+    13123, 15335, 12698, 13200,
+    15262,  9412, 11338, 11437,
+    11471, 11367,  9962, 12005,
+    11376, 11794, 11985, 11833,
+     9572,  9703, 12236, 11708,
+    11633,  9685,  9627, 10000,
+    12156, 10226, 10042, 12214,
+    10195, 12211, 10015, 15435,
+    14016, 14062, 16105, 13496,
+    15380, 15442, 13426, 13438,
+    13503, 15985, 15955, 15756,
+    13730, 13614, 13613, 14252,
+    13680, 14330, 16369, 16347,
+    18628, 18665, 19138, 19009,
+    17131, 18451, 19160, 17149,
+    18967, 18787, 17262, 16698,
+    18865, 16701, 19346, 19355,
+    17215, 22562, 20650, 22567,
+    21192, 21124, 21002, 23055,
+    22680, 22620, 20699, 20727,
+    23284, 20972, 20802, 20749,
+    23299, 21483, 20854, 22897,
+    20883, 21497, 23475, 21399,
+    19532, 17483, 19491, 18060,
+    20007, 18064, 20180, 17870,
+    17893, 17703, 20417, 18181,
+    17716, 17778, 23752, 23566,
+    21739, 22153, 22083, 24295,
+    23664, 21682, 24185, 22259,
+    23971, 24050, 21789, 23965,
+    22524, 22482, 24435, 26669,
+    26819, 26759, 27210, 25193,
+    24761, 26879, 27356, 27354,
+    27198, 27217, 27185, 25303,
+    25036, 27522, 27619, 26938,
+    24990, 24981, 24949, 26975,
+    26935, 25562, 28674, 30819,
+    28751, 29386, 31405, 28795,
+    31252, 31481, 31443, 31419,
+    31202, 28969, 31591, 29136,
+    31033, 29488, 29625, 29523,
+    29695, 27784, 25609, 27715,
+    26118, 26243, 25776, 25818,
+    28319, 26568, 28654, 27963,
+    28626, 29824, 31915, 32360,
+    32258, 31900, 29750, 31761,
+    32478, 32438, 30427, 32196,
+    32138, 30061, 30600, 30496,
+    30529, 30008, 30142, 32155,
+    30551, 34856, 32940, 34829,
+    34919, 33358, 35489, 33323,
+    32792, 35065, 32925, 35484,
+    33329, 35445, 33431, 33033,
+    33259, 33031, 35624, 35756,
+    33582, 33765, 33104, 35122,
+    35706, 35734, 33661, 36999,
+    39676, 37621, 37290, 37697,
+    37669, 39915, 39220, 37367,
+    39898, 37718, 33858, 35969,
+    35919, 34376, 34470, 36355,
+    34531, 34415, 36527, 33886,
+    33911, 34554, 36382, 36407,
+    34570, 36746, 36646, 34593,
+    34573, 34162, 34622, 40003,
+    40545, 37976, 38136, 39986,
+    38006, 38065, 38109, 38043,
+    40155, 40604, 40658, 38425,
+    40625, 38249, 40813, 38699,
+    38326, 38329, 40253, 40279,
+    43244, 43045, 41644, 41474,
+    43622, 43663, 43751, 43056,
+    43130, 43254, 43155, 41492,
+    43572, 41586, 43762, 41713,
+    41280, 43274, 41254, 43461,
+    43471, 41268, 43282, 41466,
+    43928, 41975, 45062, 45125,
+    47245, 45700, 47344, 47189,
+    45686, 47761, 45681, 47552,
+    47426, 47402, 45964, 46026,
+    47974, 45839, 45530, 47892,
+    48118, 45843, 42088, 44076,
+    44193, 44267, 44231, 42087,
+    42688, 44768, 42531, 44520,
+    42444, 42988, 44412, 42266,
+    42385, 44313, 42391, 46189,
+    48843, 48186, 48373, 48829,
+    48883, 46373, 46479, 46952,
+    49124, 48638, 48541, 46581,
+    46579, 49072, 46938, 48958,
+    46905, 49109, 46877, 48949,
+    46975, 51201, 49317, 49794,
+    51389, 49916, 51857, 49755,
+    51524, 49450, 50112, 51578,
+    49593, 51573, 49655, 52145,
+    53356, 55431, 53423, 56004,
+    53772, 55817, 53296, 55444,
+    53306, 53430, 53365, 53878,
+    55901, 55861, 53783, 55554,
+    54248, 54245, 53658, 53750,
+    53585, 56084, 54078, 54227,
+    50272, 52391, 50754, 50759,
+    52967, 50268, 52253, 52277,
+    50876, 52648, 50444, 50660,
+    50626, 52525, 52569, 52629,
+    50579, 51039, 54469, 56968,
+    54820, 57068, 55010, 57030,
+    56867, 56540, 56346, 54289,
+    56916, 54932, 56989, 56705,
+    54693, 55212, 55142, 57257,
+    54748, 56607, 57328, 57928,
+    58088, 57863, 57437, 60149,
+    60198, 60397, 57682, 57726,
+    59867, 63494, 61678, 63649,
+    64000, 64194, 61595, 64244,
+    64060, 62204, 62141, 62099,
+    61792, 63980, 61766, 63969,
+    62280, 62382, 62401, 62347,
+    62375, 61811, 61847, 62452,
+    64306, 58375, 58957, 60501,
+    60864, 60805, 61346, 59214,
+    58837, 61264, 62498, 62601,
+    62505, 63082, 65035, 65135,
+    62613, 62517, 62495, 63152,
+    62952, 62917, 64803, 65472,
+    63436, 63465, 62908, 65014,
+    62937, 64919, 64831, 65436,
+    65370, 63486, 65297, 63423,
+];
+
+pub static DICT_5X5: [u64; 1000] = [
+    21344956,  1836774, 28249821, 17012215,
+    28226852, 30672941, 13883372, 14816363,
+    17654066, 20004773, 20770307, 27450048,
+    31861521,  6189414, 33356968,  5366655,
+     9938776, 12493423, 16141764, 17178088,
+    19782261, 22078528, 23792801, 12194526,
+    27054115, 27629938, 29609611,  2245190,
+     3905138,  2368059,  2570094,  3573875,
+     4247758,  4893384,  4604858,  8024042,
+    10062508,  8573185, 10136861,  8797298,
+    11348005, 10825630, 14174711, 12782041,
+    14319153, 15295098, 15317399, 16336902,
+    16093477, 16168919, 18569444, 18535033,
+    18757643, 18256965, 19921738, 22596493,
+    22644720, 21114847, 21893671, 22470434,
+    24370161, 23382332, 24951686, 25692807,
+    26298333, 25488266, 26052013, 28495273,
+    29015411, 32101426, 31941928, 16086026,
+    28383827,  1625701,  2798105, 10021249,
+    11011612, 21078360, 25506124, 26661164,
+    33215394,   598709,  1686752,   742524,
+      182127,  1254025,  1233575,   805699,
+      424901,  1026573,  2039636,  3729529,
+     3291939,  2897479,  2991387,  3054989,
+     2606434,  4128245,  5381856,  4493053,
+     5517185,  5633737,  4710408,  5217272,
+     4642136,  6223454,  6889864,  6296493,
+     6863905,  7513552,  6553826,  7196218,
+     8983561,  9034237,  9060069,  9082154,
+     9185149, 10290706,  9364787, 10421499,
+    12087067, 11664110, 10711024, 11695606,
+    11875122, 10879969, 10983631, 10935298,
+    11946847, 12561305, 13290629, 13340510,
+    13397666, 13506955, 14757051, 16303873,
+    15947602, 14973580, 15190885, 17321240,
+    18080258, 17653203, 18106355, 19426019,
+    20482614, 19545929, 19733280, 19811694,
+    20344526, 20417985, 21050589, 21538061,
+    22110475, 22754948, 23060657, 23092423,
+    23076400, 24761701, 25004185, 23528948,
+    23507065, 25070556, 25078887, 25720288,
+    26858246, 25847297, 26887367, 25956629,
+    26028158, 26598665, 27217445, 26657733,
+    27264466, 28929676, 28514057, 28548452,
+    27603585, 27632174, 29144285, 28610181,
+    28735520, 28778386, 30421447, 30030046,
+    29580401, 31159031, 30654564, 30849560,
+    31454010, 32514562, 32509862, 32244801,
+    32257727, 33385434, 32794225, 32806275,
+    32399073, 32495097, 33435945, 32982716,
+    13725790, 20061719, 20164685, 29933085,
+     3809501,  5603937,  6849223,  7307497,
+     8285079, 11354264, 11432051, 13447536,
+    15157806, 16669179, 18255826, 21727022,
+    22238166, 23737198, 23442739, 25789416,
+    26571324, 28368711, 29301532, 30067999,
+    31100469, 31510285,   545757,    18063,
+       83446,   627100,   107971,  1578014,
+     1120140,  1641654,   721914,   188988,
+      230443,   249570,  1201022,  1712183,
+     1706544,  1825375,  1756604,  1303788,
+      368901,   312722,   911186,   908870,
+     1866788,  1390011,  1360603,  1441442,
+     1035493,  1520642,  2072860,  2003750,
+     2092790,  2178241,  2139098,  2684349,
+     3738237,  3688144,  3247720,  2881285,
+     2268455,  3359189,  2371736,  2443100,
+     4020344,  4035288,  4051783,  3468720,
+     2583567,  3050872,  4074226,  3557114,
+     3637028,  4140341,  3576278,  4200954,
+     4206252,  5243699,  5792879,  4921804,
+     4341016,  5962450,  5472402,  5071424,
+     5046329,  4548660,  5002377,  5068466,
+     5083912,  5529797,  6055258,  4619779,
+     4654752,  5163101,  5220705,  6255705,
+     5655925,  6297370,  6835493,  6358066,
+     6354521,  7958647,  7883807,  7459768,
+     7963118,  7466665,  6425449,  7041533,
+     7038375,  7550877,  7527004,  8110343,
+     7596448,  7601728,  7573265,  7085640,
+     6569386,  7176099,  7114198,  7625552,
+     7677814,  7285594,  7211302,  6767025,
+     7243598,  6812856,  8324828,  8312512,
+     8504218, 10035668,  9516243, 10059246,
+     9557781,  9545231, 10007857,  8538151,
+    10098672,  9615227,  9247434,  8743639,
+     9222406,  9300174,  9237366,  9783099,
+    10257053, 10262404,  8852391,  9393553,
+     8812070,  9344211, 10366423, 10390336,
+    10560217, 11018614, 10531995, 10544552,
+    11130339, 11539335, 12104434, 11589174,
+    10643949, 11227885, 11218799, 11178119,
+    10720824, 11718355, 11710262, 11354455,
+    10786992, 11316873, 12321392, 11797502,
+    11823655, 12336047, 12395661, 11852100,
+    11432458, 11498460, 10892889, 11471888,
+    11494975, 11493114, 11002326, 11943384,
+    12455245, 12475567, 11973504, 12040955,
+    11967582, 12508947, 11978676, 12599369,
+    13192765, 12626089, 13716686, 14276473,
+    13798811, 14411603, 14335038, 13385926,
+    13481639, 12955169, 13978281, 14441555,
+    13528356, 13601172, 13018693, 14055305,
+    14560417, 14069835, 14600107, 14778865,
+    15314848, 14833373, 14873143, 14845348,
+    14862330, 15465717, 16462104, 16479214,
+    16394023, 15896270, 16498566, 15982245,
+    14942572, 15501617, 15529092, 16521067,
+    15103427, 15668855, 15645652, 16685057,
+    16182209, 16762228, 16867463, 16873594,
+    16802992, 16824373, 17430088, 16842731,
+    17849227, 17904577, 17838890, 17447823,
+    17004302, 17023681, 17497155, 17032484,
+    17988313, 18008421, 18589757, 18005411,
+    18090328, 18714668, 18736633, 17176338,
+    17716954, 17730146, 17225969, 17816602,
+    18309719, 18293680, 18264780, 18860714,
+    18867915, 19412010, 18989902, 18911230,
+    20543914, 19998184, 20490065, 20022998,
+    19981439, 19015594, 19628022, 19565101,
+    19652016, 20687880, 20628704, 19750812,
+    19756081, 19772027, 20726364, 20280072,
+    20251295, 20215405, 20809290, 19343952,
+    19817565, 19900208, 20396871, 20855264,
+    20866725, 20444564, 20968744, 21002647,
+    21582034, 21063658, 21550462, 21018353,
+    22073649, 22649453, 22657267, 21140038,
+    21137330, 22765986, 22230585, 22222255,
+    22718677, 22795939, 21773084, 21805261,
+    22902856, 22369888, 22911408, 22403958,
+    22923163, 21916689, 21486026, 21463863,
+    22017140, 23022291, 23040379, 23060296,
+    22466540, 23607460, 23625353, 23716244,
+    24129626, 24739601, 24189961, 24703057,
+    24219783, 24766073, 23733784, 23232212,
+    23251703, 23304154, 23299279, 23246309,
+    23845951, 24322273, 24331905, 24309940,
+    24375190, 23857667, 23461764, 23393834,
+    23387317, 24996839, 24465218, 24437781,
+    23486592, 24078953, 24040088, 24087426,
+    23506876, 24553974, 24614105, 24639427,
+    25160973, 25713400, 26228840, 26773451,
+    25848669, 25372032, 25839598, 25378812,
+    25340098, 25341700, 25398895, 26417631,
+    26992862, 26394319, 26406882, 26985771,
+    26050063, 26050416, 26064787, 26501904,
+    27114587, 26144857, 26695319, 27166590,
+    27352237, 27321958, 28322096, 28885311,
+    27978416, 27509166, 28047202, 28030185,
+    28997865, 29044442, 28456546, 28493132,
+    27612921, 27626456, 27628756, 27580332,
+    29177448, 29173052, 28623281, 28210021,
+    28229196, 29229699, 29291039, 28831464,
+    29394591, 29461958, 30962043, 30974970,
+    30087752, 30018291, 29531632, 30060473,
+    29618149, 29690522, 29634612, 29644694,
+    30267396, 30196994, 30690432, 30785928,
+    30729513, 29762175, 30283725, 29805027,
+    30406085, 29865958, 31395524, 31374048,
+    30854172, 31364216, 31548483, 31547038,
+    32063097, 31582728, 31581424, 32532678,
+    33142558, 33160347, 33251467, 33187798,
+    32737715, 31737635, 32351735, 33415079,
+    31940725, 32445595, 31924027, 32498766,
+    33520395, 33443211, 33013954, 33536052,
+     3403975, 22198847,    94353,   121282,
+     1098444,  1173766,  1700154,  1147005,
+      160640,   151572,   228711,  1769642,
+     1793735,   879576,   340589,   879902,
+      850561,   320721,   890843,  1842810,
+     1944470,   457295,   512798,  1045823,
+     1480736,  2192047,  2108498,  2147607,
+     3226476,  3201537,  3730516,  3194825,
+     3703852,  2259282,  2306163,  2782406,
+     2761963,  2866500,  2806833,  3908176,
+     3859241,  2388759,  2362160,  2884913,
+     3434751,  4045279,  3989734,  3442474,
+     3472439,  3103620,  2503613,  3625255,
+     3614355,  3645494,  4097463,  4289364,
+     4819316,  5331466,  5785289,  5774645,
+     5808647,  4950943,  4952487,  4386769,
+     4529077,  4462044,  6098008,  5598303,
+     5142618,  5192340,  5191183,  4632328,
+     4627591,  6248307,  6381661,  6312180,
+     6945601,  7974162,  7404908,  6422746,
+     6434743,  6508271,  8060949,  8111418,
+     7132399,  7180157,  8222290,  8180020,
+     8175109,  7646621,  8175608,  6779736,
+     6728028,  8270656,  7822019,  7790687,
+     7853685,  8431284,  8597548,  8593250,
+     9155139,  9164290,  9648804,  8679583,
+     9784647,  9800277,  9801958,  9398669,
+     8857274,  8843498, 10384735, 10423945,
+    10424254, 10455485, 11026062, 10501928,
+    12132998, 11631583, 12171791, 12111025,
+    12170853, 11176872, 10651772, 10735564,
+    11671374, 12285041, 12274799, 11793194,
+    12320728, 10828404, 11302190, 10798660,
+    10851939, 11868057, 10896084, 11528738,
+    12564546, 12654648, 12666283, 12696587,
+    14194908, 13673496, 13268814, 12801492,
+    13296349, 13301939, 13829725, 14387224,
+    14394409, 12922187, 12905037, 12896685,
+    12892089, 14444037, 14464274, 13083560,
+    13014787, 14053667, 14087252, 15233126,
+    14683224, 15291675, 15243550, 16346958,
+    15730221, 16355213, 15428617, 14941386,
+    14851893, 15876334, 16446362, 16418488,
+    16449142, 15510032, 16069006, 16525184,
+    16563379, 15091976, 15096911, 15144617,
+    17303620, 16896092, 16898656, 18377408,
+    18412001, 17925377, 16921801, 16928070,
+    17511705, 17966639, 17044042, 17133459,
+    18707327, 18169816, 18729412, 17711568,
+    17799873, 17731417, 18224310, 18266493,
+    18852712, 18279506, 19425118, 18918033,
+    20479054, 20000574, 19968289, 19622974,
+    19038318, 20670208, 20087571, 19225164,
+    19188681, 19193627, 19709234, 20190874,
+    20298274, 19393849, 20867451, 20881426,
+    21049911, 21503956, 20979367, 22021331,
+    22558619, 22613658, 22605200, 21662030,
+    21227704, 22231890, 22220798, 22187717,
+    21824229, 21885785, 21334092, 22309579,
+    22368460, 22384926, 21945641, 22939721,
+    23102040, 23175622, 23190275, 23711704,
+    24199641, 24213625, 23252417, 24797717,
+    24805237, 24346040, 23360993, 23961426,
+    24397765, 24004951, 24007670, 23550992,
+    24030613, 25121256, 25110565, 25097345,
+    25758608, 25173011, 25223676, 26798114,
+    25301173, 25321221, 25315056, 25979319,
+    26036713, 25544754, 25573958, 25683337,
+    26132414, 26659706, 26709535, 27258226,
+    27830750, 27916216, 28954792, 28009260,
+    27475471, 27441917, 28526861, 28971617,
+    28534899, 29054675, 28546692, 27542042,
+    28086792, 27636150, 28606091, 28638132,
+    28682188, 27682682, 28296155, 28805876,
+    29425783, 29466323, 29920865, 30423725,
+    30533265, 30539102, 31051582, 29522345,
+    30120149, 30629684, 29753818, 30368284,
+    29851900, 30873926, 30883547, 31359510,
+    31425926, 31543360, 31994790, 32050047,
+    31511960, 31520471, 32110420, 33131614,
+    32562720, 33095409, 31676338, 32154399,
+    31715661, 31632432, 33229192, 32709349,
+    33169067, 31799519, 32313393, 32247690,
+    31753134, 32367158, 31837591, 32877121,
+    33327058, 31979292, 33519540, 33002013,
+];
+
+pub static DICT_6X6: [u64; 1000] = [
+     8117912230,  4021958801,  5788658381, 53984036510,
+    57453407765, 58226249320, 17826529781, 36680299162,
+    13016311037, 16155626300, 18756695267, 19554218583,
+    30338813894, 36202065159, 37969630198, 43680128222,
+     2681334212,  5718659471, 12895588578, 19335674621,
+    23319354806, 27523229516, 31919486849, 41426984179,
+    45578993700, 53273203856, 52028803393, 62084121899,
+    62864009288, 62650021809, 67165901487,  1707079613,
+     1411216086,  3480372898,  5159664107,  5813911156,
+    15637933726, 21225154240, 22294523168, 23785877044,
+    26853935627, 25857337489, 26131589562, 28990475643,
+    30246791759, 31545816858, 32905466620, 35980271786,
+    37091560511, 39706921748, 40950727892, 42645763016,
+    44335761336, 48741713247, 49384669807, 50499756638,
+    51611465573, 52817969753, 53059108237, 55419235116,
+    55042531954, 54026163277, 55765897012, 61602879607,
+    64364239081, 66436675442, 12050494036, 11774457011,
+    14973524954, 43388325391, 45502700469, 58010671880,
+      897752966,  2084607484,  4263928187,  5444428865,
+     4476764087,  4968764735,  6141712460,  6570677936,
+     7223140626,  6731522209,  7356504599, 10163412747,
+    10881474422, 13778372266, 13356155383, 13903170662,
+    16860989711, 18862934591, 18449107815, 17612284904,
+    19547100321, 22805061485, 23448897555, 22954728236,
+    23546257679, 24750813156, 24772593534, 25349256194,
+    25538311935, 24549434930, 27128378286, 27333326284,
+    28617885102, 28393287746, 28406220010, 31266319846,
+    30818844331, 32130319380, 33835479575, 32859686471,
+    32399787783, 32687806356, 36087111622, 35155027957,
+    35783898132, 41357433140, 41994816663, 42364129240,
+    43518078520, 46825326636, 46440520129, 47300344302,
+    48933732564, 48644691124, 51181813307, 50306735055,
+    53270770369, 52050582443, 54582502813, 54660511524,
+    55844076785, 55948062036, 56892720500, 61978423719,
+    61022136906, 62283901716, 63358895511, 63069225946,
+    66097151368, 65237779321, 68262013369, 46075992171,
+    51759162795,  5316786919, 21074269719, 23320814368,
+    28572165440, 30778427928, 34725965696, 55646633311,
+    57641687140, 63572097585, 65802293767, 66873613815,
+       38750119,     8706178,  1137454889,  1203044881,
+     1739475997,   178874531,  1293127316,  1359642842,
+      336265223,   433704673,  2164620859,  2260302694,
+     4002121226,  3066522523,  4257978571,  4239192992,
+     5407112600,  5376180733,  5646650711,  5197624071,
+     4638485430,  7710872120,  7747958630,  8370242123,
+     6818112631,  6802984068,  8498663172,  7384362712,
+     7431131390,  9299518410,  9327673647,  9441467200,
+    10027177580, 10642717335, 10188022821, 10844724446,
+    11880882400, 11468786640, 11080178979, 12253824016,
+    11806050726, 14643193532, 14100690887, 14191343680,
+    14917007089, 15605490037, 16663696392, 16265788578,
+    15393739129, 15423352555, 17026500401, 16487548172,
+    15480288407, 18792899166, 18927558844, 17961899438,
+    18465127231, 17389404649, 19011423438, 18011731307,
+    17484901849, 19146627499, 20970413576, 21016349024,
+    21038276719, 20012280131, 19618417465, 20751169057,
+    20308172417, 19818626446, 23128502124, 22413139964,
+    23074601722, 23041320527, 23651332570, 25432762077,
+    25519167925, 24996870748, 25620438786, 25200551016,
+    25824047687, 26935930849, 26785733238, 26267080957,
+    29588859642, 29629635441, 28598509196, 28612633845,
+    28742268929, 28831953450, 29952316793, 29434571277,
+    30042530903, 30128966240, 31788850808, 30142423030,
+    31533472896, 30411260216, 31540693945, 33323123505,
+    33476476209, 33546227422, 34129978191, 34165922008,
+    36196859161, 35310909971, 36409154649, 37114759374,
+    36661605576, 38421787875, 37988030249, 37992886781,
+    36942876621, 39303866268, 39877488402, 38895772439,
+    40492356257, 40056896070, 40060826845, 38988849446,
+    40650517241, 39109959642, 39145416545, 41457052808,
+    40951926833, 41010923898, 42358191206, 44066364270,
+    43813640083, 43826548570, 43351988312, 45049537085,
+    45169967803, 46783789577, 46254484840, 45274183763,
+    45511388344, 46706249186, 48569180910, 49284684912,
+    51015983340, 50583447465, 51200063770, 49636485507,
+    50744987336, 52079497009, 52188884535, 53362304680,
+    53451089550, 53549918483, 55360751426, 54839188567,
+    55557111245, 55099665570, 55758864848, 54172353370,
+    55821252371, 57493927693, 56932742068, 57862498686,
+    56330507601, 57394732345, 58542689481, 58062030937,
+    59283810541, 59969181672, 58948312679, 59504124702,
+    60155790541, 61797672225, 60847585712, 61351269920,
+    60933606481, 60459307050, 62227069157, 63401739957,
+    63529327904, 64075836486, 62512329442, 63054893644,
+    63731516098, 63292764630, 64967028037, 66062110642,
+    66206418651, 65751732464, 64804729909, 64880186014,
+    67197321013, 68298500038, 66854818866, 66950947517,
+    67446289540, 68006181253, 67537274854, 68696546403,
+    43928450128, 43377918722,  4084740784, 10448306802,
+    17608444559, 21128034468, 26782611386, 29622132332,
+    34582404872, 40811137533, 43761695343,   563765575,
+      559719650,   109888409,   168902632,   731211605,
+      745256242,   791802329,  1923880931,  1937299826,
+      384958069,   393412949,  2035754010,  2089433198,
+     1609547641,  3244241153,  2756062766,  2246278889,
+     2234376493,  3328737337,  2295047295,  3401740773,
+     2335408319,  3391774100,  2385849345,  4025765726,
+     4025867947,  3007765662,  3500141491,  4044007718,
+     3522583166,  4212521472,  3659551283,  3673308603,
+     3205127908,  3700006846,  4292018807,  3161838946,
+     4881788957,  4954818341,  4378519079,  4442336907,
+     4550771438,  5158921986,  6185696944,  5639696827,
+     4732927154,  5274643597,  6323133651,  5783837148,
+     6349101012,  5868505924,  4797256764,  5857351989,
+     6475669418,  7583361725,  8133137362,  6559852537,
+     6533893007,  8143806004,  7136417861,  6618607658,
+     6608854174,  7702881802,  7669504129,  7671086445,
+     7191905665,  7753878360,  7375605860,  8432881970,
+     7944243024,  8604356671, 10256993920, 10323411704,
+     8677513462,  9269540771, 10516147797,  8970880059,
+    10129666234,  9030245134,  9016650015, 10639030474,
+    10636002088,  9632992407,  9062154049, 10686803851,
+    11324350027, 11362609356, 11494209919, 11005044273,
+    11631614872, 12182024618, 11740754918, 12246950098,
+    12813018787, 11771105271, 11214387436, 13971487797,
+    12888158862, 13003513539, 14039247402, 13550266556,
+    14587149682, 14106700454, 14631807761, 14656401757,
+    13636125470, 14730425556, 13757897157, 13821995882,
+    13252948945, 14400066187, 13831380068, 14470457836,
+    14990805923, 15054615964, 15061100204, 15626210746,
+    15175851817, 15783746690, 16371388539, 16364639132,
+    15854136441, 16386490198, 15377906342, 16471752278,
+    15954943970, 16495041767, 16010806020, 16064018254,
+    17738791009, 18297863365, 17290224140, 18333650716,
+    17262506069, 17414007878, 19019493806, 18019711789,
+    17552613159, 19192779466, 18161485626, 18704509537,
+    19358275254, 19398824164, 20499336998, 19410871488,
+    19463611099, 19474586027, 20642430034, 21271035095,
+    19673373576, 20763308439, 21290111611, 20288261707,
+    20837739166, 20286611378, 21455440875, 19858385651,
+    22582839927, 22575466728, 23207977464, 22085393739,
+    21635730435, 22182381221, 21718803492, 22266172824,
+    22241263466, 22866267682, 21811825506, 22926090584,
+    22358254781, 22393208331, 23473958183, 23490024834,
+    23526651048, 21902326088, 23503927099, 23021358320,
+    23612509777, 24701689893, 25292489936, 25339257333,
+    24333170613, 25414671885, 23810564033, 24366778080,
+    23875466752, 24478683656, 25556876923, 23958337129,
+    25074678481, 23977500219, 25038899906, 25636229864,
+    25737278734, 25748651376, 26954196789, 25967889624,
+    27010150486, 26529649245, 26565212387, 27212939847,
+    26763905304, 26742790557, 27361434055, 27896215300,
+    28456028628, 27982241968, 27942873053, 29079508239,
+    27996032861, 28000213008, 29644244368, 29687808613,
+    29679301427, 29781541031, 29195653056, 28186056127,
+    28789764012, 28853359687, 28367084706, 28361256828,
+    29506087115, 31163664108, 30167182228, 31208838433,
+    30259525801, 31830830676, 31872909609, 30227331668,
+    31285047315, 30862071035, 31897528959, 31445553049,
+    31470633734, 31947791711, 30405800613, 30983429617,
+    30409679562, 30986610624, 31022529262, 31544961257,
+    32144508854, 31110975786, 31087560707, 30592508064,
+    32260289142, 33288333783, 32846439332, 32868728951,
+    34009664779, 32478354770, 34066618857, 33047098446,
+    34096156578, 33058778803, 33594800033, 34144425450,
+    32670132903, 33739862611, 34245767362, 33818739746,
+    33778666815, 34418591355, 35452067171, 34363917898,
+    34958539706, 36015077210, 35462157495, 34968116362,
+    34990819155, 35509108174, 35087341236, 34551671664,
+    36157325722, 34573842360, 36236387912, 34755298281,
+    35803121755, 35267897637, 35300203999, 36406873266,
+    36432292685, 38119957380, 37638101703, 37650076354,
+    37740591868, 36763514107, 38433217732, 37907588915,
+    37385204727, 37939730784, 37512905618, 38023737492,
+    38068333160, 37571101940, 38674209364, 40313571445,
+    39288716608, 39331624826, 39905907802, 38957691698,
+    38964483315, 40588271258, 40610248723, 40701132905,
+    39678377340, 40261977824, 39665213617, 39127883806,
+    42424159497, 42477913192, 41441275337, 41012459101,
+    41005478665, 42674065324, 42665161057, 41065861277,
+    42714796884, 41089990780, 41745864683, 41332542165,
+    42383634114, 41837727011, 42948118943, 43556326777,
+    44238476119, 44784296336, 43184294662, 43807099140,
+    44862825391, 44887626381, 43821878734, 44357597259,
+    43335658455, 44937139065, 44421245668, 43441576242,
+    44015983038, 44513639843, 44517211078, 45674805625,
+    46740050940, 46296436434, 45812212959, 45768743590,
+    46911589537, 45427033643, 45921672332, 46547189133,
+    46526223521, 45556896008, 46635295568, 46597938684,
+    46158013127, 48891348764, 48349992871, 48913725603,
+    47325201264, 47915249747, 47426929189, 48481444539,
+    47466212387, 47577558078, 47520040510, 48095511550,
+    49157071983, 47629410117, 47645538478, 49227363191,
+    47691303241, 48242728228, 48300274886, 49438938404,
+    49413152447, 50492984439, 50554104726, 51110968389,
+    49655221557, 51206267768, 50223446603, 50735869805,
+    50770148647, 50736748629, 49780418053, 50859458927,
+    49803870945, 51439101557, 49797491334, 50875953546,
+    51471381207, 50997096455, 52194139064, 52726618829,
+    52740574659, 52187959512, 53322839684, 51705466548,
+    53303448733, 52808924408, 53392987195, 53376382052,
+    52938757339, 52500099287, 52065405530, 54821067856,
+    53689750674, 53817525413, 54332887255, 53848470562,
+    53866078469, 54935771635, 54464638910, 54452011807,
+    55537606886, 54540256044, 54584502306, 54106659600,
+    54092233482, 54658941511, 55170480605, 54167013237,
+    54217799218, 54751658089, 55257911759, 55846643007,
+    56971033967, 57548651803, 57016091567, 56136681354,
+    56699919417, 56111009748, 56259485922, 57912074717,
+    57915630861, 57954328412, 57920536423, 57443552136,
+    57995402214, 59647959638, 59071293630, 59596831307,
+    58616494242, 58101569535, 59254517362, 59240722005,
+    58673980672, 58711809016, 59759403822, 58724504912,
+    59263072270, 58723083178, 58747883472, 58289875585,
+    59329962942, 59373097100, 58340052085, 58871698627,
+    59970855816, 58857126517, 58922797885, 58422210477,
+    59512163640, 58462812593, 59045964551, 60675246840,
+    60158556516, 60205514544, 61326661054, 61875341129,
+    60850398343, 60300230941, 60879738124, 60350045279,
+    60411357225, 60531426115, 62190279454, 60589189625,
+    62272963612, 63941383041, 64017883956, 63468715914,
+    62406245998, 62434214801, 64068032469, 63520412532,
+    62436138069, 62491009488, 64090134168, 63627591098,
+    63730299755, 63743019868, 64294624535, 63340128130,
+    62807498093, 63303645465, 62776087049, 65541813976,
+    66045821477, 65590876593, 65085339155, 65576939014,
+    66150995132, 66105496774, 65589620449, 65659027035,
+    64688943541, 66313117852, 66343762976, 64832519277,
+    65372827265, 66481520294, 66448827842, 65479123282,
+    66024197924, 66587975924, 67845371870, 66820641947,
+    67336153102, 68422752710, 68389163448, 67329987060,
+    67851151154, 66907154155, 67468273123, 68533406255,
+    68534341156, 67026199369, 68593198977, 67045671066,
+    68662959388, 68701747802, 68181403841,  1680773271,
+      843203693,  1977993340,  2021928870,  2692424123,
+     4451061534,  6075820815,  4561090270,  4618635820,
+     4737720577,  8145522210,  6546499760,  7083135741,
+     7232322799,  6824859825,  7941715404,  6876793122,
+     8586093114,  9692158771, 10443327568,  8865934273,
+    10818446698, 12596425816, 12163355860, 12224463815,
+    14218945393, 15136577496, 17081420876, 17949096686,
+    18487520619, 17509965512, 17650529428, 20589841549,
+    19632785746, 22070138310, 22096874970, 25352021011,
+    24883175101, 24677413734, 25775587666, 26852459320,
+    29570398403, 29080505175, 28113364065, 28105333927,
+    31571162729, 33298350240, 33916619849, 32328897349,
+    32415416314, 32978474074, 34423159232, 36260122519,
+    35193379421, 36569826932, 37758732736, 38303445419,
+    38427947876, 38410075296, 39754523808, 39532514384,
+    42141863301, 41643374477, 41333439766, 43653383491,
+    46254109099, 46371623782, 45559890952, 48898150444,
+    48433573344, 47897862305, 48089372318, 48133996821,
+    51032208310, 50599384638, 50650310869, 50773698756,
+    52642540401, 53254811598, 53240930621, 53296271946,
+    51872852962, 52908447441, 53499649787, 51902594505,
+    54261129756, 55775808857, 55886118438, 57033441504,
+    56243721634, 58867468203, 58926332168, 60093916928,
+    60710168064, 60466006230, 60560050770, 62263136169,
+    64069822520, 64454862785, 66410152548, 66551822925,
+    66619524884, 68322655451, 66810392758, 68407255551,
+];
+
+pub static DICT_7X7: [u64; 1000] = [
+    486778059068437, 501617325277526, 348907428690511, 365922815562392,
+    437025642013868, 194614233838556, 464919419857040, 133328050132918,
+    301327367089468, 412090889854078, 538767727651105,   4728246282292,
+    186808917725665, 347759484618547, 417807520469911, 453145010418991,
+    466058760961814,   4137087242715,  85313491099889, 124221643325990,
+    128782813086675, 155757139360186, 159589437619287, 207728371380560,
+    256485748924177, 326261894293170, 341008236272455, 355868782298608,
+    412255864936865, 447963941287472, 465969011951206, 533436120933177,
+    558198163404749, 128145883466205, 215714670081566,  23753431923590,
+     31343220562948,  45353567044680,  56374703999962,  95249573361256,
+     91063794876230,  98881584490669, 118551821970680, 139540663663591,
+    151321695616640, 158887097947626, 209122608834564, 208241028004539,
+    245424233261956, 252157299347911, 267253978696813, 272464795726499,
+    293383001256896, 312810485953187, 380918942590671, 389700810535551,
+    410697685312438, 425420180304205, 453839110916642, 462590265113271,
+    458312266276765, 162494838166197, 182120543698790,   4610080171841,
+     12646907683566,  33746938172222,  34760420788276,  48230987868593,
+     50456302226237,  66780710205158,  69838933168365,  86046145274687,
+     87097458440120, 101259769468120, 104813391236182,  98542220027431,
+    108465205028301, 106587779103315, 122889858273089, 167222925721256,
+    173402620203587, 169005791523036, 190175438588609, 186018991068054,
+    186028348016964, 207658603835218, 217740395595620, 232840728996297,
+    239898696392761, 246827505260386, 262374000306263, 286739572082991,
+    290250931037066, 324883516670203, 327316429665755, 327583500869279,
+    336327421536752, 372827842195024, 389349216518782, 408830299718870,
+    413745304167791, 416161488764244, 422669284906158, 427081754750209,
+    464680755204333, 472848617455404, 475426767154186, 476855220635476,
+    494397055081379, 496774346781082, 504592606941499, 510868999632020,
+    517877859943609, 526498085505937, 545024938566168, 560391526725753,
+     45697440741383,  76796687155138, 361793285640148, 395107882830873,
+    518470984013960,    310204665832,    561205856728,   5424729208417,
+      4079433942470,   9139861643070,  16638713177942,  22759898204079,
+     21328404148579,  29015104469429,  31736563951691,  51561114233564,
+     50572208076252,  55412504602459,  66446185141305,  68063261514832,
+     78278807571610,  78541065458509,  87757790526478,  91939308466666,
+     96300747055759, 101607357878562,  97875543730659, 115372724648192,
+    137252623588936, 135763940675544, 143703366955316, 146516377466407,
+    146984361010101, 156316405878963, 158950653227803, 166145951854630,
+    160024557934079, 162294663083084, 164858682429940, 167462291014474,
+    167673732692181, 168032774381750, 183029119421764, 178724776536171,
+    178862516659091, 176911499715519, 185361365730621, 186599918582926,
+    191185641347273, 201021775687415, 202258040238809, 203314927368060,
+    208057181052661, 218285999362820, 211910631501111, 221072151449694,
+    229613836487808, 237974002178251, 240466277586516, 253154217971736,
+    251015826084239, 254021002563259, 250610962879514, 259622281892982,
+    260214866070594, 270237467167852, 277354865154764, 273652985587351,
+    278253533302324, 280905589928076, 289671896971470, 289615138864811,
+    296251574552247, 305625181204054, 312308781352532, 315080818049486,
+    312980373256640, 311412090245824, 311656264181049, 316830304650592,
+    324589720057121, 332174446028113, 332502350115668, 328710932200106,
+    333951357692065, 339972595350153, 342486519782385, 346136251216058,
+    350759575969534, 351773002508995, 367264738931956, 365720141577438,
+    365763998699014, 362793042194196, 371941124847615, 385021041895453,
+    383145522242501, 384058328255756, 384088080776320, 390695228777852,
+    395659097045584, 395712309365441, 403140205545766, 396678459348412,
+    401058446934288, 410605865306485, 410668951412743, 413494329151182,
+    418330326987288, 424754854057992, 427077847216327, 428353492923492,
+    435561965930552, 439575870735148, 444974060401112, 441961168566511,
+    448907801389516, 450190884217498, 471202667149987, 466880571972071,
+    471674986369258, 472647433555595, 477280455233680, 479976040923434,
+    480614911590045, 498579419729897, 499140119965746, 504109127894561,
+    506940909381856, 504960508743588, 503364067352582, 516987720284102,
+    517671027019640, 526224664476132, 528970376626847, 539129105162934,
+    544256642822990, 547231951421398, 554376550028233, 323903936290858,
+    441294137262123,  63445976170817,  78236740962876, 104114931091737,
+    148725845250494, 161219640486301, 181147696705790, 181566282660162,
+    224021377733759, 249108534024000, 312316079587402, 331314884946145,
+    354366428199625, 415429303754191, 428416768218760, 481333412793184,
+    519514004011952, 561654391166494,    243190893861,   4612404516140,
+      8397845026480,   9032450069301,  21007173101999,  18999797920534,
+     25804567232560,  19142339525006,  19762949171807,  30977127218244,
+     39966335700549,  40180381003216,  43048422300807,  48738888605976,
+     47248107959428,  47937785890733,  52529048304498,  59977961954837,
+     60278197938041,  61064095408405,  68551354019363,  68919161131477,
+     62512332648701,  70009708760733,  70208689591127,  70587961016140,
+     75292503707550,  71220258723426,  75714612227505,  71373286666629,
+     74047295314652,  74243580305533,  86173242918568,  87129413767434,
+     85196093600787,  92386691595515,  94769122283990,  90339549926416,
+     91058887782770,  94021167607918,  89783095053755,  92316267259520,
+     99635584731478,  97420406619042, 102887131095197, 105438132653134,
+    112278569151644, 112550747779592, 108111069692345, 116556787582992,
+    121259215833214, 117248103228601, 120334157521528, 120651966077166,
+    122853042481432, 125415213271235, 129754084500371, 127670885199873,
+    124091538727911, 126185725071025, 126847760930135, 126830301954381,
+    124999758478237, 136594511968191, 139029905475189, 134730319041267,
+    138198915328495, 147724206740760, 145582331213989, 141247901930462,
+    148275735989480, 148636115128921, 156385117708801, 154309439041266,
+    154724383850613, 152754509078605, 155376088200696, 150911573557780,
+    153324374616650, 165904642079627, 174242304154019, 175254534261956,
+    175781803121117, 180966936378264, 184245133440913, 191361313344608,
+    187568782820864, 188103987785412, 186033861771430, 193634288044897,
+    198067309637225, 209054046368072, 205036091590238, 205150050927948,
+    207887647776946, 210470726868029, 211077309535327, 215677844032599,
+    211707313952682, 211921598164631, 214262133287070, 212067497500266,
+    217456011333546, 222531301008396, 223599913116652, 233422120435205,
+    232221048264348, 230070450140320, 230176825918519, 237055220629674,
+    242496158061623, 242681678179107, 242875268627674, 245247569714006,
+    238679528420581, 245434387228071, 245367164941663, 243336412625113,
+    243417621212834, 245814359355204, 253586168367070, 247487019366266,
+    247571779302621, 252054432171700, 247767116623265, 252798262667036,
+    259533227017658, 255232937874250, 259794560215533, 255960768673483,
+    262760570386882, 263247663527542, 261035395152721, 259336409849029,
+    261639235528048, 264530307691537, 269895583295551, 279611156900040,
+    276833866084656, 281316516951967, 279192812790666, 283948367704785,
+    281877966301800, 286508652631447, 285399106958922, 290640951853300,
+    292843745161244, 294949628794753, 295440173415663, 297692989926930,
+    292157234999184, 303660262519546, 304008994713104, 301871995864444,
+    306716554493878, 302424628878764, 302582200066405, 307431976512019,
+    307579300127953, 312419212954787, 310462405908816, 308513709925444,
+    311383310840151, 316809715808627, 321449590570153, 317333411361269,
+    317959450984418, 318199018473556, 324913313228770, 332680728287308,
+    332691107483014, 330844825578797, 328847387312135, 331358275007578,
+    340911045983326, 334898752232808, 337505315329775, 343052487035523,
+    346251816847333, 352418847926547, 353167650001602, 355342029032488,
+    357957201802678, 360480464394040, 360765223045680, 365440101209566,
+    365659131396791, 365795349851889, 368312732485330, 364412138998074,
+    362420994157032, 376220472205578, 376440163770385, 369811226402851,
+    370013394669557, 370256848310946, 370522383253693, 370573863993457,
+    377435444937952, 378010338289388, 378421518308142, 382972304152137,
+    385343593739583, 383232245043855, 381001779179327, 381187736229763,
+    379536153863834, 382069729662868, 386979248926922, 387301296576805,
+    390193710178066, 404330041201156, 397791781499644, 409526638917396,
+    412276694164008, 410020874203821, 410214788641503, 412530863257778,
+    413680019913031, 413778896369289, 416314572553642, 418780439799657,
+    417811352685965, 424450935699328, 424670298504269, 424250703926602,
+    424228705536458, 436162322930341, 440760273181904, 440657967061001,
+    443851227737943, 448355597825509, 448804647366473, 450521874768764,
+    450627272853690, 460056338631406, 460384672708820, 473017272845983,
+    474769820472751, 481609545196411, 476078846856055, 476118776744013,
+    481011330817998, 483530018773241, 479289188964490, 488395619212701,
+    486206475298117, 488563324693078, 484434179267266, 487261273335015,
+    487784559465457, 494922027563918, 497236559121547, 499543824460322,
+    506867436210303, 507510119489375, 512933952798950, 511572391306978,
+    512033752052567, 516503709037148, 522745015286760, 523257241900111,
+    532241731380386, 532917367522765, 529150555566468, 533916329052150,
+    529460499534657, 531662166211379, 529864933731681, 536562918378128,
+    537518805991345, 544055951793602, 553164514106926, 548804811054944,
+    553712041075144, 547525999809763, 558686496641407, 554721283514808,
+    555222819690558, 562122890004237, 247381535678172, 290123036520392,
+      2479941707967,    510969547598,   7281940710936,   2938999286556,
+      3435123458644,   1506694898956,   1864585080818,   8658989577328,
+     11792282973027,  14135346135357,  10223329702533,  10485113424736,
+     19833635552076,  18138790885273,  22914866249359,  21056232464888,
+     19399227008197,  31122793665175,  33354668684742,  29398588796934,
+     31537429923527,  29503534937726,  29606098822049,  31858092393881,
+     34182909258794,  30060705717283,  34735795632429,  32815670844451,
+     37517366300324,  38412661278002,  38686573765442,  40918966391519,
+     43785102551988,  48492993663662,  48903216942738,  45100512100143,
+     47301600892017,  47494896195060,  51920554404956,  47599769760745,
+     49971997388774,  45807373821836,  50291807183453,  48034082001130,
+     59421198654863,  53429831606705,  60061460708647,  58181890591860,
+     56171073031068,  54133150547462,  58581108534421,  54424679682209,
+     58787549540685,  54518450969625,  59333559813939,  57146049099734,
+     68455779161858,  64668295143515,  64742921289627,  62562250683515,
+     67405454539309,  70565818523169,  72888264712858,  77356250675325,
+     77586375297393,  77706504171709,  75958377961348,  71805698868389,
+     76183893691983,  74628385716279,  74763924529660,  81778483040980,
+     84040121349008,  86339617277258,  86790153837002,  80370688697866,
+     87321189147869,  83014727194067,  83029936976217,  85484490674609,
+     81138038934808,  81317267268265,  90447456290239,  92770025115027,
+     88495625653768,  96325159617396,  89978316766461,  97423177666165,
+    101911013916060, 100070437986247,  98004828936762, 100502531402083,
+     98681948385799, 105631208823201, 112751919693100, 110923853940914,
+    106995678341583, 107133271195540, 114177954886820, 117463248437942,
+    119827707601171, 117801693898763, 127813739836186, 128284694367639,
+    126249611457377, 130570464249830, 127103861501339, 132380218163662,
+    132386102052583, 134988483184528, 135145764215487, 139850143005799,
+    138106275342422, 140374171905999, 133948929790824, 140766412047441,
+    148250830150365, 148312926952509, 144387463866658, 144678310495782,
+    147259286750312, 149869585460663, 150187330777283, 154572406363064,
+    155057143386049, 150742979241461, 155290791504003, 160799809830342,
+    161174110337607, 161310664697749, 164250430215235, 166602614651282,
+    168130397228591, 172876460586257, 175170346594448, 171079226383960,
+    171091840390017, 169293181109764, 171461460008294, 173712595395077,
+    182913684044823, 182993010547353, 179310163434548, 179608329122918,
+    178022492319279, 184604964354686, 191711883592651, 187754851067479,
+    185703140590881, 192352146253221, 188449720893405, 193168351960601,
+    189005593308778, 197917794870588, 196050137690781, 196346168884382,
+    200902401815523, 196413336262642, 201454248236529, 199190446753073,
+    194979550344808, 199652732171439, 195375848219250, 200086265115128,
+    204853194827605, 207162080043702, 207918544873166, 204373490941962,
+    218107323614150, 213605383878076, 213855216633015, 218605143735840,
+    216907143711645, 212884635492109, 220172055367156, 227222166517637,
+    227310538801998, 220868701511746, 223076817904493, 227820128798161,
+    225861559295916, 228296616251248, 223945813321764, 235753093037422,
+    233827665000479, 232743745487464, 237140668361169, 238211726083030,
+    242748467216457, 238532151806129, 239271637444190, 246036196003638,
+    248507340656102, 248500461745752, 250780818670784, 251350044356402,
+    247568647806920, 254771968622155, 250387496206251, 254956569158215,
+    257429146466522, 258367266491518, 256457648514042, 268408447907290,
+    266275598405963, 267701050990698, 275545862905603, 273839410342556,
+    280671678104869, 276354276244363, 276486428470009, 288108639015822,
+    288442851374295, 284037547935998, 284155796073444, 284540434069725,
+    284733715609595, 287409590280348, 283603011514922, 297318394559376,
+    291474459929567, 291565691294808, 298214408697704, 291958666813483,
+    298556548439077, 294411055038643, 301387628258691, 303953586412301,
+    304750231870506, 310312966839543, 311019089042773, 313795019235577,
+    314176158807613, 312167046266356, 316861923143966, 319159258316240,
+    316980051811333, 317009263107024, 317155972497517, 323940050732212,
+    322473221801108, 320408319628473, 325164251632567, 320830511705272,
+    323089480318734, 323225839047520, 327874935575869, 326161216209662,
+    326204757936205, 332757086720040, 331201948360513, 333456398912289,
+    333520514570436, 334025318654641, 338926120522533, 337045152496235,
+    340177937473759, 340484327505625, 338590099764172, 347659617533653,
+    350266314865233, 350366975464216, 346162395902621, 348404393000684,
+    351924386444982, 356764544953700, 359230778240798, 357594248317046,
+    353613605169326, 353739282863975, 358350276056151, 365448799272733,
+    363323798372583, 361449668726420, 368038683107561, 366022117067532,
+    363967619016884, 361974091711494, 366662784625180, 371698046859002,
+    371822620330967, 376329948128260, 372115048776683, 374819558429942,
+    370619419451410, 375669361805225, 371255117839710, 382802489855816,
+    381294579212729, 385871030506915, 381759165301245, 386173695142564,
+    382217136035910, 382558236734587, 391992969150326, 394199362024419,
+    392262110747334, 394481396643861, 394677943328635, 388548976285203,
+    388642313510766, 390903720091140, 390967734930844, 396847974864874,
+    399520446722823, 404232039407030, 402246113874222, 397923618036910,
+    404756270703119, 405114141736964, 405105734964092, 405548215849424,
+    407993694092673, 405965869316508, 410800859630137, 406605143650649,
+    416598553271197, 414494617671541, 429069705282387, 422610686857918,
+    425089625375987, 425522020037773, 425731718617983, 425671562897343,
+    430388647404647, 426259187881731, 426526870697425, 431494460753625,
+    436070542449518, 438324396080791, 434292410942614, 436410335171983,
+    434380919000469, 434703539661501, 439224024266307, 432645364901574,
+    446531792839786, 442625150606927, 440535307338498, 447179508329810,
+    440793321942048, 443374788046960, 445845092197344, 444011203508904,
+    448464319620354, 448620356940308, 453379381287431, 451931063661465,
+    454104060769827, 450175081682461, 457157508301047, 452914890504535,
+    464196499442621, 457896208015718, 464462825260105, 462541163274806,
+    463415525508866, 465685530669900, 465952679922629, 466159531949572,
+    473379741194686, 471964972528294, 467701131477640, 477346106056613,
+    475687766704509, 475842864415690, 482516773439901, 478043658911654,
+    476177752559592, 478509365963517, 478580977156514, 483683734946714,
+    484037827328986, 488413795945178, 486968822443417, 486987731032977,
+    487136084232231, 491886331259771, 489702971775021, 492358215187426,
+    488143176591134, 499369548388672, 497208344965163, 497758005895611,
+    493670214194413, 495792114093348, 494487344083668, 508200704693896,
+    506305586151982, 508665271935710, 504296413099878, 502408419614541,
+    504629724148885, 509045582901597, 507192350410885, 505407406414899,
+    509828013607159, 503433310756475, 517010075098522, 512802217831171,
+    517225693010037, 515242126384188, 513887707336188, 516407047543678,
+    525657877278044, 521640389529565, 521808532620202, 519800478177292,
+    526690040805518, 524754800553656, 525167949424075, 530044796668181,
+    534728082200785, 534977519470595, 535533537921107, 529158191774875,
+    535823342586778, 529272996568520, 529630161900607, 539410972322795,
+    541658732180949, 541967513793395, 539746616705154, 544230953698956,
+    542096213628472, 545886324345347, 552425266067887, 546016374200361,
+    553336230356631, 551435307121261, 549543120223427, 547551166729455,
+    556454732548209, 556547730688637, 559184457335912, 557494364329809,
+    555436430970270, 559970432082249, 560002330747116, 562430530766329,
+    209890654168048, 297566059658109, 436671915638650, 509955119779135,
+];
+
+pub static ARUCO_ORIGINAL: [u64; 1024] = [
+    17318416, 17318423, 17318409, 17318414,
+    17318640, 17318647, 17318633, 17318638,
+    17318192, 17318199, 17318185, 17318190,
+    17318352, 17318359, 17318345, 17318350,
+    17325584, 17325591, 17325577, 17325582,
+    17325808, 17325815, 17325801, 17325806,
+    17325360, 17325367, 17325353, 17325358,
+    17325520, 17325527, 17325513, 17325518,
+    17311248, 17311255, 17311241, 17311246,
+    17311472, 17311479, 17311465, 17311470,
+    17311024, 17311031, 17311017, 17311022,
+    17311184, 17311191, 17311177, 17311182,
+    17316368, 17316375, 17316361, 17316366,
+    17316592, 17316599, 17316585, 17316590,
+    17316144, 17316151, 17316137, 17316142,
+    17316304, 17316311, 17316297, 17316302,
+    17547792, 17547799, 17547785, 17547790,
+    17548016, 17548023, 17548009, 17548014,
+    17547568, 17547575, 17547561, 17547566,
+    17547728, 17547735, 17547721, 17547726,
+    17554960, 17554967, 17554953, 17554958,
+    17555184, 17555191, 17555177, 17555182,
+    17554736, 17554743, 17554729, 17554734,
+    17554896, 17554903, 17554889, 17554894,
+    17540624, 17540631, 17540617, 17540622,
+    17540848, 17540855, 17540841, 17540846,
+    17540400, 17540407, 17540393, 17540398,
+    17540560, 17540567, 17540553, 17540558,
+    17545744, 17545751, 17545737, 17545742,
+    17545968, 17545975, 17545961, 17545966,
+    17545520, 17545527, 17545513, 17545518,
+    17545680, 17545687, 17545673, 17545678,
+    17089040, 17089047, 17089033, 17089038,
+    17089264, 17089271, 17089257, 17089262,
+    17088816, 17088823, 17088809, 17088814,
+    17088976, 17088983, 17088969, 17088974,
+    17096208, 17096215, 17096201, 17096206,
+    17096432, 17096439, 17096425, 17096430,
+    17095984, 17095991, 17095977, 17095982,
+    17096144, 17096151, 17096137, 17096142,
+    17081872, 17081879, 17081865, 17081870,
+    17082096, 17082103, 17082089, 17082094,
+    17081648, 17081655, 17081641, 17081646,
+    17081808, 17081815, 17081801, 17081806,
+    17086992, 17086999, 17086985, 17086990,
+    17087216, 17087223, 17087209, 17087214,
+    17086768, 17086775, 17086761, 17086766,
+    17086928, 17086935, 17086921, 17086926,
+    17252880, 17252887, 17252873, 17252878,
+    17253104, 17253111, 17253097, 17253102,
+    17252656, 17252663, 17252649, 17252654,
+    17252816, 17252823, 17252809, 17252814,
+    17260048, 17260055, 17260041, 17260046,
+    17260272, 17260279, 17260265, 17260270,
+    17259824, 17259831, 17259817, 17259822,
+    17259984, 17259991, 17259977, 17259982,
+    17245712, 17245719, 17245705, 17245710,
+    17245936, 17245943, 17245929, 17245934,
+    17245488, 17245495, 17245481, 17245486,
+    17245648, 17245655, 17245641, 17245646,
+    17250832, 17250839, 17250825, 17250830,
+    17251056, 17251063, 17251049, 17251054,
+    17250608, 17250615, 17250601, 17250606,
+    17250768, 17250775, 17250761, 17250766,
+    24658448, 24658455, 24658441, 24658446,
+    24658672, 24658679, 24658665, 24658670,
+    24658224, 24658231, 24658217, 24658222,
+    24658384, 24658391, 24658377, 24658382,
+    24665616, 24665623, 24665609, 24665614,
+    24665840, 24665847, 24665833, 24665838,
+    24665392, 24665399, 24665385, 24665390,
+    24665552, 24665559, 24665545, 24665550,
+    24651280, 24651287, 24651273, 24651278,
+    24651504, 24651511, 24651497, 24651502,
+    24651056, 24651063, 24651049, 24651054,
+    24651216, 24651223, 24651209, 24651214,
+    24656400, 24656407, 24656393, 24656398,
+    24656624, 24656631, 24656617, 24656622,
+    24656176, 24656183, 24656169, 24656174,
+    24656336, 24656343, 24656329, 24656334,
+    24887824, 24887831, 24887817, 24887822,
+    24888048, 24888055, 24888041, 24888046,
+    24887600, 24887607, 24887593, 24887598,
+    24887760, 24887767, 24887753, 24887758,
+    24894992, 24894999, 24894985, 24894990,
+    24895216, 24895223, 24895209, 24895214,
+    24894768, 24894775, 24894761, 24894766,
+    24894928, 24894935, 24894921, 24894926,
+    24880656, 24880663, 24880649, 24880654,
+    24880880, 24880887, 24880873, 24880878,
+    24880432, 24880439, 24880425, 24880430,
+    24880592, 24880599, 24880585, 24880590,
+    24885776, 24885783, 24885769, 24885774,
+    24886000, 24886007, 24885993, 24885998,
+    24885552, 24885559, 24885545, 24885550,
+    24885712, 24885719, 24885705, 24885710,
+    24429072, 24429079, 24429065, 24429070,
+    24429296, 24429303, 24429289, 24429294,
+    24428848, 24428855, 24428841, 24428846,
+    24429008, 24429015, 24429001, 24429006,
+    24436240, 24436247, 24436233, 24436238,
+    24436464, 24436471, 24436457, 24436462,
+    24436016, 24436023, 24436009, 24436014,
+    24436176, 24436183, 24436169, 24436174,
+    24421904, 24421911, 24421897, 24421902,
+    24422128, 24422135, 24422121, 24422126,
+    24421680, 24421687, 24421673, 24421678,
+    24421840, 24421847, 24421833, 24421838,
+    24427024, 24427031, 24427017, 24427022,
+    24427248, 24427255, 24427241, 24427246,
+    24426800, 24426807, 24426793, 24426798,
+    24426960, 24426967, 24426953, 24426958,
+    24592912, 24592919, 24592905, 24592910,
+    24593136, 24593143, 24593129, 24593134,
+    24592688, 24592695, 24592681, 24592686,
+    24592848, 24592855, 24592841, 24592846,
+    24600080, 24600087, 24600073, 24600078,
+    24600304, 24600311, 24600297, 24600302,
+    24599856, 24599863, 24599849, 24599854,
+    24600016, 24600023, 24600009, 24600014,
+    24585744, 24585751, 24585737, 24585742,
+    24585968, 24585975, 24585961, 24585966,
+    24585520, 24585527, 24585513, 24585518,
+    24585680, 24585687, 24585673, 24585678,
+    24590864, 24590871, 24590857, 24590862,
+    24591088, 24591095, 24591081, 24591086,
+    24590640, 24590647, 24590633, 24590638,
+    24590800, 24590807, 24590793, 24590798,
+     9978384,  9978391,  9978377,  9978382,
+     9978608,  9978615,  9978601,  9978606,
+     9978160,  9978167,  9978153,  9978158,
+     9978320,  9978327,  9978313,  9978318,
+     9985552,  9985559,  9985545,  9985550,
+     9985776,  9985783,  9985769,  9985774,
+     9985328,  9985335,  9985321,  9985326,
+     9985488,  9985495,  9985481,  9985486,
+     9971216,  9971223,  9971209,  9971214,
+     9971440,  9971447,  9971433,  9971438,
+     9970992,  9970999,  9970985,  9970990,
+     9971152,  9971159,  9971145,  9971150,
+     9976336,  9976343,  9976329,  9976334,
+     9976560,  9976567,  9976553,  9976558,
+     9976112,  9976119,  9976105,  9976110,
+     9976272,  9976279,  9976265,  9976270,
+    10207760, 10207767, 10207753, 10207758,
+    10207984, 10207991, 10207977, 10207982,
+    10207536, 10207543, 10207529, 10207534,
+    10207696, 10207703, 10207689, 10207694,
+    10214928, 10214935, 10214921, 10214926,
+    10215152, 10215159, 10215145, 10215150,
+    10214704, 10214711, 10214697, 10214702,
+    10214864, 10214871, 10214857, 10214862,
+    10200592, 10200599, 10200585, 10200590,
+    10200816, 10200823, 10200809, 10200814,
+    10200368, 10200375, 10200361, 10200366,
+    10200528, 10200535, 10200521, 10200526,
+    10205712, 10205719, 10205705, 10205710,
+    10205936, 10205943, 10205929, 10205934,
+    10205488, 10205495, 10205481, 10205486,
+    10205648, 10205655, 10205641, 10205646,
+     9749008,  9749015,  9749001,  9749006,
+     9749232,  9749239,  9749225,  9749230,
+     9748784,  9748791,  9748777,  9748782,
+     9748944,  9748951,  9748937,  9748942,
+     9756176,  9756183,  9756169,  9756174,
+     9756400,  9756407,  9756393,  9756398,
+     9755952,  9755959,  9755945,  9755950,
+     9756112,  9756119,  9756105,  9756110,
+     9741840,  9741847,  9741833,  9741838,
+     9742064,  9742071,  9742057,  9742062,
+     9741616,  9741623,  9741609,  9741614,
+     9741776,  9741783,  9741769,  9741774,
+     9746960,  9746967,  9746953,  9746958,
+     9747184,  9747191,  9747177,  9747182,
+     9746736,  9746743,  9746729,  9746734,
+     9746896,  9746903,  9746889,  9746894,
+     9912848,  9912855,  9912841,  9912846,
+     9913072,  9913079,  9913065,  9913070,
+     9912624,  9912631,  9912617,  9912622,
+     9912784,  9912791,  9912777,  9912782,
+     9920016,  9920023,  9920009,  9920014,
+     9920240,  9920247,  9920233,  9920238,
+     9919792,  9919799,  9919785,  9919790,
+     9919952,  9919959,  9919945,  9919950,
+     9905680,  9905687,  9905673,  9905678,
+     9905904,  9905911,  9905897,  9905902,
+     9905456,  9905463,  9905449,  9905454,
+     9905616,  9905623,  9905609,  9905614,
+     9910800,  9910807,  9910793,  9910798,
+     9911024,  9911031,  9911017,  9911022,
+     9910576,  9910583,  9910569,  9910574,
+     9910736,  9910743,  9910729,  9910734,
+    15221264, 15221271, 15221257, 15221262,
+    15221488, 15221495, 15221481, 15221486,
+    15221040, 15221047, 15221033, 15221038,
+    15221200, 15221207, 15221193, 15221198,
+    15228432, 15228439, 15228425, 15228430,
+    15228656, 15228663, 15228649, 15228654,
+    15228208, 15228215, 15228201, 15228206,
+    15228368, 15228375, 15228361, 15228366,
+    15214096, 15214103, 15214089, 15214094,
+    15214320, 15214327, 15214313, 15214318,
+    15213872, 15213879, 15213865, 15213870,
+    15214032, 15214039, 15214025, 15214030,
+    15219216, 15219223, 15219209, 15219214,
+    15219440, 15219447, 15219433, 15219438,
+    15218992, 15218999, 15218985, 15218990,
+    15219152, 15219159, 15219145, 15219150,
+    15450640, 15450647, 15450633, 15450638,
+    15450864, 15450871, 15450857, 15450862,
+    15450416, 15450423, 15450409, 15450414,
+    15450576, 15450583, 15450569, 15450574,
+    15457808, 15457815, 15457801, 15457806,
+    15458032, 15458039, 15458025, 15458030,
+    15457584, 15457591, 15457577, 15457582,
+    15457744, 15457751, 15457737, 15457742,
+    15443472, 15443479, 15443465, 15443470,
+    15443696, 15443703, 15443689, 15443694,
+    15443248, 15443255, 15443241, 15443246,
+    15443408, 15443415, 15443401, 15443406,
+    15448592, 15448599, 15448585, 15448590,
+    15448816, 15448823, 15448809, 15448814,
+    15448368, 15448375, 15448361, 15448366,
+    15448528, 15448535, 15448521, 15448526,
+    14991888, 14991895, 14991881, 14991886,
+    14992112, 14992119, 14992105, 14992110,
+    14991664, 14991671, 14991657, 14991662,
+    14991824, 14991831, 14991817, 14991822,
+    14999056, 14999063, 14999049, 14999054,
+    14999280, 14999287, 14999273, 14999278,
+    14998832, 14998839, 14998825, 14998830,
+    14998992, 14998999, 14998985, 14998990,
+    14984720, 14984727, 14984713, 14984718,
+    14984944, 14984951, 14984937, 14984942,
+    14984496, 14984503, 14984489, 14984494,
+    14984656, 14984663, 14984649, 14984654,
+    14989840, 14989847, 14989833, 14989838,
+    14990064, 14990071, 14990057, 14990062,
+    14989616, 14989623, 14989609, 14989614,
+    14989776, 14989783, 14989769, 14989774,
+    15155728, 15155735, 15155721, 15155726,
+    15155952, 15155959, 15155945, 15155950,
+    15155504, 15155511, 15155497, 15155502,
+    15155664, 15155671, 15155657, 15155662,
+    15162896, 15162903, 15162889, 15162894,
+    15163120, 15163127, 15163113, 15163118,
+    15162672, 15162679, 15162665, 15162670,
+    15162832, 15162839, 15162825, 15162830,
+    15148560, 15148567, 15148553, 15148558,
+    15148784, 15148791, 15148777, 15148782,
+    15148336, 15148343, 15148329, 15148334,
+    15148496, 15148503, 15148489, 15148494,
+    15153680, 15153687, 15153673, 15153678,
+    15153904, 15153911, 15153897, 15153902,
+    15153456, 15153463, 15153449, 15153454,
+    15153616, 15153623, 15153609, 15153614,
+];
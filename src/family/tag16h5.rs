@@ -0,0 +1,10 @@
+pub static CODES: [u64; 30] = [
+     8987, 11941, 13418, 17849,
+    31142, 32619, 45912, 59205,
+    65113,  5485, 14347, 61611,
+     3460, 18230, 35954, 44816,
+     2364, 37812, 42243, 18063,
+    57655, 22421, 57154,  7197,
+    59868, 29613, 44383, 54576,
+     1994, 44846,
+];
@@ -0,0 +1,11 @@
+pub static CODES: [u64; 35] = [
+    22399985, 31773110, 24841064, 32164301,
+    19797198,  3492027,  7821286, 17194411,
+    29008615, 14591624,  8509735,  5354049,
+    14403758, 31788569, 22550994, 23954050,
+    23322677, 10328961, 24374980, 11418121,
+     6257745, 27342844, 14456162, 25177669,
+    29578284,  8851364,  9520905, 23495920,
+    13169317,  8601275,  5861759, 20762704,
+    16386769, 28795824, 12161486,
+];
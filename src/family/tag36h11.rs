@@ -0,0 +1,149 @@
+pub static CODES: [u64; 587] = [
+    57401312644, 58383764297, 59366215950, 61331119256,
+    63296022562, 65260925868,  1453707397,  4401062356,
+     9313320621, 10295772274, 14225578886, 17172933845,
+    18155385498, 19137837151, 21102740457, 22085192110,
+    24050095416, 27979902028, 28962353681, 33874611946,
+    34857063599, 35839515252, 37804418558, 42716676823,
+    43699128476, 46646483435, 47628935088, 49593838394,
+    56470999965, 57453451618, 61383258230, 12312814554,
+    18207524472, 23119782737, 27049589349, 28032041002,
+    29014492655, 29996944308, 37856557532, 42768815797,
+    46698622409, 50628429021, 56523138939, 58488042245,
+    61435397204, 67330107122,  6470243610, 10400050222,
+    12364953528, 17277211793, 32013986588, 35943793200,
+    37908696506, 42820954771, 52645471301, 55592826260,
+     5539930931, 13399544155, 29118770603, 31083673909,
+    36978383827, 37960835480, 38943287133, 44837997051,
+    46802900357, 49750255316, 49802394290, 57662007514,
+     5644208879, 20380983674, 21363435327, 27258145245,
+    44942274999, 63608856406,  7661251159, 14538412730,
+    22398025954, 25345380913, 32222542484, 62678543727,
+    24415068234, 31292229805, 63713134354, 25449658861,
+    33309272085, 51975853492, 62782821675, 15677281305,
+    45150830895, 20641678544, 21624130197, 36360904992,
+    45202969869, 62887099623,  6939494376,  9886849335,
+    29535882395, 41325302231, 13868794921, 19763504839,
+    21728408145, 27623118063, 40447128526, 41429580179,
+    46341838444, 52236548362, 37551912541, 59165848907,
+     6165598619, 20902373414, 57253084575, 60200439534,
+    68060052758, 31761480571, 58287675202, 54410007564,
+    30883306866, 46602533314, 47584984967, 22197658911,
+     6530571437, 32126453389, 39986066613,  2705042773,
+    26283882445, 42985560546,  8651891665, 33213182990,
+    40194622509, 17650373464, 38281858177,  3948189296,
+    42315942737, 58035169185, 59278315708, 20032388562,
+    25979237454, 20188805484,  1730779973, 49870910970,
+    28309113578, 26500627194, 13780894679, 50131605840,
+    44445451818, 24848557732, 10216060885, 11198512538,
+    38707158822, 46827466916, 16423604647, 35142325028,
+     4738462759,  7685817718, 44036528879, 50965829424,
+     3860289054, 40419556111, 27908379492, 26047754134,
+    47870246396,  1851435627, 49061253945, 52991060557,
+    35515486699, 18865947572,  2424964341, 34176251081,
+    46948122570, 25386325178, 56981194996, 10962384227,
+     1294284619, 11744468837, 40496261644, 20177610775,
+     2084558082, 62118386863,  6275059564,  6483615460,
+    26445482364, 30792400768, 10265194003, 67247389877,
+    33896172649, 27175428000, 31209512560,  2770553597,
+    31261651534,  5770047530, 35147508025, 34582168164,
+    11524718232, 47414367480, 48657514003, 33146843451,
+     7759517395,  6107447933, 63976006365,  8445512910,
+    42005286034, 61045029112, 25059290769, 19425275721,
+    61983530644, 47768145589,  2635697378, 57452622903,
+      426476908, 64598668197,  5034090182, 26499798479,
+    46461665383, 55355869234, 28733585508, 20525377172,
+    21568156652, 57413855779, 42937775854, 42372435993,
+     7429477130, 53151831761, 40588516168,  7758688680,
+    23530054102, 67070760678, 35335851644, 38291395456,
+    47089510212, 36334681003, 58574285057, 11468744687,
+    56806742938, 37061620783, 25645362618, 66801048240,
+    52176740246,  1871338900, 61251927578, 49262140699,
+    32516512477, 24376820821, 12820523440, 62889796475,
+    47455831456,  7205063239,   994513621, 65114565936,
+    38031220297,  2731477469,  3052500166, 61554915139,
+    49877962104, 19915778186, 10664790370, 49501794577,
+    65229209878, 34937814410, 40536068190, 22234459601,
+    23718917432, 50966040131, 22968759519, 30756850207,
+    27164443998, 56862927190, 32987631380, 12207918598,
+    13250698078, 67971534508, 64927261739, 23046813478,
+    38409255961, 41120482609, 26540124736, 39328373931,
+    28389555385, 44951194270, 10312880398,  7269436344,
+    26385884955,  7603830891, 13026284187,   287168110,
+    41017553087, 37477285852, 30789296615, 42641221419,
+    40481134067, 26682860804, 29169153850, 43057504496,
+    62181313245, 35001878511, 45660618625, 41403777604,
+      349364074,  3056756151, 12617571955, 40776452486,
+     9809427497, 67469430033,  2980570329, 20394987645,
+    22789545878, 20423908486, 55193244006, 19750456106,
+    22551760137, 37417379439, 53715317598, 36236737884,
+    55335980074,  7166619232, 34436131349, 59054435641,
+    23216096652, 11658970556,  8716798594, 46620484266,
+    49698860873, 22006225252, 28218123296, 66097242409,
+    15500567922, 58566012020,  8807086684, 63977790318,
+    67517228838, 47985845576, 54401945234, 46860348851,
+    31330294737,  5229400751, 22001772673, 57565735938,
+    27180428516, 66912503845, 62329976841, 31850757465,
+    66468859060, 40113801627, 33117332828, 55946843302,
+    55945185872, 10674046871, 50568550834, 54780613019,
+    63208262956,  2466049242, 37009313441, 10043308596,
+    49011025447,  8311738452, 36030289058, 63790612644,
+    15550557981, 55068585413, 12244663330,  4862489725,
+    15001188525,  4730330358, 11970498313, 57179553760,
+     4492348023, 22505689327, 38860120742, 33989326453,
+    40698258385, 10721466601, 63077396057, 33462149821,
+     5520533750, 38960072634, 48247552136, 16819675941,
+    19470574762, 42975405753, 55088236134, 17182598141,
+    60599348316, 15542987630, 59699951138, 62673024488,
+    33398507628, 67990904945, 38582571288, 17369270711,
+    47111198426, 30300382497, 12713282963, 16989100245,
+     2618857401,  2037870252,  2226129687, 15726205198,
+    10944266389, 15523478533, 32807393912, 50322268718,
+    41475554668, 28089982957, 44557302587,  3281607545,
+    56188592113, 38250931033, 55092859057, 49186125715,
+    23534902339, 63390962295, 32306807219, 31575218266,
+    44208089961, 43362882375, 58820037795, 36371709477,
+    13411500472,  5204752972,  1977137594, 32984502953,
+    45158110801, 34100715445,  3503256489, 47326218638,
+    66132095224, 27344434842, 12073010867,  5581905445,
+    65632631643,  3961973387, 18449683548, 47559721156,
+    40164498818, 53549298266, 19497407586, 35325378674,
+    63495144910, 50306667721, 61648327423, 14377029940,
+    35793001718, 27741435618,  4664673183,  2223538542,
+    45887401030, 66699434191, 37512143607,  4327400291,
+    28201882487, 65965472985, 55470468086, 40081549062,
+    11906812512, 62042355015, 18346608169, 64036138305,
+    56632642930, 32443178963, 34915890934, 31223083687,
+    52865856710, 14625332685, 52020874438, 45086377277,
+    42231824779, 22378717616, 45075690142, 17761205065,
+    39357851814, 19571339212, 45509720380, 25425990526,
+     8187447223, 14083479811, 12599191336, 23931047094,
+      285981281,  8050083055, 18375482651, 68398671257,
+    39470446339, 34483786205, 14293264032, 11840597452,
+    52028357079, 18655102270, 57562297664, 18391098962,
+    55789070408, 39479755059, 16921383918, 37747421050,
+    45531438728,  3925520940, 21060187387, 63584486024,
+    46118859216, 52663060332, 34045177504, 61677388483,
+    57364197806, 10846878694,  5910056340, 25517457550,
+    46772013866, 42778087689, 37271836375, 13995057252,
+    12865051990, 51177733636,  1258462956, 29509907509,
+    14789960060, 22956996066, 12577438562,  2245444882,
+    57657560591, 11674975337, 44721593371, 64519763166,
+    20659025261, 23393601505, 30854914782,  7763994121,
+    19414656060,  9969487209, 14430208209, 44249069735,
+     3675975698, 61768025949,  1576026001, 40716361915,
+    13646331887, 48923374530, 18691574214, 28489821665,
+    23576061426, 24337422446,  3431308682, 61182758056,
+    22793281966, 18363218694, 31430488766, 15057209589,
+    16683733900, 45770064437, 59920337435,  4087591922,
+    48519091831, 46742923501, 34864967069, 43127841100,
+    38367565737, 19126814041, 62462892087, 46281919037,
+    42639532513, 25462922955,  1933639297, 21709440703,
+    21298892938, 54525655228, 17219513647, 15427824620,
+    22529935601, 33667726627, 39637835710, 37112719582,
+    12858744915, 45967164474,  5206104837, 19250736854,
+     5074169601, 22279632263, 15591759320, 17157469540,
+    63031446439,  2267780344, 58667694498, 41516404011,
+     8377134390,  6616417823, 11867490755, 13765362826,
+    34128921134,  9757026555, 62339828595,
+];
@@ -0,0 +1,18 @@
+pub static BITS: [(i32, i32); 21] = [
+    ( 1, -2), ( 2, -2), ( 3, -2), ( 1,  1), ( 2,  1), ( 6,  1), ( 6,  2), ( 6,  3),
+    ( 3,  1), ( 3,  2), ( 3,  6), ( 2,  6), ( 1,  6), ( 3,  3), ( 2,  3), (-2,  3),
+    (-2,  2), (-2,  1), ( 1,  3), ( 1,  2), ( 2,  2),
+];
+
+pub static CODES: [u64; 38] = [
+    1407075,  294440, 1278957,   38204,
+     894603, 1879120,  766485, 1494766,
+     382131, 2094929,  982294,  469822,
+    1454339, 1076822, 1676985,  786996,
+     402642, 2075013,  214181,  484091,
+    1698091,  276309,  107764, 1112792,
+       6994, 1566111,  946032,  971898,
+     825649,   85490,  657137,  938049,
+    1240067, 1471054,  717221,  567468,
+      88889, 2015715,
+];
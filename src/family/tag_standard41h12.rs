@@ -0,0 +1,540 @@
+pub static BITS: [(i32, i32); 41] = [
+    (-2, -2), (-1, -2), ( 0, -2), ( 1, -2), ( 2, -2), ( 3, -2), ( 4, -2), ( 5, -2),
+    ( 1,  1), ( 2,  1), ( 6, -2), ( 6, -1), ( 6,  0), ( 6,  1), ( 6,  2), ( 6,  3),
+    ( 6,  4), ( 6,  5), ( 3,  1), ( 3,  2), ( 6,  6), ( 5,  6), ( 4,  6), ( 3,  6),
+    ( 2,  6), ( 1,  6), ( 0,  6), (-1,  6), ( 3,  3), ( 2,  3), (-2,  6), (-2,  5),
+    (-2,  4), (-2,  3), (-2,  2), (-2,  1), (-2,  0), (-2, -1), ( 1,  3), ( 1,  2),
+    ( 2,  2),
+];
+
+pub static CODES: [u64; 2115] = [
+    1913582300432, 1914564752085, 1915547203738, 1916529655391,
+    1917512107044, 1918494558697, 1919477010350, 1920459462003,
+    1921441913656, 1922424365309, 1923406816962, 1924389268615,
+    1925371720268, 1927336623574, 1928319075227, 1929301526880,
+    1930283978533, 1933231333492, 1934213785145, 1935196236798,
+    1937161140104, 1939126043410, 1940108495063, 1941090946716,
+    1942073398369, 1944038301675, 1945020753328, 1946003204981,
+    1946985656634, 1950915463246, 1951897914899, 1953862818205,
+    1954845269858, 1955827721511, 1957792624817, 1958775076470,
+    1959757528123, 1960739979776, 1961722431429, 1964669786388,
+    1966634689694, 1967617141347, 1968599593000, 1971546947959,
+    1972529399612, 1974494302918, 1977441657877, 1978424109530,
+    1979406561183, 1980389012836, 1982353916142, 1983336367795,
+    1984318819448, 1986283722754, 1987266174407, 1988248626060,
+    1989231077713, 1991195981019, 1995125787631, 1998073142590,
+    2000038045896, 2002985400855, 2003967852508, 2005932755814,
+    2009862562426, 2010845014079, 2011827465732, 2012809917385,
+    2014774820691, 2015757272344, 2017722175650, 2018704627303,
+    2020669530609, 2022634433915, 2024599337221, 2026564240527,
+    2027546692180, 2028529143833, 2029511595486, 2030494047139,
+    2031476498792, 2035406305404, 2037371208710, 2038353660363,
+    2040318563669, 2041301015322, 2043265918628, 2044248370281,
+    2047195725240, 2048178176893, 2052107983505, 2058985145076,
+    2059967596729, 2061932500035, 2070774564912, 2071757016565,
+    2076669274830, 2077651726483, 2081581533095, 2082563984748,
+    2083546436401, 2085511339707, 2087476243013, 2089441146319,
+    2090423597972, 2093370952931, 2100248114502, 2104177921114,
+    2105160372767, 2108107727726, 2111055082685, 2120879599215,
+    2124809405827, 2125791857480, 2126774309133, 2131686567398,
+    2134633922357, 2137581277316, 2139546180622, 2144458438887,
+    2146423342193, 2148388245499, 2153300503764, 2155265407070,
+    2158212762029, 2164107471947, 2167054826906, 2168037278559,
+    2169019730212, 2171967085171, 2175896891783, 2186703859966,
+    2187686311619, 2190633666578,    1434927556,    3399830862,
+       5364734168,   12241895739,   14206799045,   15189250698,
+      18136605657,   20101508963,   21083960616,   22066412269,
+      35820735411,   41715445329,   46627703594,   49575058553,
+      61364478389,   66276736654,   72171446572,   74136349878,
+      82978414755,   86908221367,   99680092856,  101644996162,
+     103609899468,  106557254427,  108522157733,  114416867651,
+     118346674263,  119329125916,  130136094099,  146837772200,
+     150767578812,  153714933771,  165504353607,  166486805260,
+     168451708566,  171399063525,  176311321790,  186135838320,
+     187118289973,  189083193279,  192030548238,  193995451544,
+     197925258156,  198907709809,  202837516421,  214626936257,
+     216591839563,  230346162705,  233293517664,  234275969317,
+     237223324276,  239188227582,  253925002377,  254907454030,
+     256872357336,  257854808989,  263749518907,  266696873866,
+     267679325519,  284381003620,  288310810232,  289293261885,
+     290275713538,  293223068497,  297152875109,  299117778415,
+     320731714781,  336450941229,  345293006106,  356099974289,
+     358064877595,  360029780901,  362977135860,  370836749084,
+     394415588756,  395398040409,  403257653633,  408169911898,
+     413082170163,  432731203223,  434696106529,  437643461488,
+     439608364794,  441573268100,  445503074712,  447467978018,
+     452380236283,  454345139589,  455327591242,  460239849507,
+     466134559425,  470064366037,  478906430914,  479888882567,
+     488730947444,  495608109015,  498555463974,  504450173892,
+     540800885053,  544730691665,  560449918113,  562414821419,
+     563397273072,  564379724725,  566344628031,  585993661091,
+     592870822662,  593853274315,  604660242498,  606625145804,
+     609572500763,  616449662334,  620379468946,  624309275558,
+     631186437129,  649853018536,  659677535066,  662624890025,
+     679326568126,  691115987962,  701922956145,  724519344164,
+     725501795817,  730414054082,  740238570612,  754975345407,
+     765782313590,  768729668549,  772659475161,  778554185079,
+     799185669792,  824729412770,  841431090871,  847325800789,
+     850273155748,  865009930543,  883676511950,  885641415256,
+     887606318562,  911185158234,  944588514436,  952448127660,
+     993711097086, 1012377678493, 1029079356594, 1034974066512,
+    1044798583042, 1060517809490, 1074272132632, 1078201939244,
+    1082131745856, 1094903617345, 1113570198752, 1121429811976,
+    1135184135118, 1139113941730, 1142061296689, 1144026199995,
+    1146973554954, 1151885813219, 1156798071484, 1159745426443,
+    1179394459503, 1183324266115, 1211815364052, 1216727622317,
+    1218692525623, 1227534590500, 1232446848765, 1234411752071,
+    1236376655377, 1237359107030, 1238341558683, 1282551883068,
+    1307113174393, 1327744659106, 1377849693409, 1386691758286,
+    1418130211182, 1421077566141, 1434831889283, 1454480922343,
+    1457428277302, 1460375632261, 1463322987220, 1464305438873,
+    1476094858709, 1477077310362, 1490831633504, 1524234989706,
+    1553708539296, 1575322475662, 1577287378968, 1585146992192,
+    1660795769473, 1696164028981, 1699111383940, 1726620030224,
+    1743321708325, 1763953193038, 1768865451303, 1774760161221,
+    1798339000893, 1816023130647, 1846479131890, 1872022874868,
+    1900513972805, 1903461327764, 1929987522395, 1953566362067,
+    1978127653392, 2028232687695, 2066548302162, 2079320173651,
+    2091109593487, 2097986755058, 2161846112503, 2166758370768,
+    2168723274074,    6050729683,   43383892497,   48296150762,
+      61068022251,   70892538781,  105278346636,  134751896226,
+     143593961103,  244786481362,  273277579299,  283102095829,
+     329277323520,  334189581785,  355803518151,  371522744599,
+     381347261129,  406891004107,  422610230555,  483522233041,
+     496294104530,  527732557426,  574890236770,  594539269830,
+     596504173136,  599451528095,  613205851237,  645626755786,
+     690819531824,  711451016537,  726187791332,  775310373982,
+     792012052083,  852924054569,  868643281017, 1034677610374,
+    1048431933516, 1092642257901, 1095589612860, 1105414129390,
+    1167308583529, 1230185489321, 1333342912886, 1372640979006,
+    1384430398842, 1412921496779, 1419798658350, 1428640723227,
+    1430605626533, 1476780854224, 1507236855467, 1566183954647,
+    1600569762502, 1604499569114, 1613341633991, 1651657248458,
+    1653622151764, 1720428864168, 1778393511695, 1839305514181,
+    1874673773689, 1893340355096, 1906112226585, 2129128751816,
+      24420854952,  165893892984,  378103450032,  435085645906,
+     533330811206,  606032233528,  708207205440,  721961528582,
+     811364629005,  846732888513,  914522052570, 1007854959605,
+    1167012127391, 1210240000123, 1338941166666, 1382169039398,
+    1496133431146, 1534449045613, 1561957691897, 1567852401815,
+    1680834341910, 1722097311336, 1756483119191, 1812482863412,
+    1842938864655, 1851780929532, 1864552801021, 1874377317551,
+    1891078995652, 1896973705570, 1899921060529, 1985394354340,
+    1998166225829, 2039429195255, 2047288808479, 2062025583274,
+    2065955389886, 2076762358069, 2102306101047, 2191709201470,
+      93878466177,  287421441818,  293316151736,  325737056285,
+     548753581516,  657805714999,  667630231529,  786506881542,
+     834647012539,  986927018754, 1068470505953, 1091066893972,
+    1138224573316, 1139207024969, 1150013993152, 1163768316294,
+    1216820705556, 1264960836553, 1285592321266, 1385802389872,
+    1509591298150, 1790572470908, 2017518802751, 2052887062259,
+      71968073673,  118143301364,  145651947648,  161371174096,
+     252739177825,  270423307579,  657509258861,  699754679940,
+     777368360527,  786210425404,  791122683669,  875613525827,
+     888385397316,  955192109720, 1030840887001, 1260734573803,
+    1416944386630, 1542698198214, 1917012278007, 2115467511913,
+    2153783126380,  102127618778,  297635497725,  539318604363,
+     803598099020, 1129772047816, 1268297730889, 1276157344113,
+    1418612833798, 1443174125123, 1645559165641, 2146609508671,
+    2186890026444,  123445099006,  137199422148,  193199166369,
+     206953489511,  336637107707,  687372347828,  764986028415,
+     950669390832, 1263089016486, 1303369534259, 1373123601622,
+    1573543738834, 1583368255364, 1739578068191, 1830946071920,
+     378586072648,  429673558604,  543637950352,  597672791267,
+     845250607823,  855075124353,  912057320227, 1202863009515,
+    1433739147970, 1528054506658, 1643983801712,  197518512358,
+     310500452453,  522710009501,  851831313256, 1024742804184,
+    1234005006273, 1469793402993, 1644669797227, 1809721674931,
+    2168316528276,  221783347545,  377993160372,  427115743022,
+     827956017446, 1470479398508, 1501917851404, 1704302891922,
+    1867389866320,  604643036077, 1333622162603, 1525200234938,
+    1894602056466, 1981057801930, 2000706834990, 2135302711451,
+     187787079067,  462873541907, 1183993055209, 1195782475045,
+    1312694221752, 1583850877980, 1990585862322, 2077041607786,
+      58789456386,  561804702722,  731768838691,  782856324647,
+     783838776300,  884048844906, 1277029506106, 1373309768100,
+    1661168102429, 1780044752442, 1806570947073, 1851763723111,
+    1878289917742, 1902851209067, 2010920890897,   62422806860,
+     171474940343,  398421272186,  807121159834, 1076312912756,
+    1176522981362, 1563608932644, 1709994228941,  102406868495,
+     492440174736,  536650499121,  727246119803,  963034516523,
+    1107454909514, 1391383437231, 1406120212026, 1508295183938,
+    1526961765345, 1652715576929, 1867872488936, 1875732102160,
+     142390930130,  488213911986,  490178815292,  608073013652,
+     848773668637,  926387349224, 1640629700955, 1930452938590,
+    1959926488180, 2158381722086, 1113739158809, 1286650649737,
+    1492965496867, 1826016607234,   91692983551,  459129901773,
+     914005017112,  977864374557, 1495616395688, 1729439889102,
+    1904316283336,  709358617150,  738832166740,  952024175441,
+    1667248978825,  134327944007,  811237132924, 1151165404862,
+    1423304512743, 2159160800840,   65259872159,  189048780437,
+    1049676428465, 1095851656156, 1846444719048, 1959426659143,
+     180892711075,  269313359845, 1089660490100, 1667342062064,
+    1952253041434,  351542842559, 1708308575352, 2162201239038,
+     545771813715,  730472724479,  780577758782, 1496785013819,
+    1791520509719, 1991940646931, 1012139892752, 1657610628773,
+       2865588983,  326092182820,  364407797287, 1604261783373,
+     474145926285, 1191335632975, 1248317828849, 1994981085129,
+    2146278639691,  545568440816,  662480187523, 1223460081386,
+    1586967192996, 1600721516138,   92361772645,  742744766931,
+    1619091641407, 1812634617048, 1998317979465,  117609059485,
+    1569672602619, 1031975092290, 1363061299351,  469716290636,
+    1376519166355, 1534693882488, 1999393514357,   56790140238,
+    1758396403234, 1768906915279, 1999783053734, 2022379441753,
+     517949504872, 1122157271467, 1482717028118, 1738154457898,
+     242566585894,  911616161587, 1231895400465, 1389087664945,
+    1547262381078, 2027681239395,  651952469057, 1956648264241,
+    2100086205579,  508218071581, 1092776805116, 1777545607257,
+     606166780743, 1125883705180, 1914792382539,  343555733254,
+    1908601216483,  335399663892, 1476026033025,  730048772260,
+    1125680332281, 1134522397158, 1341819695941, 1044526384459,
+    1969995841585,   13638144324,   64725630280,  641424750591,
+    1128720770479,  547795387418,  706952555204, 1450372000387,
+     228888139570,  720113966070,  237433748309,  398555819401,
+    1347604116199,   37403150474,  799785633202, 1631625727155,
+    2162835615290,  392161280446,  183585073872, 1145405242159,
+     520269534713, 1503703639366, 1738509584433, 2067630888188,
+    2071560694800,  723340570746, 1581020863815, 1591531375860,
+    1841074095722, 1133412449424, 1377060459368, 1745479829243,
+     284277765094, 1680341564007,  507980285840, 1408888451641,
+    1514993230165,  193299300742,  670770804100, 1454767223194,
+    2165079768313,   36793031777,  139950455342,  313844397923,
+     687862021578, 2021048914699,  309025222897, 1610477206984,
+    1824651667338, 1091149821924,  464049211172, 1885360296925,
+    1766187190774, 2029484233778, 2169974820157,  136985893962,
+     475931714247, 1783574864390,   20760142770, 1129948059007,
+    2096384029421, 1164130493963, 1364550631175, 1941249751486,
+    1276815977920, 1091818611018, 1694061474307,  644210196627,
+    1775791127984,   22504466756, 1714286213222, 2178003393438,
+     616294804545,  719452228110,  676910350893,  824278098843,
+     213879166192, 1072541910914, 1958713301920,   27899347637,
+     904635761490, 1326107520627, 1356563521870,  479920191256,
+     987847695857, 1702776043103, 1165078532774, 1526213995280,
+    2142600721088,  189190378786, 1760816567448, 1547328102609,
+     505632894291, 2057906506031,  111373325300,  371130101069,
+     432728099070, 1916916090615,  817256234770, 1125746053812,
+     758995131105, 1405448318779,   94561357539, 1925851238731,
+    1994622854441,  596297696084,  299393923979, 1222305565523,
+     318153588625, 1119537681335,  317560676349,   74302205782,
+    1115404501824, 1506809799095, 1441078621583, 2091461615869,
+      71244561163, 1419854224594, 1314435441585,  207991507408,
+     911223518057,   93323913724, 1718095574887, 1671030978782,
+    1884222987483, 2160291901976, 1246018952410, 2052501469863,
+     232797635608, 2048461373591, 1026415198333,  514261430982,
+     830314407110,  167252624574,  762618326292,  401465657365,
+     186994740873,   57997118192, 1524983602599, 1701824900139,
+    1025991246114, 2105705612761,  892767360683,  621314248317,
+     862700898817, 1109296263720, 1936224099408, 1191322373535,
+     946395455800, 2070709686209,  598497280978, 1398898922035,
+    1442219878006,  675407759629,  456024584872,  267783406873,
+     318277980553,  423789846801, 1770527690165, 1695361535500,
+    2033028447994,  703567988586, 2099038875223, 1617044652977,
+    2114647812011,  836181755320,  646958125668, 1278174709510,
+     557444735585,  623658535713, 1622811866814, 1255078492454,
+     863173366146,  874666329844,  100587510519, 1170570443875,
+    1683799746118, 2078745310624, 1539768892504,  168359468155,
+     798297144206, 1300719478266,  340957296524, 1595048228368,
+    1435391231545,  689896593396, 1607303064399, 2046458953290,
+    1626655641321, 2107464039015, 1395872586105, 2048220483697,
+    2137623584120,  255339300211,  398777241549, 1616424378993,
+     614416776172, 1070663882541,  998055543458, 2189769398547,
+     456317936857, 2149082134976,  133480882397,  627840230334,
+    1128576910805,  522497324143,   25766327102,  774191113789,
+    1009090142095,  455952655035, 1599509172706, 1020827942668,
+     212752854865,  564656713117,  560116787808,  939622375583,
+    1890821742165, 1089530732694, 1117428918355,  238820684435,
+     596433086127,  157073824337, 1274476480333, 1578722830204,
+    1220517516236, 1169912652896, 2048893219772,  576884187440,
+    1699326597782,  175044254942, 1266937580802, 1694693589234,
+     236625046522,  341340627595,  363047647200, 2051458086488,
+    2151761238333, 1027633174402, 1464231247711, 1672104252349,
+     574409299810, 1527090947082,  482931006421, 1699299236074,
+    1799602387919, 1708217177769,   40200437453,  266054027983,
+     528368619334,  492593614028, 1068496449164,  692293342883,
+    1844116219576,  119506822763,  878738577633, 1482742971329,
+    1858039502775,  927064875108, 1403740093291, 1450304860359,
+     353092530436, 1513257642969,  791841673529, 1896506870878,
+    2104490165176,   84459276948, 1980666844056, 1316622609867,
+     236111958045, 2195120554127, 1921406082317, 1455910165273,
+    1073422809697,  741226654902,   49360111870, 1245393312948,
+    1960025204056,  406176228387, 1754082689882,   87548230256,
+    1723905938356, 1003158758010, 1245527860163, 1112786597348,
+     659766095655, 1919048535481, 1278862390681,  787026445484,
+    1442804320651,  219007481127, 1181013815892, 1935815935113,
+     864054264929, 2110785412586, 1403792555382, 1268993306022,
+    1771805179459,  534991631571,   94353461990,  175304036913,
+     500385244396,  686551229429, 1484301971665,  529062508811,
+    2195079932979,  134844403796,  742854480922,  590760641185,
+     634378053294, 1706274270693, 1232232744910, 1139086004353,
+    1970926098306, 1687480193205,  880590929954, 2060684325264,
+     784072882219, 1574336344187, 1633562693084,  172436505753,
+    1128437840940,  830255161044, 1490166215722, 1339647739914,
+    1668938003726, 1570785921665,   99742134565, 1331422844868,
+     772873270506,  177990191404, 1412228717289,  442531729357,
+    1070656255738, 1242864544730,  551294457836, 1939498643525,
+     420907637704, 1420585055397,   26748203086, 1255260979110,
+    1841843286348, 1046726236512, 1449514207821, 1547149254424,
+     526771526334, 2159995712997,  636399365672,  298715246757,
+    1387930312088,  977620647669, 2130594936072, 1538362755791,
+     331005551072,  627392287719,  821883302171, 1821577926285,
+    1214126348593, 1688505794490, 2020963992581,  719605091733,
+    1163376782751,  203673271406, 1400299384760,  423242612641,
+    1937590149291, 1712108891717,  110585201246,  906522793812,
+    1585066017055, 1693990654457,  956634879249,  205083622259,
+     652581746990, 1005247477575, 1024438145574, 1784135316639,
+    2042595978196, 1588558612008,  875002255792,  918643925456,
+    1339022943280,  458915222249, 1303416898031,  385365895489,
+     768301460839,   82498587782, 1830745494664,  796093303821,
+    2012351243814, 1195806424090, 1616185441914,   69086131735,
+     407142583606,  555661743266, 2149884319947, 1168980669168,
+    1166429904720,  941541559422, 2004113089328,  384022951823,
+    1542019788151,  312083401834, 1427393658443,  480503482563,
+    1902500563831, 1305059669147, 1114112868911,  505085927290,
+    2124166251434, 1522406010761,  239603401660,  883591856991,
+     356353239444, 2129878741855, 1486431579537,  679583780262,
+     110199876009,  648769548331,  905468679481, 1879747056698,
+    1797669327620,  493963035223,  578061233851,  625639761261,
+     818089995589,  133269574185,  516815258232,  908837725334,
+    1739550665132, 1931280491103, 1758910293188, 1668507534691,
+     321276913424,   64491750169, 1433991837456,  207737316723,
+    1493994160954, 1499075379276,  738061783425,  615720742995,
+     875663685242, 1150664115977, 1325951202990, 1721827599886,
+     257106421904, 1177746548717,  137092462449, 1944810555103,
+     957336354178, 1679776239247, 1088202692773, 1300309011295,
+     460998843495,  850401720465,  496988219815,  441054197125,
+    1100124398499,   95448690436,  538220723380, 2068549529774,
+    1440970243387, 1714150473318,  904327957376, 1601461885208,
+     890411725311,  862996162266,  919985409274,  215760791685,
+    1272910079002, 2159564092624, 1128944946919,  550305180857,
+    1851864350451,   32798839429, 1008059668299, 1583555757637,
+     387850321386,  190106183378, 1774190580970, 1187411951967,
+    1502617023657,  619096531472,  633833306267, 1673329772519,
+    1485440616902, 1570066006275, 1719574668722, 1733046637994,
+    2172885418247,  130892518252,  728595456232,  484633783729,
+     807850222279, 1197662949200,  928730135421,  677798218108,
+    1800196060321, 1186060538670, 1841990167473,  829037945101,
+     538925302462,  388279330573, 1491631207289, 1047087488651,
+    1585953617111,  769484674205, 1253612759814,  862528176236,
+    1767052486090,  433576187965,  946405795544, 2058930606117,
+     485243326757,  438489289058, 2036310803371, 2123508110594,
+     217372788430, 1477031508193,  878015249580,  156624380523,
+    1342333236034,  538733194837,  324727694540,  690613506388,
+    1284183264857,    5993353730, 1416104827770,  330707018066,
+     203636781696,  750893661106, 2048498766533, 2015957417037,
+    2003740940829,  728601623187, 1564255025786,  975163418076,
+     993637624699,  931674344876, 1764342875521, 1948192777694,
+     406733185271,  914620068724,  282403826808, 1824457174335,
+     115625674367, 1019750289557,   57154989497,  515829311214,
+     299456108794,  257731670154, 2065873715027, 2090779977600,
+    1118274128905,  565939978154,  149109121527, 1048762637092,
+    1906908346356,  884824654103,  441181301994,  357755839441,
+     239530772101, 1020169986285, 1506652514577, 1255610307604,
+     854363287382,  678376945414, 2086665114497,  443792422495,
+     544948268587,  359702960657, 1922580167681, 1196596911224,
+    1178843955786, 1274485318878, 1408360211313,  293350357823,
+     400213904527, 1437775933334,  180423983972, 1685864577042,
+    1547321687548, 1871127934221, 2195165758329, 1153401724327,
+    1016203464155,   77152590925,  744423429790, 1861168294807,
+    1668080580074,  468335047551,  789559221087, 1528922206405,
+    1273398744520, 1060306870192, 1686777051673, 1380335063889,
+    1677983501906,   40144355944,  550908925844,  485620592628,
+    2183556830983, 1659945355604, 2197241752772, 1878242264513,
+     470447716161, 1963469302952,  286803715371, 1935110490905,
+    1878907373785, 1324160952782,  213518960391, 1221021578466,
+    1758175534457, 1207872008543, 1646601683890,  657176681927,
+    1153759872313,   41435330486,  860968969145, 1958754743598,
+      48883675262, 2082913723507, 1381612203322,  657866048754,
+    1614027031539,   54849739348, 1285789730720, 1200582427186,
+     822441203638,  328233609337,  239015256895, 1167453222382,
+    1229829723468, 1495026791075, 1359176264378,  868185119466,
+    1295927868458, 1421153070800,  141220521343, 1223596784926,
+    1165163617051, 1786052751173,  338861687984,  415327903842,
+    1960317608213,   35599475422, 1194511623375,  583396229348,
+    1927043700907, 1335597650514, 1480704039020, 1199568008473,
+     388462904918,  923045785887, 1686680657526, 1016750450209,
+    1181631106531, 2054662240073, 1459647985068,  412898344467,
+     234323721056, 1324276401165,  749880947102,  933675283031,
+     652803824264, 1971981402081, 1720684202946,  379553350152,
+    1916823621151,  374106274339, 1185180603523, 1067165960216,
+     941967811072, 1564608320314, 1478862827920, 2183723507417,
+    1847915155550, 2051418037764, 1434625408986, 2185991342326,
+     735367197409,  503167583034,  319508637148, 1928181112104,
+     406821290999, 2114063859088, 2194540548185, 1580777359490,
+      55255311510,  109872909414, 1540191915948,  633127839761,
+     603516638803, 1307033531806, 2084560377955, 1463818474819,
+    2085890329340,  567457552620, 1406257736096, 1130411086579,
+     580962516237,  850823058253, 1014492789640,  993335799838,
+      29668911867,  969860465851,  435991209264, 1388184025614,
+    1566475719486, 1892853308340,   19952382321,   73006457239,
+    1174123184881, 2022794138672, 1870254309135,  162397634017,
+     877972198458,  505188338796, 1410395540012, 1886773726388,
+    1293937902875,  870132854457,  331625799513, 2055291947324,
+     283647844598,  930174647765,  425178401689,  455741855598,
+     792385695291,  856683950051,  461489868848, 1669509921514,
+     148827359134,   96352361383, 1651939719891, 1712900237487,
+    1847161565146,  868344948276, 1314516492934,  916640204221,
+     461432225736,  757547606628, 1259104829829,   73201872527,
+    2100243893872, 1502669561960, 1117637917401, 1493208333086,
+    1665369792624,  551001633679,  402607708775,  760409031237,
+     346588989775,  127443025090, 1604732968821, 1296536501764,
+    1135528934472,  127927024852, 1227278850775, 1823128017937,
+    1647114314261, 1306624973054, 1203057966697, 1207026975960,
+    1480108448664, 2103192069361,  554035615465,  622044783173,
+     131794891510,  322070065658, 1399422362819,  734866725809,
+    2033348783038, 1385242093292, 1516082755835, 1305078182575,
+    1787146020314, 1318926698943, 1645797332859,  698126351218,
+    1711003231090, 2059198704878, 1290031568149,  991091805729,
+     674937543890, 1453590125697, 1266490738439, 1090836108279,
+    1574214162498, 2004838812077, 1837495109068, 1730649611613,
+    1585864779628, 1161089346308, 1336701936823,  510069138776,
+    2125551902434,  433049747611, 1499635964896,  850897118872,
+    1653025301825, 1444752869682,  959632135782, 1271021061832,
+    1766607122628, 1204709881623,  457599258397, 1207996842352,
+     704938137874, 2161020048209,  212538862083, 1841939009144,
+     274110608363, 1089871826683,  202169064208,  247881404188,
+    1553359649438, 1079002466930,  169144475076,  921814416590,
+     373470428504,  848863634743, 1894139422934,  625890484952,
+    1575868195643,  720421900310,  992769438058, 1658525269047,
+     943399181539, 2194186747710, 1502704378577,  898003217059,
+    1792659552241,  322312183102,  478087312754, 1325244677166,
+     678406739924, 1759194380138, 1402292498675, 2111611861185,
+    1163557281314, 1132770287038, 1379187070915,  902591243968,
+    1047176341525,  381764330446, 1356534025718, 1277992842702,
+    1219393510413,  778378711034,  445324496514, 1143565267927,
+    1058768924331,   32072534104, 2060732801851,  225826468096,
+    1256406102640, 1013926402317, 1905893478855,  435596043323,
+     333856288904, 1351724942330, 2167412236647,    2588313962,
+    1079635685354, 1174334333762, 1666078038548, 1996246096990,
+    1500111075027, 1062483720611, 1290216408150, 1597153702321,
+      33739642232, 2146652082217,  514757846939,  114990537572,
+      20509281629, 1471032942312, 1677309121109,  512248874030,
+    1316167326244,  474758118188,  464353373153, 1032740065114,
+     183221391342,   15218437506, 1004143877591,  623548302795,
+    1258918643881,  965142862331,  964466137924, 1397805146530,
+    1492443089024,  207627328666, 2188555772817,  691324162816,
+     920556378817,  120528756372,  796473768693, 1222497252731,
+    1152742301189, 1831556838836, 1420729521939, 1261780516861,
+     728020233192, 1184428097146,  905065355247, 1515470763363,
+    1080400381701,  485999492652, 1280744886956,  690119131187,
+     525343090537,   51796562631,   36787856412, 1147729943412,
+     128756316518, 1740211133357,  676496311511,  324962318135,
+    2144148375859,  469557055714,  981969412347, 1454452491165,
+    2091409194295,  626703495727,  174253025901, 2114461870507,
+     728161882210,  520638638629,  363778620275, 1433504484601,
+    1424270886181,  787992243253,  650493847121, 1427368534928,
+     374102984895,  211168565610, 1456782056028,  961742036703,
+     646418205722,  837770085153,  131117018176,   71653726366,
+    1878176949168, 1122067984779, 1237113811215, 2155509743654,
+    2193701767670, 1848876716881, 1513263226931, 1417755444173,
+     185898414169, 1046312225501, 1670650680891,  402879000047,
+    2166403933386,  490423333088, 1956149595026, 1430889941702,
+    2195877765943, 2122762339934, 1494105257330,  740172071896,
+    1913334918014, 1363369579373,  636866943431, 1748494717436,
+    1993392113893, 2022998442340,  881738539783,  261065729490,
+     277605190158,  176086590728, 1166044909040,  648708205093,
+     319728331038,  854730269417,   52396410889,  574547251947,
+     904642022459, 1891652142984, 2121232043174,  276824660874,
+     883369231168,  645495094983,  359782277258,  489421778941,
+    1959707866497, 2170501726267,  122513940630,  294447111343,
+    1638888832560, 1421934250297, 1403289665120, 1344722484348,
+    1922725138919, 1728618565525,  143542938139, 1617204321205,
+     749479733763,  560907111115, 1159621548112,  872254573189,
+    1107006472264,  572790886336, 1668886872517, 1143416957319,
+     317007534235, 1555457027913,  584022108758,  424257000898,
+     918093022369,  421059319533,  350274819725,  262158171194,
+     364821725922, 1466122584525,  677477429363, 2115588909093,
+       4607019976,  962667861960, 1417181330703, 1885817632616,
+    1802547253660, 2069503590349,   50572494568, 1753157812362,
+    2104502512809,   82343269802,  716660873703, 1088114842384,
+    1339527305445,  646502175516,  722054189736, 1066792753559,
+    1858960699131,    3198373415,  942315152633, 1000969742656,
+      70558194232,  199794280078, 1047513203481, 2035009336987,
+     121093951749,  624134215766, 1943927522619, 2112847266981,
+    2115381626485, 1259477252303, 1770218257880, 2090089825430,
+     124408588769, 1243042518380,  471460541564, 1559937988872,
+     531646394811, 1288438476370,  412990549926, 1278809006214,
+    1764670210939,  615092206044, 1319387786589, 1545103375890,
+    1655688216637, 1196696552539, 1451524992662,  229239357139,
+    1589582851864,  247168218768, 1717418926601,  668973661581,
+    1503331051723, 2126444521261, 1030442499253,  573780689475,
+    2075904406081, 2132135336525,  443775610023, 1773907816327,
+     544630839751,  737352859152,  255478073621,  795120237086,
+     514386776303,  861803352549, 1357516969830, 1912961144589,
+     918865120871,  395491345286, 1045490184726,  494819917142,
+      82812386032, 1541073134879, 1202004093057, 1278299217659,
+     717243590084,  612603310160,  227519527828, 2007666184926,
+    1710922839598, 1150404904671, 1431089579940, 1892021457226,
+     250254250712, 1812762960271, 2070562596153, 1253315929341,
+    1621627722497,  454940533577,  637105990658,  990607186771,
+     541910445472, 2153539381091, 1878594783759,  141215878537,
+     621762188339, 1428050521299,  946206344735, 1958832793201,
+     659399243147, 1091742206607,  414429127939, 1409929389469,
+     984557507157, 1601380888008,  537840821015, 1926715172944,
+    1177712069216, 2065172974916,  440126685196,  287220626009,
+    1764087819528, 1420992949106,  370317194278, 1735833756524,
+    1803990768993,  753900550989, 1539310794003, 2139879717348,
+    2097884024708, 1581069823452, 1697093616997, 2052044401475,
+     149687803221, 1036184490974, 1022550402779, 1727963783669,
+      48581973021,  836337772332, 1781430481466, 2148788456823,
+    1105270169356,  860487337103, 2138167171469, 1283718908958,
+    1573142490035,  932125809377, 1024503085659, 1268420434894,
+     519804481920,  438939916804,  471742628927,  461802514418,
+    1713838426837, 1103229711882,  131204402445, 2099932250332,
+    1561891676974,  823520516172, 1648715070168,  513248461275,
+    1355508076710,  217934416036, 1120860392360, 1169008375968,
+    1619296647352,  472691221852,  653062147691, 1375287270534,
+    1760380298431, 1342898721012, 1068399594970, 1278512802405,
+     509652804929, 1319299265084, 2042878835238, 1951754310291,
+    1641267662234,  659119233122, 1723274523204,  767630200473,
+    1515347447031,   63242906928,  198633357365, 1998647123902,
+    1437003411966, 1317896558419, 1422967161027,  134011325693,
+     492461282344, 1448534623580,  195834892671, 1545363795238,
+    1903677382402, 1395762685208,  579068026140, 1289810505559,
+     519330607641,  456708843190,  470218491616, 1105635226348,
+     283826940017, 1471851220017,  379833804249,  206715063163,
+    1977622915277,  466635379180,  581372122866, 1585036967985,
+      72240120954, 1919882173369,  830962829176, 1868961077218,
+     846931319467, 1223389595877, 1706596428714, 1599084110788,
+     735238639053, 1290407421884, 1870007986740,  843954079555,
+     344779891540,  192211850977, 1604585845117, 1852246811771,
+     388112808428, 1833630023693, 1315847113039,  245713822246,
+    1667804294846,  665181201360,  520634536593,  146331938147,
+    1459529610279, 1079445142357,  625117833602, 1165344429977,
+     354065633715,  809876282061, 1396859845149, 1132217351876,
+    1852090662275,  767030721599, 1135267932381, 1277366269332,
+    1222968652781,   50135441603, 1323472435796, 1437381823989,
+    1454029707032, 1583102083564,  202022742216,  409975102812,
+    1968549597427,  246731180360,  668539501518, 1564970775309,
+    1208601984387, 1579971402275, 1607153590739,  263919415821,
+    1234047428987,  126356391503,  291357470474, 1576012492300,
+     969110260497, 1036806411037, 1223121681626, 1842032426777,
+    1700139834706,  594280877492, 1716896976879,  558372370892,
+     736244698427, 2183093522362, 1120732085681, 2081936394389,
+    1119174175862,  477190446336,  118184658179, 1788614453999,
+    1635938083081, 1819884989931, 1529458372561, 1753411270382,
+    1227627237764, 1483777623820, 1966795160066, 2180073241806,
+      85318240064, 2131553483018,  814519612096,   43638258389,
+    2161693286637,  743387678942,   37166648676, 2072010104981,
+    2129931793406,  789124284544,  997484631051,  108890890718,
+    1552385502406, 1821640904396,  805642563348,  587815647198,
+     111948054008, 1263699895521, 1749700385350, 1253996788317,
+    2123355533304, 1121247258547,  394337848019, 1645783306507,
+    2133094874697,  839921560827, 1861980385738,   16271199013,
+     759457596344,  604270973047,  565157860829,  708489572972,
+    1401098577333,  861808171085, 1142181346193, 1171656275340,
+    2049067053348, 2121096011993,  734372096603,  270934814740,
+    1959119264217,  533488727475, 1813204176752, 2125046361386,
+     572772663191,  857635098663, 1144608663440, 1117463898700,
+     510482819297, 1534602538303,  163397131827,  588297379486,
+    2020934672212, 1204827030029, 1481034000825, 1780599283059,
+    1561516603024, 2180306746725,  804847539727, 1474300887191,
+     586883206918, 1033239018736, 1944126734738,  745857105533,
+     984384356789, 1251905771292, 1268253180660,  974133553657,
+     163263309983, 1739467016744, 1392408893332,  308362937555,
+     115698040254, 2125633303886,   78997502719, 1820925926591,
+     980184982629,  421071029250, 2108290575615,  264892240060,
+     651246917824, 2185426037422, 1097218167359,  525434374188,
+    1875055380550, 1494963142719, 2093114071481,  224071457628,
+    1821153014622,  997091627227,   64870382730,  638706814679,
+     228383435542, 1498933784338, 2122788985444,
+];
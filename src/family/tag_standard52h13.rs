@@ -0,0 +1,12191 @@
+pub static BITS: [(i32, i32); 52] = [
+    (-2, -2), (-1, -2), ( 0, -2), ( 1, -2), ( 2, -2), ( 3, -2), ( 4, -2), ( 5, -2),
+    ( 6, -2), ( 1,  1), ( 2,  1), ( 3,  1), ( 2,  2), ( 7, -2), ( 7, -1), ( 7,  0),
+    ( 7,  1), ( 7,  2), ( 7,  3), ( 7,  4), ( 7,  5), ( 7,  6), ( 4,  1), ( 4,  2),
+    ( 4,  3), ( 3,  2), ( 7,  7), ( 6,  7), ( 5,  7), ( 4,  7), ( 3,  7), ( 2,  7),
+    ( 1,  7), ( 0,  7), (-1,  7), ( 4,  4), ( 3,  4), ( 2,  4), ( 3,  3), (-2,  7),
+    (-2,  6), (-2,  5), (-2,  4), (-2,  3), (-2,  2), (-2,  1), (-2,  0), (-2, -1),
+    ( 1,  4), ( 1,  3), ( 1,  2), ( 2,  3),
+];
+
+pub static CODES: [u64; 48714] = [
+    1132815230246897, 1132816212698550, 1132817195150203, 1132818177601856,
+    1132819160053509, 1132820142505162, 1132821124956815, 1132822107408468,
+    1132823089860121, 1132824072311774, 1132825054763427, 1132826037215080,
+    1132828002118386, 1132828984570039, 1132830949473345, 1132831931924998,
+    1132832914376651, 1132833896828304, 1132834879279957, 1132836844183263,
+    1132839791538222, 1132840773989875, 1132841756441528, 1132843721344834,
+    1132844703796487, 1132846668699793, 1132847651151446, 1132849616054752,
+    1132850598506405, 1132853545861364, 1132854528313017, 1132855510764670,
+    1132857475667976, 1132858458119629, 1132859440571282, 1132860423022935,
+    1132861405474588, 1132862387926241, 1132863370377894, 1132864352829547,
+    1132865335281200, 1132866317732853, 1132868282636159, 1132869265087812,
+    1132871229991118, 1132873194894424, 1132874177346077, 1132876142249383,
+    1132878107152689, 1132883019410954, 1132885966765913, 1132890879024178,
+    1132891861475831, 1132895791282443, 1132896773734096, 1132897756185749,
+    1132901685992361, 1132904633347320, 1132910528057238, 1132911510508891,
+    1132912492960544, 1132914457863850, 1132917405218809, 1132918387670462,
+    1132920352573768, 1132921335025421, 1132922317477074, 1132923299928727,
+    1132924282380380, 1132926247283686, 1132928212186992, 1132929194638645,
+    1132930177090298, 1132931159541951, 1132932141993604, 1132938036703522,
+    1132939019155175, 1132940984058481, 1132943931413440, 1132944913865093,
+    1132947861220052, 1132949826123358, 1132950808575011, 1132951791026664,
+    1132959650639888, 1132960633091541, 1132962597994847, 1132963580446500,
+    1132966527801459, 1132968492704765, 1132969475156418, 1132972422511377,
+    1132979299672948, 1132981264576254, 1132982247027907, 1132983229479560,
+    1132985194382866, 1132994036447743, 1132996983802702, 1133006808319232,
+    1133007790770885, 1133009755674191, 1133016632835762, 1133026457352292,
+    1133031369610557, 1133033334513863, 1133035299417169, 1133041194127087,
+    1133043159030393, 1133044141482046, 1133049053740311, 1133050036191964,
+    1133051018643617, 1133052983546923, 1133054948450229, 1133056913353535,
+    1133058878256841, 1133060843160147, 1133062808063453, 1133065755418412,
+    1133066737870065, 1133067720321718, 1133069685225024, 1133070667676677,
+    1133071650128330, 1133074597483289, 1133075579934942, 1133078527289901,
+    1133079509741554, 1133082457096513, 1133083439548166, 1133089334258084,
+    1133091299161390, 1133092281613043, 1133093264064696, 1133094246516349,
+    1133095228968002, 1133096211419655, 1133099158774614, 1133102106129573,
+    1133105053484532, 1133106035936185, 1133112913097756, 1133116842904368,
+    1133122737614286, 1133123720065939, 1133125684969245, 1133126667420898,
+    1133127649872551, 1133128632324204, 1133129614775857, 1133130597227510,
+    1133131579679163, 1133133544582469, 1133138456840734, 1133139439292387,
+    1133140421744040, 1133141404195693, 1133145334002305, 1133146316453958,
+    1133150246260570, 1133152211163876, 1133155158518835, 1133157123422141,
+    1133162035680406, 1133164000583712, 1133171860196936, 1133174807551895,
+    1133181684713466, 1133182667165119, 1133186596971731, 1133188561875037,
+    1133194456584955, 1133196421488261, 1133203298649832, 1133204281101485,
+    1133205263553138, 1133208210908097, 1133210175811403, 1133213123166362,
+    1133217052972974, 1133218035424627, 1133220000327933, 1133224912586198,
+    1133225895037851, 1133237684457687, 1133238666909340, 1133249473877523,
+    1133251438780829, 1133254386135788, 1133255368587441, 1133256351039094,
+    1133258315942400, 1133259298394053, 1133263228200665, 1133264210652318,
+    1133265193103971, 1133269122910583, 1133278947427113, 1133279929878766,
+    1133281894782072, 1133286807040337, 1133288771943643, 1133292701750255,
+    1133296631556867, 1133298596460173, 1133301543815132, 1133307438525050,
+    1133315298138274, 1133318245493233, 1133327087558110, 1133329052461416,
+    1133340841881252, 1133344771687864, 1133348701494476, 1133356561107700,
+    1133358526011006, 1133364420720924, 1133367368075883, 1133368350527536,
+    1133370315430842, 1133372280334148, 1133380139947372, 1133387999560596,
+    1133398806528779, 1133418455561839, 1133423367820104, 1133443016853164,
+    1133447929111429, 1133452841369694, 1133458736079612, 1133465613241183,
+    1133476420209366, 1133481332467631, 1133482314919284, 1133490174532508,
+    1133499016597385, 1133503928855650, 1133504911307303, 1133510806017221,
+    1133524560340363, 1133529472598628, 1133532419953587, 1133535367308546,
+    1133555016341606, 1133558946148218, 1133565823309789, 1133570735568054,
+    1133575647826319, 1133603156472603, 1133609051182521, 1133610033634174,
+    1133611016085827, 1133613963440786, 1133615928344092, 1133616910795745,
+    1133623787957316, 1133625752860622, 1133629682667234, 1133633612473846,
+    1133635577377152, 1133642454538723, 1133646384345335, 1133656208861865,
+    1133659156216824, 1133661121120130, 1133666033378395, 1133676840346578,
+    1133677822798231, 1133681752604843, 1133683717508149, 1133685682411455,
+    1133689612218067, 1133690594669720, 1133695506927985, 1133697471831291,
+    1133698454282944, 1133705331444515, 1133706313896168, 1133707296347821,
+    1133708278799474, 1133714173509392, 1133715155961045, 1133716138412698,
+    1133718103316004, 1133721050670963, 1133722033122616, 1133723015574269,
+    1133726945380881, 1133730875187493, 1133733822542452, 1133736769897411,
+    1133739717252370, 1133740699704023, 1133741682155676, 1133742664607329,
+    1133743647058982, 1133748559317247, 1133750524220553, 1133752489123859,
+    1133755436478818, 1133757401382124, 1133759366285430, 1133760348737083,
+    1133762313640389, 1133763296092042, 1133765260995348, 1133772138156919,
+    1133778032866837, 1133779015318490, 1133781962673449, 1133784910028408,
+    1133785892480061, 1133787857383367, 1133791787189979, 1133795716996591,
+    1133797681899897, 1133806523964774, 1133809471319733, 1133815366029651,
+    1133819295836263, 1133820278287916, 1133821260739569, 1133827155449487,
+    1133829120352793, 1133831085256099, 1133833050159405, 1133835997514364,
+    1133856628999077, 1133857611450730, 1133865471063954, 1133866453515607,
+    1133867435967260, 1133868418418913, 1133870383322219, 1133878242935443,
+    1133879225387096, 1133882172742055, 1133884137645361, 1133886102548667,
+    1133899856871809, 1133900839323462, 1133901821775115, 1133916558549910,
+    1133917541001563, 1133921470808175, 1133923435711481, 1133931295324705,
+    1133956839067683, 1133957821519336, 1133982382810661, 1133990242423885,
+    1133998102037109, 1133999084488762, 1134030522941658, 1134032487844964,
+    1134033470296617, 1134040347458188, 1134050171974718, 1134053119329677,
+    1134057049136289, 1134065891201166, 1134072768362737, 1134083575330920,
+    1134086522685879, 1134090452492491, 1134098312105715, 1134099294557368,
+    1134117961138775, 1134120908493734, 1134122873397040, 1134124838300346,
+    1134130733010264, 1134138592623488, 1134144487333406, 1134154311849936,
+    1134159224108201, 1134169048624731, 1134171013528037, 1134181820496220,
+    1134217188755728, 1134225048368952, 1134227013272258, 1134230943078870,
+    1134232907982176, 1134234872885482, 1134236837788788, 1134242732498706,
+    1134253539466889, 1134255504370195, 1134258451725154, 1134269258693337,
+    1134281048113173, 1134294802436315, 1134297749791274, 1134309539211110,
+    1134311504114416, 1134315433921028, 1134329188244170, 1134333118050782,
+    1134335082954088, 1134342942567312, 1134347854825577, 1134350802180536,
+    1134357679342107, 1134362591600372, 1134366521406984, 1134377328375167,
+    1134379293278473, 1134395012504921, 1134405819473104, 1134422521151205,
+    1134429398312776, 1134433328119388, 1134434310571041, 1134444135087571,
+    1134452977152448, 1134459854314019, 1134462801668978, 1134464766572284,
+    1134471643733855, 1134473608637161, 1134489327863609, 1134495222573527,
+    1134505047090057, 1134512906703281, 1134529608381382, 1134533538187994,
+    1134536485542953, 1134551222317748, 1134555152124360, 1134569888899155,
+    1134580695867338, 1134583643222297, 1134590520383868, 1134594450190480,
+    1134619011481805, 1134620976385111, 1134622941288417, 1134625888643376,
+    1134629818449988, 1134638660514865, 1134642590321477, 1134645537676436,
+    1134649467483048, 1134652414838007, 1134654379741313, 1134661256902884,
+    1134662239354537, 1134671081419414, 1134682870839250, 1134696625162392,
+    1134698590065698, 1134699572517351, 1134700554969004, 1134701537420657,
+    1134702519872310, 1134704484775616, 1134706449678922, 1134708414582228,
+    1134709397033881, 1134710379485534, 1134711361937187, 1134712344388840,
+    1134713326840493, 1134714309292146, 1134715291743799, 1134719221550411,
+    1134720204002064, 1134721186453717, 1134722168905370, 1134725116260329,
+    1134726098711982, 1134727081163635, 1134728063615288, 1134729046066941,
+    1134730028518594, 1134733958325206, 1134734940776859, 1134736905680165,
+    1134738870583471, 1134740835486777, 1134741817938430, 1134742800390083,
+    1134744765293389, 1134747712648348, 1134748695100001, 1134750660003307,
+    1134751642454960, 1134754589809919, 1134757537164878, 1134758519616531,
+    1134764414326449, 1134766379229755, 1134772273939673, 1134773256391326,
+    1134774238842979, 1134775221294632, 1134776203746285, 1134777186197938,
+    1134785045811162, 1134786028262815, 1134788975617774, 1134791922972733,
+    1134792905424386, 1134793887876039, 1134794870327692, 1134796835230998,
+    1134801747489263, 1134802729940916, 1134804694844222, 1134806659747528,
+    1134808624650834, 1134809607102487, 1134812554457446, 1134813536909099,
+    1134814519360752, 1134815501812405, 1134816484264058, 1134821396522323,
+    1134823361425629, 1134828273683894, 1134830238587200, 1134831221038853,
+    1134840063103730, 1134842028007036, 1134844975361995, 1134849887620260,
+    1134851852523566, 1134855782330178, 1134858729685137, 1134859712136790,
+    1134861677040096, 1134864624395055, 1134865606846708, 1134867571750014,
+    1134885255879768, 1134886238331421, 1134889185686380, 1134894097944645,
+    1134895080396298, 1134896062847951, 1134898027751257, 1134899010202910,
+    1134899992654563, 1134900975106216, 1134901957557869, 1134903922461175,
+    1134905887364481, 1134909817171093, 1134913746977705, 1134914729429358,
+    1134915711881011, 1134918659235970, 1134922589042582, 1134923571494235,
+    1134928483752500, 1134931431107459, 1134932413559112, 1134934378462418,
+    1134935360914071, 1134939290720683, 1134942238075642, 1134944202978948,
+    1134946167882254, 1134947150333907, 1134953045043825, 1134955009947131,
+    1134958939753743, 1134960904657049, 1134961887108702, 1134966799366967,
+    1134972694076885, 1134980553690109, 1134982518593415, 1134983501045068,
+    1134986448400027, 1134990378206639, 1134995290464904, 1134999220271516,
+    1135002167626475, 1135005114981434, 1135007079884740, 1135008062336393,
+    1135014939497964, 1135016904401270, 1135020834207882, 1135028693821106,
+    1135033606079371, 1135034588531024, 1135035570982677, 1135038518337636,
+    1135039500789289, 1135042448144248, 1135044413047554, 1135053255112431,
+    1135057184919043, 1135058167370696, 1135065044532267, 1135079781307062,
+    1135080763758715, 1135082728662021, 1135084693565327, 1135085676016980,
+    1135097465436816, 1135106307501693, 1135108272404999, 1135109254856652,
+    1135112202211611, 1135116132018223, 1135126938986406, 1135129886341365,
+    1135131851244671, 1135132833696324, 1135136763502936, 1135153465181037,
+    1135156412535996, 1135163289697567, 1135167219504179, 1135169184407485,
+    1135170166859138, 1135171149310791, 1135180973827321, 1135187850988892,
+    1135189815892198, 1135191780795504, 1135193745698810, 1135197675505422,
+    1135204552666993, 1135206517570299, 1135215359635176, 1135219289441788,
+    1135221254345094, 1135224201700053, 1135226166603359, 1135229113958318,
+    1135232061313277, 1135236973571542, 1135242868281460, 1135245815636419,
+    1135251710346337, 1135252692797990, 1135255640152949, 1135259569959561,
+    1135261534862867, 1135264482217826, 1135266447121132, 1135267429572785,
+    1135272341831050, 1135278236540968, 1135286096154192, 1135317534607088,
+    1135319499510394, 1135322446865353, 1135330306478577, 1135336201188495,
+    1135342095898413, 1135347990608331, 1135348973059984, 1135354867769902,
+    1135357815124861, 1135362727383126, 1135364692286432, 1135370586996350,
+    1135393183384369, 1135395148287675, 1135396130739328, 1135401042997593,
+    1135407920159164, 1135412832417429, 1135420692030653, 1135421674482306,
+    1135423639385612, 1135433463902142, 1135439358612060, 1135447218225284,
+    1135465884806691, 1135477674226527, 1135478656678180, 1135481604033139,
+    1135495358356281, 1135496340807934, 1135500270614546, 1135507147776117,
+    1135514024937688, 1135515989840994, 1135517954744300, 1135518937195953,
+    1135522867002565, 1135531709067442, 1135532691519095, 1135533673970748,
+    1135536621325707, 1135537603777360, 1135558235262073, 1135560200165379,
+    1135563147520338, 1135565112423644, 1135570024681909, 1135571989585215,
+    1135573954488521, 1135575919391827, 1135577884295133, 1135579849198439,
+    1135580831650092, 1135581814101745, 1135586726360010, 1135589673714969,
+    1135593603521581, 1135605392941417, 1135610305199682, 1135612270102988,
+    1135614235006294, 1135615217457947, 1135620129716212, 1135621112167865,
+    1135622094619518, 1135623077071171, 1135624059522824, 1135626024426130,
+    1135627989329436, 1135631919136048, 1135635848942660, 1135638796297619,
+    1135639778749272, 1135676129460433, 1135677111912086, 1135679076815392,
+    1135688901331922, 1135693813590187, 1135695778493493, 1135699708300105,
+    1135715427526553, 1135717392429859, 1135719357333165, 1135728199398042,
+    1135732129204654, 1135736059011266, 1135738023914572, 1135739988817878,
+    1135744901076143, 1135747848431102, 1135771427270774, 1135785181593916,
+    1135789111400528, 1135790093852181, 1135794023658793, 1135795988562099,
+    1135799918368711, 1135802865723670, 1135803848175323, 1135804830626976,
+    1135811707788547, 1135816620046812, 1135823497208383, 1135825462111689,
+    1135831356821607, 1135837251531525, 1135839216434831, 1135841181338137,
+    1135847076048055, 1135858865467891, 1135862795274503, 1135869672436074,
+    1135872619791033, 1135876549597645, 1135883426759216, 1135888339017481,
+    1135895216179052, 1135905040695582, 1135908970502194, 1135915847663765,
+    1135918795018724, 1135922724825336, 1135931566890213, 1135932549341866,
+    1135933531793519, 1135938444051784, 1135946303665008, 1135949251019967,
+    1135950233471620, 1135955145729885, 1135961040439803, 1135972829859639,
+    1135974794762945, 1135975777214598, 1135976759666251, 1135978724569557,
+    1135981671924516, 1135993461344352, 1136003285860882, 1136004268312535,
+    1136008198119147, 1136014092829065, 1136019005087330, 1136023917345595,
+    1136026864700554, 1136037671668737, 1136044548830308, 1136045531281961,
+    1136058303153450, 1136059285605103, 1136064197863368, 1136074022379898,
+    1136075987283204, 1136085811799734, 1136094653864611, 1136095636316264,
+    1136103495929488, 1136113320446018, 1136114302897671, 1136116267800977,
+    1136123144962548, 1136125109865854, 1136127074769160, 1136136899285690,
+    1136140829092302, 1136143776447261, 1136153600963791, 1136155565867097,
+    1136159495673709, 1136162443028668, 1136168337738586, 1136169320190239,
+    1136175214900157, 1136177179803463, 1136181109610075, 1136202723546441,
+    1136210583159665, 1136218442772889, 1136220407676195, 1136231214644378,
+    1136233179547684, 1136235144450990, 1136240056709255, 1136243986515867,
+    1136244968967520, 1136262653097274, 1136267565355539, 1136277389872069,
+    1136283284581987, 1136303916066700, 1136306863421659, 1136308828324965,
+    1136317670389842, 1136334372067943, 1136335354519596, 1136337319422902,
+    1136340266777861, 1136341249229514, 1136346161487779, 1136349108842738,
+    1136359915810921, 1136363845617533, 1136365810520839, 1136396266522082,
+    1136403143683653, 1136405108586959, 1136410020845224, 1136417880458448,
+    1136421810265060, 1136422792716713, 1136431634781590, 1136438511943161,
+    1136441459298120, 1136446371556385, 1136452266266303, 1136453248717956,
+    1136454231169609, 1136467985492751, 1136473880202669, 1136477810009281,
+    1136478792460934, 1136480757364240, 1136486652074158, 1136500406397300,
+    1136502371300606, 1136504336203912, 1136509248462177, 1136512195817136,
+    1136515143172095, 1136523985236972, 1136524967688625, 1136525950140278,
+    1136529879946890, 1136546581624991, 1136555423689868, 1136561318399786,
+    1136565248206398, 1136569178013010, 1136570160464663, 1136578020077887,
+    1136583914787805, 1136584897239458, 1136586862142764, 1136593739304335,
+    1136597669110947, 1136627142660537, 1136630090015496, 1136633037370455,
+    1136634019822108, 1136635984725414, 1136641879435332, 1136644826790291,
+    1136648756596903, 1136666440726657, 1136671352984922, 1136672335436575,
+    1136684124856411, 1136686089759717, 1136692966921288, 1136694931824594,
+    1136708686147736, 1136725387825837, 1136730300084102, 1136744054407244,
+    1136749949117162, 1136755843827080, 1136768615698569, 1136770580601875,
+    1136787282279976, 1136788264731629, 1136791212086588, 1136794159441547,
+    1136795141893200, 1136801036603118, 1136803001506424, 1136806931313036,
+    1136813808474607, 1136827562797749, 1136833457507667, 1136836404862626,
+    1136841317120891, 1136843282024197, 1136856053895686, 1136859983702298,
+    1136864895960563, 1136866860863869, 1136868825767175, 1136872755573787,
+    1136877667832052, 1136895351961806, 1136899281768418, 1136900264220071,
+    1136917948349825, 1136919913253131, 1136924825511396, 1136925807963049,
+    1136931702672967, 1136932685124620, 1136938579834538, 1136943492092803,
+    1136945456996109, 1136956263964292, 1136960193770904, 1136969035835781,
+    1136989667320494, 1136993597127106, 1136998509385371, 1137000474288677,
+    1137004404095289, 1137006368998595, 1137020123321737, 1137030930289920,
+    1137033877644879, 1137043702161409, 1137047631968021, 1137055491581245,
+    1137068263452734, 1137070228356040, 1137091842292406, 1137094789647365,
+    1137096754550671, 1137100684357283, 1137101666808936, 1137103631712242,
+    1137105596615548, 1137106579067201, 1137110508873813, 1137118368487037,
+    1137123280745302, 1137133105261832, 1137151771843239, 1137159631456463,
+    1137165526166381, 1137166508618034, 1137184192747788, 1137185175199441,
+    1137186157651094, 1137192052361012, 1137204824232501, 1137205806684154,
+    1137215631200684, 1137218578555643, 1137228403072173, 1137236262685397,
+    1137237245137050, 1137242157395315, 1137253946815151, 1137270648493252,
+    1137279490558129, 1137282437913088, 1137293244881271, 1137294227332924,
+    1137298157139536, 1137314858817637, 1137318788624249, 1137323700882514,
+    1137326648237473, 1137355139335410, 1137362998948634, 1137365946303593,
+    1137372823465164, 1137379700626735, 1137384612885000, 1137395419853183,
+    1137416051337896, 1137436682822609, 1137441595080874, 1137462226565587,
+    1137470086178811, 1137472051082117, 1137475980888729, 1137480893146994,
+    1137500542180054, 1137524121019726, 1137526085923032, 1137530015729644,
+    1137543770052786, 1137544752504439, 1137559489279234, 1137561454182540,
+    1137564401537499, 1137570296247417, 1137574226054029, 1137579138312294,
+    1137582085667253, 1137601734700313, 1137613524120149, 1137624331088332,
+    1137650857282963, 1137660681799493, 1137662646702799, 1137666576509411,
+    1137685243090818, 1137690155349083, 1137695067607348, 1137697032510654,
+    1137705874575531, 1137736330576774, 1137768751481323, 1137779558449506,
+    1137780540901159, 1137785453159424, 1137793312772648, 1137798225030913,
+    1137802154837525, 1137816891612320, 1137820821418932, 1137825733677197,
+    1137845382710257, 1137847347613563, 1137851277420175, 1137860119485052,
+    1137866014194970, 1137874856259847, 1137878786066459, 1137888610582989,
+    1137889593034642, 1137897452647866, 1137902364906131, 1137916119229273,
+    1137920049035885, 1137934785810680, 1137944610327210, 1137955417295393,
+    1137956399747046, 1137957382198699, 1137962294456964, 1137975066328453,
+    1137979978586718, 1137988820651595, 1137992750458207, 1138001592523084,
+    1138008469684655, 1138019276652838, 1138024188911103, 1138026153814409,
+    1138027136266062, 1138028118717715, 1138041873040857, 1138053662460693,
+    1138056609815652, 1138060539622264, 1138067416783835, 1138069381687141,
+    1138074293945406, 1138075276397059, 1138105732398302, 1138112609559873,
+    1138119486721444, 1138131276141280, 1138148960271034, 1138162714594176,
+    1138168609304094, 1138172539110706, 1138174504014012, 1138188258337154,
+    1138192188143766, 1138193170595419, 1138212819628479, 1138217731886744,
+    1138221661693356, 1138223626596662, 1138231486209886, 1138239345823110,
+    1138240328274763, 1138265872017741, 1138274714082618, 1138278643889230,
+    1138282573695842, 1138295345567331, 1138304187632208, 1138305170083861,
+    1138314994600391, 1138315977052044, 1138320889310309, 1138331696278492,
+    1138332678730145, 1138334643633451, 1138347415504940, 1138348397956593,
+    1138354292666511, 1138372959247918, 1138374924151224, 1138377871506183,
+    1138382783764448, 1138385731119407, 1138386713571060, 1138388678474366,
+    1138418152023956, 1138422081830568, 1138425029185527, 1138427976540486,
+    1138428958992139, 1138446643121893, 1138450572928505, 1138451555380158,
+    1138468257058259, 1138474151768177, 1138487906091319, 1138500677962808,
+    1138507555124379, 1138515414737603, 1138523274350827, 1138538011125622,
+    1138553730352070, 1138570432030171, 1138599905579761, 1138606782741332,
+    1138613659902903, 1138615624806209, 1138624466871086, 1138630361581004,
+    1138645098355799, 1138646080807452, 1138649028162411, 1138652957969023,
+    1138660817582247, 1138667694743818, 1138676536808695, 1138685378873572,
+    1138693238486796, 1138695203390102, 1138701098100020, 1138704045454979,
+    1138710922616550, 1138721729584733, 1138724676939692, 1138743343521099,
+    1138746290876058, 1138753168037629, 1138772817070689, 1138781659135566,
+    1138791483652096, 1138804255523585, 1138810150233503, 1138834711524828,
+    1138837658879787, 1138848465847970, 1138850430751276, 1138859272816153,
+    1138866149977724, 1138867132429377, 1138869097332683, 1138875974494254,
+    1138886781462437, 1138891693720702, 1138900535785579, 1138922149721945,
+    1138934921593434, 1138946711013270, 1138959482884759, 1138962430239718,
+    1138963412691371, 1138964395143024, 1138971272304595, 1138983061724431,
+    1139002710757491, 1139025307145510, 1139050850888488, 1139093096309567,
+    1139094078761220, 1139116675149239, 1139118640052545, 1139137306633952,
+    1139140253988911, 1139169727538501, 1139176604700072, 1139182499409990,
+    1139193306378173, 1139195271281479, 1139202148443050, 1139214920314539,
+    1139225727282722, 1139226709734375, 1139266007800495, 1139271902510413,
+    1139280744575290, 1139286639285208, 1139288604188514, 1139293516446779,
+    1139302358511656, 1139315130383145, 1139330849609593, 1139351481094306,
+    1139352463545959, 1139374077482325, 1139377024837284, 1139412393096792,
+    1139420252710016, 1139425164968281, 1139426147419934, 1139441866646382,
+    1139448743807953, 1139462498131095, 1139463480582748, 1139498848842256,
+    1139504743552174, 1139527339940193, 1139532252198458, 1139534217101764,
+    1139546006521600, 1139556813489783, 1139561725748048, 1139564673103007,
+    1139567620457966, 1139571550264578, 1139575480071190, 1139583339684414,
+    1139596111555903, 1139612813234004, 1139613795685657, 1139622637750534,
+    1139634427170370, 1139650146396818, 1139653093751777, 1139687479559632,
+    1139694356721203, 1139698286527815, 1139699268979468, 1139700251431121,
+    1139707128592692, 1139711058399304, 1139718918012528, 1139731689884017,
+    1139761163433607, 1139768040595178, 1139770987950137, 1139791619434850,
+    1139795549241462, 1139807338661298, 1139809303564604, 1139810286016257,
+    1139813233371216, 1139815198274522, 1139818145629481, 1139819128081134,
+    1139830917500970, 1139833864855929, 1139839759565847, 1139840742017500,
+    1139846636727418, 1139862355953866, 1139863338405519, 1139874145373702,
+    1139883969890232, 1139895759310068, 1139900671568333, 1139937022279494,
+    1139938004731147, 1139940952086106, 1139945864344371, 1139948811699330,
+    1139955688860901, 1139974355442308, 1139981232603879, 1139994004475368,
+    1139995969378674, 1140002846540245, 1140009723701816, 1140020530669999,
+    1140034284993141, 1140040179703059, 1140044109509671, 1140062776091078,
+    1140063758542731, 1140065723446037, 1140068670800996, 1140069653252649,
+    1140080460220832, 1140086354930750, 1140102074157198, 1140114846028687,
+    1140115828480340, 1140132530158441, 1140144319578277, 1140151196739848,
+    1140158073901419, 1140160038804725, 1140169863321255, 1140171828224561,
+    1140174775579520, 1140187547451009, 1140205231580763, 1140212108742334,
+    1140218985903905, 1140221933258864, 1140238634936965, 1140254354163413,
+    1140263196228290, 1140270073389861, 1140278915454738, 1140286775067962,
+    1140292669777880, 1140294634681186, 1140300529391104, 1140301511842757,
+    1140311336359287, 1140314283714246, 1140330985392347, 1140337862553918,
+    1140346704618795, 1140374213265079, 1140375195716732, 1140392879846486,
+    1140402704363016, 1140406634169628, 1140411546427893, 1140442002429136,
+    1140442984880789, 1140443967332442, 1140449862042360, 1140462633913849,
+    1140463616365502, 1140465581268808, 1140470493527073, 1140477370688644,
+    1140478353140297, 1140482282946909, 1140503896883275, 1140522563464682,
+    1140529440626253, 1140533370432865, 1140534352884518, 1140536317787824,
+    1140537300239477, 1140559896627496, 1140561861530802, 1140568738692373,
+    1140576598305597, 1140579545660556, 1140592317532045, 1140628668243206,
+    1140637510308083, 1140657159341143, 1140663054051061, 1140698422310569,
+    1140703334568834, 1140712176633711, 1140721018698588, 1140722001150241,
+    1140722983601894, 1140723966053547, 1140734773021730, 1140740667731648,
+    1140745579989913, 1140764246571320, 1140766211474626, 1140773088636197,
+    1140783895604380, 1140785860507686, 1140789790314298, 1140807474444052,
+    1140820246315541, 1140824176122153, 1140831053283724, 1140837930445295,
+    1140850702316784, 1140851684768437, 1140857579478355, 1140871333801497,
+    1140872316253150, 1140886070576292, 1140887053027945, 1140891965286210,
+    1140902772254393, 1140904737157699, 1140911614319270, 1140913579222576,
+    1140918491480841, 1140919473932494, 1140923403739106, 1140940105417207,
+    1140963684256879, 1140966631611838, 1140973508773409, 1140994140258122,
+    1141009859484570, 1141011824387876, 1141029508517630, 1141047192647384,
+    1141057017163914, 1141057999615567, 1141065859228791, 1141069789035403,
+    1141074701293668, 1141075683745321, 1141087473165157, 1141098280133340,
+    1141104174843258, 1141117929166400, 1141124806327971, 1141158209684173,
+    1141198490201946, 1141201437556905, 1141205367363517, 1141211262073435,
+    1141242700526331, 1141250560139555, 1141267261817656, 1141279051237492,
+    1141280033689145, 1141281998592451, 1141285928399063, 1141290840657328,
+    1141315401948653, 1141331121175101, 1141338980788325, 1141349787756508,
+    1141363542079650, 1141366489434609, 1141374349047833, 1141393015629240,
+    1141402840145770, 1141419541823871, 1141469646858174, 1141486348536275,
+    1141507962472641, 1141525646602395, 1141538418473884, 1141546278087108,
+    1141594418218105, 1141607190089594, 1141626839122654, 1141634698735878,
+    1141672031898692, 1141688733576793, 1141697575641670, 1141704452803241,
+    1141717224674730, 1141729996546219, 1141763399902421, 1141766347257380,
+    1141777154225563, 1141782066483828, 1141802697968541, 1141804662871847,
+    1141820382098295, 1141821364549948, 1141823329453254, 1141872452035904,
+    1141873434487557, 1141887188810699, 1141923539521860, 1141948100813185,
+    1141961855136327, 1141979539266081, 1141996240944182, 1142012942622283,
+    1142024732042119, 1142028661848731, 1142040451268567, 1142052240688403,
+    1142071889721463, 1142077784431381, 1142124942110725, 1142146556047091,
+    1142155398111968, 1142198625984700, 1142209432952883, 1142215327662801,
+    1142242836309085, 1142249713470656, 1142261502890492, 1142263467793798,
+    1142279187020246, 1142305713214877, 1142346976184303, 1142367607669016,
+    1142404940831830, 1142409853090095, 1142458975672745, 1142464870382663,
+    1142475677350846, 1142476659802499, 1142477642254152, 1142546413869862,
+    1142608308324001, 1142612238130613, 1142626974905408, 1142645641486815,
+    1142668237874834, 1142699676327730, 1142709500844260, 1142721290264096,
+    1142727184974014, 1142802833751295, 1142869640463699, 1142878482528576,
+    1142893219303371, 1142902061368248, 1142906973626513, 1142910903433125,
+    1142921710401308, 1142929570014532, 1142933499821144, 1142955113757510,
+    1143001288985201, 1143034692341403, 1143048446664545, 1143054341374463,
+    1143056306277769, 1143065148342646, 1143069078149258, 1143098551698848,
+    1143103463957113, 1143106411312072, 1143110341118684, 1143117218280255,
+    1143119183183561, 1143129990151744, 1143136867313315, 1143141779571580,
+    1143150621636457, 1143177147831088, 1143182060089353, 1143204656477372,
+    1143240024736880, 1143247884350104, 1143281287706306, 1143293077126142,
+    1143294059577795, 1143302901642672, 1143313708610855, 1143314691062508,
+    1143319603320773, 1143327462933997, 1143332375192262, 1143334340095568,
+    1143343182160445, 1143354971580281, 1143359883838546, 1143369708355076,
+    1143373638161688, 1143375603064994, 1143388374936483, 1143391322291442,
+    1143403111711278, 1143409006421196, 1143418830937726, 1143426690550950,
+    1143486620101783, 1143498409521619, 1143501356876578, 1143502339328231,
+    1143504304231537, 1143506269134843, 1143508234038149, 1143511181393108,
+    1143512163844761, 1143514128748067, 1143516093651373, 1143517076103026,
+    1143520023457985, 1143521005909638, 1143525918167903, 1143526900619556,
+    1143528865522862, 1143529847974515, 1143531812877821, 1143533777781127,
+    1143534760232780, 1143535742684433, 1143536725136086, 1143538690039392,
+    1143540654942698, 1143545567200963, 1143550479459228, 1143551461910881,
+    1143554409265840, 1143558339072452, 1143563251330717, 1143565216234023,
+    1143569146040635, 1143572093395594, 1143574058298900, 1143576023202206,
+    1143577988105512, 1143579953008818, 1143580935460471, 1143584865267083,
+    1143589777525348, 1143595672235266, 1143601566945184, 1143603531848490,
+    1143605496751796, 1143606479203449, 1143611391461714, 1143614338816673,
+    1143615321268326, 1143616303719979, 1143622198429897, 1143623180881550,
+    1143624163333203, 1143625145784856, 1143629075591468, 1143636935204692,
+    1143643812366263, 1143645777269569, 1143646759721222, 1143651671979487,
+    1143654619334446, 1143669356109241, 1143670338560894, 1143682127980730,
+    1143684092884036, 1143689005142301, 1143690970045607, 1143702759465443,
+    1143713566433626, 1143718478691891, 1143726338305115, 1143728303208421,
+    1143730268111727, 1143743039983216, 1143744022434869, 1143753846951399,
+    1143755811854705, 1143762689016276, 1143765636371235, 1143768583726194,
+    1143775460887765, 1143777425791071, 1143782338049336, 1143792162565866,
+    1143793145017519, 1143794127469172, 1143795109920825, 1143799039727437,
+    1143801004630743, 1143805916889008, 1143807881792314, 1143816723857191,
+    1143820653663803, 1143824583470415, 1143829495728680, 1143840302696863,
+    1143843250051822, 1143847179858434, 1143852092116699, 1143853074568352,
+    1143856021923311, 1143859951729923, 1143861916633229, 1143867811343147,
+    1143874688504718, 1143877635859677, 1143878618311330, 1143888442827860,
+    1143899249796043, 1143903179602655, 1143917916377450, 1143920863732409,
+    1143923811087368, 1143927740893980, 1143932653152245, 1143933635603898,
+    1143938547862163, 1143939530313816, 1143946407475387, 1143950337281999,
+    1143952302185305, 1143956231991917, 1143963109153488, 1143978828379936,
+    1143990617799772, 1143991600251425, 1143994547606384, 1144014196639444,
+    1144020091349362, 1144023038704321, 1144024021155974, 1144035810575810,
+    1144038757930769, 1144044652640687, 1144047599995646, 1144048582447299,
+    1144049564898952, 1144052512253911, 1144054477157217, 1144057424512176,
+    1144060371867135, 1144069213932012, 1144075108641930, 1144079038448542,
+    1144088862965072, 1144089845416725, 1144097705029949, 1144113424256397,
+    1144125213676233, 1144128161031192, 1144141915354334, 1144159599484088,
+    1144166476645659, 1144173353807230, 1144182195872107, 1144192020388637,
+    1144193985291943, 1144194967743596, 1144199880001861, 1144204792260126,
+    1144208722066738, 1144212651873350, 1144213634325003, 1144226406196492,
+    1144227388648145, 1144228371099798, 1144239178067981, 1144247037681205,
+    1144256862197735, 1144262756907653, 1144265704262612, 1144273563875836,
+    1144278476134101, 1144279458585754, 1144280441037407, 1144290265553937,
+    1144292230457243, 1144294195360549, 1144295177812202, 1144302054973773,
+    1144304019877079, 1144313844393609, 1144324651361792, 1144335458329975,
+    1144336440781628, 1144351177556423, 1144361002072953, 1144362966976259,
+    1144380651106013, 1144381633557666, 1144382616009319, 1144384580912625,
+    1144386545815931, 1144391458074196, 1144397352784114, 1144398335235767,
+    1144407177300644, 1144413072010562, 1144415036913868, 1144421914075439,
+    1144422896527092, 1144423878978745, 1144425843882051, 1144428791237010,
+    1144432721043622, 1144438615753540, 1144440580656846, 1144449422721723,
+    1144459247238253, 1144472019109742, 1144473001561395, 1144473984013048,
+    1144481843626272, 1144494615497761, 1144496580401067, 1144497562852720,
+    1144511317175862, 1144513282079168, 1144518194337433, 1144522124144045,
+    1144526053950657, 1144532931112228, 1144538825822146, 1144543738080411,
+    1144544720532064, 1144549632790329, 1144559457306859, 1144565352016777,
+    1144575176533307, 1144580088791572, 1144588930856449, 1144598755372979,
+    1144607597437856, 1144610544792815, 1144626264019263, 1144628228922569,
+    1144631176277528, 1144634123632487, 1144641000794058, 1144641983245711,
+    1144655737568853, 1144659667375465, 1144663597182077, 1144676369053566,
+    1144677351505219, 1144678333956872, 1144688158473402, 1144691105828361,
+    1144693070731667, 1144694053183320, 1144697000538279, 1144699947893238,
+    1144702895248197, 1144718614474645, 1144720579377951, 1144722544281257,
+    1144733351249440, 1144740228411011, 1144749070475888, 1144767737057295,
+    1144774614218866, 1144796228155232, 1144806052671762, 1144823736801516,
+    1144827666608128, 1144838473576311, 1144839456027964, 1144851245447800,
+    1144852227899453, 1144853210351106, 1144854192802759, 1144871876932513,
+    1144875806739125, 1144883666352349, 1144892508417226, 1144925911773428,
+    1144937701193264, 1144939666096570, 1144940648548223, 1144943595903182,
+    1144957350226324, 1144964227387895, 1144976999259384, 1144977981711037,
+    1144979946614343, 1144997630744097, 1144999595647403, 1145000578099056,
+    1145001560550709, 1145017279777157, 1145025139390381, 1145030051648646,
+    1145031034100299, 1145034963906911, 1145037911261870, 1145039876165176,
+    1145041841068482, 1145049700681706, 1145053630488318, 1145059525198236,
+    1145067384811460, 1145071314618072, 1145085068941214, 1145089981199479,
+    1145097840812703, 1145109630232539, 1145110612684192, 1145116507394110,
+    1145117489845763, 1145132226620558, 1145133209072211, 1145139103782129,
+    1145144016040394, 1145150893201965, 1145152858105271, 1145153840556924,
+    1145159735266842, 1145167594880066, 1145174472041637, 1145179384299902,
+    1145186261461473, 1145192156171391, 1145193138623044, 1145196085978003,
+    1145200998236268, 1145226541979246, 1145234401592470, 1145238331399082,
+    1145240296302388, 1145242261205694, 1145255033077183, 1145256997980489,
+    1145257980432142, 1145259945335448, 1145262892690407, 1145266822497019,
+    1145267804948672, 1145269769851978, 1145272717206937, 1145273699658590,
+    1145274682110243, 1145277629465202, 1145293348691650, 1145295313594956,
+    1145298260949915, 1145305138111486, 1145309067918098, 1145332646757770,
+    1145343453725953, 1145344436177606, 1145363102759013, 1145369979920584,
+    1145374892178849, 1145376857082155, 1145389628953644, 1145390611405297,
+    1145404365728439, 1145408295535051, 1145410260438357, 1145424014761499,
+    1145431874374723, 1145434821729682, 1145447593601171, 1145456435666048,
+    1145457418117701, 1145463312827619, 1145478049602414, 1145486891667291,
+    1145501628442086, 1145502610893739, 1145512435410269, 1145533066894982,
+    1145534049346635, 1145542891411512, 1145544856314818, 1145545838766471,
+    1145548786121430, 1145556645734654, 1145566470251184, 1145572364961102,
+    1145575312316061, 1145576294767714, 1145580224574326, 1145583171929285,
+    1145598891155733, 1145603803413998, 1145604785865651, 1145605768317304,
+    1145619522640446, 1145627382253670, 1145630329608629, 1145641136576812,
+    1145679452191279, 1145697136321033, 1145721697612358, 1145727592322276,
+    1145735451935500, 1145747241355336, 1145752153613601, 1145771802646661,
+    1145774750001620, 1145778679808232, 1145787521873109, 1145802258647904,
+    1145843521617330, 1145855311037166, 1145860223295431, 1145862188198737,
+    1145866118005349, 1145873977618573, 1145889696845021, 1145902468716510,
+    1145910328329734, 1145913275684693, 1145918187942958, 1145928994911141,
+    1145929977362794, 1145940784330977, 1145941766782630, 1145942749234283,
+    1145945696589242, 1145954538654119, 1145964363170649, 1145974187687179,
+    1145977135042138, 1145996784075198, 1146001696333463, 1146014468204952,
+    1146027240076441, 1146032152334706, 1146049836464460, 1146054748722725,
+    1146061625884296, 1146062608335949, 1146072432852479, 1146083239820662,
+    1146087169627274, 1146100923950416, 1146102888853722, 1146123520338435,
+    1146124502790088, 1146130397500006, 1146136292209924, 1146139239564883,
+    1146141204468189, 1146145134274801, 1146147099178107, 1146153976339678,
+    1146155941242984, 1146162818404555, 1146175590276044, 1146176572727697,
+    1146179520082656, 1146181484985962, 1146192291954145, 1146201134019022,
+    1146208011180593, 1146212923438858, 1146213905890511, 1146216853245470,
+    1146260081118202, 1146272852989691, 1146286607312833, 1146288572216139,
+    1146291519571098, 1146295449377710, 1146301344087628, 1146303308990934,
+    1146307238797546, 1146315098410770, 1146325905378953, 1146332782540524,
+    1146333764992177, 1146337694798789, 1146341624605401, 1146347519315319,
+    1146350466670278, 1146402536607887, 1146418255834335, 1146427097899212,
+    1146430045254171, 1146434957512436, 1146436922415742, 1146438887319048,
+    1146446746932272, 1146462466158720, 1146464431062026, 1146501764224840,
+    1146518465902941, 1146523378161206, 1146529272871124, 1146561693775673,
+    1146566606033938, 1146569553388897, 1146570535840550, 1146582325260386,
+    1146588219970304, 1146595097131875, 1146600009390140, 1146631447843036,
+    1146634395197995, 1146637342552954, 1146642254811219, 1146648149521137,
+    1146662886295932, 1146670745909156, 1146682535328992, 1146687447587257,
+    1146700219458746, 1146757201654620, 1146782745397598, 1146789622559169,
+    1146799447075699, 1146803376882311, 1146820078560412, 1146835797786860,
+    1146845622303390, 1146848569658349, 1146850534561655, 1146851517013308,
+    1146858394174879, 1146868218691409, 1146883937917857, 1146891797531081,
+    1146912429015794, 1146919306177365, 1146920288629018, 1146953691985220,
+    1146957621791832, 1146979235728198, 1146986112889769, 1147000849664564,
+    1147004779471176, 1147013621536053, 1147015586439359, 1147019516245971,
+    1147027375859195, 1147028358310848, 1147038182827378, 1147057831860438,
+    1147064709022009, 1147070603731927, 1147091235216640, 1147102042184823,
+    1147107936894741, 1147113831604659, 1147122673669536, 1147125621024495,
+    1147136427992678, 1147149199864167, 1147163936638962, 1147191445285246,
+    1147208146963347, 1147214041673265, 1147228778448060, 1147233690706325,
+    1147251374836079, 1147255304642691, 1147266111610874, 1147281830837322,
+    1147295585160464, 1147296567612117, 1147301479870382, 1147303444773688,
+    1147313269290218, 1147323093806748, 1147328006065013, 1147342742839808,
+    1147384005809234, 1147384988260887, 1147414461810477, 1147425268778660,
+    1147430181036925, 1147434110843537, 1147439023101802, 1147443935360067,
+    1147449830069985, 1147465549296433, 1147483233426187, 1147485198329493,
+    1147489128136105, 1147538250718755, 1147546110331979, 1147552005041897,
+    1147553969945203, 1147560847106774, 1147586390849752, 1147587373301405,
+    1147597197817935, 1147604074979506, 1147612917044383, 1147613899496036,
+    1147614881947689, 1147620776657607, 1147624706464219, 1147637478335708,
+    1147650250207197, 1147654180013809, 1147656144917115, 1147674811498522,
+    1147683653563399, 1147690530724970, 1147717056919601, 1147719021822907,
+    1147738670855967, 1147761267243986, 1147768144405557, 1147781898728699,
+    1147793688148535, 1147815302084901, 1147817266988207, 1147830038859696,
+    1147831021311349, 1147832003763002, 1147832986214655, 1147834951117961,
+    1147843793182838, 1147847722989450, 1147865407119204, 1147873266732428,
+    1147876214087387, 1147877196539040, 1147884073700611, 1147927301573343,
+    1147928284024996, 1147945968154750, 1147952845316321, 1147962669832851,
+    1147999020544012, 1148006880157236, 1148012774867154, 1148016704673766,
+    1148018669577072, 1148022599383684, 1148026529190296, 1148034388803520,
+    1148039301061785, 1148050108029968, 1148077616676252, 1148083511386170,
+    1148085476289476, 1148091370999394, 1148095300806006, 1148143440937003,
+    1148173896938246, 1148174879389899, 1148185686358082, 1148195510874612,
+    1148204352939489, 1148232844037426, 1148240703650650, 1148268212296934,
+    1148271159651893, 1148275089458505, 1148283931523382, 1148289826233300,
+    1148293756039912, 1148316352427931, 1148323229589502, 1148329124299420,
+    1148331089202726, 1148352703139092, 1148372352172152, 1148378246882070,
+    1148379229333723, 1148383159140335, 1148396913463477, 1148401825721742,
+    1148402808173395, 1148409685334966, 1148435229077944, 1148450948304392,
+    1148452913207698, 1148456843014310, 1148461755272575, 1148467649982493,
+    1148475509595717, 1148482386757288, 1148486316563900, 1148497123532083,
+    1148499088435389, 1148508912951919, 1148517755016796, 1148527579533326,
+    1148534456694897, 1148544281211427, 1148548211018039, 1148550175921345,
+    1148562947792834, 1148601263407301, 1148603228310607, 1148625824698626,
+    1148633684311850, 1148650385989951, 1148662175409787, 1148663157861440,
+    1148674947281276, 1148683789346153, 1148691648959377, 1148699508572601,
+    1148702455927560, 1148708350637478, 1148724069863926, 1148726034767232,
+    1148732911928803, 1148737824187068, 1148749613606904, 1148756490768475,
+    1148759438123434, 1148766315285005, 1148773192446576, 1148777122253188,
+    1148785964318065, 1148792841479636, 1148793823931289, 1148796771286248,
+    1148797753737901, 1148826244835838, 1148840981610633, 1148844911417245,
+    1148849823675510, 1148860630643693, 1148868490256917, 1148874384966835,
+    1148880279676753, 1148886174386671, 1148904840968078, 1148905823419731,
+    1148919577742873, 1148926454904444, 1148931367162709, 1148936279420974,
+    1148959858260646, 1148961823163952, 1148962805615605, 1148967717873870,
+    1148970665228829, 1148971647680482, 1149002103681725, 1149003086133378,
+    1149005051036684, 1149016840456520, 1149017822908173, 1149022735166438,
+    1149026664973050, 1149027647424703, 1149037471941233, 1149046314006110,
+    1149047296457763, 1149052208716028, 1149064980587517, 1149067927942476,
+    1149069892845782, 1149070875297435, 1149071857749088, 1149086594523883,
+    1149099366395372, 1149118032976779, 1149159295946205, 1149171085366041,
+    1149202523818937, 1149204488722243, 1149223155303650, 1149224137755303,
+    1149238874530098, 1149261470918117, 1149269330531341, 1149273260337953,
+    1149287997112748, 1149302733887543, 1149322382920603, 1149337119695398,
+    1149344979308622, 1149353821373499, 1149355786276805, 1149365610793335,
+    1149377400213171, 1149396066794578, 1149428487699127, 1149435364860698,
+    1149450101635493, 1149461891055329, 1149469750668553, 1149474662926818,
+    1149482522540042, 1149488417249960, 1149494311959878, 1149503154024755,
+    1149511996089632, 1149517890799550, 1149523785509468, 1149565048478894,
+    1149571925640465, 1149602381641708, 1149608276351626, 1149615153513197,
+    1149621048223115, 1149649539321052, 1149655434030970, 1149665258547500,
+    1149691784742131, 1149712416226844, 1149717328485109, 1149718310936762,
+    1149723223195027, 1149728135453292, 1149750731841311, 1149755644099576,
+    1149767433519412, 1149782170294207, 1149783152745860, 1149791994810737,
+    1149798871972308, 1149803784230573, 1149808696488838, 1149827363070245,
+    1149834240231816, 1149838170038428, 1149844064748346, 1149870590942977,
+    1149901046944220, 1149913818815709, 1149917748622321, 1149934450300422,
+    1149939362558687, 1149959994043400, 1149962941398359, 1149967853656624,
+    1149970801011583, 1149978660624807, 1149990450044643, 1150013046432662,
+    1150022870949192, 1150024835852498, 1150026800755804, 1150032695465722,
+    1150045467337211, 1150046449788864, 1150063151466965, 1150074940886801,
+    1150096554823167, 1150105396888044, 1150125045921104, 1150133887985981,
+    1150163361535571, 1150174168503754, 1150185957923590, 1150189887730202,
+    1150198729795079, 1150240975216158, 1150247852377729, 1150248834829382,
+    1150267501410789, 1150273396120707, 1150314659090133, 1150336273026499,
+    1150338237929805, 1150353957156253, 1150362799221130, 1150386378060802,
+    1150388342964108, 1150395220125679, 1150398167480638, 1150399149932291,
+    1150412904255433, 1150413886707086, 1150425676126922, 1150467921548001,
+    1150475781161225, 1150536693163711, 1150555359745118, 1150558307100077,
+    1150587780649667, 1150624131360828, 1150626096264134, 1150646727748847,
+    1150647710200500, 1150650657555459, 1150657534717030, 1150659499620336,
+    1150663429426948, 1150672271491825, 1150694867879844, 1150696832783150,
+    1150738095752576, 1150754797430677, 1150755779882330, 1150769534205472,
+    1150787218335226, 1150866796919119, 1150885463500526, 1150891358210444,
+    1150907077436892, 1150915919501769, 1150918866856728, 1150929673824911,
+    1150931638728217, 1150935568534829, 1150948340406318, 1150952270212930,
+    1150966024536072, 1150996480537315, 1151016129570375, 1151044620668312,
+    1151079006476167, 1151107497574104, 1151113392284022, 1151143848285265,
+    1151157602608407, 1151163497318325, 1151176269189814, 1151179216544773,
+    1151186093706344, 1151211637449322, 1151232268934035, 1151243075902218,
+    1151257812677013, 1151263707386931, 1151299075646439, 1151317742227846,
+    1151346233325783, 1151403215521657, 1151404197973310, 1151406162876616,
+    1151474934492326, 1151525039526629, 1151537811398118, 1151538793849771,
+    1151544688559689, 1151562372689443, 1151567284947708, 1151595776045645,
+    1151620337336970, 1151639986370030, 1151658652951437, 1151691073855986,
+    1151696968565904, 1151701880824169, 1151716617598964, 1151727424567147,
+    1151738231535330, 1151776547149797, 1151800125989469, 1151813880312611,
+    1151819775022529, 1151848266120466, 1151939634124195, 1151952405995684,
+    1152011353094864, 1152032967031230, 1152078159807268, 1152080124710574,
+    1152086019420492, 1152093879033716, 1152100756195287, 1152129247293224,
+    1152135142003142, 1152154791036202, 1152192124199016, 1152255001104808,
+    1152265808072991, 1152296264074234, 1152328684978783, 1152411210917635,
+    1152448544080449, 1152470158016815, 1152485877243263, 1152520263051118,
+    1152523210406077, 1152526157761036, 1152604753893276, 1152650929120967,
+    1152655841379232, 1152661736089150, 1152665665895762, 1152669595702374,
+    1152697104348658, 1152741314673043, 1152769805770980, 1152778647835857,
+    1152811068740406, 1152814016095365, 1152841524741649, 1152884752614381,
+    1152903419195788, 1152964331198274, 1153067488621839, 1153126435721019,
+    1153132330430937, 1153139207592508, 1153161803980527, 1153166716238792,
+    1153173593400363, 1153179488110281, 1153189312626811, 1153211909014830,
+    1153224680886319, 1153239417661114, 1153313101535089, 1153353382052862,
+    1153355346956168, 1153357311859474, 1153369101279310, 1153427065926837,
+    1153428048378490, 1153430013281796, 1153432960636755, 1153443767604938,
+    1153465381541304, 1153468328896263, 1153488960380976, 1153494855090894,
+    1153520398833872, 1153525311092137, 1153571486319828, 1153576398578093,
+    1153665801678516, 1153728678584308, 1153736538197532, 1153749310069021,
+    1153763064392163, 1153784678328529, 1153815134329772, 1153826923749608,
+    1153852467492586, 1153878993687217, 1153918291753337, 1153932046076479,
+    1153935975883091, 1153948747754580, 1153978221304170, 1153984116014088,
+    1153988045820700, 1153990010724006, 1153993940530618, 1154023414080208,
+    1154032256145085, 1154067624404593, 1154113799632284, 1154116746987243,
+    1154117729438896, 1154134431116997, 1154139343375262, 1154187483506259,
+    1154232676282297, 1154252325315357, 1154259202476928, 1154260184928581,
+    1154269026993458, 1154302430349660, 1154312254866190, 1154346640674045,
+    1154361377448840, 1154366289707105, 1154368254610411, 1154372184417023,
+    1154390850998430, 1154404605321572, 1154415412289755, 1154466499775711,
+    1154476324292241, 1154492043518689, 1154508745196790, 1154531341584809,
+    1154550008166216, 1154554920424481, 1154587341329030, 1154609937717049,
+    1154612885072008, 1154658077848046, 1154668884816229, 1154682639139371,
+    1154708182882349, 1154748463400122, 1154762217723264, 1154777936949712,
+    1154785796562936, 1154810357854261, 1154853585726993, 1154863410243523,
+    1154864392695176, 1154866357598482, 1154885024179889, 1154920392439397,
+    1154948883537334, 1154952813343946, 1154961655408823, 1154977374635271,
+    1154993093861719, 1155009795539820, 1155015690249738, 1155031409476186,
+    1155091339027019, 1155104110898508, 1155120812576609, 1155123759931568,
+    1155142426512975, 1155147338771240, 1155148321222893, 1155149303674546,
+    1155163057997688, 1155229864710092, 1155249513743152, 1155256390904723,
+    1155263268066294, 1155266215421253, 1155300601229108, 1155303548584067,
+    1155306495939026, 1155316320455556, 1155323197617127, 1155341864198534,
+    1155348741360105, 1155392951684490, 1155435197105569, 1155465653106812,
+    1155469582913424, 1155470565365077, 1155497091559708, 1155500038914667,
+    1155507898527891, 1155526565109298, 1155552108852276, 1155558003562194,
+    1155561933368806, 1155562915820459, 1155585512208478, 1155598284079967,
+    1155624810274598, 1155631687436169, 1155643476856005, 1155674915308901,
+    1155679827567166, 1155699476600226, 1155702423955185, 1155708318665103,
+    1155722072988245, 1155740739569652, 1155790844603955, 1155807546282056,
+    1155808528733709, 1155825230411810, 1155831125121728, 1155834072476687,
+    1155837019831646, 1155861581122971, 1155869440736195, 1155872388091154,
+    1155886142414296, 1155940177255211, 1155950984223394, 1155960808739924,
+    1155971615708107, 1156011896225880, 1156022703194063, 1156039404872164,
+    1156043334678776, 1156105229132915, 1156111123842833, 1156112106294486,
+    1156169088490360, 1156173018296972, 1156211333911439, 1156213298814745,
+    1156237860106070, 1156273228365578, 1156305649270127, 1156369508627572,
+    1156377368240796, 1156399964628815, 1156406841790386, 1156453017018077,
+    1156466771341219, 1156486420374279, 1156501157149074, 1156513929020563,
+    1156520806182134, 1156528665795358, 1156547332376765, 1156571893668090,
+    1156575823474702, 1156621998702393, 1156626910960658, 1156647542445371,
+    1156649507348677, 1156715331609428, 1156719261416040, 1156733015739182,
+    1156746770062324, 1156765436643731, 1156795892644974, 1156812594323075,
+    1156816524129687, 1156837155614400, 1156841085421012, 1156852874840848,
+    1156863681809031, 1156878418583826, 1156905927230110, 1156957997167719,
+    1156959962071025, 1156961926974331, 1156962909425984, 1157016944266899,
+    1157027751235082, 1157048382719795, 1157060172139631, 1157074908914426,
+    1157115189432199, 1157155469949972, 1157160382208237, 1157220311759070,
+    1157227188920641, 1157232101178906, 1157242908147089, 1157260592276843,
+    1157291048278086, 1157314627117758, 1157320521827676, 1157341153312389,
+    1157346065570654, 1157356872538837, 1157360802345449, 1157441363380995,
+    1157442345832648, 1157454135252484, 1157462977317361, 1157472801833891,
+    1157478696543809, 1157504240286787, 1157532731384724, 1157534696288030,
+    1157548450611172, 1157573994354150, 1157575959257456, 1157608380162005,
+    1157617222226882, 1157619187130188, 1157629011646718, 1157681081584327,
+    1157706625327305, 1157712520037223, 1157750835651690, 1157757712813261,
+    1157758695264914, 1157763607523179, 1157766554878138, 1157769502233097,
+    1157807817847564, 1157864800043438, 1157873642108315, 1157886413979804,
+    1157912940174435, 1157922764690965, 1157952238240555, 1157961080305432,
+    1158015115146347, 1158017080049653, 1158047536050896, 1158057360567426,
+    1158093711278587, 1158110412956688, 1158120237473218, 1158133009344707,
+    1158151675926114, 1158160517990991, 1158165430249256, 1158190973992234,
+    1158199816057111, 1158236166768272, 1158249921091414, 1158262692962903,
+    1158267605221168, 1158286271802575, 1158297078770758, 1158350131160020,
+    1158362903031509, 1158444446518708, 1158452306131932, 1158463113100115,
+    1158469990261686, 1158494551553011, 1158518130392683, 1158522060199295,
+    1158523042650948, 1158537779425743, 1158552516200538, 1158554481103844,
+    1158592796718311, 1158690059431958, 1158705778658406, 1158748024079485,
+    1158779462532381, 1158789287048911, 1158802058920400, 1158836444728255,
+    1158849216599744, 1158856093761315, 1158857076212968, 1158877707697681,
+    1158913075957189, 1158944514410085, 1158947461765044, 1158968093249757,
+    1158999531702653, 1159134127579114, 1159141987192338, 1159153776612174,
+    1159157706418786, 1159166548483663, 1159211741259701, 1159245144615903,
+    1159271670810534, 1159272653262187, 1159277565520452, 1159286407585329,
+    1159314898683266, 1159326688103102, 1159341424877897, 1159367951072528,
+    1159412161396913, 1159457354172951, 1159472090947746, 1159493704884112,
+    1159529073143620, 1159532020498579, 1159533002950232, 1159548722176680,
+    1159599809662636, 1159611599082472, 1159614546437431, 1159634195470491,
+    1159636160373797, 1159641072632062, 1159649914696939, 1159653844503551,
+    1159668581278346, 1159675458439917, 1159683318053141, 1159689212763059,
+    1159757984378769, 1159775668508523, 1159803177154807, 1159826755994479,
+    1159833633156050, 1159840510317621, 1159842475220927, 1159846405027539,
+    1159852299737457, 1159854264640763, 1159863106705640, 1159883738190353,
+    1159885703093659, 1159912229288290, 1159925001159779, 1159932860773003,
+    1159966264129205, 1160013421808549, 1160041912906486, 1160048790068057,
+    1160062544391199, 1160088088134177, 1160093982844095, 1160165701814764,
+    1160167666718070, 1160176508782947, 1160188298202783, 1160211877042455,
+    1160262964528411, 1160278683754859, 1160291455626348, 1160351385177181,
+    1160354332532140, 1160359244790405, 1160373981565200, 1160379876275118,
+    1160383806081730, 1160384788533383, 1160389700791648, 1160393630598260,
+    1160439805825951, 1160443735632563, 1160445700535869, 1160466332020582,
+    1160489910860254, 1160491875763560, 1160494823118519, 1160506612538355,
+    1160533138732986, 1160554752669352, 1160575384154065, 1160586191122248,
+    1160596998090431, 1160600927897043, 1160624506736715, 1160643173318122,
+    1160734541321851, 1160754190354911, 1160792505969378, 1160795453324337,
+    1160809207647479, 1160828856680539, 1160845558358640, 1160847523261946,
+    1160858330230129, 1160874049456577, 1160875031908230, 1160886821328066,
+    1160897628296249, 1160917277329309, 1160921207135921, 1160922189587574,
+    1160933979007410, 1160935943910716, 1160960505202041, 1160972294621877,
+    1160990961203284, 1161004715526426, 1161023382107833, 1161033206624363,
+    1161066609980565, 1161072504690483, 1161087241465278, 1161094118626849,
+    1161096083530155, 1161097065981808, 1161105908046685, 1161123592176439,
+    1161124574628092, 1161126539531398, 1161140293854540, 1161143241209499,
+    1161145206112805, 1161146188564458, 1161149135919417, 1161167802500824,
+    1161180574372313, 1161182539275619, 1161191381340496, 1161194328695455,
+    1161198258502067, 1161210047921903, 1161235591664881, 1161243451278105,
+    1161244433729758, 1161249345988023, 1161252293342982, 1161263100311165,
+    1161269977472736, 1161271942376042, 1161276854634307, 1161280784440919,
+    1161285696699184, 1161286679150837, 1161309275538856, 1161314187797121,
+    1161315170248774, 1161318117603733, 1161319100055386, 1161322047410345,
+    1161323029861998, 1161327942120263, 1161330889475222, 1161337766636793,
+    1161339731540099, 1161341696443405, 1161342678895058, 1161343661346711,
+    1161346608701670, 1161353485863241, 1161355450766547, 1161359380573159,
+    1161360363024812, 1161363310379771, 1161373134896301, 1161392783929361,
+    1161398678639279, 1161410468059115, 1161411450510768, 1161419310123992,
+    1161423239930604, 1161427169737216, 1161431099543828, 1161436011802093,
+    1161443871415317, 1161445836318623, 1161447801221929, 1161449766125235,
+    1161452713480194, 1161456643286806, 1161459590641765, 1161462537996724,
+    1161474327416560, 1161477274771519, 1161481204578131, 1161485134384743,
+    1161495941352926, 1161508713224415, 1161524432450863, 1161525414902516,
+    1161528362257475, 1161529344709128, 1161530327160781, 1161533274515740,
+    1161545063935576, 1161548011290535, 1161548993742188, 1161550958645494,
+    1161558818258718, 1161559800710371, 1161561765613677, 1161573555033513,
+    1161576502388472, 1161600081228144, 1161601063679797, 1161602046131450,
+    1161607940841368, 1161615800454592, 1161617765357898, 1161627589874428,
+    1161630537229387, 1161648221359141, 1161659028327324, 1161667870392201,
+    1161668852843854, 1161670817747160, 1161675730005425, 1161680642263690,
+    1161685554521955, 1161695379038485, 1161700291296750, 1161702256200056,
+    1161704221103362, 1161706186006668, 1161721905233116, 1161725835039728,
+    1161727799943034, 1161742536717829, 1161752361234359, 1161753343686012,
+    1161754326137665, 1161766115557501, 1161768080460807, 1161770045364113,
+    1161780852332296, 1161802466268662, 1161816220591804, 1161818185495110,
+    1161824080205028, 1161846676593047, 1161849623948006, 1161863378271148,
+    1161866325626107, 1161869272981066, 1161879097497596, 1161882044852555,
+    1161913483305451, 1161917413112063, 1161919378015369, 1161926255176940,
+    1161934114790164, 1161942956855041, 1161948851564959, 1161977342662896,
+    1161991096986038, 1162005833760833, 1162007798664139, 1162011728470751,
+    1162018605632322, 1162029412600505, 1162043166923647, 1162061833505054,
+    1162064780860013, 1162071658021584, 1162094254409603, 1162098184216215,
+    1162108991184398, 1162123727959193, 1162126675314152, 1162127657765805,
+    1162130605120764, 1162131587572417, 1162138464733988, 1162159096218701,
+    1162171868090190, 1162190534671597, 1162221973124493, 1162222955576146,
+    1162224920479452, 1162234744995982, 1162241622157553, 1162243587060859,
+    1162253411577389, 1162267165900531, 1162284850030285, 1162289762288550,
+    1162299586805080, 1162319235838140, 1162321200741446, 1162335937516241,
+    1162359516355913, 1162366393517484, 1162371305775749, 1162385060098891,
+    1162391937260462, 1162392919712115, 1162405691583604, 1162411586293522,
+    1162437130036500, 1162444007198071, 1162447937004683, 1162454814166254,
+    1162457761521213, 1162476428102620, 1162480357909232, 1162486252619150,
+    1162490182425762, 1162493129780721, 1162514743717087, 1162522603330311,
+    1162525550685270, 1162549129524942, 1162554041783207, 1162580567977838,
+    1162581550429491, 1162582532881144, 1162607094172469, 1162612988882387,
+    1162616918688999, 1162621830947264, 1162623795850570, 1162624778302223,
+    1162630673012141, 1162635585270406, 1162636567722059, 1162644427335283,
+    1162665058819996, 1162681760498097, 1162685690304709, 1162687655208015,
+    1162719093660911, 1162722041015870, 1162728918177441, 1162746602307195,
+    1162754461920419, 1162757409275378, 1162758391727031, 1162761339081990,
+    1162772146050173, 1162773128501826, 1162780005663397, 1162782953018356,
+    1162788847728274, 1162803584503069, 1162827163342741, 1162835022955965,
+    1162836987859271, 1162846812375801, 1162860566698943, 1162863514053902,
+    1162882180635309, 1162893970055145, 1162916566443164, 1162930320766306,
+    1162932285669612, 1162963724122508, 1162988285413833, 1162994180123751,
+    1163005969543587, 1163006951995240, 1163022671221688, 1163025618576647,
+    1163027583479953, 1163029548383259, 1163049197416319, 1163054109674584,
+    1163065899094420, 1163073758707644, 1163088495482439, 1163102249805581,
+    1163115021677070, 1163123863741947, 1163124846193600, 1163130740903518,
+    1163131723355171, 1163138600516742, 1163148425033272, 1163166109163026,
+    1163177898582862, 1163194600260963, 1163204424777493, 1163207372132452,
+    1163209337035758, 1163224073810553, 1163229968520471, 1163241757940307,
+    1163242740391960, 1163243722843613, 1163247652650225, 1163252564908490,
+    1163255512263449, 1163263371876673, 1163270249038244, 1163273196393203,
+    1163281056006427, 1163292845426263, 1163317406717588, 1163323301427506,
+    1163332143492383, 1163333125944036, 1163338038202301, 1163347862718831,
+    1163348845170484, 1163365546848585, 1163385195881645, 1163387160784951,
+    1163389125688257, 1163391090591563, 1163412704527929, 1163478528788680,
+    1163526668919677, 1163560072275879, 1163564984534144, 1163568914340756,
+    1163569896792409, 1163582668663898, 1163596422987040, 1163625896536630,
+    1163642598214731, 1163649475376302, 1163651440279608, 1163656352537873,
+    1163661264796138, 1163678948925892, 1163725124153583, 1163735931121766,
+    1163741825831684, 1163742808283337, 1163764422219703, 1163784071252763,
+    1163787018607722, 1163788983511028, 1163789965962681, 1163793895769293,
+    1163799790479211, 1163804702737476, 1163813544802353, 1163823369318883,
+    1163826316673842, 1163855790223432, 1163858737578391, 1163863649836656,
+    1163865614739962, 1163878386611451, 1163885263773022, 1163886246224675,
+    1163896070741205, 1163897053192858, 1163898035644511, 1163905895257735,
+    1163924561839142, 1163939298613937, 1163956000292038, 1163995298358158,
+    1163999228164770, 1164009052681300, 1164030666617666, 1164032631520972,
+    1164036561327584, 1164052280554032, 1164054245457338, 1164055227908991,
+    1164084701458581, 1164106315394947, 1164107297846600, 1164123017073048,
+    1164146595912720, 1164175087010657, 1164196700947023, 1164198665850329,
+    1164207507915206, 1164215367528430, 1164220279786695, 1164221262238348,
+    1164222244690001, 1164231086754878, 1164237963916449, 1164243858626367,
+    1164277261982569, 1164284139144140, 1164297893467282, 1164308700435465,
+    1164329331920178, 1164335226630096, 1164340138888361, 1164394173729276,
+    1164406945600765, 1164407928052418, 1164409892955724, 1164412840310683,
+    1164442313860273, 1164444278763579, 1164452138376803, 1164477682119781,
+    1164484559281352, 1164488489087964, 1164489471539617, 1164498313604494,
+    1164536629218961, 1164541541477226, 1164549401090450, 1164565120316898,
+    1164570032575163, 1164577892188387, 1164581821994999, 1164583786898305,
+    1164587716704917, 1164611295544589, 1164614242899548, 1164616207802854,
+    1164617190254507, 1164664347933851, 1164679084708646, 1164683996966911,
+    1164697751290053, 1164731154646255, 1164732137097908, 1164737049356173,
+    1164739996711132, 1164741961614438, 1164753751034274, 1164763575550804,
+    1164783224583864, 1164785189487170, 1164807785875189, 1164834312069820,
+    1164844136586350, 1164850031296268, 1164883434652470, 1164894241620653,
+    1164908978395448, 1164913890653713, 1164914873105366, 1164915855557019,
+    1164925680073549, 1164944346654956, 1164957118526445, 1164960065881404,
+    1164969890397934, 1164996416592565, 1164998381495871, 1165000346399177,
+    1165021960335543, 1165031784852073, 1165036697110338, 1165041609368603,
+    1165057328595051, 1165085819692988, 1165100556467783, 1165112345887619,
+    1165120205500843, 1165128065114067, 1165141819437209, 1165153608857045,
+    1165162450921922, 1165172275438452, 1165180135051676, 1165185047309941,
+    1165187994664900, 1165195854278124, 1165198801633083, 1165218450666143,
+    1165223362924408, 1165228275182673, 1165229257634326, 1165236134795897,
+    1165260696087222, 1165263643442181, 1165281327571935, 1165288204733506,
+    1165359923704175, 1165392344608724, 1165397256866989, 1165400204221948,
+    1165423783061620, 1165428695319885, 1165429677771538, 1165446379449639,
+    1165448344352945, 1165469958289311, 1165473888095923, 1165488624870718,
+    1165521045775267, 1165522028226920, 1165523993130226, 1165525958033532,
+    1165530870291797, 1165536765001715, 1165550519324857, 1165567221002958,
+    1165571150809570, 1165577045519488, 1165580975326100, 1165581957777753,
+    1165584905132712, 1165586870036018, 1165595712100895, 1165604554165772,
+    1165610448875690, 1165635010167015, 1165651711845116, 1165668413523217,
+    1165669395974870, 1165687080104624, 1165689045007930, 1165700834427766,
+    1165736202687274, 1165742097397192, 1165743079848845, 1165760763978599,
+    1165764693785211, 1165784342818271, 1165795149786454, 1165828553142656,
+    1165854096885634, 1165866868757123, 1165872763467041, 1165879640628612,
+    1165883570435224, 1165886517790183, 1165911079081508, 1165915008888120,
+    1165934657921180, 1165935640372833, 1165938587727792, 1165942517534404,
+    1165958236760852, 1165971008632341, 1165975920890606, 1165976903342259,
+    1165995569923666, 1165999499730278, 1166003429536890, 1166029955731521,
+    1166045674957969, 1166048622312928, 1166059429281111, 1166076130959212,
+    1166086937927395, 1166105604508802, 1166131148251780, 1166150797284840,
+    1166156691994758, 1166160621801370, 1166164551607982, 1166183218189389,
+    1166214656642285, 1166217603997244, 1166221533803856, 1166224481158815,
+    1166225463610468, 1166231358320386, 1166233323223692, 1166254937160058,
+    1166255919611711, 1166269673934853, 1166271638838159, 1166273603741465,
+    1166276551096424, 1166286375612954, 1166289322967913, 1166300129936096,
+    1166314866710891, 1166315849162544, 1166324691227421, 1166332550840645,
+    1166340410453869, 1166351217422052, 1166352199873705, 1166358094583623,
+    1166369884003459, 1166379708519989, 1166380690971642, 1166382655874948,
+    1166390515488172, 1166399357553049, 1166403287359661, 1166420971489415,
+    1166424901296027, 1166425883747680, 1166450445039005, 1166462234458841,
+    1166473041427024, 1166474023878677, 1166483848395207, 1166517251751409,
+    1166531006074551, 1166552620010917, 1166553602462570, 1166567356785712,
+    1166568339237365, 1166569321689018, 1166572269043977, 1166584058463813,
+    1166592900528690, 1166611567110097, 1166616479368362, 1166625321433239,
+    1166630233691504, 1166631216143157, 1166640058208034, 1166651847627870,
+    1166661672144400, 1166665601951012, 1166666584402665, 1166682303629113,
+    1166696057952255, 1166716689436968, 1166746162986558, 1166752057696476,
+    1166770724277883, 1166777601439454, 1166808057440697, 1166821811763839,
+    1166822794215492, 1166830653828716, 1166838513441940, 1166876829056407,
+    1166926934090710, 1166935776155587, 1166943635768811, 1166952477833688,
+    1166975074221707, 1166988828544849, 1167007495126256, 1167026161707663,
+    1167034021320887, 1167056617708906, 1167074301838660, 1167101810484944,
+    1167115564808086, 1167174511907266, 1167212827521733, 1167224616941569,
+    1167250160684547, 1167267844814301, 1167276686879178, 1167321879655216,
+    1167327774365134, 1167336616430011, 1167337598881664, 1167364125076295,
+    1167371002237866, 1167373949592825, 1167374932044478, 1167377879399437,
+    1167392616174232, 1167408335400680, 1167411282755639, 1167413247658945,
+    1167435844046964, 1167443703660188, 1167455493080024, 1167465317596554,
+    1167469247403166, 1167476124564737, 1167487913984573, 1167496756049450,
+    1167499703404409, 1167502650759368, 1167508545469286, 1167520334889122,
+    1167521317340775, 1167563562761854, 1167566510116813, 1167568475020119,
+    1167578299536649, 1167592053859791, 1167595983666403, 1167610720441198,
+    1167628404570952, 1167652965862277, 1167664755282113, 1167668685088725,
+    1167671632443684, 1167698158638315, 1167704053348233, 1167709948058151,
+    1167770860060637, 1167797386255268, 1167805245868492, 1167842579031306,
+    1167851421096183, 1167924122518505, 1167933947035035, 1167990929230909,
+    1167994859037521, 1168026297490417, 1168038086910253, 1168108823429269,
+    1168124542655717, 1168153033753654, 1168212963304487, 1168220822917711,
+    1168229664982588, 1168238507047465, 1168242436854077, 1168251278918954,
+    1168255208725566, 1168266015693749, 1168297454146645, 1168302366404910,
+    1168318085631358, 1168347559180948, 1168355418794172, 1168394716860292,
+    1168455628862778, 1168463488476002, 1168481172605756, 1168490014670633,
+    1168490997122286, 1168495909380551, 1168497874283857, 1168508681252040,
+    1168522435575182, 1168541102156589, 1168550926673119, 1168553874028078,
+    1168575487964444, 1168581382674362, 1168602014159075, 1168608891320646,
+    1168613803578911, 1168629522805359, 1168670785774785, 1168747417003719,
+    1168750364358678, 1168763136230167, 1168769030940085, 1168799486941328,
+    1168805381651246, 1168850574427284, 1168886925138445, 1168941942431013,
+    1169025450821518, 1169037240241354, 1169048047209537, 1169054924371108,
+    1169077520759127, 1169084397920698, 1169090292630616, 1169174783472774,
+    1169190502699222, 1169247484895096, 1169253379605014, 1169273028638074,
+    1169316256510806, 1169322151220724, 1169395835094699, 1169414501676106,
+    1169427273547595, 1169429238450901, 1169432185805860, 1169442992774043,
+    1169524536261242, 1169558922069097, 1169565799230668, 1169575623747198,
+    1169600185038523, 1169605097296788, 1169666009299274, 1169672886460845,
+    1169723973946801, 1169744605431514, 1169759342206309, 1169765236916227,
+    1169774078981104, 1169865446984833, 1169905727502606, 1169945025568726,
+    1169955832536909, 1169960744795174, 1169964674601786, 1169981376279887,
+    1170024604152619, 1170037376024108, 1170058989960474, 1170116954608001,
+    1170157235125774, 1170305585325377, 1170334076423314, 1170343900939844,
+    1170357655262986, 1170361585069598, 1170370427134475, 1170387128812576,
+    1170408742748942, 1170443128556797, 1170505023010936, 1170510917720854,
+    1170541373722097, 1170549233335321, 1170560040303504, 1170563970110116,
+    1170581654239870, 1170627829467561, 1170644531145662, 1170693653728312,
+    1170703478244842, 1170712320309719, 1170714285213025, 1170715267664678,
+    1170716250116331, 1170719197471290, 1170779127022123, 1170788951538653,
+    1170851828444445, 1170869512574199, 1170891126510565, 1170925512318420,
+    1170942213996521, 1170951056061398, 1171000178644048, 1171015897870496,
+    1171059125743228, 1171062073098187, 1171065020453146, 1171067967808105,
+    1171073862518023, 1171089581744471, 1171107265874225, 1171114143035796,
+    1171133792068856, 1171157370908528, 1171168177876711, 1171277230010194,
+    1171323405237885, 1171336177109374, 1171339124464333, 1171347966529210,
+    1171368598013923, 1171402983821778, 1171413790789961, 1171437369629633,
+    1171448176597816, 1171483544857324, 1171489439567242, 1171495334277160,
+    1171502211438731, 1171508106148649, 1171536597246586, 1171540527053198,
+    1171557228731299, 1171573930409400, 1171638772218498, 1171659403703211,
+    1171666280864782, 1171701649124290, 1171722280609003, 1171732105125533,
+    1171772385643306, 1171784175063142, 1171819543322650, 1171835262549098,
+    1171897157003237, 1171905016616461, 1171915823584644, 1171936455069357,
+    1171963963715641, 1171992454813578, 1171995402168537, 1172021928363168,
+    1172039612492922, 1172052384364411, 1172078910559042, 1172095612237143,
+    1172099542043755, 1172103471850367, 1172149647078058, 1172215471338809,
+    1172237085275175, 1172278348244601, 1172345154957005, 1172357926828494,
+    1172376593409901, 1172382488119819, 1172396242442961, 1172409014314450,
+    1172422768637592, 1172427680895857, 1172443400122305, 1172446347477264,
+    1172462066703712, 1172562276772318, 1172584873160337, 1172599609935132,
+    1172602557290091, 1172605504645050, 1172619258968192, 1172635960646293,
+    1172656592131006, 1172696872648779, 1172697855100432, 1172748942586388,
+    1172750907489694, 1172762696909530, 1172800030072344, 1172819679105404,
+    1172879608656237, 1172902205044256, 1172935608400458, 1172940520658723,
+    1172943468013682, 1173006344919474, 1173034836017411, 1173076098986837,
+    1173085923503367, 1173095748019897, 1173138975892629, 1173150765312465,
+    1173153712667424, 1173167466990566, 1173168449442219, 1173170414345525,
+    1173172379248831, 1173188098475279, 1173194975636850, 1173214624669910,
+    1173224449186440, 1173252940284377, 1173276519124049, 1173305010221986,
+    1173344308288106, 1173348238094718, 1173358062611248, 1173369852031084,
+    1173375746741002, 1173397360677368, 1173403255387286, 1173407185193898,
+    1173410132548857, 1173471044551343, 1173523114488952, 1173540798618706,
+    1173544728425318, 1173557500296807, 1173570272168296, 1173618412299293,
+    1173656727913760, 1173669499785249, 1173735324046000, 1173757920434019,
+    1173814902629893, 1173825709598076, 1173835534114606, 1173839463921218,
+    1173842411276177, 1173849288437748, 1173895463665439, 1173896446117092,
+    1173935744183212, 1173943603796436, 1173950480958007, 1173966200184455,
+    1173980936959250, 1174015322767105, 1174056585736531, 1174062480446449,
+    1174104725867528, 1174110620577446, 1174129287158853, 1174134199417118,
+    1174148936191913, 1174175462386544, 1174195111419604, 1174208865742746,
+    1174234409485724, 1174244234002254, 1174248163808866, 1174260935680355,
+    1174274690003497, 1174305146004740, 1174347391425819, 1174373917620450,
+    1174404373621693, 1174412233234917, 1174444654139466, 1174449566397731,
+    1174460373365914, 1174466268075832, 1174479039947321, 1174481987302280,
+    1174494759173769, 1174497706528728, 1174506548593605, 1174507531045258,
+    1174512443303523, 1174513425755176, 1174531109884930, 1174544864208072,
+    1174580232467580, 1174606758662211, 1174625425243618, 1174629355050230,
+    1174668653116350, 1174687319697757, 1174703038924205, 1174726617763877,
+    1174745284345284, 1174749214151896, 1174805213896117, 1174817985767606,
+    1174830757639095, 1174831740090748, 1174887739834969, 1174892652093234,
+    1174902476609764, 1174908371319682, 1174937844869272, 1174938827320925,
+    1174953564095720, 1174956511450679, 1175063598680856, 1175075388100692,
+    1175112721263506, 1175138265006484, 1175140229909790, 1175170685911033,
+    1175240439978396, 1175278755592863, 1175337702692043, 1175354404370144,
+    1175362263983368, 1175369141144939, 1175411386566018, 1175458544245362,
+    1175468368761892, 1175473281020157, 1175499807214788, 1175508649279665,
+    1175522403602807, 1175540087732561, 1175541070184214, 1175546964894132,
+    1175566613927192, 1175596087476782, 1175630473284637, 1175632438187943,
+    1175638332897861, 1175664859092492, 1175665841544145, 1175718893933407,
+    1175719876385060, 1175722823740019, 1175724788643325, 1175737560514814,
+    1175738542966467, 1175754262192915, 1175767034064404, 1175772928774322,
+    1175791595355729, 1175795525162341, 1175807314582177, 1175816156647054,
+    1175818121550360, 1175824998711931, 1175848577551603, 1175853489809868,
+    1175865279229704, 1175881980907805, 1175886893166070, 1175911454457395,
+    1175932085942108, 1175970401556575, 1175978261169799, 1175982190976411,
+    1176009699622695, 1176010682074348, 1176013629429307, 1176045067882203,
+    1176079453690058, 1176144295499156, 1176157067370645, 1176222891631396,
+    1176235663502885, 1176243523116109, 1176308364925207, 1176325066603308,
+    1176326049054961, 1176327031506614, 1176351592797939, 1176400715380589,
+    1176403662735548, 1176408574993813, 1176423311768608, 1176477346609523,
+    1176494048287624, 1176506820159113, 1176514679772337, 1176582468936394,
+    1176588363646312, 1176591311001271, 1176592293452924, 1176595240807883,
+    1176609977582678, 1176630609067391, 1176662047520287, 1176725906877732,
+    1176751450620710, 1176764222492199, 1176817274881461, 1176826116946338,
+    1176838888817827, 1176873274625682, 1176888011400477, 1176922397208332,
+    1176976432049247, 1177004923147184, 1177010817857102, 1177020642373632,
+    1177029484438509, 1177074677214547, 1177079589472812, 1177123799797197,
+    1177143448830257, 1177158185605052, 1177196501219519, 1177263307931923,
+    1177272149996800, 1177333061999286, 1177339939160857, 1177375307420365,
+    1177396921356731, 1177433272067892, 1177464710520788, 1177465692972441,
+    1177494184070378, 1177499096328643, 1177632709753451, 1177666113109653,
+    1177698534014202, 1177743726790240, 1177764358274953, 1177767305629912,
+    1177801691437767, 1177805621244379, 1177849831568764, 1177902883958026,
+    1177910743571250, 1177916638281168, 1177928427701004, 1177939234669187,
+    1177948076734064, 1178002111574979, 1178023725511345, 1178026672866304,
+    1178027655317957, 1178038462286140, 1178048286802670, 1178049269254323,
+    1178057128867547, 1178123935579951, 1178150461774582, 1178241829778311,
+    1178255584101453, 1178320425910551, 1178322390813857, 1178349899460141,
+    1178354811718406, 1178367583589895, 1178379373009731, 1178385267719649,
+    1178390179977914, 1178391162429567, 1178411793914280, 1178458951593624,
+    1178461898948583, 1178463863851889, 1178483512884949, 1178491372498173,
+    1178545407339088, 1178547372242394, 1178594529921738, 1178596494825044,
+    1178620073664716, 1178626950826287, 1178628915729593, 1178638740246123,
+    1178644634956041, 1178646599859347, 1178734038056464, 1178745827476300,
+    1178805757027133, 1178885335611026, 1178905967095739, 1178906949547392,
+    1178910879354004, 1178966879098225, 1178970808904837, 1179013054325916,
+    1179038598068894, 1179061194456913, 1179101474974686, 1179111299491216,
+    1179137825685847, 1179139790589153, 1179145685299071, 1179148632654030,
+    1179156492267254, 1179184000913538, 1179214456914781, 1179254737432554,
+    1179292070595368, 1179329403758182, 1179345122984630, 1179469894344561,
+    1179514104668946, 1179539648411924, 1179607437575981, 1179622174350776,
+    1179680138998303, 1179733191387565, 1179749893065666, 1179754805323931,
+    1179773471905338, 1179813752423111, 1179836348811130, 1179860910102455,
+    1179889401200392, 1179908067781799, 1179971927139244, 1179991576172304,
+    1179996488430569, 1180003365592140, 1180007295398752, 1180020067270241,
+    1180056417981402, 1180069189852891, 1180095716047522, 1180106523015705,
+    1180140908823560, 1180181189341333, 1180219504955800, 1180225399665718,
+    1180242101343819, 1180251925860349, 1180297118636387, 1180309890507876,
+    1180314802766141, 1180316767669447, 1180321679927712, 1180327574637630,
+    1180347223670690, 1180358030638873, 1180397328704993, 1180415995286400,
+    1180416977738053, 1180489679160375, 1180527994774842, 1180544696452943,
+    1180566310389309, 1180569257744268, 1180579082260798, 1180612485617000,
+    1180675362522792, 1180684204587669, 1180691081749240, 1180701888717423,
+    1180714660588912, 1180715643040565, 1180718590395524, 1180761818268256,
+    1180784414656275, 1180840414400496, 1180849256465373, 1180879712466616,
+    1180891501886452, 1180897396596370, 1180987782148446, 1181082097507134,
+    1181097816733582, 1181127290283172, 1181136132348049, 1181142027057967,
+    1181143991961273, 1181147921767885, 1181163640994333, 1181165605897639,
+    1181179360220781, 1181191149640617, 1181192132092270, 1181199991705494,
+    1181207851318718, 1181215710931942, 1181260903707980, 1181275640482775,
+    1181320833258813, 1181337534936914, 1181346377001791, 1181372903196422,
+    1181385675067911, 1181433815198908, 1181475078168334, 1181480972878252,
+    1181538937525779, 1181570375978675, 1181612621399754, 1181638165142732,
+    1181657814175792, 1181673533402240, 1181685322822076, 1181688270177035,
+    1181697112241912, 1181702024500177, 1181717743726625, 1181727568243155,
+    1181751147082827, 1181756059341092, 1181774725922499, 1181810094182007,
+    1181854304506392, 1181861181667963, 1181870023732840, 1181890655217553,
+    1181891637669206, 1181901462185736, 1181908339347307, 1181916198960531,
+    1181948619865080, 1181963356639875, 1181989882834506, 1181999707351036,
+    1182003637157648, 1182027215997320, 1182028198448973, 1182037040513850,
+    1182054724643604, 1182090092903112, 1182092057806418, 1182095005161377,
+    1182108759484519, 1182125461162620, 1182169671487005, 1182204057294860,
+    1182212899359737, 1182230583489491, 1182236478199409, 1182237460651062,
+    1182267916652305, 1182324898848179, 1182384828399012, 1182396617818848,
+    1182401530077113, 1182402512528766, 1182469319241170, 1182515494468861,
+    1182518441823820, 1182525318985391, 1182572476664735, 1182573459116388,
+    1182594090601101, 1182598020407713, 1182606862472590, 1182632406215568,
+    1182643213183751, 1182712967251114, 1182724756670950, 1182730651380868,
+    1182762089833764, 1182838721062698, 1182848545579228, 1182853457837493,
+    1182868194612288, 1182886861193695, 1182933036421386, 1182986088810648,
+    1182993948423872, 1183079421717683, 1183089246234213, 1183096123395784,
+    1183106930363967, 1183137386365210, 1183153105591658, 1183256263015223,
+    1183306368049526, 1183323069727627, 1183326999534239, 1183340753857381,
+    1183346648567299, 1183356473083829, 1183375139665236, 1183407560569785,
+    1183409525473091, 1183434086764416, 1183444893732599, 1183470437475577,
+    1183473384830536, 1183479279540454, 1183511700445003, 1183522507413186,
+    1183538226639634, 1183547068704511, 1183549033607817, 1183561805479306,
+    1183592261480549, 1183601103545426, 1183636471804934, 1183648261224770,
+    1183660050644606, 1183675769871054, 1183677734774360, 1183722927550398,
+    1183751418648335, 1183777944842966, 1183799558779332, 1183828049877269,
+    1183859488330165, 1183889944331408, 1183900751299591, 1183931207300834,
+    1183938084462405, 1183939066914058, 1183960680850424, 1183968540463648,
+    1184050083950847, 1184087417113661, 1184117873114904, 1184203346408715,
+    1184212188473592, 1184223977893428, 1184257381249630, 1184329100220299,
+    1184359556221542, 1184421450675681, 1184448959321965, 1184459766290148,
+    1184463696096760, 1184480397774861, 1184495134549656, 1184553099197183,
+    1184583555198426, 1184614993651322, 1184619905909587, 1184661168879013,
+    1184662151330666, 1184666081137278, 1184669028492237, 1184671975847196,
+    1184679835460420, 1184685730170338, 1184694572235215, 1184697519590174,
+    1184730922946376, 1184738782559600, 1184747624624477, 1184753519334395,
+    1184796747207127, 1184810501530269, 1184850782048042, 1184879273145979,
+    1184888115210856, 1184914641405487, 1184940185148465, 1184990290182768,
+    1185006991860869, 1185009939215828, 1185040395217071, 1185052184636907,
+    1185096394961292, 1185098359864598, 1185124886059229, 1185185798061715,
+    1185216254062958, 1185217236514611, 1185220183869570, 1185221166321223,
+    1185254569677425, 1185256534580731, 1185260464387343, 1185263411742302,
+    1185279130968750, 1185309586969993, 1185341025422889, 1185363621810908,
+    1185378358585703, 1185393095360498, 1185401937425375, 1185431410974965,
+    1185434358329924, 1185440253039842, 1185454989814637, 1185466779234473,
+    1185518849172082, 1185521796527041, 1185526708785306, 1185622006595647,
+    1185648532790278, 1185669164274991, 1185702567631193, 1185707479889458,
+    1185752672665496, 1185754637568802, 1185760532278720, 1185775269053515,
+    1185799830344840, 1185884321186998, 1185935408672954, 1185941303382872,
+    1185983548803951, 1185994355772134, 1186003197837011, 1186022846870071,
+    1186050355516355, 1186071969452721, 1186105372808923, 1186112249970494,
+    1186116179777106, 1186132881455207, 1186139758616778, 1186209512684141,
+    1186220319652324, 1186226214362242, 1186289091268034, 1186324459527542,
+    1186378494368457, 1186387336433334, 1186445301080861, 1186456108049044,
+    1186462985210615, 1186481651792022, 1186485581598634, 1186491476308552,
+    1186495406115164, 1186510142889959, 1186538633987896, 1186558283020956,
+    1186602493345341, 1186631966894931, 1186652598379644, 1186663405347827,
+    1186675194767663, 1186681089477581, 1186720387543701, 1186734141866843,
+    1186742001480067, 1186742983931720, 1186755755803209, 1186817650257348,
+    1186828457225531, 1186832387032143, 1186840246645367, 1186846141355285,
+    1186852036065203, 1186874632453222, 1186879544711487, 1186882492066446,
+    1186898211292894, 1186902141099506, 1186905088454465, 1186916877874301,
+    1186947333875544, 1186954211037115, 1186999403813153, 1187006280974724,
+    1187022000201172, 1187024947556131, 1187035754524314, 1187041649234232,
+    1187048526395803, 1187055403557374, 1187091754268535, 1187106491043330,
+    1187110420849942, 1187133999689614, 1187155613625980, 1187182139820611,
+    1187189016982182, 1187218490531772, 1187227332596649, 1187236174661526,
+    1187241086919791, 1187255823694586, 1187318700600378, 1187332454923520,
+    1187344244343356, 1187354068859886, 1187377647699558, 1187386489764435,
+    1187445436863615, 1187458208735104, 1187461156090063, 1187467050799981,
+    1187512243576019, 1187553506545445, 1187557436352057, 1187576102933464,
+    1187682207711988, 1187702839196701, 1187749014424392, 1187750979327698,
+    1187767681005799, 1187832522814897, 1187874768235976, 1187897364623995,
+    1187915048753749, 1187923890818626, 1187953364368216, 1187973013401276,
+    1187974978304582, 1187992662434336, 1188002486950866, 1188003469402519,
+    1188006416757478, 1188044732371945, 1188052591985169, 1188072241018229,
+    1188083047986412, 1188089925147983, 1188098767212860, 1188132170569062,
+    1188136100375674, 1188138065278980, 1188157714312040, 1188168521280223,
+    1188207819346343, 1188249082315769, 1188252029670728, 1188273643607094,
+    1188297222446766, 1188299187350072, 1188304099608337, 1188311959221561,
+    1188318836383132, 1188340450319498, 1188356169545946, 1188379748385618,
+    1188421011355044, 1188429853419921, 1188438695484798, 1188452449807940,
+    1188466204131082, 1188467186582735, 1188499607487284, 1188535958198445,
+    1188551677424893, 1188555607231505, 1188558554586464, 1188564449296382,
+    1188640098073663, 1188642062976969, 1188646975235234, 1188696097817884,
+    1188699045172843, 1188700027624496, 1188790413176572, 1188814974467897,
+    1188839535759222, 1188891605696831, 1188901430213361, 1188925009053033,
+    1188953500150970, 1188971184280724, 1189017359508415, 1189043885703046,
+    1189047815509658, 1189061569832800, 1189148025578264, 1189166692159671,
+    1189169639514630, 1189190270999343, 1189194200805955, 1189253147905135,
+    1189292445971255, 1189319954617539, 1189344515908864, 1189354340425394,
+    1189360235135312, 1189371042103495, 1189386761329943, 1189402480556391,
+    1189417217331186, 1189436866364246, 1189468304817142, 1189483041591937,
+    1189490901205161, 1189500725721691, 1189513497593180, 1189522339658057,
+    1189523322109710, 1189556725465912, 1189591111273767, 1189604865596909,
+    1189669707406007, 1189670689857660, 1189684444180802, 1189706058117168,
+    1189707040568821, 1189710970375433, 1189711952827086, 1189728654505187,
+    1189742408828329, 1189755180699818, 1189766970119654, 1189773847281225,
+    1189784654249408, 1189791531410979, 1189793496314285, 1189803320830815,
+    1189834759283711, 1189836724187017, 1189839671541976, 1189877987156443,
+    1189927109739093, 1189982127031661, 1190011600581251, 1190028302259352,
+    1190042056582494, 1190057775808942, 1190119670263081, 1190127529876305,
+    1190142266651100, 1190154056070936, 1190182547168873, 1190191389233750,
+    1190192371685403, 1190238546913094, 1190247388977971, 1190250336332930,
+    1190260160849460, 1190283739689132, 1190374125241208, 1190399668984186,
+    1190425212727164, 1190436019695347, 1190460580986672, 1190461563438325,
+    1190463528341631, 1190465493244937, 1190469423051549, 1190510686020975,
+    1190539177118912, 1190540159570565, 1190543106925524, 1190581422539991,
+    1190598124218092, 1190602054024704, 1190604018928010, 1190613843444540,
+    1190629562670988, 1190644299445783, 1190648229252395, 1190680650156944,
+    1190712088609840, 1190746474417695, 1190759246289184, 1190798544355304,
+    1190799526806957, 1190800509258610, 1190819175840017, 1190824088098282,
+    1190844719582995, 1190849631841260, 1190859456357790, 1190895807068951,
+    1190955736619784, 1190969490942926, 1191037280106983, 1191043174816901,
+    1191071665914838, 1191093279851204, 1191100157012775, 1191108016625999,
+    1191201349533034, 1191209209146258, 1191227875727665, 1191263243987173,
+    1191299594698334, 1191302542053293, 1191338892764454, 1191346752377678,
+    1191379173282227, 1191383103088839, 1191414541541735, 1191420436251653,
+    1191447944897937, 1191536365546707, 1191545207611584, 1191557979483073,
+    1191582540774398, 1191593347742581, 1191604154710764, 1191631663357048,
+    1191641487873578, 1191662119358291, 1191691592907881, 1191705347231023,
+    1191715171747553, 1191716154199206, 1191730890974001, 1191776083750039,
+    1191790820524834, 1191819311622771, 1191822258977730, 1191838960655831,
+    1191873346463686, 1191923451497989, 1191925416401295, 1191944082982702,
+    1191994188017005, 1192054117567838, 1192065906987674, 1192075731504204,
+    1192086538472387, 1192089485827346, 1192094398085611, 1192122889183548,
+    1192124854086854, 1192139590861649, 1192153345184791, 1192182818734381,
+    1192220151897195, 1192227029058766, 1192241765833561, 1192257485060009,
+    1192300712932741, 1192309554997618, 1192328221579025, 1192330186482331,
+    1192353765322003, 1192369484548451, 1192394045839776, 1192395028291429,
+    1192412712421183, 1192428431647631, 1192432361454243, 1192466747262098,
+    1192468712165404, 1192469694617057, 1192513904941442, 1192522747006319,
+    1192579729202193, 1192632781591455, 1192649483269556, 1192773272177834,
+    1192776219532793, 1192822394760484, 1192832219277014, 1192854815665033,
+    1192897061086112, 1192930464442314, 1192932429345620, 1192934394248926,
+    1192982534379923, 1192998253606371, 1193007095671248, 1193026744704308,
+    1193063095415469, 1193065060318775, 1193085691803488, 1193138744192750,
+    1193141691547709, 1193152498515892, 1193155445870851, 1193157410774157,
+    1193166252839034, 1193195726388624, 1193216357873337, 1193242884067968,
+    1193243866519621, 1193270392714252, 1193276287424170, 1193292006650618,
+    1193357830911369, 1193393199170877, 1193445269108486, 1193458040979975,
+    1193463935689893, 1193493409239483, 1193522882789073, 1193577900081641,
+    1193632917374209, 1193637829632474, 1193639794535780, 1193676145246941,
+    1193682039956859, 1193723302926285, 1193740004604386, 1193773407960588,
+    1193787162283730, 1193801899058525, 1193847091834563, 1193852004092828,
+    1193869688222582, 1193896214417213, 1193898179320519, 1193899161772172,
+    1193921758160191, 1193948284354822, 1193957126419699, 1193962038677964,
+    1193998389389125, 1194054389133346, 1194062248746570, 1194121195845750,
+    1194150669395340, 1194155581653605, 1194163441266829, 1194254809270558,
+    1194263651335435, 1194298037143290, 1194309826563126, 1194365826307347,
+    1194371721017265, 1194397264760243, 1194398247211896, 1194409054180079,
+    1194449334697852, 1194456211859423, 1194509264248685, 1194551509669764,
+    1194553474573070, 1194559369282988, 1194566246444559, 1194569193799518,
+    1194608491865638, 1194636000511922, 1194640912770187, 1194660561803247,
+    1194682175739613, 1194704772127632, 1194705754579285, 1194710666837550,
+    1194711649289203, 1194770596388383, 1194824631229298, 1194844280262358,
+    1194902244909885, 1194911086974762, 1194933683362781, 1195017191753286,
+    1195029963624775, 1195039788141305, 1195052560012794, 1195085963368996,
+    1195096770337179, 1195123296531810, 1195152770081400, 1195194033050826,
+    1195198945309091, 1195216629438845, 1195253962601659, 1195332558733899,
+    1195342383250429, 1195414102221098, 1195429821447546, 1195467154610360,
+    1195492698353338, 1195513329838051, 1195519224547969, 1195547715645906,
+    1195571294485578, 1195608627648392, 1195609610100045, 1195613539906657,
+    1195635153843023, 1195665609844266, 1195713749975263, 1195715714878569,
+    1195726521846752, 1195797258365768, 1195816907398828, 1195826731915358,
+    1195901398240986, 1195906310499251, 1195927924435617, 1195928906887270,
+    1195957397985207, 1196013397729428, 1196029116955876, 1196044836182324,
+    1196100835926545, 1196107713088116, 1196148976057542, 1196164695283990,
+    1196169607542255, 1196185326768703, 1196230519544741, 1196246238771189,
+    1196280624579044, 1196281607030697, 1196284554385656, 1196293396450533,
+    1196314027935246, 1196316975290205, 1196352343549713, 1196365115421202,
+    1196380834647650, 1196385746905915, 1196389676712527, 1196426027423688,
+    1196447641360054, 1196449606263360, 1196470237748073, 1196485956974521,
+    1196490869232786, 1196491851684439, 1196494799039398, 1196502658652622,
+    1196505606007581, 1196506588459234, 1196518377879070, 1196530167298906,
+    1196532132202212, 1196553746138578, 1196561605751802, 1196594026656351,
+    1196601886269575, 1196605816076187, 1196609745882799, 1196615640592717,
+    1196616623044370, 1196626447560900, 1196630377367512, 1196631359819165,
+    1196639219432389, 1196650026400572, 1196651008852225, 1196653956207184,
+    1196654938658837, 1196655921110490, 1196691289369998, 1196699148983222,
+    1196700131434875, 1196701113886528, 1196710938403058, 1196711920854711,
+    1196725675177853, 1196736482146036, 1196744341759260, 1196749254017525,
+    1196764973243973, 1196765955695626, 1196766938147279, 1196792481890257,
+    1196798376600175, 1196802306406787, 1196810166020011, 1196811148471664,
+    1196846516731172, 1196875007829109, 1196875990280762, 1196876972732415,
+    1196877955184068, 1196903498927046, 1196922165508453, 1196933954928289,
+    1196946726799778, 1196953603961349, 1196967358284491, 1197005673898958,
+    1197027287835324, 1197067568353097, 1197069533256403, 1197075427966321,
+    1197076410417974, 1197092129644422, 1197104901515911, 1197124550548971,
+    1197135357517154, 1197138304872113, 1197145182033684, 1197164831066744,
+    1197165813518397, 1197167778421703, 1197168760873356, 1197172690679968,
+    1197173673131621, 1197189392358069, 1197205111584517, 1197212971197741,
+    1197225743069230, 1197233602682454, 1197239497392372, 1197245392102290,
+    1197267006038656, 1197273883200227, 1197279777910145, 1197298444491552,
+    1197307286556429, 1197313181266347, 1197323005782877, 1197339707460978,
+    1197343637267590, 1197348549525855, 1197355426687426, 1197374093268833,
+    1197379005527098, 1197380970430404, 1197394724753546, 1197396689656852,
+    1197423215851483, 1197435005271319, 1197435987722972, 1197452689401073,
+    1197461531465950, 1197466443724215, 1197467426175868, 1197472338434133,
+    1197476268240745, 1197489040112234, 1197495917273805, 1197501811983723,
+    1197503776887029, 1197522443468436, 1197536197791578, 1197553881921332,
+    1197555846824638, 1197590232632493, 1197647214828367, 1197674723474651,
+    1197686512894487, 1197697319862670, 1197703214572588, 1197716968895730,
+    1197738582832096, 1197756266961850, 1197758231865156, 1197780828253175,
+    1197789670318052, 1197793600124664, 1197804407092847, 1197822091222601,
+    1197826021029213, 1197829950835825, 1197833880642437, 1197836827997396,
+    1197839775352355, 1197843705158967, 1197850582320538, 1197854512127150,
+    1197868266450292, 1197871213805251, 1197881038321781, 1197913459226330,
+    1197934090711043, 1197941950324267, 1197958652002368, 1197968476518898,
+    1197982230842040, 1198001879875100, 1198006792133365, 1198028406069731,
+    1198034300779649, 1198075563749075, 1198081458458993, 1198087353168911,
+    1198090300523870, 1198108967105277, 1198134510848255, 1198165949301151,
+    1198173808914375, 1198177738720987, 1198184615882558, 1198188545689170,
+    1198190510592476, 1198192475495782, 1198213106980495, 1198222931497025,
+    1198225878851984, 1198228826206943, 1198259282208186, 1198269106724716,
+    1198271071628022, 1198275983886287, 1198276966337940, 1198286790854470,
+    1198293668016041, 1198320194210672, 1198326088920590, 1198343773050344,
+    1198362439631751, 1198378158858199, 1198384053568117, 1198386018471423,
+    1198397807891259, 1198411562214401, 1198423351634237, 1198438088409032,
+    1198450860280521, 1198476404023499, 1198513737186313, 1198517666992925,
+    1198518649444578, 1198531421316067, 1198549105445821, 1198553035252433,
+    1198561877317310, 1198565807123922, 1198567772027228, 1198569736930534,
+    1198575631640452, 1198580543898717, 1198618859513184, 1198638508546244,
+    1198668964547487, 1198689596032200, 1198713174871872, 1198736753711544,
+    1198740683518156, 1198747560679727, 1198750508034686, 1198757385196257,
+    1198767209712787, 1198770157067746, 1198802577972295, 1198832051521885,
+    1198861525071475, 1198888051266106, 1198889033717759, 1198894928427677,
+    1198918507267349, 1198930296687185, 1198932261590491, 1198960752688428,
+    1198970577204958, 1198993173592977, 1198994156044630, 1198999068302895,
+    1199029524304138, 1199031489207444, 1199035419014056, 1199039348820668,
+    1199043278627280, 1199044261078933, 1199048190885545, 1199053103143810,
+    1199064892563646, 1199074717080176, 1199099278371501, 1199129734372744,
+    1199181804310353, 1199186716568618, 1199200470891760, 1199264330249205,
+    1199272189862429, 1199280049475653, 1199285944185571, 1199335066768221,
+    1199351768446322, 1199361592962852, 1199362575414505, 1199363557866158,
+    1199389101609136, 1199394996319054, 1199397943674013, 1199401873480625,
+    1199412680448808, 1199429382126909, 1199446083805010, 1199463767934764,
+    1199464750386417, 1199474574902947, 1199482434516171, 1199485381871130,
+    1199503066000884, 1199504048452537, 1199507978259149, 1199564960455023,
+    1199585591939736, 1199590504198001, 1199591486649654, 1199597381359572,
+    1199622925102550, 1199628819812468, 1199636679425692, 1199683837105036,
+    1199687766911648, 1199689731814954, 1199690714266607, 1199703486138096,
+    1199722152719503, 1199727064977768, 1199773240205459, 1199794854141825,
+    1199831204852986, 1199858713499270, 1199859695950923, 1199862643305882,
+    1199879344983983, 1199887204597207, 1199890151952166, 1199897029113737,
+    1199931414921592, 1199947134148040, 1199996256730690, 1200005098795567,
+    1200015905763750, 1200059133636482, 1200075835314583, 1200078782669542,
+    1200094501895990, 1200097449250949, 1200098431702602, 1200105308864173,
+    1200114150929050, 1200132817510457, 1200134782413763, 1200144606930293,
+    1200164255963353, 1200173098028230, 1200207483836085, 1200239904740634,
+    1200248746805511, 1200280185258407, 1200322430679486, 1200330290292710,
+    1200359763842300, 1200367623455524, 1200384325133625, 1200388254940237,
+    1200401026811726, 1200409868876603, 1200429517909663, 1200451131846029,
+    1200462921265865, 1200477658040660, 1200489447460496, 1200494359718761,
+    1200512043848515, 1200554289269594, 1200555271721247, 1200558219076206,
+    1200570990947695, 1200585727722490, 1200600464497285, 1200648604628282,
+    1200709516630768, 1200715411340686, 1200725235857216, 1200736042825399,
+    1200740955083664, 1200743902438623, 1200747832245235, 1200753726955153,
+    1200762569020030, 1200791060117967, 1200812674054333, 1200845094958882,
+    1200860814185330, 1200882428121696, 1200922708639469, 1200939410317570,
+    1200963971608895, 1200969866318813, 1200979690835343, 1200991480255179,
+    1201009164384933, 1201013094191545, 1201016041546504, 1201054357160971,
+    1201055339612624, 1201068111484113, 1201089725420479, 1201109374453539,
+    1201127058583293, 1201145725164700, 1201176181165943, 1201181093424208,
+    1201192882844044, 1201195830199003, 1201252812394877, 1201259689556448,
+    1201272461427937, 1201286215751079, 1201287198202732, 1201296040267609,
+    1201327478720505, 1201346145301912, 1201403127497786, 1201443408015559,
+    1201469934210190, 1201483688533332, 1201485653436638, 1201550495245736,
+    1201552460149042, 1201566214472184, 1201576038988714, 1201590775763509,
+    1201599617828386, 1201603547634998, 1201641863249465, 1201661512282525,
+    1201662494734178, 1201666424540790, 1201704740155257, 1201719476930052,
+    1201729301446582, 1201731266349888, 1201779406480885, 1201804950223863,
+    1201816739643699, 1201852107903207, 1201853090354860, 1201873721839573,
+    1201877651646185, 1201944458358589, 1201971967004873, 1202029931652400,
+    1202053510492072, 1202086913848274, 1202133089075965, 1202135053979271,
+    1202154703012331, 1202157650367290, 1202224457079694, 1202225439531347,
+    1202243123661101, 1202260807790855, 1202289298888792, 1202300105856975,
+    1202312877728464, 1202335474116483, 1202370842375991, 1202440596443354,
+    1202445508701619, 1202539824060307, 1202563402899979, 1202584034384692,
+    1202608595676017, 1202612525482629, 1202620385095853, 1202629227160730,
+    1202635121870648, 1202648876193790, 1202657718258667, 1202680314646686,
+    1202688174259910, 1202694068969828, 1202701928583052, 1202703893486358,
+    1202713718002888, 1202717647809500, 1202719612712806, 1202748103810743,
+    1202805086006617, 1202814910523147, 1202818840329759, 1202843401621084,
+    1202885647042163, 1202887611945469, 1202922980204977, 1202926910011589,
+    1202959330916138, 1202964243174403, 1202987822014075, 1202992734272340,
+    1203199049119470, 1203210838539306, 1203249154153773, 1203257996218650,
+    1203270768090139, 1203363118545521, 1203393574546764, 1203503609131900,
+    1203519328358348, 1203523258164960, 1203537012488102, 1203585152619099,
+    1203602836748853, 1203637222556708, 1203653924234809, 1203655889138115,
+    1203676520622828, 1203697152107541, 1203732520367049, 1203758064110027,
+    1203787537659617, 1203791467466229, 1203803256886065, 1203846484758797,
+    1203892659986488, 1203900519599712, 1203917221277813, 1203933922955914,
+    1203944729924097, 1203983045538564, 1204010554184848, 1204086202962129,
+    1204088167865435, 1204131395738167, 1204161851739410, 1204164799094369,
+    1204188377934041, 1204210974322060, 1204260096904710, 1204284658196035,
+    1204293500260912, 1204313149293972, 1204339675488603, 1204350482456786,
+    1204403534846048, 1204434973298944, 1204456587235310, 1204482130978288,
+    1204501780011348, 1204522411496061, 1204533218464244, 1204583323498547,
+    1204585288401853, 1204598060273342, 1204704165051866, 1204744445569639,
+    1204788655894024, 1204789638345677, 1204806340023778, 1204813217185349,
+    1204815182088655, 1204824024153532, 1204827953960144, 1204843673186592,
+    1204877076542794, 1204921286867179, 1204954690223381, 1204995953192807,
+    1205007742612643, 1205013637322561, 1205046058227110, 1205054900291987,
+    1205333916561439, 1205337846368051, 1205339811271357, 1205396793467231,
+    1205400723273843, 1205442968694922, 1205459670373023, 1205576582119730,
+    1205581494377995, 1205684651801560, 1205704300834620, 1205731809480904,
+    1205732791932557, 1205803528451573, 1205862475550753, 1206043246654905,
+    1206044229106558, 1206050123816476, 1206051106268129, 1206067807946230,
+    1206087456979290, 1206089421882596, 1206107106012350, 1206114965625574,
+    1206188649499549, 1206203386274344, 1206204368725997, 1206217140597486,
+    1206276087696666, 1206299666536338, 1206391034540067, 1206398894153291,
+    1206404788863209, 1206423455444616, 1206431315057840, 1206496156866938,
+    1206503034028509, 1206512858545039, 1206521700609916, 1206525630416528,
+    1206535454933058, 1206539384739670, 1206546261901241, 1206551174159506,
+    1206563946030995, 1206585559967361, 1206593419580585, 1206643524614888,
+    1206654331583071, 1206736857521923, 1206745699586800, 1206807594040939,
+    1206837067590529, 1206899944496321, 1206937277659135, 1206987382693438,
+    1206989347596744, 1206997207209968, 1207008996629804, 1207027663211211,
+    1207045347340965, 1207077768245514, 1207162259087672, 1207246749929830,
+    1207342047740171, 1207344995095130, 1207346959998436, 1207349907353395,
+    1207393135226127, 1207437345550512, 1207487450584815, 1207578818588544,
+    1207695730335251, 1207752712531125, 1207756642337737, 1207762537047655,
+    1207792993048898, 1207800852662122, 1207817554340223, 1207834256018324,
+    1207836220921630, 1207864712019567, 1207866676922873, 1207892220665851,
+    1207947237958419, 1208027798993965, 1208041553317107, 1208051377833637,
+    1208069061963391, 1208076921576615, 1208077904028268, 1208105412674552,
+    1208146675643978, 1208176149193568, 1208234113841095, 1208270464552256,
+    1208279306617133, 1208284218875398, 1208323516941518, 1208375586879127,
+    1208376569330780, 1208381481589045, 1208431586623348, 1208461060172938,
+    1208483656560957, 1208502323142364, 1208596638501052, 1208602533210970,
+    1208619234889071, 1208636919018825, 1208637901470478, 1208666392568415,
+    1208733199280819, 1208749900958920, 1208756778120491, 1208811795413059,
+    1208825549736201, 1208912987933318, 1208953268451091, 1208970952580845,
+    1208979794645722, 1208980777097375, 1208990601613905, 1209047583809779,
+    1209126179942019, 1209147793878385, 1209154671039956, 1209189056847811,
+    1209225407558972, 1209227372462278, 1209308915949477, 1209379652468493,
+    1209385547178411, 1209389476985023, 1209397336598247, 1209410108469736,
+    1209446459180897, 1209488704601976, 1209513265893301, 1209522107958178,
+    1209523090409831, 1209548634152809, 1209666528351169, 1209679300222658,
+    1209771650678040, 1209797194421018, 1209811931195813, 1209827650422261,
+    1209850246810280, 1209851229261933, 1209862036230116, 1209866948488381,
+    1209909193909460, 1209913123716072, 1209920983329296, 1209933755200785,
+    1209945544620621, 1209972070815252, 1209999579461536, 1210065403722287,
+    1210085052755347, 1210130245531385, 1210136140241303, 1210191157533871,
+    1210203929405360, 1210214736373543, 1210222595986767, 1210262876504540,
+    1210274665924376, 1210311999087190, 1210316911345455, 1210322806055373,
+    1210382735606206, 1210405331994225, 1210447577415304, 1210493752642995,
+    1210504559611178, 1210553682193828, 1210611646841355, 1210754102331040,
+    1210761961944264, 1210785540783936, 1210801260010384, 1210818944140138,
+    1210875926336012, 1210891645562460, 1210893610465766, 1210896557820725,
+    1210908347240561, 1210934873435192, 1210967294339741, 1210969259243047,
+    1210973189049659, 1211027223890574, 1211036065955451, 1211059644795123,
+    1211185398606707, 1211248275512499, 1211276766610436, 1211305257708373,
+    1211306240160026, 1211331783903004, 1211427081713345, 1211447713198058,
+    1211450660553017, 1211462449972853, 1211464414876159, 1211465397327812,
+    1211475221844342, 1211495853329055, 1211509607652197, 1211513537458809,
+    1211524344426992, 1211549888169970, 1211592133591049, 1211641256173699,
+    1211650098238576, 1211678589336513, 1211707080434450, 1211708062886103,
+    1211711010241062, 1211728694370816, 1211729676822469, 1211730659274122,
+    1211758167920406, 1211781746760078, 1211816132567933, 1211868202505542,
+    1211888833990255, 1211908483023315, 1211914377733233, 1211929114508028,
+    1211935009217946, 1211953675799353, 1211959570509271, 1211993956317126,
+    1212050938513000, 1212074517352672, 1212097113740691, 1212122657483669,
+    1212123639935322, 1212133464451852, 1212189464196073, 1212215990390704,
+    1212218937745663, 1212246446391947, 1212278867296496, 1212323077620881,
+    1212339779298982, 1212345674008900, 1212438024464282, 1212471427820484,
+    1212502866273380, 1212506796079992, 1212512690789910, 1212540199436194,
+    1212560830920907, 1212587357115538, 1212594234277109, 1212599146535374,
+    1212616830665128, 1212630584988270, 1212715075830428, 1212727847701917,
+    1212729812605223, 1212748479186630, 1212756338799854, 1212797601769280,
+    1212799566672586, 1212813320995728, 1212888969773009, 1212894864482927,
+    1212919425774252, 1212930232742435, 1212940057258965, 1212947916872189,
+    1212972478163514, 1212999004358145, 1213006863971369, 1213012758681287,
+    1213033390166000, 1213071705780467, 1213099214426751, 1213106091588322,
+    1213129670427994, 1213142442299483, 1213159143977584, 1213186652623868,
+    1213199424495357, 1213222020883376, 1213235775206518, 1213240687464783,
+    1213266231207761, 1213349739598266, 1213358581663143, 1213361529018102,
+    1213367423728020, 1213395914825957, 1213444054956954, 1213469598699932,
+    1213520686185888, 1213547212380519, 1213568826316885, 1213581598188374,
+    1213626790964412, 1213630720771024, 1213687702966898, 1213691632773510,
+    1213708334451611, 1213737808001201, 1213744685162772, 1213768264002444,
+    1213792825293769, 1213811491875176, 1213828193553277, 1213839982973113,
+    1213901877427252, 1213917596653700, 1213940193041719, 1213962789429738,
+    1213992262979328, 1213994227882634, 1214028613690489, 1214034508400407,
+    1214046297820243, 1214091490596281, 1214166156921909, 1214192683116540,
+    1214196612923152, 1214204472536376, 1214205454988029, 1214219209311171,
+    1214238858344231, 1214373454220692, 1214402927770282, 1214426506609954,
+    1214428471513260, 1214450085449626, 1214569944551292, 1214615137327330,
+    1214626926747166, 1214657382748409, 1214677031781469, 1214708470234365,
+    1214724189460813, 1214727136815772, 1214728119267425, 1214765452430239,
+    1214790013721564, 1214810645206277, 1214821452174460, 1214850925724050,
+    1214875487015375, 1214893171145129, 1214929521856290, 1214940328824473,
+    1214941311276126, 1214954083147615, 1215006153085224, 1215026784569937,
+    1215045451151344, 1215067065087710, 1215075907152587, 1215081801862505,
+    1215100468443912, 1215154503284827, 1215156468188133, 1215170222511275,
+    1215175134769540, 1215213450384007, 1215218362642272, 1215219345093925,
+    1215231134513761, 1215245871288556, 1215251765998474, 1215272397483187,
+    1215291064064594, 1215317590259225, 1215333309485673, 1215339204195591,
+    1215368677745181, 1215390291681547, 1215406993359648, 1215409940714607,
+    1215432537102626, 1215449238780727, 1215465940458828, 1215476747427011,
+    1215487554395194, 1215527834912967, 1215586782012147, 1215606431045207,
+    1215639834401409, 1215674220209264, 1215677167564223, 1215682079822488,
+    1215706641113813, 1215734149760097, 1215744956728280, 1215786219697706,
+    1215845166796886, 1215852043958457, 1215893306927883, 1215900184089454,
+    1215903131444413, 1216006288867978, 1216022008094426, 1216025937901038,
+    1216055411450628, 1216058358805587, 1216099621775013, 1216161516229152,
+    1216167410939070, 1216175270552294, 1216237165006433, 1216245024619657,
+    1216270568362635, 1216278427975859, 1216290217395695, 1216302989267184,
+    1216316743590326, 1216321655848591, 1216330497913468, 1216350146946528,
+    1216356041656446, 1216364883721323, 1216366848624629, 1216410076497361,
+    1216429725530421, 1216485725274642, 1216510286565967, 1216539760115557,
+    1216543689922169, 1216555479342005, 1216586917794901, 1216621303602756,
+    1216626215861021, 1216666496378794, 1216671408637059, 1216674355992018,
+    1216685162960201, 1216706776896567, 1216790285287072, 1216801092255255,
+    1216810916771785, 1216899337420555, 1216918986453615, 1216947477551552,
+    1216966144132959, 1216998565037508, 1217007407102385, 1217138073172234,
+    1217198002723067, 1217242213047452, 1217263826983818, 1217289370726796,
+    1217345370471017, 1217358142342506, 1217375826472260, 1217494703122273,
+    1217516317058639, 1217541860801617, 1217547755511535, 1217554632673106,
+    1217581158867737, 1217583123771043, 1217608667514021, 1217687263646261,
+    1217705930227668, 1217715754744198, 1217780596553296, 1217786491263214,
+    1217813017457845, 1217825789329334, 1217828736684293, 1217878841718596,
+    1217928946752899, 1217961367657448, 1217974139528937, 1218012455143404,
+    1218063542629360, 1218157857988048, 1218177507021108, 1218183401731026,
+    1218185366634332, 1218240383926900, 1218244313733512, 1218286559154591,
+    1218294418767815, 1218326839672364, 1218336664188894, 1218337646640547,
+    1218345506253771, 1218355330770301, 1218370067545096, 1218390699029809,
+    1218429997095929, 1218456523290560, 1218504663421557, 1218506628324863,
+    1218527259809576, 1218531189616188, 1218624522523223, 1218627469878182,
+    1218635329491406, 1218664803040996, 1218714908075299, 1218750276334807,
+    1218773855174479, 1218785644594315, 1218805293627375, 1218807258530681,
+    1218828872467047, 1218863258274902, 1218869152984820, 1218872100339779,
+    1218978205118303, 1219065643315420, 1219130485124518, 1219176660352209,
+    1219198274288575, 1219250344226184, 1219264098549326, 1219311256228670,
+    1219337782423301, 1219346624488178, 1219371185779503, 1219398694425787,
+    1219443887201825, 1219578483078286, 1219579465529939, 1219610903982835,
+    1219611886434488, 1219657079210526, 1219669851082015, 1219706201793176,
+    1219715043858053, 1219736657794419, 1219774973408886, 1219789710183681,
+    1219808376765088, 1219816236378312, 1219819183733271, 1219851604637820,
+    1219866341412615, 1219923323608489, 1219934130576672, 1219957709416344,
+    1219979323352710, 1220004867095688, 1220014691612218, 1220075603614704,
+    1220087393034540, 1220104094712641, 1220166971618433, 1220199392522982,
+    1220216094201083, 1220287813171752, 1220299602591588, 1220395882853582,
+    1220449917694497, 1220456794856068, 1220461707114333, 1220468584275904,
+    1220474478985822, 1220478408792434, 1220518689310207, 1220550127763103,
+    1220576653957734, 1220585496022611, 1220606127507324, 1220659179896586,
+    1220681776284605, 1220713214737501, 1220740723383785, 1220790828418088,
+    1220819319516025, 1220823249322637, 1220832091387514, 1220837003645779,
+    1220845845710656, 1220880231518511, 1220920512036284, 1220949985585874,
+    1220954897844139, 1220998125716871, 1221037423782991, 1221046265847868,
+    1221091458623906, 1221093423527212, 1221119949721843, 1221138616303250,
+    1221171037207799, 1221177914369370, 1221200510757389, 1221212300177225,
+    1221229984306979, 1221247668436733, 1221304650632607, 1221358685473522,
+    1221359667925175, 1221389141474765, 1221399948442948, 1221407808056172,
+    1221414685217743, 1221427457089232, 1221440228960721, 1221456930638822,
+    1221462825348740, 1221469702510311, 1221482474381800, 1221505070769819,
+    1221564017868999, 1221565982772305, 1221589561611977, 1221606263290078,
+    1221642614001239, 1221670122647523, 1221732017101662, 1221741841618192,
+    1221763455554558, 1221795876459107, 1221815525492167, 1222015945629379,
+    1222166260732288, 1222235032347998, 1222239944606263, 1222344084481481,
+    1222359803707929, 1222373558031071, 1222383382547601, 1222396154419090,
+    1222420715710415, 1222442329646781, 1222483592616207, 1222499311842655,
+    1222548434425305, 1222560223845141, 1222570048361671, 1222576925523242,
+    1222609346427791, 1222634890170769, 1222636855074075, 1222650609397217,
+    1222693837269949, 1222702679334826, 1222703661786479, 1222704644238132,
+    1222705626689785, 1222707591593091, 1222746889659211, 1222759661530700,
+    1222769486047230, 1222827450694757, 1222851029534429, 1222883450438978,
+    1222915871343527, 1222927660763363, 1222934537924934, 1222936502828240,
+    1222968923732789, 1222985625410890, 1222989555217502, 1223008221798909,
+    1223043590058417, 1223067168898089, 1223085835479496, 1223087800382802,
+    1223169343870001, 1223171308773307, 1223253834712159, 1223305904649768,
+    1223373693813825, 1223394325298538, 1223436570719617, 1223452289946065,
+    1223471938979125, 1223474886334084, 1223499447625409, 1223524008916734,
+    1223532850981611, 1223540710594835, 1223559377176242, 1223575096402690,
+    1223579026209302, 1223581973564261, 1223588850725832, 1223633061050217,
+    1223667446858072, 1223679236277908, 1223714604537416, 1223769621829984,
+    1223824639122552, 1223844288155612, 1223845270607265, 1223863937188672,
+    1223866884543631, 1223921901836199, 1223923866739505, 1223966112160584,
+    1223981831387032, 1224046673196130, 1224060427519272, 1224085971262250,
+    1224134111393247, 1224147865716389, 1224168497201102, 1224180286620938,
+    1224243163526730, 1224286391399462, 1224292286109380, 1224298180819298,
+    1224334531530459, 1224360075273437, 1224368917338314, 1224467162503614,
+    1224532986764365, 1224555583152384, 1224558530507343, 1224604705735034,
+    1224612565348258, 1224678389609009, 1224686249222233, 1224697056190416,
+    1224765827806126, 1224786459290839, 1224799231162328, 1224809055678858,
+    1224842459035060, 1224914178005729, 1224919090263994, 1224956423426808,
+    1225072352721862, 1225080212335086, 1225132282272695, 1225145054144184,
+    1225159790918979, 1225233474792954, 1225247229116096, 1225282597375604,
+    1225304211311970, 1225316000731806, 1225320912990071, 1225374947830986,
+    1225382807444210, 1225387719702475, 1225408351187188, 1225428000220248,
+    1225469263189674, 1225510526159100, 1225610736227706, 1225633332615725,
+    1225645122035561, 1225650034293826, 1225654946552091, 1225671648230192,
+    1225672630681845, 1225695227069864, 1225697191973170, 1225716841006230,
+    1225722735716148, 1225795437138470, 1225827858043019, 1225828840494672,
+    1225847507076079, 1225877963077322, 1225878945528975, 1225881892883934,
+    1225939857531461, 1225948699596338, 1226035155341802, 1226082313021146,
+    1226092137537676, 1226152067088509, 1226156979346774, 1226157961798427,
+    1226264066576951, 1226281750706705, 1226282733158358, 1226285680513317,
+    1226306311998030, 1226340697805885, 1226394732646800, 1226407504518289,
+    1226499854973671, 1226503784780283, 1226539153039791, 1226561749427810,
+    1226624626333602, 1226628556140214, 1226732696015432, 1226776906339817,
+    1226847642858833, 1226858449827016, 1226863362085281, 1226889888279912,
+    1226892835634871, 1226955712540663, 1226967501960499, 1227028413962985,
+    1227033326221250, 1227038238479515, 1227039220931168, 1227088343513818,
+    1227090308417124, 1227132553838203, 1227163009839446, 1227277956682847,
+    1227287781199377, 1227316272297314, 1227345745846904, 1227415499914267,
+    1227421394624185, 1227423359527491, 1227555990500646, 1227558937855605,
+    1227604130631643, 1227608060438255, 1227636551536192, 1227653253214293,
+    1227656200569252, 1227657183020905, 1227687639022148, 1227693533732066,
+    1227713182765126, 1227717112571738, 1227773112315959, 1227794726252325,
+    1227826164705221, 1227830094511833, 1227881181997789, 1227906725740767,
+    1227918515160603, 1227930304580439, 1227966655291600, 1228013812970944,
+    1228044268972187, 1228053111037064, 1228109110781285, 1228150373750711,
+    1228179847300301, 1228204408591626, 1228220127818074, 1228236829496175,
+    1228386162147431, 1228400898922226, 1228439214536693, 1228455916214794,
+    1228537459701993, 1228577740219766, 1228607213769356, 1228609178672662,
+    1228612126027621, 1228670090675148, 1228677950288372, 1228695634418126,
+    1228720195709451, 1228725107967716, 1228754581517306, 1228795844486732,
+    1228806651454915, 1228813528616486, 1228826300487975, 1228839072359464,
+    1228899001910297, 1228902931716909, 1228924545653275, 1228931422814846,
+    1229011983850392, 1229074860756184, 1229129878048752, 1229140685016935,
+    1229216333794216, 1229235000375623, 1229238930182235, 1229277245796702,
+    1229320473669434, 1229333245540923, 1229346017412412, 1229380403220267,
+    1229382368123573, 1229447209932671, 1229458016900854, 1229459981804160,
+    1229472753675649, 1229511069290116, 1229518928903340, 1229622086326905,
+    1229649594973189, 1229687910587656, 1229710506975675, 1229711489427328,
+    1229748822590142, 1229755699751713, 1229822506464117, 1229971839115373,
+    1230023909052982, 1230025873956288, 1230102505185222, 1230104470088528,
+    1230153592671178, 1230167346994320, 1230184048672421, 1230189943382339,
+    1230194855640604, 1230203697705481, 1230234153706724, 1230309802484005,
+    1230323556807147, 1230342223388554, 1230372679389797, 1230393310874510,
+    1230543625977419, 1230571134623703, 1230621239658006, 1230699835790246,
+    1230748958372896, 1230756817986120, 1230781379277445, 1230797098503893,
+    1230807905472076, 1230896326120846, 1230953308316720, 1230966080188209,
+    1230983764317963, 1230990641479534, 1231060395546897, 1231117377742771,
+    1231135061872525, 1231161588067156, 1231193026520052, 1231202851036582,
+    1231207763294847, 1231331552203125, 1231336464461390, 1231349236332879,
+    1231371832720898, 1231397376463876, 1231418990400242, 1231440604336608,
+    1231460253369668, 1231472042789504, 1231473025241157, 1231496604080829,
+    1231510358403971, 1231523130275460, 1231615480730842, 1231683269894899,
+    1231703901379612, 1231709796089530, 1231767760737057, 1231825725384584,
+    1231838497256073, 1231880742677152, 1231894497000294, 1231901374161865,
+    1231921023194925, 1231925935453190, 1231984882552370, 1231996671972206,
+    1232081162814364, 1232128320493708, 1232211828884213, 1232230495465620,
+    1232286495209841, 1232291407468106, 1232302214436289, 1232320881017696,
+    1232380810568529, 1232426985796220, 1232437792764403, 1232516388896643,
+    1232537020381356, 1232594985028883, 1232611686706984, 1232662774192940,
+    1232671616257817, 1232674563612776, 1232808177037584, 1232819966457420,
+    1232820948909073, 1232857299620234, 1232866141685111, 1232870071491723,
+    1232878913556600, 1232901509944619, 1232923123880985, 1232973228915288,
+    1232990913045042, 1233017439239673, 1233049860144222, 1233055754854140,
+    1233126491373156, 1233173649052500, 1233205087505396, 1233210982215314,
+    1233228666345068, 1233235543506639, 1233319051897144, 1233360314866570,
+    1233385858609548, 1233423191772362, 1233528314099233, 1233534208809151,
+    1233595120811637, 1233609857586432, 1233614769844697, 1233698278235202,
+    1233728734236445, 1233742488559587, 1233759190237688, 1233767049850912,
+    1233768032302565, 1233775891915789, 1233809295271991, 1233813225078603,
+    1233822067143480, 1233845645983152, 1233848593338111, 1233858417854641,
+    1233874137081089, 1233903610630679, 1233950768310023, 1233954698116635,
+    1233981224311266, 1234038206507140, 1234181644448478, 1234194416319967,
+    1234197363674926, 1234274977355513, 1234300521098491, 1234309363163368,
+    1234334906906346, 1234378134779078, 1234401713618750, 1234432169619993,
+    1234446906394788, 1234460660717930, 1234519607817110, 1234523537623722,
+    1234527467430334, 1234570695303066, 1234607046014227, 1234628659950593,
+    1234646344080347, 1234663045758448, 1234682694791508, 1234699396469609,
+    1234729852470852, 1234730834922505, 1234744589245647, 1234791746924991,
+    1234800588989868, 1234802553893174, 1234809431054745, 1234810413506398,
+    1234853641379130, 1234858553637395, 1234863465895660, 1234864448347313,
+    1234916518284922, 1234956798802695, 1234968588222531, 1234996096868815,
+    1235012798546916, 1235129710293623, 1235154271584948, 1235185710037844,
+    1235196517006027, 1235230902813882, 1235246622040330, 1235341919850671,
+    1235378270561832, 1235381217916791, 1235391042433321, 1235401849401504,
+    1235421498434564, 1235432305402747, 1235528585664741, 1235565918827555,
+    1235613076506899, 1235625848378388, 1235671041154426, 1235729988253606,
+    1235751602189972, 1235760444254849, 1235764374061461, 1235776163481297,
+    1235845917548660, 1235879320904862, 1235900934841228, 1235915671616023,
+    1235927461035859, 1235933355745777, 1236015881684629, 1236035530717689,
+    1236039460524301, 1236041425427607, 1236057144654055, 1236114126849929,
+    1236151460012743, 1236221214080106, 1236251670081349, 1236321424148712,
+    1236333213568548, 1236336160923507, 1236365634473097, 1236429493830542,
+    1236488440929722, 1236506125059476, 1236516932027659, 1236519879382618,
+    1236563107255350, 1236681983905363, 1236685913711975, 1236721281971483,
+    1236766474747521, 1236769422102480, 1236797913200417, 1236847035783067,
+    1236852930492985, 1236868649719433, 1236888298752493, 1236975736949610,
+    1237052368178544, 1237072999663257, 1237182051796740, 1237200718378147,
+    1237215455152942, 1237241981347573, 1237250823412450, 1237252788315756,
+    1237273419800469, 1237297981091794, 1237322542383119, 1237324507286425,
+    1237342191416179, 1237407033225277, 1237549488714962, 1237577979812899,
+    1237581909619511, 1237598611297612, 1237608435814142, 1237622190137284,
+    1237690961752994, 1237760715820357, 1237868785502187, 1237875662663758,
+    1237922820343102, 1237932644859632, 1238008293636913, 1238015170798484,
+    1238025977766667, 1238044644348074, 1238107521253866, 1238167450804699,
+    1238219520742308, 1238279450293141, 1238280432744794, 1238304011584466,
+    1238307941391078, 1238309906294384, 1238332502682403, 1238342327198933,
+    1238359028877034, 1238390467329930, 1238449414429110, 1238491659850189,
+    1238510326431596, 1238521133399779, 1238538817529533, 1238558466562593,
+    1238625273274997, 1238632150436568, 1238711729020461, 1238714676375420,
+    1238763798958070, 1238768711216335, 1238783447991130, 1238811939089067,
+    1238940640255610, 1238976008515118, 1239023166194462, 1239024148646115,
+    1239051657292399, 1239124358714721, 1239141060392822, 1239175446200677,
+    1239183305813901, 1239201972395308, 1239207867105226, 1239354252401523,
+    1239407304790785, 1239438743243681, 1239442673050293, 1239495725439555,
+    1239502602601126, 1239539935763940, 1239545830473858, 1239569409313530,
+    1239579233830060, 1239593970604855, 1239615584541221, 1239628356412710,
+    1239639163380893, 1239654882607341, 1239662742220565, 1239667654478830,
+    1239684356156931, 1239693198221808, 1239742320804458, 1239849408034635,
+    1239894600810673, 1239915232295386, 1239946670748282, 1239953547909853,
+    1239955512813159, 1240037056300358, 1240059652688377, 1240090108689620,
+    1240140213723923, 1240144143530535, 1240148073337147, 1240152985595412,
+    1240177546886737, 1240208002887980, 1240209967791286, 1240236493985917,
+    1240327861989646, 1240341616312788, 1240358317990889, 1240363230249154,
+    1240374037217337, 1240382879282214, 1240411370380151, 1240519440061981,
+    1240526317223552, 1240547931159918, 1240566597741325, 1240590176580997,
+    1240606878259098, 1240618667678934, 1240632422002076, 1240655018390095,
+    1240695298907868, 1240712983037622, 1240716912844234, 1240723790005805,
+    1240733614522335, 1240775859943414, 1240834807042594, 1240889824335162,
+    1240922245239711, 1240990034403768, 1241033262276500, 1241048981502948,
+    1241070595439314, 1241075507697579, 1241117753118658, 1241167858152961,
+    1241207156219081, 1241208138670734, 1241248419188507, 1241250384091813,
+    1241260208608343, 1241267085769914, 1241281822544709, 1241283787448015,
+    1241327015320747, 1241368278290173, 1241389892226539, 1241449821777372,
+    1241510733779858, 1241517610941429, 1241544137136060, 1241546102039366,
+    1241592277267057, 1241599154428628, 1241638452494748, 1241674803205909,
+    1241690522432357, 1241693469787316, 1241720978433600, 1241730802950130,
+    1241731785401783, 1241866381278244, 1241889960117916, 1241920416119159,
+    1241921398570812, 1241952837023708, 1241979363218339, 1242020626187765,
+    1242088415351822, 1242111994191494, 1242162099225797, 1242189607872081,
+    1242227923486548, 1242267221552668, 1242297677553911, 1242307502070441,
+    1242336975620031, 1242346800136561, 1242362519363009, 1242376273686151,
+    1242428343623760, 1242449957560126, 1242543290467161, 1242551150080385,
+    1242570799113445, 1242602237566341, 1242609114727912, 1242681816150234,
+    1242696552925029, 1242735850991149, 1242799710348594, 1242813464671736,
+    1242832131253143, 1242846868027938, 1242868481964304, 1242897955513894,
+    1242920551901913, 1242954937709768, 1242959849968033, 1242980481452746,
+    1243008972550683, 1243032551390355, 1243124901845737, 1243144550878797,
+    1243165182363510, 1243273252045340, 1243320409724684, 1243345953467662,
+    1243355777984192, 1243364620049069, 1243367567404028, 1243379356823864,
+    1243396058501965, 1243441251278003, 1243465812569328, 1243473672182552,
+    1243567005089587, 1243586654122647, 1243587636574300, 1243588619025953,
+    1243600408445789, 1243603355800748, 1243638724060256, 1243652478383398,
+    1243715355289190, 1243726162257373, 1243757600710269, 1243773319936717,
+    1243779214646635, 1243818512712755, 1243820477616061, 1243863705488793,
+    1243877459811935, 1243902021103260, 1243965880460705, 1243974722525582,
+    1244003213623519, 1244010090785090, 1244015985495008, 1244022862656579,
+    1244023845108232, 1244050371302863, 1244064125626005, 1244087704465677,
+    1244102441240472, 1244161388339652, 1244199703954119, 1244216405632220,
+    1244292054409501, 1244329387572315, 1244341176992151, 1244361808476864,
+    1244366720735129, 1244376545251659, 1244399141639678, 1244412895962820,
+    1244540614677710, 1244590719712013, 1244661456231029, 1244664403585988,
+    1244693877135578, 1244700754297149, 1244705666555414, 1244733175201698,
+    1244757736493023, 1244768543461206, 1244771490816165, 1244827490560386,
+    1244884472756260, 1244924753274033, 1244954226823623, 1244967981146765,
+    1244986647728172, 1244987630179825, 1245086857796778, 1245167418832324,
+    1245195909930261, 1245205734446791, 1245212611608362, 1245217523866627,
+    1245258786836053, 1245312821676968, 1245327558451763, 1245363909162924,
+    1245395347615820, 1245407137035656, 1245441522843511, 1245456259618306,
+    1245477873554672, 1245518154072445, 1245563346848483, 1245648820142294,
+    1245652749948906, 1245654714852212, 1245661592013783, 1245674363885272,
+    1245693030466679, 1245694012918332, 1245700890079903, 1245705802338168,
+    1245724468919575, 1245787345825367, 1245802082600162, 1245841380666282,
+    1245869871764219, 1245871836667525, 1245941590734888, 1245952397703071,
+    1245987765962579, 1246008397447292, 1246049660416718, 1246054572674983,
+    1246133168807223, 1246141028420447, 1246199975519627, 1246207835132851,
+    1246224536810952, 1246275624296908, 1246360115139066, 1246363062494025,
+    1246393518495268, 1246417097334940, 1246435763916347, 1246516324951893,
+    1246534991533300, 1246640113860171, 1246652885731660, 1246661727796537,
+    1246666640054802, 1246692183797780, 1246702990765963, 1246733446767206,
+    1246736394122165, 1246770779930020, 1246797306124651, 1246861165482096,
+    1246868042643667, 1246909305613093, 1246926007291194, 1246929937097806,
+    1246962358002355, 1246982989487068, 1246998708713516, 1247046848844513,
+    1247088111813939, 1247119550266835, 1247165725494526, 1247215830528829,
+    1247226637497012, 1247250216336684, 1247266918014785, 1247271830273050,
+    1247335689630495, 1247338636985454, 1247343549243719, 1247389724471410,
+    1247409373504470, 1247446706667284, 1247627477771436, 1247628460223089,
+    1247651056611108, 1247662846030944, 1247669723192515, 1247704109000370,
+    1247721793130124, 1247724740485083, 1247748319324755, 1247772880616080,
+    1247795477004099, 1247855406554932, 1247856389006585, 1247886845007828,
+    1247898634427664, 1247903546685929, 1247962493785109, 1248025370690901,
+    1248062703853715, 1248245439861173, 1248344667478126, 1248404597028959,
+    1248424246062019, 1248433088126896, 1248444877546732, 1248463544128139,
+    1248528385937237, 1248551964776909, 1248571613809969, 1248582420778152,
+    1248612876779395, 1248627613554190, 1248631543360802, 1248700314976512,
+    1248746490204203, 1248759262075692, 1248760244527345, 1248767121688916,
+    1248784805818670, 1248787753173629, 1248803472400077, 1248808384658342,
+    1248888945693888, 1248976383891005, 1249005857440595, 1249012734602166,
+    1249172874221605, 1249221996804255, 1249233786224091, 1249234768675744,
+    1249324171776167, 1249339891002615, 1249346768164186, 1249375259262123,
+    1249394908295183, 1249417504683202, 1249432241457997, 1249435188812956,
+    1249439118619568, 1249452872942710, 1249511820041890, 1249545223398092,
+    1249550135656357, 1249562907527846, 1249569784689417, 1249580591657600,
+    1249607117852231, 1249622837078679, 1249717152437367, 1249797713472913,
+    1249803608182831, 1249816380054320, 1249842906248951, 1249892028831601,
+    1249899888444825, 1249923467284497, 1249985361738636, 1250002063416737,
+    1250005010771696, 1250070835032447, 1250093431420466, 1250111115550220,
+    1250149431164687, 1250167115294441, 1250214272973785, 1250261430653129,
+    1250425500079180, 1250426482530833, 1250435324595710, 1250436307047363,
+    1250601358925067, 1250637709636228, 1250641639442840, 1250669148089124,
+    1250674060347389, 1250769358157730, 1250780165125913, 1250852866548235,
+    1250921638163945, 1250936374938740, 1250947181906923, 1250985497521390,
+    1251024795587510, 1251034620104040, 1251054269137100, 1251105356623056,
+    1251129917914381, 1251169215980501, 1251185917658602, 1251205566691662,
+    1251242899854476, 1251292022437126, 1251298899598697, 1251309706566880,
+    1251315601276798, 1251352934439612, 1251355881794571, 1251356864246224,
+    1251372583472672, 1251410899087139, 1251450197153259, 1251467881283013,
+    1251476723347890, 1251508161800786, 1251527810833846, 1251547459866906,
+    1251578898319802, 1251583810578067, 1251601494707821, 1251630968257411,
+    1251655529548736, 1251667318968572, 1251692862711550, 1251774406198749,
+    1251809774458257, 1251880510977273, 1251892300397109, 1251909984526863,
+    1251910966978516, 1251913914333475, 1251914896785128, 1252021001563652,
+    1252024931370264, 1252039668145059, 1252052440016548, 1252093702985974,
+    1252097632792586, 1252116299373993, 1252122194083911, 1252161492150031,
+    1252171316666561, 1252195877957886, 1252203737571110, 1252216509442599,
+    1252225351507476, 1252235176024006, 1252240088282271, 1252255807508719,
+    1252269561831861, 1252272509186820, 1252278403896738, 1252309842349634,
+    1252318684414511, 1252335386092612, 1252350122867407, 1252362894738896,
+    1252459175000890, 1252514192293458, 1252620297071982, 1252624226878594,
+    1252643875911654, 1252774541981503, 1252832506629030, 1252855103017049,
+    1252919944826147, 1253019172443100, 1253048645992690, 1253085979155504,
+    1253122329866665, 1253195031288987, 1253202890902211, 1253218610128659,
+    1253219592580312, 1253325697358836, 1253326679810489, 1253346328843549,
+    1253402328587770, 1253433767040666, 1253452433622073, 1253498608849764,
+    1253546748980761, 1253677415050610, 1253702958793588, 1253755028731197,
+    1253776642667563, 1253949554158491, 1253956431320062, 1253966255836592,
+    1253976080353122, 1253981975063040, 1254042887065526, 1254071378163463,
+    1254094957003135, 1254099869261400, 1254112641132889, 1254189272361823,
+    1254205974039924, 1254276710558940, 1254304219205224, 1254361201401098,
+    1254370043465975, 1254381832885811, 1254428990565155, 1254429973016808,
+    1254434885275073, 1254492849922600, 1254521341020537, 1254539025150291,
+    1254541972505250, 1254548849666821, 1254604849411042, 1254608779217654,
+    1254611726572613, 1254622533540796, 1254624498444102, 1254631375605673,
+    1254633340508979, 1254664778961875, 1254713901544525, 1254773831095358,
+    1254790532773459, 1254804287096601, 1254878953422229, 1254882883228841,
+    1254928076004879, 1254937900521409, 1254945760134633, 1255047935106545,
+    1255132425948703, 1255136355755315, 1255361337183852, 1255376073958647,
+    1255384916023524, 1255435021057827, 1255476284027253, 1255496915511966,
+    1255549967901228, 1255560774869411, 1255660984938017, 1255711089972320,
+    1255749405586787, 1255754317845052, 1255765124813235, 1255772984426459,
+    1255803440427702, 1255923299529368, 1255950808175652, 1255953755530611,
+    1255998948306649, 1256044141082687, 1256141403796334, 1256203298250473,
+    1256212140315350, 1256225894638492, 1256238666509981, 1256296631157508,
+    1256310385480650, 1256351648450076, 1256407648194297, 1256413542904215,
+    1256493121488108, 1256494103939761, 1256533402005881, 1256542244070758,
+    1256545191425717, 1256550103683982, 1256563858007124, 1256602173621591,
+    1256603156073244, 1256734804594746, 1256762313241030, 1256775085112519,
+    1256792769242273, 1256800628855497, 1256952908861712, 1256953891313365,
+    1256965680733201, 1257003013896015, 1257014803315851, 1257026592735687,
+    1257096346803050, 1257129750159252, 1257136627320823, 1257220135711328,
+    1257263363584060, 1257277117907202, 1257331152748117, 1257341959716300,
+    1257344907071259, 1257378310427461, 1257400906815480, 1257416626041928,
+    1257427433010111, 1257505046690698, 1257602309404345, 1257672063471708,
+    1257718238699399, 1257723150957664, 1257725115860970, 1257732975474194,
+    1257752624507254, 1257802729541557, 1257806659348169, 1257842027607677,
+    1257962869160996, 1258028693421747, 1258040482841583, 1258074868649438,
+    1258104342199028, 1258161324394902, 1258193745299451, 1258205534719287,
+    1258209464525899, 1258275288786650, 1258329323627565, 1258476691375515,
+    1258479638730474, 1258484550988739, 1258489463247004, 1258497322860228,
+    1258513042086676, 1258519919248247, 1258544480539572, 1258578866347427,
+    1258595568025528, 1258606374993711, 1258630936285036, 1258637813446607,
+    1258652550221402, 1258653532673055, 1258656480028014, 1258718374482153,
+    1258786163646210, 1258798935517699, 1258841180938778, 1258886373714816,
+    1258905040296223, 1258907987651182, 1258931566490854, 1258961040040444,
+    1259063215012356, 1259110372691700, 1259154583016085, 1259166372435921,
+    1259201740695429, 1259243003664855, 1259281319279322, 1259299003409076,
+    1259308827925606, 1259330441861972, 1259355003153297, 1259381529347928,
+    1259386441606193, 1259420827414048, 1259425739672313, 1259427704575619,
+    1259431634382231, 1259455213221903, 1259467002641739, 1259473879803310,
+    1259482721868187, 1259544616322326, 1259551493483897, 1259602580969853,
+    1259606510776465, 1259612405486383, 1259675282392175, 1259738159297967,
+    1259749948717803, 1259750931169456, 1259809878268636, 1259925807563690,
+    1259952333758321, 1259963140726504, 1260034859697173, 1260058438536845,
+    1260077105118252, 1260094789248006, 1260165525767022, 1260232332479426,
+    1260254928867445, 1260271630545546, 1260272612997199, 1260280472610423,
+    1260298156740177, 1260300121643483, 1260305033901748, 1260306016353401,
+    1260343349516215, 1260356121387704, 1260364963452581, 1260431770164985,
+    1260468120876146, 1260522155717061, 1260543769653427, 1260583067719547,
+    1260642997270380, 1260678365529888, 1260767768630311, 1260797242179901,
+    1260807066696431, 1260918083733220, 1260969171219176, 1260983907993971,
+    1261014363995214, 1261058574319599, 1261102784643984, 1261129310838615,
+    1261236398068792, 1261242292778710, 1261258012005158, 1261342502847316,
+    1261391625429966, 1261420116527903, 1261433870851045, 1261458432142370,
+    1261459414594023, 1261462361948982, 1261519344144856, 1261545870339487,
+    1261590080663872, 1261591063115525, 1261636255891563, 1261650010214705,
+    1261660817182888, 1261686360925866, 1261760044799841, 1261785588542819,
+    1261826851512245, 1261880886353160, 1261956535130441, 1261971271905236,
+    1262006640164744, 1262007622616397, 1262021376939539, 1262048885585823,
+    1262059692554006, 1262129446621369, 1262177586752366, 1262212955011874,
+    1262323972048663, 1262328884306928, 1262330849210234, 1262348533339988,
+    1262435971537105, 1262468392441654, 1262571549865219, 1262589233994973,
+    1262630496964399, 1262637374125970, 1262638356577623, 1262653093352418,
+    1262693373870191, 1262706145741680, 1262783759422267, 1262797513745409,
+    1262816180326816, 1262824039940040, 1262839759166488, 1262848601231365,
+    1262849583683018, 1262922285105340, 1262925232460299, 1263003828592539,
+    1263035267045435, 1263123687694205, 1263125652597511, 1263137442017347,
+    1263152178792142, 1263167898018590, 1263232739827688, 1263246494150830,
+    1263261230925625, 1263265160732237, 1263318213121499, 1263325090283070,
+    1263463615966143, 1263558913776484, 1263604106552522, 1263633580102112,
+    1263651264231866, 1263666983458314, 1263728877912453, 1263729860364106,
+    1263747544493860, 1263776035591797, 1263796667076510, 1263814351206264,
+    1263821228367835, 1263858561530649, 1263878210563709, 1263919473533135,
+    1264083542959186, 1264087472765798, 1264130700638530, 1264172946059609,
+    1264174910962915, 1264181788124486, 1264201437157546, 1264203402060852,
+    1264338980388966, 1264378278455086, 1264386138068310, 1264387120519963,
+    1264389085423269, 1264471611362121, 1264499120008405, 1264601294980317,
+    1264651400014620, 1264652382466273, 1264671049047680, 1264719189178677,
+    1264739820663390, 1264750627631573, 1264821364150589, 1264836100925384,
+    1264866556926627, 1264910767251012, 1264922556670848, 1264975609060110,
+    1264981503770028, 1265022766739454, 1265023749191107, 1265046345579126,
+    1265050275385738, 1265064029708880, 1265067959515492, 1265098415516735,
+    1265177994100628, 1265245783264685, 1265328309203537, 1265334203913455,
+    1265362695011392, 1265391186109329, 1265396098367594, 1265457010370080,
+    1265555255535380, 1265644658635803, 1265645641087456, 1265652518249027,
+    1265664307668863, 1265861780451116, 1265922692453602, 1265940376583356,
+    1265988516714353, 1266015042908984, 1266052376071798, 1266128024849079,
+    1266154551043710, 1266155533495363, 1266177147431729, 1266312725759843,
+    1266328444986291, 1266333357244556, 1266353988729269, 1266357918535881,
+    1266380514923900, 1266457146152834, 1266475812734241, 1266507251187137,
+    1266521005510279, 1266571110544582, 1266648724225169, 1266668373258229,
+    1266676232871453, 1266678197774759, 1266686057387983, 1266703741517737,
+    1266717495840879, 1266761706165264, 1266832442684280, 1266840302297504,
+    1266885495073542, 1266895319590072, 1266901214299990, 1266906126558255,
+    1266915951074785, 1266938547462804, 1266949354430987, 1266970968367353,
+    1266986687593801, 1267003389271902, 1267043669789675, 1267044652241328,
+    1267051529402899, 1267087880114060, 1267091809920672, 1267092792372325,
+    1267112441405385, 1267166476246300, 1267183177924401, 1267186125279360,
+    1267224440893827, 1267234265410357, 1267245072378540, 1267250967088458,
+    1267267668766559, 1267276510831436, 1267298124767802, 1267300089671108,
+    1267317773800862, 1267318756252515, 1267320721155821, 1267349212253758,
+    1267354124512023, 1267376720900042, 1267429773289304, 1267434685547569,
+    1267448439870711, 1267453352128976, 1267474966065342, 1267476930968648,
+    1267529983357910, 1267546685036011, 1267557492004194, 1267567316520724,
+    1267570263875683, 1267619386458333, 1267625281168251, 1267631175878169,
+    1267636088136434, 1267639035491393, 1267660649427759, 1267662614331065,
+    1267678333557513, 1267711736913715, 1267728438591816, 1267734333301734,
+    1267796227755873, 1267804087369097, 1267832578467034, 1267846332790176,
+    1267870894081501, 1267871876533154, 1267932788535640, 1267993700538126,
+    1268035945959205, 1268036928410858, 1268075244025325, 1268086050993508,
+    1268094893058385, 1268110612284833, 1268159734867483, 1268166612029054,
+    1268204927643521, 1268275664162537, 1268279593969149, 1268317909583616,
+    1268328716551799, 1268333628810064, 1268361137456348, 1268366049714613,
+    1268367032166266, 1268381768941061, 1268446610750159, 1268464294879913,
+    1268478049203055, 1268479031654708, 1268527171785705, 1268540926108847,
+    1268599873208027, 1268626399402658, 1268650960693983, 1268658820307207,
+    1268722679664652, 1268733486632835, 1268760995279119, 1268761977730772,
+    1268806188055157, 1268808152958463, 1268811100313422, 1268825837088217,
+    1268891661348968, 1268914257736987, 1268962397867984, 1268963380319637,
+    1269018397612205, 1269040011548571, 1269060643033284, 1269061625484937,
+    1269087169227915, 1269143168972136, 1269146116327095, 1269154958391972,
+    1269194256458092, 1269205063426275, 1269209975684540, 1269242396589089,
+    1269287589365127, 1269289554268433, 1269295448978351, 1269320010269676,
+    1269327869882900, 1269350466270919, 1269363238142408, 1269365203045714,
+    1269382887175468, 1269472290275891, 1269494886663910, 1269519447955235,
+    1269558746021355, 1269584289764333, 1269586254667639, 1269603938797393,
+    1269669763058144, 1269678605123021, 1269712008479223, 1269717903189141,
+    1269724780350712, 1269756218803608, 1269830885129236, 1269907516358170,
+    1269911446164782, 1269922253132965, 1269972358167268, 1269988077393716,
+    1270048006944549, 1270053901654467, 1270093199720587, 1270125620625136,
+    1270137410044972, 1270142322303237, 1270160988884644, 1270227795597048,
+    1270228778048701, 1270231725403660, 1270334882827225, 1270388917668140,
+    1270394812378058, 1270402671991282, 1270403654442935, 1270419373669383,
+    1270430180637566, 1270443934960708, 1270534320512784, 1270535302964437,
+    1270542180126008, 1270553969545844, 1270569688772292, 1270667933937592,
+    1270675793550816, 1270687582970652, 1270706249552059, 1270757337038015,
+    1270859512009927, 1270870318978110, 1270892915366129, 1270894880269435,
+    1270971511498369, 1271001967499612, 1271042248017385, 1271067791760363,
+    1271077616276893, 1271144422989297, 1271147370344256, 1271166036925663,
+    1271183721055417, 1271204352540130, 1271235790993026, 1271237755896332,
+    1271245615509556, 1271308492415348, 1271346808029815, 1271369404417834,
+    1271370386869487, 1271426386613708, 1271470596938093, 1271500070487683,
+    1271511859907519, 1271540351005456, 1271618947137696, 1271623859395961,
+    1271632701460838, 1271659227655469, 1271672981978611, 1271673964430264,
+    1271679859140182, 1271704420431507, 1271711297593078, 1271717192302996,
+    1271743718497627, 1271758455272422, 1271800700693501, 1271845893469539,
+    1271857682889375, 1271859647792681, 1271866524954252, 1271887156438965,
+    1271908770375331, 1271939226376574, 1272014875153855, 1272036489090221,
+    1272044348703445, 1272082664317912, 1272083646769565, 1272091506382789,
+    1272092488834442, 1272095436189401, 1272133751803868, 1272178944579906,
+    1272186804193130, 1272204488322884, 1272226102259250, 1272235926775780,
+    1272239856582392, 1272271295035288, 1272326312327856, 1272337119296039,
+    1272389189233648, 1272412768073320, 1272440276719604, 1272442241622910,
+    1272446171429522, 1272458943301011, 1272517890400191, 1272551293756393,
+    1272599433887390, 1272643644211775, 1272650521373346, 1272725187698974,
+    1272771362926665, 1272795924217990, 1272811643444438, 1272850941510558,
+    1272874520350230, 1272879432608495, 1272914800868003, 1272941327062634,
+    1272967853257265, 1272972765515530, 1272980625128754, 1272994379451896,
+    1273002239065120, 1273010098678344, 1273029747711404, 1273043502034546,
+    1273056273906035, 1273078870294054, 1273121115715133, 1273125045521745,
+    1273127992876704, 1273164343587865, 1273233115203575, 1273256694043247,
+    1273283220237878, 1273286167592837, 1273307781529203, 1273347079595323,
+    1273368693531689, 1273457114180459, 1273511149021374, 1273695849932138,
+    1273716481416851, 1273737112901564, 1273758726837930, 1273794095097438,
+    1273874656132984, 1273894305166044, 1273915919102410, 1273945392652000,
+    1273988620524732, 1274076058721849, 1274098655109868, 1274102584916480,
+    1274134023369376, 1274155637305742, 1274180198597067, 1274207707243351,
+    1274246022857818, 1274258794729307, 1274278443762367, 1274295145440468,
+    1274309882215263, 1274310864666916, 1274330513699976, 1274372759121055,
+    1274397320412380, 1274493600674374, 1274534863643800, 1274544688160330,
+    1274562372290084, 1274575144161573, 1274594793194633, 1274620336937611,
+    1274631143905794, 1274635073712406, 1274642933325630, 1274663564810343,
+    1274690091004974, 1274740196039277, 1274810932558293, 1274853177979372,
+    1274908195271940, 1274991703662445, 1275009387792199, 1275034931535177,
+    1275098790892622, 1275105668054193, 1275132194248824, 1275135141603783,
+    1275168544959985, 1275238299027348, 1275247141092225, 1275251070898837,
+    1275252053350490, 1275257948060408, 1275275632190162, 1275282509351733,
+    1275290368964957, 1275349316064137, 1275352263419096, 1275372894903809,
+    1275407280711664, 1275409245614970, 1275410228066623, 1275479982133986,
+    1275619490268712, 1275826787567495, 1275837594535678, 1275856261117085,
+    1275866085633615, 1275900471441470, 1276042926931155, 1276069453125786,
+    1276188329775799, 1276218785777042, 1276243347068367, 1276391697267970,
+    1276394644622929, 1276449661915497, 1276455556625415, 1276486012626658,
+    1276618643599813, 1276630433019649, 1276638292632873, 1276697239732053,
+    1276804326962230, 1276834782963473, 1276837730318432, 1276896677417612,
+    1276908466837448, 1276911414192407, 1276970361291587, 1277134430717638,
+    1277176676138717, 1277178641042023, 1277187483106900, 1277212044398225,
+    1277337798209809, 1277342710468074, 1277382990985847, 1277418359245355,
+    1277449797698251, 1277460604666434, 1277476323892882, 1277491060667677,
+    1277521516668920, 1277543130605286, 1277548042863551, 1277558849831734,
+    1277593235639589, 1277608954866037, 1277698357966460, 1277711129837949,
+    1277763199775558, 1277822146874738, 1277845725714410, 1277877164167306,
+    1277886006232183, 1277887971135489, 1277906637716896, 1277985233849136,
+    1277989163655748, 1278017654753685, 1278020602108644, 1278026496818562,
+    1278035338883439, 1278037303786745, 1278073654497906, 1278112952564026,
+    1278119829725597, 1278166987404941, 1278193513599572, 1278196460954531,
+    1278228881859080, 1278258355408670, 1278371337348765, 1278417512576456,
+    1278424389738027, 1278512810386797, 1278529512064898, 1278545231291346,
+    1278568810131018, 1278569792582671, 1278621862520280, 1278644458908299,
+    1278727967298804, 1278745651428558, 1278798703817820, 1278856668465347,
+    1278964738147177, 1279026632601316, 1279034492214540, 1279035474666193,
+    1279139614541411, 1279143544348023, 1279182842414143, 1279195614285632,
+    1279202491447203, 1279208386157121, 1279249649126547, 1279259473643077,
+    1279272245514566, 1279278140224484, 1279290912095973, 1279341999581929,
+    1279356736356724, 1279372455583172, 1279392104616232, 1279500174298062,
+    1279535542557570, 1279540454815835, 1279546349525753, 1279570910817078,
+    1279581717785261, 1279589577398485, 1279605296624933, 1279627893012952,
+    1279633787722870, 1279638699981135, 1279764453792719, 1279786067729085,
+    1279794909793962, 1279795892245615, 1279845014828265, 1279946207348524,
+    1280027750835723, 1280028733287376, 1280096522451433, 1280151539744001,
+    1280163329163837, 1280253714715913, 1280334275751459, 1280349012526254,
+    1280437433175024, 1280481643499409, 1280577923761403, 1280581853568015,
+    1280606414859340, 1280658484796949, 1280815677061429, 1280857922482508,
+    1280862834740773, 1280899185451934, 1280940448421360, 1280962062357726,
+    1280984658745745, 1280988588552357, 1281007255133764, 1281031816425089,
+    1281039676038313, 1281173289463121, 1281183113979651, 1281211605077588,
+    1281242061078831, 1281284306499910, 1281300025726358, 1281301990629664,
+    1281315744952806, 1281386481471822, 1281409077859841, 1281460165345797,
+    1281464095152409, 1281518129993324, 1281547603542914, 1281606550642094,
+    1281718550130536, 1281790269101205, 1281809918134265, 1281901286137994,
+    1281954338527256, 1281982829625193, 1282051601240903, 1282097776468594,
+    1282113495695042, 1282127250018184, 1282142969244632, 1282152793761162,
+    1282231389893402, 1282257916088033, 1282318828090519, 1282333564865314,
+    1282427880224002, 1282437704740532, 1282505493904589, 1282560511197157,
+    1282571318165340, 1282653844104192, 1282659738814110, 1282677422943864,
+    1282764861140981, 1282765843592634, 1282772720754205, 1282775668109164,
+    1282797282045530, 1282815948626937, 1282822825788508, 1282832650305038,
+    1282840509918262, 1282849351983139, 1282910263985625, 1282912228888931,
+    1282924018308767, 1282936790180256, 1282962333923234, 1282963316374887,
+    1282973140891417, 1282991807472824, 1283048789668698, 1283077280766635,
+    1283089070186471, 1283107736767878, 1283148999737304, 1283185350448465,
+    1283231525676156, 1283242332644339, 1283248227354257, 1283402472263778,
+    1283417209038573, 1283509559493955, 1283511524397261, 1283517419107179,
+    1283592085432807, 1283601909949337, 1283645137822069, 1283652014983640,
+    1283677558726618, 1283700155114637, 1283767944278694, 1283782681053489,
+    1283859312282423, 1283901557703502, 1284014539643597, 1284020434353515,
+    1284039100934922, 1284048925451452, 1284062679774594, 1284076434097736,
+    1284100012937408, 1284116714615509, 1284178609069648, 1284212012425850,
+    1284225766748992, 1284249345588664, 1284317134752721, 1284396713336614,
+    1284450748177529, 1284503800566791, 1284756290641612, 1284777904577978,
+    1284807378127568, 1284812290385833, 1284848641096994, 1284863377871789,
+    1284864360323442, 1284868290130054, 1284900711034603, 1284958675682130,
+    1284965552843701, 1284974394908578, 1285097201365203, 1285163025625954,
+    1285180709755708, 1285201341240421, 1285251446274724, 1285285832082579,
+    1285359515956554, 1285493129381362, 1285495094284668, 1285517690672687,
+    1285530462544176, 1285602181514845, 1285620848096252, 1285728917778082,
+    1285730882681388, 1285732847584694, 1285785899973956, 1285819303330158,
+    1285825198040076, 1285859583847931, 1285871373267767, 1285914601140499,
+    1285963723723149, 1285975513142985, 1286099302051263, 1286117968632670,
+    1286129758052506, 1286139582569036, 1286145477278954, 1286209336636399,
+    1286211301539705, 1286212283991358, 1286215231346317, 1286226038314500,
+    1286229968121112, 1286238810185989, 1286276143348803, 1286325265931453,
+    1286359651739308, 1286378318320715, 1286454949549649, 1286455932001302,
+    1286536493036848, 1286693685301328, 1286712351882735, 1286764421820344,
+    1286767369175303, 1286768351626956, 1286773263885221, 1286781123498445,
+    1286782105950098, 1286806667241423, 1286807649693076, 1286815509306300,
+    1286935368407966, 1286966806860862, 1286991368152187, 1287025753960042,
+    1287039508283184, 1287042455638143, 1287106314995588, 1287148560416667,
+    1287208489967500, 1287245823130314, 1287262524808415, 1287277261583210,
+    1287304770229494, 1287307717584453, 1287343085843961, 1287377471651816,
+    1287378454103469, 1287453120429097, 1287461962493974, 1287463927397280,
+    1287487506236952, 1287571014627457, 1287595575918782, 1287618172306801,
+    1287817609992360, 1287854943155174, 1287872627284928, 1287883434253111,
+    1287901118382865, 1287987574128329, 1288001328451471, 1288015082774613,
+    1288022942387837, 1288031784452714, 1288040626517591, 1288069117615528,
+    1288094661358506, 1288131994521320, 1288174239942399, 1288195853878765,
+    1288208625750254, 1288240064203150, 1288244976461415, 1288304906012248,
+    1288314730528778, 1288319642787043, 1288330449755226, 1288340274271756,
+    1288345186530021, 1288350098788286, 1288360905756469, 1288402168725895,
+    1288413958145731, 1288436554533750, 1288469957889952, 1288503361246154,
+    1288616343186249, 1288650728994104, 1288658588607328, 1288675290285429,
+    1288679220092041, 1288717535706508, 1288853114034622, 1288878657777600,
+    1288937604876780, 1288958236361493, 1289082025269771, 1289083007721424,
+    1289112481271014, 1289133112755727, 1289170445918541, 1289222515856150,
+    1289238235082598, 1289408199218567, 1289443567478075, 1289445532381381,
+    1289477953285930, 1289491707609072, 1289525110965274, 1289617461420656,
+    1289636128002063, 1289754022200423, 1289827706074398, 1289835565687622,
+    1289863074333906, 1289901389948373, 1289992757952102, 1289994722855408,
+    1290007494726897, 1290015354340121, 1290023213953345, 1290052687502935,
+    1290083143504178, 1290096897827320, 1290103774988891, 1290192195637661,
+    1290216756928986, 1290247212930229, 1290271774221554, 1290285528544696,
+    1290320896804204, 1290322861707510, 1290383773709996, 1290545878232741,
+    1290551772942659, 1290572404427372, 1290590088557126, 1290737456305076,
+    1290753175531524, 1290772824564584, 1290800333210868, 1290879911794761,
+    1290881876698067, 1290920192312534, 1290953595668736, 1290955560572042,
+    1291015490122875, 1291044963672465, 1291072472318749, 1291151068450989,
+    1291210998001822, 1291364260459690, 1291429102268788, 1291590224339880,
+    1291614785631205, 1291616750534511, 1291637382019224, 1291787697122133,
+    1291800468993622, 1291868258157679, 1291880047577515, 1291896749255616,
+    1291904608868840, 1291907556223799, 1291993029517610, 1291995976872569,
+    1291997941775875, 1291998924227528, 1292002854034140, 1292117800877541,
+    1292193449654822, 1292233730172595, 1292241589785819, 1292244537140778,
+    1292273028238715, 1292295624626734, 1292327063079630, 1292330992886242,
+    1292365378694097, 1292380115468892, 1292404676760217, 1292411553921788,
+    1292426290696583, 1292453799342867, 1292536325281719, 1292540255088331,
+    1292578570702798, 1292637517801978, 1292711201675953, 1292809446841253,
+    1292833025680925, 1292935200652837, 1292944042717714, 1292970568912345,
+    1292974498718957, 1292975481170610, 1293011831881771, 1293091410465664,
+    1293095340272276, 1293157234726415, 1293178848662781, 1293284953441305,
+    1293333093572302, 1293359619766933, 1293380251251646, 1293391058219829,
+    1293453935125621, 1293524671644637, 1293548250484309, 1293563969710757,
+    1293570846872328, 1293605232680183, 1293625864164896, 1293653372811180,
+    1293671056940934, 1293782073977723, 1293791898494253, 1293830214108720,
+    1293842985980209, 1293878354239717, 1293919617209143, 1293933371532285,
+    1293963827533528, 1294003125599648, 1294012950116178, 1294062072698828,
+    1294078774376929, 1294088598893459, 1294098423409989, 1294159335412475,
+    1294162282767434, 1294175054638923, 1294183896703800, 1294204528188513,
+    1294217300060002, 1294224177221573, 1294241861351327, 1294377439679441,
+    1294390211550930, 1294430492068703, 1294439334133580, 1294442281488539,
+    1294467825231517, 1294480597103006, 1294505158394331, 1294527754782350,
+    1294541509105492, 1294594561494754, 1294595543946407, 1294632877109221,
+    1294787122018742, 1294896174152225, 1294915823185285, 1294920735443550,
+    1294987542155954, 1295032734931992, 1295046489255134, 1295154558936964,
+    1295169295711759, 1295179120228289, 1295204663971267, 1295281295200201,
+    1295327470427892, 1295343189654340, 1295376593010542, 1295392312236990,
+    1295429645399804, 1295458136497741, 1295463048756006, 1295479750434107,
+    1295488592498984, 1295530837920063, 1295557364114694, 1295566206179571,
+    1295568171082877, 1295664451344871, 1295757784251906, 1295844239997370,
+    1295878625805225, 1295898274838285, 1295947397420935, 1296008309423421,
+    1296032870714746, 1296041712779623, 1296056449554418, 1296060379361030,
+    1296124238718475, 1296138975493270, 1296147817558147, 1296160589429636,
+    1296175326204431, 1296221501432122, 1296255887239977, 1296291255499485,
+    1296306974725933, 1296325641307340, 1296365921825113, 1296384588406520,
+    1296393430471397, 1296455324925536, 1296556517445795, 1296564377059019,
+    1296579113833814, 1296607604931751, 1296627253964811, 1296667534482584,
+    1296674411644155, 1296686201063991, 1296724516678458, 1296780516422679,
+    1296790340939209, 1296800165455739, 1296837498618553, 1296862059909878,
+    1296899393072692, 1296905287782610, 1296921007009058, 1296951463010301,
+    1296960305075178, 1296967182236749, 1297042831014030, 1297110620178087,
+    1297136163921065, 1297143041082636, 1297159742760737, 1297161707664043,
+    1297182339148756, 1297257005474384, 1297271742249179, 1297371952317785,
+    1297395531157457, 1297445636191760, 1297450548450025, 1297549776066978,
+    1297600863552934, 1297612652972770, 1297662758007073, 1297703038524846,
+    1297712863041376, 1297748231300884, 1297749213752537, 1297752161107496,
+    1297845494014531, 1297878897370733, 1297898546403793, 1297928019953383,
+    1297963388212891, 1297978124987686, 1297998756472399, 1298009563440582,
+    1298041001893478, 1298071457894721, 1298132369897207, 1298198194157958,
+    1298199176609611, 1298269913128627, 1298279737645157, 1298297421774911,
+    1298326895324501, 1298369140745580, 1298434965006331, 1298437912361290,
+    1298450684232779, 1298472298169145, 1298494894557164, 1298503736622041,
+    1298507666428653, 1298578402947669, 1298585280109240, 1298598051980729,
+    1298616718562136, 1298619665917095, 1298641279853461, 1298702191855947,
+    1298787665149758, 1298814191344389, 1298845629797285, 1298922261026219,
+    1298927173284484, 1298975313415481, 1298993979996888, 1299024435998131,
+    1299074541032434, 1299087312903923, 1299097137420453, 1299118751356819,
+    1299123663615084, 1299142330196491, 1299171803746081, 1299271031363034,
+    1299345697688662, 1299362399366763, 1299377136141558, 1299387943109741,
+    1299389908013047, 1299403662336189, 1299412504401066, 1299464574338675,
+    1299604082473401, 1299618819248196, 1299648292797786, 1299680713702335,
+    1299690538218865, 1299699380283742, 1299702327638701, 1299724924026720,
+    1299859519903181, 1299866397064752, 1299883098742853, 1299897835517648,
+    1299944993196992, 1299947940551951, 1299993133327989, 1300062887395352,
+    1300102185461472, 1300153272947428, 1300155237850734, 1300161132560652,
+    1300259377725952, 1300272149597441, 1300421482248697, 1300423447152003,
+    1300428359410268, 1300456850508205, 1300466675024735, 1300480429347877,
+    1300496148574325, 1300503025735896, 1300577692061524, 1300626814644174,
+    1300641551418969, 1300650393483846, 1300652358387152, 1300656288193764,
+    1300670042516906, 1300678884581783, 1300679867033436, 1300750603552452,
+    1300781059553695, 1300797761231796, 1300823304974774, 1300830182136345,
+    1300837059297916, 1300839024201222, 1300847866266099, 1300948076334705,
+    1301123935180592, 1301160285891753, 1301164215698365, 1301224145249198,
+    1301262460863665, 1301308636091356, 1301376425255413, 1301390179578555,
+    1301469758162448, 1301510038680221, 1301543442036423, 1301582740102543,
+    1301592564619073, 1301642669653376, 1301716353527351, 1301763511206695,
+    1301827370564140, 1301873545791831, 1301886317663320, 1301892212373238,
+    1301896142179850, 1301909896502992, 1301924633277787, 1302071018574084,
+    1302123088511693, 1302181053159220, 1302195789934015, 1302201684643933,
+    1302279298324520, 1302289122841050, 1302336280520394, 1302354947101801,
+    1302383438199738, 1302425683620817, 1302470876396855, 1302479718461732,
+    1302495437688180, 1302564209303890, 1302567156658849, 1302657542210925,
+    1302736138343165, 1302750875117960, 1302776418860938, 1302803927507222,
+    1302811787120446, 1302897260414257, 1302931646222112, 1303019084419229,
+    1303023014225841, 1303036768548983, 1303043645710554, 1303264697332479,
+    1303266662235785, 1303269609590744, 1303291223527110, 1303342311013066,
+    1303381609079186, 1303449398243243, 1303523082117218, 1303525047020524,
+    1303541748698625, 1303555503021767, 1303601678249458, 1303643923670537,
+    1303670449865168, 1303723502254430, 1303755923158979, 1303790308966834,
+    1303807010644935, 1303918027681724, 1303919992585030, 1303977957232557,
+    1303996623813964, 1304043781493308, 1304060483171409, 1304065395429674,
+    1304070307687939, 1304109605754059, 1304114518012324, 1304120412722242,
+    1304132202142078, 1304139079303649, 1304146938916873, 1304262868211927,
+    1304311008342924, 1304352271312350, 1304356201118962, 1304376832603675,
+    1304397464088388, 1304430867444590, 1304454446284262, 1304591007064029,
+    1304595919322294, 1304604761387171, 1304656831324780, 1304669603196269,
+    1304704971455777, 1304775707974793, 1304779637781405, 1304809111330995,
+    1304857251461992, 1304866093526869, 1304924058174396, 1304951566820680,
+    1304957461530598, 1305064548760775, 1305077320632264, 1305081250438876,
+    1305109741536813, 1305161811474422, 1305225670831867, 1305382863096347,
+    1305472266196770, 1305547914974051, 1305563634200499, 1305611774331496,
+    1305635353171168, 1305641247881086, 1305658932010840, 1305701177431919,
+    1305707072141837, 1305746370207957, 1305796475242260, 1305841668018298,
+    1305857387244746, 1305973316539800, 1305976263894759, 1305985105959636,
+    1305987070862942, 1306018509315838, 1306072544156753, 1306128543900974,
+    1306275911648924, 1306289665972066, 1306293595778678, 1306295560681984,
+    1306309315005126, 1306337806103063, 1306418367138609, 1306448823139852,
+    1306519559658868, 1306562787531600, 1306606997855985, 1306715067537815,
+    1306716049989468, 1306751418248976, 1306853593220888, 1306861452834112,
+    1306877172060560, 1306905663158497, 1307023557356857, 1307038294131652,
+    1307063837874630, 1307067767681242, 1307126714780422, 1307137521748605,
+    1307157170781665, 1307181732072990, 1307222995042416, 1307261310656883,
+    1307283907044902, 1307293731561432, 1307312398142839, 1307343836595735,
+    1307358573370530, 1307385099565161, 1307405731049874, 1307435204599464,
+    1307539344474682, 1307562923314354, 1307577660089149, 1307592396863944,
+    1307602221380474, 1307648396608165, 1307661168479654, 1307675905254449,
+    1307683764867673, 1307684747319326, 1307690642029244, 1307705378804039,
+    1307721098030487, 1307777097774708, 1307781027581320, 1307806571324298,
+    1307852746551989, 1307858641261907, 1307877307843314, 1307903834037945,
+    1307916605909434, 1307941167200759, 1307945097007371, 1307958851330513,
+    1307967693395390, 1307999131848286, 1308004044106551, 1308074780625567,
+    1308132745273094, 1308153376757807, 1308182850307397, 1308197587082192,
+    1308215271211946, 1308224113276823, 1308226078180129, 1308244744761536,
+    1308253586826413, 1308264393794596, 1308272253407820, 1308274218311126,
+    1308304674312369, 1308355761798325, 1308379340637997, 1308381305541303,
+    1308472673545032, 1308544392515701, 1308548322322313, 1308587620388433,
+    1308612181679758, 1308702567231834, 1308715339103323, 1308756602072749,
+    1308813584268623, 1308842075366560, 1308882355884333, 1308899057562434,
+    1308915759240535, 1308931478466983, 1308963899371532, 1309010074599223,
+    1309021864019059, 1309033653438895, 1309038565697160, 1309065091891791,
+    1309081793569892, 1309197722864946, 1309247827899249, 1309258634867432,
+    1309263547125697, 1309266494480656, 1309272389190574, 1309314634611653,
+    1309340178354631, 1309347055516202, 1309442353326543, 1309444318229849,
+    1309446283133155, 1309483616295969, 1309518984555477, 1309556317718291,
+    1309597580687717, 1309645720818714, 1309684036433181, 1309765579920380,
+    1309770492178645, 1309821579664601, 1309833369084437, 1310009227930324,
+    1310047543544791, 1310102560837359, 1310181156969599, 1310189999034476,
+    1310261718005145, 1310264665360104, 1310425787431196, 1310451331174174,
+    1310544664081209, 1310740171960156, 1310783399832888, 1310861995965128,
+    1310879680094882, 1310892451966371, 1310898346676289, 1310905223837860,
+    1310917995709349, 1310981855066794, 1310998556744895, 1310999539196548,
+    1311016240874649, 1311132170169703, 1311148871847804, 1311190134817230,
+    1311201924237066, 1311225503076738, 1311267748497817, 1311283467724265,
+    1311412168890808, 1311421010955685, 1311563466445370, 1311602764511490,
+    1311612589028020, 1311628308254468, 1311690202708607, 1311695114966872,
+    1311799254842090, 1311989850462772, 1312024236270627, 1312077288659889,
+    1312105779757826, 1312107744661132, 1312121498984274, 1312232516021063,
+    1312236445827675, 1312301287636773, 1312305217443385, 1312313077056609,
+    1312332726089669, 1312368094349177, 1312513497193821, 1312516444548780,
+    1312524304162004, 1312560654873165, 1312568514486389, 1312594058229367,
+    1312612724810774, 1312614689714080, 1312656935135159, 1312658900038465,
+    1312663812296730, 1312685426233096, 1312787601205008, 1312814127399639,
+    1312877004305431, 1312916302371551, 1312950688179406, 1312996863407097,
+    1313051880699665, 1313110827798845, 1313194336189350, 1313220862383981,
+    1313295528709609, 1313328932065811, 1313340721485647, 1313353493357136,
+    1313397703681521, 1313424229876152, 1313443878909212, 1313462545490619,
+    1313478264717067, 1313534264461288, 1313560790655919, 1313584369495591,
+    1313631527174935, 1313642334143118, 1313687526919156, 1313691456725768,
+    1313725842533623, 1313726824985276, 1313730754791888, 1313840789377024,
+    1313897771572898, 1313904648734469, 1313948859058854, 1313958683575384,
+    1313984227318362, 1314002893899769, 1314028437642747, 1314049069127460,
+    1314116858291517, 1314134542421271, 1314137489776230, 1314189559713839,
+    1314224927973347, 1314343804623360, 1314348716881625, 1314399804367581,
+    1314415523594029, 1314424365658906, 1314431242820477, 1314454821660149,
+    1314532435340736, 1314650329539096, 1314677838185380, 1314680785540339,
+    1314738750187866, 1314741697542825, 1314755451865967, 1314770188640762,
+    1314815381416800, 1314816363868453, 1314819311223412, 1314857626837879,
+    1314872363612674, 1314944082583343, 1314968643874668, 1315008924392441,
+    1315047240006908, 1315066889039968, 1315091450331293, 1315107169557741,
+    1315110116912700, 1315121906332536, 1315232923369325, 1315234888272631,
+    1315268291628833, 1315290888016852, 1315293835371811, 1315315449308177,
+    1315418606731742, 1315470676669351, 1315472641572657, 1315510957187124,
+    1315516851897042, 1315519799252001, 1315620991772260, 1315623939127219,
+    1315812569844595, 1315836148684267, 1315880359008652, 1315885271266917,
+    1315955025334280, 1315970744560728, 1316040498628091, 1316092568565700,
+    1316111235147107, 1316221269732243, 1316224217087202, 1316233059152079,
+    1316378461996723, 1316445268709127, 1316476707162023, 1316478672065329,
+    1316547443681039, 1316587724198812, 1316600496070301, 1316617197748402,
+    1316635864329809, 1316636846781462, 1316725267430232, 1316766530399658,
+    1316792074142636, 1316794039045942, 1316801898659166, 1316888354404630,
+    1316898178921160, 1316940424342239, 1316950248858769, 1317123160349697,
+    1317194879320366, 1317197826675325, 1317213545901773, 1317222387966650,
+    1317262668484423, 1317450316750146, 1317482737654695, 1317552491722058,
+    1317620280886115, 1317646807080746, 1317665473662153, 1317686105146866,
+    1317748982052658, 1317759789020841, 1317804981796879, 1317822665926633,
+    1317843297411346, 1317857051734488, 1317900279607220, 1318012279095662,
+    1318075156001454, 1318190102844855, 1318270663880401, 1318283435751890,
+    1318300137429991, 1318382663368843, 1318402312401903, 1318513329438692,
+    1318540838084976, 1318554592408118, 1318625328927134, 1318668556799866,
+    1318669539251519, 1318684276026314, 1318694100542844, 1318708837317639,
+    1318736345963923, 1318788415901532, 1318828696419305, 1318829678870958,
+    1319029116556517, 1319068414622637, 1319121467011899, 1319134238883388,
+    1319211852563975, 1319255080436707, 1319279641728032, 1319498728446651,
+    1319516412576405, 1319624482258235, 1319737464198330, 1319745323811554,
+    1319780692071062, 1319875989881403, 1319914305495870, 1319922165109094,
+    1319923147560747, 1319955568465296, 1320015498016129, 1320056760985555,
+    1320098023954981, 1320175637635568, 1320211988346729, 1320222795314912,
+    1320233602283095, 1320234584734748, 1320284689769051, 1320342654416578,
+    1320350514029802, 1320414373387247, 1320415355838900, 1320502794036017,
+    1320525390424036, 1320535214940566, 1320560758683544, 1320591214684787,
+    1320685530043475, 1320817178564977, 1320868266050933, 1320883985277381,
+    1320886932632340, 1320975353281110, 1321001879475741, 1321008756637312,
+    1321033317928637, 1321047072251779, 1321166931353445, 1321173808515016,
+    1321186580386505, 1321266158970398, 1321403702201818, 1321422368783225,
+    1321458719494386, 1321493105302241, 1321562859369604, 1321678788664658,
+    1321680753567964, 1321685665826229, 1321787840798141, 1321805524927895,
+    1321890998221706, 1321905734996501, 1321917524416337, 1322016752033290,
+    1322080611390735, 1322244680816786, 1322299698109354, 1322357662756881,
+    1322364539918452, 1322367487273411, 1322387136306471, 1322411697597796,
+    1322439206244080, 1322442153599039, 1322461802632099, 1322472609600282,
+    1322510925214749, 1322578714378806, 1322582644185418, 1322619977348232,
+    1322658292962699, 1322663205220964, 1322701520835431, 1322711345351961,
+    1322745731159816, 1322756538127999, 1322894081359419, 1323008045751167,
+    1323009028202820, 1323017870267697, 1323020817622656, 1323032607042492,
+    1323069940205306, 1323078782270183, 1323091554141672, 1323101378658202,
+    1323231062276398, 1323360745894594, 1323382359830960, 1323383342282613,
+    1323580815064866, 1323624042937598, 1323635832357434, 1323721305651245,
+    1323726217909510, 1323764533523977, 1323780252750425, 1323827410429769,
+    1323884392625643, 1323892252238867, 1324056321664918, 1324070075988060,
+    1324087760117814, 1324161443991789, 1324164391346748, 1324178145669890,
+    1324197794702950, 1324243969930641, 1324262636512048, 1324271478576925,
+    1324277373286843, 1324280320641802, 1324333373031064, 1324341232644288,
+    1324472881165790, 1324498424908768, 1324566214072825, 1324593722719109,
+    1324603547235639, 1324608459493904, 1324632038333576, 1324633020785229,
+    1324649722463330, 1324679196012920, 1324685090722838, 1324699827497633,
+    1324722423885652, 1324763686855078, 1324824598857564, 1325033861059653,
+    1325040738221224, 1325085930997262, 1325109509836934, 1325193018227439,
+    1325219544422070, 1325224456680335, 1325241158358436, 1325245088165048,
+    1325275544166291, 1325278491521250, 1325314842232411, 1325360035008449,
+    1325457297722096, 1325501508046481, 1325570279662191, 1325697015925428,
+    1325697998377081, 1325820804833706, 1325846348576684, 1325853225738255,
+    1325867962513050, 1325868944964703, 1325928874515536, 1325954418258514,
+    1325960312968432, 1325981926904798, 1325999611034552, 1326004523292817,
+    1326044803810590, 1326128312201095, 1326352311177979, 1326354276081285,
+    1326425012600301, 1326432872213525, 1326447608988320, 1326451538794932,
+    1326483959699481, 1326504591184194, 1326545854153620, 1326552731315191,
+    1326592029381311, 1326611678414371, 1326649994028838, 1326675537771816,
+    1326750204097444, 1326757081259015, 1326761011065627, 1326778695195381,
+    1326799326680094, 1326872028102416, 1326918203330107, 1326929010298290,
+    1326957501396227, 1327020378302019, 1327095044627647, 1327107816499136,
+    1327115676112360, 1327164798695010, 1327208026567742, 1327213921277660,
+    1327245359730556, 1327328868121061, 1327346552250815, 1327370131090487,
+    1327464446449175, 1327482130578929, 1327486060385541, 1327579393292576,
+    1327657989424816, 1327693357684324, 1327748374976892, 1327759181945075,
+    1327761146848381, 1327782760784747, 1327829918464091, 1327861356916987,
+    1327873146336823, 1327942900404186, 1328030338601303, 1328049005182710,
+    1328058829699240, 1328067671764117, 1328068654215770, 1328090268152136,
+    1328092233055442, 1328153145057928, 1328176723897600, 1328180653704212,
+    1328195390479007, 1328296582999266, 1328328021452162, 1328468512038541,
+    1328479319006724, 1328488161071601, 1328557915138964, 1328573634365412,
+    1328613914883185, 1328694475918731, 1328719037210056, 1329012790254303,
+    1329056018127035, 1329169000067130, 1329199456068373, 1329233841876228,
+    1329273139942348, 1329306543298550, 1329359595687812, 1329374332462607,
+    1329375314914260, 1329376297365913, 1329381209624178, 1329392999044014,
+    1329496156467579, 1329508928339068, 1329559033373371, 1329566892986595,
+    1329581629761390, 1329594401632879, 1329641559312223, 1329674962668425,
+    1329682822281649, 1329688716991567, 1329772225382072, 1329808576093233,
+    1329822330416375, 1329909768613492, 1329947101776306, 1329986399842426,
+    1329994259455650, 1330079732749461, 1330120995718887, 1330167170946578,
+    1330168153398231, 1330252644240389, 1330283100241632, 1330295872113121,
+    1330329275469323, 1330465836249090, 1330512993928434, 1330516923735046,
+    1330544432381330, 1330671168644567, 1330823448650782, 1330873553685085,
+    1330886325556574, 1330929553429306, 1330932500784265, 1330988500528486,
+    1331005202206587, 1331014044271464, 1331035658207830, 1331043517821054,
+    1331067096660726, 1331089693048745, 1331136850728089, 1331154534857843,
+    1331164359374373, 1331188920665698, 1331229201183471, 1331232148538430,
+    1331260639636367, 1331272429056203, 1331396217964481, 1331418814352500,
+    1331426673965724, 1331466954483497, 1331492498226475, 1331534743647554,
+    1331568147003756, 1331622181844671, 1331850110628167, 1331865829854615,
+    1331891373597593, 1331912005082306, 1331926741857101, 1331934601470325,
+    1331943443535202, 1331982741601322, 1331992566117852, 1332022039667442,
+    1332074109605051, 1332190038900105, 1332260775419121, 1332288284065405,
+    1332327582131525, 1332373757359216, 1332391441488970, 1332394388843929,
+    1332444493878232, 1332482809492699, 1332542739043532, 1332564352979898,
+    1332614458014201, 1332650808725362, 1332696983953053, 1332722527696031,
+    1332834527184473, 1332845334152656, 1332882667315470, 1332923930284896,
+    1333145964358474, 1333190174682859, 1333242244620468, 1333331647720891,
+    1333386665013459, 1333468208500658, 1333515366180002, 1333557611601081,
+    1333591014957283, 1333603786828772, 1333613611345302, 1333664698831258,
+    1333693189929195, 1333708909155643, 1333733470446968, 1333736417801927,
+    1333738382705233, 1333786522836230, 1333792417546148, 1333824838450697,
+    1333856276903593, 1333929960777568, 1333947644907322, 1333961399230464,
+    1334051784782540, 1334062591750723, 1334118591494944, 1334242380403222,
+    1334297397695790, 1334326871245380, 1334387783247866, 1334435923378863,
+    1334465396928453, 1334497817833002, 1334542028157387, 1334553817577223,
+    1334634378612769, 1334667781968971, 1334669746872277, 1334675641582195,
+    1334688413453684, 1334737536036334, 1334741465842946, 1334822026878492,
+    1334871149461142, 1334878026622713, 1334924201850404, 1334933043915281,
+    1335034236435540, 1335090236179761, 1335108902761168, 1335115779922739,
+    1335120692181004, 1335132481600840, 1335138376310758, 1335143288569023,
+    1335197323409938, 1335201253216550, 1335243498637629, 1335348620964500,
+    1335450795936412, 1335552970908324, 1335568690134772, 1335599146136015,
+    1335633531943870, 1335738654270741, 1335803496079839, 1335945951569524,
+    1336156196223266, 1336165038288143, 1336179775062938, 1336209248612528,
+    1336214160870793, 1336235774807159, 1336274090421626, 1336341879585683,
+    1336376265393538, 1336404756491475, 1336550159336119, 1336554089142731,
+    1336655281662990, 1336699491987375, 1336777105667962, 1336791842442757,
+    1336798719604328, 1336823280895653, 1336895982317975, 1337028613291130,
+    1337048262324190, 1337054157034108, 1337056121937414, 1337093455100228,
+    1337111139229982, 1337228050976689, 1337315489173806, 1337327278593642,
+    1337350857433314, 1337360681949844, 1337381313434557, 1337443207888696,
+    1337450085050267, 1337468751631674, 1337480541051510, 1337573873958545,
+    1337577803765157, 1337640680670949, 1337662294607315, 1337670154220539,
+    1337690785705252, 1337740890739555, 1337874504164363, 1337887276035852,
+    1338026784170578, 1338040538493720, 1338158432692080, 1338163344950345,
+    1338289098761929, 1338332326634661, 1338350993216068, 1338416817476819,
+    1338423694638390, 1338432536703267, 1338438431413185, 1338470852317734,
+    1338474782124346, 1338481659285917, 1338509167932201, 1338520957352037,
+    1338527834513608, 1338540606385097, 1338579904451217, 1338627062130561,
+    1338644746260315, 1338669307551640, 1338736114264044, 1338754780845451,
+    1338900183690095, 1338928674788032, 1338978779822335, 1338985656983906,
+    1339026919953332, 1339030849759944, 1339058358406228, 1339135972086815,
+    1339138919441774, 1339169375443017, 1339171340346323, 1339186077121118,
+    1339204743702525, 1339240111962033, 1339426777776103, 1339429725131062,
+    1339543689522810, 1339660601269517, 1339702846690596, 1339744109660022,
+    1339772600757959, 1339783407726142, 1339822705792262, 1339986775218313,
+    1340063406447247, 1340136107869569, 1340221581163380, 1340229440776604,
+    1340234353034869, 1340262844132806, 1340297229940661, 1340388597944390,
+    1340510421949362, 1340543825305564, 1340554632273747, 1340597860146479,
+    1340607684663009, 1340634210857640, 1340663684407230, 1340697087763432,
+    1340704947376656, 1340849367769647, 1340861157189483, 1340864104544442,
+    1340968244419660, 1341062559778348, 1341083191263061, 1341099892941162,
+    1341134278749017, 1341151962878771, 1341166699653566, 1341187331138279,
+    1341198138106462, 1341221716946134, 1341227611656052, 1341238418624235,
+    1341261015012254, 1341311120046557, 1341325856821352, 1341363189984166,
+    1341370067145737, 1341451610632936, 1341462417601119, 1341467329859384,
+    1341469294762690, 1341491891150709, 1341495820957321, 1341644171156924,
+    1341661855286678, 1341720802385858, 1341746346128836, 1341748311032142,
+    1341841643939177, 1341852450907360, 1341854415810666, 1341887819166868,
+    1341974274912332, 1341983116977209, 1342010625623493, 1342014555430105,
+    1342043046528042, 1342199256340869, 1342205151050787, 1342364308218573,
+    1342443886802466, 1342476307707015, 1342483184868586, 1342529360096277,
+    1342543114419419, 1342549991580990, 1342620728100006, 1342622693003312,
+    1342624657906618, 1342696376877287, 1342775955461180, 1342820165785565,
+    1342847674431849, 1342857498948379, 1342870270819868, 1342892867207887,
+    1342933147725660, 1342934130177313, 1342937077532272, 1342974410695086,
+    1342980305405004, 1343011743857900, 1343026480632695, 1343084445280222,
+    1343108024119894, 1343132585411219, 1343149287089320, 1343153216895932,
+    1343217076253377, 1343244584899661, 1343257356771150, 1343367391356286,
+    1343369356259592, 1343380163227775, 1343529495879031, 1343545215105479,
+    1343551109815397, 1343555039622009, 1343596302591435, 1343647390077391,
+    1343648372529044, 1343690617950123, 1343698477563347, 1343716161693101,
+    1343803599890218, 1343849775117909, 1343863529441051, 1343872371505928,
+    1343898897700559, 1343938195766679, 1343956862348086, 1343998125317512,
+    1344011879640654, 1344057072416692, 1344062967126610, 1344065914481569,
+    1344105212547689, 1344110124805954, 1344120931774137, 1344136651000585,
+    1344142545710503, 1344185773583235, 1344335106234491, 1344460860046075,
+    1344462824949381, 1344489351144012, 1344562052566334, 1344567947276252,
+    1344613140052290, 1344638683795268, 1344647525860145, 1344663245086593,
+    1344681911668000, 1344793911156442, 1344955033227534, 1344985489228777,
+    1345012997875061, 1345111243040361, 1345170190139541, 1345194751430866,
+    1345203593495743, 1345300856209390, 1345389276858160, 1345507171056520,
+    1345706608742079, 1345707591193732, 1345727240226792, 1345740012098281,
+    1345838257263581, 1345852011586723, 1345879520233007, 1345892292104496,
+    1345982677656572, 1346003309141285, 1346008221399550, 1346096642048320,
+    1346138887469399, 1346143799727664, 1346196852116926, 1346207659085109,
+    1346222395859904, 1346300991992144, 1346308851605368, 1346398254705791,
+    1346425763352075, 1346620288779369, 1346641902715735, 1346776498592196,
+    1346890462983944, 1346891445435597, 1347099725186033, 1347173409060008,
+    1347201900157945, 1347221549191005, 1347273619128614, 1347282461193491,
+    1347312917194734, 1347320776807958, 1347352215260854, 1347466179652602,
+    1347478951524091, 1347488776040621, 1347490740943927, 1347528074106741,
+    1347559512559637, 1347567372172861, 1347631231530306, 1347656775273284,
+    1347691161081139, 1347815932441070, 1347825756957600, 1347875861991903,
+    1347879791798515, 1347923019671247, 1347937756446042, 1347961335285714,
+    1348047791031178, 1348084141742339, 1348129334518377, 1348167650132844,
+    1348201053489046, 1348259018136573, 1348261965491532, 1348295368847734,
+    1348311088074182, 1348335649365507, 1348364140463444, 1348484982016763,
+    1348491859178334, 1348493824081640, 1348502666146517, 1348520350276271,
+    1348541964212637, 1348549823825861, 1348565543052309, 1348585192085369,
+    1348603858666776, 1348616630538265, 1348679507444057, 1348755156221338,
+    1348850454031679, 1348858313644903, 1348863225903168, 1348911366034165,
+    1348928067712266, 1348930032615572, 1348969330681692, 1348988979714752,
+    1349051856620544, 1349094102041623, 1349153049140803, 1349172698173863,
+    1349207083981718, 1349231645273043, 1349300416888753, 1349301399340406,
+    1349332837793302, 1349336767599914, 1349340697406526, 1349343644761485,
+    1349380977924299, 1349414381280501, 1349418311087113, 1349435012765214,
+    1349448767088356, 1349482170444558, 1349491012509435, 1349510661542495,
+    1349550942060268, 1349599082191265, 1349616766321019, 1349646239870609,
+    1349674730968546, 1349699292259871, 1349769046327234, 1349900694848736,
+    1349964554206181, 1350026448660320, 1350058869564869, 1350268131766958,
+    1350287780800018, 1350300552671507, 1350312342091343, 1350320201704567,
+    1350440060806233, 1350459709839293, 1350472481710782, 1350575639134347,
+    1350652270363281, 1350674866751300, 1350681743912871, 1350701392945931,
+    1350759357593458, 1350794725852966, 1350809462627761, 1350828129209168,
+    1350829111660821, 1350830094112474, 1350857602758758, 1350877251791818,
+    1350884128953389, 1350954865472405, 1350971567150506, 1350993181086872,
+    1350998093345137, 1351034444056298, 1351050163282746, 1351066864960847,
+    1351109110381926, 1351115987543497, 1351172969739371, 1351188688965819,
+    1351305600712526, 1351332126907157, 1351353740843523, 1351408758136091,
+    1351749668859682, 1351769317892742, 1351796826539026, 1351803703700597,
+    1351828264991922, 1351868545509695, 1351891141897714, 1351904896220856,
+    1351941246932017, 1351958931061771, 1351967773126648, 1352066018291948,
+    1352089597131620, 1352156403844024, 1352161316102289, 1352164263457248,
+    1352311631205198, 1352415771080416, 1352430507855211, 1352695769801521,
+    1352764541417231, 1352778295740373, 1352787137805250, 1352807769289963,
+    1352830365677982, 1352875558454020, 1352945312521383, 1352990505297421,
+    1353110364399087, 1353259697050343, 1353261661953649, 1353298012664810,
+    1353317661697870, 1353322573956135, 1353378573700356, 1353392328023498,
+    1353447345316066, 1353451275122678, 1353461099639208, 1353474853962350,
+    1353526923899959, 1353571134224344, 1353575064030956, 1353594713064016,
+    1353598642870628, 1353606502483852, 1353699835390887, 1353713589714029,
+    1353731273843783, 1353781378878086, 1353786291136351, 1353822641847512,
+    1353826571654124, 1353829519009083, 1353848185590490, 1353876676688427,
+    1353909097592976, 1353958220175626, 1353984746370257, 1353988676176869,
+    1353998500693399, 1354006360306623, 1354009307661582, 1354087903793822,
+    1354267692446321, 1354302078254176, 1354311902770706, 1354325657093848,
+    1354335481610378, 1354350218385173, 1354396393612864, 1354400323419476,
+    1354405235677741, 1354460252970309, 1354502498391388, 1354526077231060,
+    1354576182265363, 1354632182009584, 1354647901236032, 1354688181753805,
+    1354837514405061, 1354865023051345, 1354873865116222, 1354903338665812,
+    1354911198279036, 1354926917505484, 1354978987443093, 1355008460992683,
+    1355030074929049, 1355065443188557, 1355143056869144, 1355214775839813,
+    1355285512358829, 1355327757779908, 1355387687330741, 1355474143076205,
+    1355484950044388, 1355491827205959, 1355493792109265, 1355512458690672,
+    1355538984885303, 1355565511079934, 1355652949277051, 1355659826438622,
+    1355670633406805, 1355672598310111, 1355804246831613, 1355811123993184,
+    1355882842963853, 1355955544386175, 1355967333806011, 1355993860000642,
+    1356051824648169, 1356078350842800, 1356093087617595, 1356128455877103,
+    1356141227748592, 1356159894329999, 1356204104654384, 1356224736139097,
+    1356289577948195, 1356295472658113, 1356321016401091, 1356334770724233,
+    1356443822857716, 1356468384149041, 1356592173057319, 1356594137960625,
+    1356596102863931, 1356613786993685, 1356623611510215, 1356639330736663,
+    1356648172801540, 1356701225190802, 1356705154997414, 1356725786482127,
+    1356734628547004, 1356753295128411, 1356765084548247, 1356769014354859,
+    1356848592938752, 1356870206875118, 1356912452296197, 1356930136425951,
+    1356932101329257, 1356973364298683, 1357022486881333, 1357076521722248,
+    1357128591659857, 1357147258241264, 1357194415920608, 1357232731535075,
+    1357299538247479, 1357406625477656, 1357478344448325, 1357645361229335,
+    1357647326132641, 1357655185745865, 1357676799682231, 1357684659295455,
+    1357766202782654, 1357781922009102, 1357816307816957, 1357852658528118,
+    1357921430143828, 1357955815951683, 1357976447436396, 1358027534922352,
+    1358079604859961, 1358096306538062, 1358127744990958, 1358153288733936,
+    1358174902670302, 1358181779831873, 1358191604348403, 1358257428609154,
+    1358285919707091, 1358303603836845, 1358428375196776, 1358524655458770,
+    1358540374685218, 1358622900624070, 1358635672495559, 1358714268627799,
+    1358767321017061, 1358882267860462, 1358913706313358, 1358955951734437,
+    1359089565159245, 1359090547610898, 1359125915870406, 1359131810580324,
+    1359184862969586, 1359255599488602, 1359267388908438, 1359280160779927,
+    1359369563880350, 1359386265558451, 1359427528527877, 1359472721303915,
+    1359478616013833, 1359506124660117, 1359530685951442, 1359739948153531,
+    1359742895508490, 1359748790218408, 1359774333961386, 1359793982994446,
+    1359838193318831, 1359842123125443, 1359847035383708, 1359939385839090,
+    1359942333194049, 1359972789195292, 1359992438228352, 1360014052164718,
+    1360017981971330, 1360039595907696, 1360054332682491, 1360069069457286,
+    1360087736038693, 1360164367267627, 1360176156687463, 1360226261721766,
+    1360417839794101, 1360427664310631, 1360446330892038, 1360482681603199,
+    1360494471023035, 1360522962120972, 1360524927024278, 1360741066387938,
+    1360814750261913, 1360940504073497, 1360963100461516, 1361009275689207,
+    1361014187947472, 1361017135302431, 1361018117754084, 1361039731690450,
+    1361186116986747, 1361234257117744, 1361272572732211, 1361316783056596,
+    1361435659706609, 1361436642158262, 1361456291191322, 1361485764740912,
+    1361499519064054, 1361536852226868, 1361558466163234, 1361586957261171,
+    1361595799326048, 1361638044747127, 1361644921908698, 1361662606038452,
+    1361683237523165, 1361687167329777, 1361690114684736, 1361701904104572,
+    1361707798814490, 1361736289912427, 1361781482688465, 1361839447335992,
+    1361860078820705, 1361896429531866, 1361909201403355, 1361939657404598,
+    1362043797279816, 1362072288377753, 1362089972507507, 1362103726830649,
+    1362134182831892, 1362298252257943, 1362380778196795, 1362497689943502,
+    1362555654591029, 1362626391110045, 1362812074472462, 1362867091765030,
+    1362876916281560, 1362889688153049, 1362909337186109, 1363040985707611,
+    1363085196031996, 1363159862357624, 1363210949843580, 1363228633973334,
+    1363238458489864, 1363269896942760, 1363331791396899, 1363333756300205,
+    1363342598365082, 1363347510623347, 1363377966624590, 1363452632950218,
+    1363497825726256, 1363521404565928, 1363523369469234, 1363525334372540,
+    1363564632438660, 1363615719924616, 1363637333860982, 1363659930249001,
+    1363679579282061, 1363696280960162, 1363710035283304, 1363729684316364,
+    1363903578258945, 1363928139550270, 1363964490261431, 1364031296973835,
+    1364064700330037, 1364271997628820, 1364345681502795, 1364351576212713,
+    1364353541116019, 1364384979568915, 1364400698795363, 1364512698283805,
+    1364528417510253, 1364541189381742, 1364567715576373, 1364667925644979,
+    1364714100872670, 1364732767454077, 1364772065520197, 1364814310941276,
+    1364825117909459, 1364829047716071, 1364873258040456, 1364952836624349,
+    1364967573399144, 1364980345270633, 1364986239980551, 1365094309662381,
+    1365170940891315, 1365285887734716, 1365307501671082, 1365383150448363,
+    1365405746836382, 1365408694191341, 1365479430710357, 1365483360516969,
+    1365503992001682, 1365510869163253, 1365555079487638, 1365634658071531,
+    1365669043879386, 1365721113816995, 1365791850336011, 1365804622207500,
+    1365892060404617, 1365900902469494, 1366007989699671, 1366008972151324,
+    1366053182475709, 1366100340155053, 1366138655769520, 1366142585576132,
+    1366143568027785, 1366155357447621, 1366197602868700, 1366198585320353,
+    1366271286742675, 1366328268938549, 1366355777584833, 1366431426362114,
+    1366508057591048, 1366671144565446, 1366682933985282, 1366708477728260,
+    1366769389730746, 1366860757734475, 1366895143542330, 1366896125993983,
+    1366909880317125, 1366935424060103, 1366952125738204, 1366976687029529,
+    1367011072837384, 1367015985095649, 1367071002388217, 1367083774259706,
+    1367104405744419, 1367137809100621, 1367138791552274, 1367161387940293,
+    1367165317746905, 1367168265101864, 1367221317491126, 1367295983816754,
+    1367297948720060, 1367301878526672, 1367416825370073, 1367525877503556,
+    1367555351053146, 1367571070279594, 1367626087572162, 1367664403186629,
+    1367694859187872, 1367733174802339, 1367789174546560, 1367868753130453,
+    1367892331970125, 1367977805263936, 1368157593916435, 1368177242949495,
+    1368183137659413, 1368216541015615, 1368285312631325, 1368351136892076,
+    1368447417154070, 1368577100772266, 1368666503872689, 1368692047615667,
+    1368694012518973, 1368725450971869, 1368734293036746, 1368749029811541,
+    1368758854328071, 1368849239880147, 1368885590591308, 1368915064140898,
+    1368979905949996, 1369085028276867, 1369160677054148, 1369199975120268,
+    1369213729443410, 1369220606604981, 1369372886611196, 1369444605581865,
+    1369555622618654, 1369575271651714, 1369631271395935, 1369649937977342,
+    1369766849724049, 1369782568950497, 1369789446112068, 1369794358370333,
+    1369848393211248, 1369851340566207, 1369891621083980, 1369946638376548,
+    1369958427796384, 1369966287409608, 1369974147022832, 1369982006636056,
+    1370024252057135, 1370046848445154, 1370106777995987, 1370281654390221,
+    1370371057490644, 1370424109879906, 1370445723816272, 1370524319948512,
+    1370608810790670, 1370609793242323, 1370632389630342, 1370634354533648,
+    1370745371570437, 1370789581894822, 1370794494153087, 1370837722025819,
+    1370843616735737, 1370849511445655, 1370881932350204, 1370901581383264,
+    1370928107577895, 1370936949642772, 1370948739062608, 1371066633260968,
+    1371077440229151, 1371189439717593, 1371296526947770, 1371349579337032,
+    1371372175725051, 1371407543984559, 1371453719212250, 1371587332637058,
+    1371627613154831, 1371631542961443, 1371633507864749, 1371663963865992,
+    1371676735737481, 1371712103996989, 1371713086448642, 1371725858320131,
+    1371733717933355, 1371832945550308, 1371885997939570, 1372012734202807,
+    1372091330335047, 1372125716142902, 1372128663497861, 1372140452917697,
+    1372155189692492, 1372230838469773, 1372258347116057, 1372270136535893,
+    1372390978089212, 1372516731900796, 1372736801071068, 1372752520297516,
+    1372756450104128, 1372946063273157, 1372975536822747, 1372985361339277,
+    1372999115662419, 1373037431276886, 1373069852181435, 1373072799536394,
+    1373182834121530, 1373230974252527, 1373255535543852, 1373277149480218,
+    1373293851158319, 1373307605481461, 1373326272062868, 1373347885999234,
+    1373372447290559, 1373384236710395, 1373460867939329, 1373484446779001,
+    1373642621495134, 1373678972206295, 1373795883953002, 1373822410147633,
+    1373880374795160, 1373887251956731, 1373916725506321, 1373918690409627,
+    1374054268737741, 1374085707190637, 1374141706934858, 1374200654034038,
+    1374227180228669, 1374260583584871, 1374296934296032, 1374303811457603,
+    1374317565780745, 1374350969136947, 1374359811201824, 1374365705911742,
+    1374460021270430, 1374477705400184, 1374485565013408, 1374490477271673,
+    1374616231083257, 1374628020503093, 1374643739729541, 1374652581794418,
+    1374663388762601, 1374669283472519, 1374701704377068, 1374743949798147,
+    1374786195219226, 1374853984383283, 1374901142062627, 1374931598063870,
+    1375018053809334, 1375033773035782, 1375116298974634, 1375155597040754,
+    1375193912655221, 1375198824913486, 1375264649174237, 1375272508787461,
+    1375290192917215, 1375351104919701, 1375383525824250, 1375477841182938,
+    1375485700796162, 1375490613054427, 1375529911120547, 1375535805830465,
+    1375536788282118, 1375587875768074, 1375780436292062, 1375809909841652,
+    1375847243004466, 1375898330490422, 1375911102361911, 1375920926878441,
+    1375990680945804, 1376046680690025, 1376092855917716, 1376135101338795,
+    1376138048693754, 1376322749604518, 1376328644314436, 1376344363540884,
+    1376356152960720, 1376360082767332, 1376363030122291, 1376382679155351,
+    1376427871931389, 1376429836834695, 1376449485867755, 1376489766385528,
+    1376550678388014, 1376587029099175, 1376638116585131, 1376652853359926,
+    1376660712973150, 1376832642012425, 1376839519173996, 1376882747046728,
+    1376940711694255, 1376962325630621, 1376970185243845, 1376983939566987,
+    1377133272218243, 1377172570284363, 1377176500090975, 1377181412349240,
+    1377220710415360, 1377267868094704, 1377308148612477, 1377349411581903,
+    1377391657002982, 1377400499067859, 1377446674295550, 1377524287976137,
+    1377526252879443, 1377601901656724, 1377636287464579, 1377644147077803,
+    1377655936497639, 1377724708113349, 1377807234052201, 1377813128762119,
+    1377982110446435, 1378012566447678, 1378042039997268, 1378077408256776,
+    1378147162324139, 1378208074326625, 1378273898587376, 1378288635362171,
+    1378324986073332, 1378352494719616, 1378567651631623, 1378584353309724,
+    1378608914601049, 1378615791762620, 1378646247763863, 1378649195118822,
+    1378661966990311, 1378687510733289, 1378704212411390, 1378723861444450,
+    1378802457576690, 1378845685449422, 1378919369323397, 1378922316678356,
+    1378987158487454, 1379074596684571, 1379083438749448, 1379108000040773,
+    1379140420945322, 1379151227913505, 1379197403141196, 1379199368044502,
+    1379261262498641, 1379338876179228, 1379372279535430, 1379398805730061,
+    1379403717988326, 1379456770377588, 1379573682124295, 1379617892448680,
+    1379620839803639, 1379677821999513, 1379719084968939, 1379723014775551,
+    1379731856840428, 1379732839292081, 1379735786647040, 1379797681101179,
+    1379862522910277, 1379926382267722, 1379944066397476, 1379977469753678,
+    1380096346403691, 1380107153371874, 1380427432610752, 1380434309772323,
+    1380497186678115, 1380516835711175, 1380617045779781, 1380641607071106,
+    1380656343845901, 1380694659460368, 1380741817139712, 1380753606559548,
+    1380808623852116, 1380814518562034, 1380859711338072, 1380908833920722,
+    1380914728630640, 1380929465405435, 1381077815605038, 1381090587476527,
+    1381172130963726, 1381329323228206, 1381406936908793, 1381477673427809,
+    1381567076528232, 1381661391886920, 1381665321693532, 1381801882473299,
+    1381841180539419, 1381845110346031, 1381874583895621, 1381899145186946,
+    1381964969447697, 1381977741319186, 1381987565835716, 1382074021581180,
+    1382094653065893, 1382173249198133, 1382326511656001, 1382345178237408,
+    1382362862367162, 1382376616690304, 1382409037594853, 1382441458499402,
+    1382494510888664, 1382591773602311, 1382628124313472, 1382652685604797,
+    1382867842516804, 1382896333614741, 1382914017744495, 1382938579035820,
+    1382964122778798, 1382971982392022, 1383005385748224, 1383087911687076,
+    1383154718399480, 1383193034013947, 1383281454662717, 1383601733901595,
+    1383677382678876, 1383679347582182, 1383707838680119, 1383852259073110,
+    1383911206172290, 1384042854693792, 1384044819597098, 1384139134955786,
+    1384236397669433, 1384270783477288, 1384398502192178, 1384556676908311,
+    1384573378586412, 1384601869684349, 1384605799490961, 1384630360782286,
+    1384685378074854, 1384729588399239, 1384768886465359, 1384817026596356,
+    1384874991243883, 1384953587376123, 1385030218605057, 1385070499122830,
+    1385165796933171, 1385269936808389, 1385271901711695, 1385370146876995,
+    1385376041586913, 1385414357201380, 1385443830750970, 1385509655011721,
+    1385564672304289, 1385628531661734, 1385714987407198, 1385737583795217,
+    1385766074893154, 1385772952054725, 1385786706377867, 1385792601087785,
+    1385796530894397, 1385827969347293, 1385860390251842, 1385864320058454,
+    1385868249865066, 1385920319802675, 1386019547419628, 1386048038517565,
+    1386062775292360, 1386127617101458, 1386138424069641, 1386262212977919,
+    1386278914656020, 1386316247818834, 1386335896851894, 1386407615822563,
+    1386433159565541, 1386476387438273, 1386531404730841, 1386597228991592,
+    1386682702285403, 1386689579446974, 1386745579191195, 1386754421256072,
+    1386804526290375, 1386864455841208, 1386876245261044, 1386940104618489,
+    1387044244493707, 1387058981268502, 1387062911075114, 1387090419721398,
+    1387098279334622, 1387117928367682, 1387122840625947, 1387288874955304,
+    1387294769665222, 1387326208118118, 1387344874699525, 1387360593925973,
+    1387368453539197, 1387393014830522, 1387439190058213, 1387551189546655,
+    1387577715741286, 1387642557550384, 1387651399615261, 1387686767874769,
+    1387703469552870, 1387820381299577, 1387831188267760, 1387853784655779,
+    1387871468785533, 1387942205304549, 1387967749047527, 1388020801436789,
+    1388037503114890, 1388063046857868, 1388078766084316, 1388082695890928,
+    1388130836021925, 1388283116028140, 1388317501835995, 1388393150613276,
+    1388407887388071, 1388553290232715, 1388562132297592, 1388569009459163,
+    1388575886620734, 1388577851524040, 1388626974106690, 1388715394755460,
+    1388732096433561, 1388733078885214, 1388761569983151, 1388764517338110,
+    1388765499789763, 1388816587275719, 1388873569471593, 1388963955023669,
+    1389113287674925, 1389137848966250, 1389155533096004, 1389174199677411,
+    1389191883807165, 1389205638130307, 1389219392453449, 1389299953488995,
+    1389313707812137, 1389356935684869, 1389447321236945, 1389452233495210,
+    1389465005366699, 1389546548853898, 1389572092596876, 1389582899565059,
+    1389728302409703, 1389739109377886, 1389752863701028, 1389760723314252,
+    1389774477637394, 1389825565123350, 1389831459833268, 1389896301642366,
+    1389902196352284, 1390049564100234, 1390074125391559, 1390078055198171,
+    1390165493395288, 1390289282303566, 1390315808498197, 1390450404374658,
+    1390451386826311, 1390573210831283, 1390589912509384, 1390636087737075,
+    1390674403351542, 1390683245416419, 1390719596127580, 1390756929290394,
+    1390794262453208, 1390796227356514, 1390871876133795, 1390897419876773,
+    1390903314586691, 1390925910974710, 1390931805684628, 1390959314330912,
+    1390988787880502, 1391001559751991, 1391084085690843, 1391228506083834,
+    1391245207761935, 1391249137568547, 1391280576021443, 1391322821442522,
+    1391337558217317, 1391362119508642, 1391455452415677, 1391479031255349,
+    1391691240812397, 1391696153070662, 1391759029976454, 1391811099914063,
+    1391981064050032, 1392003660438051, 1392030186632682, 1392048853214089,
+    1392170677219061, 1392243378641383, 1392262045222790, 1392279729352544,
+    1392313132708746, 1392340641355030, 1392473272328185, 1392479167038103,
+    1392493903812898, 1392560710525302, 1392592148978198, 1392605903301340,
+    1392651096077378, 1392742464081107, 1392806323438552, 1392871165247650,
+    1392880007312527, 1392886884474098, 1392895726538975, 1392896708990628,
+    1392914393120382, 1392974322671215, 1393019515447253, 1393189479583222,
+    1393282812490257, 1393316215846459, 1393381057655557, 1393382040107210,
+    1393397759333658, 1393414461011759, 1393487162434081, 1393716073669230,
+    1393759301541962, 1393901757031647, 1393954809420909, 1393962669034133,
+    1393991160132070, 1394040282714720, 1394152282203162, 1394158176913080,
+    1394177825946140, 1394207299495730, 1394224983625484, 1394372351373434,
+    1394433263375920, 1394483368410223, 1394603227511889, 1394627788803214,
+    1394706384935454, 1394770244292899, 1394835086101997, 1394926454105726,
+    1394956910106969, 1394957892558622, 1395025681722679, 1395088558628471,
+    1395113119919796, 1395123926887979, 1395153400437569, 1395342031154945,
+    1395366592446270, 1395453048191734, 1395472697224794, 1395538521485545,
+    1395551293357034, 1395571924841747, 1395720275041350, 1395775292333918,
+    1395818520206650, 1395892204080625, 1395958028341376, 1396004203569067,
+    1396397184230267, 1396417815714980, 1396421745521592, 1396467920749283,
+    1396601534174091, 1396631990175334, 1396636902433599, 1396674235596413,
+    1396685042564596, 1396788199988161, 1396858936507177, 1396896269669991,
+    1396971918447272, 1397041672514635, 1397048549676206, 1397078023225796,
+    1397196899875809, 1397231285683664, 1397273531104743, 1397293180137803,
+    1397448407498977, 1397541740406012, 1397580056020479, 1397690090605615,
+    1397694020412227, 1397714651896940, 1397757879769672, 1397764756931243,
+    1397836475901912, 1397851212676707, 1397887563387868, 1397924896550682,
+    1398074229201938, 1398136123656077, 1398300193082128, 1398369947149491,
+    1398374859407756, 1398375841859409, 1398408262763958, 1398446578378425,
+    1398481946637933, 1398493736057769, 1398635209095801, 1398696121098287,
+    1398870015040868, 1398888681622275, 1398909313106988, 1399060610661550,
+    1399076329887998, 1399204048602888, 1399211908216112, 1399299346413229,
+    1399390714416958, 1399430012483078, 1399439836999608, 1399454573774403,
+    1399573450424416, 1399586222295905, 1399598011715741, 1399713941010795,
+    1399715905914101, 1399759133786833, 1399773870561628, 1399884887598417,
+    1400060746444304, 1400069588509181, 1400192394965806, 1400201237030683,
+    1400205166837295, 1400270008646393, 1400300464647636, 1400335832907144,
+    1400429165814179, 1400447832395586, 1400462569170381, 1400543130205927,
+    1400607972015025, 1400714076793549, 1400829023636950, 1400896812801007,
+    1400905654865884, 1401063829582017, 1401113934616320, 1401114917067973,
+    1401115899519626, 1401153232682440, 1401213162233273, 1401240670879557,
+    1401258355009311, 1401402775402302, 1401448950629993, 1401564879925047,
+    1401592388571331, 1401595335926290, 1401679826768448, 1401805580580032,
+    1401819334903174, 1401891053873843, 1401964737747818, 1402052175944935,
+    1402063965364771, 1402070842526342, 1402103263430891, 1402157298271806,
+    1402225087435863, 1402251613630494, 1402282069631737, 1402366560473895,
+    1402409788346627, 1402450068864400, 1402460875832583, 1402504103705315,
+    1402509015963580, 1402523752738375, 1402657366163183, 1402695681777650,
+    1402721225520628, 1402759541135095, 1402798839201215, 1402805716362786,
+    1402832242557417, 1402833225009070, 1402834207460723, 1402938347335941,
+    1402947189400818, 1403008101403304, 1403072943212402, 1403083750180585,
+    1403099469407033, 1403257644123166, 1403274345801267, 1403282205414491,
+    1403287117672756, 1403305784254163, 1403360801546731, 1403430555614094,
+    1403446274840542, 1403456099357072, 1403604449556675, 1403628028396347,
+    1403655537042631, 1403774413692644, 1403806834597193, 1403825501178600,
+    1403832378340171, 1403870693954638, 1404044587897219, 1404109429706317,
+    1404283323648898, 1404301007778652, 1404419884428665, 1404452305333214,
+    1404532866368760, 1404599673081164, 1404684163923322, 1404744093474155,
+    1404753917990685, 1404788303798540, 1404802058121682, 1404849215801026,
+    1404862970124168, 1404960232837815, 1405009355420465, 1405010337872118,
+    1405070267422951, 1405162617878333, 1405280512076693, 1405309003174630,
+    1405351248595709, 1405404300984971, 1405431809631255, 1405437704341173,
+    1405494686537047, 1405556580991186, 1405570335314328, 1405581142282511,
+    1405610615832101, 1405618475445325, 1405668580479628, 1405675457641199,
+    1405795316742865, 1405805141259395, 1405813000872619, 1406091034690418,
+    1406182402694147, 1406274753149529, 1406280647859447, 1406333700248709,
+    1406343524765239, 1406369068508217, 1406382822831359, 1406404436767725,
+    1406587172775183, 1406609769163202, 1406645137422710, 1406670681165688,
+    1406948714983487, 1407048925052093, 1407073486343418, 1407222818994674,
+    1407293555513690, 1407454677584782, 1407474326617842, 1407487098489331,
+    1407588291009590, 1407633483785628, 1407664922238524, 1407774956823660,
+    1407823096954657, 1407891868570367, 1407914464958386, 1407918394764998,
+    1407979306767484, 1408020569736910, 1408050043286500, 1408283866779914,
+    1408292708844791, 1408314322781157, 1408346743685706, 1408406673236539,
+    1408454813367536, 1408462672980760, 1408463655432413, 1408464637884066,
+    1408482322013820, 1408504918401839, 1408521620079940, 1408523584983246,
+    1408546181371265, 1408551093629530, 1408553058532836, 1408614952986975,
+    1408708285894010, 1408733829636988, 1408762320734925, 1408812425769228,
+    1408823232737411, 1408833057253941, 1408856636093613, 1408867443061796,
+    1408895934159733, 1408943091839077, 1408982389905197, 1409216213398611,
+    1409237827334977, 1409250599206466, 1409280072756056, 1409304634047381,
+    1409359651339949, 1409377335469703, 1409435300117230, 1409436282568883,
+    1409451019343678, 1409486387603186, 1409501124377981, 1409508001539552,
+    1409526668120959, 1409630807996177, 1409658316642461, 1409767368775944,
+    1409821403616859, 1409840070198266, 1409867578844550, 1409912771620588,
+    1409923578588771, 1409930455750342, 1410096490079699, 1410168209050368,
+    1410176068663592, 1410205542213182, 1410220278987977, 1410247787634261,
+    1410392208027252, 1410445260416514, 1410487505837593, 1410539575775202,
+    1410610312294218, 1410612277197524, 1410637820940502, 1410650592811991,
+    1410652557715297, 1410664347135133, 1410681048813234, 1410754732687209,
+    1410760627397127, 1410782241333493, 1410872626885569, 1410888346112017,
+    1410896205725241, 1410984626374011, 1411052415538068, 1411075011926087,
+    1411142801090144, 1411150660703368, 1411166379929816, 1411558378139363,
+    1411612412980278, 1411624202400114, 1411634026916644, 1411689044209212,
+    1411690026660865, 1411693956467477, 1411698868725742, 1411727359823679,
+    1411755850921616, 1411847218925345, 1411854096086916, 1411886516991465,
+    1411963148220399, 1411990656866683, 1412035849642721, 1412037814546027,
+    1412079077515453, 1412092831838595, 1412125252743144, 1412174375325794,
+    1412261813522911, 1412277532749359, 1412319778170438, 1412417040884085,
+    1412436689917145, 1412449461788634, 1412508408887814, 1412517250952691,
+    1412553601663852, 1412594864633278, 1412624338182868, 1412626303086174,
+    1412693109798578, 1412711776379985, 1412723565799821, 1412724548251474,
+    1412775635737430, 1412797249673796, 1412926933291992, 1412929880646951,
+    1413001599617620, 1413066441426718, 1413069388781677, 1413150932268876,
+    1413202019754832, 1413210861819709, 1413228545949463, 1413324826211457,
+    1413327773566416, 1413332685824681, 1413335633179640, 1413341527889558,
+    1413368054084189, 1413419141570145, 1413526228800322, 1413557667253218,
+    1413642158095376, 1413698157839597, 1413776753971837, 1413884823653667,
+    1413929033978052, 1413947700559459, 1413991910883844, 1413995840690456,
+    1414012542368557, 1414014507271863, 1414062647402860, 1414162857471466,
+    1414222787022299, 1414285663928091, 1414434014127694, 1414587276585562,
+    1414695346267392, 1414714995300452, 1414871205113279, 1414921310147582,
+    1414984187053374, 1415038221894289, 1415200326417034, 1415352606423249,
+    1415385027327798, 1415674850565433, 1415677797920392, 1415683692630310,
+    1415711201276594, 1415729867858001, 1415872323347686, 1415961726448109,
+    1415982357932822, 1415995129804311, 1416058006710103, 1416132673035731,
+    1416146427358873, 1416150357165485, 1416153304520444, 1416164111488627,
+    1416208321813012, 1416349794851044, 1416469653952710, 1416487338082464,
+    1416571828924622, 1416640600540332, 1416665161831657, 1416738845705632,
+    1416771266610181, 1416802705063077, 1416812529579607, 1416825301451096,
+    1416969721844087, 1417000177845330, 1417074844170958, 1417078773977570,
+    1417129861463526, 1417136738625097, 1417209440047419, 1417236948693703,
+    1417308667664372, 1417309650116025, 1417462912573893, 1417579824320600,
+    1417723262261938, 1417735051681774, 1418076944857018, 1418086769373548,
+    1418098558793384, 1418136874407851, 1418197786410337, 1418307820995473,
+    1418415890677303, 1418463048356647, 1418486627196319, 1418699819205020,
+    1418702766559979, 1418711608624856, 1418720450689733, 1418740099722793,
+    1418748941787670, 1418764661014118, 1418793152112055, 1418842274694705,
+    1418910063858762, 1418912028762068, 1418934625150087, 1419010273927368,
+    1419037782573652, 1419254904388965, 1419406201943527, 1419472026204278,
+    1419539815368335, 1419553569691477, 1419595815112556, 1419655744663389,
+    1419663604276613, 1419700937439427, 1419804094862992, 1419833568412582,
+    1419846340284071, 1419951462610942, 1419964234482431, 1419970129192349,
+    1420005497451857, 1420038900808059, 1420044795517977, 1420078198874179,
+    1420104725068810, 1420111602230381, 1420198057975845, 1420275671656432,
+    1420303180302716, 1420350337982060, 1420372934370079, 1420391600951486,
+    1420396513209751, 1420425004307688, 1420427951662647, 1420492793471745,
+    1420567459797373, 1420608722766799, 1420664722511020, 1420760020321361,
+    1420777704451115, 1420801283290787, 1420810125355664, 1420907388069311,
+    1420929002005677, 1420932931812289, 1421031176977589, 1421079317108586,
+    1421146123820990, 1421162825499091, 1421330824731754, 1421344579054896,
+    1421422192735483, 1421434964606972, 1421440859316890, 1421451666285073,
+    1421488999447887, 1421493911706152, 1421502753771029, 1421573490290045,
+    1421597069129717, 1421606893646247, 1421646191712367, 1421685489778487,
+    1421708086166506, 1421723805392954, 1421727735199566, 1421884927464046,
+    1421985137532652, 1422076505536381, 1422204224251271, 1422212083864495,
+    1422235662704167, 1422280855480205, 1422285767738470, 1422331942966161,
+    1422342749934344, 1422350609547568, 1422416433808319, 1422431170583114,
+    1422511731618660, 1422514678973619, 1422587380395941, 1422660081818263,
+    1422731800788932, 1422746537563727, 1422789765436459, 1422809414469519,
+    1422865414213740, 1422866396665393, 1423018676671608, 1423051097576157,
+    1423096290352195, 1423133623515009, 1423135588418315, 1423160149709640,
+    1423210254743943, 1423223026615432, 1423225973970391, 1423251517713369,
+    1423340920813792, 1423363517201811, 1423399867912972, 1423411657332808,
+    1423417552042726, 1423548218112575, 1423555095274146, 1423570814500594,
+    1423607165211755, 1423614042373326, 1423816427413844, 1423861620189882,
+    1424040426390728, 1424153408330823, 1424245758786205, 1424307653240344,
+    1424328284725057, 1424408845760603, 1424419652728786, 1424456003439947,
+    1424507090925903, 1424547371443676, 1424667230545342, 1424736002161052,
+    1424751721387500, 1424770387968907, 1424829335068087, 1424939369653223,
+    1424943299459835, 1424963930944548, 1425019930688769, 1425329402959464,
+    1425333332766076, 1425500349547086, 1425578945679326, 1425595647357427,
+    1425694874974380, 1425797049946292, 1425833400657453, 1425950312404160,
+    1425982733308709, 1426018101568217, 1426072136409132, 1426087855635580,
+    1426171364026085, 1426254872416590, 1426306942354199, 1426319714225688,
+    1426342310613707, 1426371784163297, 1426396345454622, 1426415012036029,
+    1426522099266206, 1426577116558774, 1426590870881916, 1426594800688528,
+    1426664554755891, 1426679291530686, 1426830589085248, 1426912132572447,
+    1426996623414605, 1427045745997255, 1427140061355943, 1427230446908019,
+    1427232411811325, 1427357183171256, 1427494726402676, 1427529112210531,
+    1427572340083263, 1427592971567976, 1427668620345257, 1427677462410134,
+    1427711848217989, 1427712830669642, 1427732479702702, 1427789461898576,
+    1427903426290324, 1427933882291567, 1427952548872974, 1428031145005214,
+    1428114653395719, 1428121530557290, 1428158863720104, 1428206021399448,
+    1428227635335814, 1428325880501114, 1428342582179215, 1428375003083764,
+    1428395634568477, 1428449669409392, 1428499774443695, 1428561668897834,
+    1428596054705689, 1428630440513544, 1428714931355702, 1428866228910264,
+    1429002789690031, 1429053877175987, 1429176683632612, 1429182578342530,
+    1429187490600795, 1429220893956997, 1429222858860303, 1429247420151628,
+    1429319139122297, 1429448822740493, 1429709172428538, 1429710154880191,
+    1429783838754166, 1429802505335573, 1429820189465327, 1429839838498387,
+    1429840820950040, 1429882083919466, 1430006855279397, 1430012749989315,
+    1430014714892621, 1430048118248823, 1430157170382306, 1430213170126527,
+    1430220047288098, 1430222012191404, 1430399835940597, 1430529519558793,
+    1430665097886907, 1430692606533191, 1430723062534434, 1430733869502617,
+    1430760395697248, 1430801658666674, 1430886149508832, 1430899903831974,
+    1430926430026605, 1430939201898094, 1430948043962971, 1430976535060908,
+    1431041376870006, 1431073797774555, 1431079692484473, 1431086569646044,
+    1431126850163817, 1431132744873735, 1431202498941098, 1431237867200606,
+    1431240814555565, 1431285024879950, 1431301726558051, 1431452041660960,
+    1431527690438241, 1431538497406424, 1431692742315945, 1431767408641573,
+    1431779198061409, 1431797864642816, 1431802776901081, 1431965863875479,
+    1432032670587883, 1432104389558552, 1432174143625915, 1432208529433770,
+    1432219336401953, 1432338213051966, 1432416809184206, 1432444317830490,
+    1432448247637102, 1432462001960244, 1432490493058181, 1432518001704465,
+    1432540598092484, 1432621159128030, 1432630001192907, 1432670281710680,
+    1432716456938371, 1432742983133002, 1432792105715652, 1432883473719381,
+    1432937508560296, 1433022981854107, 1433040665983861, 1433143823407426,
+    1433163472440486, 1433170349602057, 1433189016183464, 1433194910893382,
+    1433467050001263, 1433476874517793, 1433504383164077, 1433514207680607,
+    1433540733875238, 1433558418004992, 1433564312714910, 1433592803812847,
+    1433638979040538, 1433659610525251, 1433818767693037, 1433842346532709,
+    1433866907824034, 1433868872727340, 1433998556345536, 1434163608223240,
+    1434198976482748, 1434206836095972, 1434254976226969, 1434256941130275,
+    1434290344486477, 1434302133906313, 1434365993263758, 1434401361523266,
+    1434551676626175, 1434617500886926, 1434665641017923, 1434683325147677,
+    1434688237405942, 1434772728248100, 1434806131604302, 1434811043862567,
+    1434816938572485, 1434864096251829, 1434876868123318, 1435120516133262,
+    1435213849040297, 1435268866332865, 1435382830724613, 1435396585047755,
+    1435422128790733, 1435460444405200, 1435654969832494, 1435677566220513,
+    1435786618353996, 1435809214742015, 1435844583001523, 1435855389969706,
+    1435884863519296, 1435929073843681, 1436041073332123, 1436066617075101,
+    1436104932689568, 1436142265852382, 1436161914885442, 1436320089601575,
+    1436415387411916, 1436425211928446, 1436504790512339, 1436507737867298,
+    1436573562128049, 1436599105871027, 1436605983032598, 1436611877742516,
+    1436633491678882, 1436636439033841, 1436766122652037, 1436805420718157,
+    1436806403169810, 1436815245234687, 1436864367817337, 1437074612471079,
+    1437130612215300, 1437141419183483, 1437144366538442, 1437149278796707,
+    1437159103313237, 1437169910281420, 1437299593899616, 1437302541254575,
+    1437330049900859, 1437359523450449, 1437368365515326, 1437406681129793,
+    1437414540743017, 1437428295066159, 1437459733519055, 1437607101267005,
+    1437630680106677, 1437865486051744, 1437951941797208, 1437983380250104,
+    1437998117024899, 1438074748253833, 1438082607867057, 1438099309545158,
+    1438101274448464, 1438136642707972, 1438197554710458, 1438236852776578,
+    1438239800131537, 1438241765034843, 1438254536906332, 1438259449164597,
+    1438279098197657, 1438356711878244, 1438467728915033, 1438508991884459,
+    1438578745951822, 1438603307243147, 1438619026469595, 1438656359632409,
+    1438668149052245, 1438672078858857, 1438734955764649, 1438739868022914,
+    1438819446606807, 1438991375646082, 1439018884292366, 1439022814098978,
+    1439025761453937, 1439063094616751, 1439100427779565, 1439180988815111,
+    1439235023656026, 1439278251528758, 1439304777723389, 1439365689725875,
+    1439372566887446, 1439380426500670, 1439610320187472, 1439688916319712,
+    1439699723287895, 1439784214130053, 1439804845614766, 1439872634778823,
+    1439910950393290, 1440019020075120, 1440026879688344, 1440144773886704,
+    1440190949114395, 1440204703437537, 1440262668085064, 1440271510149941,
+    1440275439956553, 1440287229376389, 1440347158927222, 1440365825508629,
+    1440398246413178, 1440492561771866, 1440554456226005, 1440567228097494,
+    1440675297779324, 1440712630942138, 1440740139588422, 1440760771073135,
+    1440863928496700, 1440868840754965, 1440871788109924, 1440935647467369,
+    1440985752501672, 1441017190954568, 1441147857024417, 1441194032252108,
+    1441196979607067, 1441226453156657, 1441233330318228, 1441234312769881,
+    1441247084641370, 1441286382707490, 1441359084129812, 1441390522582708,
+    1441454381940153, 1441504486974456, 1441523153555863, 1441594872526532,
+    1441705889563321, 1441807082083580, 1441810029438539, 1441935783250123,
+    1441943642863347, 1441969186606325, 1442036975770382, 1442078238739808,
+    1442101817579480, 1442130308677417, 1442297325458427, 1442359219912566,
+    1442387711010503, 1442450587916295, 1442460412432825, 1442525254241923,
+    1442527219145229, 1442602867922510, 1442663779924996, 1442860270255596,
+    1442888761353533, 1442947708452713, 1442967357485773, 1443061672844461,
+    1443222794915553, 1443294513886222, 1443310233112670, 1443353460985402,
+    1443377039825074, 1443420267697806, 1443448758795743, 1443496898926740,
+    1443518512863106, 1443575495058980, 1443621670286671, 1443718933000318,
+    1443755283711479, 1443770020486274, 1443926230299101, 1443980265140016,
+    1444035282432584, 1444050019207379, 1444172825664004, 1444251421796244,
+    1444277947990875, 1444281877797487, 1444427280642131, 1444468543611557,
+    1444605104391324, 1444639490199179, 1444731840654561, 1444767208914069,
+    1444797664915312, 1444811419238454, 1444817313948372, 1444821243754984,
+    1444943067759956, 1445004962214095, 1445226013836020, 1445312469581484,
+    1445325241452973, 1445354715002563, 1445404820036866, 1445408749843478,
+    1445518784428614, 1445542363268286, 1445585591141018, 1445768327148476,
+    1445813519924514, 1445823344441044, 1445845940829063, 1445951063155934,
+    1446048325869581, 1446103343162149, 1446116115033638, 1446122009743556,
+    1446122992195209, 1446241868845222, 1446318500074156, 1446343061365481,
+    1446450148595658, 1446487481758472, 1446674147572542, 1446757655963047,
+    1446767480479577, 1446840181901899, 1446858848483306, 1446862778289918,
+    1446865725644877, 1446994426811420, 1447024882812663, 1447029795070928,
+    1447154566430859, 1447223338046569, 1447280320242443, 1447289162307320,
+    1447299969275503, 1447467968508166, 1447472880766431, 1447475828121390,
+    1447517091090816, 1447599617029668, 1447731265551170, 1447759756649107,
+    1447893370073915, 1447932668140035, 1447945440011524, 1447991615239215,
+    1448094772662780, 1448107544534269, 1448114421695840, 1448332525962806,
+    1448350210092560, 1448392455513639, 1448528033841753, 1448530981196712,
+    1448598770360769, 1448679331396315, 1448689155912845, 1448712734752517,
+    1448944593342625, 1449008452700070, 1449092943542228, 1449130276705042,
+    1449223609612077, 1449242276193484, 1449401433361270, 1449420099942677,
+    1449449573492267, 1449472169880286, 1449483959300122, 1449514415301365,
+    1449533081882772, 1449561572980709, 1449593993885258, 1449626414789807,
+    1449694203953864, 1449823887572060, 1449836659443549, 1449915255575789,
+    1449937851963808, 1449948658931991, 1449970272868357, 1450068518033657,
+    1450075395195228, 1450092096873329, 1450116658164654, 1450132377391102,
+    1450134342294408, 1450155956230774, 1450165780747304, 1450185429780364,
+    1450355393916333, 1450366200884516, 1450380937659311, 1450491954696100,
+    1450605919087848, 1450636375089091, 1450668795993640, 1450769988513899,
+    1450806339225060, 1450829918064732, 1450886900260606, 1450887882712259,
+    1450894759873830, 1451077495881288, 1451094197559389, 1451168863885017,
+    1451240582855686, 1451298547503213, 1451309354471396, 1451317214084620,
+    1451322126342885, 1451327038601150, 1451349634989169, 1451478336155712,
+    1451590335644154, 1451605072418949, 1451677773841271, 1451751457715246,
+    1451752440166899, 1451772089199959, 1451799597846243, 1451805492556161,
+    1451817281975997, 1451822194234262, 1451826124040874, 1451874264171871,
+    1451883106236748, 1451902755269808, 1451903737721461, 1452011807403291,
+    1452141491021487, 1452223034508686, 1452224999411992, 1452227946766951,
+    1452232859025216, 1452236788831828, 1452253490509929, 1452303595544232,
+    1452333069093822, 1452451945743835, 1452497138519873, 1452504015681444,
+    1452504998133097, 1452546261102523, 1452679874527331, 1452701488463697,
+    1452702470915350, 1452786961757508, 1452789909112467, 1452794821370732,
+    1452858680728177, 1452864575438095, 1452873417502972, 1452901908600909,
+    1452943171570335, 1452944154021988, 1452950048731906, 1452952013635212,
+    1453045346542247, 1453096434028203, 1453105276093080, 1453158328482342,
+    1453265415712519, 1453271310422437, 1453303731326986, 1453330257521617,
+    1453399029137327, 1453475660366261, 1453476642817914, 1453477625269567,
+    1453507098819157, 1453536572368747, 1453604361532804, 1453667238438596,
+    1453781202830344, 1453912851351846, 1453986535225821, 1453987517677474,
+    1453993412387392, 1454016008775411, 1454090675101039, 1454112289037405,
+    1454123096005588, 1454328428401065, 1454329410852718, 1454380498338674,
+    1454389340403551, 1454478743503974, 1454590742992416, 1454775443903180,
+    1454928706361048, 1455064284689162, 1455150740434626, 1455326599280513,
+    1455405195412753, 1455521124707807, 1455586948968558, 1455598738388394,
+    1455669474907410, 1455730386909896, 1455779509492546, 1455805053235524,
+    1455811930397095, 1455946526273556, 1455955368338433, 1456045753890509,
+    1456110595699607, 1456168560347134, 1456264840609128, 1456296279062024,
+    1456385682162447, 1456391576872365, 1456395506678977, 1456396489130630,
+    1456401401388895, 1456420067970302, 1456425962680220, 1456486874682706,
+    1456553681395110, 1456633259979003, 1456876907988947, 1456894592118701,
+    1456957469024493, 1456968275992676, 1457076345674506, 1457114661288973,
+    1457174590839806, 1457213888905926, 1457230590584027, 1457282660521636,
+    1457287572779901, 1457360274202223, 1457381888138589, 1457392695106772,
+    1457500764788602, 1457568553952659, 1457626518600186, 1457660904408041,
+    1457668764021265, 1457696272667549, 1457710026990691, 1457765044283259,
+    1457769956541524, 1457811219510950, 1457858377190294, 1457874096416742,
+    1457909464676250, 1457935990870881, 1457948762742370, 1458018516809733,
+    1458163919654377, 1458237603528352, 1458255287658106, 1458274936691166,
+    1458276901594472, 1458345673210182, 1458357462630018, 1458459637601930,
+    1458493040958132, 1458497953216397, 1458564759928801, 1458566724832107,
+    1458572619542025, 1458593251026738, 1458614864963104, 1458812337745357,
+    1458850653359824, 1458895846135862, 1458936126653635, 1458948898525124,
+    1458954793235042, 1458986231687938, 1459038301625547, 1459065810271831,
+    1459139494145806, 1459152266017295, 1459167002792090, 1459169950147049,
+    1459245598924330, 1459368405380955, 1459437176996665, 1459446019061542,
+    1459447983964848, 1459501036354110, 1459520685387170, 1459578650034697,
+    1459588474551227, 1459593386809492, 1459723070427688, 1459838017271089,
+    1459936262436389, 1459957876372755, 1459975560502509, 1460074788119462,
+    1460205454189311, 1460216261157494, 1460273243353368, 1460367558712056,
+    1460440260134378, 1460451067102561, 1460472681038927, 1460496259878599,
+    1460520821169924, 1460552259622820, 1460591557688940, 1460597452398858,
+    1460612189173653, 1460623978593489, 1460729100920360, 1460765451631521,
+    1460976678736916, 1461045450352626, 1461110292161724, 1461184958487352,
+    1461195765455535, 1461223274101819, 1461250782748103, 1461310712298936,
+    1461518992049372, 1461539623534085, 1461549448050615, 1461572044438634,
+    1461617237214672, 1461631973989467, 1461647693215915, 1461726289348155,
+    1461737096316338, 1461788183802294, 1461818639803537, 1461845165998168,
+    1461876604451064, 1461902148194042, 1461984674132894, 1462008252972566,
+    1462062287813481, 1462071129878358, 1462154638268863, 1462169375043658,
+    1462182146915147, 1462278427177141, 1462307900726731, 1462310848081690,
+    1462444461506498, 1462558425898246, 1462647828998669, 1462678284999912,
+    1462683197258177, 1462734284744133, 1462766705648682, 1462768670551988,
+    1462799126553231, 1462848249135881, 1462878705137124, 1462899336621837,
+    1463165581019800, 1463179335342942, 1463185230052860, 1463284457669813,
+    1463321790832627, 1463347334575605, 1463363053802053, 1463407264126438,
+    1463424948256192, 1463499614581820, 1463522210969839, 1463553649422735,
+    1463685297944237, 1463701999622338, 1463742280140111, 1463745227495070,
+    1463752104656641, 1463789437819455, 1463820876272351, 1463871963758307,
+    1463881788274837, 1463952524793853, 1463991822859973, 1464037998087664,
+    1464053717314112, 1464095962735191, 1464111681961639, 1464117576671557,
+    1464130348543046, 1464194207900491, 1464229576159999, 1464283611000914,
+    1464305224937280, 1464390698231091, 1464456522491842, 1464463399653413,
+    1464491890751350, 1464501715267880, 1464599960433180, 1464621574369546,
+    1464630416434423, 1464733573857988, 1464783678892291, 1464867187282796,
+    1464895678380733, 1464908450252222, 1464933993995200, 1464960520189831,
+    1464972309609667, 1465017502385705, 1465055818000172, 1465058765355131,
+    1465075467033232, 1465149150907207, 1465289641493586, 1465356448205990,
+    1465360378012602, 1465402623433681, 1465503815953940, 1465506763308899,
+    1465581429634527, 1465698341381234, 1465784797126698, 1465888937001916,
+    1465906621131670, 1465908586034976, 1465926270164730, 1465963603327544,
+    1465968515585809, 1465974410295727, 1465977357650686, 1466024515330030,
+    1466116865785412, 1466168935723021, 1466220023208977, 1466283882566422,
+    1466297636889564, 1466330057794113, 1466374268118498, 1466454829154044,
+    1466491179865205, 1466519670963142, 1466547179609426, 1466584512772240,
+    1466616933676789, 1466664091356133, 1466776090844575, 1466810476652430,
+    1466937212915667, 1467002054724765, 1467004019628071, 1467009914337989,
+    1467041352790885, 1467107177051636, 1467155317182633, 1467227036153302,
+    1467243737831403, 1467285983252482, 1467428438742167, 1467663244687234,
+    1467665209590540, 1467765419659146, 1467773279272370, 1467849910501304,
+    1467954050376522, 1468016927282314, 1468151523158775, 1468199663289772,
+    1468357838005905, 1468446258654675, 1468453135816246, 1468464925236082,
+    1468465907687735, 1468470819946000, 1468480644462530, 1468631942017092,
+    1468640784081969, 1468717415310903, 1468823520089427, 1468864783058853,
+    1468882467188607, 1468988571967131, 1469003308741926, 1469060290937800,
+    1469062255841106, 1469188992104343, 1469203728879138, 1469341272110558,
+    1469348149272129, 1469404149016350, 1469530885279587, 1469534815086199,
+    1469627165541581, 1469692989802332, 1469796147225897, 1469874743358137,
+    1469898322197809, 1469964146458560, 1470112496658163, 1470136075497835,
+    1470157689434201, 1470364004281331, 1470374811249514, 1470397407637533,
+    1470419021573899, 1470422951380511, 1470574248935073, 1470589968161521,
+    1470620424162764, 1470698037843351, 1470711792166493, 1470721616683023,
+    1470824774106588, 1470850317849566, 1470853265204525, 1470878808947503,
+    1470940703401642, 1470976071661150, 1471046808180166, 1471071369471491,
+    1471105755279346, 1471218737219441, 1471263929995479, 1471329754256230,
+    1471416210001694, 1471435859034754, 1471438806389713, 1471532139296748,
+    1471565542652950, 1471732559433960, 1471776769758345, 1471789541629834,
+    1471814102921159, 1471822944986036, 1471882874536869, 1471916277893071,
+    1471934944474478, 1471970312733986, 1471983084605475, 1472010593251759,
+    1472026312478207, 1472061680737715, 1472084277125734, 1472136347063343,
+    1472202171324094, 1472444836882385, 1472450731592303, 1472557818822480,
+    1472611853663395, 1472639362309679, 1472734660120020, 1472737607474979,
+    1472784765154323, 1472797537025812, 1472856484124992, 1472864343738216,
+    1473075570843611, 1473131570587832, 1473206236913460, 1473304482078760,
+    1473318236401902, 1473336902983309, 1473358516919675, 1473430235890344,
+    1473477393569688, 1473585463251518, 1473625743769291, 1473633603382515,
+    1473727918741203, 1473728901192856, 1473766234355670, 1473773111517241,
+    1473837953326339, 1473871356682541, 1473936198491639, 1473947987911475,
+    1474010864817267, 1474122864305709, 1474141530887116, 1474238793600763,
+    1474339986121022, 1474402863026814, 1474493248578890, 1474504055547073,
+    1474542371161540, 1474702510780979, 1474713317749162, 1474934369371087,
+    1474969737630595, 1474989386663655, 1475029667181428, 1475081737119037,
+    1475192754155826, 1475219280350457, 1475247771448394, 1475348963968653,
+    1475367630550060, 1475465875715360, 1475483559845114, 1475526787717846,
+    1475599489140168, 1475601454043474, 1475678085272408, 1475851979214989,
+    1475858856376560, 1476018995995999, 1476090714966668, 1476113311354687,
+    1476149662065848, 1476367766332814, 1476448327368360, 1476470923756379,
+    1476490572789439, 1476551484791925, 1476588817954739, 1476613379246064,
+    1476616326601023, 1476682150861774, 1476702782346487, 1476717519121282,
+    1476965096937838, 1477026008940324, 1477222499270924, 1477309937468041,
+    1477313867274653, 1477406217730035, 1477471059539133, 1477474006894092,
+    1477482848958969, 1477591901092452, 1477652813094938, 1477655760449897,
+    1477657725353203, 1477697023419323, 1477715690000730, 1477795268584623,
+    1477811970262724, 1477842426263967, 1477878776975128, 1477952460849103,
+    1477984881753652, 1478072319950769, 1478348388865262, 1478365090543363,
+    1478387686931382, 1478531124872720, 1478533089776026, 1478753158946298,
+    1478791474560765, 1478792457012418, 1478795404367377, 1478805228883907,
+    1478887754822759, 1478972245664917, 1478978140374835, 1479019403344261,
+    1479044947087239, 1479114701154602, 1479177578060394, 1479182490318659,
+    1479350489551322, 1479430068135215, 1479491962589354, 1479492945041007,
+    1479577435883165, 1479647189950528, 1479660944273670, 1479713014211279,
+    1479722838727809, 1479746417567481, 1479757224535664, 1479761154342276,
+    1479838768022863, 1479969434092712, 1480011679513791, 1480015609320403,
+    1480040170611728, 1480177713843148, 1480204240037779, 1480269081846877,
+    1480385011141931, 1480475396694007, 1480481291403925, 1480545150761370,
+    1480559887536165, 1480611957473774, 1480612939925427, 1480661080056424,
+    1480690553606014, 1480741641091970, 1480747535801888, 1480803535546109,
+    1480830061740740, 1480870342258513, 1480897850904797, 1480908657872980,
+    1480946973487447, 1480962692713895, 1480970552327119, 1481079604460602,
+    1481092376332091, 1481317357760628, 1481404795957745, 1481578689900326,
+    1481617005514793, 1481663180742484, 1481679882420585, 1481696584098686,
+    1481819390555311, 1481827250168535, 1481867530686308, 1481886197267715,
+    1481911741010693, 1481939249656977, 1481962828496649, 1481994266949545,
+    1482065985920214, 1482208441409899, 1482219248378082, 1482227107991306,
+    1482228090442959, 1482418686063641, 1482469773549597, 1482535597810348,
+    1482660369170279, 1482798894853352, 1482948227504608, 1482968858989321,
+    1482986543119075, 1483123103898842, 1483129981060413, 1483159454610003,
+    1483313699519524, 1483335313455890, 1483476786493922, 1483481698752187,
+    1483503312688553, 1483580926369140, 1483603522757159, 1483608435015424,
+    1483683101341052, 1483717487148907, 1483720434503866, 1483825556830737,
+    1483837346250573, 1483850118122062, 1483904152962977, 1483919872189425,
+    1483924784447690, 1483979801740258, 1484077064453905, 1484094748583659,
+    1484127169488208, 1484140923811350, 1484154678134492, 1484196923555571,
+    1484229344460120, 1484245063686568, 1484265695171281, 1484295168720871,
+    1484296151172524, 1484316782657237, 1484327589625420, 1484348221110133,
+    1484368852594846, 1484407168209313, 1484599728733301, 1484621342669667,
+    1484638044347768, 1484661623187440, 1484719587834967, 1484772640224229,
+    1484837482033327, 1484960288489952, 1484990744491195, 1485117480754432,
+    1485175445401959, 1485227515339568, 1485237339856098, 1485265830954035,
+    1485313971085032, 1485360146312723, 1485478040511083, 1485505549157367,
+    1485520285932162, 1485553689288364, 1485635232775563, 1485705969294579,
+    1485786530330125, 1485881828140466, 1485885757947078, 1485905406980138,
+    1485917196399974, 1485937827884687, 1485966318982624, 1486054739631394,
+    1486062599244618, 1486070458857842, 1486091090342555, 1486261054478524,
+    1486263019381830, 1486293475383073, 1486314106867786, 1486329826094234,
+    1486348492675641, 1486399580161597, 1486466386874001, 1486611789718645,
+    1486614737073604, 1486624561590134, 1486835788695529, 1486850525470324,
+    1487039156187700, 1487061752575719, 1487119717223246, 1487159015289366,
+    1487290663810868, 1487298523424092, 1487388908976168, 1487431154397247,
+    1487549048595607, 1487584416855115, 1487597188726604, 1487615855308011,
+    1487717047828270, 1487820205251835, 1487856555962996, 1487861468221261,
+    1487941046805154, 1487945959063419, 1487970520354744, 1488031432357230,
+    1488062870810126, 1488173887846915, 1488178800105180, 1488204343848158,
+    1488495149537446, 1488506938957282, 1488524623087036, 1488677885544904,
+    1488697534577964, 1488712271352759, 1488715218707718, 1488772200903592,
+    1488804621808141, 1488849814584179, 1488859639100709, 1488976550847416,
+    1488993252525517, 1489003077042047, 1489253602213562, 1489301742344559,
+    1489398022606553, 1489572899000787, 1489578793710705, 1489598442743765,
+    1489606302356989, 1489641670616497, 1489666231907822, 1489829318882220,
+    1490018932051249, 1490039563535962, 1490211492575237, 1490267492319458,
+    1490317597353761, 1490330369225250, 1490356895419881, 1490477736973200,
+    1490675209755453, 1490685034271983, 1490758718145958, 1490859910666217,
+    1490892331570766, 1490930647185233, 1490935559443498, 1491074085126571,
+    1491206716099726, 1491214575712950, 1491227347584439, 1491310855974944,
+    1491398294172061, 1491424820366692, 1491467065787771, 1491526995338604,
+    1491533872500175, 1491596749405967, 1491828607996075, 1491873800772113,
+    1492115483878751, 1492268746336619, 1492277588401496, 1492369938856878,
+    1492375833566796, 1492389587889938, 1492417096536222, 1492420043891181,
+    1492441657827547, 1492496675120115, 1492581165962273, 1492703972418898,
+    1492788463261056, 1492831691133788, 1492958427397025, 1492968251913555,
+    1492979058881738, 1492996743011492, 1493077304047038, 1493221724440029,
+    1493319969605329, 1493386776317733, 1493453583030137, 1493470284708238,
+    1493654985619002, 1493677582007021, 1493905510790517, 1493951686018208,
+    1493977229761186, 1494051896086814, 1494066632861609, 1494210070802947,
+    1494220877771130, 1494295544096758, 1494315193129818, 1494466490684380,
+    1494518560621989, 1494550981526538, 1494624665400513, 1494632525013737,
+    1494740594695567, 1494761226180280, 1494822138182766, 1494826067989378,
+    1494849646829050, 1494860453797233, 1494887962443517, 1495258346716698,
+    1495279960653064, 1495290767621247, 1495360521688610, 1495449924789033,
+    1495479398338623, 1495539327889456, 1495625783634920, 1495645432667980,
+    1495740730478321, 1495794765319236, 1495800660029154, 1495809502094031,
+    1495812449448990, 1495864519386599, 1495964729455205, 1495981431133306,
+    1495986343391571, 1496015816941161, 1496125851526297, 1496150412817622,
+    1496238833466392, 1496287956049042, 1496292868307307, 1496316447146979,
+    1496358692568058, 1496413709860626, 1496533568962292, 1496562060060229,
+    1496639673740816, 1496714340066444, 1496787041488766, 1496790971295378,
+    1496837146523069, 1496845006136293, 1496889216460678, 1496925567171839,
+    1496934409236716, 1497024794788792, 1497041496466893, 1497097496211114,
+    1497103390921032, 1497152513503682, 1497197706279720, 1497215390409474,
+    1497264512992124, 1497308723316509, 1497332302156181, 1497362758157424,
+    1497428582418175, 1497483599710743, 1497645704233488, 1497808791207886,
+    1497834334950864, 1497837282305823, 1497956158955836, 1497967948375672,
+    1497983667602120, 1498042614701300, 1498092719735603, 1498178193029414,
+    1498319666067446, 1498330473035629, 1498349139617036, 1498368788650096,
+    1498407104264563, 1498536787882759, 1498574121045573, 1498627173434835,
+    1498640927757977, 1498930750995612, 1498962189448508, 1498984785836527,
+    1498985768288180, 1499013276934464, 1499042750484054, 1499045697839013,
+    1499078118743562, 1499102680034887, 1499177346360515, 1499202890103493,
+    1499268714364244, 1499272644170856, 1499274609074162, 1499314889591935,
+    1499378748949380, 1499483871276251, 1499694115929993, 1499708852704788,
+    1499792361095293, 1499805132966782, 1499952500714732, 1500067447558133,
+    1500209903047818, 1500220710016001, 1500222674919307, 1500418182798254,
+    1500425059959825, 1500613690677201, 1500625480097037, 1500688357002829,
+    1500692286809441, 1500723725262337, 1500738462037132, 1500830812492514,
+    1500929057657814, 1500934952367732, 1500956566304098, 1501071513147499,
+    1501201196765695, 1501224775605367, 1501240494831815, 1501309266447525,
+    1501332845287197, 1501366248643399, 1501472353421923, 1501657054332687,
+    1501729755755009, 1501792632660801, 1501794597564107, 1501838807888492,
+    1501859439373205, 1501912491762467, 1501922316278997, 1501927228537262,
+    1501957684538505, 1501967509055035, 1502005824669502, 1502020561444297,
+    1502218034226550, 1502234735904651, 1502335928424910, 1502361472167888,
+    1502479366366248, 1502796698250167, 1502966662386136, 1502974521999360,
+    1503001048193991, 1503003013097297, 1503131714263840, 1503225047170875,
+    1503289888979973, 1503300695948156, 1503318380077910, 1503356695692377,
+    1503440204082882, 1503479502149002, 1503540414151488, 1503547291313059,
+    1503587571830832, 1503590519185791, 1503705466029192, 1503753606160189,
+    1503855781132101, 1503860693390366, 1503901956359792, 1503902938811445,
+    1503910798424669, 1503968763072196, 1504015920751540, 1504018868106499,
+    1504059148624272, 1504062095979231, 1504105323851963, 1504160341144531,
+    1504257603858178, 1504314586054052, 1504334235087112, 1504350936765213,
+    1504408901412740, 1504415778574311, 1504456059092084, 1504527778062753,
+    1504536620127630, 1504596549678463, 1504650584519378, 1504708549166905,
+    1504871636141303, 1504875565947915, 1504953179628502, 1505002302211152,
+    1505053389697108, 1505106442086370, 1505135915635960, 1505136898087613,
+    1505140827894225, 1505157529572326, 1505191915380181, 1505196827638446,
+    1505288195642175, 1505527913845507, 1505538720813690, 1505543633071955,
+    1505576053976504, 1505619281849236, 1505667421980233, 1505730298886025,
+    1505747000564126, 1505763702242227, 1505948403152991, 1505953315411256,
+    1505976894250928, 1506055490383168, 1506218577357566, 1506279489360052,
+    1506373804718740, 1506420962398084, 1506423909753043, 1506447488592715,
+    1506449453496021, 1506591908985706, 1506674434924558, 1506704890925801,
+    1506714715442331, 1506783487058041, 1506873872610117, 1506938714419215,
+    1506956398548969, 1507004538679966, 1507093941780389, 1507121450426673,
+    1507147976621304, 1507165660751058, 1507219695591973, 1507271765529582,
+    1507355273920087, 1507415203470920, 1507423063084144, 1507428957794062,
+    1507488887344895, 1507645097157722, 1507660816384170, 1507718781031697,
+    1507795412260631, 1507816043745344, 1507823903358568, 1507832745423445,
+    1507884815361054, 1507889727619319, 1508024323495780, 1508042990077187,
+    1508232603246216, 1508380953445819, 1508457584674753, 1508459549578059,
+    1508467409191283, 1508554847388400, 1508602005067744, 1508622636552457,
+    1508746425460735, 1508748390364041, 1508760179783877, 1508777863913631,
+    1508797512946691, 1508811267269833, 1508825021592975, 1508933091274805,
+    1508938985984723, 1509176739284749, 1509185581349626, 1509222914512440,
+    1509274001998396, 1509344738517412, 1509365370002125, 1509420387294693,
+    1509492106265362, 1509498000975280, 1509500948330239, 1509530421879829,
+    1509579544462479, 1509720035048858, 1509869367700114, 1509889016733174,
+    1509930279702600, 1509941086670783, 1510037366932777, 1510097296483610,
+    1510148383969566, 1510210278423705, 1510221085391888, 1510222067843541,
+    1510253506296437, 1510279050039415, 1510295751717516, 1510557083857214,
+    1510604241536558, 1510657293925820, 1510665153539044, 1510718205928306,
+    1510792872253934, 1510810556383688, 1511096449814711, 1511104309427935,
+    1511128870719260, 1511141642590749, 1511184870463481, 1511193712528358,
+    1511235957949437, 1511244800014314, 1511248729820926, 1511253642079191,
+    1511255606982497, 1511339115373002, 1511569009059804, 1511594552802782,
+    1511606342222618, 1511613219384189, 1511664306870145, 1511672166483369,
+    1511796937843300, 1511911884686701, 1512222339409049, 1512301917992942,
+    1512317637219390, 1512353005478898, 1512464022515687, 1512487601355359,
+    1512514127549990, 1512714547687202, 1512755810656628, 1512766617624811,
+    1512840301498786, 1512856020725234, 1512891388984742, 1512943458922351,
+    1513282404742636, 1513367878036447, 1513426825135627, 1513433702297198,
+    1513495596751337, 1513521140494315, 1513522122945968, 1513572227980271,
+    1513694051985243, 1513755946439382, 1513771665665830, 1513775595472442,
+    1513807033925338, 1513817840893521, 1513821770700133, 1513846331991458,
+    1513878752896007, 1513937699995187, 1514041839870405, 1514051664386935,
+    1514062471355118, 1514124365809257, 1514153839358847, 1514155804262153,
+    1514237347749352, 1514256996782412, 1514328715753081, 1514394540013832,
+    1514453487113012, 1514507521953927, 1514545837568394, 1514548784923353,
+    1514559591891536, 1514673556283284, 1514741345447341, 1514815029321316,
+    1514864151903966, 1514905414873392, 1514927028809758, 1514959449714307,
+    1514979098747367, 1514984993457285, 1514988923263897, 1515064572041178,
+    1515104852558951, 1515108782365563, 1515226676563923, 1515365202246996,
+    1515382886376750, 1515386816183362, 1515434956314359, 1515438886120971,
+    1515507657736681, 1515532219028006, 1515579376707350, 1515586253868921,
+    1515632429096612, 1515794533619357, 1515871164848291, 1515968427561938,
+    1515979234530121, 1516150181117743, 1516197338797087, 1516332917125201,
+    1516478319969845, 1516487162034722, 1516501898809517, 1516568705521921,
+    1516634529782672, 1516721967979789, 1516760283594256, 1516789757143846,
+    1517017685927342, 1517025545540566, 1517074668123216, 1517127720512478,
+    1517154246707109, 1517246597162491, 1517280982970346, 1517283930325305,
+    1517410666588542, 1517443087493091, 1517604209564183, 1517605192015836,
+    1517779085958417, 1517920558996449, 1517973611385711, 1517980488547282,
+    1517994242870424, 1518079716164235, 1518125891391926, 1518143575521680,
+    1518177961329535, 1518389188434930, 1518423574242785, 1518469749470476,
+    1518525749214697, 1518530661472962, 1518686871285789, 1518720274641991,
+    1518828344323821, 1518845046001922, 1518884344068042, 1518928554392427,
+    1519020904847809, 1519037606525910, 1519053325752358, 1519058238010623,
+    1519099500980049, 1519309745633791, 1519352973506523, 1519506235964391,
+    1519608410936303, 1519645744099117, 1519647709002423, 1519654586163994,
+    1519700761391685, 1519886444754102, 1519892339464020, 1519908058690468,
+    1519927707723528, 1519935567336752, 1520158583861983, 1520165461023554,
+    1520175285540084, 1520191004766532, 1520192969669838, 1520335425159523,
+    1520510301553757, 1520586932782691, 1520708756787663, 1520858089438919,
+    1520908194473222, 1521021176413317, 1521076193705885, 1521142017966636,
+    1521221596550529, 1521300192682769, 1521428893849312, 1521479981335268,
+    1521588051017098, 1521779629089433, 1521811067542329, 1522016399937806,
+    1522117592458065, 1522131346781207, 1522187346525428, 1522295416207258,
+    1522311135433706, 1522324889756848, 1522357310661397, 1522409380599006,
+    1522648116350685, 1522762080742433, 1522774852613922, 1522894711715588,
+    1523008676107336, 1523202219082977, 1523215973406119, 1523289657280094,
+    1523294569538359, 1523354499089192, 1523485165159041, 1523663971359887,
+    1523781865558247, 1523867338852058, 1523950847242563, 1523959689307440,
+    1523990145308683, 1524025513568191, 1524089372925636, 1524144390218204,
+    1524220038995485, 1524224951253750, 1524226916157056, 1524242635383504,
+    1524251477448381, 1524274073836400, 1524284880804583, 1524290775514501,
+    1524335968290539, 1524418494229391, 1524426353842615, 1524451897585593,
+    1524476458876918, 1524579616300483, 1524673931659171, 1524721089338515,
+    1524744668178187, 1524757440049676, 1524791825857531, 1524805580180673,
+    1524849790505058, 1524879264054648, 1524880246506301, 1524961789993500,
+    1525037438770781, 1525068877223677, 1525146490904264, 1525315472588580,
+    1525318419943539, 1525327262008416, 1525334139169987, 1525341998783211,
+    1525431401883634, 1525647541247294, 1525663260473742, 1525686839313414,
+    1525918697903522, 1526038557005188, 1526052311328330, 1526062135844860,
+    1526072942813043, 1526152521396936, 1526185924753138, 1526229152625870,
+    1526231117529176, 1526258626175460, 1526413853536634, 1526431537666388,
+    1526474765539120, 1526479677797385, 1526588729930868, 1526595607092439,
+    1526633922706906, 1526768518583367, 1526831395489159, 1526841220005689,
+    1526881500523462, 1526906061814787, 1526923745944541, 1526938482719336,
+    1526974833430497, 1526983675495374, 1527018061303229, 1527021008658188,
+    1527032798078024, 1527036727884636, 1527122201178447, 1527182130729280,
+    1527207674472258, 1527220446343747, 1527291182862763, 1527294130217722,
+    1527408094609470, 1527516164291300, 1527520094097912, 1527530901066095,
+    1527532865969401, 1527543672937584, 1527586900810316, 1527607532295029,
+    1527653707522720, 1527689075782228, 1527747040429755, 1527753917591326,
+    1527757847397938, 1527807952432241, 1527897355532664, 1527941565857049,
+    1527984793729781, 1528085986250040, 1528192091028564, 1528239248707908,
+    1528404300585612, 1528411177747183, 1528425914521978, 1528440651296773,
+    1528534966655461, 1528561492850092, 1528603738271171, 1528622404852578,
+    1528634194272414, 1528662685370351, 1528744228857550, 1528807105763342,
+    1529062543193122, 1529153911196851, 1529185349649747, 1529199103972889,
+    1529208928489419, 1529246261652233, 1529273770298517, 1529484997403912,
+    1529641207216739, 1529670680766329, 1529710961284102, 1529817066062626,
+    1529848504515522, 1529872083355194, 1529886820129989, 1530184502980848,
+    1530227730853580, 1530304362082514, 1530326958470533, 1530467449056912,
+    1530473343766830, 1530479238476748, 1530489062993278, 1530519518994521,
+    1530547027640805, 1530604992288332, 1530785763392484, 1530795587909014,
+    1530836850878440, 1530863377073071, 1530879096299519, 1530937060947046,
+    1531046113080529, 1531050042887141, 1531051025338794, 1531052990242100,
+    1531159095020624, 1531348708189653, 1531351655544612, 1531633619169023,
+    1531675864590102, 1531677829493408, 1531969617634349, 1531996143828980,
+    1532294809131492, 1532295791583145, 1532388142038527, 1532622947983594,
+    1532646526823266, 1532739859730301, 1532756561408402, 1532969753417103,
+    1532990384901816, 1533031647871242, 1533037542581160, 1533251717041514,
+    1533263506461350, 1533361751626650, 1533457049436991, 1533511084277906,
+    1533546452537414, 1533548417440720, 1533590662861799, 1533611294346512,
+    1533663364284121, 1533682030865528, 1533693820285364, 1533713469318424,
+    1533766521707686, 1533779293579175, 1534044555525485, 1534174239143681,
+    1534299010503612, 1534309817471795, 1534326519149896, 1534367782119322,
+    1534384483797423, 1534669394776793, 1534676271938364, 1534710657746219,
+    1534723429617708, 1534888481495412, 1534906165625166, 1534949393497898,
+    1534993603822283, 1535001463435507, 1535031919436750, 1535145883828498,
+    1535278514801653, 1535308970802896, 1535315847964467, 1535352198675628,
+    1535515285650026, 1535728477658727, 1535802161532702, 1535808056242620,
+    1535810021145926, 1535841459598822, 1535964266055447, 1536000616766608,
+    1536070370833971, 1536256054196388, 1536305176779038, 1536314018843915,
+    1536317948650527, 1536371983491442, 1536421106074092, 1536486930334843,
+    1536499702206332, 1536505596916250, 1536565526467083, 1536633315631140,
+    1536638227889405, 1536881875899349, 1536895630222491, 1536904472287368,
+    1536914296803898, 1536979138612996, 1536997805194403, 1537057734745236,
+    1537063629455154, 1537130436167558, 1537158927265495, 1537162857072107,
+    1537176611395249, 1537181523653514, 1537201172686574, 1537210997203104,
+    1537241453204347, 1537306295013445, 1537315137078322, 1537471346891149,
+    1537490013472556, 1537601030509345, 1537631486510588, 1537661942511831,
+    1537726784320929, 1537771977096967, 1537872187165573, 1537920327296570,
+    1537934081619712, 1538022502268482, 1538071624851132, 1538139414015189,
+    1538162992854861, 1538323132474300, 1538342781507360, 1538438079317701,
+    1538458710802414, 1538517657901594, 1538577587452427, 1538733797265254,
+    1538752463846661, 1538885094819816, 1539020673147930, 1539028532761154,
+    1539052111600826, 1539280040384322, 1539287899997546, 1539324250708707,
+    1539357654064909, 1539368461033092, 1539431337938884, 1539514846329389,
+    1539601302074853, 1539621933559566, 1539647477302544, 1539668108787257,
+    1539794845050494, 1539818423890166, 1539842985181491, 1539844950084797,
+    1539857721956286, 1539979545961258, 1539983475767870, 1540092527901353,
+    1540196667776571, 1540216316809631, 1540429508818332, 1540442280689821,
+    1540461929722881, 1540467824432799, 1540486491014206, 1540599472954301,
+    1540633858762156, 1540662349860093, 1540693788312989, 1540771401993576,
+    1540772384445229, 1540788103671677, 1540844103415898, 1540918769741526,
+    1540923681999791, 1540996383422113, 1541043541101457, 1541184031687836,
+    1541207610527508, 1541328452080827, 1541342206403969, 1541602556092014,
+    1541640871706481, 1541847186553611, 1541851116360223, 1541919887975933,
+    1541966063203624, 1542025010302804, 1542119325661492, 1542172378050754,
+    1542187114825549, 1542270623216054, 1542305009023909, 1542333500121846,
+    1542390482317720, 1542440587352023, 1542452376771859, 1542519183484263,
+    1542576165680137, 1542583042841708, 1542592867358238, 1542647884650806,
+    1542730410589658, 1542758901687595, 1542843392529753, 1542970128792990,
+    1543089987894656, 1543254057320707, 1543455459909572, 1543480021200897,
+    1543639178368683, 1543645073078601, 1543721704307535, 1543831738892671,
+    1543944720832766, 1543973211930703, 1544128439291877, 1544163807551385,
+    1544238473877013, 1544352438268761, 1544378964463392, 1544480156983651,
+    1544619665118377, 1544643243958049, 1544648156216314, 1544667805249374,
+    1544766050414674, 1544774892479551, 1544784716996081, 1544848576353526,
+    1545245486821338, 1545270048112663, 1545318188243660, 1545348644244903,
+    1545437064893673, 1545444924506897, 1545494047089547, 1545632572772620,
+    1545671870838740, 1545821203489996, 1545856571749504, 1546000009690842,
+    1546223026216073, 1546276078605335, 1546292780283436, 1546382183383859,
+    1546511867002055, 1546515796808667, 1546519726615279, 1546544287906604,
+    1546779093851671, 1546905830114908, 1546931373857886, 1547004075280208,
+    1547035513733104, 1547045338249634, 1547218249740562, 1547247723290152,
+    1547294880969496, 1547310600195944, 1547315512454209, 1547370529746777,
+    1547382319166613, 1547562107819112, 1547612212853415, 1547615160208374,
+    1547632844338128, 1547728142148469, 1547820492603851, 1547834246926993,
+    1547842106540217, 1547858808218318, 1547900071187744, 1547914807962539,
+    1548055298548918, 1548103438679915, 1548118175454710, 1548166315585707,
+    1548167298037360, 1548239017008029, 1548246876621253, 1548309753527045,
+    1548324490301840, 1548327437656799, 1548344139334900, 1548367718174572,
+    1548414875853916, 1548463998436566, 1548481682566320, 1548517050825828,
+    1548518033277481, 1548607436377904, 1548615295991128, 1548634945024188,
+    1548639857282453, 1548679155348573, 1548724348124611, 1548769540900649,
+    1548778382965526, 1549012206458940, 1549050522073407, 1549068206203161,
+    1549126170850688, 1549148767238707, 1549166451368461, 1549171363626726,
+    1549250942210619, 1549281398211862, 1549289257825086, 1549317748923023,
+    1549320696277982, 1549340345311042, 1549416976539976, 1549436625573036,
+    1549452344799484, 1549461186864361, 1549492625317257, 1549513256801970,
+    1549621326483800, 1549624273838759, 1549705817325958, 1549721536552406,
+    1549752975005302, 1549867921848703, 1549883641075151, 1549936693464413,
+    1550031991274754, 1550046728049549, 1550052622759467, 1550053605211120,
+    1550062447275997, 1550077184050792, 1550081113857404, 1550092903277240,
+    1550123359278483, 1550169534506174, 1550266797219821, 1550278586639657,
+    1550534024069437, 1550650935816144, 1550654865622756, 1550664690139286,
+    1550680409365734, 1550686304075652, 1550689251430611, 1550741321368220,
+    1550799286015747, 1550820899952113, 1550834654275255, 1550919145117413,
+    1550958443183533, 1550989881636429, 1551163775579010, 1551168687837275,
+    1551172617643887, 1551199143838518, 1551217810419925, 1551503703850948,
+    1551531212497232, 1551542019465415, 1551580335079882, 1551650089147245,
+    1551710018698078, 1551806298960072, 1551854439091069, 1551864263607599,
+    1551866228510905, 1551965456127858, 1551981175354306, 1552007701548937,
+    1552125595747297, 1552259209172105, 1552331910594427, 1552433103114686,
+    1552448822341134, 1552539207893210, 1552592260282472, 1552646295123387,
+    1552751417450258, 1552807417194479, 1552814294356050, 1552815276807703,
+    1552886995778372, 1552931206102757, 1553022574106486, 1553173871661048,
+    1553184678629231, 1553283906246184, 1553407695154462, 1553428326639175,
+    1553441098510664, 1553453870382153, 1553458782640418, 1553530501611087,
+    1553531484062740, 1553539343675964, 1553595343420185, 1553720114780116,
+    1553724044586728, 1553742711168135, 1553831131816905, 1553907763045839,
+    1553971622403284, 1554086569246685, 1554185796863638, 1554242779059512,
+    1554575830169879, 1554688812109974, 1554776250307091, 1554777232758744,
+    1554981582702568, 1555077862964562, 1555176108129862, 1555201651872840,
+    1555250774455490, 1555253721810449, 1555318563619547, 1555470843625762,
+    1555558281822879, 1555606421953876, 1555610351760488, 1555618211373712,
+    1555727263507195, 1555775403638192, 1555876596158451, 1555877578610104,
+    1555983683388628, 1556026911261360, 1556179191267575, 1556182138622534,
+    1556235191011796, 1556293155659323, 1556321646757260, 1556337365983708,
+    1556462137343639, 1556475891666781, 1556669434642422, 1556670417094075,
+    1557044731173868, 1557104660724701, 1557133151822638, 1557149853500739,
+    1557200940986695, 1557209783051572, 1557295256345383, 1557323747443320,
+    1557413150543743, 1557421992608620, 1557550693775163, 1557551676226816,
+    1557671535328482, 1557682342296665, 1557723605266091, 1557756026170640,
+    1557805148753290, 1557832657399574, 1557927955209915, 1558127392895474,
+    1558138199863657, 1558285567611607, 1558307181547973, 1558397567100049,
+    1558439812521128, 1558530198073204, 1558622548528586, 1558688372789337,
+    1558785635502984, 1558799389826126, 1558815109052574, 1558848512408776,
+    1558975248672013, 1558989985446808, 1559089213063761, 1559284720942708,
+    1559302405072462, 1559325983912134, 1559355457461724, 1559388860817926,
+    1559424229077434, 1559425211529087, 1559459597336942, 1559595175665056,
+    1559598123020015, 1559653140312583, 1559745490767965, 1559755315284495,
+    1559904647935751, 1559926261872117, 1559954752970054, 1559975384454767,
+    1560103103169657, 1560124717106023, 1560128646912635, 1560140436332471,
+    1560258330530831, 1560284856725462, 1560301558403563, 1560454820861431,
+    1560531452090365, 1560620855190788, 1560668012870132, 1560684714548233,
+    1560705346032946, 1560731872227577, 1560732854679230, 1560740714292454,
+    1560748573905678, 1560841906812713, 1560962748366032, 1560995169270581,
+    1561009906045376, 1561023660368518, 1561025625271824, 1561032502433395,
+    1561034467336701, 1561072782951168, 1561118958178859, 1561166115858203,
+    1561180852632998, 1561315448509459, 1561350816768967, 1561371448253680,
+    1561391097286740, 1561416641029718, 1561512921291712, 1561524710711548,
+    1561593482327258, 1561611166457012, 1561627868135113, 1561646534716520,
+    1561676008266110, 1561712358977271, 1561885270468199, 1561927515889278,
+    1561963866600439, 1561969761310357, 1562076848540534, 1562091585315329,
+    1562126953574837, 1562140707897979, 1562148567511203, 1562177058609140,
+    1562282180936011, 1562289058097582, 1562336215776926, 1562337198228579,
+    1562344075390150, 1562366671778169, 1562377478746352, 1562393197972800,
+    1562537618365791, 1562667301983987, 1562715442114984, 1562719371921596,
+    1562747863019533, 1562813687280284, 1562870669476158, 1562894248315830,
+    1562898178122442, 1562908985090625, 1562924704317073, 1562955160318316,
+    1563016072320802, 1563020984579067, 1563065194903452, 1563242036200992,
+    1563334386656374, 1563351088334475, 1563449333499775, 1563474877242753,
+    1563543648858463, 1563603578409296, 1563833472096098, 1563915015583297,
+    1564257891210194, 1564334522439128, 1564371855601942, 1564421960636245,
+    1564476977928813, 1564539854834605, 1564577187997419, 1564590942320561,
+    1564601749288744, 1564699012002391, 1564806099232568, 1564940695109029,
+    1565058589307389, 1565065466468960, 1565116553954916, 1565219711378481,
+    1565250167379724, 1565420131515693, 1565495780292974, 1565518376680993,
+    1565605814878110, 1565713884559940, 1565731568689694, 1565732551141347,
+    1565764972045896, 1565970304441373, 1566066584703367, 1566106865221140,
+    1566184478901727, 1566252268065784, 1566264057485620, 1566304338003393,
+    1566564687691438, 1566639354017066, 1566659985501779, 1566698301116246,
+    1566769037635262, 1566776897248486, 1566788686668322, 1566789669119975,
+    1566911493124947, 1566924264996436, 1567040194291490, 1567062790679509,
+    1567113878165465, 1567117807972077, 1567126650036954, 1567140404360096,
+    1567258298558456, 1567266158171680, 1567297596624576, 1567312333399371,
+    1567321175464248, 1567383069918387, 1567486227341952, 1567604121540312,
+    1567610998701883, 1567651279219656, 1567669945801063, 1567670928252716,
+    1567680752769246, 1567800611870912, 1567823208258931, 1567972540910187,
+    1568118926206484, 1568230925694926, 1568254504534598, 1568373381184611,
+    1568471626349911, 1568509941964378, 1568538433062315, 1568584608290006,
+    1568782081072259, 1568830221203256, 1568850852687969, 1568966781983023,
+    1569013939662367, 1569218289606191, 1569295903286778, 1569323411933062,
+    1569512042650438, 1569605375557473, 1569893233891802, 1569897163698414,
+    1569927619699657, 1569952180990982, 1570068110286036, 1570084811964137,
+    1570120180223645, 1570175197516213, 1570233162163740, 1570237091970352,
+    1570347126555488, 1570368740491854, 1570376600105078, 1570419827977810,
+    1570462073398889, 1570670353149325, 1570995544646468, 1571119333554746,
+    1571167473685743, 1571325648401876, 1571418981308911, 1571537857958924,
+    1571682278351915, 1571737295644483, 1571820804034988, 1572088030884604,
+    1572121434240806, 1572200030373046, 1572227539019330, 1572264872182144,
+    1572329713991242, 1572382766380504, 1572426976704889, 1572438766124725,
+    1572512449998700, 1572537011290025, 1572539958644984, 1572659817746650,
+    1572731536717319, 1572784589106581, 1572805220591294, 1572938834016102,
+    1572963395307427, 1573213920478942, 1573326902419037, 1573444796617397,
+    1573602971333530, 1573605918688489, 1573758198694704, 1573788654695947,
+    1573911461152572, 1574035250060850, 1574059811352175, 1574076513030276,
+    1574085355095153, 1574237635101368, 1574245494714592, 1574270056005917,
+    1574314266330302, 1574387950204277, 1574404651882378, 1574483248014618,
+    1574490125176189, 1574534335500574, 1574581493179918, 1574794685188619,
+    1574850684932840, 1574863456804329, 1574931245968386, 1574963666872935,
+    1575011807003932, 1575020649068809, 1575021631520462, 1575121841589068,
+    1575166051913453, 1575365489599012, 1575386121083725, 1575460787409353,
+    1575484366249025, 1575506962637044, 1575575734252754, 1575605207802344,
+    1575636646255240, 1575833136585840, 1575916644976345, 1575990328850320,
+    1576099380983803, 1576100363435456, 1576124924726781, 1576132784340005,
+    1576159310534636, 1576199591052409, 1576213345375551, 1576216292730510,
+    1576383309511520, 1576498256354921, 1576610255843363, 1576669202942543,
+    1576676080104114, 1576688851975603, 1576833272368594, 1576914815855793,
+    1576926605275629, 1576938394695465, 1576959026180178, 1576969833148361,
+    1577000289149604, 1577052359087213, 1577117200896311, 1577189902318633,
+    1577225270578141, 1577358884002949, 1577524918332306, 1577605479367852,
+    1577651654595543, 1577717478856294, 1577774461052168, 1577842250216225,
+    1578021056417071, 1578112424420800, 1578239160684037, 1578344283010908,
+    1578446457982820, 1578480843790675, 1578684211282846, 1578695018251029,
+    1578848280708897, 1578865964838651, 1578891508581629, 1578894455936588,
+    1578994666005194, 1579008420328336, 1579030034264702, 1579032981619661,
+    1579496698799877, 1579576277383770, 1579634242031297, 1579652908612704,
+    1579800276360654, 1579934872237115, 1580013468369355, 1580033117402415,
+    1580036064757374, 1580099924114819, 1580190309666895, 1580275782960706,
+    1580323923091703, 1580356343996252, 1580365186061129, 1580372063222700,
+    1580485045162795, 1580591149941319, 1580651079492152, 1580680553041742,
+    1580840692661181, 1580860341694241, 1580905534470279, 1581008691893844,
+    1581029323378557, 1581035218088475, 1581097112542614, 1581132480802122,
+    1581163919255018, 1581178656029813, 1581180620933119, 1581336830745946,
+    1581402655006697, 1581416409329839, 1581452760041000, 1581463567009183,
+    1581478303783978, 1581481251138937, 1581537250883158, 1581566724432748,
+    1581716057084004, 1581754372698471, 1581774021731531, 1581869319541872,
+    1581924336834440, 1582030441612964, 1582052055549330, 1582070722130737,
+    1582101178131980, 1582129669229917, 1582136546391488, 1582201388200586,
+    1582223002136952, 1582247563428277, 1582253458138195, 1582256405493154,
+    1582375282143167, 1582555070795666, 1582563912860543, 1582641526541130,
+    1582700473640310, 1582740754158083, 1582871420227932, 1583059068493655,
+    1583097384108122, 1583231979984583, 1583328260246577, 1583417663347000,
+    1583427487863530, 1583432400121795, 1583549311868502, 1583570925804868,
+    1583636750065619, 1583645592130496, 1583660328905291, 1583693732261493,
+    1583958994207803, 1584076888406163, 1584109309310712, 1584152537183444,
+    1584153519635097, 1584181028281381, 1584186922991299, 1584376536160328,
+    1584377518611981, 1584421728936366, 1584473798873975, 1584497377713647,
+    1584613307008701, 1584623131525231, 1584629026235149, 1584647692816556,
+    1584661447139698, 1584664394494657, 1584674219011187, 1584747902885162,
+    1584838288437238, 1585005305218248, 1585008252573207, 1585165444837687,
+    1585269584712905, 1585298075810842, 1585445443558792, 1585473934656729,
+    1585505373109625, 1585514215174502, 1585588881500130, 1585593793758395,
+    1585707758150143, 1585708740601796, 1585787336734036, 1585870845124541,
+    1585892459060907, 1585917020352232, 1586028037389021, 1586161650813829,
+    1586162633265482, 1586263825785741, 1586437719728322, 1586560526184947,
+    1586626350445698, 1586643052123799, 1586665648511818, 1586687262448184,
+    1586697086964714, 1587024243365163, 1587081225561037, 1587116593820545,
+    1587182418081296, 1587183400532949, 1587270838730066, 1587378908411896,
+    1587384803121814, 1587422136284628, 1587464381705707, 1587468311512319,
+    1587518416546622, 1587541995386294, 1587546907644559, 1587693292940856,
+    1587721784038793, 1587744380426812, 1587746345330118, 1587755187394995,
+    1587782696041279, 1587976239016920, 1588070554375608, 1588073501730567,
+    1588078413988832, 1588109852441728, 1588123606764870, 1588263114899596,
+    1588318132192164, 1588433079035565, 1588453710520278, 1588483184069868,
+    1588530341749212, 1588560797750455, 1588561780202108, 1588565710008720,
+    1588581429235168, 1588617779946329, 1588709147950058, 1588718972466588,
+    1588889919054210, 1588930199571983, 1589091321643075, 1589103111062911,
+    1589135531967460, 1589236724487719, 1589258338424085, 1589282899715410,
+    1589340864362937, 1589885142578699, 1589888089933658, 1589969633420857,
+    1589971598324163, 1590007949035324, 1590008931486977, 1590132720395255,
+    1590206404269230, 1590294824918000, 1590435315504379, 1590472648667193,
+    1590488367893641, 1590549279896127, 1590676016159364, 1590695665192424,
+    1590817489197396, 1590853839908557, 1590965839396999, 1591068996820564,
+    1591164294630905, 1591288083539183, 1591294960700754, 1591409907544155,
+    1591435451287133, 1591450188061928, 1591468854643335, 1591496363289619,
+    1591504222902843, 1591589696196654, 1591625064456162, 1591662397618976,
+    1591679099297077, 1591749835816093, 1591771449752459, 1591780291817336,
+    1591815660076844, 1591862817756188, 1591876572079330, 1591914887693797,
+    1591941413888428, 1591947308598346, 1592020010020668, 1592067167700012,
+    1592112360476050, 1592224359964492, 1592348148872770, 1592451306296335,
+    1592453271199641, 1592580007462878, 1592595726689326, 1592600638947591,
+    1592651726433547, 1592898321798450, 1592925830444734, 1592953339091018,
+    1593012286190198, 1593118390968722, 1593125268130293, 1593167513551372,
+    1593220565940634, 1593312916396016, 1593426880787764, 1593470108660496,
+    1593490740145209, 1593519231243146, 1593532003114635, 1593547722341083,
+    1593565406470837, 1593577195890673, 1593584073052244, 1593595862472080,
+    1593645967506383, 1593682318217544, 1593703932153910, 1593754037188213,
+    1593769756414661, 1593815931642352, 1593989825584933, 1594019299134523,
+    1594059579652296, 1594369051922991, 1594389683407704, 1594518384574247,
+    1594522314380859, 1594534103800695, 1594662804967238, 1594736488841213,
+    1594871084717674, 1594888768847428, 1595129469502413, 1595309258154912,
+    1595342661511114, 1595357398285909, 1595390801642111, 1595407503320212,
+    1595471362677657, 1595695361654541, 1595776905141740, 1595885957275223,
+    1595978307730605, 1596031360119867, 1596168903351287, 1596221955740549,
+    1596234727612038, 1596273043226505, 1596284832646341, 1596331007874032,
+    1596432200394291, 1596504901816613, 1596564831367446, 1596746584923251,
+    1596780970731106, 1596813391635655, 1596832058217062, 1596859566863346,
+    1596876268541447, 1596905742091037, 1596929320930709, 1597005952159643,
+    1597176898747265, 1597257459782811, 1597306582365461, 1597320336688603,
+    1597382231142742, 1597394020562578, 1597536476052263, 1597663212315500,
+    1597852825484529, 1597862650001059, 1597885246389078, 1598017877362233,
+    1598046368460170, 1598058157880006, 1598060122783312, 1598075842009760,
+    1598116122527533, 1598120052334145, 1598202578272997, 1598659418291642,
+    1598678084873049, 1598687909389579, 1598733102165617, 1598806786039592,
+    1598913873269769, 1598953171335889, 1599073030437555, 1599162433537978,
+    1599173240506161, 1599188959732609, 1599363836126843, 1599488607486774,
+    1599545589682648, 1599556396650831, 1599590782458686, 1599650712009519,
+    1599734220400024, 1599751904529778, 1599771553562838, 1599801027112428,
+    1599811834080611, 1599872746083097, 1599886500406239, 1600061376800473,
+    1600083973188492, 1600178288547180, 1600215621709994, 1600427831267042,
+    1600545725465402, 1600735338634431, 1600924951803460, 1601011407548924,
+    1601123407037366, 1601156810393568, 1601216739944401, 1601407335565083,
+    1601415195178307, 1601600878540724, 1601726632352308, 1601736456868838,
+    1601756105901898, 1601905438553154, 1601940806812662, 1602067543075899,
+    1602197226694095, 1602207051210625, 1602218840630461, 1602269928116417,
+    1602393717024695, 1602419260767673, 1602424173025938, 1602426137929244,
+    1602451681672222, 1602489014835036, 1602570558322235, 1602781785427630,
+    1602801434460690, 1602820101042097, 1602948802208640, 1602956661821864,
+    1603117783892956, 1603196380025196, 1603202274735114, 1603403677323979,
+    1603421361453733, 1603468519133077, 1603476378746301, 1603627676300863,
+    1603698412819879, 1603852657729400, 1603860517342624, 1603881148827337,
+    1603952867798006, 1603983323799249, 1604024586768675, 1604155252838524,
+    1604283954005067, 1604314410006310, 1604411672719957, 1604464725109219,
+    1604511882788563, 1604671039956349, 1604681846924532, 1604848863705542,
+    1604850828608848, 1604870477641908, 1604938266805965, 1605131809781606,
+    1605141634298136, 1605389212114692, 1605393141921304, 1605633842576289,
+    1605792017292422, 1605864718714744, 1605969841041615, 1606138822725931,
+    1606272436150739, 1606307804410247, 1606332365701572, 1606344155121408,
+    1606386400542487, 1606426681060260, 1606437488028443, 1606439452931749,
+    1606472856287951, 1606477768546216, 1606573066356557, 1606629066100778,
+    1606756784815668, 1606779381203687, 1606793135526829, 1606869766755763,
+    1606924784048331, 1606925766499984, 1606950327791309, 1606978818889246,
+    1607206747672742, 1607209695027701, 1607256852707045, 1607290256063247,
+    1607337413742591, 1607370817098793, 1607411097616566, 1607444500972768,
+    1607596780978983, 1607617412463696, 1607753973243463, 1607882674410006,
+    1607895446281495, 1607953410929022, 1607982884478612, 1607992708995142,
+    1607994673898448, 1608021200093079, 1608292356749307, 1608480987466683,
+    1608547794179087, 1608676495345630, 1608747231864646, 1608944704646899,
+    1608952564260123, 1608956494066735, 1609034107747322, 1609037055102281,
+    1609079300523360, 1609100914459726, 1609427088408522, 1609444772538276,
+    1609466386474642, 1609600982351103, 1609704139774668, 1609914384428410,
+    1610075506499502, 1610198312956127, 1610274944185061, 1610307365089610,
+    1610324066767711, 1610358452575566, 1610363364833831, 1610476346773926,
+    1610545118389636, 1610554942906166, 1610576556842532, 1610628626780141,
+    1610723924590482, 1610893888726451, 1610907643049593, 1610995081246710,
+    1611149326156231, 1611243641514919, 1611309465775670, 1611348763841790,
+    1611439149393866, 1611531499849248, 1611550166430655, 1611642516886037,
+    1611655288757526, 1611738797148031, 1611762375987703, 1611801674053823,
+    1611818375731924, 1611857673798044, 1611904831477388, 1612100339356335,
+    1612254584265856, 1612268338588998, 1612311566461730, 1612688827896482,
+    1612772336286987, 1612831283386167, 1612842090354350, 1612913809325019,
+    1612960967004363, 1613115211913884, 1613220334240755, 1613251772693651,
+    1613286158501506, 1613327421470932, 1613372614246970, 1613455140185822,
+    1613479701477147, 1613485596187065, 1613554367802775, 1613696823292460,
+    1613756752843293, 1613907067946202, 1613911980204467, 1613921804720997,
+    1613947348463975, 1613966997497035, 1613979769368524, 1614120259954903,
+    1614280399574342, 1614361943061541, 1614437591838822, 1614450363710311,
+    1614460188226841, 1614534854552469, 1614589871845037, 1614651766299176,
+    1614706783591744, 1614710713398356, 1614716608108274, 1614743134302905,
+    1614881659985978, 1614944536891770, 1614961238569871, 1614964185924830,
+    1614982852506237, 1614994641926073, 1615021168120704, 1615051624121947,
+    1615055553928559, 1615076185413272, 1615118430834351, 1615161658707083,
+    1615254991614118, 1615293307228585, 1615314921164951, 1615409236523639,
+    1615531060528611, 1615577235756302, 1615736392924088, 1615773726086902,
+    1615851339767489, 1616031128419988, 1616134285843553, 1616315056947705,
+    1616413302113005, 1616549862892772, 1616636318638236, 1616675616704356,
+    1616710984963864, 1616726704190312, 1616728669093618, 1616753230384943,
+    1616891756068016, 1616943826005625, 1616944808457278, 1617298491052358,
+    1617478279704857, 1617517577770977, 1617641366679255, 1617673787583804,
+    1617695401520170, 1617727822424719, 1617805436105306, 1617882067334240,
+    1617894839205729, 1617979330047887, 1618025505275578, 1618103118956165,
+    1618118838182613, 1618198416766506, 1618213153541301, 1618255398962380,
+    1618321223223131, 1618400801807024, 1618443047228103, 1618463678712816,
+    1618470555874387, 1618554064264892, 1618592379879359, 1618776098338470,
+    1618811466597978, 1618884168020300, 1618962764152540, 1619012869186843,
+    1619058061962881, 1619108166997184, 1619129780933550, 1619229008550503,
+    1619233920808768, 1619298762617866, 1619327253715803, 1619367534233576,
+    1619567954370788, 1619589568307154, 1619910829997685, 1619917707159256,
+    1620026759292739, 1620075881875389, 1620127951812998, 1620197705880361,
+    1620198688332014, 1620356863048147, 1620379459436166, 1620465915181630,
+    1620499318537832, 1620517002667586, 1620520932474198, 1620525844732463,
+    1620595598799826, 1620616230284539, 1620633914414293, 1620721352611410,
+    1620766545387448, 1620793071582079, 1620987597009373, 1621039666946982,
+    1621060298431695, 1621076017658143, 1621115315724263, 1621153631338730,
+    1621156578693689, 1621206683727992, 1621208648631298, 1621241069535847,
+    1621542682193318, 1621653699230107, 1621666471101596, 1621727383104082,
+    1621734260265653, 1621747032137142, 1621779453041691, 1621843312399136,
+    1621916013821458, 1622044714988001, 1622063381569408, 1622078118344203,
+    1622293275256210, 1622416081712835, 1622444572810772, 1622450467520690,
+    1622556572299214, 1622585063397151, 1622594887913681, 1622649905206249,
+    1622993763284799, 1623066464707121, 1623095938256711, 1623136218774484,
+    1623395586010876, 1623417199947242, 1623446673496832, 1623527234532378,
+    1623543936210479, 1623547866017091, 1623565550146845, 1623770882542322,
+    1623775794800587, 1623776777252240, 1623823934931584, 1623825899834890,
+    1623837689254726, 1624058740876651, 1624072495199793, 1624275862691964,
+    1624337757146103, 1624427160246526, 1624480212635788, 1624558808768028,
+    1624562738574640, 1624646246965145, 1624647229416798, 1624654106578369,
+    1624664913546552, 1624748421937057, 1624835860134174, 1624880070458559,
+    1624887930071783, 1625074595885853, 1625352629703652, 1625385050608201,
+    1625387997963160, 1625397822479690, 1625583505842107, 1625896907919414,
+    1625900837726026, 1625926381469004, 1626025609085957, 1626195573221926,
+    1626257467676065, 1626395010907485, 1626395993359138, 1626401888069056,
+    1626638658917429, 1626821394924887, 1626859710539354, 1626923569896799,
+    1627016902803834, 1627017885255487, 1627161323196825, 1627177042423273,
+    1627305743589816, 1627412830819993, 1627548409148107, 1627577882697697,
+    1627586724762574, 1627604408892328, 1627751776640278, 1627826442965906,
+    1627940407357654, 1627989529940304, 1628261669048185, 1628265598854797,
+    1628275423371327, 1628473878605233, 1628483703121763, 1628556404544085,
+    1628670368935833, 1628763701842868, 1628822648942048, 1628921876559001,
+    1628981806109834, 1629014227014383, 1629427839160296, 1629439628580132,
+    1629458295161539, 1629550645616921, 1629580119166511, 1629803135691742,
+    1629865030145881, 1629919064986796, 1629925942148367, 1630019275055402,
+    1630044818798380, 1630057590669869, 1630134221898803, 1630276677388488,
+    1630283554550059, 1630308115841384, 1630335624487668, 1630470220364129,
+    1630606781143896, 1630643131855057, 1630679482566218, 1630692254437707,
+    1630912323607979, 1631081305292295, 1631499829696473, 1631674706090707,
+    1631810284418821, 1631831898355187, 1631939968037017, 1632109932172986,
+    1632172809078778, 1632253370114324, 1632464597219719, 1632516667157328,
+    1632585438773038, 1632586421224691, 1632850700719348, 1633007892983828,
+    1633045226146642, 1633067822534661, 1633146418666901, 1633170979958226,
+    1633368452740479, 1633439189259495, 1633496171455369, 1633671047849603,
+    1633751608885149, 1633765363208291, 1633793854306228, 1633839047082266,
+    1633840029533919, 1633952029022361, 1633956941280626, 1634138694836431,
+    1634163256127756, 1634189782322387, 1634231045291813, 1634302764262482,
+    1634345992135214, 1634359746458356, 1634411816395965, 1634474693301757,
+    1634561149047221, 1634834270606755, 1634851954736509, 1634888305447670,
+    1635201707524977, 1635231181074567, 1635277356302258, 1635310759658460,
+    1635369706757640, 1635565214636587, 1635605495154360, 1635620231929155,
+    1635656582640316, 1635750897999004, 1635868792197364, 1635968019814317,
+    1635972932072582, 1636042686139945, 1636063317624658, 1636163527693264,
+    1636283386794930, 1636317772602785, 1636466122802388, 1636517210288344,
+    1636555525902811, 1636569280225953, 1636726472490433, 1636727454942086,
+    1636735314555310, 1636932787337563, 1636983874823519, 1636991734436743,
+    1637003523856579, 1637013348373109, 1637047734180964, 1637283522577684,
+    1637452504262000, 1637643099882682, 1637673555883925, 1637744292402941,
+    1637855309439730, 1638152009838936, 1638182465840179, 1638207027131504,
+    1638381903525738, 1638453622496407, 1638529271273688, 1638600990244357,
+    1638629481342294, 1638646183020395, 1638649130375354, 1638794533219998,
+    1638798463026610, 1638882953868768, 1639086321360939, 1639111865103917,
+    1639267092465091, 1639297548466334, 1639498951055199, 1639608985640335,
+    1639620775060171, 1639687581772575, 1639716072870512, 1639944984105661,
+    1640152281404444, 1640246596763132, 1640260351086274, 1640348771735044,
+    1640356631348268, 1640360561154880, 1640412631092489, 1640421473157366,
+    1640460771223486, 1640514806064401, 1640718173556572, 1640737822589632,
+    1640823295883443, 1640987365309494, 1641152417187198, 1641197609963236,
+    1641275223643823, 1641317469064902, 1641854870119093, 1641909887411661,
+    1641928553993068, 1641971781865800, 1642079851547630, 1642080833999283,
+    1642136833743504, 1642202658004255, 1642388341366672, 1642642796344799,
+    1642644761248105, 1642659498022900, 1642777392221260, 1642783286931178,
+    1642888409258049, 1642963075583677, 1643026934941122, 1643174302689072,
+    1643325600243634, 1643404196375874, 1643406161279180, 1643511283606051,
+    1643540757155641, 1643674370580449, 1643703844130039, 1643728405421364,
+    1643748054454424, 1643857106587907, 1643884615234191, 1644063421435037,
+    1644115491372646, 1644227490861088, 1644271701185473, 1644291350218533,
+    1644360121834243, 1644371911254079, 1644398437448710, 1644436753063177,
+    1644454437192931, 1644603769844187, 1644710857074364, 1644866084435538,
+    1644876891403721, 1644901452695046, 1644953522632655, 1645177521609539,
+    1645219767030618, 1645309170131041, 1645346503293855, 1645459485233950,
+    1645466362395521, 1645695273630670, 1645892746412923, 1645927132220778,
+    1645931062027390, 1645962500480286, 1646046008870791, 1646070570162116,
+    1646075482420381, 1646145236487744, 1646303411203877, 1646317165527019,
+    1646322077785284, 1646343691721650, 1646450778951827, 1646507761147701,
+    1646508743599354, 1646515620760925, 1646523480374149, 1646803479095254,
+    1646824110579967, 1646883057679147, 1646915478583696, 1647053021815116,
+    1647085442719665, 1647319266213079, 1647334985439527, 1647481370735824,
+    1647488247897395, 1647551124803187, 1647625791128815, 1647638563000304,
+    1647666071646588, 1647724036294115, 1647818351652803, 1647860597073882,
+    1647953929980917, 1647987333337119, 1648014841983403, 1648092455663990,
+    1648111122245397, 1648169086892924, 1648199542894167, 1648453997872294,
+    1648632804073140, 1648652453106200, 1648672102139260, 1648790978789273,
+    1648845013630188, 1648969784990119, 1649018907572769, 1649062135445501,
+    1649067047703766, 1649068030155419, 1649193783967003, 1649254695969489,
+    1649316590423628, 1649521922819105, 1649628027597629, 1649678132631932,
+    1649699746568298, 1649781290055497, 1649787184765415, 1649816658315005,
+    1649896236898898, 1649925710448488, 1650075043099744, 1650084867616274,
+    1650087814971233, 1650101569294375, 1650116306069170, 1650147744522066,
+    1650166411103473, 1650267603623732, 1650269568527038, 1650304936786546,
+    1650338340142748, 1650357006724155, 1650370761047297, 1650429708146477,
+    1650620303767159, 1650625216025424, 1650653707123361, 1650714619125847,
+    1650826618614289, 1650829565969248, 1650853144808920, 1650866899132062,
+    1650908162101488, 1651013284428359, 1651026056299848, 1651028021203154,
+    1651145915401514, 1651147880304820, 1651246125470120, 1651270686761445,
+    1651274616568057, 1651407247541212, 1651427879025925, 1651445563155679,
+    1651698053230500, 1651745210909844, 1651805140460677, 1651814964977207,
+    1651829701752002, 1651944648595403, 1651973139693340, 1652043876212356,
+    1652249208607833, 1652268857640893, 1652352366031398, 1652463383068187,
+    1652501698682654, 1652528224877285, 1652540996748774, 1652639241914074,
+    1652946749281463, 1652947731733116, 1653168783355041, 1653245414583975,
+    1653398677041843, 1653496922207143, 1653592220017484, 1653623658470380,
+    1653654114471623, 1653705201957579, 1653774956024942, 1653861411770406,
+    1653950814870829, 1653956709580747, 1653979305968766, 1653999937453479,
+    1654107024683656, 1654240638108464, 1654489198376673, 1654550110379159,
+    1654561899798995, 1654608075026686, 1654678811545702, 1654724004321740,
+    1654751512968024, 1654834038906876, 1655100283304839, 1655113055176328,
+    1655115020079634, 1655345896218089, 1655377334670985, 1655382246929250,
+    1655448071190001, 1655558105775137, 1655721192749535, 1655726105007800,
+    1655747718944166, 1656079787602880, 1656166243348344, 1656183927478098,
+    1656224207995871, 1656281190191745, 1656335225032660, 1656472768264080,
+    1656551364396320, 1656739012662043, 1656914871507930, 1656959081832315,
+    1656963011638927, 1657026870996372, 1657238098101767, 1657318659137313,
+    1657325536298884, 1657336343267067, 1657448342755509, 1657578026373705,
+    1657674306635699, 1657767639542734, 1657862937353075, 1657888481096053,
+    1657910095032419, 1658033883940697, 1658172409623770, 1658323707178332,
+    1658334514146515, 1658373812212635, 1658385601632471, 1658408198020490,
+    1658409180472143, 1658554583316787, 1658563425381664, 1658569320091582,
+    1658586021769683, 1658827704876321, 1658876827458971, 1658994721657331,
+    1659209878569338, 1659240334570581, 1659247211732152, 1659271773023477,
+    1659341527090840, 1659397526835061, 1659649034458229, 1659681455362778,
+    1659683420266084, 1659698157040879, 1659959489180577, 1659987980278514,
+    1659997804795044, 1660075418475631, 1660154997059524, 1660245382611600,
+    1660305312162433, 1660361311906654, 1660499837589727, 1660755275019507,
+    1660790643279015, 1660794573085627, 1660799485343892, 1661040185998877,
+    1661140396067483, 1661213097489805, 1661241588587742, 1661437096466689,
+    1661579551956374, 1661621797377453, 1661684674283245, 1661719060091100,
+    1661883129517151, 1661888041775416, 1661896883840293, 1662086497009322,
+    1662193584239499, 1662230917402313, 1662305583727941, 1662407758699853,
+    1662429372636219, 1662452951475891, 1662460811089115, 1662506003865153,
+    1662565933415986, 1662724108132119, 1662730002842037, 1662806634070971,
+    1662917651107760, 1662918633559413, 1662932387882555, 1662978563110246,
+    1662987405175123, 1663116106341666, 1663143614987950, 1663334210608632,
+    1663365649061528, 1663369578868140, 1663785155917359, 1663914839535555,
+    1663948242891757, 1664035681088874, 1664161434900458, 1664169294513682,
+    1664171259416988, 1664196803159966, 1664233153871127, 1664341223552957,
+    1664422767040156, 1664430626653380, 1664497433365784, 1664617292467450,
+    1664744028730687, 1664757783053829, 1664790203958378, 1665169430296436,
+    1665288306946449, 1665293219204714, 1665300096366285, 1665399323983238,
+    1665464165792336, 1665513288374986, 1665553568892759, 1665602691475409,
+    1665721568125422, 1665987812523385, 1666023180782893, 1666277635761020,
+    1666413214089134, 1666434828025500, 1666441705187071, 1666536020545759,
+    1666598897451551, 1666615599129652, 1666819949073476, 1667299385480140,
+    1667337701094607, 1667371104450809, 1667416297226847, 1667434963808254,
+    1667488016197516, 1667725769497542, 1667997908605423, 1668016575186830,
+    1668078469640969, 1668258258293468, 1668293626552976, 1668316222940995,
+    1668361415717033, 1668894886964612, 1668913553546019, 1668949904257180,
+    1669047166970827, 1669095307101824, 1669277060657629, 1669309481562178,
+    1669333060401850, 1669512849054349, 1669567866346917, 1669647444930810,
+    1669667093963870, 1669692637706848, 1669836075648186, 1670086600819701,
+    1670225126502774, 1670369546895765, 1670454037737923, 1670465827157759,
+    1670558177613141, 1670592563420996, 1670715369877621, 1670745825878864,
+    1670814597494574, 1670816562397880, 1670948210919382, 1671048420987988,
+    1671062175311130, 1671083789247496, 1671239016608670, 1671266525254954,
+    1671484629521920, 1671500348748368, 1671687014562438, 1671711575853763,
+    1671759715984760, 1671776417662861, 1671990592123215, 1672100626708351,
+    1672121258193064, 1672136977419512, 1672176275485632, 1672291222329033,
+    1672390449945986, 1672450379496819, 1672660624150561, 1672664553957173,
+    1672666518860479, 1672698939765028, 1672794237575369, 1672837465448101,
+    1672845325061325, 1672898377450587, 1672929815903483, 1672959289453073,
+    1672968131517950, 1673001534874152, 1673230446109301, 1673298235273358,
+    1673307077338235, 1673429883794860, 1673441673214696, 1673462304699409,
+    1673486865990734, 1673525181605201, 1673565462122974, 1673581181349422,
+    1673810092584571, 1673851355553997, 1673862162522180, 1674082231692452,
+    1674123494661878, 1674215845117260, 1674224687182137, 1674312125379254,
+    1674595071455318, 1674679562297476, 1674771912752858, 1674815140625590,
+    1674843631723527, 1674924192759073, 1675023420376026, 1675094156895042,
+    1675320120775232, 1675367278454576, 1675406576520696, 1675429172908715,
+    1675448821941775, 1675460611361611, 1675571628398400, 1675578505559971,
+    1675703276919902, 1675763206470735, 1676037310481922, 1676047134998452,
+    1676174853713342, 1676296677718314, 1676413589465021, 1676514781985280,
+    1676558992309665, 1676579623794378, 1676707342509268, 1676721096832410,
+    1676737798510511, 1676782991286549, 1677033516458064, 1677043340974594,
+    1677143551043200, 1677170077237831, 1677422567312652, 1677429444474223,
+    1677440251442406, 1677666215322596, 1677707478292022, 1677712390550287,
+    1677770355197814, 1677796881392445, 1677828319845341, 1677861723201543,
+    1677911828235846, 1677923617655682, 1677957021011884, 1678033652240818,
+    1678144669277607, 1678198704118522, 1678366703351185, 1678418773288794,
+    1678668316008656, 1678712526333041, 1678764596270650, 1678769508528915,
+    1678790140013628, 1678793087368587, 1678797999626852, 1678952244536373,
+    1678989577699187, 1679035752926878, 1679055401959938, 1679077998347957,
+    1679079963251263, 1679135962995484, 1679185085578134, 1679239120419049,
+    1679255822097150, 1679307892034759, 1679333435777737, 1679438558104608,
+    1679539750624867, 1679581996045946, 1679603609982312, 1679751960181915,
+    1679805995022830, 1679936661092679, 1679937643544332, 1680082063937323,
+    1680154765359645, 1680159677617910, 1680188168715847, 1680359115303469,
+    1680452448210504, 1680455395565463, 1680559535440681, 1680825779838644,
+    1680831674548562, 1680932867068821, 1680949568746922, 1680972165134941,
+    1681190269401907, 1681204023725049, 1681232514822986, 1681275742695718,
+    1681580302708148, 1681607811354432, 1681664793550306, 1681902546850332,
+    1681930055496616, 1681963458852818, 1682094124922667, 1682210054217721,
+    1682373141192119, 1682556859651230, 1682584368297514, 1682697350237609,
+    1682743525465300, 1682917419407881, 1682923314117799, 1683423382009176,
+    1683565837498861, 1683738748989789, 1683835029251783, 1683859590543108,
+    1684002046032793, 1684104221004705, 1684191659201822, 1684212290686535,
+    1684296781528693, 1684394044242340, 1684403868758870, 1684420570436971,
+    1684568920636574, 1684572850443186, 1684573832894839, 1684737902320890,
+    1684755586450644, 1684843024647761, 1684907866456859, 1684917690973389,
+    1685033620268443, 1685092567367623, 1685195724791188, 1685204566856065,
+    1685302812021365, 1685308706731283, 1685371583637075, 1685377478346993,
+    1685385337960217, 1685582810742470, 1685637828035038, 1685679091004464,
+    1685908002239613, 1685927651272673, 1685957124822263, 1686068141859052,
+    1686339298515280, 1686600630654978, 1686697893368625, 1686847226019881,
+    1686880629376083, 1686917962538897, 1686940558926916, 1686974944734771,
+    1687094803836437, 1687109540611232, 1687172417517024, 1687218592744715,
+    1687260838165794, 1687316837910015, 1687499573917473, 1687503503724085,
+    1687583082307978, 1687599783986079, 1687666590698483, 1687680345021625,
+    1687734379862540, 1687927922838181, 1688055641553071, 1688212833817551,
+    1688312061434504, 1688566516412631, 1688700129837439, 1688733533193641,
+    1689128478758147, 1689184478502368, 1689235565988324, 1689318091927176,
+    1689440898383801, 1689492968321410, 1689575494260262, 1689639353617707,
+    1689662932457379, 1689671774522256, 1689710090136723, 1689758230267720,
+    1689799493237146, 1689932124210301, 1689947843436749, 1689977316986339,
+    1690023492214030, 1690148263573961, 1690279912095463, 1690283841902075,
+    1690357525776050, 1690361455582662, 1690386999325640, 1690477384877716,
+    1690492121652511, 1690524542557060, 1690822225407919, 1690823207859572,
+    1690827137666184, 1690846786699244, 1690879207603793, 1690952891477768,
+    1691094364515800, 1691181802712917, 1691315416137725, 1691391064915006,
+    1691503064403448, 1691506011758407, 1691511906468325, 1691550222082792,
+    1691584607890647, 1691843975127039, 1691849869836957, 1691865589063405,
+    1691902922226219, 1692045377715904, 1692154429849387, 1692183903398977,
+    1692242850498157, 1692257587272952, 1692416744440738, 1692455060055205,
+    1692616182126297, 1692627971546133, 1692647620579193, 1692715409743250,
+    1692775339294083, 1692954145494929, 1692977724334601, 1693038636337087,
+    1693106425501144, 1693242986280911, 1693247898539176, 1693288179056949,
+    1693380529512331, 1693674282556578, 1693770562818572, 1693818702949569,
+    1693899263985115, 1694155683866548, 1694333507615741, 1694568313560808,
+    1694579120528991, 1694678348145944, 1695205924683605, 1695255047266255,
+    1695286485719151, 1695293362880722, 1695391608046022, 1695415186885694,
+    1695426976305530, 1695428941208836, 1695502625082811, 1695552730117114,
+    1695833711289872, 1695858272581197, 1695891675937399, 1696113710010977,
+    1696201148208094, 1696313147696536, 1696375042150675, 1696397638538694,
+    1696429076991590, 1696825005007749, 1696843671589156, 1696846618944115,
+    1697031319854879, 1697511738713196, 1697592299748742, 1698110051769873,
+    1698240717839722, 1698282963260801, 1698514821850909, 1698549207658764,
+    1698687733341837, 1698763382119118, 1698995240709226, 1699138678650564,
+    1699144573360482, 1699203520459662, 1699279169236943, 1699309625238186,
+    1699513975182010, 1699554255699783, 1699807728226257, 1699812640484522,
+    1699908920746516, 1699933482037841, 1699962955587431, 1700129972368441,
+    1700157481014725, 1700280287471350, 1700758741426361, 1700762671232973,
+    1700781337814380, 1700848144526784, 1700963091370185, 1701219511251618,
+    1701302037190470, 1701405194614035, 1701462176809909, 1701506387134294,
+    1701582035911575, 1701594807783064, 1701631158494225, 1701765754370686,
+    1701849262761191, 1701860069729374, 1702099787932706, 1702174454258334,
+    1702311015038101, 1702345400845956, 1702383716460423, 1702429891688114,
+    1702587083952594, 1702610662792266, 1702728556990626, 1702903433384860,
+    1702959433129081, 1703235502043574, 1703448694052275, 1703476202698559,
+    1703536132249392, 1703668763222547, 1703730657676686, 1703742447096522,
+    1703764061032888, 1703769955742806, 1703784692517601, 1703799429292396,
+    1703968410976712, 1704061743883747, 1704331918088322, 1704579495904878,
+    1704592267776367, 1704696407651585, 1704904687402021, 1705066791924766,
+    1705109037345845, 1705242650770653, 1705410650003316, 1705425386778111,
+    1705656262916566, 1705718157370705, 1705738788855418, 1705850788343860,
+    1706379347333174, 1706406855979458, 1706512960757982, 1706738924638172,
+    1706813590963800, 1706959976260097, 1707040537295643, 1707063133683662,
+    1707325448275013, 1707849095006062, 1707910989460201, 1707933585848220,
+    1707941445461444, 1708425794126373, 1708533863808203, 1708561372454487,
+    1708604600327219, 1708727406783844, 1708739196203680, 1708896388468160,
+    1708970072342135, 1709077159572312, 1709166562672735, 1709253018418199,
+    1709283474419442, 1709297228742584, 1709456385910370, 1709487824363266,
+    1709505508493020, 1709659753402541, 1709686279597172, 1709709858436844,
+    1710021295610845, 1710047821805476, 1710079260258372, 1710103821549697,
+    1710134277550940, 1710135260002593, 1710168663358795, 1710172593165407,
+    1710182417681937, 1710333715236499, 1710409364013780, 1710570486084872,
+    1710597994731156, 1710610766602645, 1710721783639434, 1710788590351838,
+    1710802344674980, 1710845572547712, 1710926133583258, 1710962484294419,
+    1711139325591959, 1711295535404786, 1711419324313064, 1711436025991165,
+    1711464517089102, 1711466481992408, 1711687533614333, 1711735673745330,
+    1711835883813936, 1711904655429646, 1712028444337924, 1712040233757760,
+    1712161075311079, 1712298618542499, 1712325144737130, 1712437144225572,
+    1712472512485080, 1712592371586746, 1712636581911131, 1712950966440091,
+    1712968650569845, 1713430402846755, 1713543384786850, 1713554191755033,
+    1713725138342655, 1713903944543501, 1713917698866643, 1714223241330726,
+    1714266469203458, 1714376503788594, 1714381416046859, 1714404012434878,
+    1714611309733661, 1714660432316311, 1714673204187800, 1714741975803510,
+    1714804852709302, 1715026886782880, 1715027869234533, 1715051448074205,
+    1715069132203959, 1715613410419721, 1715619305129639, 1715656638292453,
+    1715707725778409, 1715737199327999, 1715749971199488, 1715764707974283,
+    1715781409652384, 1715794181523873, 1715910110818927, 1716070250438366,
+    1716166530700360, 1716210741024745, 1716226460251193, 1716246109284253,
+    1716303091480127, 1716332565029717, 1716356143869389, 1716441617163200,
+    1716454389034689, 1716676423108267, 1716728493045876, 1716785475241750,
+    1716842457437624, 1716952492022760, 1716970176152514, 1717185333064521,
+    1717239367905436, 1717265894100067, 1717416209202976, 1717555717337702,
+    1718084276327016, 1718206100331988, 1718243433494802, 1718263082527862,
+    1718274871947698, 1718309257755553, 1718451713245238, 1718530309377478,
+    1718636414156002, 1718754308354362, 1718773957387422, 1719021535203978,
+    1719035289527120, 1719100131336218, 1719124692627543, 1719206236114742,
+    1719213113276313, 1719360481024263, 1719362445927569, 1719368340637487,
+    1719370305540793, 1719393884380465, 1719491147094112, 1719507848772213,
+    1719567778323046, 1719755426588769, 1719981390468959, 1720067846214423,
+    1720179845702865, 1720232898092127, 1720305599514449, 1720472616295459,
+    1720587563138860, 1720658299657876, 1720702509982261, 1720748685209952,
+    1720794860437643, 1721099420450073, 1721234016326534, 1721252682907941,
+    1721261524972818, 1721365664848036, 1721409875172421, 1721529734274087,
+    1721611277761286, 1721623067181122, 1721655488085671, 1721662365247242,
+    1721683979183608, 1721703628216668, 1721705593119974, 1721780259445602,
+    1721816610156763, 1721931557000164, 1722020960100587, 1722075977393155,
+    1722085801909685, 1722149661267130, 1722159485783660, 1722234152109288,
+    1722312748241528, 1722326502564670, 1722346151597730, 1722363835727484,
+    1722512185927087, 1722771553163479, 1722899271878369, 1722995552140363,
+    1723185165309392, 1723288322732957, 1723652812296220, 1723767759139621,
+    1723870916563186, 1723905302371041, 1723951477598732, 1723952460050385,
+    1724010424697912, 1724058564828909, 1724146985477679, 1724326774130178,
+    1724391615939276, 1724543895945491, 1724554702913674, 1724870069894287,
+    1724878911959164, 1724913297767019, 1725063612869928, 1725086209257947,
+    1725109788097619, 1725218840231102, 1725391751722030, 1725401576238560,
+    1725402558690213, 1725466418047658, 1725468382950964, 1725523400243532,
+    1725582347342712, 1725662908378258, 1725778837673312, 1725781785028271,
+    1725974345552259, 1726094204653925, 1726479325701901, 1726595254996955,
+    1726745570099864, 1726751464809782, 1726809429457309, 1726875253718060,
+    1726892937847814, 1727325216575134, 1727385146125967, 1727421496837128,
+    1727457847548289, 1727615039812769, 1727643530910706, 1727667109750378,
+    1727687741235091, 1727945143568177, 1727959880342972, 1727996231054133,
+    1728053213250007, 1728136721640512, 1728166195190102, 1728268370162014,
+    1728283106936809, 1728712438309170, 1728826402700918, 1728930542576136,
+    1728939384641013, 1728968858190603, 1729019945676559, 1729279312912951,
+    1729294049687746, 1729323523237336, 1729421768402636, 1729436505177431,
+    1729511171503059, 1729516083761324, 1729576995763810, 1729668363767539,
+    1729764644029533, 1729777415901022, 1729848152420038, 1729884503131199,
+    1729930678358890, 1729966046618398, 1729985695651458, 1730100642494859,
+    1730119309076266, 1730162536948998, 1730167449207263, 1730353132569680,
+    1730508359930854, 1730543728190362, 1730560429868463, 1730606605096154,
+    1730825691814773, 1731026111951985, 1731143023698692, 1731277619575153,
+    1731566460361135, 1731567442812788, 1731800283854549, 1731828774952486,
+    1731838599469016, 1731961405925641, 1732011510959944, 1732062598445900,
+    1732079300124001, 1732104843866979, 1732210948645503, 1732254176518235,
+    1732257123873194, 1732322948133945, 1732355369038494, 1732387789943043,
+    1732574455757113, 1732739507634817, 1732802384540609, 1732804349443915,
+    1732828910735240, 1732864278994748, 1732883928027808, 1732935997965417,
+    1733129540941058, 1733151154877424, 1733159996942301, 1733202242363380,
+    1733206172169992, 1733277891140661, 1733326031271658, 1733423293985305,
+    1733451785083242, 1733478311277873, 1733505819924157, 1733601117734498,
+    1733603082637804, 1733621749219211, 1733636485994006, 1733763222257243,
+    1733764204708896, 1733868344584114, 1733875221745685, 1733913537360152,
+    1733924344328335, 1734042238526695, 1734330096861024, 1734372342282103,
+    1734401815831693, 1734465675189138, 1734712270554041, 1735059075987550,
+    1735090514440446, 1735098374053670, 1735226092768560, 1735299776642535,
+    1735327285288819, 1735556196523968, 1735567003492151, 1735669178464063,
+    1735835212793420, 1735912826474007, 1735916756280619, 1735921668538884,
+    1735941317571944, 1735973738476493, 1736083773061629, 1736161386742216,
+    1736185948033541, 1736207561969907, 1736243912681068, 1736402087397201,
+    1736511139530684, 1736518016692255, 1736523911402173, 1736585805856312,
+    1736592683017883, 1736673244053429, 1736734156055915, 1736759699798893,
+    1736761664702199, 1736780331283606, 1737189031171254, 1737198855687784,
+    1737218504720844, 1737274504465065, 1737504398151867, 1737570222412618,
+    1737598713510555, 1737767695194871, 1737816817777521, 1738034922044487,
+    1738056535980853, 1738083062175484, 1738276605151125, 1738299201539144,
+    1738415130834198, 1738446569287094, 1738504533934621, 1738580182711902,
+    1738619480778022, 1738629305294552, 1738635200004470, 1738644042069347,
+    1738702989168527, 1738706918975139, 1738714778588363, 1738967268663184,
+    1739161794090478, 1739222706092964, 1739238425319412, 1739289512805368,
+    1739649090110366, 1739664809336814, 1739768949212032, 1739843615537660,
+    1739849510247578, 1739947755412878, 1740007684963711, 1740016527028588,
+    1740061719804626, 1740083333740992, 1740257227683573, 1740311262524488,
+    1740318139686059, 1740326981750936, 1740340736074078, 1740502840596823,
+    1740594208600552, 1740597155955511, 1740634489118325, 1740779891962969,
+    1740824102287354, 1740856523191903, 1740859470546862, 1740916452742736,
+    1740930207065878, 1740938066679102, 1740992101520017, 1741008803198118,
+    1741191539205576, 1741347749018403, 1741444029280397, 1741529502574208,
+    1741622835481243, 1741647396772568, 1741755466454398, 1741811466198619,
+    1741880237814329, 1741940167365162, 1741978482979629, 1742075745693276,
+    1742170061051964, 1742202481956513, 1742266341313958, 1742272236023876,
+    1742561076809858, 1742826338756168, 1742855812305758, 1743053285088011,
+    1743114197090497, 1743122056703721, 1743229143933898, 1743237985998775,
+    1743296933097955, 1743352932842176, 1743384371295072, 1743552370527735,
+    1743571037109142, 1743607387820303, 1743629984208322, 1743809772860821,
+    1743842193765370, 1743898193509591, 1744163455455901, 1744295103977403,
+    1744359945786501, 1744382542174520, 1744387454432785, 1744504366179492,
+    1744509278437757, 1744656646185707, 1744732294962988, 1744742119479518,
+    1744862961032837, 1745226468144447, 1745242187370895, 1745279520533709,
+    1745297204663463, 1745368923634132, 1745371870989091, 1745372853440744,
+    1745463238992820, 1745910254494935, 1745973131400727, 1746252147670179,
+    1746265901993321, 1746426041612760, 1746503655293347, 1746504637745000,
+    1746645128331379, 1746646110783032, 1746817057370654, 1746868144856610,
+    1746991933764888, 1747029266927702, 1747045968605803, 1747207090676895,
+    1747228704613261, 1747330879585173, 1747358388231457, 1747424212492208,
+    1747460563203369, 1747492984107918, 1747664913147193, 1747939999610033,
+    1747950806578216, 1747995999354254, 1748255366590646, 1748312348786520,
+    1748344769691069, 1748398804531984, 1748499997052243, 1748623785960521,
+    1748715153964250, 1748724978480780, 1748798662354755, 1749048205074617,
+    1749070801462636, 1749089468044043, 1749111081980409, 1749250590115135,
+    1749349817732088, 1749440203284164, 1749504062641609, 1749555150127565,
+    1749656342647824, 1749659290002783, 1749848903171812, 1749854797881730,
+    1749874446914790, 1749967779821825, 1749983499048273, 1750129884344570,
+    1750134796602835, 1750141673764406, 1750276269640867, 1750359778031372,
+    1750393181387574, 1750455075841713, 1750488479197915, 1750661390688843,
+    1750732127207859, 1750744899079348, 1750756688499184, 1750780267338856,
+    1750847074051260, 1750896196633910, 1751099564126081, 1751100546577734,
+    1751301949166599, 1751764683895162, 1751814788929465, 1751869806222033,
+    1751938577837743, 1751949384805926, 1752031910744778, 1752040752809655,
+    1752073173714204, 1752141945329914, 1752190085460911, 1752195980170829,
+    1752202857332400, 1752308962110924, 1752369874113410, 1752383628436552,
+    1752420961599366, 1752680328835758, 1752690153352288, 1752740258386591,
+    1752748117999815, 1752881731424623, 1753030081624226, 1753532114418909,
+    1753569447581723, 1753611693002802, 1753742359072651, 1753816042946626,
+    1753953586178046, 1753984042179289, 1753987971985901, 1754073445279712,
+    1754085234699548, 1754142216895422, 1754184462316501, 1754758214081853,
+    1754849582085582, 1754862353957071, 1754891827506661, 1754943897444270,
+    1755110914225280, 1755115826483545, 1755124668548422, 1755133510613299,
+    1755173791131072, 1755674841474102, 1755687613345591, 1755719051798487,
+    1755728876315017, 1755762279671219, 1755876244062967, 1755887051031150,
+    1755898840450986, 1755902770257598, 1755982348841491, 1756054067812160,
+    1756072734393567, 1756355680469631, 1756497153507663, 1756508942927499,
+    1756530556863865, 1756568872478332, 1756637644094042, 1756688731579998,
+    1756738836614301, 1756786976745298, 1756812520488276, 1756813502939929,
+    1757088589402769, 1757132799727154, 1757238904505678, 1757394131866852,
+    1757406903738341, 1757485499870581, 1757542482066455, 1757664306071427,
+    1757688867362752, 1757845077175579, 1757854901692109, 1758009146601630,
+    1758167321317763, 1758237075385126, 1758350057325221, 1758359881841751,
+    1758431600812420, 1758663459402528, 1758789213214112, 1758801985085601,
+    1758895317992636, 1758948370381898, 1759077071548441, 1759139948454233,
+    1759296158267060, 1759306965235243, 1759393420980707, 1759444508466663,
+    1759507385372455, 1759571244729900, 1759574192084859, 1759826682159680,
+    1760003523457220, 1760129277268804, 1760309065921303, 1760367030568830,
+    1760573345415960, 1760639169676711, 1760648994193241, 1760673555484566,
+    1760773765553172, 1760951589302365, 1760969273432119, 1761362254093319,
+    1761368148803237, 1761392710094562, 1761406464417704, 1761441832677212,
+    1761488007904903, 1761929128697100, 1762161969738861, 1762246460581019,
+    1762428214136824, 1762468494654597, 1762478319171127, 1762527441753777,
+    1762611932595935, 1762630599177342, 1762914527705059, 1762915510156712,
+    1762922387318283, 1763008843063747, 1763459788372474, 1763477472502228,
+    1763498103986941, 1763508910955124, 1763667085671257, 1763836067355573,
+    1763846874323756, 1764025680524602, 1764194662208918, 1764266381179587,
+    1764288977567606, 1764462871510187, 1764472696026717, 1764496274866389,
+    1764522801061020, 1764660344292440, 1764675081067235, 1764689817842030,
+    1764806729588737, 1764823431266838, 1764943290368504, 1764948202626769,
+    1765000272564378, 1765003219919337, 1765078868696618, 1765110307149514,
+    1765248832832587, 1765316621996644, 1765599568072708, 1765616269750809,
+    1765643778397093, 1765806865371491, 1765814724984715, 1766070162414495,
+    1766161530418224, 1766201810935997, 1766323634940969, 1766360968103783,
+    1766361950555436, 1766439564236023, 1766497528883550, 1766645879083153,
+    1766650791341418, 1766651773793071, 1766720545408781, 1766858088640201,
+    1766928825159217, 1766980895096826, 1766989737161703, 1767001526581539,
+    1767292332270827, 1767690225190292, 1767732470611371, 1768081240948186,
+    1768126433724224, 1768370081734168, 1768629448970560, 1768641238390396,
+    1768644185745355, 1768918289756542, 1768954640467703, 1769050920729697,
+    1769055832987962, 1769090218795817, 1769168814928057, 1769174709637975,
+    1769213025252442, 1769372182420228, 1769600111203724, 1769650216238027,
+    1769749443854980, 1770108038708325, 1770201371615360, 1770319265813720,
+    1770333020136862, 1770504949176137, 1770618913567885, 1770662141440617,
+    1770695544796819, 1770876315900971, 1771058069456776, 1771105227136120,
+    1771142560298934, 1771228033592745, 1771355752307635, 1771388173212184,
+    1771419611665080, 1771651470255188, 1771806697616362, 1771939328589517,
+    1771950135557700, 1772080801627549, 1772089643692426, 1772098485757303,
+    1772158415308136, 1772195748470950, 1772307747959392, 1772329361895758,
+    1772381431833367, 1772685991845797, 1772727254815223, 1772776377397873,
+    1772837289400359, 1772898201402845, 1773297076773963, 1773299041677269,
+    1773321638065288, 1773411041165711, 1773546619493825, 1773579040398374,
+    1773637005045901, 1773733285307895, 1773748022082690, 1773768653567403,
+    1773816793698400, 1773817776150053, 1773819741053359, 1773993634995940,
+    1774040792675284, 1774149844808767, 1774160651776950, 1774161634228603,
+    1774336510622837, 1774729491284037, 1774907315033230, 1774915174646454,
+    1774931876324555, 1774934823679514, 1774948578002656, 1774967244584063,
+    1774984928713817, 1775061559942751, 1775119524590278, 1775235453885332,
+    1775243313498556, 1775475172088664, 1775487943960153, 1775573417253964,
+    1775617627578349, 1775649066031245, 1775692293903977, 1775702118420507,
+    1775776784746135, 1775969345270123, 1776003731077978, 1776103941146584,
+    1776333834833386, 1776339729543304, 1776367238189588, 1776658043878876,
+    1776773973173930, 1776842744789640, 1777094252412808, 1777095234864461,
+    1777119796155786, 1777129620672316, 1777137480285540, 1777168918738436,
+    1777204286997944, 1777274041065307, 1777337900422752, 1777386040553749,
+    1778002037740180, 1778044283161259, 1778247650653430, 1778258457621613,
+    1778355720335260, 1778378316723279, 1778381264078238, 1778402878014604,
+    1778526666922882, 1778530596729494, 1778550245762554, 1778554175569166,
+    1778717262543564, 1778731999318359, 1778814525257211, 1778875437259697,
+    1778906875712593, 1778966805263426, 1779022805007647, 1779029682169218,
+    1779355856118014, 1779396136635787, 1779497329156046, 1779626030322589,
+    1779660416130444, 1779692837034993, 1779778310328804, 1779813678588312,
+    1779911923753612, 1779920765818489, 1780066168663133, 1780099572019335,
+    1780405114483418, 1780481745712352, 1780686095656176, 1780795147789659,
+    1780915006891325, 1781038795799603, 1781126233996720, 1781182233740941,
+    1781255917614916, 1781262794776487, 1781320759424014, 1781573249498835,
+    1781580126660406, 1781593880983548, 1781598793241813, 1781601740596772,
+    1781610582661649, 1781628266791403, 1782001598419543, 1782053668357152,
+    1782122439972862, 1782237386816263, 1782277667334036, 1782361175724541,
+    1782440754308434, 1782642156897299, 1782706016254744, 1782751209030782,
+    1782789524645249, 1782870085680795, 1782871068132448, 1782894646972120,
+    1782950646716341, 1782967348394442, 1782972260652707, 1782989944782461,
+    1783120610852310, 1783215908662651, 1783222785824222, 1783425170864740,
+    1783465451382513, 1783481170608961, 1783513591513510, 1783736608038741,
+    1783754292168495, 1783804397202798, 1783876116173467, 1783951764950748,
+    1783996957726786, 1784057869729272, 1784232746123506, 1784492113359898,
+    1784530428974365, 1784552042910731, 1784613937364870, 1784625726784706,
+    1784797655823981, 1784823199566959, 1784917514925647, 1784998075961193,
+    1785012812735988, 1785085514158310, 1785141513902531, 1785331127071560,
+    1785369442686027, 1785483407077775, 1785636669535643, 1785647476503826,
+    1785697581538129, 1785905861288565, 1786021790583619, 1786085649941064,
+    1786217298462566, 1786230070334055, 1786235965043973, 1786240877302238,
+    1786475683247305, 1786608314220460, 1786667261319640, 1786741927645268,
+    1786789085324612, 1787005224688272, 1787072031400676, 1787213504438708,
+    1787230206116809, 1787310767152355, 1787347117863516, 1787554415162299,
+    1787564239678829, 1787613362261479, 1787660519940823, 1787687046135454,
+    1787784308849101, 1787844238399934, 1787909080209032, 1787969992211518,
+    1788121289766080, 1788131114282610, 1788132096734263, 1788187114026831,
+    1788202833253279, 1788209710414850, 1788212657769809, 1788290271450396,
+    1788322692354945, 1788464165392977, 1788682269659943, 1788734339597552,
+    1788821777794669, 1788834549666158, 1789028092641799, 1789252091618683,
+    1789286477426538, 1789369003365390, 1789570405954255, 1789674545829473,
+    1789712861443940, 1789738405186918, 1789816018867505, 1789820931125770,
+    1789917211387764, 1789970263777026, 1789975176035291, 1790003667133228,
+    1790049842360919, 1790358332179961, 1790432016053936, 1790659944837432,
+    1790787663552322, 1790914399815559, 1791090258661446, 1791160995180462,
+    1791179661761869, 1791185556471787, 1791222889634601, 1791428222030078,
+    1791479309516034, 1791504853259012, 1791933202179720, 1791995096633859,
+    1792073692766099, 1792162113414869, 1792279025161576, 1792458813814075,
+    1792554111624416, 1792637620014921, 1792790882472789, 1792826250732297,
+    1792884215379824, 1793021758611244, 1793113126614973, 1793252634749699,
+    1793278178492677, 1793288003009207, 1793317476558797, 1793385265722854,
+    1793405897207567, 1793409827014179, 1793530668567498, 1793717334381568,
+    1793833263676622, 1794302875566756, 1794599575965962, 1794808838168051,
+    1794945398947818, 1795016135466834, 1795068205404443, 1795311853414387,
+    1795418940644564, 1795510308648293, 1795620343233429, 1795999569571487,
+    1796031008024383, 1796179358223986, 1796348339908302, 1796392550232687,
+    1796414164169053, 1796442655266990, 1796539917980637, 1796703987406688,
+    1796802232571988, 1796977108966222, 1796991845741017, 1797085178648052,
+    1797111704842683, 1797267914655510, 1797469317244375, 1797528264343555,
+    1797564615054716, 1797582299184470, 1797616684992325, 1797924192359714,
+    1798012613008484, 1798046998816339, 1798127559851885, 1798465523220517,
+    1798556891224246, 1798566715740776, 1798612890968467, 1798628610194915,
+    1798659066196158, 1798883065173042, 1798928257949080, 1799069730987112,
+    1799091344923478, 1799253449446223, 1799306501835485, 1799319273706974,
+    1799389027774337, 1799403764549132, 1799439132808640, 1799598289976426,
+    1799642500300811, 1799643482752464, 1799657237075606, 1799683763270237,
+    1800009937219033, 1800103270126068, 1800120954255822, 1800139620837229,
+    1800210357356245, 1800215269614510, 1800217234517816, 1800283058778567,
+    1800302707811627, 1800387198653785, 1800507057755451, 1800550285628183,
+    1800622987050505, 1800733021635641, 1800754635572007, 1800828319445982,
+    1800899055964998, 1800909862933181, 1801281229658015, 1801292036626198,
+    1801374562565050, 1801665368254338, 1801808806195676, 1801844174455184,
+    1801852034068408, 1802018068397765, 1802080945303557, 1802399259639129,
+    1802473925964757, 1802612451647830, 1802619328809401, 1802926836176790,
+    1802953362371421, 1803122344055737, 1803133151023920, 1803314904579725,
+    1803321781741296, 1803546763169833, 1803560517492975, 1803594903300830,
+    1803658762658275, 1803729499177291, 1803784516469859, 1803907322926484,
+    1804064515190964, 1804123462290144, 1804229567068668, 1804260023069911,
+    1804277707199665, 1804342549008763, 1804620582826562, 1804647109021193,
+    1804673635215824, 1804689354442272, 1804900581547667, 1804926125290645,
+    1804982125034866, 1805183527623731, 1805225773044810, 1805246404529523,
+    1805376088147719, 1805425210730369, 1805428158085328, 1805505771765915,
+    1805520508540710, 1805578473188237, 1805580438091543, 1805700297193209,
+    1805759244292389, 1805928225976705, 1805929208428358, 1806217066762687,
+    1806251452570542, 1806258329732113, 1806355592445760, 1806600222907357,
+    1806619871940417, 1806873344466891, 1806988291310292, 1807012852601617,
+    1807032501634677, 1807075729507409, 1807370465003309, 1807412710424388,
+    1807419587585959, 1807636709401272, 1807639656756231, 1807828287473607,
+    1807917690574030, 1807966813156680, 1807967795608333, 1808126952776119,
+    1808169198197198, 1808195724391829, 1808358811366227, 1808447232014997,
+    1808498319500953, 1808781265577017, 1808797967255118, 1808800914610077,
+    1808826458353055, 1808949264809680, 1809013124167125, 1809068141459693,
+    1809113334235731, 1809368771665511, 1809499437735360, 1809551507672969,
+    1809618314385373, 1809630103805209, 1809771576843241, 1809776489101506,
+    1809787296069689, 1809823646780850, 1809891435944907, 1809989681110207,
+    1810085961372201, 1810124276986668, 1810187153892460, 1810189118795766,
+    1810190101247419, 1810205820473867, 1810261820218088, 1810308977897432,
+    1810326662027186, 1810330591833798, 1810391503836284, 1810402310804467,
+    1810789396755749, 1810842449145011, 1810856203468153, 1810866027984683,
+    1811134237285952, 1811147009157441, 1811201043998356, 1811419148265322,
+    1811520340785581, 1811731567890976, 1811742374859159, 1811786585183544,
+    1811966373836043, 1812056759388119, 1812228688427394, 1812364266755508,
+    1812543072956354, 1812845668065478, 1812875141615068, 1812915422132841,
+    1812946860585737, 1813011702394835, 1813368332344874, 1813378156861404,
+    1813772119974257, 1813876259849475, 1813967627853204, 1814189661926782,
+    1814190644378435, 1814305591221836, 1814320327996631, 1814438222194991,
+    1814443134453256, 1814556116393351, 1814631765170632, 1814774220660317,
+    1814824325694620, 1814925518214879, 1815119061190520, 1815143622481845,
+    1815243832550451, 1815262499131858, 1815402007266584, 1815589655532307,
+    1815596532693878, 1815743900441828, 1815746847796787, 1815786145862907,
+    1816067127035665, 1816121161876580, 1816130986393110, 1816310775045609,
+    1816387406274543, 1816803965775415, 1816884526810961, 1816895333779144,
+    1816897298682450, 1817033859462217, 1817216595469675, 1817226419986205,
+    1817253928632489, 1817267682955631, 1817316805538281, 1817453366318048,
+    1817498559094086, 1817762838588743, 1817836522462718, 1817899399368510,
+    1817928872918100, 1817965223629261, 1817966206080914, 1817977013049097,
+    1818012381308605, 1818530133329736, 1818648027528096, 1818948657733914,
+    1819020376704583, 1819078341352110, 1819175604065757, 1819485076336452,
+    1819513567434389, 1819637356342667, 1819675671957134, 1820030337003867,
+    1820066687715028, 1820115810297678, 1820246476367527, 1820294616498524,
+    1820297563853483, 1820320160241502, 1820362405662581, 1820486194570859,
+    1820549071476651, 1820570685413017, 1820587387091118, 1820666965675011,
+    1820767175743617, 1820908648781649, 1820976437945706, 1821109068918861,
+    1821185700147795, 1821218121052344, 1821263313828382, 1821310471507726,
+    1821340927508969, 1821377278220130, 1821506961838326, 1821608154358585,
+    1821626820939992, 1821665136554459, 1821946117727217, 1821967731663583,
+    1822015871794580, 1822016854246233, 1822288010902461, 1822476641619837,
+    1822483518781408, 1822623026916134, 1822634816335970, 1822792991052103,
+    1823021902287252, 1823076919579820, 1823105410677757, 1823257690683972,
+    1823267515200502, 1823357900752578, 1823397198818698, 1823467935337714,
+    1823506250952181, 1823672285281538, 1823989617165457, 1824124213041918,
+    1824163511108038, 1824176282979527, 1824306949049376, 1824333475244007,
+    1824378668020045, 1824408141569635, 1824444492280796, 1824445474732449,
+    1824476913185345, 1824588912673787, 1824620351126683, 1824640000159743,
+    1824727438356860, 1824748069841573, 1824776560939510, 1824963226753580,
+    1825023156304413, 1825177401213934, 1825188208182117, 1825275646379234,
+    1825280558637499, 1825286453347417, 1825324768961884, 1825508487420995,
+    1825694170783412, 1825701047944983, 1825730521494573, 1825852345499545,
+    1825858240209463, 1826059642798328, 1826150028350404, 1826328834551250,
+    1826457535717793, 1826501746042178, 1826627499853762, 1826659920758311,
+    1826686446952942, 1826768972891794, 1826770937795100, 1826918305543050,
+    1826951708899252, 1827086304775713, 1827163918456300, 1827209111232338,
+    1827379075368307, 1827704266865450, 1827744547383223, 1827778933191078,
+    1827867353839848, 1827911564164233, 1828474508961402, 1828585525998191,
+    1828852752847807, 1829023699435429, 1829054155436672, 1829166154925114,
+    1829956046054126, 1830089659478934, 1830477727881869, 1830488534850052,
+    1830531762722784, 1830558288917415, 1830591692273617, 1830618218468248,
+    1830633937694696, 1830982708031511, 1831028883259202, 1831109444294748,
+    1831140882747644, 1831201794750130, 1831367829079487, 1831536810763803,
+    1831584950894800, 1831637020832409, 1831641933090674, 1831660599672081,
+    1831729371287791, 1831934703683268, 1832205860339496, 1832291333633307,
+    1832302140601490, 1832679402036242, 1832704945779220, 1832891611593290,
+    1832928944756104, 1833006558436691, 1833174557669354, 1833268873028042,
+    1833302276384244, 1833372030451607, 1833386767226402, 1833402486452850,
+    1833498766714844, 1833509573683027, 1833703116658668, 1833794484662397,
+    1833797432017356, 1833980168024814, 1833988027638038, 1834066623770278,
+    1834253289584348, 1834269991262449, 1834303394618651, 1834307324425263,
+    1834324026103364, 1834352517201301, 1834383955654197, 1834416376558746,
+    1834549989983554, 1834555884693472, 1834619744050917, 1835022549228647,
+    1835088373489398, 1835114899684029, 1835333986402648, 1835408652728276,
+    1835653283189873, 1835996158816770, 1836002053526688, 1836042334044461,
+    1836228017406878, 1836307595990771, 1836418613027560, 1836431384899049,
+    1836435314705661, 1836452016383762, 1836649489166015, 1836705488910236,
+    1836853839109839, 1837049346988786, 1837080785441682, 1837203591898307,
+    1837244854867733, 1837375520937582, 1837615239140914, 1837620151399179,
+    1837653554755381, 1837686958111583, 1837721343919438, 1837756712178946,
+    1837772431405394, 1837849062634328, 1837936500831445, 1837948290251281,
+    1838221411810815, 1838299025491402, 1838373691817030, 1838386463688519,
+    1838416919689762, 1838492568467043, 1838517129758368, 1838622252085239,
+    1838809900350962, 1838983794293543, 1839006390681562, 1839044706296029,
+    1839268705272913, 1839288354305973, 1839311933145645, 1839339441791929,
+    1839374810051437, 1839441616763841, 1839604703738239, 1839811018585369,
+    1839921053170505, 1839929895235382, 1840098876919698, 1840127368017635,
+    1840137192534165, 1840159788922184, 1840349402091213, 1840405401835434,
+    1840417191255270, 1840649049845378, 1840668698878438, 1840687365459845,
+    1840688347911498, 1840906452178464, 1840933960824748, 1840981118504092,
+    1840992907923928, 1841145187930143, 1841217889352465, 1841353467680579,
+    1841511642396712, 1841530308978119, 1841571571947545, 1841582378915728,
+    1841741536083514, 1841787711311205, 1841788693762858, 1841811290150877,
+    1841951780737256, 1841986166545111, 1841989113900070, 1842082446807105,
+    1842294656364153, 1842303498429030, 1842367357786475, 1842530444760873,
+    1842615918054684, 1843010863619190, 1843123845559285, 1843179845303506,
+    1843188687368383, 1843228967886156, 1843272195758888, 1843342932277904,
+    1843463773831223, 1843637667773804, 1843693667518025, 1843900964816808,
+    1843930438366398, 1844145595278405, 1844253664960235, 1844295910381314,
+    1844305734897844, 1844333243544128, 1844404962514797, 1844480611292078,
+    1844503207680097, 1844515979551586, 1844781241497896, 1844847065758647,
+    1844939416214029, 1845061240219001, 1845300958422333, 1845302923325639,
+    1845381519457879, 1845426712233917, 1845581939595091, 1845657588372372,
+    1845660535727331, 1845771552764120, 1845807903475281, 1845965095739761,
+    1846029937548859, 1846040744517042, 1846051551485225, 1846197936781522,
+    1846393444660469, 1846472040792709, 1846673443381574, 1846722565964224,
+    1846749092158855, 1846909231778294, 1847027125976654, 1847060529332856,
+    1847155827143197, 1847224598758907, 1847506562383318, 1847566491934151,
+    1848099963181730, 1848126489376361, 1848251260736292, 1848463470293340,
+    1848545996232192, 1848790626693789, 1848812240630155, 1848837784373133,
+    1848966485539676, 1849313290973185, 1849407606331873, 1849649289438511,
+    1849718061054221, 1849771113443483, 1849858551640600, 1849860516543906,
+    1849881148028619, 1849952866999288, 1850025568421610, 1850034410486487,
+    1850119883780298, 1850207321977415, 1850267251528248, 1850365496693548,
+    1850459812052236, 1850474548827031, 1850531531022905, 1850613074510104,
+    1850759459806401, 1850782056194420, 1851160300080825, 1851403948090769,
+    1851622052357735, 1851850963592884, 1851899103723881, 1851949208758184,
+    1852057278440014, 1852086751989604, 1852101488764399, 1852409978583441,
+    1852414890841706, 1852595661945858, 1852630047753713, 1852632995108672,
+    1852644784528508, 1852655591496691, 1852785275114887, 1853060361577727,
+    1853179238227740, 1853302044684365, 1853315799007507, 1853323658620731,
+    1853360009331892, 1853503447273230, 1853505412176536, 1853605622245142,
+    1853651797472833, 1853750042638133, 1853892498127818, 1853956357485263,
+    1854128286524538, 1854185268720412, 1854321829500179, 1854621477254344,
+    1854622459705997, 1854670599836994, 1854771792357253, 1854817967584944,
+    1854931931976692, 1854936844234957, 1855193264116390, 1855345544122605,
+    1855412350835009, 1855424140254845, 1855450666449476, 1855540069549899,
+    1855692349556114, 1855695296911073, 1855790594721414, 1856039154989623,
+    1856234662868570, 1856319153710728, 1856607994496710, 1856806449730616,
+    1856831011021941, 1857072694128579, 1857110027291393, 1857161114777349,
+    1857208272456693, 1857259359942649, 1857266237104220, 1857347780591419,
+    1857367429624479, 1857456832724902, 1857536411308795, 1857564902406732,
+    1857579639181527, 1857696550928234, 1857759427834026, 1857810515319982,
+    1857978514552645, 1858063987846456, 1858152408495226, 1858176969786551,
+    1858563073286180, 1858582722319240, 1858596476642382, 1858736967228761,
+    1858763493423392, 1858779212649840, 1859072965694087, 1859133877696573,
+    1859203631763936, 1859299912025930, 1859391280029659, 1859528823261079,
+    1859569103778852, 1859572051133811, 1859624121071420, 1859696822493742,
+    1859758716947881, 1859891347921036, 1859922786373932, 1859934575793768,
+    1859946365213604, 1860077031283453, 1860103557478084, 1860872817122383,
+    1860878711832301, 1860906220478585, 1861350288625741, 1861395481401779,
+    1861416112886492, 1861478007340631, 1861551691214606, 1861598848893950,
+    1861821865419181, 1861982005038620, 1862096951882021, 1862115618463428,
+    1862266916017990, 1862299336922539, 1862310143890722, 1862331757827088,
+    1862424108282470, 1862425090734123, 1862454564283713, 1862583265450256,
+    1862682493067209, 1862814141588711, 1862861299268055, 1863042070372207,
+    1863057789598655, 1863437998388366, 1863460594776385, 1863482208712751,
+    1863563752199950, 1863718979561124, 1863756312723938, 1863901715568582,
+    1863902698020235, 1863929224214866, 1863973434539251, 1864056942929756,
+    1864134556610343, 1864268170035151, 1864311397907883, 1864337924102514,
+    1864345783715738, 1864673922567840, 1864748588893468, 1864867465543481,
+    1865597427121660, 1865617076154720, 1865643602349351, 1865896092424172,
+    1865901004682437, 1866195740178337, 1866206547146520, 1866277283665536,
+    1866349985087858, 1866626054002351, 1866751807813935, 1866832368849481,
+    1866888368593702, 1866919807046598, 1866933561369740, 1867083876472649,
+    1867316717514410, 1867332436740858, 1867423804744587, 1867472927327237,
+    1867507313135092, 1867553488362783, 1867574119847496, 1867597698687168,
+    1867610470558657, 1867770610178096, 1867806960889257, 1867836434438847,
+    1867966118057043, 1867972995218614, 1867999521413245, 1868018187994652,
+    1868186187227315, 1868341414588489, 1868369905686426, 1868375800396344,
+    1868451449173625, 1868577202985209, 1868659728924061, 1868671518343897,
+    1869035025455507, 1869167656428662, 1869251164819167, 1869291445336940,
+    1869398532567117, 1869761057227074, 1869787583421705, 1869834741101049,
+    1869995863172141, 1870104915305624, 1870109827563889, 1870143230920091,
+    1870208072729189, 1870216914794066, 1870221827052331, 1870319089765978,
+    1870388843833341, 1870440913770950, 1870876139853229, 1871059858312340,
+    1871309401032202, 1871313330838814, 1871471505554947, 1871485259878089,
+    1871605118979755, 1871721048274809, 1871759363889276, 1872041327513687,
+    1872200484681473, 1872233888037675, 1872237817844287, 1872386168043890,
+    1872520763920351, 1872565956696389, 1872571851406307, 1872576763664572,
+    1872755569865418, 1872775218898478, 1872787990769967, 1872795850383191,
+    1872930446259652, 1873044410651400, 1873077814007602, 1873286093758038,
+    1873300830532833, 1873306725242751, 1873370584600196, 1873406935311357,
+    1873459005248966, 1873859845523390, 1873873599846532, 1873934511849018,
+    1874037669272583, 1874076967338703, 1874107423339946, 1874167352890779,
+    1874470930451556, 1874546579228837, 1874647771749096, 1874671350588768,
+    1874914016147059, 1874952331761526, 1875114436284271, 1875214646352877,
+    1875279488161975, 1875285382871893, 1875601732304159, 1875611556820689,
+    1875621381337219, 1875624328692178, 1875650854886809, 1875705872179377,
+    1875774643795087, 1876026151418255, 1876040888193050, 1876411272466231,
+    1876451552984004, 1876543903439386, 1876595973376995, 1876646078411298,
+    1876727621898497, 1876771832222882, 1876776744481147, 1876852393258428,
+    1876908393002649, 1877177584755571, 1877343619084928, 1877366215472947,
+    1877398636377496, 1877432039733698, 1877667828130418, 1877741512004393,
+    1877872178074242, 1877900669172179, 1877915405946974, 1877948809303176,
+    1878036247500293, 1878153159247000, 1878234702734199, 1878289720026767,
+    1878330000544540, 1878335895254458, 1878446912291247, 1878460666614389,
+    1878534350488364, 1878554981973077, 1878580525716055, 1878872313856996,
+    1878965646764031, 1879163119546284, 1879179821224385, 1879316382004152,
+    1879345855553742, 1879349785360354, 1879430346395900, 1879477504075244,
+    1879501082914916, 1879514837238058, 1879568872078973, 1879577714143850,
+    1879683818922374, 1879691678535598, 1879709362665352, 1879733923956677,
+    1879805642927346, 1879855747961649, 1879915677512482, 1879971677256703,
+    1880060097905473, 1880092518810022, 1880109220488123, 1880150483457549,
+    1880459955728244, 1880487464374528, 1880538551860484, 1880561148248503,
+    1880594551604705, 1880672165285292, 1880753708772491, 1880816585678283,
+    1880820515484895, 1880862760905974, 1880876515229116, 1880940374586561,
+    1881026830332025, 1881052374075003, 1881154549046915, 1881190899758076,
+    1881330407892802, 1881353986732474, 1881529845578361, 1881600582097377,
+    1881711599134166, 1881858966882116, 1882036790631309, 1882091807923877,
+    1882104579795366, 1882122263925120, 1882335455933821, 1882651805366087,
+    1882753980337999, 1882757910144611, 1882832576470239, 1882864014923135,
+    1882913137505785, 1882951453120252, 1883004505509514, 1883130259321098,
+    1883138118934322, 1883241276357887, 1883320854941780, 1883735449539346,
+    1883762958185630, 1883819940381504, 1883955518709618, 1884073412907978,
+    1884506674086951, 1884851514617154, 1884910461716334, 1884952707137413,
+    1884963514105596, 1885229758503559, 1885428213737465, 1885433125995730,
+    1885500915159787, 1885535300967642, 1885609967293270, 1885964632340003,
+    1886035368859019, 1886122807056136, 1886200420736723, 1886210245253253,
+    1886239718802843, 1886335016613184, 1886410665390465, 1886479437006175,
+    1886541331460314, 1886701471079753, 1886807575858277, 1886815435471501,
+    1886827224891337, 1887001118833918, 1887059083481445, 1887159293550051,
+    1887213328390966, 1887240837037250, 1887290942071553, 1887320415621143,
+    1887560133824475, 1887721255895567, 1887757606606728, 1887922658484432,
+    1887981605583612, 1888122096169991, 1888135850493133, 1888143710106357,
+    1888199709850578, 1888201674753884, 1888257674498105, 1888296972564225,
+    1888366726631588, 1888380480954730, 1888433533343992, 1888614304448144,
+    1888661462127488, 1888741040711381, 1888808829875438, 1889043635820505,
+    1889061319950259, 1889303985508550, 1889305950411856, 1889427774416828,
+    1889560405389983, 1889762790430501, 1889763772882154, 1889834509401170,
+    1889904263468533, 1889957315857795, 1889974999987549, 1890036894441688,
+    1890040824248300, 1890386647230156, 1890441664522724, 1890464260910743,
+    1890513383493393, 1890702014210769, 1890737382470277, 1890858224023596,
+    1890951556930631, 1891197169843881, 1891282643137692, 1891483063274904,
+    1891521378889371, 1891524326244330, 1891529238502595, 1891533168309207,
+    1891590150505081, 1891657939669138, 1891762079544356, 1891837728321637,
+    1891927131422060, 1892137376075802, 1892175691690269, 1892364322407645,
+    1892651198290321, 1892723899712643, 1892849653524227, 1892853583330839,
+    1892862425395716, 1892869302557287, 1893112950567231, 1893165020504840,
+    1893197441409389, 1893318282962708, 1893335967092462, 1893348738963951,
+    1893440106967680, 1893490212001983, 1893594351877201, 1893596316780507,
+    1893659193686299, 1893782982594577, 1893865508533429, 1894237857709916,
+    1894277155776036, 1894648522500870, 1894689785470296, 1894821433991798,
+    1894851889993041, 1895020871677357, 1895121081745963, 1895405010273680,
+    1895492448470797, 1895521922020387, 1895625079443952, 1896134971851859,
+    1896208655725834, 1896425777541147, 1896712653423823, 1896759811103167,
+    1896928792787483, 1896984792531704, 1897430825582166, 1897435737840431,
+    1897554614490444, 1897595877459870, 1897680368302028, 1897723596174760,
+    1897756017079309, 1897855244696262, 1898338610909538, 1898419171945084,
+    1898432926268226, 1898596995694277, 1898597978145930, 1898667732213293,
+    1898703100472801, 1898796433379836, 1898820994671161, 1898828854284385,
+    1899150115974916, 1899203168364178, 1899278817141459, 1899354465918740,
+    1899447798825775, 1899455658438999, 1899660008382823, 1899702253803902,
+    1899739586966716, 1899763165806388, 1899818183098956, 1899823095357221,
+    1899873200391524, 1899874182843177, 1900088357303531, 1900257338987847,
+    1900309408925456, 1900356566604800, 1900950949854865, 1901006949599086,
+    1901179861090014, 1901708420079328, 1901734946273959, 1901946173379354,
+    1901959927702496, 1902127926935159, 1902210452874011, 1902492416498422,
+    1902552346049255, 1902676134957533, 1902809748382341, 1902813678188953,
+    1902836274576972, 1903100554071629, 1903267570852639, 1903413956148936,
+    1903549534477050, 1903573113316722, 1903597674608047, 1903881603135764,
+    1904349250122592, 1904377741220529, 1904416056834996, 1904442583029627,
+    1904458302256075, 1904721599299079, 1904743213235445, 1904757950010240,
+    1904764827171811, 1904781528849912, 1904890580983395, 1904966229760676,
+    1904998650665225, 1905035001376386, 1905242298675169, 1905291421257819,
+    1905316965000797, 1905485946685113, 1905736471856628, 1905815067988868,
+    1905819980247133, 1905822927602092, 1905874015088048, 1905891699217802,
+    1905921172767392, 1905975207608307, 1905988961931449, 1906138294582705,
+    1906251276522800, 1906319065686857, 1906575485568290, 1906613801182757,
+    1906654081700530, 1906659976410448, 1906673730733590, 1906911484033616,
+    1906997939779080, 1907411551924993, 1907741655680401, 1908000040465140,
+    1908196530795740, 1908312460090794, 1908338003833772, 1908464740097009,
+    1908506985518088, 1908714282816871, 1908727054688360, 1908779124625969,
+    1909062070702033, 1909072877670216, 1909223192773125, 1909264455742551,
+    1909628945305814, 1909634840015732, 1909754699117398, 1909881435380635,
+    1909903049317001, 1909966908674446, 1909977715642629, 1910184030489759,
+    1910238065330674, 1910394275143501, 1910411959273255, 1910455187145987,
+    1910493502760454, 1910730273608827, 1910764659416682, 1910775466384865,
+    1910795115417925, 1910806904837761, 1910861922130329, 1910884518518348,
+    1910966062005547, 1910971956715465, 1911103605236967, 1911278481631201,
+    1911603673128344, 1911765777651089, 1911844373783329, 1911858128106471,
+    1911895461269285, 1911907250689121, 1912380792385867, 1912403388773886,
+    1912512440907369, 1912669633171849, 1912730545174335, 1912910333826834,
+    1913001701830563, 1913124508287188, 1913140227513636, 1913239455130589,
+    1913241420033895, 1913450682235984, 1913554822111202, 1913622611275259,
+    1913623593726912, 1913645207663278, 1913675663664521, 1913738540570313,
+    1913814189347594, 1914009697226541, 1914029346259601, 1914138398393084,
+    1914195380588958, 1914373204338151, 1914453765373697, 1914507800214612,
+    1914542186022467, 1914756360482821, 1914774044612575, 1914797623452247,
+    1915103165916330, 1915184709403529, 1915190604113447, 1915313410570072,
+    1915336006958091, 1915483374706041, 1915729970070944, 1915752566458963,
+    1915756496265575, 1915951021692869, 1915979512790806, 1916162248798264,
+    1916170108411488, 1916214318735873, 1916236915123892, 1916420633583003,
+    1916458949197470, 1916552282104505, 1916816561599162, 1916919719022727,
+    1917388348461208, 1917400137881044, 1917541610919076, 1917579926533543,
+    1917609400083133, 1917856977899689, 1917884486545973, 1917887433900932,
+    1917961117774907, 1918037749003841, 1918040696358800, 1918092766296409,
+    1918375712372473, 1918452343601407, 1918678307481597, 1918979920139068,
+    1919151849178343, 1919587075260622, 1919672548554433, 1919721671137083,
+    1919851354755279, 1920038020569349, 1920070441473898, 1920228616190031,
+    1920238440706561, 1920270861611110, 1920702157886777, 1920716894661572,
+    1920748333114468, 1920791560987200, 1920937946283497, 1920955630413251,
+    1921088261386406, 1921244471199233, 1921315207718249, 1921318155073208,
+    1921438014174874, 1921486154305871, 1921702293669531, 1921722925154244,
+    1921742574187304, 1921845731610869, 1921961660905923, 1922566851124171,
+    1922630710481616, 1922755481841547, 1922819341198992, 1922881235653131,
+    1922894989976273, 1922932323139087, 1923046287530835, 1923269304056066,
+    1923355759801530, 1923516881872622, 1923639688329247, 1923647547942471,
+    1923671126782143, 1923732038784629, 1923861722402825, 1923949160599942,
+    1924216387449558, 1924543543850007, 1924586771722739, 1924760665665320,
+    1924769507730197, 1924944384124431, 1925018067998406, 1925093716775687,
+    1925546626987720, 1925568240924086, 1925676310605916, 1925706766607159,
+    1925758836544768, 1926135115527867, 1926147887399356, 1926235325596473,
+    1926305079663836, 1926401359925830, 1926434763282032, 1926559534641963,
+    1926626341354367, 1926877848977535, 1926886691042412, 1927027181628791,
+    1927187321248230, 1927287531316836, 1927290478671795, 1927328794286262,
+    1927384794030483, 1927417214935032, 1927516442551985, 1927557705521411,
+    1927564582682982, 1927700161011096, 1927727669657380, 1927804300886314,
+    1927924159987980, 1928003738571873, 1928098053930561, 1928310263487609,
+    1928395736781420, 1928633490081446, 1928668858340954, 1928818190992210,
+    1928836857573617, 1928862401316595, 1928887945059573, 1929216083911675,
+    1929223943524899, 1929330048303423, 1929504924697657, 1929529485988982,
+    1929540292957165, 1929566819151796, 1929594327798080, 1929608082121222,
+    1930075729108050, 1930113062270864, 1930177904079962, 1930205412726246,
+    1930237833630795, 1930600358290752, 1930771304878374, 1931060145664356,
+    1931120075215189, 1931174110056104, 1931191794185858, 1931359793418521,
+    1931664353430951, 1931688914722276, 1931775370467740, 1931898176924365,
+    1931905054085936, 1932196842226877, 1932327508296726, 1932382525589294,
+    1932423788558720, 1932520068820714, 1932657612052134, 1932686103150071,
+    1932719506506273, 1932893400448854, 1933035855938539, 1933067294391435,
+    1933171434266653, 1933221539300956, 1933425889244780, 1933441608471228,
+    1933489748602225, 1933803150679532, 1934152903468000, 1934349393798600,
+    1934419147865963, 1934494796643244, 1934575357678790, 1934673602844090,
+    1934707988651945, 1935046934472230, 1935132407766041, 1935175635638773,
+    1935250301964401, 1935435985326818, 1935508686749140, 1935711071789658,
+    1935808334503305, 1935815211664876, 1935844685214466, 1935995000317375,
+    1936025456318618, 1936125666387224, 1936228823810789, 1936294648071540,
+    1936319209362865, 1936726926798860, 1936779979188122, 1936806505382753,
+    1936821242157548, 1936899838289788, 1937064890167492, 1937152328364609,
+    1937330152113802, 1937345871340250, 1937576747478705, 1937824325295261,
+    1937938289687009, 1938006078851066, 1938170148277117, 1938276253055641,
+    1938421655900285, 1938554286873440, 1938655479393699, 1938729163267674,
+    1938750777204040, 1938767478882141, 1938825443529668, 1938939407921416,
+    1938950214889599, 1939050424958205, 1939309792194597, 1939369721745430,
+    1939410002263203, 1939659544983065, 1939782351439690, 1939942491059129,
+    1940018139836410, 1940194981133950, 1940377717141408, 1940899398969151,
+    1940923960260476, 1940946556648495, 1940967188133208, 1941294344533657,
+    1941460378863014, 1941490834864257, 1941538974995254, 1941566483641538,
+    1941594974739475, 1941672588420062, 1941724658357671, 1941813079006441,
+    1941873008557274, 1942073428694486, 1942196235151111, 1942237498120537,
+    1942282690896575, 1942339673092449, 1942620654265207, 1942628513878431,
+    1942848583048703, 1943029354152855, 1943112862543360, 1943431176878932,
+    1943464580235134, 1943479317009929, 1943665000372346, 1943685631857059,
+    1943816297926908, 1943825139991785, 1943848718831457, 1943979384901306,
+    1943986262062877, 1944230892524474, 1944247594202575, 1944261348525717,
+    1944995239910508, 1945111169205562, 1945276221083266, 1945314536697733,
+    1945332220827487, 1945480571027090, 1945683938519261, 1945819516847375,
+    1945841130783741, 1945882393753167, 1945915797109369, 1946115234794928,
+    1946191866023862, 1946411935194134, 1946497408487945, 1946503303197863,
+    1946662460365649, 1946677197140444, 1946683091850362, 1947168422966944,
+    1947200843871493, 1947285334713651, 1947392421943828, 1947612491114100,
+    1947628210340548, 1947644912018649, 1947730385312460, 1947750034345520,
+    1947876770608757, 1947890524931899, 1947906244158347, 1948100769585641,
+    1948132208038537, 1948186242879452, 1948253049591856, 1948285470496405,
+    1948663714382810, 1948780626129517, 1948793398001006, 1949212904856837,
+    1949236483696509, 1949332763958503, 1949398588219254, 1949423149510579,
+    1949444763446945, 1949456552866781, 1949549885773816, 1949668762423829,
+    1949742446297804, 1949930094563527, 1950007708244114, 1950104970957761,
+    1950308338449932, 1950455706197882, 1950477320134248, 1950621740527239,
+    1950779915243372, 1950925318088016, 1950978370477278, 1951041247383070,
+    1951058931512824, 1951195492292591, 1951211211519039, 1951391000171538,
+    1951459771787248, 1951507911918245, 1951539350371141, 1951578648437261,
+    1951728963540170, 1951755489734801, 1951953944968707, 1951990295679868,
+    1952028611294335, 1952096400458392, 1952134716072859, 1952142575686083,
+    1952153382654266, 1952171066784020, 1952459907570002, 1952547345767119,
+    1952788046422104, 1952858782941120, 1953027764625436, 1953053308368414,
+    1953319552766377, 1953483622192428, 1953567130582933, 1953637867101949,
+    1953801936528000, 1953829445174284, 1953851059110650, 1953967970857357,
+    1954023970601578, 1954248952030115, 1954344249840456, 1954582985592135,
+    1954639967788009, 1954660599272722, 1954689090370659, 1954723476178514,
+    1954810914375631, 1954968106640111, 1955041790514086, 1955052597482269,
+    1955216666908320, 1955254000071134, 1955310982267008, 1955398420464125,
+    1955410209883961, 1955444595691816, 1955450490401734, 1955480946402977,
+    1955603752859602, 1955757997769123, 1955818909771609, 1956294416371661,
+    1956306205791497, 1956329784631169, 1956345503857617, 1956464380507630,
+    1956495818960526, 1956513503090280, 1956597011480785, 1956638274450211,
+    1956895676783297, 1956932027494458, 1957041079627941, 1957043044531247,
+    1957047956789512, 1957633497974700, 1957638410232965, 1957663953975943,
+    1957996022634657, 1958264231935926, 1958363459552879, 1958637563564066,
+    1958826194281442, 1959079666807916, 1959159245391809, 1959222122297601,
+    1959359665529021, 1959390121530264, 1959417630176548, 1959692716639388,
+    1959885277163376, 1960070960525793, 1960235029951844, 1960237977306803,
+    1960257626339863, 1960294959502677, 1960404994087813, 1960537625060968,
+    1960548432029151, 1960705624293631, 1960713483906855, 1960732150488262,
+    1960757694231240, 1960799939652319, 1960896219914313, 1961231235927986,
+    1961297060188737, 1961331445996592, 1961342252964775, 1961447375291646,
+    1961455234904870, 1961478813744542, 1961483726002807, 1961521059165621,
+    1961641900718940, 1961645830525552, 1961648777880511, 1961817759564827,
+    1961910110020209, 1962155722933459, 1962183231579743, 1962210740226027,
+    1962359090425630, 1962554598304577, 1962919087867840, 1962933824642635,
+    1962938736900900, 1963020280388099, 1963109683488522, 1963458453825337,
+    1963687365060486, 1963701119383628, 1964258169470879, 1964595150387858,
+    1964599080194470, 1964653115035385, 1964663922003568, 1964789675815152,
+    1964811289751518, 1964953745241203, 1965147288216844, 1965172831959822,
+    1965267147318510, 1965816337792537, 1965846793793780, 1966111073288437,
+    1966175915097535, 1966258441036387, 1966349809040116, 1966371422976482,
+    1966519773176085, 1966828262995127, 1966831210350086, 1966843982221575,
+    1967302787143526, 1967335208048075, 1967348962371217, 1967367628952624,
+    1967434435665028, 1967611276962568, 1967839205746064, 1967858854779124,
+    1967915836974998, 1967958082396077, 1968066152077907, 1968517097386634,
+    1968642851198218, 1968690991329215, 1968796113656086, 1968930709532547,
+    1968938569145771, 1968971972501973, 1968993586438339, 1968994568889992,
+    1968996533793298, 1969184182059021, 1969481864909880, 1969520180524347,
+    1969537864654101, 1969554566332202, 1969566355752038, 1969596811753281,
+    1969650846594196, 1969697021821887, 1970054634223579, 1970065441191762,
+    1970119476032677, 1970165651260368, 1970302212040135, 1970687333088111,
+    1970714841734395, 1970743332832332, 1970964384454257, 1971281716338176,
+    1971303330274542, 1971319049500990, 1971494908346877, 1971515539831590,
+    1971717924872108, 1971720872227067, 1971733644098556, 1971896731072954,
+    1971930134429156, 1972026414691150, 1972094203855207, 1972495044129631,
+    1972522552775915, 1972800586593714, 1972843814466446, 1972969568278030,
+    1973028515377210, 1973081567766472, 1973115953574327, 1973135602607387,
+    1973176865576813, 1973209286481362, 1973377285714025, 1973427390748328,
+    1973478478234284, 1973589495271073, 1973615039014051, 1973645495015294,
+    1973664161596701, 1973954967285989, 1973977563674008, 1974049282644677,
+    1974080721097573, 1974279176331479, 1974507105114975, 1974527736599688,
+    1974620087055070, 1974721279575329, 1975172224884056, 1975259663081173,
+    1975296996243987, 1975335311858454, 1975356925794820, 1975673275227086,
+    1975720432906430, 1975750888907673, 1975774467747345, 1976137974858955,
+    1976191027248217, 1976259798863927, 1976267658477151, 1976339377447820,
+    1976391447385429, 1976501481970565, 1976612499007354, 1976722533592490,
+    1976752989593733, 1976997620055330, 1977007444571860, 1977204917354113,
+    1977327723810738, 1977399442781407, 1977424004072732, 1977440705750833,
+    1977760002538058, 1977809125120708, 1977818949637238, 1977840563573604,
+    1977858247703358, 1977862177509970, 1977982036611636, 1978273824752577,
+    1978295438688943, 1978425122307139, 1978963505812983, 1979132487497299,
+    1979136417303911, 1979247434340700, 1979389889830385, 1979459643897748,
+    1979466521059319, 1979641397453553, 1980017676436652, 1980147360054848,
+    1980204342250722, 1980334025868918, 1980431288582565, 1980492200585051,
+    1980507919811499, 1980805602662358, 1980838023566907, 1980840970921866,
+    1980848830535090, 1981065952350403, 1981071847060321, 1981240828744637,
+    1981274232100839, 1981285039069022, 1981380336879363, 1981540476498802,
+    1981569950048392, 1981692756505017, 1981739914184361, 1981814580509989,
+    1981836194446355, 1981850931221150, 1981870580254210, 1982102438844318,
+    1982133877297214, 1982149596523662, 1982156473685233, 1982663418738181,
+    1982760681451828, 1982779348033235, 1983000399655160, 1983009241720037,
+    1983228328438656, 1983412046897767, 1983523063934556, 1983534853354392,
+    1983609519680020, 1983633098519692, 1983724466523421, 1983806010010620,
+    1983816816978803, 1984066359698665, 1984125306797845, 1984433796616887,
+    1984441656230111, 1984682356885096, 1985104811095886, 1985131337290517,
+    1985221722842593, 1985394634333521, 1985486002337250, 1985486984788903,
+    1985521370596758, 1985529230209982, 1985673650602973, 1985826913060841,
+    1985852456803819, 1986046982231113, 1986254279529896, 1986324033597259,
+    1986338770372054, 1986640383029525, 1986648242642749, 1986703259935317,
+    1987130626404372, 1987168942018839, 1987340871058114, 1987706343073030,
+    1987768237527169, 1987769219978822, 1987828167078002, 1987842903852797,
+    1987994201407359, 1988015815343725, 1988035464376785, 1988147463865227,
+    1988202481157795, 1988237849417303, 1988324305162767, 1988450058974351,
+    1988466760652452, 1988482479878900, 1988528655106591, 1988660303628093,
+    1988827320409103, 1988992372286807, 1989130897969880, 1989148582099634,
+    1989253704426505, 1989302827009155, 1989422686110821, 1989675176185642,
+    1989871666516242, 1989894262904261, 1989904087420791, 1990141840720817,
+    1990199805368344, 1990227314014628, 1990355032729518, 1990413979828698,
+    1990427734151840, 1990620294675828, 1990653698032030, 1990659592741948,
+    1990684154033273, 1990695943453109, 1990863942685772, 1990916995075034,
+    1990928784494870, 1990979871980826, 1991466185549061, 1991546746584607,
+    1991790394594551, 1991874885436709, 1992052709185902, 1992074323122268,
+    1992263936291297, 1992418181200818, 1992439795137184, 1992561619142156,
+    1992610741724806, 1992985055804599, 1992988003159558, 1993217896846360,
+    1993377054014146, 1993400632853818, 1993512632342260, 1993691438543106,
+    1993803438031548, 1994195436241095, 1994214102822502, 1994254383340275,
+    1994271085018376, 1994489189285342, 1994750521425040, 1994863503365135,
+    1994967643240353, 1995070800663918, 1995086519890366, 1995098309310202,
+    1995118940794915, 1995324273190392, 1995373395773042, 1995375360676348,
+    1995440202485446, 1995446097195364, 1995872481212766, 1995899007407397,
+    1995948129990047, 1995966796571454, 1996040480445429, 1996112199416098,
+    1996123006384281, 1996149532578912, 1996283146003720, 1996285110907026,
+    1996384338523979, 1996443285623159, 1996470794269443, 1996527776465317,
+    1996564127176478, 1996833318929400, 1996934511449659, 1997037668873224,
+    1997138861393483, 1997202720750928, 1997239071462089, 1997269527463332,
+    1997295071206310, 1997325527207553, 1997331421917471, 1997399211081528,
+    1997707700900570, 1997715560513794, 1997780402322892, 1997841314325378,
+    1998088892141934, 1998288329827493, 1998362013701468, 1998438644930402,
+    1998669521068857, 1998752047007709, 1998781520557299, 1998882713077558,
+    1998987835404429, 1999186290638335, 1999195132703212, 1999231483414373,
+    1999476113875970, 1999902497893372, 1999925094281391, 1999929024088003,
+    2000007620220243, 2000035128866527, 2000503758305008, 2000552880887658,
+    2000694353925690, 2000923265160839, 2000986142066631, 2001022492777792,
+    2001032317294322, 2001056878585647, 2001176737687313, 2001240597044758,
+    2001401719115850, 2001450841698500, 2001459683763377, 2001600174349756,
+    2001605086608021, 2001765226227460, 2001865436296066, 2001986277849385,
+    2002082558111379, 2002137575403947, 2002263329215531, 2002302627281651,
+    2002807607431293, 2002893080725104, 2003039466021401, 2003104307830499,
+    2003115114798682, 2003216307318941, 2003309640225976, 2003338131323913,
+    2003587674043775, 2003686901660728, 2003806760762394, 2003905005927694,
+    2003942339090508, 2003985566963240, 2004444371885191, 2004700791766624,
+    2004729282864561, 2005227385852632, 2005287315403465, 2005337420437768,
+    2005367876439011, 2005428788441497, 2005463174249352, 2005609559545649,
+    2005778541229965, 2005796225359719, 2005881698653530, 2005886610911795,
+    2005899382783284, 2006058539951070, 2006075241629171, 2006098820468843,
+    2006162679826288, 2006498678291614, 2006618537393280, 2006634256619728,
+    2006716782558580, 2006920150050751, 2006929974567281, 2006955518310259,
+    2007009553151174, 2007021342571010, 2007112710574739, 2007226674966487,
+    2007253201161118, 2007521410462387, 2007738532277700, 2007824005571511,
+    2007979232932685, 2008072565839720, 2008098109582698, 2008118741067411,
+    2008280845590156, 2008367301335620, 2008557896956302, 2008640422895154,
+    2008730808447230, 2009050105234455, 2009141473238184, 2009145403044796,
+    2009174876594386, 2009342875827049, 2009412629894412, 2009627786806419,
+    2009657260356009, 2009678874292375, 2009744698553126, 2009849820879997,
+    2009921539850666, 2009947083593644, 2009995223724641, 2010147503730856,
+    2010204485926730, 2010231994573014, 2010314520511866, 2010472695227999,
+    2010494309164365, 2010500203874283, 2010581747361482, 2010847991759445,
+    2010859781179281, 2010915780923502, 2010968833312764, 2011059218864840,
+    2011134867642121, 2011136832545427, 2011308761584702, 2011374585845453,
+    2011385392813636, 2011431568041327, 2011621181210356, 2011837320574016,
+    2011863846768647, 2011911004447991, 2011941460449234, 2012006302258332,
+    2012067214260818, 2012073108970736, 2012121249101733, 2012213599557115,
+    2012222441621992, 2012227353880257, 2012420896855898, 2012441528340611,
+    2012964192620007, 2013013315202657, 2013182296886973, 2013183279338626,
+    2013420050186999, 2013615558065946, 2013675487616779, 2013707908521328,
+    2013869030592420, 2014008538727146, 2014071415632938, 2014077310342856,
+    2014210923767664, 2014403484291652, 2014649097204902, 2014676605851186,
+    2014688395271022, 2014737517853672, 2014761096693344, 2014828885857401,
+    2015120673998342, 2015620741889719, 2015743548346344, 2015913512482313,
+    2016055967971998, 2016081511714976, 2016246563592680, 2016334984241450,
+    2016350703467898, 2016622842575779, 2016633649543962, 2016819332906379,
+    2016836034584480, 2017135682338645, 2017256523891964, 2017407821446526,
+    2017482487772154, 2017547329581252, 2017688802619284, 2017879398239966,
+    2017885292949884, 2017972731147001, 2017994345083367, 2018023818632957,
+    2018086695538749, 2018273361352819, 2018539605750782, 2018607394914839,
+    2018658482400795, 2018700727821874, 2018727254016505, 2018742973242953,
+    2018747885501218, 2018972866929755, 2019063252481831, 2019233216617800,
+    2019280374297144, 2019300023330204, 2019313777653346, 2019331461783100,
+    2019481776886009, 2019520092500476, 2019552513405025, 2019658618183549,
+    2020059458457973, 2020116440653847, 2020149844010049, 2020405281439829,
+    2020411176149747, 2020557561446044, 2020693139774158, 2020727525582013,
+    2021009489206424, 2021019313722954, 2021021278626260, 2021196155020494,
+    2021206961988677, 2021243312699838, 2021315031670507, 2022264079967305,
+    2022278816742100, 2022893831476878, 2022997971352096, 2023189549424431,
+    2023348706592217, 2023380145045113, 2023457758725700, 2023919511002610,
+    2023979440553443, 2023980423005096, 2024007931651380, 2024066878750560,
+    2024140562624535, 2024149404689412, 2024241755144794, 2024576771158467,
+    2024637683160953, 2024666174258890, 2024719226648152, 2024781121102291,
+    2024805682393616, 2024855787427919, 2025005120079175, 2025011997240746,
+    2025051295306866, 2025074874146538, 2025081751308109, 2025143645762248,
+    2025205540216387, 2025613257652382, 2025694801139581, 2025799923466452,
+    2025952203472667, 2026092694059046, 2026107430833841, 2026134939480125,
+    2026151641158226, 2026158518319797, 2026162448126409, 2026284272131381,
+    2026315710584277, 2026321605294195, 2026496481688429, 2026534797302896,
+    2026780410216146, 2026785322474411, 2026872760671528, 2026963146223604,
+    2027069251002128, 2027318793721990, 2027344337464968, 2027470091276552,
+    2027568336441852, 2027589950378218, 2027775633740635, 2027901387552219,
+    2028112614657614, 2028116544464226, 2028370999442353, 2028379841507230,
+    2028408332605167, 2028416192218391, 2028426016734921, 2028432893896492,
+    2028505595318814, 2028594015967584, 2028642156098581, 2028856330558935,
+    2028966365144071, 2029002715855232, 2029070505019289, 2029130434570122,
+    2029259135736665, 2029437941937511, 2029478222455284, 2029499836391650,
+    2029631484913152, 2029678642592496, 2029797519242509, 2030011693702863,
+    2030078500415267, 2030292674875621, 2030322148425211, 2030380113072738,
+    2030447902236795, 2031074706391409, 2031119899167447, 2031151337620343,
+    2031237793365807, 2031273161625315, 2031424459179877, 2031474564214180,
+    2031804667969588, 2031925509522907, 2031985439073740, 2031998210945229,
+    2032336174313861, 2032383331993205, 2032508103353136, 2032756663621345,
+    2032851961431686, 2032858838593257, 2032889294594500, 2032979680146576,
+    2032993434469718, 2033022908019308, 2033069083246999, 2033336310096615,
+    2033674273465247, 2033916939023538, 2033928728443374, 2034028938511980,
+    2034223463939274, 2034250972585558, 2034409147301691, 2034577146534354,
+    2034631181375269, 2034762829896771, 2034893495966620, 2034997635841838,
+    2035176442042684, 2035327739597246, 2035471177538584, 2035569422703884,
+    2035857281038213, 2036012508399387, 2036123525436176, 2036249279247760,
+    2036270893184126, 2036946819921390, 2036949767276349, 2036968433857756,
+    2037165906640009, 2037178678511498, 2037257274643738, 2037292642903246,
+    2037339800582590, 2037507799815253, 2037767167051645, 2037792710794623,
+    2037884078798352, 2037934183832655, 2038011797513242, 2038055025385974,
+    2038056007837627, 2038202393133924, 2038236778941779, 2038250533264921,
+    2038257410426492, 2038304568105836, 2038484356758335, 2038553128374045,
+    2038913688130696, 2038975582584835, 2039006038586078, 2039112143364602,
+    2039336142341486, 2039368563246035, 2039428492796868, 2039436352410092,
+    2039674105710118, 2039933472946510, 2040158454375047, 2040247857475470,
+    2040407997094909, 2040580908585837, 2040583855940796, 2040767574399907,
+    2040962099827201, 2041069187057378, 2041105537768539, 2041185116352432,
+    2041266659839631, 2041727429664888, 2041898376252510, 2041916060382264,
+    2042096831486416, 2042119427874435, 2042157743488902, 2042348339109584,
+    2042357181174461, 2042402373950499, 2042451496533149, 2042678442864992,
+    2042771775772027, 2042891634873693, 2042943704811302, 2042948617069567,
+    2043202089596041, 2043226650887366, 2043236475403896, 2043254159533650,
+    2043355352053909, 2043522368834919, 2043741455553538, 2043747350263456,
+    2043753244973374, 2043758157231639, 2043839700718838, 2043870156720081,
+    2043883911043223, 2044074506663905, 2044455697905269, 2044636469009421,
+    2045110993157820, 2045164045547082, 2045221027742956, 2045346781554540,
+    2045427342590086, 2045435202203310, 2045491201947531, 2045530500013651,
+    2045561938466547, 2045578640144648, 2045717165827721, 2045736814860781,
+    2045742709570699, 2046026638098416, 2046169093588101, 2046198567137691,
+    2046302707012909, 2046470706245572, 2046708459545598, 2047078843818779,
+    2047084738528697, 2047097510400186, 2047103405110104, 2047261579826237,
+    2047289088472521, 2047406000219228, 2047411894929146, 2047412877380799,
+    2047567122290320, 2047631964099418, 2047829436881671, 2047874629657709,
+    2048193926444934, 2048217505284606, 2048500451360670, 2048786344791693,
+    2048874765440463, 2048898344280135, 2048988729832211, 2049197992034300,
+    2049277570618193, 2049398412171512, 2049538902757891, 2049756024573204,
+    2049769778896346, 2050018339164555, 2050260022271193, 2050299320337313,
+    2050386758534430, 2050422126793938, 2050509564991055, 2050519389507585,
+    2050538056088992, 2050549845508828, 2050554757767093, 2050575389251806,
+    2050691318546860, 2050701143063390, 2050831809133239, 2050850475714646,
+    2050953633138211, 2050956580493170, 2050957562944823, 2051209070567991,
+    2051474332514301, 2051549981291582, 2051726822589122, 2052037277311470,
+    2052049066731306, 2052133557573464, 2052163031123054, 2052225908028846,
+    2052253416675130, 2052591380043762, 2052620853593352, 2052637555271453,
+    2052641485078065, 2052971588833473, 2053088500580180, 2053095377741751,
+    2053168079164073, 2053189693100439, 2053707445121570, 2053781128995545,
+    2053865619837703, 2053910812613741, 2054287091596840, 2054715440517548,
+    2054835299619214, 2054903088783271, 2054924702719637, 2054968913044022,
+    2054990526980388, 2055185052407682, 2055228280280414, 2055251859120086,
+    2055376630480017, 2055398244416383, 2055664488814346, 2055826593337091,
+    2055873751016435, 2055886522887924, 2056288345614001, 2056422941490462,
+    2056732413761157, 2056739290922728, 2056766799569012, 2057031079063669,
+    2057078236743013, 2057101815582685, 2057450585919500, 2057652970960018,
+    2057674584896384, 2057777742319949, 2057779707223255, 2057860268258801,
+    2058083284784032, 2058153038851395, 2058170722981149, 2058188407110903,
+    2058235564790247, 2058487072413415, 2058671773324179, 2058799492039069,
+    2058814228813864, 2059162999150679, 2059246507541184, 2059320191415159,
+    2059394857740787, 2059499980067658, 2059647347815608, 2059675838913545,
+    2059843838146208, 2060221099580960, 2060226994290878, 2060326221907831,
+    2060537449013226, 2060569869917775, 2060772254958293, 2060840044122350,
+    2060998218838483, 2061014920516584, 2061025727484767, 2061066008002540,
+    2061278217559588, 2061314568270749, 2061472742986882, 2061714426093520,
+    2062058284172070, 2062262634115894, 2062412949218803, 2062521018900633,
+    2062530843417163, 2062590772967996, 2062894350528773, 2062938560853158,
+    2063129156473840, 2063135051183758, 2063522137135040, 2063589926299097,
+    2063621364751993, 2063696031077621, 2063740241402006, 2063756943080107,
+    2063891538956568, 2064077222318985, 2064132239611553, 2064169572774367,
+    2064230484776853, 2064649009181031, 2064689289698804, 2064701079118640,
+    2064715815893435, 2064739394733107, 2064801289187246, 2064965358613297,
+    2065263041464156, 2065297427272011, 2065321006111683, 2065527320958813,
+    2065615741607583, 2065746407677432, 2065766056710492, 2066045072979944,
+    2066186546017976, 2066203247696077, 2066252370278727, 2066259247440298,
+    2066305422667989, 2066426264221308, 2066428229124614, 2066431176479573,
+    2066895876111442, 2066908647982931, 2066966612630458, 2067064857795758,
+    2067110050571796, 2067119875088326, 2067448013940428, 2067483382199936,
+    2067593416785072, 2067648434077640, 2067908783765685, 2068060081320247,
+    2068105274096285, 2068177975518607, 2068233975262828, 2068463868949630,
+    2068468781207895, 2068524780952116, 2068528710758728, 2068548359791788,
+    2068628920827334, 2069030743553411, 2069069059167878, 2069107374782345,
+    2069379513890226, 2069605477770416, 2069632986416700, 2070188071600645,
+    2070226387215112, 2070290246572557, 2070496561419687, 2070654736135820,
+    2070842384401543, 2070890524532540, 2070901331500723, 2070998594214370,
+    2071338522486308, 2071369960939204, 2071570381076416, 2071751152180568,
+    2071777678375199, 2071858239410745, 2071882800702070, 2071995782642165,
+    2072377956335182, 2072537113502968, 2072713954800508, 2072769954544729,
+    2072864269903417, 2072936971325739, 2072987076360042, 2073013602554673,
+    2073024409522856, 2073122654688156, 2073183566690642, 2073227777015027,
+    2073258233016270, 2073332899341898, 2073472407476624, 2073580477158454,
+    2073646301419205, 2073658090839041, 2074033387370487, 2074106088792809,
+    2074257386347371, 2074452894226318, 2074636612685429, 2074713243914363,
+    2074872401082149, 2075087557994156, 2075145522641683, 2075161241868131,
+    2075283065873103, 2075432398524359, 2075717309503729, 2076001238031446,
+    2076048395710790, 2076141728617825, 2076288113914122, 2076395201144299,
+    2076764602965827, 2076866777937739, 2076872672647657, 2076970917812957,
+    2077057373558421, 2077079969946440, 2077172320401822, 2077205723758024,
+    2077560388804757, 2077569230869634, 2078158701861434, 2078617506783385,
+    2078754067563152, 2078910277375979, 2079178486677248, 2079210907581797,
+    2079226626808245, 2079233503969816, 2079327819328504, 2079471257269842,
+    2079526274562410, 2079777782185578, 2079778764637231, 2079792518960373,
+    2079842623994676, 2079892729028979, 2079947746321547, 2080052868648418,
+    2080108868392639, 2080204166202980, 2080217920526122, 2080267043108772,
+    2080397709178621, 2080402621436886, 2080593217057568, 2080630550220382,
+    2080925285716282, 2081297634892769, 2081414546639476, 2081909702272588,
+    2082032508729213, 2082061982278803, 2082228999059813, 2082247665641220,
+    2082381279066028, 2082414682422230, 2082437278810249, 2082463805004880,
+    2082521769652407, 2082522752104060, 2082558120363568, 2082761487855739,
+    2082845978697897, 2082926539733443, 2082930469540055, 2083028714705355,
+    2083312643233072, 2083404993688454, 2083470817949205, 2083653553956663,
+    2083675167893029, 2083838254867427, 2083919798354626, 2083933552677768,
+    2084058324037699, 2084140849976551, 2084208639140608, 2084497479926590,
+    2084617339028256, 2085012284592762, 2085219581891545, 2085296213120479,
+    2085314879701886, 2085361054929577, 2085476001772978, 2085581124099849,
+    2085714737524657, 2085895508628809, 2086358243357372, 2086376909938779,
+    2086425050069776, 2086566523107808, 2086611715883846, 2086777750213203,
+    2086859293700402, 2086878942733462, 2087171713326056, 2087215923650441,
+    2087249327006643, 2087459571660385, 2087515571404606, 2087715009090165,
+    2087744482639755, 2087779850899263, 2087785745609181, 2087794587674058,
+    2087850587418279, 2087991078004658, 2088283848597252, 2088611987449354,
+    2088788828746894, 2088820267199790, 2088902793138642, 2089046231079980,
+    2089059002951469, 2089298721154801, 2089499141292013, 2089682859751124,
+    2089894086856519, 2090003138990002, 2090122015640015, 2090170155771012,
+    2090406926619385, 2090744889988017, 2090995415159532, 2091336325883123,
+    2091637938540594, 2091661517380266, 2091750920480689, 2092099690817504,
+    2092190076369580, 2092358075602243, 2092440601541095, 2092641021678307,
+    2092697021422528, 2092872880268415, 2093056598727526, 2093156808796132,
+    2093308106350694, 2093586140168493, 2093599894491635, 2093873998502822,
+    2094190347935088, 2094288593100388, 2094439890654950, 2094489995689253,
+    2094509644722313, 2094577433886370, 2094629503823979, 2094644240598774,
+    2094768029507052, 2094899678028554, 2094919327061614, 2094943888352939,
+    2094976309257488, 2095066694809564, 2095236658945533, 2095535324248045,
+    2095541218957963, 2096131672401416, 2096177847629107, 2096410688670868,
+    2096582617710143, 2096705424166768, 2096718196038257, 2096897984690756,
+    2096902896949021, 2097047317342012, 2097146544958965, 2097256579544101,
+    2097378403549073, 2097394122775521, 2097406894647010, 2097697700336298,
+    2097884366150368, 2098164364871473, 2098212505002470, 2098303873006199,
+    2098482679207045, 2098653625794667, 2098778397154598, 2098921835095936,
+    2099080992263722, 2099252921302997, 2099410113567477, 2099437622213761,
+    2099564358476998, 2099724498096437, 2099757901452639, 2099991724946053,
+    2099997619655971, 2100082110498129, 2100113548951025, 2100358179412622,
+    2100392565220477, 2100435793093209, 2100489827934124, 2100536985613468,
+    2100646037746951, 2100661756973399, 2100734458395721, 2100897545370119,
+    2101053755182946, 2101135298670145, 2101146105638328, 2101651085787970,
+    2101712980242109, 2101804348245838, 2101839716505346, 2102127574839675,
+    2102209118326874, 2102254311102912, 2102287714459114, 2102337819493417,
+    2102342731751682, 2102651221570724, 2102672835507090, 2102738659767841,
+    2102755361445942, 2102756343897595, 2102917465968687, 2103071710878208,
+    2103213183916240, 2103349744696007, 2103441112699736, 2103447989861307,
+    2103678865999762, 2103758444583655, 2103884198395239, 2103939215687807,
+    2104291915831234, 2104391143448187, 2104554230422585, 2104576826810604,
+    2104788053915999, 2105075912250328, 2105086719218511, 2105087701670164,
+    2105267490322663, 2105315630453660, 2105318577808619, 2105424682587143,
+    2105459068394998, 2105512120784260, 2105517033042525, 2105551418850380,
+    2105554366205339, 2105839277184709, 2106105521582672, 2106317731139720,
+    2106440537596345, 2106598712312478, 2106618361345538, 2106652747153393,
+    2106771623803406, 2107165586916259, 2107222569112133, 2107345375568758,
+    2107812040103933, 2108089091470079, 2108731614851141, 2108941859504883,
+    2108949719118107, 2108957578731331, 2109359401457408, 2109513646366929,
+    2109549997078090, 2109856521993826, 2109953784707473, 2110089363035587,
+    2110231818525272, 2110475466535216, 2110488238406705, 2110548167957538,
+    2110858622679886, 2110896938294353, 2110906762810883, 2110914622424107,
+    2111075744495199, 2111414690315484, 2111545356385333, 2111547321288639,
+    2111663250583693, 2111680934713447, 2111734969554362, 2112085704794483,
+    2112089634601095, 2112139739635398, 2112237002349045, 2112461001325929,
+    2112614263783797, 2113020998768139, 2113032788187975, 2113306892199162,
+    2113638960857876, 2113720504345075, 2113791240864091, 2113834468736823,
+    2114107590296357, 2114285414045550, 2114865060520820, 2115187304663004,
+    2115195164276228, 2115246251762184, 2115272777956815, 2115286532279957,
+    2115406391381623, 2115492847127087, 2115494812030393, 2115579302872551,
+    2115769898493233, 2115818038624230, 2116065616440786, 2116120633733354,
+    2116302387289159, 2116400632454459, 2116438948068926, 2116461544456945,
+    2116493965361494, 2116943928218568, 2117380136752500, 2117440066303333,
+    2117515715080614, 2117617890052526, 2117741678960804, 2117853678449246,
+    2117973537550912, 2118053116134805, 2118344904275746, 2118475570345595,
+    2118525675379898, 2118592482092302, 2119047357207641, 2119097462241944,
+    2119242865086588, 2119264479022954, 2119278233346096, 2119613249359769,
+    2119626021231258, 2119675143813908, 2119762582011025, 2120156545123878,
+    2120164404737102, 2120431631586718, 2120509245267305, 2120711630307823,
+    2120719489921047, 2121060400644638, 2121207768392588, 2121465170725674,
+    2121946572035644, 2122002571779865, 2122029097974496, 2122372956053046,
+    2122376885859658, 2122605797094807, 2122651972322498, 2123500810550690,
+    2123547968230034, 2123695335977984, 2123760177787082, 2123826002047833,
+    2124008738055291, 2124027404636698, 2124056878186288, 2124106983220591,
+    2124176737287954, 2124261228130112, 2124290701679702, 2124447893944182,
+    2124490139365261, 2124519612914851, 2124591331885520, 2124638489564864,
+    2124883120026461, 2124971540675231, 2125052101710777, 2125168031005831,
+    2125809571935240, 2125889150519133, 2125945150263354, 2126499252995646,
+    2126814619976259, 2126860795203950, 2126959040369250, 2127036654049837,
+    2127309775609371, 2127494476520135, 2127849141566868, 2127988649701594,
+    2128169420805746, 2128304999133860, 2128359033974775, 2128443524816933,
+    2128449419526851, 2128762821604158, 2128950469869881, 2129358187305876,
+    2129706957642691, 2130104850562156, 2130110745272074, 2130245341148535,
+    2130249270955147, 2130431024510952, 2130646181422959, 2130843654205212,
+    2130868215496537, 2131189477187068, 2131194389445333, 2131454739133378,
+    2131479300424703, 2131503861716028, 2131520563394129, 2131664001335467,
+    2131762246500767, 2131852632052843, 2131885052957392, 2132424418914889,
+    2132443085496296, 2132547225371514, 2132795785639723, 2133029609133137,
+    2133177959332740, 2133537536637738, 2133596483736918, 2134012060786137,
+    2134284199894018, 2134446304416763, 2134513111129167, 2134583847648183,
+    2134592689713060, 2134725320686215, 2135063284054847, 2135084897991213,
+    2135091775152784, 2135145809993699, 2135231283287510, 2135238160449081,
+    2135629176206975, 2135679281241278, 2135692053112767, 2135858087442124,
+    2136067349644213, 2136195068359103, 2136430856755823, 2136456400498801,
+    2136818925158758, 2137112678203005, 2137268888015832, 2137368115632785,
+    2137985095270869, 2138114778889065, 2138304392058094, 2138337795414296,
+    2138380040835375, 2139048107959415, 2139404737909454, 2139487263848306,
+    2139497088364836, 2139502000623101, 2139571754690464, 2139630701789644,
+    2139638561402868, 2139716175083455, 2139965717803317, 2139966700254970,
+    2140022699999191, 2140054138452087, 2140123892519450, 2140708451252985,
+    2140824380548039, 2141032660298475, 2141080800429472, 2141258624178665,
+    2141362764053883, 2141927673754358, 2142150690279589, 2142206690023810,
+    2142403180354410, 2142512232487893, 2142739178819736, 2142740161271389,
+    2142777494434203, 2142922897278847, 2142960230441661, 2143138054190854,
+    2143199948644993, 2143230404646236, 2143335526973107, 2143532999755360,
+    2143605701177682, 2143745209312408, 2143809068669853, 2143992787128964,
+    2144365136305451, 2144511521601748, 2144522328569931, 2144622538638537,
+    2144650047284821, 2144822958775749, 2144996852718330, 2145275868987782,
+    2145441903317139, 2145516569642767, 2145542113385745, 2145750393136181,
+    2146208215606479, 2146569757814783, 2146577617428007, 2146676845044960,
+    2146684704658184, 2147191649711132, 2147239789842129, 2147251579261965,
+    2147289894876432, 2147301684296268, 2147325263135940, 2147458876560748,
+    2147631788051676, 2147787015412850, 2147790945219462, 2147881330771538,
+    2147908839417822, 2147927505999229, 2148191785493886, 2148286100852574,
+    2148575924090209, 2148980694171245, 2148986588881163, 2149039641270425,
+    2149198798438211, 2149893391756882, 2150407213971401, 2150450441844133,
+    2150499564426783, 2150507424040007, 2151114579161561, 2151290438007448,
+    2151316964202079, 2151322858911997, 2151330718525221, 2151550787695493,
+    2151595980471531, 2152033171457116, 2152363275212524, 2152931132267958,
+    2153020535368381, 2153225867763858, 2153779970496150, 2153889022629633,
+    2153937162760630, 2154354704713155, 2154574773883427, 2154697580340052,
+    2155041438418602, 2155076806678110, 2155345998431032, 2155430489273190,
+    2155431471724843, 2155455050564515, 2155895188905059, 2156076942460864,
+    2156204661175754, 2156392309441477, 2156685080034071, 2156739114874986,
+    2156873710751447, 2156967043658482, 2157184165473795, 2157315813995297,
+    2157585005748219, 2157596795168055, 2157615461749462, 2157773636465595,
+    2157807039821797, 2158142055835470, 2158255037775565, 2158261914937136,
+    2158437773783023, 2158586123982626, 2158675527083049, 2158679456889661,
+    2159245349041789, 2159288576914521, 2159299383882704, 2159784714999286,
+    2159830890226977, 2160312291536947, 2160601132322929, 2160832008461384,
+    2160881131044034, 2161056989889921, 2161430321518061, 2161478461649058,
+    2161595373395765, 2161674951979658, 2161745688498674, 2162214317937155,
+    2162378387363206, 2162495299109913, 2162702596408696, 2162710456021920,
+    2162760561056223, 2163185962621972, 2163277330625701, 2163283225335619,
+    2163333330369922, 2163738100450958, 2163752837225753, 2163775433613772,
+    2163776416065425, 2163909047038580, 2164296132989862, 2164301045248127,
+    2164333466152676, 2164448412996077, 2164451360351036, 2164491640868809,
+    2164547640613030, 2164577114162620, 2164768692234955, 2164988761405227,
+    2165038866439530, 2165059497924243, 2165172479864338, 2165214725285417,
+    2165446583875525, 2165453461037096, 2165873950344580, 2165886722216069,
+    2166075352933445, 2166099914224770, 2166105808934688, 2166297387007023,
+    2166367141074386, 2166383842752487, 2166405456688853, 2166413316302077,
+    2166463421336380, 2166711981604589, 2166879980837252, 2166928120968249,
+    2166935980581473, 2167098085104218, 2167613872222043, 2167632538803450,
+    2167652187836510, 2167739626033627, 2167940046170839, 2168042221142751,
+    2168066782434076, 2168289798959307, 2168826217561845, 2169065935765177,
+    2169068883120136, 2169080672539972, 2169157303768906, 2169181865060231,
+    2169276180418919, 2169680950499955, 2169691757468138, 2169943265091306,
+    2169991405222303, 2170275333750020, 2170455122402519, 2170695823057504,
+    2170714489638911, 2170734138671971, 2171358977923279, 2171405153150970,
+    2172082062339887, 2172126272664272, 2172146904148985, 2172215675764695,
+    2172596867006059, 2172611603780854, 2172734410237479, 2173046829863133,
+    2173320933874320, 2173627458790056, 2173817071959085, 2174140298552922,
+    2174748436126129, 2174938049295158, 2175243591759241, 2175698466874580,
+    2175709273842763, 2175896922108486, 2175979448047338, 2175990255015521,
+    2175993202370480, 2176316428964317, 2176338042900683, 2176339025352336,
+    2176730041110230, 2176772286531309, 2177473757011551, 2177516002432630,
+    2177598528371482, 2177869685027710, 2177886386705811, 2178111368134348,
+    2178187016911629, 2178466033181081, 2178787294871612, 2178814803517896,
+    2178818733324508, 2178970030879070, 2179577186000624, 2179638098003110,
+    2179912202014297, 2180087078408531, 2180093955570102, 2180133253636222,
+    2180192200735402, 2180438796100305, 2180441743455264, 2180675566948678,
+    2180724689531328, 2180798373405303, 2181180547098320, 2181236546842541,
+    2181366230460737, 2181400616268592, 2181511633305381, 2181727772669041,
+    2181787702219874, 2181832894995912, 2181937034871130, 2181980262743862,
+    2182258296561661, 2182734785613366, 2182915556717518, 2183277098925822,
+    2183377308994428, 2183390080865917, 2183416607060548, 2183559062550233,
+    2183613097391148, 2183700535588265, 2183708395201489, 2184006078052348,
+    2184131831863932, 2184185866704847, 2184539549299927, 2184585724527618,
+    2184682004789612, 2184793021826401, 2185706701863691, 2185744035026505,
+    2186208734658374, 2186211682013333, 2186305997372021, 2186642978289000,
+    2186977994302673, 2186985853915897, 2187054625531607, 2187239326442371,
+    2187249150958901, 2187277642056838, 2187378834577097, 2187460378064296,
+    2187523254970088, 2187819955369294, 2187869077951944, 2187953568794102,
+    2188119603123459, 2188356373971832, 2188474268170192, 2188551881850779,
+    2188656021725997, 2188711039018565, 2189446895306662, 2189896858163736,
+    2190025559330279, 2190061910041440, 2190111032624090, 2191384289966378,
+    2191432430097375, 2191680007913931, 2191707516560215, 2191751726884600,
+    2191759586497824, 2191852919404859, 2192160426772248, 2192392285362356,
+    2192713547052887, 2192780353765291, 2192920844351670, 2192962107321096,
+    2193617402573647, 2193949471232361, 2194168557950980, 2194537959772508,
+    2194761958749392, 2194831712816755, 2194984975274623, 2195104834376289,
+    2195113676441166, 2195350447289539, 2195519428973855, 2195772901500329,
+    2195813182018102, 2196241530938810, 2196251355455340, 2196376126815271,
+    2196805458187632, 2196830019478957, 2196831984382263, 2197273105174460,
+    2197317315498845, 2197741734612941, 2197743699516247, 2197858646359648,
+    2197974575654702, 2198496257482445, 2198996325373822, 2199086710925898,
+    2199306780096170, 2199414849778000, 2199429586552795, 2199625094431742,
+    2199859900376809, 2199867759990033, 2200204740907012, 2200275477426028,
+    2200482774724811, 2200501441306218, 2200700878991777, 2200768668155834,
+    2200784387382282, 2200920948162049, 2201011333714125, 2201112526234384,
+    2201171473333564, 2201218631012908, 2201349297082757, 2201536945348480,
+    2201708874387755, 2201732453227427, 2201766839035282, 2202095960339037,
+    2202139188211769, 2202243328086987, 2202371046801877, 2202429011449404,
+    2202856377918459, 2202857360370112, 2203005710569715, 2203172727350725,
+    2203454690975136, 2203964583383043, 2204050056676854, 2204060863645037,
+    2204073635516526, 2204108021324381, 2204179740295050, 2204277985460350,
+    2204447949596319, 2204752509608749, 2204798684836440, 2204882193226945,
+    2204973561230674, 2205089490525728, 2205158262141438, 2205274191436492,
+    2205562049770821, 2205675031710916, 2205748715584891, 2205854820363415,
+    2206093556115094, 2206463940388275, 2206474747356458, 2206609343232919,
+    2206613273039531, 2206624080007714, 2206697763881689, 2206703658591607,
+    2207187024804883, 2207333410101180, 2207870811155371, 2207873758510330,
+    2208151792328129, 2208188143039290, 2208195020200861, 2208403299951297,
+    2208821824355475, 2209356278054707, 2209441751348518, 2209795433943598,
+    2209937889433283, 2210007643500646, 2210105888665946, 2210121607892394,
+    2210254238865549, 2210901674504876, 2210952761990832, 2211024480961501,
+    2211238655421855, 2211247497486732, 2211498022658247, 2211501952464859,
+    2211614934404954, 2211643425502891, 2211699425247112, 2211873319189693,
+    2212088476101700, 2212149388104186, 2212500123344307, 2212593456251342,
+    2212743771354251, 2212841034067898, 2213060120786517, 2213241874342322,
+    2213268400536953, 2213638784810134, 2213915836176280, 2214171273606060,
+    2214254781996565, 2214342220193682, 2214516114136263, 2214827551310264,
+    2214866849376384, 2214893375571015, 2215077094030126, 2215287338683868,
+    2215338426169824, 2215458285271490, 2215922002451706, 2216160738203385,
+    2216426982601348, 2216551753961279, 2216640174610049, 2216666700804680,
+    2216693226999311, 2217242417473338, 2217454627030386, 2217495889999812,
+    2218048027828798, 2218075536475082, 2218367324616023, 2219081566967754,
+    2219145426325199, 2219151321035117, 2219152303486770, 2219225004909092,
+    2219343881559105, 2219478477435566, 2219903879001315, 2220009983779839,
+    2220023738102981, 2220423595925752, 2220458964185260, 2220533630510888,
+    2220548367285683, 2221113276986158, 2221213487054764, 2221243943056007,
+    2221353977641143, 2221489555969257, 2221959167859391, 2221968992375921,
+    2222046606056508, 2222162535351562, 2222168430061480, 2222203798320988,
+    2222510323236724, 2222684217179305, 2223033969967773, 2223141057197950,
+    2223269758364493, 2223486880179806, 2223505546761213, 2224051789880281,
+    2224129403560868, 2224413332088585, 2224631436355551, 2224698243067955,
+    2224892768495249, 2225271994833307, 2225402660903156, 2225783852144520,
+    2225837886985435, 2225859500921801, 2226333042618547, 2226447007010295,
+    2226449954365254, 2226929390771918, 2227019776323994, 2227328266143036,
+    2227346932724443, 2227363634402544, 2227511002150494, 2227512967053800,
+    2227598440347611, 2227715352094318, 2227979631588975, 2228087701270805,
+    2228491488900188, 2228496401158453, 2228552400902674, 2228583839355570,
+    2228602505936977, 2228606435743589, 2228646716261362, 2229543694620551,
+    2229584957589977, 2229632115269321, 2229744114757763, 2229850219536287,
+    2229888535150754, 2230128253354086, 2230406287171885, 2230711829635968,
+    2230822846672757, 2230856250028959, 2231215827333957, 2231441791214147,
+    2231479124376961, 2231548878444324, 2231556738057548, 2231805298325757,
+    2231817087745593, 2232144244146042, 2232203191245222, 2232259190989443,
+    2232441926996901, 2232603049067993, 2232662978618826, 2232934135275054,
+    2233097222249452, 2233140450122184, 2233451887296185, 2233501009878835,
+    2234190690939241, 2234511952629772, 2234777214576082, 2234885284257912,
+    2235803876553467, 2235852999136117, 2235855946491076, 2235863806104300,
+    2235894262105543, 2236112366372509, 2236133980308875, 2236320646122945,
+    2236514189098586, 2236835450789117, 2237251027838336, 2237447518168936,
+    2237819867345423, 2238016357676023, 2238032076902471, 2238948704294720,
+    2239150106883585, 2239233615274090, 2239316141212942, 2239455649347668,
+    2239495929865441, 2239664911549757, 2239733683165467, 2239771016328281,
+    2239786735554729, 2240225891443620, 2240327083963879, 2240355575061816,
+    2240594310813495, 2241169045030500, 2241415640395403, 2241675007631795,
+    2241810585959909, 2241881322478925, 2242020830613651, 2242279215398390,
+    2242386302628567, 2242467846115766, 2242585740314126, 2242724265997199,
+    2242927633489370, 2242976756072020, 2243037668074506, 2243432613639012,
+    2243530858804312, 2243908120239064, 2244074154568421, 2244157662958926,
+    2244240188897778, 2244396398710605, 2244408188130441, 2244965238217692,
+    2245001588928853, 2245057588673074, 2245143061966885, 2245723690893808,
+    2245881865609941, 2246206074655431, 2246212951817002, 2246546002927369,
+    2246599055316631, 2246619686801344, 2246754282677805, 2246863334811288,
+    2247132526564210, 2247152175597270, 2247237648891081, 2247310350313403,
+    2247682699489890, 2247731822072540, 2247759330718824, 2247843821560982,
+    2247885084530408, 2247924382596528, 2248403819003192, 2248551186751142,
+    2248938272702424, 2249542480469019, 2249573918921915, 2249647602795890,
+    2249649567699196, 2249903040225670, 2249973776744686, 2250054337780232,
+    2250107390169494, 2250331389146378, 2250399178310435, 2250492511217470,
+    2250659527998480, 2250772509938575, 2251200858859283, 2251556506357669,
+    2251625277973379, 2251879732951506, 2252112573993267, 2252120433606491,
+    2252155801865999, 2252177415802365, 2252255029482952, 2252261906644523,
+    2252283520580889, 2252469203943306, 2252482958266448, 2252655869757376,
+    2252665694273906, 2252690255565231, 2252728571179698, 2252750185116064,
+    2252839588216487, 2253183446295037, 2253235516232646, 2253253200362400,
+    2253298393138438, 2253322954429763, 2253355375334312, 2253425129401675,
+    2253431024111593, 2253466392371101, 2253530251728546, 2253581339214502,
+    2253725759607493, 2253731654317411, 2253742461285594, 2253769969931878,
+    2253834811740976, 2253851513419077, 2254012635490169, 2254096143880674,
+    2254281827243091, 2254360423375331, 2254401686344757, 2254403651248063,
+    2254435089700959, 2254669895646026, 2254864421073320, 2254871298234891,
+    2254877192944809, 2254887017461339, 2254913543655970, 2254936140043989,
+    2255045192177472, 2255074665727062, 2255138525084507, 2255426383418836,
+    2255519716325871, 2255606172071335, 2255634663169272, 2255655294653985,
+    2255736838141184, 2255752557367632, 2255774171303998, 2255780066013916,
+    2255947082794926, 2256003082539147, 2256041398153614, 2256042380605267,
+    2256088555832958, 2256125888995772, 2256158309900321, 2256284063711905,
+    2256423571846631, 2256433396363161, 2256488413655729, 2256559150174745,
+    2256668202308228, 2256744833537162, 2256752693150386, 2256771359731793,
+    2256974727223964, 2257023849806614, 2257076902195876, 2257090656519018,
+    2257120130068608, 2257197743749195, 2257202656007460, 2257291076656230,
+    2257368690336817, 2257389321821530, 2257496409051707, 2257654583767840,
+    2257687987124042, 2257751846481487, 2257754793836446, 2257913951004232,
+    2257921810617456, 2257925740424068, 2257955213973658, 2257981740168289,
+    2258167423530706, 2258193949725337, 2258218511016662, 2258310861472044,
+    2258806017105156, 2259306084996533, 2259802223081298, 2259809100242869,
+    2260004608121816, 2260343553942101, 2260347483748713, 2260428044784259,
+    2260480114721868, 2260533167111130, 2260680534859080, 2260880954996292,
+    2261000814097958, 2261016533324406, 2261019480679365, 2261039129712425,
+    2261131480167807, 2261278847915757, 2261379057984363, 2261405584178994,
+    2261515618764130, 2261638425220755, 2261714073998036, 2261763196580686,
+    2261804459550112, 2262057932076586, 2262058914528239, 2262073651303034,
+    2262166984210069, 2262222001502637, 2262284878408429, 2262506912482007,
+    2262551122806392, 2262625789132020, 2262798700622948, 2262816384752702,
+    2262930349144450, 2263177926961006, 2263185786574230, 2263245716125063,
+    2263275189674653, 2263426487229215, 2263518837684597, 2263659328270976,
+    2263661293174282, 2263751678726358, 2263789994340825, 2263849923891658,
+    2263868590473065, 2263884309699513, 2263917713055715, 2263922625313980,
+    2263958976025141, 2263980589961507, 2263997291639608, 2264205571390044,
+    2264250764166082, 2264261571134265, 2264370623267748, 2264395184559073,
+    2264398131914032, 2264493429724373, 2264779323155396, 2264799954640109,
+    2264839252706229, 2264989567809138, 2265043602650053, 2265057356973195,
+    2265296092724874, 2265349145114136, 2265394337890174, 2265455249892660,
+    2265512232088534, 2265540723186471, 2265541705638124, 2265569214284408,
+    2265648792868301, 2265659599836484, 2265858055070390, 2266322754702259,
+    2266670542587421, 2266740296654784, 2266751103622967, 2266755033429579,
+    2267007523504400, 2267154891252350, 2267320925581707, 2267491872169329,
+    2267496784427594, 2267522328170572, 2267545907010244, 2267566538494957,
+    2267577345463140, 2267582257721405, 2267651029337115, 2267661836305298,
+    2267918256186731, 2268105904452454, 2268124571033861, 2268274886136770,
+    2268422253884720, 2268607937247137, 2268668849249623, 2268685550927724,
+    2268689480734336, 2268734673510374, 2268765129511617, 2268967514552135,
+    2269142390946369, 2269163022431082, 2269187583722407, 2269252425531505,
+    2269304495469114, 2269511792767897, 2269852703491488, 2269885124396037,
+    2269888071750996, 2269945053946870, 2269948001301829, 2269959790721665,
+    2270218175506404, 2270286947122114, 2270309543510133, 2270404841320474,
+    2270649471782071, 2270787997465144, 2271062101476331, 2271082732961044,
+    2271397117490004, 2271581818400768, 2271612274402011, 2271664344339620,
+    2271668274146232, 2271679081114415, 2271702659954087, 2271878518799974,
+    2271925676479318, 2271963009642132, 2272065184614044, 2272094658163634,
+    2272139850939672, 2272223359330177, 2272246938169849, 2272460130178550,
+    2272582936635175, 2272629111862866, 2272808900515365, 2272992618974476,
+    2273211705693095, 2273302091245171, 2273642019517109, 2273648896678680,
+    2273708826229513, 2273767773328693, 2274123420827079, 2274249174638663,
+    2274368051288676, 2274394577483307, 2274564541619276, 2274601874782090,
+    2274617594008538, 2274650997364740, 2274684400720942, 2274717804077144,
+    2274749242530040, 2274780680982936, 2274793452854425, 2274944750408987,
+    2274979136216842, 2275184468612319, 2275227696485051, 2275252257776376,
+    2275262082292906, 2275276819067701, 2275407485137550, 2275436958687140,
+    2275444818300364, 2275682571600390, 2275730711731387, 2275748395861141,
+    2275832886703299, 2275868254962807, 2275895763609091, 2275945868643394,
+    2275969447483066, 2276001868387615, 2276069657551672, 2276342779111206,
+    2276356533434348, 2276453796147995, 2276622777832311, 2276821233066217,
+    2276922425586476, 2276933232554659, 2277070775786079, 2277108108948893,
+    2277185722629480, 2277401861993140, 2277403826896446, 2277446072317525,
+    2277917649110965, 2277925508724189, 2277926491175842, 2278033578406019,
+    2278167191830827, 2278242840608108, 2278266419447780, 2278296875449023,
+    2278420664357301, 2278480593908134, 2278499260489541, 2278510067457724,
+    2278589646041617, 2278616172236248, 2278639751075920, 2279012100252407,
+    2279104450707789, 2279109362966054, 2279145713677215, 2279173222323499,
+    2279184029291682, 2279200730969783, 2279271467488799, 2279407045816913,
+    2279423747495014, 2279452238592951, 2279624167632226, 2279628097438838,
+    2279658553440081, 2279852096415722, 2279966060807470, 2280187112429395,
+    2280222480688903, 2280247041980228, 2280435672697604, 2280471040957112,
+    2280483812828601, 2280678338255895, 2280846337488558, 2280858126908394,
+    2280860091811700, 2280966196590224, 2281054617238994, 2281249142666288,
+    2281512439709292, 2281783596365520, 2281892648499003, 2282096015991174,
+    2282300365934998, 2282461488006090, 2282492926458986, 2282515522847005,
+    2282536154331718, 2282656013433384, 2282701206209422, 2282704153564381,
+    2282716925435870, 2282943871767713, 2282954678735896, 2282959590994161,
+    2282960573445814, 2282992994350363, 2283008713576811, 2283131520033436,
+    2283207168810717, 2283392852173134, 2283418395916112, 2283632570376466,
+    2284031445747584, 2284085480588499, 2284087445491805, 2284160146914127,
+    2284180778398840, 2284262321886039, 2284331093501749, 2284518741767472,
+    2284543303058797, 2284912704880325, 2284916634686937, 2285131791598944,
+    2285177966826635, 2285300773283260, 2285411790320049, 2285418667481620,
+    2285619087618832, 2285676069814706, 2285685894331236, 2285690806589501,
+    2285719297687438, 2285858805822164, 2286011085828379, 2286235084805263,
+    2286295014356096, 2286387364811478, 2286404066489579, 2286479715266860,
+    2286488557331737, 2286563223657365, 2286731222890028, 2286739082503252,
+    2286743012309864, 2286806871667309, 2286854029346653, 2286872695928060,
+    2286928695672281, 2286965046383442, 2287326588591746, 2287327571043399,
+    2287441535435147, 2287488693114491, 2287585955828138, 2287618376732687,
+    2287883638678997, 2287905252615363, 2287930796358341, 2287981883844297,
+    2288019217007111, 2288023146813723, 2288060479976537, 2288079146557944,
+    2288137111205471, 2288219637144323, 2288321812116235, 2288395495990210,
+    2288476057025756, 2288589038965851, 2288747213681984, 2288750161036943,
+    2288929949689442, 2288949598722502, 2288951563625808, 2289130369826654,
+    2289267913058074, 2289479140163469, 2289498789196529, 2289505666358100,
+    2289770928304410, 2289858366501527, 2290027348185843, 2290044049863944,
+    2290051909477168, 2290177663288752, 2290210084193301, 2290322083681743,
+    2290382013232576, 2290419346395390, 2290624678790867, 2290714081891290,
+    2290932186158256, 2291273096881847, 2291319272109538, 2291321237012844,
+    2291370359595494, 2291377236757065, 2291623832121968, 2291874357293483,
+    2292028602203004, 2292055128397635, 2292060040655900, 2292257513438153,
+    2292275197567907, 2292348881441882, 2292460880930324, 2292802774105568,
+    2293276315802314, 2293304806900251, 2293509156844075, 2293663401753596,
+    2293688945496574, 2293690910399880, 2293901155053622, 2294081926157774,
+    2294188030936298, 2294418907074753, 2294475889270627, 2294518134691706,
+    2294528941659889, 2294582976500804, 2294981851871922, 2295007395614900,
+    2295008378066553, 2295112517941771, 2295123324909954, 2295572305315375,
+    2295610620929842, 2295637147124473, 2295710830998448, 2295749146612915,
+    2295859181198051, 2295880795134417, 2296131320305932, 2296143109725768,
+    2296144092177421, 2296168653468746, 2296404441865466, 2296681493231612,
+    2296933000854780, 2296944790274616, 2297170754154806, 2297522471846580,
+    2297820154697439, 2297866329925130, 2297868294828436, 2297877136893313,
+    2297881066699925, 2297891873668108, 2297904645539597, 2297912505152821,
+    2297919382314392, 2297978329413572, 2298247521166494, 2298336924266917,
+    2298348713686753, 2298365415364854, 2298440081690482, 2298451871110318,
+    2298478397304949, 2298571730211984, 2298626747504552, 2298664080667366,
+    2298843869319865, 2298849764029783, 2298868430611190, 2298877272676067,
+    2298950956550042, 2299062956038484, 2299326253081488, 2299395024697198,
+    2299402884310422, 2299428428053400, 2299623935932347, 2299990390398916,
+    2300029688465036, 2300135793243560, 2300153477373314, 2300188845632822,
+    2300233055957207, 2300496353000211, 2300779299076275, 2300823509400660,
+    2300856912756862, 2301110385283336, 2301189963867229, 2301236139094920,
+    2301241051353185, 2301574102463552, 2301678242338770, 2301795154085477,
+    2301857048539616, 2301930732413591, 2302113468421049, 2302165538358658,
+    2302342379656198, 2302448484434722, 2302494659662413, 2302663641346729,
+    2303039920329828, 2303040902781481, 2303142095301740, 2303215779175715,
+    2303237393112081, 2303323848857545, 2303328761115810, 2303462374540618,
+    2303546865382776, 2303575356480713, 2303790513392720, 2303793460747679,
+    2303883846299755, 2303922161914222, 2303978161658443, 2303985038820014,
+    2304007635208033, 2304032196499358, 2304112757534904, 2304232616636570,
+    2304359352899807, 2304380966836173, 2304415352644028, 2304491983872962,
+    2304674719880420, 2304693386461827, 2304697316268439, 2304854508532919,
+    2304866297952755, 2304876122469285, 2304903631115569, 2304908543373834,
+    2304963560666402, 2304965525569708, 2304971420279626, 2305192471901551,
+    2305315278358176, 2305407628813558, 2305417453330088, 2305421383136700,
+    2305456751396208, 2305660118888379, 2305704329212764, 2305709241471029,
+    2305725943149130, 2305750504440455, 2305783907796657, 2305968608707421,
+    2306017731290071, 2306146432456614, 2306168046392980, 2306194572587611,
+    2306293800204564, 2306355694658703, 2306557097247568, 2306588535700464,
+    2306615061895095, 2306839060871979, 2307083691333576, 2307094498301759,
+    2307171129530693, 2307220252113343, 2307302778052195, 2307441303735268,
+    2307514987609243, 2307679057035294, 2307935476916727, 2307997371370866,
+    2308161440796917, 2308191896798160, 2308284247253542, 2308315685706438,
+    2308357931127517, 2308371685450659, 2308381509967189, 2308406071258514,
+    2308737157465575, 2308806911532938, 2308883542761872, 2308938560054440,
+    2309019121089986, 2309074138382554, 2309096734770573, 2309427820977634,
+    2309428803429287, 2309591890403685, 2309594837758644, 2309714696860310,
+    2309803117509080, 2309837503316935, 2309900380222727, 2309901362674380,
+    2309985853516538, 2310041853260759, 2310093923198368, 2310155817652507,
+    2310168589523996, 2310435816373612, 2310512447602546, 2310540938700483,
+    2310567464895114, 2310594973541398, 2310757078064143, 2310914270328623,
+    2311017427752188, 2311107813304264, 2311242409180725, 2311485074739016,
+    2311928160434519, 2311944862112620, 2311954686629150, 2312010686373371,
+    2312030335406431, 2312092229860570, 2312107949087018, 2312218966123807,
+    2312371246130022, 2312570683815581, 2312775033759405, 2312785840727588,
+    2313168996872258, 2313366469654511, 2313711310184714, 2313720152249591,
+    2313750608250834, 2313810537801667, 2314048291101693, 2314221202592621,
+    2314251658593864, 2314273272530230, 2314435377052975, 2314725200290610,
+    2314861761070377, 2314865690876989, 2314900076684844, 2314927585331128,
+    2314942322105923, 2314958041332371, 2315080847788996, 2315274390764637,
+    2315338250122082, 2315378530639855, 2315383442898120, 2315484635418379,
+    2315552424582436, 2315688985362203, 2315841265368418, 2316100632604810,
+    2316463157264767, 2316466104619726, 2316518174557335, 2316602665399493,
+    2316637051207348, 2316743155985872, 2316745120889178, 2316785401406951,
+    2317025119610283, 2317045751094996, 2317054593159873, 2317082101806157,
+    2317529117308272, 2317564485567780, 2317641116796714, 2317808133577724,
+    2318263991144716, 2318374025729852, 2318581323028635, 2318614726384837,
+    2318769953746011, 2318849532329904, 2319296547832019, 2319302442541937,
+    2319366301899382, 2319397740352278, 2319737668624216, 2319746510689093,
+    2319961667601100, 2319975421924242, 2319991141150690, 2320019632248627,
+    2320024544506892, 2320069737282930, 2320336964132546, 2320419490071398,
+    2320421454974704, 2320428332136275, 2320498086203638, 2320594366465632,
+    2320632682080099, 2320805593571027, 2320843909185494, 2320852751250371,
+    2321038434612788, 2321077732678908, 2321204468942145, 2321594502248386,
+    2321613168829793, 2321710431543440, 2321952114650078, 2321959974263302,
+    2322084745623233, 2322092605236457, 2322105377107946, 2322130920850924,
+    2322503270027411, 2322549445255102, 2322793093265046, 2322798005523311,
+    2322879549010510, 2323010215080359, 2323023969403501, 2323207687862612,
+    2323366845030398, 2323592808910588, 2323641931493238, 2323874772534999,
+    2323941579247403, 2324603751661525, 2324739329989639, 2324743259796251,
+    2324873925866100, 2324936802771892, 2324939750126851, 2324947609740075,
+    2325021293614050, 2325214836589691, 2325220731299609, 2325291467818625,
+    2325429993501698, 2325492870407490, 2325708027319497, 2325769921773636,
+    2325801360226532, 2325809219839756, 2325818061904633, 2325890763326955,
+    2325932026296381, 2325953640232747, 2325971324362501, 2325986061137296,
+    2326912513046075, 2327017635372946, 2327086406988656, 2327195459122139,
+    2327548159265566, 2327616930881276, 2327644439527560, 2327659176302355,
+    2327751526757737, 2327800649340387, 2328025630768924, 2328042332447025,
+    2328345910007802, 2328413699171859, 2328524716208648, 2328587593114440,
+    2328590540469399, 2328610189502459, 2328639663052049, 2328737908217349,
+    2328751662540491, 2328760504605368, 2328819451704548, 2328824363962813,
+    2328898047836788, 2329075871585981, 2329143660750038, 2329157415073180,
+    2329225204237237, 2329263519851704, 2329301835466171, 2329338186177332,
+    2329363729920310, 2329400080631471, 2329464922440569, 2329557272895951,
+    2329622114705049, 2329725272128614, 2330284287119171, 2330313760668761,
+    2330399233962572, 2330431654867121, 2330472917836547, 2330807933850220,
+    2330821688173362, 2330889477337419, 2330906179015520, 2331036845085369,
+    2331263791417212, 2331282457998619, 2331348282259370, 2331407229358550,
+    2331577193494519, 2331644982658576, 2331717684080898, 2331886665765214,
+    2331892560475132, 2332014384480104, 2332115577000363, 2332473189402055,
+    2332498733145033, 2332589118697109, 2332606802826863, 2332611715085128,
+    2332634311473147, 2332642171086371, 2332695223475633, 2332956555615331,
+    2333026309682694, 2333234589433130, 2333266027886026, 2333459570861667,
+    2333479219894727, 2333490026862910, 2333522447767459, 2333598096544740,
+    2333653113837308, 2333801464036911, 2334066725983221, 2334071638241486,
+    2334251426893985, 2334561881616333, 2334576618391128, 2334595284972535,
+    2334697459944447, 2335189668222600, 2335285948484594, 2335693665920589,
+    2335695630823895, 2335959910318552, 2336004120642937, 2336155418197499,
+    2336213382845026, 2336240891491310, 2336306715752061, 2336309663107020,
+    2336378434722730, 2336384329432648, 2336388259239260, 2336592609183084,
+    2336652538733917, 2336709520929791, 2336770432932277, 2336849029064517,
+    2336892256937249, 2336906011260391, 2336983624940978, 2337060256169912,
+    2337321588309610, 2337461096444336, 2337486640187314, 2338052532339442,
+    2338068251565890, 2338198917635739, 2338220531572105, 2338494635583292,
+    2338513302164699, 2338935756375489, 2339003545539546, 2339066422445338,
+    2339184316643698, 2339298281035446, 2339327754585036, 2339575332401592,
+    2339680454728463, 2339719752794583, 2339731542214419, 2339742349182602,
+    2339804243636741, 2339821927766495, 2340099961584294, 2340120593069007,
+    2340666836188075, 2340682555414523, 2340859396712063, 2341182623305900,
+    2341221921372020, 2341261219438140, 2341294622794342, 2341304447310872,
+    2341638480872892, 2341934198820445, 2341975461789871, 2342226969413039,
+    2342254478059323, 2342299670835361, 2342307530448585, 2342345846063052,
+    2342378266967601, 2342420512388680, 2342427389550251, 2342834124534593,
+    2342867527890795, 2342923527635016, 2343368578233825, 2343540507273100,
+    2343735032700394, 2343921698514464, 2343962961483890, 2344121136200023,
+    2344203662138875, 2344207591945487, 2344432573374024, 2344519029119488,
+    2344703730030252, 2345008290042682, 2345117342176165, 2345299095731970,
+    2345369832250986, 2345572217291504, 2345604638196053, 2345667515101845,
+    2345803093429959, 2345870882594016, 2346147933960162, 2346384704808535,
+    2346476072812264, 2346487862232100, 2346592984558971, 2346653896561457,
+    2346695159530883, 2347033122899515, 2347348489880128, 2347360279299964,
+    2347403507172696, 2347420208850797, 2347470313885100, 2347561681888829,
+    2347760137122735, 2347825961383486, 2347930101258704, 2347995925519455,
+    2348284766305437, 2348317187209986, 2348393818438920, 2348469467216201,
+    2348949886074518, 2349017675238575, 2349067780272878, 2349074657434449,
+    2349094306467509, 2349130657178670, 2349145393953465, 2349163078083219,
+    2349392971770021, 2349570795519214, 2349935285082477, 2349936267534130,
+    2349978512955209, 2349996197084963, 2350309599162270, 2350521808719318,
+    2350547352462296, 2350567001495356, 2350730088469754, 2350879421121010,
+    2351201665263194, 2351276331588822, 2351320541913207, 2351400120497100,
+    2351406015207018, 2351438436111567, 2351564189923151, 2351744961027303,
+    2351810785288054, 2351821592256237, 2351836329031032, 2351902153291783,
+    2351948328519474, 2352156608269910, 2352186081819500, 2352219485175702,
+    2352402221183160, 2352925867914209, 2352985797465042, 2352994639529919,
+    2352999551788184, 2353157726504317, 2353299199542349, 2353309024058879,
+    2353356181738223, 2353498637227908, 2353876881114313, 2353919126535392,
+    2353953512343247, 2354004599829203, 2354459474944542, 2354645158306959,
+    2354694280889609, 2354905507995004, 2355000805805345, 2355023402193364,
+    2355101015873951, 2355309295624387, 2355771047901297, 2356020590621159,
+    2356094274495134, 2356175817982333, 2356280940309204, 2356549149610473,
+    2356747604844379, 2356819323815048, 2356932305755143, 2357443180614703,
+    2357526689005208, 2357715319722584, 2357742828368868, 2357879389148635,
+    2357947178312692, 2358194756129248, 2358203598194125, 2358261562841652,
+    2358399106073072, 2358400088524725, 2358402053428031, 2358566122854082,
+    2358776367507824, 2358789139379313, 2358791104282619, 2358834332155351,
+    2358958121063629, 2358982682354954, 2359035734744216, 2359042611905787,
+    2359083874875213, 2359362891144665, 2359474890633107, 2359960221749689,
+    2360098747432762, 2360428851188170, 2360447517769577, 2360599797775792,
+    2360786463589862, 2360942673402689, 2360984918823768, 2361148988249819,
+    2361176496896103, 2361247233415119, 2361481056908533, 2361672634980868,
+    2361774809952780, 2361879932279651, 2362070527900333, 2362080352416863,
+    2362189404550346, 2362212000938365, 2362266035779280, 2362647227020644,
+    2362682595280152, 2362787717607023, 2363505889765366, 2363727923838944,
+    2363752485130269, 2363845818037304, 2364164132372876, 2364173956889406,
+    2364268272248094, 2364430376770839, 2364455920513817, 2364556130582423,
+    2364606235616726, 2364632761811357, 2364739849041534, 2364795848785755,
+    2364835146851875, 2364877392272954, 2364908830725850, 2365203566221750,
+    2365412828423839, 2365459003651530, 2365546441848647, 2365548406751953,
+    2365674160563537, 2365717388436269, 2365721318242881, 2366094649871021,
+    2366163421486731, 2366178158261526, 2366271491168561, 2366527911049994,
+    2366611419440499, 2366961172228967, 2367690151355493, 2367732396776572,
+    2367879764524522, 2368131272147690, 2368199061311747, 2368390639384082,
+    2368442709321691, 2368475130226240, 2368701094106430, 2368793444561812,
+    2368880882758929, 2368946707019680, 2369011548828778, 2369240460063927,
+    2369415336458161, 2369523406139991, 2369793580344566, 2369899685123090,
+    2370304455204126, 2370450840500423, 2370472454436789, 2370609015216556,
+    2370663050057471, 2370841856258317, 2371033434330652, 2371065855235201,
+    2371147398722400, 2371471607767890, 2372316516189470, 2372333217867571,
+    2372537567811395, 2372765496594891, 2372809706919276, 2373531808884231,
+    2373672299470610, 2373746965796238, 2373813772508642, 2373938543868573,
+    2374116367617766, 2374193981298353, 2374327594723161, 2374348226207874,
+    2374636084542203, 2374924925328185, 2375357204055505, 2375431870381133,
+    2375512431416679, 2375561553999329, 2375596922258837, 2375607729227020,
+    2375975166145242, 2376356357386606, 2376692355851932, 2376907512763939,
+    2376935021410223, 2376991021154444, 2377133476644129, 2377375159750767,
+    2377469475109455, 2377502878465657, 2377792701703292, 2377830034866106,
+    2378378242888480, 2378840977617043, 2379127853499719, 2379250659956344,
+    2379468764223310, 2379694728103500, 2379971779469646, 2380003217922542,
+    2380234094060997, 2380364760130846, 2380404058196966, 2380422724778373,
+    2380520969943673, 2380570092526323, 2380848126344122, 2381255843780117,
+    2381333457460704, 2381381597591701, 2381460193723941, 2381930788065728,
+    2381933735420687, 2381954366905400, 2381977945745072, 2381981875551684,
+    2382078155813678, 2382155769494265, 2382331628340152, 2382704959968292,
+    2382732468614576, 2382968257011296, 2383102852887757, 2383179484116691,
+    2383299343218357, 2383303273024969, 2383315062444805, 2383721797429147,
+    2383763060398573, 2383877024790321, 2384058778346126, 2384187479512669,
+    2384195339125893, 2384428180167654, 2384520530623036, 2384771055794551,
+    2384958704060274, 2385156176842527, 2385173860972281, 2385476456081405,
+    2385693577896718, 2386062979718246, 2386246698177357, 2386448100766222,
+    2386456942831099, 2386463819992670, 2386503118058790, 2386509012768708,
+    2386548310834828, 2386845993685687, 2386945221302640, 2386977642207189,
+    2387125992406792, 2387140729181587, 2387525850229563, 2387614270878333,
+    2387662411009330, 2388247952194518, 2388481775687932, 2388634055694147,
+    2388705774664816, 2388759809505731, 2388898335188804, 2389029001258653,
+    2389283456236780, 2389539876118213, 2390093978850505, 2390099873560423,
+    2390128364658360, 2390150961046379, 2390243311501761, 2390286539374493,
+    2390665765712551, 2390801344040665, 2391181552830376, 2391370183547752,
+    2391412428968831, 2391486112842806, 2391789690403583, 2391817199049867,
+    2392165969386682, 2392352635200752, 2392390950815219, 2392502950303661,
+    2392594318307390, 2392657195213182, 2392667019729712, 2392907720384697,
+    2392972562193795, 2393019719873139, 2393071789810748, 2393398946211197,
+    2393595436541797, 2393627857446346, 2393632769704611, 2393709400933545,
+    2393745751644706, 2393942241975306, 2393965820814978, 2393994311912915,
+    2394261538762531, 2394543502386942, 2394709536716299, 2394736062910930,
+    2394835290527883, 2394969886404344, 2395087780602704, 2395193885381228,
+    2395313744482894, 2395407077389929, 2395417884358112, 2395605532623835,
+    2395710654950706, 2395809882567659, 2395883566441634, 2395941531089161,
+    2396210722842083, 2396220547358613, 2396381669429705, 2396454370852027,
+    2396520195112778, 2396795281575618, 2396877807514470, 2397046789198786,
+    2397477103022800, 2397523278250491, 2397535067670327, 2397571418381488,
+    2397575348188100, 2397586155156283, 2397588120059589, 2397613663802567,
+    2397690295031501, 2397695207289766, 2397790505100107, 2397831768069533,
+    2397880890652183, 2397893662523672, 2398143205243534, 2398441870546046,
+    2398490993128696, 2398512607065062, 2398789658431208, 2399044113409335,
+    2399156112897777, 2399395831101109, 2399512742847816, 2399782917052391,
+    2400051126353660, 2400461791144614, 2400645509603725, 2400654351668602,
+    2400660246378520, 2400704456702905, 2400730000445883, 2400753579285555,
+    2401740943196820, 2401837223458814, 2401879468879893, 2402149643084468,
+    2402286203864235, 2402362835093169, 2402391326191106, 2402518062454343,
+    2402706693171719, 2402727324656432, 2402771534980817, 2402921850083726,
+    2403034832023821, 2403057428411840, 2403197918998219, 2403234269709380,
+    2403324655261456, 2403391461973860, 2403444514363122, 2403483812429242,
+    2403799179409855, 2404029073096657, 2404180370651219, 2404370966271901,
+    2404611666926886, 2404615596733498, 2404667666671107, 2404704999833921,
+    2404829771193852, 2404833701000464, 2404992858168250, 2405084226171979,
+    2405095033140162, 2405619662322864, 2405636364000965, 2405718889939817,
+    2405867240139420, 2405984151886127, 2406043098985307, 2406089274212998,
+    2406305413576658, 2406345694094431, 2406409553451876, 2406607026234129,
+    2406757341337038, 2406801551661423, 2406825130501095, 2406828077856054,
+    2406868358373827, 2407264286389986, 2407285900326352, 2407352707038756,
+    2407407724331324, 2407492215173482, 2407696565117306, 2407728003570202,
+    2407833125897073, 2407858669640051, 2408393123339283, 2408669192253776,
+    2408867647487682, 2409107365691014, 2409118172659197, 2409358873314182,
+    2409399153831955, 2409475785060889, 2409500346352214, 2409584837194372,
+    2409702731392732, 2409810801074562, 2409957186370859, 2410073115665913,
+    2410126168055175, 2410207711542374, 2410209676445680, 2410312833869245,
+    2410470026133725, 2410683218142426, 2410795217630868, 2410925883700717,
+    2411062444480484, 2411074233900320, 2411210794680087, 2411259917262737,
+    2411319846813570, 2411485881142927, 2411681389021874, 2411690231086751,
+    2412086159102910, 2412087141554563, 2412096966071093, 2412148053557049,
+    2412183421816557, 2412345526339302, 2412353385952526, 2412649103900079,
+    2412671700288098, 2412673665191404, 2412715910612483, 2412751278871991,
+    2413159978759639, 2413184540050964, 2413232680181961, 2413241522246838,
+    2413252329215021, 2413771063687805, 2413800537237395, 2413835905496903,
+    2413911554274184, 2413960676856834, 2413974431179976, 2414098220088254,
+    2414298640225466, 2414361517131258, 2414550147848634, 2414593375721366,
+    2414648393013934, 2414870427087512, 2415199548391267, 2415494283887167,
+    2415508038210309, 2415615125440486, 2415617090343792, 2415810633319433,
+    2415869580418613, 2415950141454159, 2415955053712424, 2415976667648790,
+    2416102421460374, 2416115193331863, 2416237999788488, 2416298911790974,
+    2416714488840193, 2416786207810862, 2416862839039796, 2417388450674151,
+    2417660589782032, 2418399393425088, 2418420024909801, 2418850338733815,
+    2418968232932175, 2419000653836724, 2419394616949577, 2419675598122335,
+    2420492015445978, 2420493980349284, 2420900715333626, 2421072644372901,
+    2421114889793980, 2421399800773350, 2421406677934921, 2421492151228732,
+    2421503940648568, 2421536361553117, 2421806535757692, 2421921482601093,
+    2421988289313497, 2422029552282923, 2422206393580463, 2422261410873031,
+    2422604286499928, 2422685829987127, 2422796847023916, 2422805689088793,
+    2422823373218547, 2422847934509872, 2423052284453696, 2423308704335129,
+    2423311651690088, 2423361756724391, 2423823509001301, 2423894245520317,
+    2423956139974456, 2424237121147214, 2424450313155915, 2424881609431582,
+    2424947433692333, 2424962170467128, 2425094801440283, 2425099713698548,
+    2425601746493231, 2425619430622985, 2425714728433326, 2425918095925497,
+    2426077253093283, 2426973249000819, 2427015494421898, 2427105879973974,
+    2427183493654561, 2427190370816132, 2427212967204151, 2427268966948372,
+    2427278791464902, 2427747420903383, 2427853525681907, 2427968472525308,
+    2428036261689365, 2428104050853422, 2428150226081113, 2428256330859637,
+    2428363418089814, 2428466575513379, 2428484259643133, 2428575627646862,
+    2428662083392326, 2428722012943159, 2429103204184523, 2429263343803962,
+    2429492255039111, 2429634710528796, 2429667131433345, 2429677938401528,
+    2429727060984178, 2429851832344109, 2429882288345352, 2430042427964791,
+    2430151480098274, 2430180953647864, 2430214357004066, 2430298847846224,
+    2430515969661537, 2430793021027683, 2430838213803721, 2430954143098775,
+    2430996388519854, 2431122142331438, 2431675262612077, 2431703753710014,
+    2431844244296393, 2431980805076160, 2432068243273277, 2432081997596419,
+    2432417996061745, 2432512311420433, 2432597784714244, 2432632170522099,
+    2432772661108478, 2432797222399803, 2432998624988668, 2433072308862643,
+    2433096870153968, 2433114554283722, 2434092093678457, 2434182479230533,
+    2434331811881789, 2434590196666528, 2434653073572320, 2434820090353330,
+    2435002826360788, 2435312298631483, 2435356508955868, 2435751454520374,
+    2435758331681945, 2435789770134841, 2436056996984457, 2436430328612597,
+    2436476503840288, 2436599310296913, 2436919589535791, 2437065974832088,
+    2437125904382921, 2437168149804000, 2437191728643672, 2437454043235023,
+    2437529692012304, 2437932497190034, 2437943304158217, 2437947233964829,
+    2438050391388394, 2438132917327246, 2438176145199978, 2438251793977259,
+    2438273407913625, 2438519020826875, 2438569125861178, 2438633967670276,
+    2438745967158718, 2438813756322775, 2439024000976517, 2439163509111243,
+    2439202807177363, 2439234245630259, 2439363929248455, 2439386525636474,
+    2439747085393125, 2439911154819176, 2440237328767972, 2440330661675007,
+    2440472134713039, 2440759993047368, 2440875922342422, 2440968272797804,
+    2441044904026738, 2441213885711054, 2441238447002379, 2441321955392884,
+    2441354376297433, 2441554796434645, 2441764058636734, 2441916338642949,
+    2441991004968577, 2442030303034697, 2442487143053342, 2442622721381456,
+    2442665949254188, 2442702299965349, 2442881106166195, 2443143420757546,
+    2443222999341439, 2443232823857969, 2443388051219143, 2443440121156752,
+    2443630716777434, 2443755488137365, 2444242784157253, 2444558151137866,
+    2444586642235803, 2444760536178384, 2444877447925091, 2445091622385445,
+    2445388322784651, 2445627058536330, 2445687970538816, 2445698777506999,
+    2445930636097107, 2446205722559947, 2446261722304168, 2446453300376503,
+    2446728386839343, 2446792246196788, 2446867894974069, 2447059473046404,
+    2447544804162986, 2447780592559706, 2447850346627069, 2447904381467984,
+    2448007538891549, 2448068450894035, 2448147047026275, 2448410344069279,
+    2448417221230850, 2448469291168459, 2448705079565179, 2448722763694933,
+    2448944797768511, 2449084305903237, 2449283743588796, 2449935109034735,
+    2450050055878136, 2450068722459543, 2450114897687234, 2450221002465758,
+    2450302545952957, 2450311388017834, 2450340861567424, 2450385071891809,
+    2450568790350920, 2450597281448857, 2450750543906725, 2450793771779457,
+    2451110121211723, 2451167103407597, 2451244717088184, 2451478540581598,
+    2451694679945258, 2451821416208495, 2451849907306432, 2452308712228383,
+    2452506185010636, 2452583798691223, 2452954182964404, 2452983656513994,
+    2452986603868953, 2453090743744171, 2453108427873925, 2453112357680537,
+    2453330461947503, 2453379584530153, 2453446391242557, 2453556425827693,
+    2453651723638034, 2453678249832665, 2453726389963662, 2453784354611189,
+    2453872775259959, 2454459298896800, 2454472070768289, 2454731438004681,
+    2455076278534884, 2455162734280348, 2455508557262204, 2455652977655195,
+    2455775784111820, 2455828836501082, 2455844555727530, 2456089186189127,
+    2456168764773020, 2456393746201557, 2456591218983810, 2456603990855299,
+    2456607920661911, 2456923287642524, 2456956690998726, 2456975357580133,
+    2456981252290051, 2457199356557017, 2457332969981825, 2457346724304967,
+    2457536337473996, 2458338018022844, 2458520754030302, 2458563981903034,
+    2458790928234877, 2458872471722076, 2459514012651485, 2459706573175473,
+    2459882432021360, 2459946291378805, 2460111343256509, 2460466008303242,
+    2460515130885892, 2460578990243337, 2461025023293799, 2461103619426039,
+    2461146847298771, 2461172391041749, 2461173373493402, 2461364951565737,
+    2461667546674861, 2461850282682319, 2461863054553808, 2462054632626143,
+    2462240315988560, 2462267824634844, 2462349368122043, 2462439753674119,
+    2462459402707179, 2462676524522492, 2462687331490675, 2462724664653489,
+    2462736454073325, 2462897576144417, 2462922137435742, 2463275820030822,
+    2463429082488690, 2463532239912255, 2463815185988319, 2463877080442458,
+    2463947816961474, 2463990062382553, 2464039184965203, 2464040167416856,
+    2464105009225954, 2464141359937115, 2464500937242113, 2464527463436744,
+    2464530410791703, 2464571673761129, 2464874268870253, 2464914549388026,
+    2464945005389269, 2465137565913257, 2465165074559541, 2465209284883926,
+    2465492230959990, 2465598335738514, 2465695598452161, 2465699528258773,
+    2465747668389770, 2465974614721613, 2465980509431531, 2465989351496408,
+    2466090544016667, 2466160298084030, 2466741909462606, 2466850961596089,
+    2467090679799421, 2467102469219257, 2467188924964721, 2467378538133750,
+    2467426678264747, 2467680150791221, 2467914956736288, 2468128148744989,
+    2468167446811109, 2468168429262762, 2468205762425576, 2468376709013198,
+    2468486743598334, 2468693058445464, 2468764777416133, 2468803093030600,
+    2468832566580190, 2469051653298809, 2469227512144696, 2469292353953794,
+    2469325757309996, 2469374879892646, 2469440704153397, 2469835649717903,
+    2469858246105922, 2469871017977411, 2469962385981140, 2469978105207588,
+    2470029192693544, 2470485050260536, 2470578383167571, 2471006732088279,
+    2471214029387062, 2471477326430066, 2471516624496186, 2471688553535461,
+    2471783851345802, 2472000973161115, 2472016692387563, 2472063850066907,
+    2472186656523532, 2472297673560321, 2472483356922738, 2472492198987615,
+    2472636619380606, 2472653321058707, 2472696548931439, 2472733882094253,
+    2472800688806657, 2473156336305043, 2473437317477801, 2473530650384836,
+    2473611211420382, 2473901034658017, 2474414856872536, 2474724329143231,
+    2474746925531250, 2474758714951086, 2474795065662247, 2474914924763913,
+    2474937521151932, 2474957170184992, 2475057380253598, 2475526992143732,
+    2475567272661505, 2475662570471846, 2476034919648333, 2476488812312019,
+    2476504531538467, 2476571338250871, 2476588039928972, 2476592952187237,
+    2476638144963275, 2476714776192209, 2476979055686866, 2477164739049283,
+    2477231545761687, 2477333720733599, 2477840665786547, 2477967402049784,
+    2478146208250630, 2478242488512624, 2478757293178796, 2478945923896172,
+    2479169922873056, 2479197431519340, 2479213150745788, 2479219045455706,
+    2479502973983423, 2479690622249146, 2479698481862370, 2479823253222301,
+    2480587600608335, 2480619039061231, 2480897072879030, 2480967809398046,
+    2481034616110450, 2481062124756734, 2481368649672470, 2481441351094792,
+    2481664367620023, 2481867735112194, 2481911945436579, 2481929629566333,
+    2482005278343614, 2482200786222561, 2482263663128353, 2482414960682915,
+    2482510258493256, 2482512223396562, 2482556433720947, 2482577065205660,
+    2482582959915578, 2482639942111452, 2482664503402777, 2482713625985427,
+    2482738187276752, 2482739169728405, 2482744081986670, 2482746046889976,
+    2483055519160671, 2483294254912350, 2483373833496243, 2483562464213619,
+    2483635165635941, 2483724568736364, 2484708985292670, 2485031229434854,
+    2485416350482830, 2486255364194492, 2486342802391609, 2486397819684177,
+    2486399784587483, 2486441047556909, 2486625748467673, 2487385183595442,
+    2487436271081398, 2487442165791316, 2487447078049581, 2487479498954130,
+    2487702515479361, 2488179986982719, 2488386301829849, 2489100544181580,
+    2489353034256401, 2489955277119690, 2490481871205698, 2490497590432146,
+    2490503485142064, 2490527063981736, 2490628256501995, 2490691133407787,
+    2490870922060286, 2490957377805750, 2491065447487580, 2491228534461978,
+    2491749233838068, 2491823900163696, 2491845514100062, 2492130425079432,
+    2492154003919104, 2492382915154253, 2492490984836083, 2492513581224102,
+    2492586282646424, 2492714983812967, 2492988105372501, 2493050982278293,
+    2493729856370516, 2493805505147797, 2494069784642454, 2494103187998656,
+    2494227959358587, 2494298695877603, 2494323257168928, 2494584589308626,
+    2494779114735920, 2495231042496300, 2495298831660357, 2495639742383948,
+    2495673145740150, 2495821495939753, 2495992442527375, 2496076933369533,
+    2496362826800556, 2496374616220392, 2496456159707591, 2496542615453055,
+    2496758754816715, 2496846193013832, 2496989630955170, 2497005350181618,
+    2497044648247738, 2497069209539063, 2497440576263897, 2497932784542050,
+    2498183309713565, 2498197064036707, 2498912288840091, 2499261059176906,
+    2499452637249241, 2499643232869923, 2499832846038952, 2500781894335750,
+    2500917472663864, 2501033401958918, 2501355646101102, 2501652346500308,
+    2501747644310649, 2502227080717313, 2502440272726014, 2502606307055371,
+    2502983568490123, 2503010094684754, 2503647705807551, 2504024967242303,
+    2504091773954707, 2504207703249761, 2504256825832411, 2504499491390702,
+    2504507351003926, 2504644894235346, 2504757876175441, 2505155769094906,
+    2505363066393689, 2505657801889589, 2505835625638782, 2506137238296253,
+    2506253167591307, 2506264957011143, 2506472254309926, 2507558845838144,
+    2507714073199318, 2507787757073293, 2507947896692732, 2508437157615926,
+    2508462701358904, 2508548174652715, 2508572735944040, 2508739752725050,
+    2508872383698205, 2508904804602754, 2508935260603997, 2508981435831688,
+    2509271259069323, 2509468731851576, 2509482486174718, 2509491328239595,
+    2509493293142901, 2509569924371835, 2509683888763583, 2510003185550808,
+    2510300868401667, 2510496376280614, 2510619182737239, 2510776375001719,
+    2510792094228167, 2510893286748426, 2511052443916212, 2511314758507563,
+    2511645844714624, 2511822686012164, 2511882615562997, 2511941562662177,
+    2511968088856808, 2512437700746942, 2512456367328349, 2512899453023852,
+    2512956435219726, 2513068434708168, 2513248223360667, 2513260012780503,
+    2513466327627633, 2513468292530939, 2513561625437974, 2513995869068600,
+    2515650317652252, 2515797685400202, 2515943088244846, 2516162174963465,
+    2516226034320910, 2516228981675869, 2516894101444950, 2517179012424320,
+    2517291994364415, 2517341116947065, 2517417748175999, 2517673185605779,
+    2517822518257035, 2518064201363673, 2518265603952538, 2518305884470311,
+    2518312761631882, 2518511216865788, 2518731286036060, 2518756829779038,
+    2519472054582422, 2519568334844416, 2519900403503130, 2520706013858590,
+    2521130432972686, 2521231625492945, 2521297449753696, 2522229796372393,
+    2522274006696778, 2522658145293101, 2522728881812117, 2522922424787758,
+    2523229932155147, 2523252528543166, 2523489299391539, 2524422628461889,
+    2524478628206110, 2524488452722640, 2524713434151177, 2524715399054483,
+    2524917784095001, 2525088730682623, 2525391325791747, 2525493500763659,
+    2525879604263288, 2525911042716184, 2525913990071143, 2526169427500923,
+    2526500513707984, 2526555531000552, 2526642969197669, 2526671460295606,
+    2527036932310522, 2527334615161381, 2527603806914303, 2527902472216815,
+    2528169699066431, 2528456574949107, 2528713977282193, 2528833836383859,
+    2529218957431835, 2529280851885974, 2529442956408719, 2529537271767407,
+    2529552008542202, 2529885059652569, 2530109058629453, 2530146391792267,
+    2530461758772880, 2530468635934451, 2530518740968754, 2530534460195202,
+    2530559021486527, 2530758459172086, 2531072843701046, 2531946243220563,
+    2532066102322229, 2532125049421409, 2532220347231750, 2532276346975971,
+    2532718450219821, 2532859923257853, 2533093746751267, 2533201816433097,
+    2533345254374435, 2533383569988902, 2533726445615799, 2533742164842247,
+    2533747077100512, 2533879708073667, 2533913111429869, 2533936690269541,
+    2534222583700564, 2534332618285700, 2534380758416697, 2534433810805959,
+    2534490793001833, 2534692195590698, 2534914229664276, 2535247280774643,
+    2535317034842006, 2535915347898683, 2535934014480090, 2535993944030923,
+    2536396749208653, 2536413450886754, 2536671835671493, 2536697379414471,
+    2537492182801748, 2537610077000108, 2537717164230285, 2538052180243958,
+    2538201512895214, 2538221161928274, 2538629861815922, 2539170210225072,
+    2539266490487066, 2539660453599919, 2540166416201214, 2540238135171883,
+    2540271538528085, 2540280380592962, 2540518133892988, 2540990693138081,
+    2541424936768707, 2541431813930278, 2541463252383174, 2541816934978254,
+    2541881776787352, 2542052723374974, 2542616650623796, 2542642194366774,
+    2542749281596951, 2542884859925065, 2543116718515173, 2543665908989200,
+    2543732715701604, 2543823101253680, 2543924293773939, 2544200362688432,
+    2544854675489330, 2545170042469943, 2545214252794328, 2545427444803029,
+    2545794881721251, 2545803723786128, 2545962880953914, 2546037547279542,
+    2546043441989460, 2546105336443599, 2546257616449814, 2546569053623815,
+    2546858876861450, 2547006244609400, 2547171296487104, 2547411014690436,
+    2547603575214424, 2547618311989219, 2547650732893768, 2547811854964860,
+    2548251993305404, 2548704903517437, 2549205953860467, 2549273743024524,
+    2549316970897256, 2549884827952690, 2550064616605189, 2550175633641978,
+    2550421246555228, 2550552895076730, 2550923279349911, 2551382084271862,
+    2551455768145837, 2551540258987995, 2551725942350412, 2552017730491353,
+    2552234852306666, 2552584605095134, 2552639622387702, 2552895059817482,
+    2553010006660883, 2553165234022057, 2553409864483654, 2553521863972096,
+    2553653512493598, 2553696740366330, 2553913862181643, 2554127054190344,
+    2554162422449852, 2554246913292010, 2554257720260193, 2554287193809783,
+    2554588806467254, 2554700805955696, 2554733226860245, 2555115400553262,
+    2555935747683517, 2555942624845088, 2556426973510017, 2556444657639771,
+    2556816024364605, 2556836655849318, 2557300373029534, 2557424161937812,
+    2557479179230380, 2557561705169232, 2558011668026306, 2558029352156060,
+    2558092229061852, 2558123667514748, 2558454753721809, 2558464578238339,
+    2558967593484675, 2559049136971874, 2559537415443415, 2559702467321119,
+    2559905834813290, 2560179938824477, 2560254605150105, 2560299797926143,
+    2560322394314162, 2560502182966661, 2560542463484434, 2560760567751400,
+    2560777269429501, 2560925619629104, 2561082811893584, 2561204635898556,
+    2561333337065099, 2561647721594059, 2561700773983321, 2561718458113075,
+    2561730247532911, 2561761685985807, 2561804913858539, 2561981755156079,
+    2562148771937089, 2562251929360654, 2562350174525954, 2562819786416088,
+    2562822733771047, 2563043785392972, 2563877886846369, 2563991851238117,
+    2564068482467051, 2564158868019127, 2564194236278635, 2564409393190642,
+    2564496831387759, 2564501743646024, 2564515497969166, 2564583287133223,
+    2565117740832455, 2565139354768821, 2565531352978368, 2565558861624652,
+    2565663001499870, 2565762229116823, 2565773036085006, 2565909596864773,
+    2565956754544117, 2566109034550332, 2566201385005714, 2566326156365645,
+    2566676891605766, 2566796750707432, 2567129801817799, 2567314502728563,
+    2567315485180216, 2567402923377333, 2567648536290583, 2567816535523246,
+    2568759689110126, 2568861864082038, 2569624246564766, 2569660597275927,
+    2569854140251568, 2569897368124300, 2569941578448685, 2570372874724352,
+    2570683329446700, 2570738346739268, 2570740311642574, 2571107748560796,
+    2571655956583170, 2571740447425328, 2571756166651776, 2571809219041038,
+    2571822973364180, 2572240515316705, 2572424233775816, 2572466479196895,
+    2572599110170050, 2572893845665950, 2572975389153149, 2572994055734556,
+    2573134546320935, 2573314334973434, 2573332019103188, 2573341843619718,
+    2573394896008980, 2573407667880469, 2573962753064414, 2574276155141721,
+    2574401908953305, 2574414680824794, 2574462820955791, 2574464785859097,
+    2574567943282662, 2574568925734315, 2574610188703741, 2574957976588903,
+    2575012993881471, 2575126958273219, 2575563166807151, 2575593622808394,
+    2575957129920004, 2575967936888187, 2576152637798951, 2576291163482024,
+    2576683161691571, 2577035861834998, 2577041756544916, 2577139019258563,
+    2577154738485011, 2577183229582948, 2577330597330898, 2577384632171813,
+    2577625332826798, 2577684279925978, 2577889612321455, 2577896489483026,
+    2578622521254593, 2578791502938909, 2578944765396777, 2579012554560834,
+    2579950795889449, 2580336899389078, 2580597249077123, 2581117948453213,
+    2581444122402009, 2581560051697063, 2581783068222294, 2581962856874793,
+    2582110224622743, 2582114154429355, 2582294925533507, 2582458012507905,
+    2582486503605842, 2582528749026921, 2582680046581483, 2582747835745540,
+    2583121167373680, 2583202710860879, 2583399201191479, 2583525937454716,
+    2583710638365480, 2584491687429615, 2584892527704039, 2585367051852438,
+    2585428946306577, 2585595963087587, 2585966347360768, 2586495888801735,
+    2586573502482322, 2586727747391843, 2586934062238973, 2587105008826595,
+    2587670900978723, 2587715111303108, 2587770128595676, 2588123811190756,
+    2588141495320510, 2588243670292422, 2588592440629237, 2589136718844999,
+    2589872575133096, 2590270468052561, 2590390327154227, 2590447309350101,
+    2590826535688159, 2590845202269566, 2591052499568349, 2591095727441081,
+    2591720566692389, 2592466247497016, 2592506528014789, 2592642106342903,
+    2592704983248695, 2592986946873106, 2593143156685933, 2593230594883050,
+    2593558733735152, 2593594101994660, 2593815153616585, 2593999854527349,
+    2594397747446814, 2594630588488575, 2594761254558424, 2594803499979503,
+    2594946937920841, 2595148340509706, 2595471567103543, 2595487286329991,
+    2596068897708567, 2596603351407799, 2596893174645434, 2596961946261144,
+    2597443347571114, 2597515066541783, 2597641802805020, 2597720398937260,
+    2598042643079444, 2598214572118719, 2598243063216656, 2598336396123691,
+    2598408115094360, 2598501448001395, 2598636043877856, 2598748043366298,
+    2598750990721257, 2598814850078702, 2599003480796078, 2599774705343683,
+    2600232527813981, 2600282632848284, 2600802349772721, 2601493013284780,
+    2602371325062562, 2602487254357616, 2602519675262165, 2602581569716304,
+    2602673920171686, 2602838972049390, 2602860585985756, 2602942129472955,
+    2604051317389192, 2604531736247509, 2604540578312386, 2604597560508260,
+    2604724296771497, 2604835313808286, 2604977769297971, 2605339311506275,
+    2605391381443884, 2605540714095140, 2605543661450099, 2605769625330289,
+    2606437692454329, 2606444569615900, 2606472078262184, 2606620428461787,
+    2607382810944515, 2607615651986276, 2607823931736712, 2607937896128460,
+    2608278806852051, 2608336771499578, 2608363297694209, 2608482174344222,
+    2608707155772759, 2608713050482677, 2608957680944274, 2610100272216713,
+    2610569884106847, 2610601322559743, 2610607217269661, 2610787988373813,
+    2611310652653209, 2611723282347469, 2611840194094176, 2611876544805337,
+    2611978719777249, 2612207631012398, 2612777452971138, 2612889452459580,
+    2612891417362886, 2613260819184414, 2613947552889861, 2613994710569205,
+    2614497725815541, 2614650988273409, 2614984039383776, 2615507686114825,
+    2615559756052434, 2615649159152857, 2615694351928895, 2615810281223949,
+    2615842702128498, 2615849579290069, 2616066701105382, 2616312314018632,
+    2616359471697976, 2616458699314929, 2616652242290570, 2616709224486444,
+    2616774066295542, 2617300660381550, 2617378274062137, 2617721149689034,
+    2617748658335318, 2617755535496889, 2617766342465072, 2617949078472530,
+    2618246761323389, 2618612233338305, 2618841144573454, 2618858828703208,
+    2618877495284615, 2618897144317675, 2619467948728068, 2619579948216510,
+    2620095735334335, 2620451382832721, 2620680294067870, 2620771662071599,
+    2621053625696010, 2621869060568000, 2622558741628406, 2622584285371384,
+    2623047020099947, 2623198317654509, 2623256282302036, 2623347650305765,
+    2623739648515312, 2623792700904574, 2623841823487224, 2623915507361199,
+    2624472557448450, 2624475504803409, 2624506943256305, 2624610100679870,
+    2625443219681614, 2625505114135753, 2625548342008485, 2625758586662227,
+    2625935427959767, 2625977673380846, 2626068058932922, 2626424688882961,
+    2626850090448710, 2627026931746250, 2627547631122340, 2627962225719906,
+    2628157733598853, 2628764888720407, 2628911274016704, 2628944677372906,
+    2629014431440269, 2629060606667960, 2629972321801944, 2630001795351534,
+    2630382986592898, 2630430144272242, 2630557862987132, 2630934141970231,
+    2631462700959545, 2631522630510378, 2631731892712467, 2631800664328177,
+    2632023680853408, 2632065926274487, 2632107189243913, 2632108171695566,
+    2632333153124103, 2632494275195195, 2633225219225027, 2633380446586201,
+    2633635884015981, 2633668304920530, 2633816655120133, 2633841216411458,
+    2634001356030897, 2634014127902386, 2634138899262317, 2634147741327194,
+    2634179179780090, 2634274477590431, 2634423810241687, 2634544651795006,
+    2634964158650837, 2634976930522326, 2635004439168610, 2635896505269534,
+    2636075311470380, 2636572432006798, 2636617624782836, 2636937904021714,
+    2637109833060989, 2637229692162655, 2637264077970510, 2637487094495741,
+    2637717970634196, 2637865338382146, 2638180705362759, 2638247512075163,
+    2638308424077649, 2638346739692116, 2638398809629725, 2638606106928508,
+    2638619861251650, 2638811439323985, 2639317401925280, 2639490313416208,
+    2639690733553420, 2639698593166644, 2639699575618297, 2639835153946411,
+    2640310660546463, 2640327362224564, 2640816623147758, 2640866728182061,
+    2641112341095311, 2641212551163917, 2641273463166403, 2641351076846990,
+    2641398234526334, 2641411006397823, 2641590795050322, 2641698864732152,
+    2641889460352834, 2641924828612342, 2641956267065238, 2641957249516891,
+    2642447492891738, 2642577176509934, 2642680333933499, 2642859140134345,
+    2642936753814932, 2643584189454259, 2644171695542753, 2644196256834078,
+    2644399624326249, 2644771973502736, 2644881025636219, 2645300532492050,
+    2645335900751558, 2645357514687924, 2645464601918101, 2645502917532568,
+    2645622776634234, 2645943055873112, 2646314422597946, 2646370422342167,
+    2646385159116962, 2646425439634735, 2646621929965335, 2647210418505482,
+    2647481575161710, 2647698696977023, 2647947257245232, 2647959046665068,
+    2648220378804766, 2648708657276307, 2648737148374244, 2648771534182099,
+    2649180234069747, 2649279461686700, 2649596793570619, 2649739249060304,
+    2649757915641711, 2649927879777680, 2649932792035945, 2650337562116981,
+    2650776718005872, 2651431030806770, 2651458539453054, 2651511591842316,
+    2651597065136127, 2651867239340702, 2651897695341945, 2651926186439882,
+    2651966466957655, 2652095168124198, 2652127589028747, 2652492078592010,
+    2652640428791613, 2652691516277569, 2652914532802800, 2652980357063551,
+    2653037339259425, 2653040286614384, 2653454881211950, 2653501056439641,
+    2653703441480159, 2653779090257440, 2654297824730224, 2654575858548023,
+    2654587647967859, 2655235083607186, 2655622169558468, 2655677186851036,
+    2655852063245270, 2656194938872167, 2656415990494092, 2656421885204010,
+    2656440551785417, 2656548621467247, 2656792269477191, 2656800129090415,
+    2656941602128447, 2657076198004908, 2657286442658650, 2657595914929345,
+    2657655844480178, 2657706931966134, 2657789457904986, 2658056684754602,
+    2658465384642250, 2658514507224900, 2659240538996467, 2659839834504797,
+    2659849659021327, 2660131622645738, 2660206288971366, 2660224955552773,
+    2660315341104849, 2660355621622622, 2661266354304953, 2661353792502070,
+    2661589580898790, 2661778211616166, 2661888246201302, 2662035613949252,
+    2662361787898048, 2662505225839386, 2662800943786939, 2662956171148113,
+    2663069153088208, 2663310836194846, 2663404169101881, 2664117429001959,
+    2664637145926396, 2664652865152844, 2664995740779741, 2665147038334303,
+    2665576369706664, 2665791526618671, 2666296506768313, 2666444856967916,
+    2666774960723324, 2666801486917955, 2667180713256013, 2667379168489919,
+    2667863517154848, 2668083586325120, 2668094393293303, 2668368497304490,
+    2668446110985077, 2668460847759872, 2668845968807848, 2670110384085259,
+    2670136910279890, 2670770591596075, 2671787429056930, 2671843428801151,
+    2671899428545372, 2672640197091734, 2672830792712416, 2672989949880202,
+    2673020405881445, 2673278790666184, 2673396684864544, 2673525386031087,
+    2673538157902576, 2673542087709188, 2673810297010457, 2673925243853858,
+    2673986155856344, 2674419417035317, 2674479346586150, 2675104185837458,
+    2675290851651528, 2676034567552849, 2676672178675646, 2677776454333618,
+    2677838348787757, 2677991611245625, 2678971115543666, 2679334622655276,
+    2680700230452946, 2680900650590158, 2681132509180266, 2681230754345566,
+    2681448858612532, 2681575594875769, 2681754401076615, 2681792716691082,
+    2682176855287405, 2682236784838238, 2682240714644850, 2682833132991609,
+    2683105272099490, 2683188780489995, 2683206464619749, 2683337130689598,
+    2683443235468122, 2683445200371428, 2683710462317738, 2683886321163625,
+    2683957057682641, 2684322529697557, 2684414880152939, 2684477757058731,
+    2685084912180285, 2685107508568304, 2685277472704273, 2685310876060475,
+    2685686172591921, 2685925890795253, 2686038872735348, 2686081118156427,
+    2686179363321727, 2686342450296125, 2686592975467640, 2687134306328443,
+    2687276761818128, 2688189459403765, 2688317178118655, 2688412475928996,
+    2688693457101754, 2688935140208392, 2689389032872078, 2689614014300615,
+    2689619909010533, 2689745662822117, 2689765311855177, 2690445168399053,
+    2690511975111457, 2690526711886252, 2690581729178820, 2691161375654090,
+    2691176112428885, 2691464953214867, 2692299054668264, 2692637018036896,
+    2693107612378683, 2693150840251415, 2693690206208912, 2693763890082887,
+    2693891608797777, 2693902415765960, 2694108730613090, 2694338624299892,
+    2694364168042870, 2694442764175110, 2695189427431390, 2695361356470665,
+    2695393777375214, 2696352650188542, 2696394895609621, 2696520649421205,
+    2696660157555931, 2697078681960109, 2697483452041145, 2697512925590735,
+    2698076852839557, 2698090607162699, 2698183940069734, 2698527798148284,
+    2699069129009087, 2699116286688431, 2699236145790097, 2699247935209933,
+    2699617337031461, 2699661547355846, 2699916984785626, 2700417052677003,
+    2701057611154759, 2701899572221380, 2702429113662347, 2702546025409054,
+    2702775919095856, 2702985181297945, 2703022514460759, 2703105040399611,
+    2703258302857479, 2704080614891040, 2704136614635261, 2704364543418757,
+    2704697594529124, 2704929453119232, 2705171136225870, 2705181943194053,
+    2705926641547027, 2706254780399129, 2706524954603704, 2706538708926846,
+    2706775479775219, 2707048601334753, 2707105583530627, 2707822773237317,
+    2707838492463765, 2708517366555988, 2708743330436178, 2709742483767279,
+    2709753290735462, 2709803395769765, 2710109920685501, 2710306411016101,
+    2710309358371060, 2710780935164500, 2710925355557491, 2711303599443896,
+    2711317353767038, 2711780088495601, 2711788930560478, 2712025701408851,
+    2712753698083724, 2713007170610198, 2713012082868463, 2713056293192848,
+    2713171240036249, 2713986674908239, 2714088849880151, 2714360006536379,
+    2714764776617415, 2715055582306703, 2715072283984804, 2715791438594800,
+    2715861192662163, 2715878876791917, 2716181471901041, 2716421190104373,
+    2716760135924658, 2717255291557770, 2718106094689268, 2718107077140921,
+    2718507917415345, 2718981459112091, 2718985388918703, 2719098370858798,
+    2719219212412117, 2719458930615449, 2719620052686541, 2719846999018384,
+    2720034647284107, 2720244891937849, 2720511136335812, 2720524890658954,
+    2720836327832955, 2721337378175985, 2722378776928165, 2723434912455140,
+    2723549859298541, 2723794489760138, 2723823963309728, 2723894699828744,
+    2723933015443211, 2724154067065136, 2724303399716392, 2724493995337074,
+    2724680661151144, 2724688520764368, 2724693433022633, 2725102132910281,
+    2725462692666932, 2725501008281399, 2725680796933898, 2725797708680605,
+    2726131742242625, 2726368513090998, 2726372442897610, 2726711388717895,
+    2726817493496419, 2727324438549367, 2727461981780787, 2727981698705224,
+    2728016084513079, 2728410047625932, 2728615380021409, 2728780431899113,
+    2729037834232199, 2729467165604560, 2729971163302549, 2730000636852139,
+    2730193197376127, 2730388705255074, 2730567511455920, 2731093123090275,
+    2731256210064673, 2731308280002282, 2731528349172554, 2731555857818838,
+    2731658032790750, 2731716979889930, 2732178732166840, 2732344766496197,
+    2733039359814868, 2733332130407462, 2733493252478554, 2733789952877760,
+    2733881320881489, 2733885250688101, 2734246792896405, 2734414792129068,
+    2735106438092780, 2735119209964269, 2735172262353531, 2735618295403993,
+    2735685102116397, 2736588957637157, 2736751062159902, 2736943622683890,
+    2737787548653817, 2738250283382380, 2738448738616286, 2738774912565082,
+    2738948806507663, 2739236664841992, 2739260243681664, 2740348800113188,
+    2740887183619032, 2741477637062485, 2741745846363754, 2741794968946404,
+    2742140791928260, 2742197774124134, 2742251808965049, 2742634965109719,
+    2742778403051057, 2743267663974251, 2743530961017255, 2745037059401304,
+    2745057690886017, 2745205058633967, 2745854459176600, 2746059791572077,
+    2747067786968055, 2747116909550705, 2747588486344145, 2748125887398336,
+    2748136694366519, 2749227215701349, 2749290092607141, 2749368688739381,
+    2749557319456757, 2749752827335704, 2750194930579554, 2751073242357336,
+    2751452468695394, 2752128395432658, 2752945795207954, 2752948742562913,
+    2753011619468705, 2753034215856724, 2753085303342680, 2753267056898485,
+    2753303407609646, 2753589301040669, 2753639406074972, 2753850633180367,
+    2754036316542784, 2754124737191554, 2754216105195283, 2754467612818451,
+    2755329222918132, 2755646554802051, 2756564164645953, 2756882478981525,
+    2756941426080705, 2756989566211702, 2757422827390675, 2757468020166713,
+    2757639949205988, 2757965140703131, 2758102683934551, 2758554611694931,
+    2758663663828414, 2759456502312385, 2759612712125212, 2759765974583080,
+    2759910394976071, 2760092148531876, 2760119657178160, 2760248358344703,
+    2760709128169960, 2760888916822459, 2761257336192334, 2761286809741924,
+    2761394879423754, 2761430247683262, 2762028560739939, 2762799785287544,
+    2762882311226396, 2763377466859508, 2763519922349193, 2764106445986034,
+    2764114305599258, 2764566233359638, 2764685110009651, 2765000476990264,
+    2765119353640277, 2765234300483678, 2765356124488650, 2765359071843609,
+    2765941665673838, 2766281593945776, 2766473172018111, 2766983064426018,
+    2767012537975608, 2767158923271905, 2767451693864499, 2767995972080261,
+    2769523684400676, 2769665157438708, 2770052243389990, 2770067962616438,
+    2770317505336300, 2770647609091708, 2770764520838415, 2771224308212019,
+    2772316794450155, 2772497565554307, 2772543740781998, 2772641003495645,
+    2772800160663431, 2772848300794428, 2772934756539892, 2773054615641558,
+    2773386684300272, 2774580363058667, 2774788642809103, 2774922256233911,
+    2775211097019893, 2775295587862051, 2775719024524494, 2776151303251814,
+    2776194531124546, 2776844914118832, 2776885194636605, 2776949053994050,
+    2777120000581672, 2777419648335837, 2777767436220999, 2777861751579687,
+    2778247855079316, 2778510169670667, 2778560274704970, 2778596625416131,
+    2779513252808380, 2779883637081561, 2780099776445221, 2780193109352256,
+    2780738370019671, 2781142157649054, 2781574436376374, 2782070574461139,
+    2782417379894648, 2782678712034346, 2782954780948839, 2783319270512102,
+    2783340884448468, 2783614988459655, 2783732882658015, 2783799689370419,
+    2784961929675918, 2785432524017705, 2785496383375150, 2785620172283428,
+    2786045573849177, 2786062275527278, 2786659606132302, 2787139042538966,
+    2787149849507149, 2787619461397283, 2787670548883239, 2787804162308047,
+    2787917144248142, 2788165704516351, 2788420159494478, 2788582264017223,
+    2789369207791276, 2790059871303335, 2790376220735601, 2790540290161652,
+    2791243725545200, 2792202598358528, 2792231089456465, 2792287089200686,
+    2792710525863129, 2792851016449508, 2793423785763207, 2793597679705788,
+    2794070238950881, 2794515289549690, 2794541815744321, 2794912200017502,
+    2794921042082379, 2795508548170873, 2795576337334930, 2796529315438340,
+    2796544052213135, 2797134505656588, 2797189522949156, 2797274996242967,
+    2797628678838047, 2797743625681448, 2798059975113714, 2798111062599670,
+    2799251688968803, 2799340109617573, 2799760598925057, 2799765511183322,
+    2799881440478376, 2800584875861924, 2800714559480120, 2801522134738886,
+    2802010413210427, 2802579252717514, 2803137285256418, 2803303319585775,
+    2803488020496539, 2803569563983738, 2803651107470937, 2803712019473423,
+    2803743457926319, 2804065702068503, 2804186543621822, 2804239596011084,
+    2804698400933035, 2804850680939250, 2805208293340942, 2805410678381460,
+    2805529555031473, 2805885202529859, 2806322393515444, 2806921689023774,
+    2807541616016817, 2807656562860218, 2808103578362333, 2808773610389679,
+    2808904276459528, 2808992697108298, 2809074240595497, 2809166591050879,
+    2809356204219908, 2809703992105070, 2809796342560452, 2810170656640245,
+    2810329813808031, 2810767004793616, 2810851495635774, 2811111845323819,
+    2811310300557725, 2811326019784173, 2811563773084199, 2811807421094143,
+    2812062858523923, 2812085454911942, 2813069871468248, 2813144537793876,
+    2813878429178667, 2814117164930346, 2814560250625849, 2815590842409846,
+    2816070278816510, 2816274628760334, 2816510417157054, 2816897503108336,
+    2817083186470753, 2817929077343986, 2817965428055147, 2818437987300240,
+    2818579460338272, 2818648231953982, 2819389000500344, 2819511806956969,
+    2819825209034276, 2819985348653715, 2820610187905023, 2820902958497617,
+    2820930467143901, 2821219307929883, 2821431517486931, 2821628007817531,
+    2822445407592827, 2822506319595313, 2822858037287087, 2822946457935857,
+    2823408210212767, 2823876839651248, 2823901400942573, 2823966242751671,
+    2824073329981848, 2824760063687295, 2824765958397213, 2825684550692768,
+    2826111917161823, 2826624756924689, 2827344893986338, 2827587559544629,
+    2827726085227702, 2827907838783507, 2827968750785993, 2827975627947564,
+    2828046364466580, 2828328328090991, 2828442292482739, 2828748817398475,
+    2828852957273693, 2829011131989826, 2829839338733305, 2829860952669671,
+    2830161582875489, 2830700948832986, 2831206911434281, 2831228525370647,
+    2831475120735550, 2831871048751709, 2832007609531476, 2832122556374877,
+    2832312169543906, 2832495888003017, 2832933078988602, 2833500936044036,
+    2833820232831261, 2833968583030864, 2834063880841205, 2834411668726367,
+    2834420510791244, 2834531527828033, 2834612088863579, 2834932368102457,
+    2835456997285159, 2835714399618245, 2836223309574499, 2836323519643105,
+    2836514115263787, 2836580921976191, 2837047586511366, 2837415023429588,
+    2837784425251116, 2838213756623477, 2838280563335881, 2839443786093033,
+    2840110870765420, 2840175712574518, 2840509746136538, 2840580482655554,
+    2841067778675442, 2841256409392818, 2841545250178800, 2841615986697816,
+    2841746652767665, 2841763354445766, 2842241808400777, 2842273246853673,
+    2842299773048304, 2842848963522331, 2842876472168615, 2843303838637670,
+    2843336259542219, 2843428609997601, 2843569100583980, 2843927695437325,
+    2844001379311300, 2844250922031162, 2844867901669246, 2844975971351076,
+    2845089935742824, 2845330636397809, 2846028177071439, 2846051755911111,
+    2846435894507434, 2846697226647132, 2846875050396325, 2847702274688151,
+    2847799537401798, 2848318271874582, 2848367394457232, 2848393920651863,
+    2848399815361781, 2848612024918829, 2848783953958104, 2848817357314306,
+    2848939181319278, 2849773282772675, 2850877558430647, 2850886400495524,
+    2850930610819909, 2850972856240988, 2851519099360056, 2851709694980738,
+    2851849203115464, 2851947448280764, 2851978886733660, 2852534954369258,
+    2852755023539530, 2852761900701101, 2853040916970553, 2853086109746591,
+    2853100846521386, 2853144074394118, 2853734527837571, 2854103929659099,
+    2854188420501257, 2854282735859945, 2854294525279781, 2854634453551719,
+    2854655085036432, 2854776909041404, 2854781821299669, 2855308415385677,
+    2855442028810485, 2855971570251452, 2856292831941983, 2856346866782898,
+    2856506023950684, 2856608198922596, 2857096477394137, 2857123003588768,
+    2857459984505747, 2858129034081440, 2858204682858721, 2858654645715795,
+    2858686084168691, 2858725382234811, 2858807908173663, 2858851136046395,
+    2859057450893525, 2859318783033223, 2859326642646447, 2859365940712567,
+    2859368888067526, 2860061516482891, 2860168603713068, 2860336602945731,
+    2860451549789132, 2860515409146577, 2860729583606931, 2861031196264402,
+    2861245370724756, 2861371124536340, 2861601018223142, 2861866280169452,
+    2861911472945490, 2862076524823194, 2862454768709599, 2862470487936047,
+    2862545154261675, 2862550066519940, 2862990204860484, 2863029502926604,
+    2863057994024541, 2863409711716315, 2863455886944006, 2863537430431205,
+    2864125918971352, 2865280299663627, 2865383457087192, 2866009278790153,
+    2866209698927365, 2866258821510015, 2866359031578621, 2866699942302212,
+    2867466254591552, 2867539938465527, 2867997760935825, 2868197198621384,
+    2868607863412338, 2868745406643758, 2869392842283085, 2870501047747669,
+    2870766309693979, 2870783011372080, 2871269324940315, 2871388201590328,
+    2871430447011407, 2872445319568956, 2873556472388499, 2874053592924917,
+    2874603765850597, 2875184394777520, 2875192254390744, 2875353376461836,
+    2875680532862285, 2876157021913990, 2876162916623908, 2876309301920205,
+    2877099193049217, 2877274069443451, 2877328104284366, 2878291889355959,
+    2879506199599067, 2879847110322658, 2880481774090496, 2880547598351247,
+    2880651738226465, 2881827732855106, 2882745342699008, 2883006674838706,
+    2883456637695780, 2883937056554097, 2884613965743014, 2885319366029868,
+    2885614101525768, 2885660276753459, 2886013959348539, 2886107292255574,
+    2887277392174297, 2887675285093762, 2887729319934677, 2887992616977681,
+    2888491702417405, 2888822788624466, 2889355277420392, 2889370996646840,
+    2889493803103465, 2889564539622481, 2889992888543189, 2890199203390319,
+    2890416325205632, 2890965515679659, 2890971410389577, 2891072602909836,
+    2891120743040833, 2891150216590423, 2891462636216077, 2891511758798727,
+    2892227966053764, 2892234843215335, 2893256592934455, 2893352873196449,
+    2893894204057252, 2894038624450243, 2894207606134559, 2894362833495733,
+    2894580937762699, 2894616306022207, 2894645779571797, 2894876655710252,
+    2895032865523079, 2895082970557382, 2895122268623502, 2896184298860395,
+    2896479034356295, 2896595946103002, 2896710892946403, 2897307241099774,
+    2897313135809692, 2897537134786576, 2897553836464677, 2897604923950633,
+    2897793554668009, 2898291657656080, 2898350604755260, 2898982321168139,
+    2899395933314052, 2899547230868614, 2900168140313310, 2900185824443064,
+    2900489402003841, 2900495296713759, 2900693751947665, 2900994382153483,
+    2901582870693630, 2901626098566362, 2901731220893233, 2901811781928779,
+    2902125184006086, 2902269604399077, 2902638023768952, 2902655707898706,
+    2904398577131128, 2904683488110498, 2904857382053079, 2904866224117956,
+    2905042082963843, 2905591273437870, 2907443194803775, 2907603334423214,
+    2907778210817448, 2907981578309619, 2908104384766244, 2908234068384440,
+    2908713504791104, 2908763609825407, 2909088801322550, 2909398273593245,
+    2909625219925088, 2909929779937518, 2909950411422231, 2910235322401601,
+    2910423953118977, 2910477987959892, 2910800232102076, 2910971178689698,
+    2911627456393902, 2912397698489854, 2912599101078719, 2913112923293238,
+    2913600219313126, 2913617903442880, 2913784920223890, 2914205409531374,
+    2914498180123968, 2914904915108310, 2915379439256709, 2915442316162501,
+    2915763577853032, 2915981682119998, 2916811853766783, 2916815783573395,
+    2916883572737452, 2917649885026792, 2918011427235096, 2918158794983046,
+    2918207917565696, 2918408337702908, 2918420127122744, 2918558652805817,
+    2918613670098385, 2919115702893068, 2919867278407613, 2920298574683280,
+    2920341802556012, 2920382083073785, 2920549099854795, 2921303622724299,
+    2921419552019353, 2921535481314407, 2921573796928874, 2923021930665396,
+    2923727330952250, 2924032873416333, 2924057434707658, 2924099680128737,
+    2924191048132466, 2925315955275151, 2925589076834685, 2925993846915721,
+    2926265986023602, 2926370125898820, 2926433985256265, 2926980228375333,
+    2927161981931138, 2927253349934867, 2927268086709662, 2927960715125027,
+    2928085486484958, 2928264292685804, 2928632712055679, 2928900921356948,
+    2929037482136715, 2929378392860306, 2929389199828489, 2929480567832218,
+    2929494322155360, 2929606321643802, 2929682952872736, 2929996354950043,
+    2930326458705451, 2930402107482732, 2930688983365408, 2931141893577441,
+    2931219507258028, 2931597751144433, 2931614452822534, 2931802101088257,
+    2933031148106160, 2933372058829751, 2933474233801663, 2933693320520282,
+    2933746372909544, 2933769951749216, 2933880968786005, 2934146230732315,
+    2934707210626178, 2935273102778306, 2935571768080818, 2936073800875501,
+    2936886288392532, 2936966849428078, 2937332321442994, 2938078984699274,
+    2938335404580707, 2938487684586922, 2938532877362960, 2938928805379119,
+    2939343399976685, 2939549714823815, 2939555609533733, 2940019326713949,
+    2940106764911066, 2940204027624713, 2940760095260311, 2941063672821088,
+    2941135391791757, 2941264092958300, 2941875177886466, 2942257351579483,
+    2942381140487761, 2942391947455944, 2943738888672207, 2943872502097015,
+    2943910817711482, 2944301833469376, 2944404990892941, 2944438394249143,
+    2944548428834279, 2944873620331422, 2945079935178552, 2945298039445518,
+    2945497477131077, 2945583932876541, 2946108562059243, 2946501542720443,
+    2946764839763447, 2948094096849956, 2948239499694600, 2948241464597906,
+    2948371148216102, 2948380972732632, 2948776900748791, 2948918373786823,
+    2949024478565347, 2949174793668256, 2949237670574048, 2949876264148498,
+    2950977592451511, 2951004118646142, 2951113170779625, 2951416748340402,
+    2952112324110726, 2952432603349604, 2952436533156216, 2953065302214136,
+    2953217582220351, 2953751053467930, 2954169577872108, 2954507541240740,
+    2954983047840792, 2955059679069726, 2955717921677236, 2955826973810719,
+    2955977288913628, 2956093218208682, 2956109919886783, 2956479321708311,
+    2956547110872368, 2956703320685195, 2956895881209183, 2956988231664565,
+    2957225984964591, 2958146542163452, 2958269348620077, 2958715381670539,
+    2958840153030470, 2958879451096590, 2959255730079689, 2959385413697885,
+    2959880569330997, 2959915937590505, 2962567574601952, 2962632416411050,
+    2963219922499544, 2963601113740908, 2963710165874391, 2963723920197533,
+    2963852621364076, 2964212198669074, 2965161246965872, 2965480543753097,
+    2965905945318846, 2966401100951958, 2966529802118501, 2966869730390439,
+    2967030852461531, 2967072115430957, 2967074080334263, 2967695972230612,
+    2967917023852537, 2967955339467004, 2968108601924872, 2968250074962904,
+    2969313087651450, 2969329789329551, 2970327960208999, 2970946904750389,
+    2971964724662897, 2972336091387731, 2972958965735733, 2973007105866730,
+    2973596576858530, 2973908996484184, 2973970890938323, 2973997417132954,
+    2974458186958211, 2974610466964426, 2974847237812799, 2975225481699204,
+    2975312919896321, 2975931864437711, 2976658878660931, 2976694246920439,
+    2977291577525463, 2977400629658946, 2977463506564738, 2977929188648260,
+    2978073609041251, 2978973534755399, 2979013815273172, 2979022657338049,
+    2979246656314933, 2979436269483962, 2979786022272430, 2979799776595572,
+    2979927495310462, 2980143634674122, 2980936473158093, 2981056332259759,
+    2981105454842409, 2981195840394485, 2981304892527968, 2981394295628391,
+    2981633031380070, 2981653662864783, 2982206783145422, 2982547693869013,
+    2982897446657481, 2982917095690541, 2982920043045500, 2983583197911275,
+    2983650004623679, 2983808179339812, 2984347545297309, 2984511614723360,
+    2985304453207331, 2985786836968954, 2985939116975169, 2986514833643827,
+    2987888301054721, 2990302184766142, 2990564499357493, 2991295443387325,
+    2991501758234455, 2991562670236941, 2992172772713454, 2992227790006022,
+    2992232702264287, 2992584419956061, 2992611928602345, 2992867366032125,
+    2993510871864840, 2994547358358755, 2994913812825324, 2995663423436563,
+    2996127140616779, 2996209666555631, 2996220473523814, 2996247982170098,
+    2996610506830055, 2996639980379645, 2996685173155683, 2996826646193715,
+    2996835488258592, 2996977943748277, 2997006434846214, 2997157732400776,
+    2997364047247906, 2997533028932222, 2998963478538990, 2999027337896435,
+    2999223828227035, 2999580458177074, 2999588317790298, 3000333016143272,
+    3000454840148244, 3000542278345361, 3001013855138801, 3001031539268555,
+    3001477572319017, 3001877430141788, 3002234060091827, 3002349989386881,
+    3002521918426156, 3002693847465431, 3003078968513407, 3003171318968789,
+    3003433633560140, 3003931736548211, 3004052578101530, 3004180296816420,
+    3004757978388384, 3005314046023982, 3005315028475635, 3005610746423188,
+    3005995867471164, 3006574531494781, 3006728776404302, 3007814385480867,
+    3010424759522888, 3010916967801041, 3011094791550234, 3011432754918866,
+    3011661666154015, 3012599907482630, 3013255202735181, 3013881024438142,
+    3013882006889795, 3014090286640231, 3014272040196036, 3014350636328276,
+    3014775055442372, 3014844809509735, 3014947966933300, 3015946137812748,
+    3016282136278074, 3016316522085929, 3016523819384712, 3016996378629805,
+    3017082834375269, 3017597639041441, 3018557494306422, 3018581073146094,
+    3018682265666353, 3019270754206500, 3019315946982538, 3019464297182141,
+    3019900505716073, 3020996921760821, 3021844777537360, 3022068776514244,
+    3022136565678301, 3022211232003929, 3022298670201046, 3022548212920908,
+    3023785119552035, 3025298095097655, 3025553532527435, 3026758018254013,
+    3026794368965174, 3026872965097414, 3028462571871968, 3028530361036025,
+    3028714079495136, 3029558005465063, 3029758425602275, 3030042354129992,
+    3030314493237873, 3031027753137951, 3031082770430519, 3031222278565245,
+    3031841223106635, 3031912942077304, 3032525991908776, 3032544658490183,
+    3032581991652997, 3033378759943580, 3033406268589864, 3033512373368388,
+    3033540864466325, 3033907318932894, 3034407386824271, 3034564579088751,
+    3034871104004487, 3034969349169787, 3035060717173516, 3035331873829744,
+    3035430118995044, 3035605977840931, 3035877134497159, 3036130607023633,
+    3036212150510832, 3036985339961743, 3037522741015934, 3038231088657747,
+    3038314597048252, 3038505192668934, 3038727226742512, 3038852980554096,
+    3039310803024394, 3039662520716168, 3040146869381097, 3040183220092258,
+    3040559499075357, 3040620411077843, 3040694094951818, 3040961321801434,
+    3041143075357239, 3041315986848167, 3041317951751473, 3041546862986622,
+    3042248333466864, 3042319069985880, 3042779839811137, 3043477380484767,
+    3044426428781565, 3045020812031630, 3045179969199416, 3045948246392062,
+    3047849290340617, 3047851255243923, 3047930833827816, 3048491813721679,
+    3048943741482059, 3049467388213108, 3049611808606099, 3050413489154947,
+    3050532365804960, 3051054047632703, 3051190608412470, 3051613062623260,
+    3052194674001836, 3052341059298133, 3052426532591944, 3052450111431616,
+    3052494321756001, 3052813618543226, 3053089687457719, 3053336282822622,
+    3054285331119420, 3054487716159938, 3055583149753033, 3055717745629494,
+    3055758026147267, 3055976130414233, 3057282791112723, 3057685596290453,
+    3058051068305369, 3058195488698360, 3058415557868632, 3058698503944696,
+    3058800678916608, 3058852748854217, 3059842077668788, 3060001234836574,
+    3060043480257653, 3060782283900709, 3060812739901952, 3060894283389151,
+    3061414000313588, 3061836454524378, 3061946489109514, 3062446557000891,
+    3063126413544767, 3063196167612130, 3063513499496049, 3064324022109774,
+    3064985212072243, 3065374262926831, 3067576919532857, 3067750813475438,
+    3067767515153539, 3068453266407333, 3068596704348671, 3068666458416034,
+    3070060557311641, 3070657887916665, 3071327919944011, 3071505743693204,
+    3072511774185876, 3072970579107827, 3073058017304944, 3073649453200050,
+    3073654365458315, 3073723137074025, 3074846079313404, 3075225305651462,
+    3075803969675079, 3075986705682537, 3076294213049926, 3076343335632576,
+    3076431756281346, 3076489720928873, 3076545720673094, 3077083121727285,
+    3077363120448390, 3077393576449633, 3078054766412102, 3078189362288563,
+    3078218835838153, 3078384870167510, 3078504729269176, 3079252374977109,
+    3079525496536643, 3080201423273907, 3080926472593821, 3081763521402177,
+    3081815591339786, 3082964077322143, 3082979796548591, 3083018112163058,
+    3083496566118069, 3083818810260253, 3084053616205320, 3084255018794185,
+    3084759998943827, 3085864274601799, 3086516622499391, 3086988199292831,
+    3087269180465589, 3087746651968947, 3087765318550354, 3088024685786746,
+    3088634788263259, 3089342153453419, 3089575976946833, 3089684046628663,
+    3090467060596104, 3090538779566773, 3091083057782535, 3091801229940878,
+    3092369086996312, 3092386771126066, 3092915330115380, 3092939891406705,
+    3093305363421621, 3093337784326170, 3093600098917521, 3093961641125825,
+    3094092307195674, 3094449919597366, 3094631673153171, 3096116157600854,
+    3096156438118627, 3096821557887708, 3097290187326189, 3099006530363980,
+    3099033056558611, 3099431931929729, 3099577334774373, 3099589124194209,
+    3099600913614045, 3100154033894684, 3102351778242445, 3102755565871828,
+    3103709526426891, 3103789105010784, 3103913876370715, 3104185033026943,
+    3104563276913348, 3104576048784837, 3104949380412977, 3104984748672485,
+    3105108537580763, 3105397378366745, 3105626289601894, 3105969165228791,
+    3106018287811441, 3106695197000358, 3106700109258623, 3107266001410751,
+    3107475263612840, 3107789648141800, 3108239610998874, 3108278909064994,
+    3109243676588240, 3109266272976259, 3109341921753540, 3109393009239496,
+    3109942199713523, 3112057418122432, 3112372785103045, 3113610674185825,
+    3114174601434647, 3114183443499524, 3114457547510711, 3114904563012826,
+    3114987088951678, 3115951856474924, 3115989189637738, 3116077610286508,
+    3116358591459266, 3117229043623824, 3117446165439137, 3117762514871403,
+    3118115215014830, 3118225249599966, 3118883492207476, 3119679278046406,
+    3119805031857990, 3119907206829902, 3121025236811016, 3121292463660632,
+    3121432954247011, 3121609795544551, 3123237717933572, 3123662137047668,
+    3123757434858009, 3125463953379270, 3125827460490880, 3126121213535127,
+    3126970051763319, 3127118401962922, 3127659732823725, 3127941696448136,
+    3128120502648982, 3129199234563976, 3129250322049932, 3129739582973126,
+    3130713192561249, 3130762315143899, 3130911647795155, 3130958805474499,
+    3131247646260481, 3131449048849346, 3132572973540378, 3133055357302001,
+    3133108409691263, 3135153874032809, 3135477100626646, 3135677520763858,
+    3136758217582158, 3137257303021882, 3137549091162823, 3137716107943833,
+    3137840879303764, 3138135614799664, 3138315403452163, 3140283254113122,
+    3140521007413148, 3141846334693045, 3141981913021159, 3142084087993071,
+    3143482116695290, 3143546958504388, 3143715940188704, 3146211367387324,
+    3147145678909327, 3148036762558598, 3148257814180523, 3148987775758702,
+    3149650930624477, 3150079279545185, 3150701171441534, 3151326010692842,
+    3151370221017227, 3151794640131323, 3151883060780093, 3152225936406990,
+    3152227901310296, 3153927542669986, 3154072945514630, 3154286137523331,
+    3154308733911350, 3154574978309313, 3154629995601881, 3155079958458955,
+    3156558548196720, 3156583109488045, 3157152931446785, 3157312088614571,
+    3157319948227795, 3157781700504705, 3158155032132845, 3158227733555167,
+    3158407522207666, 3159030396555668, 3159769200198724, 3159805550909885,
+    3160513898551698, 3160530600229799, 3160907861664551, 3161065053929031,
+    3162692976318052, 3162984764458993, 3163482867447064, 3163688199842541,
+    3163836550042144, 3164465319100064, 3166065732842801, 3166560888475913,
+    3166575625250708, 3167042289785883, 3167657304520661, 3168004109954170,
+    3168234986092625, 3169290139167947, 3169557366017563, 3169690979442371,
+    3170002416616372, 3170352169404840, 3170406204245755, 3170458274183364,
+    3171020236528880, 3171602830359109, 3172006617988492, 3172214897738928,
+    3172992999448104, 3173053911450590, 3173061771063814, 3173179665262174,
+    3173199314295234, 3173760294189097, 3174375308923875, 3175314532704143,
+    3175744846528157, 3175815583047173, 3176443369653440, 3176799999603479,
+    3176863858960924, 3177543715504800, 3177696977962668, 3178195080950739,
+    3178792411555763, 3179096971568193, 3179374022934339, 3179580337781469,
+    3179771915853804, 3180102019609212, 3180668894212993, 3180790718217965,
+    3180982296290300, 3181331066627115, 3182023695042480, 3182871550819019,
+    3183572038847608, 3183574003750914, 3184046562996007, 3185252031174238,
+    3185720660612719, 3188042193868758, 3188162052970424, 3188289771685314,
+    3188359525752677, 3189005978940351, 3189079662814326, 3189101276750692,
+    3190064079370632, 3190303797573964, 3191142811285626, 3191278389613740,
+    3191992631965471, 3192082035065894, 3192113473518790, 3192249051846904,
+    3192820838708950, 3193106732139973, 3194700268721139, 3194703216076098,
+    3195808474185723, 3196226998589901, 3197099415657765, 3197470782382599,
+    3197859833237187, 3198033727179768, 3198308813642608, 3198379550161624,
+    3198990635089790, 3199538843112164, 3199549650080347, 3200073296811396,
+    3200132243910576, 3200537013991612, 3200554698121366, 3200705995675928,
+    3201227677503671, 3201747394428108, 3201826973012001, 3201845639593408,
+    3202423321165372, 3202426268520331, 3203111037322472, 3203561982631199,
+    3203667104958070, 3203674964571294, 3203879314515118, 3203953980840746,
+    3204589627060237, 3204628925126357, 3204662328482559, 3205928708663276,
+    3206743161083613, 3207508490921300, 3207671577895698, 3207697121638676,
+    3207752138931244, 3207873962936216, 3208618661289190, 3208845607621033,
+    3209587358619048, 3209609955007067, 3209881111663295, 3209981321731901,
+    3210102163285220, 3210383144457978, 3210532477109234, 3210733879698099,
+    3212292048019757, 3213355060708303, 3214685300246465, 3214995754968813,
+    3215855400165188, 3216923325111999, 3217051043826889, 3217103113764498,
+    3217127675055823, 3217210200994675, 3217233779834347, 3217405708873622,
+    3218313494200994, 3218749702734926, 3218815526995677, 3219319524693666,
+    3220291169378483, 3220399239060313, 3220420852996679, 3221114463863697,
+    3223574522802809, 3224647360007885, 3225196550481912, 3225357672553004,
+    3225740828697674, 3225755565472469, 3225928476963397, 3226040476451839,
+    3228121309052893, 3228852253082725, 3229582214660904, 3230410421404383,
+    3231015611622631, 3231309364666878, 3232117922377297, 3232748656338523,
+    3232898971441432, 3232954971185653, 3233014900736486, 3233679038053914,
+    3234169281428761, 3235123241983824, 3235695028845870, 3236985970317912,
+    3238797611166044, 3239623853006217, 3240235920386036, 3240512971752182,
+    3240621041434012, 3241118161970430, 3241194793199364, 3241306792687806,
+    3241805878127530, 3242794224490448, 3243126293149162, 3243210783991320,
+    3243591975232684, 3245631544864312, 3246313366311494, 3247278133834740,
+    3247405852549630, 3247593500815353, 3247869569729846, 3247915744957537,
+    3248363742911305, 3249735245418893, 3250426891382605, 3250603732680145,
+    3250799240559092, 3251503658394293, 3251795446535234, 3251934954669960,
+    3252215935842718, 3252271935586939, 3253584490995347, 3254574802261571,
+    3254893116597143, 3254999221375667, 3255177045124860, 3256284268137791,
+    3259544042722445, 3259906567382402, 3260658142896947, 3260902773358544,
+    3260950913489541, 3261207333370974, 3261401858798268, 3263616304824130,
+    3264269635173375, 3265125350563138, 3265637207874351, 3266050820020264,
+    3266970394767472, 3267298533619574, 3267658110924572, 3267673830151020,
+    3268471580893256, 3268823298585030, 3268875368522639, 3269028630980507,
+    3269406874866912, 3271304971460508, 3271658654055588, 3271685180250219,
+    3272013319102321, 3272806157586292, 3273157875278066, 3273905520985999,
+    3275381163368805, 3275390987885335, 3275397865046906, 3275468601565922,
+    3275517724148572, 3275576671247752, 3275778073836617, 3276126844173432,
+    3276513930124714, 3277379470031007, 3278444447622859, 3279058479905984,
+    3279383671403127, 3279517284827935, 3280315035570171, 3281040084890085,
+    3281416363873184, 3281626608526926, 3282131588676568, 3282657200310923,
+    3283053128327082, 3283194601365114, 3284835295625624, 3285643853336043,
+    3286508410790683, 3286861110934110, 3287081180104382, 3287293389661430,
+    3287606791738737, 3287613668900308, 3288014509174732, 3288018438981344,
+    3288143210341275, 3288167771632600, 3288280753572695, 3288829944046722,
+    3289053943023606, 3289104048057909, 3289280889355449, 3289568747689778,
+    3290299691719610, 3290408743853093, 3290610146441958, 3290648462056425,
+    3290853794451902, 3292990626797177, 3293335467327380, 3294041850065887,
+    3294523251375857, 3294752162611006, 3295338686247847, 3296348646547131,
+    3296882117794710, 3297190607613752, 3297896007900606, 3297901902610524,
+    3298159304943610, 3298554250508116, 3298934459297827, 3299017967688332,
+    3299082809497430, 3299316632990844, 3299398176478043, 3300117331088039,
+    3300134032766140, 3300714661693063, 3300812906858363, 3302319005242412,
+    3302718863065183, 3303362368897898, 3303507771742542, 3303540192647091,
+    3303974436277717, 3304009804537225, 3304412609714955, 3304421451779832,
+    3304572749334394, 3306042497007282, 3306138777269276, 3306290074823838,
+    3306817651361499, 3307128106083847, 3307393368030157, 3307834488822354,
+    3308141013738090, 3309083184873317, 3309560656376675, 3309580305409735,
+    3309909426713490, 3310339740537504, 3310508722221820, 3310553914997858,
+    3310723879133827, 3311176789345860, 3311795733887250, 3312346889264583,
+    3312532572627000, 3312902956900181, 3313012009033664, 3313682041061010,
+    3313704637449029, 3313738040805231, 3314849193624774, 3315056490923557,
+    3315425892745085, 3315540839588486, 3315837539987692, 3316799360155979,
+    3317096060555185, 3317913460330481, 3317990091559415, 3318460685901202,
+    3318574650292950, 3318950929276049, 3319888188153011, 3320029661191043,
+    3321587829512701, 3322832595757052, 3323263892032719, 3324127467035706,
+    3324422202531606, 3325458689025521, 3326468649324805, 3326816437209967,
+    3326832156436415, 3328008151065056, 3328041554421258, 3328746954708112,
+    3328860919099860, 3328881550584573, 3329394390347439, 3329785406105333,
+    3330808138276106, 3331298381650953, 3331380907589805, 3331640274826197,
+    3331643222181156, 3332314236660155, 3332969531912706, 3333292758506543,
+    3333651353359888, 3333761387945024, 3334336122162029, 3335396187495616,
+    3335893308032034, 3335922781581624, 3336026921456842, 3336202780302729,
+    3336530919154831, 3336945513752397, 3336948461107356, 3337114495436713,
+    3338120525929385, 3338148034575669, 3339210064812562, 3340572725255273,
+    3340818338168523, 3340919530688782, 3343000363289836, 3343282326914247,
+    3343887517132495, 3343967095716388, 3344152779078805, 3344332567731304,
+    3345330738610752, 3345733543788482, 3345843578373618, 3346962590806385,
+    3347369325790727, 3347458728891150, 3347737745160602, 3347848762197391,
+    3348407777187948, 3348637670874750, 3348963844823546, 3348997248179748,
+    3349142651024392, 3349748823694293, 3350300961523279, 3350316680749727,
+    3350818713544410, 3351569306607302, 3352156812695796, 3352212812440017,
+    3353852524248874, 3354130558066673, 3354827116288650, 3355234833724645,
+    3355668094903618, 3355676936968495, 3357208579095522, 3357384437941409,
+    3358005347386105, 3358869904840745, 3360576423362006, 3361170806612071,
+    3361493050754255, 3361505822625744, 3361645330760470, 3363187779855680,
+    3363433392768930, 3363749742201196, 3363900057304105, 3364334300934731,
+    3364956192831080, 3367884881208673, 3368328949355829, 3368442913747577,
+    3369939187615096, 3370009924134112, 3370386203117211, 3371355882898722,
+    3371785214271083, 3372164440609141, 3373697065187821, 3373761906996919,
+    3373840503129159, 3373985905973803, 3374323869342435, 3374360220053596,
+    3374469272187079, 3375383934676022, 3375463513259915, 3375627582685966,
+    3375950809279803, 3376665051631534, 3377582661475436, 3378863778430948,
+    3379051426696671, 3379893387763292, 3380409174881117, 3381106715554747,
+    3382429095479685, 3382576463227635, 3385230065142388, 3385407888891581,
+    3385838202715595, 3389517484156080, 3391530527593077, 3391565895852585,
+    3391576702820768, 3392845047904791, 3393774447168529, 3394359988353717,
+    3396829871809359, 3398551127105415, 3398941160411656, 3399027616157120,
+    3399658350118346, 3400203610785761, 3400408943181238, 3401823673561558,
+    3402512372170311, 3402845423280678, 3403406403174541, 3404190399593635,
+    3404436994958538, 3405143377697045, 3405321201446238, 3405807515014473,
+    3406197548320714, 3407016912999316, 3407131859842717, 3407236982169588,
+    3408505327253611, 3409123289343348, 3409139008569796, 3409312902512377,
+    3409538866392567, 3409629251944643, 3410079214801717, 3410130302287673,
+    3410371002942658, 3410507563722425, 3413016745244187, 3414347967234002,
+    3414826421189013, 3415074981457222, 3415425716697343, 3416716658169385,
+    3417100796765708, 3417358199098794, 3419452786022990, 3420558044132615,
+    3420628780651631, 3421157339640945, 3421497267912883, 3422155510520393,
+    3424364061836337, 3425957598417503, 3426208123589018, 3426466508373757,
+    3426687559995682, 3427085452915147, 3427088400270106, 3427839975784651,
+    3428150430506999, 3428235903800810, 3428270289608665, 3429497371723262,
+    3431439678641243, 3433069565933570, 3433281775490618, 3433668861441900,
+    3434837978908970, 3437100565065829, 3439371010835912, 3439707991752891,
+    3440841740960453, 3441931279843630, 3441983349781239, 3442506014060635,
+    3442584610192875, 3442966783885892, 3443244817703691, 3443539553199591,
+    3444986704484460, 3445167475588612, 3445664596125030, 3445809998969674,
+    3446435820672635, 3446860239786731, 3447047888052454, 3447342623548354,
+    3448303461264988, 3448824160641078, 3450462889998282, 3450871589885930,
+    3452136005163341, 3452922948937394, 3452951440035331, 3453669612193674,
+    3454742449398750, 3454751291463627, 3454764063335116, 3455629603241409,
+    3456444055661746, 3457289946534979, 3459046570090543, 3460066354906357,
+    3461080245012253, 3461327822828809, 3461827890720186, 3462128520926004,
+    3462457642229759, 3462871254375672, 3463573707307567, 3464474615473368,
+    3464480510183286, 3465376506090822, 3466839376602139, 3469619714780129,
+    3470023502409512, 3470592341916599, 3471011848772430, 3472334228697368,
+    3472626016838309, 3472938436463963, 3473570152876842, 3473847204242988,
+    3474592885047615, 3474886638091862, 3474916111641452, 3475590073475410,
+    3475655897736161, 3477594274847530, 3478363534491829, 3478936303805528,
+    3479861773262654, 3479988509525891, 3480089702046150, 3480549489419754,
+    3481225416157018, 3481708782370294, 3483040004360109, 3483093056749371,
+    3483241406948974, 3483705124129190, 3484197332407343, 3484655154877641,
+    3485909745638522, 3486205463586075, 3486376410173697, 3486509041146852,
+    3487289107759334, 3487543562737461, 3488030858757349, 3489213730547561,
+    3489963341158800, 3490163761296012, 3490398567241079, 3490490917696461,
+    3490574426086966, 3490922213972128, 3492785924757869, 3493506061819518,
+    3494044445325362, 3494169216685293, 3494787178775030, 3495354053378811,
+    3495821700365639, 3495957278693753, 3497612709729058, 3497909410128264,
+    3498486109248575, 3498617757770077, 3500109119379331, 3500127785960738,
+    3500136628025615, 3500889185991813, 3501353885623682, 3501423639691045,
+    3501712480477027, 3502123145267981, 3502563283608525, 3502755844132513,
+    3503472051387550, 3504404398006247, 3505206078555095, 3506361441699023,
+    3506399757313490, 3506609019515579, 3507523682004522, 3508851956639378,
+    3509391322596875, 3509477778342339, 3509701777319223, 3510039740687855,
+    3510737281361485, 3511388646807424, 3512483097948866, 3513370251791525,
+    3513535303669229, 3514716210556135, 3514887157143757, 3515062033537991,
+    3515590592527305, 3515846029957085, 3515926590992631, 3517619355190750,
+    3518683350330949, 3518903419501221, 3519514504429387, 3519945800705054,
+    3520373167174109, 3520406570530311, 3521911686462707, 3522160246730916,
+    3522601367523113, 3523220312064503, 3523281224066989, 3523876589768707,
+    3523883466930278, 3524457218695630, 3527517555594725, 3528214113816702,
+    3529198530373008, 3529863650142089, 3532697040709341, 3533180406922617,
+    3535164959261677, 3536078639298967, 3536385164214703, 3537764526335515,
+    3538044525056620, 3539072169485658, 3539592868861748, 3540173497788671,
+    3540739389940799, 3540842547364364, 3543705411481206, 3544173058468034,
+    3544298812279618, 3544871581593317, 3545863857762847, 3545876629634336,
+    3545989611574431, 3546092768997996, 3546197891324867, 3546951431742718,
+    3548007567269693, 3548792546140440, 3549136404218990, 3549619770432266,
+    3550915624162573, 3551498217992802, 3551988461367649, 3552742984237153,
+    3553956312028608, 3554158697069126, 3555871110300305, 3557004859507867,
+    3557405699782291, 3559376497798209, 3559581830193686, 3561680346924494,
+    3561704908215819, 3562186309525789, 3562398519082837, 3562855359101482,
+    3563590232937926, 3564746578533507, 3564801595826075, 3564944051315760,
+    3565176892357521, 3565650434054267, 3566148537042338, 3566191764915070,
+    3566282150467146, 3567417864578014, 3567466004709011, 3567695898395813,
+    3567869792338394, 3568016177634691, 3568512315719456, 3568551613785576,
+    3568867963217842, 3569464311371213, 3569617573829081, 3571247461121408,
+    3571658125912362, 3572374333167399, 3572515806205431, 3572767313828599,
+    3572818401314555, 3572914681576549, 3573369556691888, 3573541485731163,
+    3575079022568108, 3575945544926054, 3576511437078182, 3576577261338933,
+    3576799295412511, 3577381889242740, 3577573467315075, 3577695291320047,
+    3577939921781644, 3578282797408541, 3578390867090371, 3580040403415758,
+    3580745803702612, 3580950153646436, 3581545519348154, 3582130078081689,
+    3582745092816467, 3583704948081448, 3583890631443865, 3583920104993455,
+    3583943683833127, 3584187331843071, 3584269857781923, 3584302278686472,
+    3584456523595993, 3585884025847802, 3585938060688717, 3586568794649943,
+    3586623811942511, 3586732864075994, 3587285001904980, 3587391106683504,
+    3587457913395908, 3588784223127458, 3590320777512750, 3590960353538853,
+    3592589258379527, 3592918379683282, 3593195431049428, 3593309395441176,
+    3594372408129722, 3594621950849584, 3594926510862014, 3595082720674841,
+    3595271351392217, 3596470924860530, 3596624187318398, 3599167754648015,
+    3599757225639815, 3600472450443199, 3600976448141188, 3601001009432513,
+    3601630760942086, 3601762409463588, 3601964794504106, 3602366617230183,
+    3603122122551340, 3605103727535441, 3605348357997038, 3606304283455407,
+    3606432002170297, 3606743439344298, 3608072696430807, 3608358589861830,
+    3609478584746250, 3609599426299569, 3609921670441753, 3610676193311257,
+    3613563618719424, 3614045020029394, 3614105932031880, 3615519679960547,
+    3615990274302334, 3616415675868083, 3617451179910345, 3617472793846711,
+    3617506197202913, 3618579034407989, 3618735244220816, 3619363030827083,
+    3619844432137053, 3620042887370959, 3620621551394576, 3620736498237977,
+    3621668844856674, 3622326105012531, 3622572700377434, 3622694524382406,
+    3622834032517132, 3624853953115700, 3625847211736883, 3626147841942701,
+    3626320753433629, 3626693102610116, 3626810014356823, 3627237380825878,
+    3627494783158964, 3628103903183824, 3630083543264619, 3630688733482867,
+    3630901925491568, 3631564097905690, 3631649571199501, 3631759605784637,
+    3632567181043403, 3632845214861202, 3633078055902963, 3634984994561436,
+    3635257133669317, 3637121826906711, 3637372352078226, 3637553123182378,
+    3639231150605702, 3639364764030510, 3639919849214455, 3639975848958676,
+    3640051497735957, 3640307917617390, 3641120405134421, 3641474087729501,
+    3641553666313394, 3642180470468008, 3642477170867214, 3642831835913947,
+    3643022431534629, 3643656112850814, 3644613020760836, 3645245719625368,
+    3645929505975856, 3646928659306957, 3648828720803859, 3648988860423298,
+    3649243315401425, 3649273771402668, 3649659874902297, 3650148153373838,
+    3650774957528452, 3650797553916471, 3651025482699967, 3652139582874469,
+    3652779158900572, 3653098455687797, 3653281191695255, 3655284410615722,
+    3657423207864303, 3657581382580436, 3657953731756923, 3658147274732564,
+    3658348677321429, 3660556246185720, 3660801859098970, 3661321576023407,
+    3662404237745013, 3662508377620231, 3662595815817348, 3663291391587672,
+    3664340649953076, 3664726753452705, 3665105979790763, 3666023589634665,
+    3666330114550401, 3666920567993854, 3667075795355028, 3668839296072163,
+    3668977821755236, 3669031856596151, 3669577117263566, 3669633117007787,
+    3670659778985172, 3670713813826087, 3671285600688133, 3672040123557637,
+    3672624682291172, 3672906645915583, 3673852746857422, 3673869448535523,
+    3675208530138562, 3676185087081644, 3677788448179340, 3679049916101792,
+    3679325002564632, 3679365283082405, 3681083591023502, 3681840078796312,
+    3682020849900464, 3682571022826144, 3682684004766239, 3682820565546006,
+    3682872635483615, 3684353190124686, 3684843433499533, 3684910240211937,
+    3685503641010349, 3685994866836849, 3685999779095114, 3686730723124946,
+    3688308540479664, 3688896046568158, 3688972677797092, 3690587828314624,
+    3691188106274607, 3691453368220917, 3691851261140382, 3692254066318112,
+    3692531117684258, 3692943747378518, 3693070483641755, 3693349499911207,
+    3693625568825700, 3694155110266667, 3694239601108825, 3694345705887349,
+    3694623739705148, 3695376297671346, 3695401841414324, 3695888154982559,
+    3696101346991260, 3696361696679305, 3697440428594299, 3697841268868723,
+    3698083934427014, 3698303021145633, 3699201964408128, 3699259929055655,
+    3699855294757373, 3699907364694982, 3700209959804106, 3701479287339782,
+    3701762233415846, 3702988333078790, 3704287134164056, 3704531764625653,
+    3705087832261251, 3705488672535675, 3705795197451411, 3706266774244851,
+    3706296247794441, 3707409365517290, 3708056801156617, 3709211181848892,
+    3709339883015435, 3710776227332121, 3711444294456161, 3711890327506623,
+    3712181133195911, 3712401202366183, 3712432640819079, 3712466044175281,
+    3713239233626192, 3713289338660495, 3714133264630422, 3714284562184984,
+    3715692415403733, 3715934098510371, 3716446938273237, 3716480341629439,
+    3717368477923751, 3717845949427109, 3718089597437053, 3718099421953583,
+    3718456051903622, 3719084820961542, 3719235136064451, 3719729309245910,
+    3720462218179048, 3722490980842493, 3723946974192239, 3724426410598903,
+    3724727040804721, 3725206477211385, 3725220231534527, 3725896158271791,
+    3726393278808209, 3726739101790065, 3726845206568589, 3727862044029444,
+    3728760987291939, 3729487019063506, 3729994946568107, 3730062735732164,
+    3730080419861918, 3730676768015289, 3730714101178103, 3730891924927296,
+    3731324203654616, 3731751570123671, 3731827218900952, 3732178936592726,
+    3732318444727452, 3732387216343162, 3733274370185821, 3733337247091613,
+    3733385387222610, 3733850086854479, 3733864823629274, 3733913946211924,
+    3734521101333478, 3734567276561169, 3734936678382697, 3735008397353366,
+    3735698078413772, 3737476315905702, 3737922348956164, 3738767257377744,
+    3739818480646454, 3741995593509502, 3742245136229364, 3742679379859990,
+    3742705906054621, 3743090044650944, 3743906461974587, 3744489055804816,
+    3744710107426741, 3745229824351178, 3745312350290030, 3747206517077014,
+    3748897316371827, 3749233314837153, 3750264889072803, 3750458432048444,
+    3750511484437706, 3750566501730274, 3752106003470525, 3752426282709403,
+    3752658141299511, 3752870350856559, 3753066841187159, 3753741785472770,
+    3754177994006702, 3754561150151372, 3754946271199348, 3755049428622913,
+    3755459110962214, 3756020090856077, 3756276510737510, 3756648859913997,
+    3757065419414869, 3757110612190907, 3757381768847135, 3758013485260014,
+    3759571653581672, 3759669898746972, 3759789757848638, 3759844775141206,
+    3759896845078815, 3759899792433774, 3761152418291349, 3761190733905816,
+    3761396066301293, 3761413750431047, 3761554241017426, 3763749038010228,
+    3764187211447466, 3764990856899620, 3766086290492715, 3767040251047778,
+    3768578770336376, 3769239960298845, 3770191955950602, 3771933842731371,
+    3772458471914073, 3774093271464665, 3774331024764691, 3774873338077147,
+    3775814526760721, 3776294945619038, 3776921749773652, 3776946311064977,
+    3777352063597666, 3777478799860903, 3778699004813929, 3780181524358306,
+    3781993165206438, 3782088463016779, 3782781091432144, 3783384316747086,
+    3783445228749572, 3783543473914872, 3784098559098817, 3784540662342667,
+    3785670481743617, 3787397631749591, 3788033277969082, 3788173768555461,
+    3788979378910921, 3789293763439881, 3790218250445354, 3790668213302428,
+    3791201684550007, 3792063294649688, 3792141890781928, 3792240135947228,
+    3792584976477431, 3793031991979546, 3793078167207237, 3793829742721782,
+    3794308196676793, 3794347494742913, 3794444757456560, 3796146363719556,
+    3796147346171209, 3796981447624606, 3797627900812280, 3798948315833912,
+    3799277437137667, 3800313923631582, 3800731465584107, 3801489918260223,
+    3801535111036261, 3801894688341259, 3802013564991272, 3802522474947526,
+    3803583522732766, 3803716153705921, 3804265344179948, 3804276151148131,
+    3804921621884152, 3805386321516021, 3805752775982590, 3806933682869496,
+    3807126243393484, 3807346312563756, 3807871924198111, 3809283707223472,
+    3809333812257775, 3809479215102419, 3809784757566502, 3809867283505354,
+    3809879072925190, 3810182650485967, 3811562995058432, 3812840182207332,
+    3813238075126797, 3815883817428326, 3816806339530493, 3816929145987118,
+    3817374196585927, 3818245631202138, 3818459805662492, 3818528577278202,
+    3819120013173308, 3819840150234957, 3820185973216813, 3821147793385100,
+    3821453335849183, 3821586949273991, 3821792281669468, 3824148200733362,
+    3826467769086095, 3826724188967528, 3826817521874563, 3826934433621270,
+    3827444326029177, 3827530781774641, 3828000393664775, 3828219480383394,
+    3828933722735125, 3829319826234754, 3829617509085613, 3829729508574055,
+    3830348453115445, 3830718837388626, 3833323316720729, 3834417767862171,
+    3834867730719245, 3837110667843044, 3837128351972798, 3837227579589751,
+    3838295504536562, 3839058869470943, 3839504902521405, 3840518792627301,
+    3840684826956658, 3840880334835605, 3840997246582312, 3842003277074984,
+    3842878641497807, 3842928746532110, 3843726497274346, 3843838496762788,
+    3844245231747130, 3847519743106579, 3847786969956195, 3848351879656670,
+    3848503177211232, 3849929697011388, 3851103726736723, 3851385690361134,
+    3852206037491389, 3854297677060626, 3855149462643777, 3855865669898814,
+    3856074932100903, 3856337246692254, 3856931629942319, 3857449381963450,
+    3857611486486195, 3858255974770563, 3859627477278151, 3860336807371617,
+    3860357438856330, 3861316311669658, 3861533433484971, 3862005992730064,
+    3863037566965714, 3863714476154631, 3866954601706225, 3867046952161607,
+    3867916421874512, 3869229959734573, 3869837114856127, 3870823496315739,
+    3870850022510370, 3870923706384345, 3871491563439779, 3872537874450224,
+    3873378853065192, 3873691272690846, 3873792465211105, 3874073446383863,
+    3874744460862862, 3874771969509146, 3875276949658788, 3875841859359263,
+    3876635680294887, 3877824446795017, 3878032726545453, 3878034691448759,
+    3878515110307076, 3878913985678194, 3879199879109217, 3879262756015009,
+    3879414053569571, 3880285488185782, 3880497697742830, 3881435939071445,
+    3881729692115692, 3881900638703314, 3881961550705800, 3882447864274035,
+    3883783998522115, 3884847011210661, 3884917747729677, 3885064133025974,
+    3885259640904921, 3885504271366518, 3885505253818171, 3885597604273553,
+    3886050514485586, 3886425811017032, 3886546652570351, 3887397455701849,
+    3888071417535807, 3888096961278785, 3888232539606899, 3888292469157732,
+    3888549871490818, 3890528529119960, 3891526699999408, 3891688804522153,
+    3891703541296948, 3894985912269621, 3895288507378745, 3896080363411063,
+    3896136363155284, 3897166954939281, 3897175797004158, 3897257340491357,
+    3897807513417037, 3897810460771996, 3898698597066308, 3898984490497331,
+    3899179015924625, 3899231085862234, 3899327366124228, 3902238370372067,
+    3902614649355166, 3902843560590315, 3903141243441174, 3904390921943790,
+    3907421785293295, 3907608451107365, 3907797081824741, 3908697007538889,
+    3909435811181945, 3909739388742722, 3909791458680331, 3909812090165044,
+    3909932931718363, 3910663875748195, 3910979242728808, 3911944992703707,
+    3912530533888895, 3912568849503362, 3913763510713410, 3913846036652262,
+    3914589752553583, 3914674243395741, 3914908066889155, 3915926869253316,
+    3916533041923217, 3916951566327395, 3917532195254318, 3917820053588647,
+    3919001942927206, 3920672110737306, 3920988460169572, 3921424668703504,
+    3921441370381605, 3921455124704747, 3921457089608053, 3922757855596625,
+    3923108590836746, 3923851324286414, 3924362199145974, 3925087248465888,
+    3926220997673450, 3926791802083843, 3926802609052026, 3927551237211612,
+    3929290176637422, 3930054524023456, 3930106593961065, 3930307996549930,
+    3930709819276007, 3931314027042602, 3931804270417449, 3932060690298882,
+    3932218865015015, 3932421250055533, 3932964545819642, 3933044124403535,
+    3934568889368991, 3934637660984701, 3935069939712021, 3935489446567852,
+    3936885510366765, 3937486770778401, 3937801155307361, 3938138136224340,
+    3938734484377711, 3938817010316563, 3939531252668294, 3939741497322036,
+    3940143320048113, 3941418542293707, 3942785132543030, 3944011232205974,
+    3945145963865189, 3946121538356618, 3946462449080209, 3946751289866191,
+    3947139358269126, 3947683636484888, 3947786793908453, 3948985384925113,
+    3951597723870440, 3952229440283319, 3952329650351925, 3952656806752374,
+    3952711824044942, 3953299330133436, 3954493008891831, 3955007813558003,
+    3955269145697701, 3955338899765064, 3955573705710131, 3956373421355673,
+    3957655520762838, 3958237132141414, 3958834462746438, 3959465196707664,
+    3959868001885394, 3960182386414354, 3960761050437971, 3960850453538394,
+    3961111785678092, 3961244416651247, 3962623778772059, 3964303771098689,
+    3964674155371870, 3965638922895116, 3966005377361685, 3967087056631638,
+    3967171547473796, 3967560598328384, 3968268945970197, 3969276941366175,
+    3970359603087781, 3970473567479529, 3972923801902111, 3973572219993091,
+    3973768710323691, 3974485900030381, 3974771793461404, 3975195230123847,
+    3976606030697555, 3976715082831038, 3977959849075389, 3978314514122122,
+    3978699635170098, 3979364754939179, 3981256956822857, 3983227754838775,
+    3983320105294157, 3983330912262340, 3984140452424412, 3984438135275271,
+    3986330337158949, 3986782264919329, 3987416928687167, 3987656646890499,
+    3987991662904172, 3988276573883542, 3988302117626520, 3988527099055057,
+    3988580151444319, 3989251165923318, 3990386880034186, 3991832066415749,
+    3992836132005115, 3993395146995672, 3993405953963855, 3994359914518918,
+    3994370721487101, 3995198928230580, 3995663627862449, 3997291550251470,
+    3997600040070512, 3998267124742899, 3998360457649934, 3998545158560698,
+    3999123822584315, 4000229080693940, 4000369571280319, 4001453215453578,
+    4002258825809038, 4003172505846328, 4003294329851300, 4004237483438180,
+    4004502745384490, 4005598178977585, 4005732774854046, 4005763230855289,
+    4007597468091440, 4009394372164777, 4009673388434229, 4010409244722326,
+    4010689243443431, 4011360257922430, 4011442783861282, 4011545941284847,
+    4012006711110104, 4012084324790691, 4012606989070087, 4013038285345754,
+    4013789860860299, 4015135819624909, 4015616238483226, 4015950272045246,
+    4016063253985341, 4016332445738263, 4016577076199860, 4016842338146170,
+    4017142968351988, 4017376791845402, 4017901421028104, 4018617628283141,
+    4019239520179490, 4019887938270470, 4021030529542909, 4022603434639362,
+    4023371711832008, 4023671359586173, 4023757815331637, 4024810021052000,
+    4025356264171068, 4025913314258319, 4026289593241418, 4027037238949351,
+    4027057870434064, 4027304465798967, 4027638499360987, 4027777025044060,
+    4028243689579235, 4029448175305813, 4029786138674445, 4030226277014989,
+    4030826554974972, 4032165636578011, 4032618546790044, 4033036088742569,
+    4034581485192738, 4034711168810934, 4035883233632963, 4036389196234258,
+    4036792983863641, 4037968978492282, 4038898377756020, 4039116482022986,
+    4040103845934251, 4040737527250436, 4040824965447553, 4041875206264610,
+    4041886013232793, 4042862570175875, 4045433646151776, 4045634066288988,
+    4045716592227840, 4045920942171664, 4046211747860952, 4046454413419243,
+    4048438965758303, 4049932292270863, 4049997134079961, 4050895094890803,
+    4051570039176414, 4054389675420524, 4054931988732980, 4055069531964400,
+    4055273881908224, 4056126649943028, 4056491139506291, 4056699419256727,
+    4056721033193093, 4058076816474233, 4058288043579628, 4058708532887112,
+    4059761721059128, 4059946421969892, 4061469222032042, 4061668659717601,
+    4062272867484196, 4062955671383031, 4063730825737248, 4064630751451396,
+    4065425554838673, 4066261621195376, 4066515093721850, 4067076073615713,
+    4067654737639330, 4069170660539909, 4069306238868023, 4069727710627160,
+    4071852753552599, 4072931485467593, 4074163479840455, 4074195900745004,
+    4075021160133524, 4075616525835242, 4076151961986127, 4077149150413922,
+    4077486131330901, 4078238689297099, 4078822265578981, 4078946054487259,
+    4079014826102969, 4079183807787285, 4079220158498446, 4079273210887708,
+    4079812576845205, 4079846962653060, 4081172289932957, 4081222394967260,
+    4081311798067683, 4081776497699552, 4082456354243428, 4083077263688124,
+    4083164701885241, 4083630383968763, 4083942803594417, 4084493958971750,
+    4084678659882514, 4084893816794521, 4085052973962307, 4088297029320513,
+    4089544742919823, 4089738285895464, 4089810987317786, 4091167753050579,
+    4091385857317545, 4091504733967558, 4091741504815931, 4091809293979988,
+    4092986271060282, 4094567035769959, 4095209559151021, 4095688995557685,
+    4095708644590745, 4097314953043400, 4097560565956650, 4097783582481881,
+    4098126458108778, 4098509614253448, 4099645328364316, 4100181746966854,
+    4100656271115253, 4101117040940510, 4101678020834373, 4102339210796842,
+    4102610367453070, 4103478854714322, 4103615415494089, 4104497657078483,
+    4106713085555998, 4106932172274617, 4107097224152321, 4107268170739943,
+    4107472520683767, 4107753501856525, 4107809501600746, 4107904799411087,
+    4108174973615662, 4110385489834912, 4110878680564718, 4110987732698201,
+    4111151802124252, 4111726536341257, 4113545054350960, 4114753469884150,
+    4114820276596554, 4115698588374336, 4115728061923926, 4116422655242597,
+    4116651566477746, 4117073038236883, 4117277388180707, 4118622364493664,
+    4119953586483479, 4120785723033570, 4121544175709686, 4121977436888659,
+    4123994410132268, 4124151602396748, 4125279456894392, 4125734332009731,
+    4126185277318458, 4126248154224250, 4126536995010232, 4126756081728851,
+    4129887155146962, 4129895997211839, 4132287284535241, 4133571348845712,
+    4134075346543701, 4134206012613550, 4135313235626481, 4136435195414207,
+    4137490348489529, 4137657365270539, 4138041503866862, 4138243888907380,
+    4138253713423910, 4138514063111955, 4140108582144774, 4140306054927027,
+    4140808087721710, 4141428997166406, 4142527378114460, 4143053972200468,
+    4143780003972035, 4144160212761746, 4144493263872113, 4145008068538285,
+    4145180980029213, 4145241892031699, 4146450307564889, 4146615359442593,
+    4146836411064518, 4146847218032701, 4147484829155498, 4148010440789853,
+    4149761169635499, 4153445363334249, 4154784444937288, 4155019250882355,
+    4155024163140620, 4155038899915415, 4155339530121233, 4157140364001182,
+    4157837904674812, 4162290375566208, 4164275910356921, 4166003060362895,
+    4166456953026581, 4167251756413858, 4167272387898571, 4167440387131234,
+    4168219471292063, 4168329505877199, 4169164589782249, 4170553776419591,
+    4171369211291581, 4171676718658970, 4173016782713662, 4173689762095967,
+    4173733972420352, 4173815515907551, 4174267443667931, 4174804844722122,
+    4174975791309744, 4175308842420111, 4175497473137487, 4176776625189693,
+    4179359490585430, 4179780962344567, 4180146434359483, 4181785163716687,
+    4182298985931206, 4182417862581219, 4183186139773865, 4183511331271008,
+    4183742207409463, 4184446625244664, 4184683396093037, 4186684650110198,
+    4186798614501946, 4187388085493746, 4188665272642646, 4190265686385383,
+    4190352142130847, 4190648842530053, 4191313962299134, 4191350313010295,
+    4191934871743830, 4193299497089847, 4194223984095320, 4194474509266835,
+    4194911700252420, 4197988738829616, 4198491754075952, 4200673779197265,
+    4200922339465474, 4201850756277559, 4202125842740399, 4202384227525138,
+    4203484573376498, 4203534678410801, 4203817624486865, 4204360920250974,
+    4204654673295221, 4206030105609421, 4207087223588049, 4207163854816983,
+    4207292555983526, 4208026447368317, 4208725952945253, 4208981390375033,
+    4210610295215707, 4211182082077753, 4211674290355906, 4212079060436942,
+    4212221515926627, 4212803127305203, 4213324809132946, 4215944025239844,
+    4216337988352697, 4217678052407389, 4218001279001226, 4218686047803367,
+    4219309904603022, 4220491793941581, 4221371088171016, 4222478311183947,
+    4223009817528220, 4223200413148902, 4224159285962230, 4224441249586641,
+    4224897107153633, 4224949177091242, 4224980615544138, 4225673243959503,
+    4225869734290103, 4226009242424829, 4226548608382326, 4226969097689810,
+    4228293442518054, 4228790563054472, 4229030281257804, 4230466625574490,
+    4230926412948094, 4231300727027887, 4232586756241664, 4232619177146213,
+    4234332572829045, 4235316989385351, 4235611724881251, 4236990104550410,
+    4237748557226526, 4239181954188253, 4239797951374684, 4240042581836281,
+    4240329457718957, 4240846227288435, 4240936612840511, 4241820819328211,
+    4242131274050559, 4242703060912605, 4244111896583007, 4246227114991916,
+    4246371535384907, 4248318754561153, 4249517345577813, 4250227658122932,
+    4250499797230813, 4251382038815207, 4251845755995423, 4252025544647922,
+    4253945255177884, 4254432551197772, 4254669322046145, 4257080258402607,
+    4257176538664601, 4258384954197791, 4259165020810273, 4260280103436428,
+    4260594487965388, 4261125994309661, 4261269432250999, 4261610342974590,
+    4264949696143137, 4265356431127479, 4265516570746918, 4265681622624622,
+    4265824078114307, 4267150387845857, 4267233896236362, 4270975072130986,
+    4271249176142173, 4272254224183192, 4272653099554310, 4272775906010935,
+    4273216044351479, 4274886212161579, 4276851115467579, 4277134061543643,
+    4277434691749461, 4277493638848641, 4277739251761891, 4279417279185215,
+    4279604927450938, 4284169397830776, 4284212625703508, 4285207849227997,
+    4285346374911070, 4285542865241670, 4285703004861109, 4286269879464890,
+    4287383979639392, 4287492049321222, 4288634640593661, 4290061160393817,
+    4290096528653325, 4290870700555889, 4292516307074664, 4293016374966041,
+    4293044866063978, 4293169637423909, 4293244303749537, 4293743389189261,
+    4294675735807958, 4296277132002348, 4297463933599172, 4297665336188037,
+    4298914032239000, 4299061399986950, 4301131425619821, 4301556827185570,
+    4302104052756291, 4303003978470439, 4303249591383689, 4303403836293210,
+    4303974640703603, 4304402007172658, 4306918065855991, 4308279743847049,
+    4309588369448845, 4310116928438159, 4311681973921388, 4312794109192584,
+    4312882529841354, 4313176282885601, 4313898384850556, 4314568416877902,
+    4314815994694458, 4315042941026301, 4316462583664886, 4316969528717834,
+    4317013739042219, 4317633666035262, 4318939344282099, 4318945238992017,
+    4319163343258983, 4319854989222695, 4320055409359907, 4321876874724569,
+    4322201083770059, 4322771888180452, 4324197425528955, 4326006119022128,
+    4326653554661455, 4328019162459125, 4330867289801172, 4331475427374379,
+    4331542234086783, 4334219414841208, 4334947411516081, 4335044674229728,
+    4335428812826051, 4335702916837238, 4335855196843453, 4336227546019940,
+    4336717789394787, 4337091121022927, 4337524382201900, 4340427526836515,
+    4340875524790283, 4341989624964785, 4342590885376421, 4343851370847220,
+    4344027229693107, 4344455578613815, 4344553823779115, 4345856554670993,
+    4346209254814420, 4346355640110717, 4346745673416958, 4347503143641421,
+    4347911843529069, 4348095561988180, 4348217385993152, 4348696822399816,
+    4348912961763476, 4349748045668526, 4350438709180585, 4350608673316554,
+    4351774843428665, 4351942842661328, 4351995895050590, 4352039122923322,
+    4352213999317556, 4352755330178359, 4352890908506473, 4352928241669287,
+    4353993219261139, 4354102271394622, 4354737917614113, 4355118126403824,
+    4356373699616358, 4357909271549997, 4358589128093873, 4359937051761789,
+    4360236699515954, 4361535500601220, 4361757534674798, 4362373531861229,
+    4362693811100107, 4362850020912934, 4366451688672832, 4366627547518719,
+    4366732669845590, 4366787687138158, 4368357644879652, 4368996238454102,
+    4372318889944548, 4372648011248303, 4372876922483452, 4373132359913232,
+    4373156921204557, 4373909479170755, 4374919439470039, 4374956772632853,
+    4375867505315184, 4376789044965698, 4377724338939354, 4380299344721867,
+    4384310694821066, 4384677149287635, 4384696798320695, 4385861985981153,
+    4385922897983639, 4387264926941637, 4387487943466868, 4387916292387576,
+    4388538184283925, 4388589271769881, 4389183655019946, 4389719091170831,
+    4389825195949355, 4389918528856390, 4390431368619256, 4390901962961043,
+    4391179014327189, 4391857888419412, 4393122303696823, 4393339425512136,
+    4393551635069184, 4393633178556383, 4395872185873570, 4396590358031913,
+    4396665024357541, 4397041303340640, 4397388108774149, 4398476665205673,
+    4398625997856929, 4398700664182557, 4399786273259122, 4400550620645156,
+    4400821777301384, 4400888584013788, 4401192161574565, 4401365073065493,
+    4401752159016775, 4401955526508946, 4402477208336689, 4402551874662317,
+    4403334888629758, 4404054043239754, 4405082670120445, 4405624983432901,
+    4406784276383441, 4406944416002880, 4409033108217158, 4411024537717789,
+    4411196466757064, 4411342852053361, 4412303689769995, 4413077861672559,
+    4416585214073769, 4417137351902755, 4419004992495108, 4420116145314651,
+    4420657476175454, 4421071088321367, 4422795290972382, 4423255078345986,
+    4424530300591580, 4424531283043233, 4424792615182931, 4425274016492901,
+    4425904750454127, 4426073732138443, 4427434427677848, 4429210700266472,
+    4429505435762372, 4430489852318678, 4431630478687811, 4432405633042028,
+    4432502895755675, 4433257418625179, 4433282962368157, 4433762398774821,
+    4434988498437765, 4435744986210575, 4435838319117610, 4437150874526018,
+    4437187225237179, 4439041111506390, 4439900756702765, 4440203351811889,
+    4440753524737569, 4442943409472106, 4443433652846953, 4443447407170095,
+    4445252170856656, 4445909431012513, 4448648506221077, 4449264503407508,
+    4449307731280240, 4450354042290685, 4452362173469417, 4454466584910143,
+    4455220125327994, 4455261388297420, 4455725105477636, 4457701798203472,
+    4459599894797068, 4461562833199762, 4462364513748610, 4463276228882594,
+    4463400017790872, 4463952155619858, 4464354960797588, 4464580924677778,
+    4465177272831149, 4465586955170450, 4465862041633290, 4466174461258944,
+    4467177544396657, 4467926172556243, 4467976277590546, 4468526450516226,
+    4468742589879886, 4469000974664625, 4470581739374302, 4470710440540845,
+    4474961508843376, 4476109012374080, 4476226906572440, 4478353914401185,
+    4478358826659450, 4478940438038026, 4480062397825752, 4483187576533945,
+    4483895924175758, 4484593464849388, 4485498302821801, 4486530859509104,
+    4486633034481016, 4488326781130788, 4491555117262546, 4491887185921260,
+    4492021781797721, 4492101360381614, 4493269495397031, 4494654752227761,
+    4495480994067934, 4495794396145241, 4496234534485785, 4496879022770153,
+    4496908496319743, 4497112846263567, 4497608001896679, 4497886035714478,
+    4498624839357534, 4498981469307573, 4499265397835290, 4499758588565096,
+    4500766583961074, 4501078021135075, 4501094722813176, 4502621452681938,
+    4502850363917087, 4502969240567100,    3103911929657,    3165806383796,
+       5139551754673,    6246774767604,    7183051192913,    7618277275192,
+      11115805159872,   12107098877749,   13707512620486,   14383439357750,
+      15127155259071,   19165031552901,   19852747710001,   21414845838271,
+      22037720186273,   23208802556649,   25550967297401,   26468577141303,
+      27508010990177,   29677264240001,   30563435631007,   31713886516670,
+      31951639816696,   33180686834599,   33190511351129,   33632614594979,
+      34419558369032,   34441172305398,   38068383808274,   41171948580101,
+      41529560981793,   44597757494112,   45032001124738,   45064422029287,
+      45351297911963,   46735572291040,   47211078891092,   51669444492406,
+      51751970431258,   51898355727555,   51990706182937,   53810206644293,
+      54353502408402,   55365427610992,   55928372408161,   56197564161083,
+      56699596955766,   58649763486971,   58720500005987,   59418040679617,
+      59492707005245,   62023502463373,   66428815675425,   66454359418403,
+      67096882799465,   67154847446992,   67546845656539,   67727616760691,
+      68254210846699,   68494911501684,   71935457190490,   71951176416938,
+      73049557364992,   74800286210638,   75281687520608,   75944842386383,
+      76719014288947,   77456835480350,   79506229628508,   81988884955639,
+      83108879840059,   84433224668303,   84557996028234,   84749574100569,
+      84795749328260,   85271255928312,   89137203182867,   89255097381227,
+      91149264168211,   92013821622851,   92247645116265,   93823497567677,
+      94024900156542,   94200759002429,   94325530362360,   97456603780471,
+      98696457766557,   98854632482690,   99483401540610,  100161293181180,
+     100650554104374,  101183042900300,  102831596774034,  103302191115821,
+     103461348283607,  107191717210048,  107819503816315,  108031713373363,
+     108199712606026,  110442649729825,  111074366142704,  112214010060184,
+     112336816516809,  112871270216041,  113451899142964,  114767401906331,
+     115956168406461,  116579042754463,  117493705243406,  119243451637399,
+     119384924675431,  119930185342846,  120525551044564,  122723295392325,
+     123602589621760,  123767641499464,  125599913832309,  125606790993880,
+     126271910762961,  126376050638179,  128140533806967,  130885503725449,
+     132490829726451,  133950752882809,  134108927598942,  135170957835835,
+     136062041485106,  137270457018296,  138048558727472,  138199856282034,
+     140570512120723,  140974299750106,  141056825688958,  142980466025532,
+     143202500099110,  144050355875649,  144479687248010,  146353222550281,
+     150096363348211,  152083863042230,  152267581501341,  154664763534661,
+     157349803902310,  159102497651262,  162289570813594,  162733638960750,
+     162769989671911,  163358478212058,  163784862229460,  164512858904333,
+     164556086777065,  164784998012214,  167513266252595,  167715651293113,
+     167930808205120,  169183434062695,  169292486196178,  169386801554866,
+     169690379115643,  169769957699536,  170366305852907,  172064964760944,
+     172446156002308,  172519839876283,  172584681685381,  175057512495982,
+     175614562583233,  175699053425391,  175767825041101,  176565575783337,
+     176594066881274,  178200375333929,  178723039613325,  179695666749795,
+     180993485383408,  181039660611099,  182038813942200,  182219585046352,
+     182607653449287,  182812985844764,  183148001858437,  183540000067984,
+     183998804989935,  184105892220112,  184717959599931,  188583906854486,
+     189797234645941,  191012527340702,  191194280896507,  191381929162230,
+     192985290259926,  193214201495075,  194563107614644,  195757768824692,
+     196007311544554,  196079030515223,  197086043459548,  197449550571158,
+     197616567352168,  199454734394931,  201337111762079,  201429462217461,
+     201718303003443,  202532755423780,  203196892741208,  206549017781244,
+     207065787350722,  207222979615202,  207332031748685,  207497083626389,
+     211637134892131,  212035027811596,  212587165640582,  213089198435265,
+     213349548123310,  213395723351001,  213414389932408,  214469543007730,
+     214470525459383,  216122026688076,  217057320661732,  217688054622958,
+     217790229594870,  217825597854378,  219568467086800,  223859815907104,
+     224692934908848,  225359037129582,  226072297029660,  226371944783825,
+     226480014465655,  227297414240951,  227936990267054,  228641408102255,
+     230572908052053,  230800836835549,  230939362518622,  231366728987677,
+     235490078575318,  236111970471667,  238703677932281,  240813001631272,
+     241569489404082,  241767944637988,  242932149846793,  244054109634519,
+     244719229403600,  247312901767520,  248253107999441,  248625457175928,
+     248689316533373,  249675697992985,  250013661361617,  250168888722791,
+     250804534942282,  252385299651959,  254645920905512,  254800165815033,
+     255706968690752,  255764933338279,  256009563799876,  256090124835422,
+     256130405353195,  257061769520239,  263794510698248,  263898650573466,
+     265092329331861,  265638572450929,  266176955956773,  268721505738043,
+     269676448744759,  272029420453694,  272509839312011,  273196573017458,
+     274088639118382,  274217340284925,  274387304420894,  274713478369690,
+     275435580334645,  276549680509147,  277342518993118,  278183497608086,
+     279049037514379,  281661376459706,  281984603053543,  282790213409003,
+     283563402859914,  283990769328969,  284852379428650,  286336863876333,
+     286650265953640,  287304578754538,  287457841212406,  287464718373977,
+     287557068829359,  289276359222109,  289747936015549,  289845198729196,
+     290042671511449,  290172355129645,  291480980731441,  292838728915887,
+     293546094106047,  293605041205227,  294787912995439,  295764469938521,
+     296013030206730,  297943547704875,  300034204822459,  301291742938299,
+     301469566687492,  302568930087199,  303475732962918,  305346320910230,
+     306275720173968,  306545894378543,  308492131103136,  309789949736749,
+     310838225650500,  311799063367134,  311846221046478,  312655761208550,
+     313129302905296,  313459406660704,  314270911726082,  314758207745970,
+     315540239261758,  315601151264244,  315622765200610,  317112161906558,
+     318307805568259,  318529839641837,  324376409428840,  325511141088055,
+     326119278661262,  326319698798474,  326653732360494,  327275624256843,
+     327589026334150,  328631407537983,  330221014312537,  330399820513383,
+     330693573557630,  331222132546944,  332477705759478,  332993492877303,
+     335825900992902,  335911374286713,  336435021017762,  337099158335190,
+     337429262090598,  337581542096813,  339113184223840,  339678093924315,
+     340918930362054,  341151771403815,  342018293761761,  342658852239517,
+     343842706481382,  344141371783894,  344342774372759,  344486212314097,
+     345866556886562,  346947253704862,  347032726998673,  347267532943740,
+     347497426630542,  348998612756326,  349198050441885,  349760012787401,
+     352877331882370,  353373469967135,  354048414252746,  354157466386229,
+     354398167041214,  355170374040472,  355730371482682,  357106786248535,
+     357220750640283,  359444038731022,  359591406478972,  359861580683547,
+     360762488849348,  361160381768813,  362159535099914,  362586901568969,
+     363149846366138,  363807106521995,  364854399984093,  364898610308478,
+     365219871999009,  365795588667667,  366641479540900,  366647374250818,
+     368907013052718,  369099573576706,  370188130008230,  370956407200876,
+     371192195597596,  372209033058451,  374311479595871,  374651407867809,
+     376132944960533,  381551165826828,  381861620549176,  383768559207649,
+     384776554603627,  384910168028435,  385171500168133,  386034092719467,
+     386279705632717,  387362367354323,  388238714228799,  388913658514410,
+     389053166649136,  389593515058286,  392433782787109,  392922061258650,
+     393981144140584,  394704228557192,  395201349093610,  396246677652402,
+     397145620914897,  398372703029494,  399307014551497,  399648907726741,
+     400020274451575,  400632341831394,  402408614420018,  402888050826682,
+     403081593802323,  404049308680528,  404128887264421,  404473727794624,
+     404845094519458,  405913019466269,  406503472909722,  406571262073779,
+     406643963496101,  406892523764310,  407304171006917,  407327749846589,
+     407443679141643,  408542060089697,  409013636883137,  413914105728301,
+     414651926919704,  416031289040516,  416762233070348,  419372607112369,
+     420479830125300,  422074349158119,  422830836930929,  423329922370653,
+     423799534260787,  424030410399242,  424434198028625,  425297773031612,
+     425828296924232,  427097624459908,  428379723867073,  428639091103465,
+     428652845426607,  430245399556120,  431486235993859,  432579704683648,
+     434008189387110,  435211692662035,  435356113055026,  442541764445068,
+     442637062255409,  442949481881063,  444038038312587,  444702175630015,
+     446508904219882,  447516899615860,  447575846715040,  448255703258916,
+     448475772429188,  448723350245744,  449736257899987,  450773726845555,
+     451823967662612,  453650345285539,  454599393582337,  454643603906722,
+     454839111785669,  455185917219178,  455261565996459,  456719524249511,
+     457480924280586,  457713765322347,  457798256164505,  458037974367837,
+     459081338023323,  459708142177937,  460645401054899,  460774102221442,
+     462812689401417,  467922420448670,  469556237547609,  469965919886910,
+     470026831889396,  470989634509336,  472439733149164,  472756082581430,
+     474272987933662,  474611933753947,  474740634920490,  474856564215544,
+     475268211458151,  476199575625195,  476535574090521,  476748766099222,
+     477056273466611,  478898370315986,  479234368781312,  479442648531748,
+     480297381469858,  480948746915797,  481483200615029,  482205302579984,
+     482575686853165,  484292029890956,  484351959441789,  485519112005553,
+     486046688543214,  486963315935463,  488117696627738,  488127521144268,
+     488285695860401,  488741553427393,  489272077320013,  489910670894463,
+     490681895442068,  491284138305357,  492007222721965,  493082024830347,
+     494888753420214,  495270927113231,  496918498535312,  497095339832852,
+     497642565403573,  498886349196271,  498982629458265,  499290136825654,
+     499581924966595,  499846204461252,  500025993113751,  500295184866673,
+     500691112882832,  500947532764265,  502495876569393,  505235934229610,
+     505771370380495,  505836212189593,  509589177504053,  510244472756604,
+     511692606493126,  511918570373316,  512306638776251,  512327270260964,
+     513516036761094,  514838416686032,  515326695157573,  516183392998989,
+     516500724882908,  517580439249555,  520755722992051,  520898178481736,
+     520990528937118,  521008213066872,  521174247396229,  521562315799164,
+     521758806129764,  522862099336083,  523294378063403,  524009602866787,
+     524024339641582,  525863489135998,  526729029042291,  527888321992831,
+     528308811300315,  528925790938399,  530973220183251,  532247459977192,
+     532992158330166,  533826259783563,  534827378017970,  534872570794008,
+     535049412091548,  535575023725903,  536313827368959,  536461195116909,
+     537620488067449,  538893745409737,  542133870961331,  542911972670507,
+     542972884672993,  543089796419700,  543230287006079,  543380602108988,
+     543406145851966,  543657653475134,  544133160075186,  547403741628023,
+     547872371066504,  548226053661584,  548290895470682,  548643595614109,
+     550732287828387,  550856076736665,  550938602675517,  551364004241266,
+     551461266954913,  551523161409052,  553510661103071,  553565678395639,
+     556391209349667,  557109381508010,  557395274939033,  557513169137393,
+     557637940497324,  558418989561459,  558573234470980,  559563545737204,
+     560587260359630,  561596238207261,  562732934769782,  562873425356161,
+     563578825643015,  564853065436956,  565405203265942,  566791442548325,
+     566872986035524,  567120563852080,  567300352504579,  568928274893600,
+     571123071886402,  571148615629380,  573434780625911,  574141163364418,
+     575408525996788,  575573577874492,  575682630007975,  576612029271713,
+     577171044262270,  577338061043280,  578098478622702,  578222267530980,
+     581051728291620,  581156850618491,  581816075677654,  582532282932691,
+     582713054036843,  582802457137266,  583891996020443,  584955991160642,
+     585201604073892,  585440339825571,  585751776999572,  588638219956086,
+     589040042682163,  589735618452487,  589838775876052,  590247475763700,
+     590917507791046,  592420658820136,  592569991471392,  594333492188527,
+     596051800129624,  597086321720233,  597289689212404,  597564775675244,
+     598454876872862,  598663156623298,  600143711264369,  601313811183092,
+     603658923278803,  604008676067271,  604311271176395,  604415411051613,
+     604716041257431,  604734707838838,  606655400820453,  609089916016587,
+     610301278904736,  616715705747173,  617072335697212,  617089037375313,
+     617990927992767,  618801450606492,  618917379901546,  619693516707416,
+     620988387986070,  621095475216247,  622216452552320,  623090834523490,
+     623219535690033,  624253074828989,  625960575801903,  626081417355222,
+     626216013231683,  626794677255300,  626820220998278,  627941198334351,
+     629351016456406,  631000552781793,  631177394079333,  634363484790012,
+     636738070435313,  637565294727139,  638174414751999,  639970336373683,
+     640167809155936,  640796578213856,  644840349217604,  644992629223819,
+     645674450671001,  647119637052564,  648103071157217,  650040465816933,
+     650631901712039,  652910207095346,  653053645036684,  653540941056572,
+     654705146265377,  655171810800552,  656492225822184,  656869487256936,
+     657620080319828,  659569264399380,  661394659570654,  662140340375281,
+     666517162489396,  667187194516742,  667214703163026,  668213856494127,
+     668344522563976,  669526411902535,  670561915944797,  670800651696476,
+     671042334803114,  672618187254526,  672919799911997,  672932571783486,
+     673410043286844,  676813255812836,  680947412368660,  681776601563792,
+     681830636404707,  683721855836732,  684249432374393,  684465571738053,
+     685409707776586,  686393141881239,  687256716884226,  687945415492979,
+     688213624794248,  689471162910088,  690833823352799,  692383149609580,
+     693072830669986,  693666231468398,  693721248760966,  696423973258369,
+     696427903064981,  697915334867623,  700138622958362,  700213289283990,
+     700958970088617,  701795036445320,  702700856869386,  705004705995671,
+     705208073487842,  705608913762266,  706851715103311,  710524119382225,
+     710678364291746,  710713732551254,  711875972856753,  711920183181138,
+     712459549138635,  714154278240060,  714260383018584,  715149501764549,
+     715909919343971,  716894335900277,  717908226006173,  718527170547563,
+     718617556099639,  719451657553036,  720018532156817,  722655432393469,
+     724683212605261,  725467209024355,  725610646965693,  725775698843397,
+     725989873303751,  726206012667411,  726611765200100,  729342980795440,
+     732283458592869,  733470260189693,  735748565573000,  735855652803177,
+     736387159147450,  737328347831024,  737558241517826,  737679083071145,
+     738042590182755,  738417886714201,  743482424985416,  743577722795757,
+     744486490574782,  745998483668749,  746414060717968,  751270319238747,
+     751908912813197,  752048420947923,  752883504852973,  753527993137341,
+     754162656905179,  754308059749823,  755284616692905,  757655272531594,
+     758207410360580,  758901021227598,  760576101295963,  761201922998924,
+     761356167908445,  762602899056102,  762613706024285,  762857354034229,
+     764189558475697,  764695521076992,  765201483678287,  766421688631313,
+     766638810446626,  767307860022319,  767887506497589,  768905326410097,
+     770109812136675,  771976470277375,  772673028499352,  772954009672110,
+     773300815105619,  773609304924661,  777901636196618,  778261213501616,
+     778845772235151,  783208840026124,  784336694523768,  784787639832495,
+     788072958160127,  789023971360231,  789247970337115,  791159821253853,
+     791397574553879,  792744515770142,  795470819107217,  800765251065234,
+     801210301664043,  801243705020245,  802718364951398,  803819693254411,
+     803832465125900,  806541084333221,  806736592212168,  809633842136865,
+     810436505137366,  810444364750590,  810586820240275,  810808854313853,
+     813152001506258,  813529262941010,  813725753271610,  814208137033233,
+     814317189166716,  816121952853277,  816364618411568,  816644617132673,
+     816959001661633,  817382438324076,  819051623682523,  820368108897543,
+     821968522640280,  822191539165511,  823013851199072,  824166266988041,
+     825811873506816,  826584080506074,  826646957411866,  827712917455371,
+     829221963194379,  829647364760128,  830028556001492,  830642588284617,
+     830808622613974,  831303778247086,  835853511852129,  836794700535703,
+     837325224428323,  838885357653287,  839547530067409,  840610542755955,
+     840981909480789,  841960431327177,  842360289149948,  847023987146739,
+     849736536160672,  850280814376434,  851996174962572,  853286133982961,
+     855538895623290,  856007525061771,  856102822872112,  856836714256903,
+     857386887182583,  859143510738147,  860047366258907,  862477951648429,
+     863053668317087,  864994992783415,  865715129845064,  867961996775475,
+     868071048908958,  868598625446619,  869035816432204,  869913145758333,
+     870711878952222,  871287595620880,  873730952881891,  873825268240579,
+     874155371995987,  874547370205534,  876296134147874,  879401663823007,
+     880181730435489,  881026638857069,  884396448026859,  887723029323917,
+     889988562835735,  891163575012723,  891575222255330,  891635151806163,
+     892026167564057,  892876970695555,  893683563502668,  895921588368202,
+     899495747481816,  899906412272770,  900357357581497,  900777846888981,
+     900948793476603,  901050968448515,  902583593027195,  903312572153721,
+     905305966557658,  905530947986195,  906663714742104,  907699218784366,
+     910025664298670,  913903400973061,  914897642045897,  915129500636005,
+     916578616824180,  917817488358613,  918772431365329,  921318946049905,
+     923611005756354,  927295199455104,  928500667633335,  929248313341268,
+     929354418119792,  930304448868243,  931366479105136,  933244926665672,
+     933899239466570,  935466249853105,  936017405230438,  940861874331381,
+     944040105428836,  947188862976701,  948305910506162,  948428716962787,
+     950036007867095,  950091025159663,  950319936394812,  952309400992137,
+     954109252420433,  954114164678698,  955599631578034,  957056607379433,
+     957698148308842,  958328882270068,  959291684890008,  960136593311588,
+     960560029974031,  961714410666306,  962682125544511,  964537976717028,
+     965484077658867,  969032693029503,  970433669086681,  971921100889323,
+     972874078992733,  975175963215712,  975308594188867,  976450203009653,
+     976843183670853,  977283322011397,  977356023433719,  978375808249533,
+     979474189197587,  979626469203802,  979800363146383,  981645407350717,
+     983321469870735,  983489469103398,  984582937793187,  985428828666420,
+     986911348210797,  988315271622934,  988577586214285,  990518910680613,
+     991470906332370,  995081416157145,  995373204298086,  995582466500175,
+     996572777766399,  996762390935428,  999021047285675, 1001639280940920,
+    1003127695195215, 1004216251626739, 1006909151607612, 1009469420615330,
+    1009811313790574, 1010211171613345, 1011923584844524, 1017355560033961,
+    1020811824949215, 1022936867874654, 1023759179908215, 1024145283407844,
+    1024286756445876, 1024848718791392, 1027899231173957, 1027993546532645,
+    1028725473014130, 1029538942982814, 1030090098360147, 1033016821834434,
+    1034983690043740, 1035732318203326, 1036735401341039, 1036828734248074,
+    1037881922420090, 1040078684316198, 1041118118165072, 1043472072325660,
+    1043790386661232, 1044291437004262, 1044994872387810, 1046076551657763,
+    1047172967702511, 1052397645593165, 1053676797645371, 1056054330645631,
+    1057220500757742, 1057638042710267, 1058086040664035, 1058614599653349,
+    1068469572184592, 1069187744342935, 1069648514168192, 1069817495852508,
+    1071410049982021, 1071554470375012, 1072702956357369, 1073101831728487,
+    1073111656245017, 1075306453237819, 1076015783331285, 1076956972014859,
+    1078739139313401, 1080035975495361, 1080160746855292, 1080660814746669,
+    1081506705619902, 1082581507728284, 1083271188788690, 1083412661826722,
+    1084240868570201, 1084532656711142, 1087356222761864, 1087778676972654,
+    1088580357521502, 1089398739748451, 1089440985169530, 1092981740926942,
+    1093887561351008, 1095751272136749, 1098250629141981, 1098294839466366,
+    1099008099366444, 1099565149453695, 1101782542834516, 1103435026514862,
+    1114118205789584, 1114282275215635, 1114353011734651, 1114713571491302,
+    1115460234747582, 1116456440723724, 1117690399999892, 1118714114622318,
+    1118896850629776, 1122532904197529, 1123588057272851, 1123610653660870,
+    1125183558757323, 1187444467362892, 1193610333937120, 1199007923318702,
+    1212455721544966, 1218109730807981, 1219588320545746, 1225424083364566,
+    1229895220837369, 1230879637393675, 1242185691016399, 1263800609834052,
+    1280194780567663, 1281685159725264, 1285742685052154, 1287814675588331,
+    1289099722350455, 1302311732179999, 1321834028976762, 1323381390330237,
+    1325385591702357, 1331580931826175, 1333752149979305, 1343431263664661,
+    1346433635916229, 1358936315652307, 1361224445552144, 1361264726069917,
+    1376484867078193, 1377189284913394, 1380551234469960, 1381474739023780,
+    1389139826820486, 1392489986957216, 1394658257755387, 1401805593530962,
+    1401947066568994, 1415349672019220, 1416391070771400, 1425554397338931,
+    1427504563870136, 1427830737818932, 1430000973520409, 1437642482477443,
+    1442299303312663, 1445957953268435, 1449156815850603, 1453723251133747,
+    1456721693578703, 1464482079185750, 1465755336528038, 1469384512934220,
+    1474226034680204, 1477090863700352, 1483732236874632, 1490454171084458,
+    1493542999081490, 1495112956822984, 1500185354707423, 1504931578643066,
+    1511296882902853, 1515235531579730, 1515900651348811, 1516684647767905,
+    1520192982620768, 1525814570979234, 1527067196836809, 1528066350167910,
+    1533844148339203, 1535537894988975, 1543788523970869, 1543836664101866,
+    1546678896733995, 1549115376833435, 1549698953115317, 1554490369826998,
+    1557899477062908, 1564880778509126, 1567207224023430, 1571515274521835,
+    1572057587834291, 1572080184222310, 1574568734259359, 1577385423148510,
+    1579396501682201, 1589565858742404, 1590015821599478, 1594594046302458,
+    1594699168629329, 1595534252534379, 1596780001230383, 1600718649907260,
+    1603220954267451, 1605209436413123, 1614753954222018, 1630817038748568,
+    1631593175554438, 1632785871861180, 1637023185840569, 1637468236439378,
+    1650883613761093, 1656081765457116, 1656125975781501, 1658697051757402,
+    1661170865019656, 1662097316928435, 1663395135562048, 1668086342205123,
+    1671663448673696, 1674033122060732, 1676050095304341, 1677815560924782,
+    1679839411329962, 1682146207811206, 1685548437885545, 1685906050287237,
+    1697155121714087, 1704852630415342, 1708176264357441, 1711603055723105,
+    1711619757401206, 1716260859009978, 1717965412627933, 1718110815472577,
+    1720866592359242, 1721765535621737, 1722624198366459, 1740016539979518,
+    1741527550621832, 1746159810165727, 1749883301930597, 1751707714650218,
+    1751796135298988, 1752056484987033, 1752251010414327, 1753500688916943,
+    1754069528424030, 1755091278143150, 1755170856727043, 1756580674849098,
+    1757441302497126, 1774411189899395, 1776365286237212, 1780408074789307,
+    1783022378637940, 1785511911126642, 1788083969554196, 1788929860427429,
+    1794343169035459, 1794363800520172, 1796928981786155, 1799485320987261,
+    1802151694773503, 1802269588971863, 1802552535047927, 1805903677636310,
+    1806937216775266, 1809903238315673, 1814680900704212, 1819424177284896,
+    1821901920353762, 1822861775618743, 1822993424140245, 1824935731058226,
+    1825047730546668, 1825822884900885, 1827328000833281, 1828452907975966,
+    1829119010196700, 1829603358861629, 1831497525648613, 1831893453664772,
+    1832840537058264, 1833874076197220, 1836368520944187, 1836827325866138,
+    1837855952746829, 1839619453463964, 1841169762172398, 1842141406857215,
+    1842951929470940, 1844528764374005, 1846044687274584, 1848516535633532,
+    1851816590735959, 1851936449837625, 1857685756910981, 1857756493429997,
+    1859887431065354, 1860258797790188, 1861887702630862, 1862295420066857,
+    1862674646404915, 1864160113304251, 1864802636685313, 1865918701763121,
+    1866688943859073, 1866759680378089, 1870775942735553, 1872545338162606,
+    1875796270682383, 1883201991242697, 1885114824611088, 1885720997280989,
+    1886252503625262, 1887243797343139, 1890746237486084, 1892516615364790,
+    1893105103904937, 1893223980554950, 1896648807017308, 1898662832905958,
+    1901617065026529, 1902370605444380, 1902464920803068, 1903497477490371,
+    1903744072855274, 1905586169704649, 1906286657733238, 1906611849230381,
+    1907276968999462, 1909000189198824, 1909630923160050, 1910112324470020,
+    1910457165000223, 1912219683265705, 1913928166690272, 1913937008755149,
+    1914995109185430, 1915282967519759, 1917464010189419, 1921945954630405,
+    1922948055316465, 1923574859471079, 1924952256688585, 1929508867455199,
+    1931634892832291, 1931916856456702, 1932160504466646, 1933001483081614,
+    1933760918209383, 1934431932688382, 1937383217453994, 1941764951826374,
+    1942773929674005, 1949456565817711, 1952066939859732, 1956355341325077,
+    1958946066334038, 1961607527862015, 1962875872946038, 1964044007961455,
+    1969460263924444, 1969972121235657, 1973387123181485, 1975736165083808,
+    1979609971951587, 1980687721414928, 1982311713997337, 1985143139661283,
+    1985225665600135, 1987089376385876, 1992912367333207, 1996260562566631,
+    2001383065485373, 2002510919983017, 2004895330144848, 2007377003020326,
+    2007821071167482, 2008751452882873, 2008819242046930, 2011010109233120,
+    2011246880081493, 2014332760723566, 2016819345857309, 2018178076493408,
+    2019348176412131, 2019360948283620, 2021674621926435, 2023142404696017,
+    2024621976885435, 2025312640397494, 2025577902343804, 2025689901832246,
+    2026507301607542, 2027943645924228, 2028860273316477, 2029374095530996,
+    2030191495306292, 2030457739704255, 2033667409254606, 2034690141425379,
+    2034731404394805, 2036764096864862, 2039493347556896, 2041334461954618,
+    2042014318498494, 2044074519614835, 2044426237306609, 2045754511941465,
+    2047585801822657, 2049231408341432, 2049727546426197, 2050150000636987,
+    2050459472907682, 2054925698122220, 2058291577485398, 2061344054771269,
+    2063012257678063, 2067143466878928, 2069224299479982, 2070200856423064,
+    2072356355349746, 2072425126965456, 2074054031806130, 2074526591051223,
+    2076348056415885, 2076424687644819, 2077771628861082, 2078421029403715,
+    2081457787463138, 2084852157924253, 2085212717680904, 2088017617150219,
+    2088143370961803, 2088263230063469, 2089379295141277, 2090404974667009,
+    2090687920743073, 2097385293661574, 2103288845644451, 2110091340889823,
+    2112020875936315, 2114422970227900, 2115911384482195, 2116477276634323,
+    2116958677944293, 2117522605193115, 2120061260264467, 2122846510700722,
+    2128500519963737, 2131530400861589, 2132225976631913, 2132479449158387,
+    2132894043755953, 2143220593080636, 2146301561464444, 2146657208962830,
+    2147723169006335, 2150027018132620, 2150412139180596, 2151743361170411,
+    2151810167882815, 2154776189423222, 2155827412691932, 2156051411668816,
+    2156634005499045, 2158315962728981, 2160182620869681, 2160314269391183,
+    2160963669933816, 2162476645479436, 2165473123021086, 2170868747499362,
+    2172593932602030, 2173797435876955, 2174883044953520, 2175163043674625,
+    2178705764335343, 2183468689949087, 2183698583635889, 2184742929743028,
+    2184820543423615, 2185898292886956, 2186435693941147, 2187713863541700,
+    2189309365026172, 2192479736510403, 2192654612904637, 2193307943253882,
+    2193625275137801, 2193979940184534, 2198027640994894, 2198414726946176,
+    2198552270177596, 2198597462953634, 2200191981986453, 2201057521892746,
+    2203554913994672, 2206013008030478, 2213020835671327, 2214859002714090,
+    2217563692114799, 2217731691347462, 2219121860436457, 2219331122638546,
+    2220233013256000, 2222894474783977, 2223114543954249, 2223485910679083,
+    2224541063754405, 2230805175493933, 2232045029480019, 2234050213303792,
+    2234574842486494, 2236350132623465, 2237996721593893, 2238270825605080,
+    2238455526515844, 2240794743901637, 2245682440875312, 2246061667213370,
+    2255988358715282, 2256266392533081, 2256448146088886, 2261024405888560,
+    2270021698126734, 2270406819174710, 2271932566591819, 2274075293647012,
+    2276710228980358, 2285559171018929, 2287750038205119, 2287996633570022,
+    2288699086501917, 2289712976607813, 2290898795752984, 2294164465047556,
+    2297750413581006, 2301609483673990, 2307738017085404, 2310245233703860,
+    2312183610815229, 2312309364626813, 2312811397421496, 2316192013559469,
+    2317351306510009, 2319457682854041, 2319875224806566, 2323729382641285,
+    2333206111286123, 2335235856401221, 2335420557311985, 2340768041659264,
+    2341278916518824, 2341805510604832, 2347135310822357, 2349056986255625,
+    2353822859224328, 2355553939036914, 2359543675199747, 2360084023608897,
+    2375736443344493, 2377829065365383, 2379801828284607, 2380111300555302,
+    2380198738752419, 2380280282239618, 2380325475015656, 2385506925033578,
+    2385962782600570, 2386304675775814, 2386586639400225, 2386859760959759,
+    2387678143186708, 2387726283317705, 2390071395413416, 2390672655825052,
+    2394090605125839, 2394849057801955, 2398839776416441, 2402464040564358,
+    2403772666166154, 2405256168162184, 2408828362372492, 2409288149746096,
+    2409928708223852, 2413120693644449, 2414447985827652, 2415643629489353,
+    2418893579557477, 2422693702551281, 2423530751359637, 2423878539244799,
+    2428112905869229, 2432728463735023, 2433310075113599, 2434979260472046,
+    2436488306211054, 2437971808207084, 2439471029429562, 2444007991163116,
+    2445124056240924, 2448743408130576, 2453424790257121, 2453655666395576,
+    2458040348122915, 2464119758951679, 2468360020286027, 2470305274558967,
+    2471625689580599, 2473429470815507, 2473508066947747, 2474767569966893,
+    2477683486472997, 2485676713121805, 2488778312990326, 2488804839184957,
+    2494895056981904, 2495692807724140, 2500660083281708, 2501666113774380,
+    2502302742445524, 2502547372907121, 2502579793811670, 2503430596943168,
+    2504514241116427, 2507829032993649, 2508417521533796, 2509616112550456,
+    2512136101040401, 2513743391944709, 2515387033560178, 2523857731712344,
+    2528201150470257, 2536582445522000, 2537765317312212, 2539815693912023,
+    2539819623718635, 2540139902957513, 2540322638964971, 2542084174778800,
+    2542692312352007, 2543101012239655, 2545111108321693, 2546908994846683,
+    2548472075426606, 2551486237098010, 2557974347814422, 2563552708300156,
+    2564112705742366, 2564383862398594, 2567668198274573, 2571719828891545,
+    2573794766782681, 2573814415815741, 2574222133251736, 2575309707231607,
+    2576630122253239, 2581899010468278, 2582032623893086, 2582257605321623,
+    2584130158172241, 2584731418583877, 2588923539787228, 2589484519681091,
+    2589737009755912, 2589791044596827, 2591651808027609, 2592215735276431,
+    2594529408919246, 2596413751189700, 2597016976504642, 2600598012779827,
+    2601984252062210, 2604028733952103, 2605064237994365, 2609039237382403,
+    2610013829422179, 2613898443258141, 2615629523070727, 2619359891997168,
+    2619905152664583, 2620757920699387, 2621038901872145, 2621168585490341,
+    2622606894710333, 2622999875371533, 2623637486494330, 2624414605751853,
+    2624594394404352, 2625745827741668, 2626546525838863, 2627189049219925,
+    2628090939837379, 2629363214728014, 2629982159269404, 2630235631795878,
+    2630332894509525, 2632748743124252, 2637369213248311, 2642632206753432,
+    2642796276179483, 2643176484969194, 2644757249678871, 2663848250199967,
+    2665076314766217, 2671071234752823, 2675679915457046, 2676993453317107,
+    2679886773435192, 2679927053952965, 2680353437970367, 2682561989286311,
+    2683602405586838, 2684032719410852, 2685902324906511, 2687141196440944,
+    2699061282346793, 2699357000294346, 2700765835964748, 2701477130961520,
+    2702348565577731, 2712287046499479, 2712893219169380, 2716723798164427,
+    2716956639206188, 2718634666629512, 2718993261482857, 2719131787165930,
+    2719344979174631, 2719437329630013, 2721824687146803, 2722344404071240,
+    2726219193390672, 2726243754681997, 2727810765068532, 2733463791879894,
+    2737738439022097, 2738235559558515, 2742933643363161, 2743803113076066,
+    2745869208902325, 2747461763031838, 2749241965427074, 2751800269531486,
+    2755452042325687, 2758690202973975, 2761477418313536, 2768097177551450,
+    2769464750252426, 2770541517264114, 2770775340757528, 2772240176172151,
+    2772337438885798, 2773588099840067, 2774732656015812, 2782518585365837,
+    2787759964934592, 2788438839026815, 2791854823424296, 2793361904259998,
+    2794451443143175, 2798741809511826, 2802513441407693, 2807683102005779,
+    2812002941924020, 2812919569316269, 2813986511811427, 2815196892247923,
+    2817781722546966, 2817942844618058, 2817943827069711, 2820459885753044,
+    2823340433999640, 2823732432209187, 2823806116083162, 2824294394554703,
+    2826337893992943, 2828212411746867, 2829173249463501, 2830135069631788,
+    2836760723579620, 2838809135276125, 2839146116193104, 2841010809430498,
+    2847652182604778, 2850868729316700, 2852899456883451, 2857831364181511,
+    2858771570413432, 2858951359065931, 2859750092259820, 2861828959957568,
+    2862480325403507, 2863665162097025, 2865226277773642, 2865280312614557,
+    2865774485796016, 2865792169925770, 2865837362701808, 2870079588939462,
+    2870269202108491, 2870894041359799, 2871092496593705, 2872591717816183,
+    2872868769182329, 2873027926350115, 2875162793792084, 2876421314359577,
+    2878536532768486, 2879263546991706, 2884288787196801, 2887756841531891,
+    2888079085674075, 2888470101431969, 2889057607520463, 2890463495835906,
+    2891698437563727, 2892697590894828, 2895097720283107, 2896073294774536,
+    2898824159402936, 2899105140575694, 2902675369882696, 2903505541529481,
+    2904371081435774, 2904676623899857, 2907495277692314, 2910272668515345,
+    2913424373418169, 2914193633062468, 2914852858121631, 2915177067167121,
+    2917880774116177, 2918405403298879, 2921308547933494, 2924227411794557,
+    2929154406834352, 2931101626010598, 2931195941369286, 2936795915791386,
+    2938318715853536, 2938328540370066, 2938549591991991, 2939584113582600,
+    2943834199433478, 2947771865658702, 2949371296949786, 2949532419020878,
+    2950427432476761, 2951891285439731, 2952035705832722, 2958838201078094,
+    2959715530404223, 2962668780073141, 2965798871039599, 2966445324227273,
+    2966552411457450, 2968005457452237, 2970948882604625, 2973692870071454,
+    2973890342853707, 2977888921081417, 2979210318554702, 2982788407474928,
+    2985657166301688, 2987878489489121, 2988838344754102, 2991818120617651,
+    2995085754815529, 2998349459206795, 3004388589517786, 3005249217165814,
+    3005786618220005, 3005876021320428, 3007913626048750, 3009198672810874,
+    3014910646721416, 3015929449085577, 3018808032428867, 3020196236614556,
+    3022392016059011, 3022938259178079, 3025008284810950, 3025956350656095,
+    3027323923357071, 3029184686787853, 3031009099507474, 3031721376955899,
+    3034113646730954, 3036540302313864, 3037119948789134, 3039873760772493,
+    3043909672163017, 3044732966648231, 3047184183522466, 3048985017402415,
+    3050379116298022, 3054382606783997, 3054776569896850, 3055470180763868,
+    3057224839416126, 3058230869908798, 3058898937032838, 3059234935498164,
+    3061903274187712, 3063032111137009, 3063834774137510, 3066832234130813,
+    3067841211978444, 3068257771479316, 3068289209932212, 3069740291023693,
+    3072706312564100, 3073630799569573, 3075120196275521, 3075586860810696,
+    3081793990354350, 3083012230404070, 3083619385525624, 3083868928245486,
+    3086409548220144, 3087459789037201, 3087521683491340, 3089351990920879,
+    3090423845674302, 3097720514101133, 3097995600563973, 3098247108187141,
+    3100617764025830, 3101830109365632, 3103293962328602, 3104286238498132,
+    3107761169994793, 3108890006944090, 3109149374180482, 3109864598983866,
+    3114359315296341, 3115569695732837, 3117004075146217, 3117094460698293,
+    3117207442638388, 3117792983823576, 3117899088602100, 3118677190311276,
+    3120120411789533, 3120986934147479, 3121169670154937, 3121176547316508,
+    3121344546549171, 3121403493648351, 3125037582312798, 3126566277084866,
+    3133112352448805, 3136038093471439, 3138043277295212, 3146212362789907,
+    3146388221635794, 3146571940094905, 3147708636657426, 3152182721485188,
+    3154958147404913, 3156159685776532, 3162857058695033, 3163153759094239,
+    3164225613847662, 3165198240984132, 3165975360241655, 3167432336043054,
+    3171582211825326, 3172425155343600, 3174613075174831, 3176552434737853,
+    3180432136315550, 3183121106489811, 3185281517674758, 3186038005447568,
+    3187761225646930, 3188126697661846, 3188551116775942, 3193199095546285,
+    3196199502894547, 3197879495221177, 3198192897298484, 3198535772925381,
+    3199945591047436, 3207018260497383, 3207217698182942, 3207929975631367,
+    3215321941868539, 3218272244182498, 3219483607070647, 3223463518716950,
+    3223668851112427, 3223883025572781, 3229343491860155, 3231043133219845,
+    3232611126058033, 3234131961216877, 3235191044098811, 3236243249819174,
+    3236525213443585, 3237291525732925, 3244571492481655, 3249021998469745,
+    3250478974271144, 3252853559916445, 3253170891800364, 3258855357064622,
+    3259088198106383, 3261108118704951, 3261741800021136, 3269211379938895,
+    3271004354205620, 3276258505645864, 3277423693306322, 3282328091958098,
+    3283460858714007, 3290052126853984, 3291809732861201, 3292000328481883,
+    3292266572879846, 3292309800752578, 3293685233066778, 3294158774763524,
+    3294220669217663, 3296457711631544, 3297459812317604, 3298096440988748,
+    3298984577283060, 3299614328792633, 3299748924669094, 3300729411418788,
+    3301455443190355, 3301609688099876, 3301961405791650, 3306117176283840,
+    3307616397506318, 3307889519065852, 3308861163750669, 3310348595553311,
+    3314904223868272, 3315453414342299, 3317734667080565, 3322627276312505,
+    3323339553760930, 3325926348963279, 3326809572999326, 3328281285575520,
+    3329979944483557, 3332736703821875, 3337544822211657, 3344326685972316,
+    3348824349639750, 3349284137013354, 3350217466083704, 3353755274486157,
+    3354685656201548, 3355338004099140, 3358393428739970, 3364539646281138,
+    3367176546517790, 3368847696779543, 3369024538077083, 3370984529124818,
+    3373363044576731, 3376696503035360, 3378936492804200, 3384421520382899,
+    3393759723344664, 3397345671878114, 3398338930499297, 3405592371053396,
+    3405599248214967, 3405613984989762, 3410457471639052, 3415683131981359,
+    3419688587370640, 3422076927339083, 3422135874438263, 3424346390657513,
+    3427213184580967, 3427979496870307, 3428882369939414, 3429835348042824,
+    3432128390200926, 3433182560824595, 3434001925503197, 3435139604517371,
+    3438640079757010, 3440028283942699, 3443516969762502, 3453083101507763,
+    3456087438662637, 3458911004713359, 3461325870876433, 3462380041500102,
+    3465433501237626, 3466092726296789, 3468110681992051, 3471365544318440,
+    3474180268304285, 3475107702664717, 3477065728809146, 3477082430487247,
+    3477822216581956, 3480359889201655, 3480500379788034, 3481081008714957,
+    3481463182407974, 3484776991833543, 3487358874777627, 3489466233573312,
+    3489796337328720, 3491452750815678, 3492702429318294, 3494923752505727,
+    3495398276654126, 3495839397446323, 3496328658369517, 3497463390028732,
+    3497898616111011, 3499171873453299, 3499826186254197, 3500426464214180,
+    3500490323571625, 3504566515479922, 3505379002996953, 3509835403694961,
+    3510085928866476, 3512527321224181, 3514645486988049, 3515514956700954,
+    3516701758297778, 3517772630599548, 3517816840923933, 3517975015640066,
+    3518402382109121, 3520432127224219, 3520745529301526, 3522265382008717,
+    3522931484229451, 3522958992875735, 3524873791147432, 3527877145850653,
+    3529898048900874, 3530411871115393, 3534022380940168, 3535640478812659,
+    3535743636236224, 3536362580777614, 3542734762198972, 3543015743371730,
+    3550216131536567, 3552364753301678, 3553157591785649, 3554215692215930,
+    3554217657119236, 3563446807947518, 3563766104734743, 3566117111540372,
+    3566867704603264, 3571393859368635, 3572691678002248, 3575416998887670,
+    3577063587858098, 3577183446959764, 3578372213459894, 3580021749785281,
+    3582059354513603, 3582580053889693, 3583531067089797, 3588054274500209,
+    3590585069958337, 3598368051953403, 3600622778497038, 3601787966157496,
+    3606339664665845, 3608616005145846, 3609946244684008, 3614622714552288,
+    3616740880316156, 3616755617090951, 3619704936953257, 3620190268069839,
+    3621150123334820, 3623310534519767, 3624265477526483, 3624572002442219,
+    3625023930202599, 3627578304500399, 3627813110445466, 3628991069977413,
+    3629205244437767, 3638884358123123, 3640471017542718, 3640805051104738,
+    3643228759332689, 3643550021023220, 3646516042563627, 3646884461933502,
+    3647564318477378, 3650101008645424, 3651577633479883, 3651829141103051,
+    3654976916199263, 3655998665918383, 3657004696411055, 3660606364170953,
+    3660710504046171, 3661613377115278, 3663150913952223, 3666144444138914,
+    3667089562629100, 3668039593377551, 3669986812553797, 3670714809228670,
+    3671743436109361, 3672870308155352, 3673553112054187, 3681531601928200,
+    3684527097018197, 3685323865308780, 3689880476075394, 3690199772862619,
+    3690496473261825, 3692731550772400, 3696414762019497, 3701399721706819,
+    3704238024532336, 3705322651157248, 3706487838817706, 3706933871868168,
+    3708077445592260, 3708660039422489, 3711327395660384, 3712637986165486,
+    3713585069558978, 3718932553906257, 3722816185290566, 3723473445446423,
+    3724528598521745, 3726818693324888, 3730160011396741, 3730275940691795,
+    3730535307928187, 3733852064708715, 3734789323585677, 3735646021427093,
+    3736612753853645, 3738909725818359, 3742874900689867, 3743282618125862,
+    3745073627489281, 3748765680801255, 3750510514936983, 3752051981580540,
+    3752214086103285, 3755042564412272, 3756193997749588, 3756723539190555,
+    3760378259339715, 3760532504249236, 3762857967311887, 3763349193138387,
+    3764500626475703, 3765407429351422, 3766615844884612, 3770083899219702,
+    3771782558127739, 3774976508451642, 3775177911040507, 3778930876354967,
+    3779805258326137, 3780670798232430, 3781875283959008, 3782815490190929,
+    3782977594713674, 3785753020633399, 3785989791481772, 3790536577731856,
+    3796035359633697, 3796749601985428, 3797651492602882, 3798609382964557,
+    3799919973469659, 3799949447019249, 3801928104648391, 3803493150131620,
+    3806047524429420, 3806077980430663, 3806766679039416, 3807851305664328,
+    3814115417403856, 3814738291751858, 3814766782849795, 3816085232968121,
+    3816347547559472, 3817293648501311, 3817926347365843, 3819616164209003,
+    3820443388500829, 3825524628450145, 3829167559179469, 3830508605685814,
+    3831843757482241, 3832416526795940, 3832441088087265, 3833356733027861,
+    3833559118068379, 3835070128710693, 3835415951692549, 3836018194555838,
+    3837805274112645, 3837869133470090, 3839390951080587, 3840534524804679,
+    3844882855820857, 3845720887080866, 3847616036319503, 3849710623243699,
+    3850524093212383, 3851044792588473, 3851206897111218, 3853780920442078,
+    3854055024453265, 3854366461627266, 3857100624577565, 3859895699530350,
+    3860603064720510, 3860950852605672, 3861394920752828, 3863213438762531,
+    3863935540727486, 3865658760926848, 3867457629903491, 3869666181219435,
+    3870890315979073, 3873408339565712, 3874189388629847, 3874377036895570,
+    3878923823145654, 3879542767687044, 3881101918460355, 3881181497044248,
+    3882165913600554, 3882662051685319, 3883281978678362, 3883547240624672,
+    3888936970393030, 3896135393654561, 3898188717609331, 3899370606947890,
+    3901732420721702, 3902142103061003, 3903737604545475, 3906467837689162,
+    3906768467894980, 3907788252710794, 3908392460477389, 3910893782385927,
+    3914545555180128, 3914794115448337, 3915154675204988, 3915807023102580,
+    3915870882460025, 3916472142871661, 3922352116014866, 3924598000493624,
+    3925565715371829, 3934375359344280, 3934834164266231, 3936182087934147,
+    3936678226018912, 3940309367328400, 3944617417826805, 3947662035499452,
+    3948744697221058, 3949690798162897, 3949896130558374, 3950124059341870,
+    3951495561849458, 3952299207301612, 3952868046808699, 3958123180700596,
+    3959844435996652, 3962509827331241, 3966321739744881, 3967099841454057,
+    3970074705059341, 3971973784104590, 3972406062831910, 3972448308252989,
+    3972825569687741, 3973989774896546, 3974079177996969, 3979321540017377,
+    3981965317415600, 3982269877428030, 3985042355992796, 3985119969673383,
+    3985407828007712, 3988168517152642, 3993553334662735, 3993825473770616,
+    3995075152273232, 3997251282684627, 4001100528261081, 4002158628691362,
+    4004258127873823, 4008614318503225, 4010362099993912, 4013012754553706,
+    4014307625832360, 4017716733068270, 4019263111970092, 4023391373815998,
+    4025570451582352, 4027686652442914, 4029256610184408, 4029514994969147,
+    4029926642211754, 4032743331100905, 4033063610339783, 4034360446521743,
+    4034838900476754, 4035060934550332, 4037844220083281, 4039167582459872,
+    4046061445708973, 4047007546650812, 4048841783886963, 4048884029308042,
+    4058068969811939, 4060059416860917, 4063275963572839, 4063742628108014,
+    4063927329018778, 4066608439579815, 4072702587183374, 4074833524818731,
+    4076563622179664, 4079247680095660, 4089485808771573, 4093174914728588,
+    4095632026312741, 4097438754902608, 4099096150841219, 4099822182612786,
+    4100994247434815, 4104699072618278, 4106856536448266, 4107572743703303,
+    4112393633964574, 4116751789497282, 4116819578661339, 4116999367313838,
+    4119298304181858, 4120501807456783, 4121164962322558, 4123737020750112,
+    4124538701298960, 4125119330225883, 4125326627524666, 4127030198690968,
+    4130404920119023, 4131879580050176, 4133460344759853, 4137897096424801,
+    4139690070691526, 4145664359193419, 4147256913322932, 4147472070234939,
+    4148364136335863, 4152312609529270, 4154338424837756, 4154361021225775,
+    4155877926578007, 4157932232984430, 4161566321648877, 4163362243270561,
+    4168221449146299, 4168805025428181, 4169823827792342, 4170449649495303,
+    4172053993044652, 4172359535508735, 4178990101714832, 4180183780473227,
+    4181710510341989, 4183767764103371, 4184274709156319, 4184538988650976,
+    4185358353329578, 4188527742362156, 4189550474532929, 4200489091237431,
+    4200767125055230, 4205065351037105, 4207322042484046, 4208888070418928,
+    4212525106438334, 4213700118615322, 4214838780081149, 4220606753735912,
+    4220790472195023, 4222149202831122, 4227352266785410, 4227701037122225,
+    4231602352636288, 4238115024644025, 4239783227550819, 4240167366147142,
+    4241425886714635, 4242178444680833, 4242733529864778, 4243080335298287,
+    4243843700232668, 4246598494667680, 4247578981417374, 4248122277181483,
+    4248369854998039, 4249054623800180, 4249190202128294, 4249330692714673,
+    4249806199314725, 4251216017436780, 4253941338322202, 4254684071771870,
+    4254804913325189, 4255521120580226, 4256523221266286, 4262029862781351,
+    4262278423049560, 4262591825126867, 4264189291514645, 4268676148213896,
+    4269833476261130, 4275032610408806, 4275765519341944, 4277420950377249,
+    4278098842017819, 4282816574855525, 4291309869395710, 4293106773469047,
+    4296451038895859, 4296677002776049, 4297057211565760, 4300092004721877,
+    4304133810822319, 4304596545550882, 4305643839012980, 4306910219193697,
+    4307233445787534, 4307467269280948, 4308518492549658, 4309954836866344,
+    4313329558294399, 4314557622860649, 4315461478381409, 4317865537576300,
+    4322306219047860, 4322413306278037, 4328073210250970, 4328713768728726,
+    4328808084087414, 4329445695210211, 4330650180936789, 4331278949994709,
+    4331558948715814, 4331899859439405, 4336278646456826, 4337083274360633,
+    4340424592432486, 4341199746786703, 4341297991952003, 4345485200897089,
+    4352482221569755, 4353423410253329, 4357361076478553, 4358200090190215,
+    4362908980963044, 4367514714312308, 4371577151897463, 4373752299857205,
+    4382456821502785, 4385031827285298, 4385211615937797, 4386197997397409,
+    4391812708594304, 4392671371339026, 4393385613690757, 4393873892162298,
+    4393945611132967, 4395420271064120, 4395952759860046, 4397083561712649,
+    4399498427875723, 4401369998274688, 4402231608374369, 4406053345304539,
+    4407036779409192, 4407627232852645, 4408564491729607, 4409387786214821,
+    4410755358915797, 4411309461648089, 4411494162558853, 4411881248510135,
+    4416206000686641, 4416275754754004, 4416464385471380, 4418349710193487,
+    4420425630536276, 4420951242170631, 4421184083212392, 4424962592269830,
+    4425065749693395, 4427196687328752, 4428904188301666, 4429699974140596,
+    4431057722325042, 4432230769598724, 4433750622305915, 4434927599386209,
+    4439745542292521, 4444030013951254, 4444046715629355, 4446625651218480,
+    4446937088392481, 4452934955734046, 4457292128815101, 4459358224641360,
+    4462160176755716, 4462909787366955, 4463005085177296, 4463877502245160,
+    4463987536830296, 4464279324971237, 4472253885038638, 4473245178756515,
+    4473773737745829, 4474203069118190, 4477178915175127, 4477681930421463,
+    4477938350302896, 4481080230689190, 4481432930832617, 4481930051369035,
+    4484554179734198, 4485668279908700, 4488035005940777, 4497842820792676,
+    4502932902806869,    2440770014812,    5523703301926,    6433453532604,
+      19207289924910,   22241100629374,   31431935843189,   34977603858866,
+      38248185411703,   42199605960069,   42279184543962,   44478893795029,
+      44825699228538,   46551866782859,   47805475092087,   51093740774678,
+      54303410325029,   65300974128711,   74505563665668,   76660080140697,
+      78926596104168,   84363483551870,   85833231224758,   86267474855384,
+      92169061934955,  100689865121424,  105459667896739,  109561403548014,
+     111238448519685,  115584814632557,  116537792735967,  119674760863996,
+     121939311924161,  123227306041244,  125678522915479,  126196274936610,
+     127015639615212,  129477663457630,  130914007774316,  135147391947093,
+     138229342782554,  141077470124601,  144953241895686,  145480818433347,
+     146157727622264,  147013443012027,  147075337466166,  152237138451028,
+     155778876660093,  155921332149778,  160012260832870,  163249439029505,
+     170517616358399,  172134731779237,  175641101728794,  178016669825748,
+     180083748103660,  180122063718127,  181729354622435,  192886075593903,
+     193818422212600,  194948241613550,  197486896684902,  205261036615091,
+     208744810176629,  215057062047154,  215348850188095,  216408915521682,
+     221074578421779,  223972810798129,  227037077503836,  227941915476249,
+     231385408520014,  240117438811878,  241771887395530,  242265078125336,
+     242930197894417,  244558120283438,  252833310556657,  253672324268319,
+     256285645665299,  258331110006845,  258918616095339,  260275381828132,
+     268703834559219,  269820882088680,  271403611701663,  279504908032301,
+     281037532610981,  281760617027589,  283735344850119,  286430209734298,
+     289279319527998,  294693610587681,  301853718234745,  301988314111206,
+     303353921908876,  303454131977482,  304667459768937,  305383667023974,
+     305561490773167,  314317099904703,  315146289099835,  319667531606941,
+     320748228425241,  321325909997205,  329549030332815,  330512815404408,
+     332494420388509,  333046558217495,  334342411947802,  336533279133992,
+     336557840425317,  336938049215028,  336986189346025,  337862536220501,
+     338409761791222,  339395160799181,  343172687404966,  343406510898380,
+     345687763636646,  352185698869588,  352748643666757,  353422605500715,
+     353513973504444,  353680990285454,  354053339461941,  361492463378457,
+     362741159429420,  364302275106037,  365143253721005,  367022683733194,
+     367300717550993,  371364137587801,  371644136308906,  377351197961183,
+     382507104236127,  386873119382059,  387557888184200,  388857671721119,
+     389155354571978,  390798013735794,  391848254552851,  393069441957530,
+     394295541620474,  395401782181752,  396633776554614,  398082892742789,
+     399685271388832,  400938879698060,  402413539629213,  404035567308316,
+     405528893820876,  406207767913099,  406260820302361,  408148109927774,
+     408867264537770,  410762413776407,  411835250981483,  411874549047603,
+     412002267762493,  414571378835088,  422189308952450,  422206993082204,
+     423866353924121,  426489499837631,  427137917928611,  429135242139160,
+     431874317347724,  432189684328337,  434350095513284,  434657602880673,
+     440177016267227,  440201577558552,  442306971450931,  442596794688566,
+     442900372249343,  443893630870526,  445675798169068,  449249957282682,
+     449571218973213,  453229868928985,  453588463782330,  457483884586475,
+     464407221385166,  465679496275801,  466304335527109,  466516545084157,
+     466874157485849,  470953296749105,  471553574709088,  471696030198773,
+     476609270915426,  478008282069298,  478834523909471,  478865962362367,
+     478885611395427,  482736821875187,  483501169261221,  484365726715861,
+     485476879535404,  487886833440213,  488152095386523,  492460145884928,
+     496277953008486,  497452965185474,  505684927585961,  508851369263580,
+     513367699512421,  514101590897212,  514506360978248,  516546913061529,
+     520296931021030,  521081909891777,  521959239217906,  522689200796085,
+     526563990115517,  527541529510252,  529907273090676,  536556505878180,
+     537695167344007,  541968832034557,  544523206332357,  547534420648802,
+     553985198202400,  555581682138525,  557260692013502,  558665597877292,
+     560590220665519,  562284949766944,  573970229727726,  574024264568641,
+     574544963944731,  575445872110532,  581100863825200,  581217775571907,
+     583192503394437,  588540970193369,  588683425683054,  589152055121535,
+     590506855951022,  590929310161812,  592200602600794,  592625021714890,
+     592957090373604,  593173229737264,  595407324796186,  602991851557346,
+     603133324595378,  607361796509890,  607868741562838,  610741430196210,
+     615303935672742,  617738450868876,  618029256558164,  618611850388393,
+     623238215222370,  623404249551727,  625601993899488,  628158333100594,
+     634278024447131,  638819898438950,  642663249305486,  644983800109872,
+     645714744139704,  647595156603546,  649572831781035,  650420687557574,
+     655942065847434,  656107117725138,  657176025123602,  663247576339142,
+     663457820992884,  669303408328234,  670353649145291,  673783387865914,
+     673881633031214,  676603024110024,  679339151963629,  679753746561195,
+     680454234589784,  681363984820462,  686073858044944,  687289150739705,
+     694957185891370,  696669599122549,  696735423383300,  701934557530976,
+     703381708815845,  705218893406955,  705321068378867,  710733394535244,
+     722596498245219,  729775272473690,  730406988886569,  732600803427718,
+     733199116484395,  738410040051907,  739204843439184,  741629534118788,
+     745143763681569,  745394288853084,  746540809932135,  749574620636599,
+     753971091783774,  757989319044544,  758470720354514,  759719416405477,
+     763680661470373,  773472757095824,  775414081562152,  778502909559184,
+     781273423220644,  783382746919635,  785531368684746,  793797716893088,
+     797592927628627,  798143100554307,  802857886037054,  804172406348768,
+     804178301058686,  804628263915760,  806920323622209,  808329159292611,
+     809865713677903,  811331531544179,  815899931730629,  820301315136069,
+     822717163750796,  826011324143305,  826439673064013,  828924293294450,
+     831112213125681,  833761885233822,  841110623598262,  845523796423538,
+     847634102574182,  851711276934132,  853313655580175,  857170760769853,
+     857454689297570,  858817349740281,  861423793975690,  862117404842708,
+     863943782465635,  865797668734846,  868103482764437,  872791742052553,
+     881103283036933,  884928949773715,  889786190746147,  890512222517714,
+     903742898928665,  906990884093483,  912324614117620,  913241241509869,
+     920698049556139,  921204994609087,  921484010878539,  923049056361768,
+     923097196492765,  924085542855683,  925534659043858,  925609325369486,
+     929505728625284,  931777156847020,  932172102411526,  938280004338227,
+     938874387588292,  943380893320603,  943572471392938,  944160959933085,
+     944535274012878,  946744807780475,  947566137362383,  947652593107847,
+     949360094080761,  949561496669626,  952462676400935,  953076708684060,
+     953256497336559,  962180105700758,  963082978769865,  966570682138015,
+     971421045948876,  971780623253874,  973294581251147,  975950148069206,
+     981570753976019,  984561336807751,  985578174268606,  985914172733932,
+     986063505385188,  986743361929064,  987746445066777,  999356076250278,
+    1003326163380051, 1003386092930884, 1003896967790444, 1005436469530695,
+    1006448394733285, 1006521096155607, 1006658639387027, 1009237574976152,
+    1012097491738035, 1014914180627186, 1014965268113142, 1017888061780817,
+    1017998096365953, 1023036108442537, 1027182054418197, 1028066260905897,
+    1029459377349851, 1035800120318313, 1041094552276330, 1042348160585558,
+    1042651738146335, 1046862525931093, 1048196695275867, 1048785183816014,
+    1051073313715851, 1052720885137932, 1053358496260729, 1054680876185667,
+    1063002241686577, 1074223804467143, 1082914571789581, 1083082571022244,
+    1085712594097325, 1087985004770714, 1089664014645691, 1090623869910672,
+    1091500216785148, 1092309756947220, 1095576408693445, 1098751692435941,
+    1100693016902269, 1104928365978352, 1105423521611464, 1108769751941582,
+    1111542230506348, 1112666155197380, 1114102499514066, 1114866846900100,
+    1115186143687325, 1117769991534715, 1118869354934422, 1119396931472083,
+    1120493347516831, 1121560290011989, 1122428777273241, 1123787507909340,
+    1124075366243669, 1187899355429161, 1213264292206315, 1219676754145446,
+    1232248205497234, 1245067234665578, 1246725613055842, 1263816342011430,
+    1267916112759399, 1324485678939139, 1332548659655310, 1335000858981198,
+    1348784655672788, 1351049206732953, 1352520919309147, 1384573404488272,
+    1388585737039124, 1388758648530052, 1391171549789820, 1393042137737132,
+    1395755669202718, 1397930817162460, 1400667927467718, 1444266184472899,
+    1446419718496275, 1448359078059297, 1450739558414516, 1458843802100113,
+    1473342823595087, 1490242956929993, 1492620489930253, 1531822275788259,
+    1534303948663737, 1534850191782805, 1537231654589677, 1552453760501259,
+    1553012775491816, 1566083312283328, 1572428967510055, 1575815478357946,
+    1577981784252811, 1579805214520779, 1582006888675152, 1583199584981894,
+    1584925752536215, 1588014580533247, 1588860471406480, 1588964611281698,
+    1593634203988407, 1594906478879042, 1596310402291179, 1606975897436147,
+    1617476340703411, 1619094438575902, 1623379892686288, 1628695938580671,
+    1631100980227215, 1636752042135271, 1639201294106200, 1644301200636923,
+    1648705531397322, 1662954027720781, 1666929027108819, 1668980386160283,
+    1671310761481199, 1673791451905024, 1675076498667148, 1675520566814304,
+    1676668070345008, 1677871573619933, 1678761674817551, 1682761235496914,
+    1683272110356474, 1683425372814342, 1689177627242657, 1691056074803193,
+    1696973381109212, 1707542595992186, 1708682239909666, 1714242916265646,
+    1717454550719303, 1723354172895568, 1725032200318892, 1725376058397442,
+    1731311048833215, 1739701185949835, 1741888123329413, 1742439278706746,
+    1746034069305073, 1748098200228026, 1749374404925273, 1755029396639941,
+    1759640042247470, 1760569441511208, 1764960017948465, 1772596614647234,
+    1776759262300995, 1779557284608739, 1779625073772796, 1791446914513345,
+    1793167187357748, 1793958060938413, 1797832850257845, 1803099773569578,
+    1810660721491066, 1810813001497281, 1811999803094105, 1817615496742653,
+    1821837091495594, 1823400172075517, 1835943132329368, 1838314770619710,
+    1845467018653550, 1850491276406992, 1851078782495486, 1851455061478585,
+    1854163680685906, 1860922948058546, 1863337814221620, 1874754884881133,
+    1875251022965898, 1877298452210750, 1877488065379779, 1877861397007919,
+    1877901677525692, 1880729173383026, 1885498976158341, 1885916518110866,
+    1888245910980129, 1892282804822306, 1893676903717913, 1895718438252847,
+    1896372751053745, 1900703397940169, 1900762345039349, 1906808352511911,
+    1908227012698843, 1912845517919596, 1913149095480373, 1921816283963139,
+    1922990313688474, 1933014267904033, 1934403454541375, 1942199208407930,
+    1943366360971694, 1945268387371902, 1945792034102951, 1947343325263038,
+    1953602524744301, 1956159846397060, 1959914776614826, 1962204871417969,
+    1963717846963589, 1965093279277789, 1967872635004126, 1968374667798809,
+    1969689188110523, 1975695897516965, 1975875686169464, 1977964378383742,
+    1981822466025073, 1986115779748683, 1986246445818532, 2000526380594887,
+    2008467537306086, 2009227954885508, 2012598746506951, 2016782025645425,
+    2018262580286496, 2019613451309371, 2024193640915657, 2029076425631067,
+    2030208209935323, 2032041464719821, 2033183073540607, 2036977301824493,
+    2037465580296034, 2039101362298279, 2042828783869761, 2048781458435288,
+    2050642221866070, 2051076465496696, 2055512234709991, 2056664650498960,
+    2058379028633445, 2058517554316518, 2064936893417220, 2065899696037160,
+    2067370426161701, 2075794949086176, 2076313683558960, 2078722655012116,
+    2082485444843106, 2086807249664653, 2088049068554045, 2094621670112615,
+    2103586541446240, 2105885478314260, 2116500868424925, 2121506459596960,
+    2121576213664323, 2123935080083176, 2125095355485369, 2127759764368305,
+    2132657285858510, 2134028788366098, 2134132928241316, 2144750283255287,
+    2150156714701746, 2153330033540936, 2158038924313765, 2158196116578245,
+    2158770850795250, 2162076800607595, 2174393796981256, 2176853855920368,
+    2184026735438921, 2185347150460553, 2190144461882152, 2190201444078026,
+    2195446753453393, 2199699786659230, 2200522098692791, 2204348747881226,
+    2207095682703014, 2208320799914305, 2210118686439295, 2220510077573076,
+    2225260231315331, 2225285775058309, 2226347805295202, 2232820196785166,
+    2236817792561223, 2237880805249769, 2238973291487905, 2239005712392454,
+    2245497752915478, 2246771010257766, 2248412686969929, 2249370577331604,
+    2254120731073859, 2257614329151927, 2258127168914793, 2258357062601595,
+    2265783414646622, 2265809940841253, 2280564399766007, 2283243545423738,
+    2285995392503791, 2286015041536851, 2289348499995480, 2290272987000953,
+    2293383428934351, 2303712925613993, 2304215940860329, 2304231660086777,
+    2306540421471327, 2309536899012977, 2312715130110432, 2313237794389828,
+    2313634704857640, 2315542625967766, 2323243082023980, 2323495572098801,
+    2327967692023257, 2334055944916898, 2353081121177243, 2357834222274457,
+    2366096640676187, 2369435011393081, 2381442535496047, 2382898528845793,
+    2383846594690938, 2388873799799339, 2395202753347965, 2398105897982580,
+    2398363300315666, 2398629544713629, 2399222945512041, 2402704754170273,
+    2403737310857576, 2406998067893883, 2410589911137251, 2412273833270493,
+    2415124907967499, 2417476897224781, 2423846131291180, 2433185316704598,
+    2435946005849528, 2436928457502528, 2439714690390436, 2442942044070541,
+    2443059938268901, 2443204358661892, 2451020744013160, 2453452311854335,
+    2456103948865782, 2460389402976168, 2462871075851646, 2462963426307028,
+    2463451704778569, 2465104188458915, 2473946253335915, 2479419491494778,
+    2480719275031697, 2482742142985224, 2483840523933278, 2495545452927120,
+    2496424747156555, 2502990471553554, 2506230597105148, 2506696279188670,
+    2506932067585390, 2512101728183476, 2513785650316718, 2521691438768409,
+    2522012700458940, 2523738868013261, 2525357948337405, 2527355272547954,
+    2529880173296164, 2529974488654852, 2532622195859687, 2532788230189044,
+    2533230333432894, 2534936851954155, 2535292499452541, 2536374178722494,
+    2539311709164964, 2542251204510740, 2545066910948238, 2553087646243330,
+    2553167224827223, 2564743452654522, 2565402677713685, 2573278992615786,
+    2588109100317821, 2595877345538092, 2599224558319863, 2601633529773019,
+    2606367964288826, 2607563607950527, 2608492024762612, 2614356278679369,
+    2616855635684601, 2620075129751482, 2621953577312018, 2625523806619020,
+    2626469907560859, 2633464963330219, 2633465945781872, 2636681510042141,
+    2637751399892258, 2638681781607649, 2642800218937025, 2643084147464742,
+    2645372277364579, 2647567074357381, 2656836505703436, 2659006741404913,
+    2660146385322393, 2660817399801392, 2666164884148671, 2666631548683846,
+    2667399825876492, 2670011182370166, 2670720512463632, 2670832511952074,
+    2674504916230988, 2678738300403765, 2680423204988660, 2682485371008307,
+    2692263712310616, 2693312970676020, 2695006717325792, 2695142295653906,
+    2696666078167709, 2705796001379038, 2707730448683795, 2709293529263718,
+    2712019832600793, 2713064178707932, 2714675399418852, 2714945573623427,
+    2715747254172275, 2719402956773088, 2721368842530741, 2721857121002282,
+    2725593384638641, 2730036031013507, 2730435888836278, 2733696645872585,
+    2744123405265874, 2746247465739660, 2749865835177659, 2755702580448132,
+    2763378475213021, 2767986173465591, 2775817295591654, 2776611116527278,
+    2784574869626496, 2785341181915836, 2788426080106256, 2789629583381181,
+    2790649368196995, 2790960805370996, 2800834444483646, 2807426695075276,
+    2807505291207516, 2813909893533423, 2814928695897584, 2824150969564295,
+    2824536090612271, 2827316428790261, 2832714018171843, 2833009736119396,
+    2840410544421445, 2841173909355826, 2842870603360557, 2844968137639712,
+    2847797598400352, 2851685159591273, 2853474204051386, 2854802478686242,
+    2856715312054633, 2859734385984302, 2866752038141681, 2869378131410150,
+    2880126152493970, 2881018218594894, 2882435896330173, 2882654000597139,
+    2883086279324459, 2886444299074413, 2890809331768692, 2892769322816427,
+    2895997658948185, 2896192184375479, 2897823054119459, 2899392029409300,
+    2900766479271847, 2908179076993732, 2909858086868709, 2911219764859767,
+    2913804595158810, 2915388307223446, 2915471815613951, 2919732708433012,
+    2924741246960006, 2926985166535458, 2935655302373183, 2942142430637942,
+    2946589989271073, 2948174683787362, 2948437980830366, 2949521625003625,
+    2952118244722504, 2956355558701893, 2962498828888102, 2962590196891831,
+    2964181768569691, 2964952993117296, 2965308640615682, 2966735160415838,
+    2969767988668649, 2976656939659485, 2979088507500660, 2979261418991588,
+    2980258607419383, 2981670390444744, 2985717108803451, 2986912752465152,
+    2987372539838756, 2987932537280966, 2988059273544203, 2990586139195719,
+    2994588647230041, 2998630453330483, 3000486304503000, 3001752684683717,
+    3002803907952427, 3007625780665351, 3007650341956676, 3009962050696185,
+    3022403818429777, 3027888846008476, 3028728842171791, 3031198725627433,
+    3041596011471132, 3055654894625562, 3059773331954938, 3061533885317114,
+    3062757037625099, 3064397731885609, 3070485002327597, 3070794474598292,
+    3073606251229178, 3074031652794927, 3079280891976906, 3081372531546143,
+    3082465017784279, 3084023186105937, 3086643384664488, 3089691932143747,
+    3095234924369973, 3109319351267381, 3110867695072509, 3111979830343705,
+    3120033968994999, 3120503580885133, 3121863293972885, 3122803500204806,
+    3128459474371127, 3131313496423092, 3132280228849644, 3139209460358253,
+    3139670230183510, 3144913574655571, 3152778100137836, 3161436446555725,
+    3162765703642234, 3163138052818721, 3167219156985283, 3168250731220933,
+    3174131686815791, 3177653775991796, 3179037067919220, 3179156927020886,
+    3183219364606041, 3185961387169564, 3187464538198654, 3192118411678915,
+    3198851152856924, 3201348544958850, 3204086637715761, 3210985413223127,
+    3214536975948722, 3214781606410319, 3215872127745149, 3216736685199789,
+    3222544939372325, 3227119234268693, 3228303088510558, 3230634446283127,
+    3235273582988593, 3244093051477574, 3247256545800234, 3249498500472380,
+    3253442061407522, 3256299030814446, 3256388433914869, 3258794458013066,
+    3260014662966092, 3262052267694414, 3266261090575866, 3277318583930381,
+    3277599565103139, 3278804050829717, 3285440511745732, 3290630803828531,
+    3293843420733841, 3299577991032402, 3300119321893205, 3301706963764453,
+    3301772788025204, 3301914261063236, 3304806598729668, 3305581753083885,
+    3307717602977507, 3310597168772450, 3311687690107280, 3312484458397863,
+    3314490624673289, 3316468299850778, 3321067156038471, 3323583214721804,
+    3324092124678058, 3325227838788926, 3325833029007174, 3332025421776033,
+    3333130679885658, 3335126039192901, 3335435511463596, 3339153108518548,
+    3339417388013205, 3340660189354250, 3345067467469608, 3348438259091051,
+    3352338592153461, 3354314302427644, 3357871759863157, 3359237367660827,
+    3361025429669287, 3375675748718823, 3377626897701681, 3377834195000464,
+    3378246824694724, 3387607624044508, 3390689574879969, 3399959006226024,
+    3401402227704281, 3405684734459708, 3408973982593952, 3410400502394108,
+    3411435023984717, 3415586864670295, 3424159737794373, 3426057834387969,
+    3427672984905501, 3435355756831961, 3439236440861311, 3449297728239684,
+    3451966066929232, 3452535888887972, 3465737091749333, 3468346483339701,
+    3475549818859497, 3477340828222916, 3484587391615444, 3489812069506098,
+    3500264372642365, 3501982680583462, 3504337617195703, 3506459712766183,
+    3507755566496490, 3509136893520608, 3521788905907942, 3523299916550256,
+    3529081644528161, 3535183651744944, 3538293111226689, 3540892678300527,
+    3542658143920968, 3543597367701236, 3543680876091741, 3546292232585415,
+    3546769704088773, 3548353416153409, 3552622168585694, 3553496550556864,
+    3554791421835518, 3555822996071168, 3558433370113189, 3558506071535511,
+    3560187046313794, 3564921480829601, 3565173970904422, 3565673056344146,
+    3566238948496274, 3570924260429431, 3571444959805521, 3573186846586290,
+    3574426700572376, 3575552590166714, 3576646058856503, 3577153003909451,
+    3582498523353424, 3582593821163765, 3583329677451862, 3588901160776025,
+    3591418201911011, 3593221983145919, 3598496766070876, 3602025732408452,
+    3603346147430084, 3611597758863631, 3615142444427655, 3616662297134846,
+    3617311697677479, 3621641362112250, 3625218468580823, 3626445550695420,
+    3633747131380516, 3635155967050918, 3635773929140655, 3641357201884654,
+    3643601121460106, 3644289820068859, 3647409104067134, 3649717865451684,
+    3651510839718409, 3658186598700544, 3658652280784066, 3662405246098526,
+    3663572398662290, 3666138562379926, 3669658686652625, 3674579786982502,
+    3675598589346663, 3678264963132905, 3679259204205741, 3689307719712625,
+    3692000619693498, 3693365245039515, 3696555265556806, 3697189929324644,
+    3697235122100682, 3698162556461114, 3700351458743998, 3700386827003506,
+    3703794951787763, 3704143722124578, 3704510176591147, 3705175296360228,
+    3706236344145468, 3707425110645598, 3709197453427610, 3709965730620256,
+    3712751963508164, 3712952383645376, 3715506757943176, 3717714326807467,
+    3724241735589999, 3726924811054342, 3730637495851029, 3733339237896779,
+    3740660467614935, 3741906216310939, 3742249091937836, 3742753089635825,
+    3748767658655491, 3752599220102191, 3756885656664230, 3766156070461938,
+    3771936815988190, 3773086284422200, 3777401212082176, 3777479808214416,
+    3782776205075739, 3784829529030509, 3785917103010380, 3788450845823467,
+    3791323534456839, 3793037912591324, 3797974732147649, 3798413888036540,
+    3802226782901833, 3805993502539435, 3806473921397752, 3812019860978937,
+    3813204697672455, 3815938860622754, 3819026706168133, 3823043950977250,
+    3823278756922317, 3828549610040662, 3830229602367292, 3832661170208467,
+    3832851765829149, 3840260433744422, 3841352919982558, 3841617199477215,
+    3842889474367850, 3861480406997569, 3861669037714945, 3865163618244666,
+    3869529633390598, 3870974819772161, 3872921056496754, 3874008630476625,
+    3877378439646415, 3877512053071223, 3878824608479631, 3879029940875108,
+    3890926447941285, 3892529809038981, 3893893451933345, 3895627479100890,
+    3896422282488167, 3900006266118311, 3901471101532934, 3903138321988075,
+    3903613828588127, 3904665051856837, 3908718647377115, 3911537301169572,
+    3914757777688106, 3919796772216343, 3920426523725916, 3931146053711799,
+    3946262054844857, 3947566750640041, 3949847020926654, 3952820902080285,
+    3953233531774545, 3956978637475781, 3964124990799703, 3964975793931201,
+    3965987719133791, 3966669540580973, 3967218731055000, 3970242717242934,
+    3970405804217332, 3972663478115926, 3977203387204439, 3977573771477620,
+    3978416714995894, 3983535288108024, 3986627063460015, 3989913364239300,
+    3998199361480702, 4007263460431280, 4010994811809374, 4017828745507642,
+    4019835894234721, 4020334979674445, 4033676673122185, 4039387664581074,
+    4040033135317095, 4050013861659922, 4056560919475514, 4061990929761645,
+    4065782210690572, 4073152562991378, 4078697520120910, 4083314060438357,
+    4087196709371013, 4094858849812760, 4099440021870699, 4099939107310423,
+    4101381346337027, 4103820773791426, 4104771786991530, 4105857396068095,
+    4105895711682562, 4109997447333837, 4112736522542401, 4114142410857844,
+    4115237844450939, 4115369492972441, 4123127913676182, 4124177172041586,
+    4136218099500754, 4140623412712806, 4141149024347161, 4145475741426973,
+    4146820717739930, 4154270648624629, 4157151196871225, 4157860526964691,
+    4157891965417587, 4158414629696983, 4158469646989551, 4160427673133980,
+    4165049125709692, 4168622302371653, 4177641208546193, 4179965689157191,
+    4185516540996641, 4186401729935994, 4187723127409279, 4189489575481373,
+    4190156660153760, 4190855183279043, 4191709916217153, 4194630744981522,
+    4198426938168714, 4201189592216950, 4201251486671089, 4207402616470522,
+    4208989275890117, 4209307590225689, 4209937341735262, 4213765955827003,
+    4223556086549148, 4230772193940433, 4231346928157438, 4232084749348841,
+    4236401641912123, 4245759493906948, 4247592748691446, 4247982781997687,
+    4248945584617627, 4253320441828436, 4259945113324615, 4262883626218738,
+    4263069309581155, 4269294123254563, 4269880646891404, 4271758112000287,
+    4286360290918826, 4286446746664290, 4287199304630488, 4289045331286475,
+    4300038965283545, 4318198601637597, 4330740579439795, 4339858713231288,
+    4342155685196002, 4344233570442097, 4346471595307631, 4347964921820191,
+    4351580343903231, 4359024380078012, 4360097217283088, 4364993756321640,
+    4365104773358429, 4385974011371455, 4394676568113729, 4396623787289975,
+    4398686935761275, 4401084117794595, 4401892675505014, 4402201165324056,
+    4409567587818250, 4413437464879417, 4416231557380549, 4419974698178479,
+    4420513081684323, 4422251038658480, 4426216213529988, 4428139853866562,
+    4429028972612527, 4429460268888194, 4433306567109689, 4435489574682655,
+    4436424868656311, 4440711305218350, 4440847865998117, 4449794070750335,
+    4451150836483128, 4451695114698890, 4457622245521439, 4468453774995764,
+    4469490261489679, 4474109749162085, 4474803360029103, 4479954354045782,
+    4482156028200155, 4482935112360984, 4483299601924247, 4491287916314790,
+    4492977733157950, 4495299266413989, 4496097017156225, 4497019539258392,
+    4499288020125169, 4502412216381709,    2706044912052,    3566672560080,
+       4646386926727,    6137748535981,    6781254368696,    9197102983423,
+      11114848610079,   14318623450512,   14882550699334,   14964094186533,
+      23375845239519,   30644022568413,   34490320789908,   38346443527933,
+      39656051581382,   39989102691749,   46198197138709,   46289565142438,
+      47408577575205,   49823443738279,   53854442870538,   59549715102979,
+      68234587715499,   69068689168896,   72386428401077,   80099656328780,
+      80111445748616,   82141190863714,   82966450252234,   86656538660902,
+      92198548435475,   95726532321398,   96972281017402,   99076692458128,
+      99516830798672,  103728601035083,  105476382525770,  106786973030872,
+     115830440496737,  119574563746320,  123362897320288,  123609492685191,
+     124606681112986,  124655803695636,  137104448590799,  141234675340011,
+     141455726961936,  145307919893349,  149281936829734,  150755614309234,
+     152685149355726,  152968095431790,  156776078038818,  161200057832277,
+     163474433408972,  166177157906375,  176201112121934,  182292312370534,
+     183374974092140,  183531183904967,  186646538096630,  195028815600026,
+     195482708263712,  199274971644292,  202566184681842,  203498531300539,
+     211032953027396,  213233644730116,  214278973288908,  214869426732361,
+     217881623500459,  221461677323991,  224919907142551,  226328742812953,
+     227953717847015,  235370245375512,  237422586878629,  237622024564188,
+     238248828718802,  241832812348946,  242209091332045,  242434072760582,
+     243536383515248,  245271393134446,  253102515260509,  254110510656487,
+     264364358558848,  265476493830044,  267315643324460,  267647711983174,
+     270430997516123,  272613022637436,  278292575643429,  285064614887558,
+     287855760033731,  294638606246043,  297313822097162,  299007568746934,
+     302336114947298,  311067162787509,  315344757284671,  315493107484274,
+     316627839143489,  318320603341608,  323594403814912,  328955642485333,
+     331551279752559,  331701594855468,  336331889496057,  337428305540805,
+     338736931142601,  340380572758070,  349317935445411,  349529162550806,
+     356017273267218,  361594651301299,  369740157956322,  372222813283453,
+     372293549802469,  376577039009549,  382729151260635,  388327160779429,
+     389348910498549,  389903013230841,  390414870542054,  393461453118007,
+     395182708414063,  396833227191103,  397696802194090,  399221567159546,
+     400742402318390,  406063360471038,  407739422991056,  412219402528736,
+     412834417263514,  412874697781287,  415303318267503,  421377816838002,
+     425024677373938,  427795191035398,  429996865189771,  434569195182833,
+     447899099210737,  448196782061596,  465056634878729,  469763560748252,
+     470775485950842,  471113449319474,  481568699810700,  485282367059040,
+     486462291494293,  488621720227587,  491518970152284,  492684157812742,
+     494707025766269,  497804695828178,  506019956550564,  508022193019378,
+     516265944839701,  524740572798479,  528293117975727,  529314867694847,
+     530006513658559,  536675395479123,  536999604524613,  537625426227574,
+     538789631436379,  540867516682474,  541266392053592,  542369685259911,
+     545201110923857,  553388862999959,  555728080385752,  559432905569215,
+     560429111545357,  569409702105430,  574905536652312,  579715619945400,
+     584038407218600,  585670259414233,  589820135196505,  589981257267597,
+     596207053392658,  597262206467980,  605594378937073,  605663150552783,
+     623208754623710,  625145166831773,  628174065277972,  629383463262815,
+     631181349787805,  631607733805207,  633946951191000,  635517891384147,
+     638578228283242,  644827603247975,  645781563803038,  650936487626329,
+     661458544829959,  663224992902053,  664959020069598,  671888251578207,
+     676246407110915,  679407936530269,  681208770410218,  683477251276995,
+     687408040340648,  692751594881315,  699230863532850,  702869864455562,
+     704755189177669,  712233611160305,  713009747966175,  718492810641568,
+     719648173785496,  720734765313714,  721359604565022,  723306823741268,
+     730073950727132,  730180055505656,  733550847127099,  735053015704536,
+     736020730582741,  739584082728172,  742056913538773,  742550104268579,
+     757463720361119,  758454031627343,  765711401988054,  766875607196859,
+     789761818453494,  791741458534289,  798855390953662,  799670825825652,
+     802328357547017,  814827107476483,  819821891680335,  820314099958488,
+     820827922173007,  824263555603548,  830900998971216,  832356992320962,
+     834630385446004,  834636280155922,  834710946481550,  838418719019972,
+     846332367084887,  847375730740373,  855361097775957,  855682359466488,
+     861737209003927,  879107936680620,  889268451675946,  890569217664518,
+     891443599635688,  899384756346887,  903786139752327,  904977853607416,
+     910842107524173,  913653884155059,  921920232363401,  922337774315926,
+     930681736204855,  933372671282422,  946706505116938,  950984099614100,
+     953089493506479,  953138616089129,  956502530549001,  964918211408599,
+     965836803704154,  967281007634064,  967635672680797,  967811531526684,
+     968102337215972,  972524352106125,  973637469828974,  976104405929657,
+     978089940720370,  982480517157627, 1003612069762004, 1006896405637983,
+    1008258083629041, 1008629450353875, 1021530023009418, 1024052958854322,
+    1024860534113088, 1027943467400202, 1030139246844657, 1032235798672159,
+    1036973180542925, 1037943842776089, 1037954649744272, 1044317006649100,
+    1046708293972502, 1052295496523113, 1052791634607878, 1062755659272604,
+    1063738110925604, 1075138479907016, 1076604297773292, 1079551652732292,
+    1085042575020909, 1089696448501170, 1092866819985401, 1114334371055104,
+    1115393453937038, 1117240463044678, 1117484111054622, 1124003660223930,
+    1205499007291933, 1246296294634411, 1325744212457562, 1330289033804340,
+    1330973802606481, 1347245166883467, 1358076696357792, 1360027845340650,
+    1393298570569495, 1396376591598344, 1397180237050498, 1397928865210084,
+    1411910134683927, 1415112927072707, 1438871555397206, 1444643458858581,
+    1461447311931493, 1462178255961325, 1470919128318066, 1500359274551864,
+    1500898640509361, 1513842441037636, 1526583856525393, 1531535412856513,
+    1548865860015433, 1557112559190715, 1562304816176820, 1570099587591722,
+    1575141529474918, 1577650710996680, 1600591939545883, 1604944200368673,
+    1606403141073378, 1610800594672206, 1613064163280718, 1615855308426891,
+    1619006030878062, 1619929535431882, 1628422829972067, 1628620302754320,
+    1630156857139612, 1636892545672580, 1648216283425058, 1650462167903816,
+    1661963729405487, 1667160898649857, 1679136984299927, 1681124483993946,
+    1684276188896770, 1701174357328370, 1705601284476788, 1706333210958273,
+    1717490914381394, 1756520771200125, 1757647643246116, 1767749211142262,
+    1778846002562897, 1800768428747939, 1802581052047724, 1808709585459138,
+    1819968481402518, 1822412821115182, 1823774499106240, 1827722972299647,
+    1827951883534796, 1833635366347401, 1843677004692714, 1853498573867755,
+    1855283688521256, 1857048171690044, 1873501289522835, 1875467175280488,
+    1903873782375330, 1909129898718880, 1923147518903884, 1926867080862142,
+    1936204301372254, 1936476440480135, 1956023298568223, 1957679712055181,
+    1959959982341794, 1965071678292353, 1965389992627925, 1968006261379864,
+    1969118396651060, 1979716102631971, 1980262345751039, 1981197639724695,
+    1984491800117204, 1990057388731449, 1992928112461515, 2001612985074035,
+    2015170817885435, 2019444482575985, 2020802230760431, 2023266219506155,
+    2023477446611550, 2023951970759949, 2039837231537306, 2040788244737410,
+    2042616587263643, 2043328864712068, 2050002658790897, 2054131903088456,
+    2054390287873195, 2056549716606489, 2056882767716856, 2057779746076045,
+    2058632514110849, 2059460720854328, 2060079665395718, 2061649623137212,
+    2064234453436255, 2067418579243628, 2076798045174819, 2081562935691869,
+    2081729952472879, 2092907304929060, 2099091838084695, 2101185442557238,
+    2103259397996721, 2108132358195601, 2108484075887375, 2118106207376857,
+    2118374416678126, 2120204724107665, 2120538757669685, 2128874859945390,
+    2130241450194713, 2131642426251891, 2142386517529099, 2149938623385710,
+    2162181935885396, 2164337434812078, 2172288416039807, 2173494866669691,
+    2176022714772860, 2182760368209134, 2186020142793788, 2188398658245701,
+    2189481319967307, 2194706980309614, 2205338089646727, 2208596881779728,
+    2212837143114076, 2218380135340302, 2222489730604801, 2224771965794720,
+    2237559556510168, 2243507318817430, 2247793755379469, 2248410735017553,
+    2264142733337042, 2272610484134249, 2273366971907059, 2280058450115642,
+    2295756062627276, 2308680214122491, 2311848620703416, 2316911194071325,
+    2317110631756884, 2334151255678169, 2339243302595668, 2341416485652104,
+    2357809673934062, 2361024255742678, 2364268311100884, 2382719735595877,
+    2395536799860915, 2400895091176377, 2410357083046420, 2419554795421806,
+    2419723777106122, 2421414576400935, 2423833372370621, 2424551544528964,
+    2424892455252555, 2431178180928449, 2432370877235191, 2437070925943143,
+    2449692482329234, 2450501040039653, 2455595051860458, 2462176495483905,
+    2470524387179446, 2486872382685366, 2507335868165703, 2525596697040014,
+    2528021387719618, 2528932120401949, 2532127053177505, 2534905426452189,
+    2536381068834995, 2537552151205371, 2539438458379131, 2553955164003859,
+    2558570721869653, 2562351195830397, 2564220801326056, 2568657552991004,
+    2577226496308470, 2581467740094471, 2591804113935684, 2607224675081172,
+    2609447963171911, 2611119113433664, 2611437427769236, 2625212382395949,
+    2626204658565479, 2637825096717163, 2655986697974521, 2661265410706090,
+    2662150599645443, 2662964069614127, 2667953941559714, 2670170352488882,
+    2675657344970887, 2678153754621160, 2692735302054986, 2693664701318724,
+    2699378640132572, 2703502972171866, 2704222126781862, 2705888364785350,
+    2706485695390374, 2711283989263626, 2711771285283514, 2715168603099588,
+    2716690420710085, 2721587942200290, 2724036211719566, 2729730501500354,
+    2736318822285372, 2743919068272980, 2745743480992601, 2747037369819602,
+    2748467819426370, 2751264859282461, 2755243788477111, 2757608549605882,
+    2761272111819919, 2762979612792833, 2764804025512454, 2766865209080448,
+    2781374055091952, 2782146262091210, 2782791732827231, 2794829712931440,
+    2796691458813875, 2804198371894448, 2811142340177852, 2814529833477396,
+    2824135263288777, 2828575944760337, 2831840631603256, 2832869258483947,
+    2837322711826996, 2837504465382801, 2840002839936380, 2840977431976156,
+    2841780094976657, 2845948637340336, 2848305538855883, 2849153394632422,
+    2852474081219562, 2857884442472633, 2859543803314550, 2861581408042872,
+    2863236839078177, 2869837931734684, 2882254155725298, 2885985507103392,
+    2886277295244333, 2888855248381805, 2893933540976162, 2903337568198678,
+    2903730548859878, 2905720995908856, 2920201350822423, 2925645115431696,
+    2930522987888841, 2930849161837637, 2932455470290292, 2934063743646253,
+    2934286760171484, 2935482403833185, 2947016386239405, 2950673071291871,
+    2952497484011492, 2952864920929714, 2961083129007059, 2980663390451349,
+    2992982351728316, 2993960873574704, 3005990011614036, 3009968940808686,
+    3012108720508920, 3013658046765701, 3015213267732400, 3024267542166448,
+    3025881710232327, 3041257078601777, 3042606967172999, 3047250033685077,
+    3051426435661980, 3052307694794721, 3053038638824553, 3055579258799211,
+    3068667479720477, 3073231950100315, 3075568220131149, 3076915161347412,
+    3083985865894053, 3086668941358396, 3088814615768548, 3094737816784485,
+    3100034213645808, 3101524592803409, 3101848801848899, 3102359676708459,
+    3107574530082583, 3115660107186773, 3122459655077186, 3135597981032755,
+    3136610888686998, 3150154967175256, 3152682815278425, 3152707376569750,
+    3152804639283397, 3157138233524780, 3159123768315493, 3161016952650824,
+    3172772969130622, 3174881310377960, 3175380395817684, 3178887748218894,
+    3183465972921874, 3183480709696669, 3186892764287538, 3208358350453935,
+    3208451683360970, 3218832267526568, 3218889249722442, 3221750148935978,
+    3223938068767209, 3226802897787357, 3227115317413011, 3233654515615379,
+    3237899689207992, 3242316791839880, 3246725052406891, 3258072368999041,
+    3262788136933441, 3264342375448487, 3268653373301851, 3270809854680186,
+    3278263715371497, 3286979043985260, 3290559097808792, 3291836284957692,
+    3293033893522699, 3302541078168780, 3302959602572958, 3306736146727090,
+    3308425963570250, 3309573467100954, 3311231845491218, 3313022854854637,
+    3313312678092272, 3315164599458177, 3323767928583498, 3327636823193012,
+    3331005649911149, 3339103998886828, 3339887012854269, 3345734565092925,
+    3347694556140660, 3355206381479498, 3366745276143983, 3368414461502430,
+    3371942445388353, 3374244329611332, 3382909553190792, 3388136195984752,
+    3407486563742240, 3408688102113859, 3410494830703726, 3419312334289401,
+    3423629226852683, 3437814846270350, 3442748718471716, 3446402456169223,
+    3447181540330052, 3454580383728795, 3454604945020120, 3462103998487469,
+    3462607996185458, 3463328133247107, 3466662574157389, 3469416386140748,
+    3471565007905859, 3471892164306308, 3474018189683400, 3476645265403522,
+    3477288771236237, 3478173960175590, 3483902635764233, 3488202826649414,
+    3488570263567636, 3492838033548268, 3501370626154573, 3506099165960462,
+    3516785292590143, 3517257851835236, 3521623866981168, 3534371177178843,
+    3538039651651145, 3543557100134393, 3552243937650219, 3560521092826744,
+    3566125979507109, 3571663077023417, 3577242419960804, 3586059923546479,
+    3591458495379714, 3598123447393666, 3600754452920400, 3608577715433239,
+    3613289553561027, 3614100076174752, 3615304561901330, 3616944273710187,
+    3618063286142954, 3618386512736791, 3619417104520788, 3624110276067169,
+    3631803854961812, 3639100523388643, 3643185557361817, 3646163368322060,
+    3649859351440646, 3655145923785439, 3657470404396437, 3659734955456602,
+    3660931581569956, 3661444421332822, 3662951502168524, 3667547411001258,
+    3675411936483523, 3676977964418405, 3681020752970500, 3691618458951411,
+    3694919496505491, 3697703764490093, 3698334498451319, 3702250550740177,
+    3702629777078235, 3704541627994973, 3705779517077753, 3706927020608457,
+    3707181475586584, 3707350457270900, 3718327389589869, 3725310655939393,
+    3725906021641111, 3727258857567292, 3736481131234003, 3737296566105993,
+    3737558880697344, 3739187785538018, 3746712382748345, 3751079380345930,
+    3751596149915408, 3752205269940268, 3760743757256491, 3761905997561990,
+    3765757208041750, 3767835093287845, 3769739084591359, 3771863145065145,
+    3775003060548133, 3785704906404262, 3793819957058042, 3795345704475151,
+    3805418781273360, 3809213992008899, 3810734827167743, 3812527801434468,
+    3818396967609490, 3827506259336106, 3828274536528752, 3832250518368443,
+    3837550845036378, 3843573273669268, 3844950670886774, 3845659018528587,
+    3855222202918889, 3858536012344458, 3865983978325851, 3867845724208286,
+    3879109532409931, 3886063325209865, 3886207745602856, 3887914264124117,
+    3889255310630462, 3890369410804964, 3896390856986201, 3897009801527591,
+    3897262291602412, 3902819038151780, 3903237562555958, 3909433885131429,
+    3909797392243039, 3910195285162504, 3914566212566701, 3940417462912090,
+    3944900389804729, 3953168702916377, 3958098645311131, 3958633099010363,
+    3959454428592271, 3978685919699746, 3983978386754457, 3984821330272731,
+    3987876754913561, 3997956708873341, 4001035712353843, 4001607499215889,
+    4001640902572091, 4007608313912413, 4010270757892043, 4011440857810766,
+    4013733899968868, 4016070169999702, 4017247147079996, 4018444755645003,
+    4018579351521464, 4019260190516993, 4019684609631089, 4023742134957979,
+    4034640471144708, 4037444388162370, 4038457295816613, 4040559742354033,
+    4041389914000818, 4042962819097271, 4049222018578534, 4062439923117996,
+    4062615781963883, 4062830938875890, 4064549246816987, 4065156401938541,
+    4072146545449636, 4077579503090726, 4078002939753169, 4080703699347266,
+    4081506362347767, 4082582146907802, 4083957579222002, 4087328370843445,
+    4088957275684119, 4098349513486799, 4105636357397100, 4109853039891776,
+    4115449084507264, 4123289048698204, 4123959080725550, 4134967451497415,
+    4136937267061680, 4139115362376381, 4140064410673179, 4151092430478104,
+    4159116113128155, 4161456312965601, 4162834692634760, 4168746104230861,
+    4169130242827184, 4169166593538345, 4169576275877646, 4173860747536379,
+    4174358850524450, 4177296380966920, 4179862544684556, 4180276156830469,
+    4184658873654502, 4186866442518793, 4191862209174298, 4193945006678658,
+    4195568999261067, 4203506226165654, 4203847136889245, 4205698075803497,
+    4208138485709549, 4210517001161462, 4212410185496793, 4214999928054101,
+    4217903072688716, 4218046510630054, 4218280334123468, 4221043970623357,
+    4221884949238325, 4221942913885852, 4222495051714838, 4226822751246303,
+    4228208990528686, 4231629887184432, 4236846705461862, 4245042317151188,
+    4246735081349307, 4246901115678664, 4251599199483310, 4253728172215361,
+    4253936451965797, 4265325031527373, 4265607977603437, 4266838989524646,
+    4272109842642991, 4272825067446375, 4284270629203825, 4287564789596334,
+    4291894454031105, 4298459195976451, 4305748987241711, 4317618968113257,
+    4324272130707373, 4325407844818241, 4341115281846405, 4344578423923230,
+    4346415608514340, 4347595532949593, 4348624159830284, 4357816959947405,
+    4358506641007811, 4358766008244203, 4362799954731421, 4367043163420728,
+    4372161736532858, 4373686501498314, 4375613089189847, 4378582058085213,
+    4413086742590226, 4416515498859196, 4419223135614864, 4421072109625810,
+    4427229134135161, 4430005542506539, 4443539796478267, 4452191265734585,
+    4476075647870668, 4482151128892820, 4488771870582387, 4498786982733069,
+       3255248337009,    5539448430234,    8712767269424,   20698677436024,
+      20943307897621,   21499375533219,   23215718571010,   30016248913076,
+      30681368682157,   40233746104276,   46688453464486,   49108231885825,
+      50036648697910,   54166875447122,   55197467231119,   55315361429479,
+      59042783000961,   61927261054169,   62421434235628,   66885694546860,
+      69330034259524,   73215630547139,   83624705810674,   96581278210438,
+      98074604722998,  104158927810027,  105526500511003,  106667126880136,
+     110711880335537,  113532499031300,  113765340073061,  114109198151611,
+     114432424745448,  117982022567737,  118008548762368,  118571493559537,
+     123149718262517,  129635864075623,  141339810617812,  143618116001119,
+     143933482981732,  147743430492066,  155434062031750,  157470684308419,
+     163256342092936,  163848760439695,  173199735272949,  173226261467580,
+     176951718135756,  177696416488730,  179240830487246,  202920862679505,
+     204463311774715,  209714515860000,  212988044767796,  216320520774772,
+     221498040986082,  221941126681585,  233599880447736,  243723062280248,
+     245110284014284,  245363756540758,  250610048367778,  266287029394699,
+     271404620055176,  272930367472285,  275210637758898,  286118798462157,
+     286622796160146,  294739811717232,  301844902071728,  306410354903219,
+     315533400952977,  324254624276658,  324479605705195,  325186970895355,
+     329120707313967,  338387191305063,  343423238478341,  346932555782857,
+     356316933972313,  357156930135628,  362001399236571,  369508312317144,
+     377459293544873,  399404316117934,  400672661201957,  408203153122202,
+     408987149541296,  410221108817464,  420283378647490,  423747503175968,
+     425036479744704,  427296118546604,  434701839106918,  437117687721645,
+     439480483947110,  448913984719216,  450957484157456,  452997053789084,
+     458301310263631,  458995903582302,  462204590681000,  467516706768771,
+     479421073448172,  479924088694508,  483148495019654,  485084907227717,
+     486237323016686,  500782519739351,  505704602520881,  506410002807735,
+     506803965920588,  506994561541270,  507706838989695,  508595957735660,
+     509333778927063,  511249559650413,  512605342931553,  521572179168484,
+     524106904433224,  539644377325419,  541359737911557,  541888296900871,
+     547275079314270,  548384267230507,  561366383373249,  564401176529366,
+     570443254195316,  572146825361618,  578765602147879,  579760825672368,
+     582877162315684,  585383396482487,  590667021472321,  591655367835239,
+     591857752875757,  595304193274481,  606408844308340,  610296405499261,
+     628950215034772,  630810978465554,  640777950485239,  650230117838752,
+     651959232748032,  655364410177330,  656881315529562,  661325926807734,
+     664176019053087,  683282738800631,  684725960278888,  686875564495652,
+     687327492256032,  688230365325139,  694699809460144,  695346262647818,
+     700375432659525,  703672540406993,  705046990269540,  706670982851949,
+     710399386875084,  711516434404545,  720791760460518,  723875676199285,
+     729882385605727,  730363786915697,  730750872866979,  739899462659715,
+     743722182041538,  744198671093243,  750979552402249,  754696167005548,
+     756477351852437,  761338522631481,  766147623472916,  773210468406333,
+     782221514967649,  782754986215228,  784473294156325,  790039865222223,
+     805055656286675,  806388843179796,  809198654907376,  811847344563864,
+     815014768693136,  819165626927061,  819350327837825,  820922250482625,
+     821330950370273,  831088660187869,  840231355270687,  843381095270205,
+     846103468800668,  846560308819313,  848632299355490,  853788205630434,
+     862702971929756,  882855020236092,  885507639699192,  892308170041258,
+     910420648715966,  915296556269805,  917048267567104,  924535531614617,
+     924773284914643,  928663793460523,  932808756984530,  933975909548294,
+     938405784051671,  950335694474050,  956288369039577,  961004136973977,
+     965453660510414,  967304599424666,  971417142044124,  987742541162025,
+     992676413363391,  994068547355692, 1015259047059249, 1018257489504205,
+    1019083731344378, 1019690886465932, 1021132143040883, 1041706645558009,
+    1045511680810078, 1049038682244348, 1049794187565505, 1053361469517548,
+    1060486208905104, 1061277082485769, 1070391286470650, 1073422149820155,
+    1075218071441839, 1076893151510204, 1081621691316093, 1082155162563672,
+    1104759410195896, 1110981276514345, 1111095240906093, 1113260564349305,
+    1120518917161669, 1121294071515886, 1121547544042360, 1214530698288892,
+    1242466711041947, 1268702099983659, 1279024719501730, 1309743035336081,
+    1329898030997376, 1339771670110026, 1350824251206276, 1375029895032890,
+    1381598566784848, 1395236960631794, 1419019167795965, 1420817054320955,
+    1444985364984755, 1448000509107812, 1484505465178333, 1495466678270854,
+    1499773746317606, 1499856272256458, 1501487142000438, 1505816806435209,
+    1507810200839146, 1512598670195868, 1528368984129824, 1534978918851208,
+    1551419264812510, 1556538820376293, 1556714679222180, 1591412906702834,
+    1597053161642707, 1599968095697158, 1617388928408154, 1622811079081061,
+    1624076476810125, 1640586576838790, 1642841303382425, 1643499545989935,
+    1648853907498785, 1650802109126684, 1653917463318347, 1665079096548080,
+    1665988846778758, 1668932271931146, 1671725381980625, 1673868109035818,
+    1674293510601567, 1674575474225978, 1688206990911353, 1688764040998604,
+    1706719327408832, 1712205337439184, 1712224004020591, 1749916744139589,
+    1750025796273072, 1757456078124711, 1759398385042692, 1760879922135416,
+    1766387546102134, 1767918205777508, 1779137803654768, 1804799440831128,
+    1826116676797922, 1831122267969957, 1832010404264269, 1836960978143736,
+    1842158147388106, 1845313782097542, 1857426428527379, 1867981889087211,
+    1868372904845105, 1874297088312695, 1880051307644316, 1890484944199176,
+    1892292655240696, 1894645626949631, 1899651218121666, 1900065812719232,
+    1911392497826669, 1913072490153299, 1914679781057607, 1917908117189365,
+    1930996338110631, 1938737074684618, 1945811709037871, 1947779559698830,
+    1948617590958839, 1950795686273540, 1956887868973793, 1983020100491940,
+    1985216862388048, 1992545951719428, 2009204401947696, 2010445238385435,
+    2015821213830651, 2021850519625112, 2024677033030793, 2031572861183200,
+    2061934547067512, 2070516262256467, 2072658989311660, 2079207029578905,
+    2087788744767860, 2100875983237473, 2102367344846727, 2110805622094344,
+    2115132339174156, 2118302710658387, 2119956176790386, 2130544058254767,
+    2130773951941569, 2133131835908769, 2137563675315452, 2147124894802448,
+    2147185806804934, 2148500327116648, 2149598708064702, 2152356449854673,
+    2156591798930756, 2160642447096075, 2163275417526115, 2170802962091401,
+    2176097394049418, 2184969914927661, 2185571175339297, 2186955449718374,
+    2214958269183833, 2225725939300713, 2232124646916702, 2232855590946534,
+    2234161269193371, 2236930800403178, 2244681361493695, 2246291599752962,
+    2246590265055474, 2246954754618737, 2247299595148940, 2267787641920602,
+    2287220535616942, 2297643365203619, 2302720675346323, 2315294091601417,
+    2334909721305215, 2336816659963688, 2338489775128747, 2341968636432020,
+    2345775636587395, 2349170007048510, 2352163537235201, 2374986871586044,
+    2381091826157786, 2388024005021354, 2390452625507570, 2399630688849896,
+    2400063950028869, 2413194416371214, 2417736290363033, 2421042240175378,
+    2421099222371252, 2431517139699664, 2439327630341014, 2440477098775024,
+    2443776171425798, 2471953867285491, 2474475820678742, 2479107097770984,
+    2490745220052422, 2499726793064148, 2503721441485246, 2506334762882226,
+    2507516652220785, 2513494870529290, 2525909129616598, 2528901677351636,
+    2531458016552742, 2531520893458534, 2533172394687227, 2536008732609438,
+    2542233546282846, 2546327422320897, 2547885590642555, 2552166132494676,
+    2560034587783553, 2577133176352365, 2584047671086179, 2592898578028056,
+    2602419516997279, 2610260463639872, 2617124853339383, 2620619433869104,
+    2631253490561176, 2642428878114051, 2646085563166517, 2651804414238630,
+    2657438774468585, 2658070490881464, 2673149158851708, 2674025505726184,
+    2681571716872877, 2687642285636764, 2688278914307908, 2718001024166117,
+    2723066544888985, 2733489374475662, 2733659338611631, 2735067191830380,
+    2738665912235319, 2739016647475440, 2741767512103840, 2745629529551783,
+    2748771409938077, 2758533049562285, 2762627908051989, 2778150644169389,
+    2785553417374744, 2785762679576833, 2795894703474222, 2803287652163047,
+    2806560198619190, 2822160548417177, 2834835157192530, 2845586125631309,
+    2854450786896328, 2854698364712884, 2866114452920744, 2867847497636636,
+    2867871076476308, 2870694642527030, 2877070753755000, 2880808982294665,
+    2891063812648679, 2909265694423810, 2909827656769326, 2915279280991823,
+    2915455139837710, 2920808518894907, 2922038548364463, 2922367669668218,
+    2922571037160389, 2926654106230257, 2929399076148739, 2932965375649129,
+    2933472320702077, 2940604919702857, 2947190293132916, 2974214590751987,
+    2978361519179300, 2981181155423410, 2981779468480087, 2987650599558415,
+    3002795091789410, 3012399539149138, 3014693563758893, 3019871083970203,
+    3020644273421114, 3028288729733107, 3037209390742347, 3050971573497571,
+    3053960191425997, 3058452942835166, 3063718883695246, 3077633346456685,
+    3081357820673208, 3081561188165379, 3082712621502695, 3097315782872887,
+    3097705816179128, 3111141824985556, 3118018004104903, 3125756775775584,
+    3127866099474575, 3128468342337864, 3133866914171099, 3136439955050306,
+    3142582242784862, 3147302922977527, 3173134524289856, 3197147607592482,
+    3197666342065266, 3201719937585544, 3202827160598475, 3205960198919892,
+    3206139005120738, 3211925645356908, 3212528870671850, 3222703139990318,
+    3225182847962490, 3228848375079833, 3229514477300567, 3233224214742295,
+    3239495203643394, 3241142775065475, 3249301053591987, 3264724562092434,
+    3265649049097907, 3267440058461326, 3272205931430029, 3286670567117148,
+    3290969775550676, 3292411032125627, 3298533670827123, 3308598888012108,
+    3310103021492851, 3317722916513519, 3318293720923912, 3330050719855363,
+    3334538559006267, 3340525619379649, 3347979480070960, 3349352947481854,
+    3350005295379446, 3354511801111757, 3359464339894530, 3363707548583837,
+    3364942490311658, 3366630342251512, 3367853494559497, 3372738244178213,
+    3378233096273442, 3378887409074340, 3394962283020726, 3397666972421435,
+    3402253056737639, 3409385655738419, 3410573439786896, 3427798764618945,
+    3433418388074105, 3436482654779812, 3437741175347305, 3439702148846693,
+    3444011181796751, 3448717125214621, 3452481879948917, 3456764386704344,
+    3465178102660636, 3484034297236665, 3487990630043296, 3488788380785532,
+    3492389066093777, 3493320430260821, 3500947202443060, 3501565164532797,
+    3504701150209173, 3509592776989460, 3519364241130198, 3523492502976104,
+    3525128284978349, 3525716773518496, 3533818069849134, 3546947553739826,
+    3547456463696080, 3552881561723946, 3555556777575065, 3572899014153821,
+    3575489739162782, 3585959726428803, 3587471719522770, 3587639718755433,
+    3594283056833019, 3604461255958099, 3626447541500586, 3627896657688761,
+    3630446119728296, 3644255460162864, 3656502702469162, 3659920651769949,
+    3664058738132385, 3670825865118249, 3672771119391189, 3674805776764552,
+    3675977841586581, 3680313400731270, 3686481232208804, 3692289486381340,
+    3694887088551872, 3697150657160384, 3717291898498537, 3728441742308434,
+    3737041141627143, 3739314534752185, 3745338928288381, 3756041756596163,
+    3757908414736863, 3764994838509952, 3765162837742615, 3777075064035240,
+    3780687538763321, 3795597225049249, 3796542343539435, 3798155529153661,
+    3802236633320223, 3811377363499735, 3828025989211473, 3828799178662384,
+    3839950987375587, 3842346204505601, 3851068410280935, 3854226009893677,
+    3862354814870599, 3866004622761494, 3868307489436126, 3874205146709085,
+    3879953471330788, 3880067435722536, 3891790048846132, 3891990468983344,
+    3898170089880714, 3901438706530245, 3906079808139017, 3911627712623508,
+    3914099560982456, 3915226433028447, 3918570698455259, 3921108371074958,
+    3921720438454777, 3947157094202600, 3952288439186219, 3960288542996598,
+    3965565290824861, 3972107436382188, 3978111198433671, 3987566313142143,
+    3993121094788205, 3997441917158099, 4012676794941170, 4014271313973989,
+    4018668767572817, 4026348592144318, 4036778298892566, 4039851407663150,
+    4039998775411100, 4046032993463826, 4046819937237879, 4061617624035365,
+    4062526391814390, 4067646929829826, 4071410702112469, 4083291489952198,
+    4085786917150818, 4086707474349679, 4097315004847120, 4098681595096443,
+    4106160999530732, 4108593549823560, 4110171367178278, 4119723744600397,
+    4124062251100045, 4154546743440982, 4156575506104427, 4161460255723143,
+    4172474521204926, 4173212342396329, 4184532150342195, 4197471038612205,
+    4198305140065602, 4203776413321159, 4205704965915998, 4207005731904570,
+    4207023416034324, 4207547062765373, 4208572742291105, 4212477005160127,
+    4216629828297358, 4219495639769159, 4225724383249179, 4228742474727195,
+    4236817244863202, 4243325004612674, 4245825344069559, 4246599515972123,
+    4249749255971641, 4252076683937598, 4252876399583140, 4258182620960993,
+    4263421053174789, 4272770063104737, 4279346594469919, 4283440470507970,
+    4304516023368126, 4308213971390018, 4321279595923265, 4321910329884491,
+    4325988486696094, 4332736947100551, 4333495399776667, 4342365955751604,
+    4349055469056881, 4349471046106100, 4354227094558273, 4368751659796225,
+    4373219849914069, 4378045652433605, 4405573947750665, 4422668606512865,
+    4437410293566130, 4447609124175923, 4458646968497378, 4466602861983372,
+    4469270218221267, 4474906543354528, 4475462610990126, 4476028503142254,
+    4484307623222085, 4486618349509941, 4492320498903953, 4496333813906458,
+    4499819552371302,    9891722203954,   12944199489825,   24626532095648,
+      39078395911278,   46402572984393,   52498685491258,   56335159196223,
+      57913959002594,   62628744485341,   70212288794848,   70859724434175,
+      86126040670142,  102776631285186,  104995989569313,  117134179742128,
+     120055990958150,  120801671762777,  130748012297749,  134146312565476,
+     139060535733782,  148244493786026,  154374992100746,  157482486679185,
+     160360087570822,  166859005255417,  167654791094347,  173434554168946,
+     186186776624886,  190758124166295,  191407524708928,  194012986492684,
+     207484363558620,  210671436720952,  213349599927030,  215768395896716,
+     227258167978551,  236134618663406,  236770264882897,  242871289648027,
+     244059073696504,  246340326434770,  247323760539423,  257329048173575,
+     258184763563338,  260466998753257,  266207463761736,  267332370904421,
+     269207871109998,  271153125382938,  292430080831959,  292551904836931,
+     302288983169814,  304306938865076,  310440384534755,  319791359368009,
+     326546696934037,  328891809029748,  330840993109300,  347206672744974,
+     349553749743991,  356306139955060,  369440536104017,  369684184113961,
+     377305061586282,  381786023575615,  391213629637803,  391763802563483,
+     393103866618175,  401325022050479,  414359208130830,  418867678766447,
+     422215873999871,  434816798901249,  435292305501301,  445782924252035,
+     450531113090984,  454876496752203,  456360981199886,  456856136832998,
+     472881888196734,  480139258557445,  498122053613957,  509157933032106,
+     526963886791078,  536451422404099,  539203269484152,  552549875190157,
+     556063122301285,  563379439761176,  570702634382638,  575508787869114,
+     578184986171886,  580642097756039,  587442628098105,  590485280867446,
+     592189834485401,  595285539644004,  598648471652223,  605414616186434,
+     608990740203354,  609170528855853,  614995484706490,  619073641518093,
+     619479394050782,  623418042727659,  624250179277750,  625264069383646,
+     630469098241240,  631639198159963,  634902902551229,  639913405981529,
+     644391420615903,  647330915961679,  652134122093196,  656317401231670,
+     660258997263506,  683456645694142,  687547574377234,  699097276009902,
+     714806677941372,  718033049169824,  718861255913303,  722248749212847,
+     725168595525563,  728383177334179,  734215992798040,  740416245180123,
+     740618630220641,  747499721598253,  750451006363865,  751553317118531,
+     761775726567996,  774730334064454,  775455383384368,  781065182322998,
+     783907414955127,  785672880575568,  790764927493067,  793341898178886,
+     799025380991491,  799034223056368,  801303686374798,  810844274377081,
+     824558317001308,  832571192683176,  848470207783675,  856553819984559,
+     863232526321653,  863519402204329,  863628454337812,  865559954287610,
+     867708576052721,  869220569146688,  875095630031628,  882029773798502,
+     893982280608900,  895623957321063,  897090757638992,  897826613927089,
+     912526055559275,  914705133325629,  923475479231960,  930998111538981,
+     943186406746099,  945463729677753,  953077729988503,  954775406444887,
+     974277071756937,  978478035025165,  994855504080675, 1001739542813246,
+    1003643534116760, 1004887317909458, 1012159425044964, 1015727689448660,
+    1029527205366698, 1034037640905621, 1087441747859395, 1090272191071688,
+    1098986537233798, 1101423017333238, 1111827180338508, 1124826980611004,
+    1229687975342306, 1236618189302568, 1262722912174431, 1272083711524215,
+    1341030203628449, 1347146947620027, 1351347910888255, 1354553650631994,
+    1357666057468698, 1358524720213420, 1369308109556748, 1395041465703777,
+    1403259673781122, 1418705778669588, 1429970569322886, 1478848521511289,
+    1480499040288329, 1484023094367640, 1493447753074869, 1506915200334193,
+    1538827194926939, 1540671256679620, 1544547028450705, 1547042455649325,
+    1551487066927497, 1570930767592020, 1573468440211719, 1580597109405887,
+    1600174423495218, 1604249632951862, 1610710235021990, 1628183137670595,
+    1647177857929697, 1647682838079339, 1654575718876787, 1663481643111232,
+    1663853992287719, 1671139853746367, 1672229392629544, 1680493775934580,
+    1682646327506303, 1686012206869481, 1690839974292323, 1691559128902319,
+    1698366536405956, 1730582108559479, 1734547283430987, 1735951206843124,
+    1750160405100463, 1757042478929728, 1769043125871123, 1770874415752315,
+    1772917915190555, 1777106106587294, 1785501155962179, 1797185453471308,
+    1804848576364708, 1806596357855395, 1832366064713585, 1837240007364118,
+    1838697965617170, 1847388732939608, 1861735474428367, 1865606333941187,
+    1876450635287001, 1883576357126210, 1891690425328337, 1900957891771086,
+    1904534998239659, 1905938921651796, 1919533105174357, 1929647444941992,
+    1931282244492584, 1933799285627570, 1935139349682262, 1941037006955221,
+    1944196571471269, 1944349833929137, 1945684985725564, 1959175029372907,
+    1961485755660763, 1984832736742655, 1993872274401908, 1996490508057153,
+    1999574423795920, 2013782639601606, 2039389259485398, 2043487065330061,
+    2049663738872472, 2052185692265723, 2052577690475270, 2057972332501893,
+    2058103981023395, 2061087686693556, 2070949536386370, 2074409731108236,
+    2081429348168921, 2092471122296988, 2094240517724041, 2107830771439990,
+    2113285343017446, 2129365129222097, 2171369849646112, 2174545133388608,
+    2175899934218095, 2184462982825643, 2186932866281285, 2196041175556248,
+    2198213376161031, 2198577865724294, 2204544294612963, 2218507879957052,
+    2221670391828059, 2224743500598643, 2231388803579535, 2233480443148772,
+    2251799236670610, 2258840467667661, 2262927466544141, 2268399722251351,
+    2271467918763670, 2273480962200667, 2295643106589041, 2303553807298997,
+    2327996221973984, 2336735129427419, 2342979592133887, 2363089395019144,
+    2364140618287854, 2369474348311991, 2391142319518906, 2401252729479929,
+    2401415816454327, 2407625893352940, 2408717397139423, 2412647203751423,
+    2427649240492733, 2440771847221854, 2461000526757124, 2465034473244342,
+    2472716262719149, 2487967842180321, 2489427765336679, 2490029025748315,
+    2497099730294956, 2521655126910038, 2549110720804776, 2553661436861472,
+    2557672786960671, 2567686916659700, 2588995310561617, 2596982642500507,
+    2605888566734952, 2633245915464390, 2639435360878290, 2645324176086372,
+    2664026125752880, 2667095304716852, 2672330789575689, 2683162319050014,
+    2697561130476382, 2722357227746449, 2725014759467814, 2737653999983659,
+    2748793036825373, 2750742220904925, 2772716717027576, 2780374927662711,
+    2788844643363224, 2792404065702043, 2810209037009362, 2822499507188392,
+    2842303767609566, 2842440328389333, 2858711692666319, 2869721045889837,
+    2880698960660459, 2883737683623188, 2891371332966998, 2948306371161654,
+    2960549683661340, 2978532478717852, 2985915602890147, 2989184219539678,
+    3014344806373008, 3019994885829411, 3023441326228135, 3023598518492615,
+    3028018568479462, 3033761980842900, 3034692362558291, 3040422020598587,
+    3044920666717674, 3053855082050056, 3073267344261683, 3075816806301218,
+    3092879044158869, 3092915394870030, 3093180656816340, 3096805903415910,
+    3097621338287900, 3106588174524831, 3107690485279497, 3107866344125384,
+    3108725006870106, 3122280874778200, 3124437356156535, 3127255027497339,
+    3132565178681804, 3142385765405192, 3148108546283917, 3157288574529549,
+    3164309174041887, 3177096764757335, 3178945738768281, 3179053808450111,
+    3200197150474324, 3211835272755762, 3219598605717768, 3221547789797320,
+    3228633231118756, 3232440231274131, 3236110670649739, 3239193603936853,
+    3239359638266210, 3239890162158830, 3242320747548352, 3252581472612284,
+    3274415478148556, 3282487300929604, 3285763777192359, 3288839833317902,
+    3290635754939586, 3293613565899829, 3296699446541902, 3299940554545149,
+    3301987983790001, 3315689254542739, 3315830727580771, 3323549850218392,
+    3337818978026564, 3357860991747764, 3358793338366461, 3361542238091555,
+    3367703192407518, 3368659117865887, 3375247438650905, 3381777794788396,
+    3384033503783684, 3388239379310177, 3403347520830011, 3406943293879991,
+    3407728272750738, 3415499465325968, 3419524569748309, 3425994996334967,
+    3427772251375244, 3428637791281537, 3447600090636090, 3453907430248350,
+    3457916815444243, 3480336362165703, 3481708847124944, 3482262949857236,
+    3485759495290263, 3495420924845865, 3495659660597544, 3498405612967679,
+    3499605186435992, 3502674365399964, 3509589842585431, 3510518259397516,
+    3512341689665484, 3515185887200919, 3527015587554692, 3528967718989203,
+    3530448273630274, 3532778648951190, 3536229019156526, 3537915888644727,
+    3561188203400991, 3565806708621744, 3566006146307303, 3567594770630204,
+    3590856278418285, 3600010762920939, 3603867868110617, 3604876845958248,
+    3615305570254843, 3622695571588709, 3628147195811206, 3628406563047598,
+    3628892876615833, 3632164440620323, 3648226542695220, 3661808936797945,
+    3673512883340134, 3674011968779858, 3674767474101015, 3677451532017011,
+    3677801284805479, 3708309355986088, 3708429215087754, 3715669883770364,
+    3720360107961786, 3725664364436333, 3729783784217362, 3736511613137106,
+    3744834943541322, 3747989595799105, 3748811907832666, 3749772745549300,
+    3754765564849846, 3757792498392739, 3764140118522772, 3766211126607296,
+    3768146556363706, 3770329563936672, 3775611224023200, 3790903084002145,
+    3792132131020048, 3793667702953687, 3802542188735236, 3803062888111326,
+    3804424566102384, 3812134846675128, 3817407664696779, 3835728423121923,
+    3844914346077473, 3863245911470800, 3879640082204411, 3883714309209402,
+    3902013453698180, 3908080092655455, 3914259713552825, 3917493944394501,
+    3919586566415391, 3924945840182506, 3926143448747513, 3929393398815637,
+    3949906989330277, 3952094909161508, 3955754541568933, 3971215383232194,
+    3971305768784270, 3977995282089547, 4004420284200288, 4006599361966642,
+    4011794566307706, 4029679116198918, 4043546421281013, 4045200869864665,
+    4047668788417001, 4048508784580316, 4050671160668569, 4066897332169517,
+    4067382663286099, 4075477082455166, 4107216165556984, 4108331248183139,
+    4108842123042699, 4111101761844599, 4111948635169485, 4121934273770577,
+    4130613251673179, 4131102512596373, 4135768175496470, 4136362558746535,
+    4154290336510479, 4162257036964656, 4167384452141663, 4175620344348762,
+    4175840413519034, 4184963459568792, 4187357694247153, 4188349970416683,
+    4209014858485885, 4212338492427984, 4223445108365149, 4231014898351514,
+    4231506124178014, 4243611893446280, 4245247675448525, 4246965000937969,
+    4247651734643416, 4256607763912164, 4272963619031308, 4284031919354006,
+    4286341663190209, 4287420395105203, 4287867410607318, 4294808431535763,
+    4301194367280263, 4301278858122421, 4302169941771692, 4305474909132384,
+    4308647245519921, 4318971829941298, 4328422032391505, 4330606022416124,
+    4338181707112407, 4341405130985900, 4360376272405330, 4368890198430228,
+    4375572834573934, 4382430347111874, 4385861068284150, 4390018803679646,
+    4401618610346617, 4414047606208720, 4429283466443444, 4436769748039304,
+    4462414683537563, 4462946189881836, 4464667445177892, 4465668563412299,
+    4466571436481406, 4468037254347682, 4474238489181418, 4486120259472800,
+    4487927970514320, 4496926245204147, 4501119348859151,    4765302429530,
+      46832899759337,   52456453021109,   63616121347536,   66972176194184,
+      68122627079847,   70678966280953,   75193331626488,   75891854751771,
+      84952023895737,  120610106641372,  122242941288658,  123504409211110,
+     134381131461473,  142170990618110,  151203651115792,  153890656386747,
+     169293533402481,  170655211393539,  199577605606206,  199661113996711,
+     202001313834157,  216817667213050,  221118840549884,  228341825102740,
+     230222237566582,  237079750104522,  243964771288746,  245269467083930,
+     267161437267729,  268631184940617,  270548930567273,  272712289107179,
+     301286895434684,  307107921478709,  309934434884390,  339307774405784,
+     339585808223583,  341644044436618,  347550543774454,  347777490106297,
+     360283117197334,  366781052430276,  370999699828258,  371682503727093,
+     376586902378869,  392280585083891,  394746538732921,  396924634047622,
+     400459495095116,  401112825444361,  425141627973435,  431245600093524,
+     439434334621279,  441240080759493,  459390875048668,  463092752877172,
+     464037871367358,  466789718447411,  467030419102396,  467364452664416,
+     469798967860550,  471988852595087,  487865271307567,  488756354956838,
+     498181013664067,  498480661418232,  504868562066038,  510566781653438,
+     526376393653514,  528592804582682,  529461291843934,  530074341675406,
+     543701928554169,  562355738089680,  569081602106118,  570538577907517,
+     575334906877463,  585799981885219,  593719524660052,  602435835725468,
+     605845925413031,  607377567540058,  612313404644730,  614668341256971,
+     629186029333352,  641314394989637,  649937373148018,  650907052929529,
+     653754197819923,  657197690863688,  661279777481903,  663571837188352,
+     664876532983536,  668536165390961,  671568993643772,  677802649382057,
+     683310273348775,  686546469093757,  709218505890038,  716896365558233,
+     718849479444397,  726667829698971,  732891660920726,  739537946353271,
+     739935839272736,  744255679190977,  757750635096585,  763294609774464,
+     775604728986554,  780193760657717,  798067503580746,  799384971247419,
+     814681743484629,  815279074089653,  827641263239352,  845935495469865,
+     847048613192714,  847900398775865,  849516531745050,  869939736707614,
+     873390106912950,  892119565225742,  893281805531241,  893576541027141,
+     903958107644392,  921211923574378,  922055849544305,  925500325039723,
+     947354962060708,  947965064537221,  967961885482383,  971918218289014,
+     981658243976856,  981797752111582,  985419068904540,  991884583232933,
+     996041336176776, 1002106992682398, 1006026974777868, 1015108757858200,
+    1018571899935025, 1025287939434933, 1025683867451092, 1030160899633813,
+    1040223169463839, 1048627060903601, 1069803806284016, 1073642244892287,
+    1079030009757339, 1080901580156304, 1085874750423790, 1095741512374869,
+    1096251404782776, 1105126873015978, 1112898065591208, 1170699626143810,
+    1223889558637230, 1256326182412678, 1259656693516348, 1280195827773966,
+    1294635902169760, 1327631540935765, 1353204757463355, 1355505659234681,
+    1377970398732179, 1399171705403919, 1402355831211292, 1442497823301219,
+    1442696278535125, 1490890444373040, 1492120473842596, 1495639615663642,
+    1502485338781746, 1525780249926029, 1530067668939721, 1540543550915660,
+    1549863087296018, 1556135058648770, 1561602402097715, 1574747605214855,
+    1592556506328786, 1627492487109466, 1653775033730522, 1668617913304046,
+    1680684384506192, 1690684759882079, 1725957721579738, 1726399824823588,
+    1737360055464456, 1754761239142392, 1777589485751500, 1780603647422904,
+    1788066350179092, 1798271075498803, 1801248886459046, 1802468108960419,
+    1805146272166497, 1817934845333598, 1826410455744029, 1835431326821875,
+    1846590012696649, 1849758419277574, 1860059424859279, 1861811136156578,
+    1875964334669696, 1877071557682627, 1878376253477811, 1883434897039108,
+    1885045135298375, 1885927376882769, 1887093546994880, 1892504890699604,
+    1893336044798042, 1911061437521468, 1917180146416352, 1917215514675860,
+    1919763011812089, 1919768906522007, 1923659415067887, 1933489826307805,
+    1946819730335709, 1949952768657126, 1957473436060841, 1974294973263507,
+    1983187143174810, 1987091406043832, 1991539947128616, 2044494091225316,
+    2049686348211421, 2058403641728490, 2064086142089442, 2064654981596529,
+    2086756213982417, 2097029710917838, 2116051939823224, 2118426525468525,
+    2120179219217477, 2125362634138705, 2135356132353021, 2138117803949604,
+    2143535042364246, 2151772899474651, 2159892862386696, 2160594332866938,
+    2161515872517452, 2166708129503557, 2167372266820985, 2168124824787183,
+    2177226256900575, 2180303295477771, 2185470008720898, 2201348392336684,
+    2204590482791584, 2222542821846853, 2231247343492433, 2235169290491209,
+    2271564211976594, 2271920841926633, 2287763857282911, 2300493483350832,
+    2322282296111066, 2341402770181752, 2352727490385883, 2352860121359038,
+    2400359693878282, 2406232789859916, 2406881207950896, 2416759759321811,
+    2418323822353387, 2434228732163804, 2450058975648593, 2451794967719444,
+    2455264004506187, 2457390029883279, 2457939220357306, 2469279659787885,
+    2469990954784657, 2470885968240540, 2475446508813766, 2502046387318741,
+    2508496182420686, 2512138130698357, 2515703447747094, 2521106931838594,
+    2533505471699454, 2550607990074878, 2579247438211481, 2582470862084974,
+    2585201095228661, 2587692592620669, 2598011282332128, 2611384414232764,
+    2621803314012829, 2629311209545055, 2644617806298795, 2648030843341317,
+    2652619875012480, 2653668150926231, 2656413120844713, 2671845471410037,
+    2672021330255924, 2682928508507530, 2686634316142646, 2687817187932858,
+    2698419806172034, 2699466117182479, 2703899921492468, 2721672471895238,
+    2740585648667141, 2754009868053733, 2759804367903127, 2773340586778161,
+    2778507300021288, 2780649044624828, 2780766938823188, 2789162970649726,
+    2791570959651229, 2800206709681099, 2805770333392038, 2814179137090065,
+    2820015882360538, 2822768711892244, 2824664843582534, 2831898635103573,
+    2840457753904509, 2845585169081516, 2863036457793755, 2872205679071204,
+    2882910472282292, 2883365347397631, 2888108623978315, 2918719852582489,
+    2918727712195713, 2918885886911846, 2944903171586592, 2967026017908846,
+    2967277525532014, 2979690802167669, 2988457218267388, 3003380658876458,
+    3005182475208060, 3025908275279748, 3042477322407593, 3046234217528665,
+    3072030450581486, 3073670162390343, 3076701025739848, 3091649027640243,
+    3098785556447635, 3111966127824283, 3116814526731838, 3116972701447971,
+    3124917787965782, 3138564041425952, 3138626918331744, 3153940392247055,
+    3203877427317392, 3206835589244575, 3216189511432788, 3235496651317544,
+    3242431777536071, 3257909320877433, 3260311415169018, 3267882187607036,
+    3269477689091508, 3274680753045796, 3291579903929049, 3293133159992442,
+    3297995313223139, 3306259696528175, 3310669921998492, 3358947596226912,
+    3388784652928522, 3438102743457469, 3440723924467673, 3449044307516930,
+    3453290463561196, 3467713836278889, 3469604073259261, 3470300631481238,
+    3473869878336587, 3494529854147524, 3496077215500999, 3500321406641959,
+    3511254128636543, 3516207649870969, 3521996255010445, 3525809149875738,
+    3533758166200161, 3534783845725893, 3537090642207137, 3557350760195303,
+    3566166298877672, 3581147704134269, 3587743884532511, 3591553832042845,
+    3597066368267828, 3603634057568133, 3605969345147314, 3615096321003684,
+    3615188671459066, 3616034562332299, 3621257275319647, 3629520676173030,
+    3635016510719912, 3635345632023667, 3640500555846958, 3644376327618043,
+    3659233943966362, 3662279544090662, 3665056934913693, 3669915158337778,
+    3677164669085265, 3684973194823309, 3703005112462471, 3705138997452787,
+    3715187512959671, 3737345727541433, 3749229462736121, 3753697652853965,
+    3754488526434630, 3761097478704361, 3770359050437192, 3777752981577670,
+    3786741431750967, 3787870268700264, 3789530611993834, 3802245501286960,
+    3813082925471203, 3818163182968866, 3824369330060867, 3835883663434027,
+    3837051798449444, 3840971780544914, 3856567218084636, 3877709577657196,
+    3884896211498891, 3891969863400491, 3920466856047409, 3920657451668091,
+    3921324536340478, 3928112294811055, 3941249638314971, 3947284838819350,
+    3952667691426137, 3956930549148504, 3960124499472407, 3971943392857997,
+    3973716718091662, 3974066470880130, 3998088396247633, 3998363482710473,
+    4005004855884753, 4013422501647657, 4016302067442600, 4030496528925144,
+    4066156576574085, 4085774171181189, 4091548039545870, 4098907584878493,
+    4102042588103216, 4103291284154179, 4120359416721748, 4120959694681731,
+    4123701717245254, 4130492423070790, 4136352747180935, 4137915827760858,
+    4143730959094965, 4145645757366662, 4176654878890301, 4179168972670328,
+    4186483325226913, 4187723179212999, 4195788124832476, 4203290125654784,
+    4203441423209346, 4208346804312775, 4208382172572283, 4208460768704523,
+    4211318720563100, 4214533302371716, 4220547871391382, 4254963152795972,
+    4261878629981439, 4267431446724195, 4284782525367828, 4286454658081234,
+    4290679200189134, 4294006763937845, 4297800009770078, 4324118907102295,
+    4328900499297446, 4337158987892564, 4346406805302253, 4355139818045770,
+    4367109026534269, 4367910707083117, 4374246537793314, 4385511328446612,
+    4387332793811274, 4393205889792908, 4397278151894593, 4402841775605532,
+    4411569876090784, 4412267416764414, 4413085798991363, 4417951882028672,
+    4428170361671525, 4436294254390182, 4437517406698167, 4440367498943520,
+    4450183173408643, 4478676236248949, 4493880658030777,    5203488817698,
+       9627468611157,   16811155097893,   20531699507804,   34060058769614,
+      38050777384100,   45373972005562,   46199231394082,   62966733755833,
+      88503599572262,   98499062689884,  130776529297546,  141807496457430,
+     184590318590621,  193132735713456,  193388173143236,  194432519250375,
+     200595438469644,  205133382654851,  208531682922578,  214949057119974,
+     234718931733293,  246050529098995,  246087862261809,  252886427700569,
+     268708811572134,  273859805588813,  278310311576903,  278727853529428,
+     287528655437002,  299887897231742,  303317635952365,  333067254456858,
+     340827640063905,  346633929333135,  354629120885249,  355782519125871,
+     356711918389609,  357162863698336,  360599479580530,  385822943319652,
+     397025839518811,  407881930284461,  407891754800991,  412678259254407,
+     420478925379227,  420932818042913,  437086288121539,  439737925132986,
+     455038627176808,  460461760301368,  460495163657570,  466315207249942,
+     474770186175660,  478747150467004,  482174924284321,  487757214576667,
+     504580716682639,  509153046675701,  514289303917585,  523363227384693,
+     527886434795105,  532159117034002,  532295677813769,  534780298044206,
+     544200044493170,  546316245353732,  554274103743032,  554501050074875,
+     570646660540277,  573957522610887,  574468397470447,  588739490181925,
+     589036190581131,  593437573986571,  609805218525551,  622438564331478,
+     623264806171651,  624390695765989,  628819587817713,  633212129158276,
+     638336596980324,  640004799887118,  643411942219722,  643722396942070,
+     647556905743729,  668273863750540,  674486888004112,  686356868875658,
+     687010199224903,  693112206441686,  694224341712882,  695183214526210,
+     703601842740767,  719907592825608,  734947945181385,  746025087568960,
+     747008521673613,  751898183550594,  756028410299806,  760209724534974,
+     760952457984642,  770488133728660,  773490505980228,  773759697733150,
+     777997011712539,  817579988811909,  832043642047375,  832465113806512,
+     832701884654885,  837838141896769,  856969422935638,  859456990521034,
+     878469394909890,  890521129337241,  901714201019870,  910699703838208,
+     914672738322940,  945261370539095,  952477477930380,  957209947542881,
+     958227767455389,  975931546242449,  989600396090638,  990375550444855,
+    1039245643020034, 1062295923702720, 1064068266484732, 1066423203096973,
+    1082703409438836, 1083579756313312, 1088980293049853, 1097057028089166,
+    1101415183621874, 1117463531373629, 1191161159671771, 1249495209020299,
+    1268800384001749, 1285725078627980, 1323232135384561, 1337595578551421,
+    1373832325320673, 1386062865948870, 1397425901767468, 1398630387494046,
+    1408115958203761, 1411187102071039, 1437237790101987, 1458131589406338,
+    1489297903194457, 1507491925356364, 1524258445266462, 1528841582227707,
+    1530651258172533, 1536307232338854, 1541353104028662, 1547790127259118,
+    1562862900519444, 1578202900629386, 1593412234669479, 1595764223926761,
+    1610556016014329, 1630263013721856, 1646567781355044, 1649685100450013,
+    1656743033125165, 1658318885576577, 1663939491483390, 1673413272773269,
+    1675726946416084, 1687230472821061, 1704842883604392, 1719694605242793,
+    1766368918373517, 1777417569663155, 1795739310539952, 1800489464282207,
+    1809798193694382, 1840563667208077, 1842896989883952, 1860463225439592,
+    1895836397205857, 1897156812227489, 1899247469345073, 1909155494265578,
+    1910563347484327, 1914275049829361, 1962595951930513, 1971771067917880,
+    1977021289551512, 1977408375502794, 1990187124153365, 1998929961413412,
+    2004638005517342, 2005113512117394, 2016910791566618, 2027448567996696,
+    2031913810759581, 2034220607240825, 2038060028300749, 2046013956883437,
+    2062584968914588, 2065978356924050, 2073684707690182, 2075613260285021,
+    2097142705808863, 2115089150154214, 2131539320632046, 2142963268453130,
+    2160709292661269, 2169024763452261, 2178168440986732, 2180973340456047,
+    2189143408402395, 2193039811658193, 2198045402830228, 2201508544907053,
+    2208403390607807, 2210983308648585, 2215225534886239, 2215960408722683,
+    2220419756775650, 2239647318076513, 2245847570458596, 2247671000726564,
+    2263859839064698, 2273745267597184, 2276409676480120, 2339923210943264,
+    2350753757965936, 2350765547385772, 2351678244971409, 2351910103561517,
+    2357722287540665, 2360040873441745, 2384550094829136, 2404975264695006,
+    2422321431080374, 2431630160492549, 2445556412673824, 2446505460970622,
+    2461276621573477, 2462513528204604, 2483056592268834, 2491721815848294,
+    2500399811299243, 2503875725247557, 2507930303219488, 2515507952819077,
+    2524339210727894, 2525409100578011, 2533191100121424, 2538522865242255,
+    2550830037099386, 2553817672576159, 2572417447270755, 2609967731900068,
+    2616232826091249, 2622105922072883, 2629888904067949, 2633840324616315,
+    2678229455202161, 2715957563580667, 2717204294728324, 2726053236766895,
+    2759194278377544, 2777494405317975, 2801458366037951, 2812648490365621,
+    2817434994819037, 2821420801175258, 2845838654558920, 2848091416199249,
+    2858087861768524, 2871471800637343, 2873048635540408, 2883951883985402,
+    2884366478582968, 2895986916734652, 2896295406553694, 2909586994967131,
+    2950119020363299, 2951087717693157, 3015886298918425, 3018652882773273,
+    3034064601853884, 3051013857771440, 3060754865910935, 3063850571069538,
+    3098552728356804, 3100904717614086, 3120744346294768, 3124552328901796,
+    3140273520253102, 3144049081955581, 3154385455796794, 3159320310449813,
+    3181890172274182, 3193806328373419, 3200070440112947, 3202118851809452,
+    3208508717360564, 3215557807970839, 3228008417769308, 3260617953035684,
+    3289269190592123, 3297699608226516, 3307366932492036, 3313241010925323,
+    3328705782395196, 3334772421352471, 3335404137765350, 3337513461464341,
+    3353782860838021, 3361284861660329, 3379161551938317, 3410075375651615,
+    3415650788782390, 3430172406665383, 3430818859853057, 3442860769763878,
+    3443459082820555, 3458224348713492, 3496806207578455, 3508470856054524,
+    3508732188194222, 3509849235723683, 3546602752062413, 3550775224232704,
+    3598445743339570, 3602085726713935, 3609517973468880, 3610073058652825,
+    3610867862040102, 3611778594722433, 3614266162307829, 3636725007095409,
+    3643673887637078, 3654002401865067, 3660522933486028, 3663792532587212,
+    3669671523278764, 3691297249064600, 3691449529070815, 3713064447888468,
+    3749374878531695, 3764244284299850, 3765215928984667, 3766903780924521,
+    3769671347231022, 3772415334697851, 3782361675232823, 3783490512182120,
+    3787989158301207, 3806812931972687, 3813141885521313, 3824781972706057,
+    3853797699825759, 3864803123242665, 3869107243934458, 3871269620022711,
+    3874245466079648, 3895818139476222, 3898635810817026, 3910786772861330,
+    3932935162926562, 3968144265266776, 4029468897447036, 4042401891007128,
+    4049144456701667, 4058764623287843, 4062642359962234, 4068138194509116,
+    4073285258719183, 4080311752941439, 4084210121100543, 4094000251822688,
+    4096803186388697, 4105168762213992, 4107212261652232, 4114177843872002,
+    4134030244424173, 4169947694406200, 4177829904018219, 4194446108825408,
+    4225527931771369, 4228966512556869, 4230257454028911, 4234881853959582,
+    4251031394231596, 4255076147686997, 4255588987449863, 4258063783163770,
+    4272214034321929, 4279662982754975, 4299184297100085, 4305505391035487,
+    4337267070525324, 4346195591147788, 4349626312320064, 4358965497733482,
+    4360795805163021, 4367996193327858, 4369109311050707, 4379223650818342,
+    4389654340018243, 4413493529378288, 4434328381583459, 4438940009642641,
+    4440862667527562, 4443076131101771, 4454906813907197, 4465216661553779,
+    4466400515795644, 4472172419257019, 4486658668880504, 4501622390007347,
+       1217695412407,    2542040240651,   40171903453857,   53551912516064,
+      69068753923546,   79040638201496,  100298927069110,  100562224112114,
+     116576186056014,  119888030578277,  139729624162265,  140018464948247,
+     148429233549580,  152555530492180,  160711844115386,  161612752281187,
+     169914468749037,  175192199028953,  190884899282322,  193920674890092,
+     200087523915973,  212192310732586,  217717618829058,  218371931629956,
+     220946937412469,  222121949589457,  231103522601183,  250275084157825,
+     251874515448909,  258840097668679,  260267599920488,  269053665053267,
+     273337154260347,  295758665885113,  323888221613809,  341920139252971,
+     352496231297516,  386100990088381,  395877366487384,  405135008413603,
+     415692433876741,  430291665440321,  435884762700850,  444266057752593,
+     467543284767122,  467778090712189,  476797979338382,  516870217360946,
+     522670611920258,  524709199100233,  540758529303641,  544038935373008,
+     556259651484675,  556603509563225,  562970778726318,  632545057436819,
+     652945666011364,  669347696358199,  672421787580436,  683600122488270,
+     686517021446027,  692686817826867,  697711075580309,  703862205379742,
+     738072154388855,  741497963302866,  750255537337708,  751280234411787,
+     756293685197046,  761940817298490,  788509257350569,  788599642902645,
+     799248436369512,  825245089559545,  833079159040567,  843355603330947,
+     861525064201529,  868167419827462,  885156956262791,  907671800794592,
+     926179225033806,  932777370335354,  935437849411678,  942586167638906,
+     943690443296878,  949852380064494,  952906822253671,  991143840588431,
+    1021844472293028, 1025115053845865, 1031310393969683, 1038206222122090,
+    1054870567060276, 1055559265669029, 1059697352031465, 1063081897976050,
+    1094287509830289, 1105911877788585, 1107406186752798, 1116855406751352,
+    1121740156370068, 1123000641840867, 1159369037131621, 1199699659938924,
+    1250509112077125, 1295100627703489, 1310102664444799, 1335009778751655,
+    1343221109667429, 1355569544493986, 1383544855313161, 1395290064824776,
+    1399631518679383, 1456621574166607, 1459118966268533, 1485780739227647,
+    1503475675949830, 1517992381574558, 1520090898305366, 1530566780281305,
+    1543831842500111, 1548554487596082, 1551117703958759, 1575265383137846,
+    1585033899923625, 1586151929904739, 1590390226335781, 1593324809423292,
+    1598435522922198, 1615445690842240, 1619976757865876, 1636976118817735,
+    1662109197004781, 1669317444782842, 1676874462897718, 1703943953292827,
+    1740788837635286, 1755103158219496, 1788463286548764, 1808408037556317,
+    1851479700475490, 1879395081743832, 1886447119709066, 1897217737180905,
+    1901811681110333, 1910473957334834, 1910790306767100, 1912107774433773,
+    1923319512697809, 1923633897226769, 1924600629653321, 1944450082850533,
+    1955985047708406, 1957115849561009, 1960134923490678, 1966517911880219,
+    1994240732624573, 2005393523789429, 2006012468330819, 2029643377940428,
+    2033833534240473, 2034217672836796, 2034329672325238, 2060139659701201,
+    2081330159404758, 2085516385898191, 2107082182133194, 2125646588568282,
+    2135188159022218, 2160461727795643, 2173115705086283, 2176189796308520,
+    2179286483918776, 2188654160430131, 2205258575817484, 2213993553464307,
+    2215293337001226, 2215441687200829, 2232993185981674, 2242643808569093,
+    2244536992904424, 2251108612011341, 2276392987752949, 2280801248319960,
+    2285379473022940, 2298895060413261, 2311756335002684, 2348660166444323,
+    2353449618252698, 2371985533589849, 2393716381702556, 2426516512589614,
+    2428272153693525, 2441986196317752, 2469459474342244, 2473726261871223,
+    2477273894790206, 2478951922213530, 2483789514152902, 2484064600615742,
+    2491341620009513, 2503052443713273, 2519627385551036, 2526875913846870,
+    2548574341055028, 2561374703641965, 2570683433054140, 2572183636728271,
+    2577407332167272, 2580033425435741, 2668324390587545, 2685326698894363,
+    2696517805673686, 2723182525987759, 2733836231712891, 2743394503844928,
+    2755953183325227, 2767167868944222, 2771707778032735, 2795756229594869,
+    2838674630056174, 2843505344833975, 2863888269278766, 2908075014824094,
+    2912114856021230, 2919102052177366, 2928356746748626, 2936744918961940,
+    2939723712373836, 2946649014075833, 2959425797823098, 2977469504882096,
+    3023505224438370, 3026970331418501, 3037442283587828, 3039249994629348,
+    3049573596599072, 3049971489518537, 3061343367402012, 3092289612019859,
+    3144780038936343, 3155121325035821, 3198387513382288, 3219045524289919,
+    3228054605947929, 3231686729709070, 3239665219583083, 3249354157784969,
+    3250829800167775, 3251310219026092, 3253951049069356, 3257367033466837,
+    3283122986001885, 3305559234401446, 3317757354125094, 3318828226426864,
+    3367858458621482, 3370811708290400, 3417350443093010, 3422787330540712,
+    3424598971388844, 3453861293873449, 3454753359974373, 3473065276334640,
+    3482909441897700, 3490355442975787, 3513144391518775, 3521211302041558,
+    3523020977986384, 3544764597970580, 3546546765269122, 3551432497339491,
+    3557318365192614, 3568815014436020, 3604670569963908, 3611176364810074,
+    3623154415363450, 3625430755843451, 3680730994487515, 3683690138866351,
+    3714967469691259, 3729143264592396, 3742220678545479, 3748146826916375,
+    3748578123192042, 3756377806865209, 3794885981856197, 3799111506415750,
+    3805174215566413, 3808093079427476, 3815218801266685, 3815756202320876,
+    3821186212607007, 3821223545769821, 3842722535292420, 3845261190363772,
+    3846309466277523, 3863183073417798, 3864150788296003, 3876075786460117,
+    3891148559720443, 3899444381478375, 3907167433922608, 3920497337950512,
+    3923483008523979, 3928512178535686, 3930840588953296, 3945108734309815,
+    3960023332854008, 3977502130212531, 3980805132669917, 3987625312045043,
+    3994103598244925, 4003915342903436, 4010763030924846, 4019544183799360,
+    4030039714808359, 4034162081944347, 4036941437670684, 4042186747046051,
+    4046661814325466, 4050620112035403, 4050989513856931, 4052225438036405,
+    4061114660592749, 4061567570804782, 4086179949615738, 4098389858759222,
+    4105645264216627, 4109518088632753, 4114939256854007, 4115285079835863,
+    4121410665892318, 4121496139186129, 4134928218185945, 4135259304393006,
+    4167944488436663, 4172170995447869, 4177319042109589, 4202647628175582,
+    4202898153347097, 4218850220836858, 4234813095294802, 4240224438999526,
+    4247344266128817, 4253818622522087, 4255177353158186, 4259529613980976,
+    4262358092289963, 4264110786038915, 4268078908265382, 4272191450884840,
+    4284090905305976, 4285619600078044, 4285736511824751, 4299971253825068,
+    4355464052993120, 4374893999334501, 4377454268342219, 4399991709262039,
+    4401833806111414, 4455735033601606, 4456138821230989, 4459614735179303,
+    4467568663761991, 4488052780727041, 4492874653439965, 4493006301961467,
+    4496704249983359, 4498727117936886,   31533219019958,   43001377165427,
+      57775485123241,   67347511578420,   73740324484491,   97254322347393,
+      99339084755059,  113833193991768,  132006584668962,  132471284300831,
+     142951096083382,  144400212271557,  155128584322317,  168981152629617,
+     172310681281634,  174217619940107,  178730020382336,  181802146701267,
+     200117010416493,  214231893315144,  230610344822307,  233115596537457,
+     258250639627809,  259441371031245,  265556150119517,  267296071996980,
+     270587285034530,  277139255108387,  278801563305263,  293864512049059,
+     310085771291742,  315535430610933,  318888538102622,  319256957472497,
+     322175821333560,  340781490738074,  365050993922133,  372069628531165,
+     380696536496158,  387393909414659,  387617908391543,  391722591397777,
+     402835102044860,  412923898069517,  415046976091650,  440887419468856,
+     448917979280478,  449533976466909,  454874583652617,  462101498012085,
+     476693852414094,  506212594780132,  543038812541184,  566489933498294,
+     583159190694745,  596234639744522,  607780411570578,  617317069766249,
+     619332078106552,  638872059033069,  642350920336342,  654787775811669,
+     661222834138819,  668779852253695,  674355265384470,  678627947623367,
+     695953482524022,  698845820190454,  699189678269004,  706852801162404,
+     722837289556714,  726509693835628,  742628778106399,  769103885251443,
+     804881827098744,  812296389723935,  839364897667391,  841746360474263,
+     847449492319928,  856051838993596,  858558073160399,  862114548144259,
+     879894958160253,  883689186444139,  921957643231795,  923022620823647,
+     924809700380454,  925154540910657,  941071240140910,  951552034375114,
+     969316725164660,  991428764518731,  991442518841873, 1000540021148653,
+    1005348139538435, 1011971828582961, 1015987108488772, 1020695016809948,
+    1026919830483356, 1045650271247801, 1049368850754406, 1058676597714928,
+    1096062812918190, 1105116104900585, 1109450681593621, 1116910436994850,
+    1137503606093383, 1251149683505811, 1289826840181115, 1323563247493482,
+    1349035271500813, 1362774857868018, 1365498213850134, 1367098627592871,
+    1388888422804758, 1399987179128699, 1401211313888337, 1499438795058583,
+    1513041820646021, 1515744545143424, 1516435208655483, 1529597113450724,
+    1536867255682924, 1537215043568086, 1542145968414493, 1589931434364760,
+    1605846168691707, 1640473659653345, 1644756166408772, 1645493005148522,
+    1651869116376492, 1674661012274439, 1681664910108676, 1726299653607772,
+    1751641011545254, 1758902311712577, 1784576720760426, 1786331379412684,
+    1798910690377696, 1799119952579785, 1811161862490606, 1827680804584148,
+    1829540585563277, 1834788842293603, 1846897558916828, 1864152357298467,
+    1870146294833420, 1875443674146396, 1889620451499186, 1895453266963047,
+    1897943781903402, 1915012896922624, 1921386060795635, 1928478379278642,
+    1935249436071118, 1939240154685604, 1941226671927970, 1960118234763507,
+    1976067354898309, 1993861519237445, 2002749759342136, 2002919723478105,
+    2006108761543743, 2024801869145374, 2032162396929650, 2034737402712163,
+    2039263557477534, 2048116429322717, 2065166877760532, 2075177077652949,
+    2084036826659703, 2089055189703227, 2105784376450511, 2142454384398736,
+    2146077666095000, 2202912494221050, 2215441700151759, 2219724206907186,
+    2219797890781161, 2236918093286339, 2244571391663209, 2245403528213300,
+    2251592973627200, 2255683902310292, 2298856757749724, 2301648885347550,
+    2379963053963139, 2384744646158290, 2385368502957945, 2387909122932603,
+    2389327783119535, 2408570081195193, 2408616256422884, 2425980106938006,
+    2433480142857008, 2479913755332747, 2495917892760117, 2505320937530980,
+    2507820294536212, 2523288013361044, 2548638213363403, 2570392640315782,
+    2571768072629982, 2586012639146829, 2592975274011640, 2601853689599801,
+    2603612278058671, 2613665705823820, 2649658804583128, 2650609817783232,
+    2685454430560183, 2736735459491824, 2767087320859606, 2794482985203511,
+    2794668668565928, 2802852490835418, 2812948164021646, 2818929329685110,
+    2856560175349969, 2868718014555844, 2878792073805706, 2890556932350381,
+    2921627948328159, 2965840237616465, 3004594025520703, 3045171243692909,
+    3048390737759790, 3054735410534864, 3066342094363406, 3091189279119429,
+    3099397662680244, 3130769308863840, 3141640136404285, 3168036647417089,
+    3170178392020629, 3185854390595897, 3212796162276116, 3236835771773373,
+    3237352541342851, 3245401767735880, 3257191187571880, 3283103349919755,
+    3284954288834007, 3302883049049604, 3308834741163478, 3327527848765109,
+    3361450921891546, 3366792511528907, 3370203583668123, 3403682588647404,
+    3415111448726753, 3434632763071863, 3438009449403224, 3440620805896898,
+    3453073380598673, 3456224103049844, 3456719258682956, 3471716383166001,
+    3479599575229673, 3489760090224999, 3499552185850450, 3504550899860914,
+    3533236523225208, 3539644072906074, 3544834364988873, 3554847512236249,
+    3561023203327007, 3566631037362331, 3568170539102582, 3574695982981808,
+    3576287554659668, 3607494148965560, 3608543407330964, 3617842312226609,
+    3666208407103799, 3674343106790639, 3677634319828189, 3691518326588385,
+    3695463852426833, 3723786951131170, 3743283704184955, 3755484771263562,
+    3763636172628503, 3791323625113349, 3820920963611627, 3821347347629029,
+    3837631483777504, 3854365582783053, 3873415320334723, 3896049041516537,
+    3899477797785507, 3918410623590470, 3920183948824135, 3930877935067040,
+    3963015893539976, 3988794442463043, 3988975213567195, 4013218190556623,
+    4033439010478669, 4034524619555234, 4048640484905538, 4055396804923219,
+    4065325461328437, 4065711564828066, 4069930212226048, 4085310492853763,
+    4086802836914670, 4087461079522180, 4088180234132176, 4089145984107075,
+    4099396884654477, 4121806606859407, 4142980404884863, 4147486910617174,
+    4150152301951763, 4185171791122948, 4210697849971194, 4211407180064660,
+    4215929405023419, 4216471718335875, 4224822557386375, 4226969214248180,
+    4263303223731079, 4277609684702065, 4280210234227556, 4291173412223383,
+    4303231041360652, 4313852326181235, 4324841047920040, 4326647776509907,
+    4332629924625024, 4341461182533841, 4355751924278379, 4359885098382550,
+    4373181599054252, 4387037114716511, 4388764264722485, 4389910785801536,
+    4403636617845599, 4419723281211821, 4424998064136778, 4456569148005933,
+    4464369814130753, 4470193787529737, 4495257111649420, 4497455838448834,
+       6221347582996,   43540756073854,   44900469161606,   78490491177676,
+      84196570378300,   84859725244075,  124309088918637,  125904590403109,
+     148653258428324,  152537872264286,  182077246115037,  203341429692569,
+     210071223515619,  211084131169862,  218062485261121,  221728012378464,
+     224729402178379,  225729537961133,  229901027679771,  234315182956700,
+     250122830053470,  255999855841716,  258199565092783,  264714202003826,
+     279163118464497,  329338889286513,  334156832192825,  340879748854304,
+     348844484405175,  352859764310986,  369530986410743,  394691573244073,
+     411656548388077,  419820721624507,  422114746234262,  447957154514774,
+     451805417639575,  464705990295118,  483849060753823,  514568359039827,
+     520490577604111,  543246122790897,  544594046458813,  559028226144689,
+     577233055274779,  581495912997146,  607312777534680,  614648744027631,
+     625038170258106,  638241338022773,  640414521079209,  648894061296252,
+     653846600079025,  659150856553572,  681159738484078,  712202263363919,
+     718653040917517,  724553645545435,  732393609736375,  757404863918449,
+     775929972287417,  822115024494947,  836080574742342,  848059607747371,
+     851846958869686,  878927256232978,  906082219921898,  907209091967889,
+     930281969038594,  931154386106458,  943955731145048,  945266321650150,
+     980451845150692,  982120048057486,  999239268111011, 1013974078002705,
+    1015329861283845, 1031572734462894, 1041341251248673, 1046777156244722,
+    1053108074696654, 1060912670628086, 1065975243995995, 1069158387351715,
+    1069503227881918, 1086984972595400, 1090161238789549, 1109830903334262,
+    1121165448054923, 1124598134130505, 1177428489318927, 1202929004424195,
+    1298717058140042, 1325500655104128, 1353234282816665, 1380775350005214,
+    1405033063769437, 1426604754714358, 1432146764488931, 1438807786696271,
+    1482706673907270, 1489576958316699, 1490074078853117, 1514916351350875,
+    1534041737679826, 1553324316273257, 1554468872449002, 1576501333219180,
+    1586178482001230, 1606404214181541, 1613645865315804, 1617612022638965,
+    1625579705544795, 1652624634648579, 1654864624417419, 1683328213708135,
+    1763749741119409, 1769443048448544, 1772603595416245, 1781291415383724,
+    1789677622693732, 1831152801676780, 1835649482892561, 1850982605840932,
+    1857728118890430, 1877862483067012, 1934906573395151, 1961540837707981,
+    1965401872704271, 1968555542510401, 1976627365291449, 1976992837306365,
+    2002751737196372, 2010349035829021, 2017656511224035, 2021774948553411,
+    2033095738950930, 2043947899909968, 2053627013595324, 2070807145651335,
+    2089014922136384, 2103601381828475, 2104259624435985, 2120882706404745,
+    2141936645328535, 2158810252468810, 2173211028798484, 2177911077506436,
+    2184803958303884, 2191903153948462, 2195078437690958, 2209971422298785,
+    2214629225585658, 2228797160873571, 2231112799419692, 2267971438085293,
+    2297464636708353, 2305692669302228, 2312163095888886, 2314159437647782,
+    2357223240953731, 2402753980360363, 2407381327645993, 2413775123003717,
+    2454973250620619, 2468282523163810, 2500940198561183, 2502911979028754,
+    2517736192020871, 2529292770815110, 2570600933017148, 2589798038316768,
+    2590902313974740, 2607156976573625, 2608252410166720, 2611009169505038,
+    2618737134207536, 2622664975916230, 2632155458884210, 2641978010510904,
+    2648775593498011, 2669331429433730, 2675035543731048, 2716464547486405,
+    2717909733867968, 2744349472753504, 2753811464623547, 2841908886799710,
+    2844524173099996, 2867735575853774, 2869077604811772, 2875771047923661,
+    2880181273393978, 2899995358331682, 2923171392825952, 2933966571589116,
+    2935046285955763, 2943675158824062, 2963443068534075, 2977904756866235,
+    2990133332591126, 2993287002397256, 2999329080063206, 3027405583402640,
+    3036304630475514, 3065700566384927, 3074863892952458, 3089279406056927,
+    3102172119099246, 3159148420263328, 3162459282333938, 3169014199762754,
+    3193867279228695, 3215489075207919, 3226702778375261, 3257808180160894,
+    3272099904357085, 3290848029251284, 3297465823585892, 3310569763733606,
+    3319036532079160, 3319124952727930, 3329943710330766, 3329945675234072,
+    3355587663377372, 3377196687485107, 3408548684635643, 3430559531469455,
+    3432830959691191, 3433476430427212, 3434162181681006, 3448688711822264,
+    3452582167723103, 3459914204409442, 3476886056715017, 3485158299633277,
+    3493743944628844, 3529342097823646, 3529812692165433, 3536486486244262,
+    3545020061302220, 3558717402248346, 3561384758486241, 3563439064892664,
+    3574522101990157, 3578835064746827, 3582075190298421, 3588586879854505,
+    3622905880997101, 3627879051264587, 3628803538270060, 3644511957749877,
+    3644691746402376, 3671347624651572, 3680262390950894, 3768933564892409,
+    3789695715675258, 3809594291455120, 3825215272737820, 3826513091371433,
+    3846695595679012, 3849368846626825, 3855743975403142, 3856107482514752,
+    3862110262114582, 3868045252550355, 3873287614570763, 3890024660931271,
+    3893408224424203, 3895632494966595, 3896791787917135, 3900240193219165,
+    3922888651175774, 3940585552801263, 3945411355320799, 3945642231459254,
+    3954780996735460, 3954890048868943, 3970761555323158, 3974455573538438,
+    3975194377181494, 3990022519980223, 3997316241052095, 4004677751288024,
+    4007965034518962, 4031910328657531, 4039218786504198, 4042979611431882,
+    4049614107444591, 4054217875890549, 4075832794708202, 4077081490759165,
+    4083708127158650, 4085319347869570, 4107601351359610, 4141488073774886,
+    4141756283076155, 4144967917529812, 4146021105701828, 4146943627803995,
+    4178742640456646, 4184466403787024, 4217379516614177, 4222167003519246,
+    4223065946781741, 4225115340929899, 4252372479590731, 4279026392936621,
+    4289255679547657, 4290581006827554, 4326400211644281, 4342709891535734,
+    4344507778060724, 4358571573473419, 4369352015461788, 4379364180257511,
+    4394182498539710, 4424690569720319, 4485554432075322, 4492629066428575,
+      16941872971462,   22802197081607,   54149281973878,   57803019671385,
+     103733616900788,  113524730074586,  121622096598612,  127539402904631,
+     132164785286955,  155333942619654,  156565936992516,  186656466220600,
+     205107890715593,  210084008338038,  215011003377833,  267046555178978,
+     277123561783799,  281001298458190,  305729606564200,  308338015702915,
+     326049654103199,  335787714887735,  343914554961351,  345616161224347,
+     353542581160751,  357280809700416,  363029134322119,  377372928455919,
+     402969723823181,  420827747519762,  425575936358711,  439291943886244,
+     511370491860242,  512687959526915,  519569050904527,  520149679831450,
+     534311720409445,  551676553376220,  589436100207622,  607706753598463,
+     649371545750540,  652792442406286,  659592972748352,  662342854925099,
+     678878498696742,  680984875040774,  686859935925714,  729873634197360,
+     740019412417891,  757334140350363,  785134574775304,  799783911373187,
+     817548602162733,  831525941829964,  860255775518643,  875296127874420,
+     890302094422342,  897471044134283,  899884927845704,  901919585219067,
+     923604258104083,  923813520306172,  924466850655417,  932188920647997,
+     935139222961956,  942897643665697,  947435587850904,  953174087956077,
+     953623068361498,  954386433295879,  959597356863391,  968345106381703,
+     977626327147594,  986066569298517,  994608003969699, 1013688197522612,
+    1013866021271805, 1018441298619826, 1043461394866777, 1061911836910117,
+    1081502905322590, 1082600303818991, 1085770675303222, 1097335113710685,
+    1113452233078150, 1289528200780463, 1321863632035652, 1360292228442747,
+    1389760865774482, 1416806777329919, 1537283841085656, 1546133765575880,
+    1556171474114581, 1570878775359991, 1579712980623767, 1583425665420454,
+    1583963066474645, 1588175819162709, 1609513686614216, 1628122303373689,
+    1629821944733379, 1632816457371723, 1671523087596617, 1751331565176419,
+    1757617290852313, 1820296723862060, 1834722061483059, 1850417709091387,
+    1873579989262515, 1883529277152446, 1884172782985161, 1897821001348637,
+    1898647243188810, 1907564956843091, 1934816200794005, 1934889884667980,
+    1982744122233957, 1996809882549958, 2010631012404362, 2022474467081277,
+    2022502958179214, 2042126447496236, 2048357155879562, 2052445137207695,
+    2062583055815002, 2073755496012918, 2092971267893945, 2106162646238776,
+    2121360190859033, 2148069121497491, 2154830353773437, 2186357227318207,
+    2196910722974733, 2216058705691703, 2222727587512267, 2225511855496869,
+    2244328752006778, 2254529547519877, 2292941442248871, 2311949916831115,
+    2337200889216521, 2370347825537088, 2409607576042621, 2409994661993903,
+    2431800176432238, 2444415838108411, 2451216368450477, 2486341962400186,
+    2494608310608528, 2500405757812881, 2503359989933452, 2505903557263069,
+    2508209371292660, 2513315172533301, 2515413689264109, 2519575354466217,
+    2520556823667564, 2574799944333000, 2592983159526724, 2608999086373930,
+    2617572941949661, 2635962471990515, 2659801661350560, 2676180112857723,
+    2739616033640280, 2747868627525480, 2754154353201374, 2780888827582810,
+    2783257518518193, 2798335204036784, 2814974005231992, 2838693335490371,
+    2891233867441158, 2892037512893312, 2929421763193268, 2934808545606667,
+    2956436236295809, 2971555184783826, 2983273868100810, 2983349516878091,
+    2992191581755091, 2998198291161533, 2999827196002207, 3024824695861139,
+    3047423048783445, 3061113512568000, 3061485861744487, 3078643397412479,
+    3084526317910643, 3110573076134979, 3127850470904637, 3153967965647989,
+    3154322630694722, 3174232013442767, 3198666568504530, 3219630121876244,
+    3225476691663247, 3227873873696567, 3233036657133082, 3268161268631138,
+    3297677063642217, 3302239569118749, 3303609106723031, 3309495957027807,
+    3314645968592833, 3354826276297227, 3362350873507554, 3373941838109648,
+    3400206700600950, 3405714324567668, 3417586270342520, 3425890934165329,
+    3443141802740356, 3444637094156222, 3474822921194647, 3488730506794515,
+    3490069588397554, 3509660656810027, 3511182474420524, 3519173736166026,
+    3548955775575068, 3565611278448377, 3569104876526445, 3580834366811612,
+    3580934576880218, 3595735211032663, 3607992277855491, 3662362134784164,
+    3671123638625618, 3671921389367854, 3687186723152168, 3690156674499187,
+    3696977836325966, 3718513176559726, 3733031847087760, 3741537913499434,
+    3764480124500290, 3774304641030290, 3782943338415119, 3815924240406329,
+    3838842872567513, 3840218304881713, 3840822512648308, 3865455522943977,
+    3882852776815301, 3887078301374854, 3887398580613732, 3917121672923594,
+    3921417934002163, 3932370305029807, 3957957275880539, 3985809780243089,
+    3987640087672628, 4002720720546178, 4003797487557866, 4020646533406816,
+    4029004249618887, 4038787503179461, 4050532712691076, 4056794859527298,
+    4060718771429380, 4067342460473906, 4082498742124737, 4093817567618950,
+    4110048651378163, 4116318657827609, 4123084802361820, 4131532904125967,
+    4141877137580404, 4153400313018441, 4174779443439374, 4181866849664116,
+    4194296827977872, 4194481528888636, 4247986828362669, 4302554158073595,
+    4306073299894641, 4319911131427146, 4340958193189365, 4361704624745766,
+    4364619558800217, 4422483996258611, 4441318576898274, 4494570403845833,
+      27917835789708,   38214911564801,   38967469530999,   79446442537905,
+      92552347588925,  104468503688162,  128129869299014,  132192306884169,
+     136826531331370,  166456290734197,  166893481719782,  173608538768037,
+     190805372502149,  191642421310505,  193231045633406,  202258793872823,
+     211848504457756,  234082367816799,  247840620765411,  252508248568814,
+     253761856878042,  257893066078907,  259049411674488,  273996431123230,
+     289796218606776,  290705968837454,  297440674918769,  320721831739910,
+     377079188362602,  378111745049905,  402493247722406,  408361431445775,
+     434081033269662,  458166817994610,  462794165280240,  478951565165478,
+     491753892655721,  499407191032591,  500113573771098,  526114156767743,
+     527522992438145,  527771552706354,  550259871043524,  580355312529873,
+     655169988357476,  687163526437421,  690732773292770,  693219358426513,
+     697567689442691,  737240069642484,  775641157403295,  778143461763486,
+     793766407949492,  804829796013925,  805927194510326,  834365240058064,
+     850380184453617,  850972602800376,  872170962117157,  878289671012041,
+     909883351269215,  916754618130297,  962921986208073,  971692332114404,
+     974188741764677,  980467590279000,  986098020702343,  997338250064316,
+    1020162566866812, 1040337211561167, 1114612521431273, 1158897512141901,
+    1162776231267945, 1165916146750933, 1181012498850931, 1250126990187828,
+    1262143356355671, 1278637737157888, 1304209971233825, 1404009375048871,
+    1426449553255044, 1489912000232232, 1501548157610364, 1506980132799801,
+    1527353232728062, 1538154306201144, 1603192605629744, 1606274556465205,
+    1608496862104291, 1619652600624106, 1629597958707425, 1647614157120139,
+    1705627927229789, 1706154521315797, 1709946784696377, 1717878116891046,
+    1732528435940582, 1761620794289218, 1766291369447580, 1781212845153344,
+    1781540984005446, 1781614667879421, 1831349317909240, 1838038831214517,
+    1856791868366981, 1859160559302364, 1928660171687237, 1988738072719840,
+    2021669852128400, 2030343917772737, 2037312447347466, 2071973341665306,
+    2105071155403223, 2128478066035948, 2133070045062070, 2144882061286089,
+    2146677982907773, 2152244553973671, 2154511069937142, 2158657015912802,
+    2170822714731901, 2196202388283850, 2212981680065437, 2217649307868840,
+    2255022751200613, 2307074022228206, 2365969051470597, 2370230926741311,
+    2380352143670517, 2409010258388527, 2424879799939436, 2461510509821541,
+    2484741561608379, 2500867523040721, 2533321830945923, 2574268450939657,
+    2609111098813302, 2654615312025303, 2692252052400080, 2705816762373051,
+    2710240742166510, 2716606046426297, 2756608530381498, 2769595558782505,
+    2774854622481014, 2791147600694366, 2816647133347981, 2834648594985900,
+    2835536731280212, 2853799525057829, 2872830596028092, 2874546939065883,
+    2890069675183283, 2916939727892833, 2925819125932647, 2948859582098803,
+    2976364298576191, 2976799524658470, 2983256196921986, 3018761017209753,
+    3067134971700167, 3070222817245546, 3073544486284339, 3111162560077709,
+    3129314336818537, 3134827855495173, 3149881962174092, 3151070728674222,
+    3192299312292367, 3193321062011487, 3193398675692074, 3207000718827859,
+    3209871442557925, 3210525755358823, 3233722421337806, 3236352444412887,
+    3283018897930387, 3283747877056913, 3291893383711936, 3308320957801749,
+    3348271371819341, 3364564350032693, 3370837303837098, 3420801847553719,
+    3440126671568229, 3452778683955563, 3454980358109936, 3455308496962038,
+    3467280652805496, 3467797422374974, 3479446351624595, 3491591418958981,
+    3496887815820304, 3508959199280715, 3509586003435329, 3515979798793053,
+    3524124322996423, 3541986276499616, 3552431702474312, 3590361213441683,
+    3596422940140693, 3612174587493242, 3618680382339408, 3643730934587602,
+    3675762788282014, 3688190801692464, 3691881872552785, 3704857111533956,
+    3711671396199164, 3737630716226383, 3758622760696034, 3761656571400498,
+    3771501719415211, 3776341276257889, 3782992473948699, 3788573781789392,
+    3805688089584652, 3807129346159603, 3822408434267059, 3861015836875000,
+    3861115064491953, 3871647928663766, 3881030341949916, 3881489146871867,
+    3910662066256049, 3935796126894748, 3944535034348183, 3962944213422097,
+    3965408202167821, 3965441605524023, 3966762020545655, 4025006666793760,
+    4043726300590022, 4050140727432459, 4088910234563145, 4142855672377722,
+    4150284971777708, 4168309029803646, 4175416085061448, 4197204897821682,
+    4197795351265135, 4203733289055867, 4203929779386467, 4209012984239089,
+    4223015867649298, 4240110526411498, 4243650299717257, 4255514385878885,
+    4267308717973150, 4309805646675318, 4383728256401997, 4414050644220189,
+    4427551494835715, 4487153889268266,   20129954487307,   23346501199229,
+      45560715525212,   76469626980245,   95010454575661,   98879349185175,
+     110194244872776,  114735136412942,  117638281047557,  121070967123139,
+     124413267646645,  126286802948916,  135538550165217,  142640693164754,
+     182394616851746,  186777333675779,  200673129855811,  221750647619273,
+     241379049194560,  252258718799882,  264339926776823,  266295988017946,
+     287266418551231,  305145073732525,  313547982720634,  317206632676406,
+     341656906964617,  343560898268131,  376065311207636,  380538413583745,
+     401455791727768,  401719088770772,  402153332401398,  405796263130722,
+     416202391039298,  443977281721261,  451579492612175,  457983112486429,
+     471790488017691,  499013240870668,  501599053621364,  502763258830169,
+     508100918660918,  522650045190195,  549264660469965,  564101645333571,
+     591297871991917,  612565002924408,  618089328569227,  627151462616499,
+     646548988053331,  655481438482407,  659813067820484,  660994957159043,
+     710644133895051,  735001075276227,  762253301678794,  802967080630767,
+     803584060268851,  829417626484486,  848680556044857,  866605386453842,
+     867556399653946,  873406899247561,  881486581641833,  885486142321196,
+     891365133012748,  892876143655062,  895575920797506,  895729183255374,
+     924430525846116,  968407026737702,  980480375101419,  983081907078563,
+    1012056371228839, 1034019077931654, 1036329804219510, 1066442929835613,
+    1067322224065048, 1088273988016926, 1098152539387841, 1111531565998395,
+    1317703957638710, 1458585559775604, 1482729309148079, 1500081370243365,
+    1568216357282221, 1582842115040432, 1589155349362610, 1632395993966099,
+    1650057527332080, 1665700122551146, 1678677326435623, 1722140987564343,
+    1741175988341218, 1768945966764916, 1805149310177966, 1809204870601550,
+    1813106186115613, 1875189270971989, 1916496450722374, 1940994865141582,
+    1961032949056170, 1971371287800689, 1990389586899463, 2005299273185391,
+    2014662037438481, 2048550724757063, 2052047270190090, 2116437151527710,
+    2127378715587171, 2199620350535567, 2214103652804093, 2232899917829289,
+    2292579925942427, 2301622410956639, 2353166736931284, 2362044170067792,
+    2368426176005680, 2372629104177214, 2382339656315466, 2408894342044403,
+    2418054721256975, 2465090576596003, 2509103428198750, 2522714313399412,
+    2541929102828786, 2563977282825412, 2592046909003275, 2619481871413300,
+    2630015718036766, 2632426654393228, 2642614678034838, 2650897727921281,
+    2659149339354828, 2768420559556447, 2796707307549623, 2815019223909890,
+    2820905091763013, 2830059576265667, 2836739265054414, 2839700374336556,
+    2842983727760882, 2856166264040836, 2878209531779197, 2891059999400437,
+    2900032730347286, 2907040557988135, 2922520066232803, 2928541512414040,
+    3041385909277620, 3079469665154512, 3084985148734454, 3120264005142031,
+    3120376004630473, 3126446573394360, 3132811877654147, 3170182373630961,
+    3227395445643416, 3229178595393611, 3232880473222115, 3245643502646238,
+    3250996881703435, 3286073353070494, 3337500767298432, 3346753496966386,
+    3356127068187659, 3394315946391422, 3404650355329329, 3446393743613646,
+    3517818961238399, 3531841493681668, 3555558859036741, 3606565783957195,
+    3616184968091718, 3645017959203962, 3653296096832140, 3662713878377798,
+    3667404102569220, 3669967318931897, 3695370571323518, 3701560999189071,
+    3706876062631801, 3712384669050172, 3734602813182767, 3739290090019230,
+    3768859919871224, 3796408846672997, 3818831340749416, 3827683230142946,
+    3833872675556846, 3838165006828803, 3854266406969820, 3854980649321551,
+    3864611622875910, 3916691385001440, 3920018948750151, 3921319714738723,
+    3944518345621012, 3969367495280341, 3975616870245074, 3977478616127509,
+    3980080148104653, 3991405850760437, 4020424525235098, 4030189112214265,
+    4035112177447448, 4079169239374580, 4079570079649004, 4134087304325627,
+    4138388477662461, 4161594968157974, 4196551580423367, 4215762440046129,
+    4230062023855544, 4236811466711654, 4258428350432613, 4262812049708299,
+    4273244703811506, 4316678891390636, 4322968546873142, 4332041487888597,
+    4334092846940061, 4358715050267547, 4369650719617090, 4391170340624402,
+    4476124899962618, 4478032821072744, 4490388133060872,    6386451264420,
+      13591751687522,   51296281226356,   57458217993972,  124205000847139,
+     180607550245869,  187756850924750,  206896974028496,  230412936794704,
+     238803073911324,  245236167335168,  250196565731165,  251160350802758,
+     277152091734526,  291809287945633,  293781068413204,  311479934941999,
+     409367487840307,  409661240884554,  438533530062918,  444981360261557,
+     455579066242468,  498143784108693,  517752536650920,  540497274869523,
+     571426817809269,  601365067031138,  626228953465262,  664303867277277,
+     684871492632832,  685541524660178,  690049012844142,  700880542318467,
+     715310792197731,  730685178115528,  732319977666120,  733867339019595,
+     796694139777292,  800122896046262,  842720034817036,  855807273286649,
+     907665001338601, 1010534566569272, 1025802847708545, 1033534742217655,
+    1035765889921618, 1046726120562486, 1074805571256879, 1079934951337192,
+    1083667285166939, 1085834573513457, 1099844334085237, 1296567505726998,
+    1308292083753900, 1348270006417776, 1381719537847467, 1383921212001840,
+    1428392868526538, 1431746958469880, 1446870819216162, 1468991700635110,
+    1483418020707762, 1503580875982281, 1520281571631628, 1524422605349023,
+    1535462414573784, 1565951819172986, 1572118668198867, 1593229589318531,
+    1594747477122416, 1642464171456973, 1692084857095044, 1719568942087719,
+    1755062955407303, 1781251186669671, 1786558390499177, 1790692547055001,
+    1837829594914288, 1842080663216819, 1842831256279711, 1845574261294887,
+    1847171727682665, 1872577927429245, 1877280923492156, 1883792613048240,
+    1887516104813110, 1966237990864694, 1984012506170770, 2030481486906017,
+    2031732147860286, 2048959437595641, 2143712969720879, 2148882630318965,
+    2154616218165873, 2213016091775152, 2230448713905984, 2238452747522975,
+    2248981681888176, 2283726084596521, 2304095254718170, 2322757906318558,
+    2405957807004516, 2441794695950997, 2456178770602570, 2457685851438272,
+    2514574714405237, 2528958789056810, 2547656808916706, 2551881351024606,
+    2554550672165807, 2564492100442514, 2583407242117723, 2592075413052142,
+    2595308661442165, 2672355467425384, 2703416658886632, 2706675451019633,
+    2786970242167670, 2805864752358166, 2840405787574340, 2868468536590632,
+    2869074709260533, 2936447313816661, 3006510853450356, 3033586238555383,
+    3067741170271928, 3090243242932240, 3105081210247499, 3124889400475285,
+    3151672997439371, 3159474646015844, 3170159790193872, 3177225582482248,
+    3186890941844462, 3214378956643749, 3222651199562009, 3241849287313282,
+    3268624042212491, 3269938562524205, 3273872298942817, 3295629673250155,
+    3315769932136655, 3334865844916016, 3363408030338972, 3370667365602989,
+    3371183152720814, 3400248002423166, 3425427255837903, 3426021639087968,
+    3442599528280690, 3451437663351078, 3461632564154259, 3471398133585079,
+    3486536731106156, 3511697317939486, 3553924072437079, 3575765937586575,
+    3580065146020103, 3584405617423057, 3615198599583036, 3615422598559920,
+    3640553711843660, 3659115170923789, 3666545452775428, 3668927898033953,
+    3681961101662651, 3700272035571265, 3704199877279959, 3722324145374503,
+    3733391463245548, 3775397166121216, 3783694952782454, 3784017196924638,
+    3789787135482707, 3794608025743978, 3858997907081598, 3862254734311293,
+    3862743012782834, 3865780753293910, 3884542632511251, 3891836353583123,
+    3901647115789981, 3929201937301672, 3960392812381116, 3962250628456939,
+    3971465042510426, 3981960573519425, 4018862440057758, 4027317418983476,
+    4045331652492884, 4054747469135236, 4086151536223381, 4100882416308463,
+    4107635788971185, 4109830585963987, 4130125089760008, 4130948384245222,
+    4157644543012191, 4168619510427854, 4169118595867578, 4171638584357523,
+    4185383082982993, 4212042891038801, 4237251618003128, 4250981379853803,
+    4270372028129064, 4273296786700045, 4288061070141329, 4299646140033505,
+    4321472285956553, 4335132293739865, 4375831335917043, 4416225818081791,
+    4421563477912540, 4453897926716076, 4474595235689827,   12575909629250,
+      13873728262863,   40082591009944,   49751880178770,   67803446850992,
+      87670584177958,  112248577181059,  117449676232041,  205723939705744,
+     211139213217080,  214216251794276,  223737190763499,  232692237580594,
+     270441959895466,  280484580692432,  308807679396769,  316347013381891,
+     317587849819630,  322514844859425,  324043539631493,  349117670719359,
+     364092198814385,  375284288045361,  394143429976349,  413584183285913,
+     415784874988633,  429994073245972,  454348067272189,  474730009265327,
+     549830578523953,  567010710579964,  598752741036741,  664351037907551,
+     701285325350433,  723536872839230,  770605149082807,  800679959084443,
+     815963959450164,  825100759823064,  826174579479793,  867644846204576,
+     872895067838208,  902567072662114,  959928494874172,  975183021690303,
+    1014680525495862, 1023562870890635, 1121031899384765, 1245472173108704,
+    1264310683554979, 1305788809892986, 1363802580002636, 1392010731863572,
+    1392450870204116, 1405065549428636, 1478015532018845, 1484892693589845,
+    1511376642799766, 1526544713870433, 1536889929776523, 1554213499773872,
+    1610120893539490, 1703038223525271, 1730343502317100, 1739926335740462,
+    1743477898466057, 1748061035427302, 1807755780315235, 1840928260378780,
+    1856060963189939, 1876577501059538, 1879141699873868, 1949723973980347,
+    1968137082860873, 1978455772572332, 2009837243272458, 2017380507064192,
+    2067627996856877, 2074717367984925, 2083207715170151, 2126578043391836,
+    2164666711526993, 2217794749566274, 2228102632309550, 2294515381600697,
+    2369705353959746, 2378305735730108, 2413385154452126, 2420488279903316,
+    2451993539511720, 2459449365106337, 2462142265087210, 2476937004529737,
+    2545376551581023, 2574990591757402, 2663434819367074, 2679671797836205,
+    2751394698311817, 2751574486964316, 2778510363934617, 2795228743713718,
+    2906553287870107, 2941319304514818, 2960679496788836, 2963074713918850,
+    2988668561931153, 3033612777700944, 3085157103675589, 3093225979101678,
+    3104001508831782, 3109418747246424, 3117743060102293, 3151456871026641,
+    3153389353428092, 3184845490453846, 3220543853717254, 3222221881140578,
+    3244162973907027, 3291592792358908, 3306337426767132, 3316582432604616,
+    3338509771047923, 3351379887702223, 3353946051419859, 3358820976522045,
+    3369881417231519, 3377312681534811, 3378002362595217, 3378010222208441,
+    3382710270916393, 3418967649170358, 3520052099747528, 3543090591010378,
+    3553066405094940, 3558100487364912, 3607854786427791, 3627875186212625,
+    3634210034471169, 3668498579612522, 3681379503235005, 3694172006208718,
+    3731101381393335, 3734426980238740, 3736421357094330, 3801906672025045,
+    3811950275273664, 3850337608711333, 3851053815966370, 3857850416501824,
+    3887994980570823, 3910206247541847, 3921968158731563, 3928361954089287,
+    3960873244190363, 3971367792747709, 3981231607343829, 3983114967162630,
+    3984722258066938, 3998311529331234, 3999407945375982, 4055791828193305,
+    4120638549549570, 4128765389623186, 4196641009425650, 4233388631054462,
+    4240586071864340, 4259065005005617, 4289966056847426, 4303670274955123,
+    4314666856307152, 4330154224165044, 4340370738904591, 4354315657667273,
+    4393739477598857, 4462933547519647, 4474258267723778, 4479888698147121,
+      43976059861713,   86334462880808,   96190417863704,  102488915411087,
+     133421405705792,  147458674923856,  174786550103704,  181578238380893,
+     192571872377963,  223736221262776,  229457037238195,  237286194460952,
+     238528995801997,  245177246137848,  254616641619872,  260673456060617,
+     263794704962198,  274056412477783,  290655915606871,  292839905631490,
+     293151342805491,  306724894843339,  307871415922390,  314960787050438,
+     329813491140492,  338987624676206,  376481909561298,  409721196337247,
+     429251352747234,  482182900455915,  493709023248911,  512948373969610,
+     517448002540350,  528902406362677,  530359382164076,  535295219268748,
+     546216151843496,  575720157434739,  578862037821033,  582534442099947,
+     591850048673693,  624747442274398,  679300035210529,  687759926394512,
+     732110741365891,  760808154150021,  788601711413391,  804390691928754,
+     806754470605872,  883794399427520,  890256966400954,  915377272716511,
+     924264530369549,  927016377449602,  930101275640022,  938497307466560,
+    1008323093800229, 1023756426817206, 1321726153558882, 1330229272615597,
+    1353232395618939, 1449152097856288, 1452126961461572, 1470833823386345,
+    1476711831626244, 1490840468848037, 1515387023398242, 1533276485547719,
+    1537386080812218, 1539894279882327, 1577728493039357, 1585916245115459,
+    1612431632778276, 1632667189475117, 1645086360820690, 1653356638835644,
+    1667579591416125, 1669596564659734, 1694685432522395, 1702887921373292,
+    1751105666050879, 1756901148351926, 1757347181402388, 1782673802565075,
+    1814183974431744, 1816546770657209, 1835606332725409, 1856298729440895,
+    1881306053816357, 1919384897434984, 1920475418769814, 1932143997052495,
+    1974278401094706, 1974669416852600, 2025100625104396, 2118144691353414,
+    2149152830425400, 2159828150086898, 2160178885327019, 2230966491828975,
+    2231359472490175, 2235719592926189, 2250487806174085, 2266294470819202,
+    2269797893413800, 2275569796875175, 2289470505313472, 2326079601259211,
+    2340974550770344, 2354627681392085, 2399633791616015, 2417795392873373,
+    2456324199349074, 2465775384250934, 2534967489268418, 2551694711112396,
+    2560981826588205, 2583561512929104, 2592546033295789, 2659456885575007,
+    2680736788378987, 2771738337641418, 2867434040901883, 2869038384451232,
+    2886035780499785, 2905954005312707, 2909155815249834, 2931609747779149,
+    2939432027840335, 2953894698624148, 2992917678281308, 3009231287979373,
+    3047822971360866, 3067599723135756, 3137729087030202, 3143917549992449,
+    3184796380822126, 3188142611152244, 3201142411424740, 3219469064559802,
+    3233133002149726, 3239563148218611, 3241611559915116, 3303358646306166,
+    3337000738259845, 3339008869438577, 3346873394920842, 3381227764322946,
+    3405018813551994, 3422220559544371, 3490365371099757, 3516914162118776,
+    3530058382784263, 3533796611323928, 3548664052188777, 3568394628735976,
+    3576103926857067, 3613771123233087, 3617140932402877, 3624925879301249,
+    3628898913785981, 3675943611189886, 3693173848280200, 3702844119900679,
+    3754873776991906, 3757347590254160, 3765885095118730, 3782723333999497,
+    3785548864953525, 3809252475985456, 3881225901632583, 3905450212040604,
+    4000367813591893, 4036293123187144, 4064884431192750, 4064978746551438,
+    4067673611435617, 4075933082482388, 4083663994539845, 4091180732136948,
+    4132314017944752, 4138364937675579, 4149456816837949, 4197784596100672,
+    4224081879496523, 4265050113426623, 4283122311583558, 4294863591288561,
+    4296206602698212, 4297987787545101, 4328502735887281, 4329928273235784,
+    4337616939872162, 4339148581999189, 4354155530998764, 4438801600517938,
+    4443935892856516, 4448509205301231, 4450147934658435, 4450721686423787,
+      37070420143706,   48822506816892,   65189168904219,   66144111910935,
+      98957014669482,  125159982706645,  132291599255772,  144025019347551,
+     148443104431092,  150732216782582,  214368557702351,  242213202451677,
+     245470029681372,  277942021716328,  289384636118819,  312725722490793,
+     327355410055616,  337887291775776,  370214863417741,  421461506541527,
+     434554639721058,  445188696413130,  460989466348329,  462544687315028,
+     526561237024508,  534120220042690,  565695233718457,  612191723099988,
+     621647820260113,  629447503933280,  630942795349146,  634893233445859,
+     657529901982632,  663127911501426,  696842704877427,  716757982335390,
+     740635487309902,  791539254806791,  814673043879982,  822861778407737,
+     845760761535861,  907003850228922,  907429251794671,  911711758550098,
+     918575165797956,  983473957091830,  984857249019254,  986386926242975,
+     999397533483654, 1042097829677993, 1048243064767508, 1157306005218691,
+    1165386670064616, 1229226378476556, 1302621411665574, 1339273735484045,
+    1378764362128033, 1394617202000841, 1398645253778141, 1508467629357093,
+    1531266402416611, 1545946195015737, 1553125951695861, 1559674974414759,
+    1580079512795916, 1597285188594905, 1630487142208040, 1630642369569214,
+    1643983080565301, 1651217854537993, 1659037187244220, 1713592727535310,
+    1725756461451103, 1743750063475798, 1768313319704104, 1781320979589824,
+    1831079208459315, 1855586464943400, 1863010852085121, 1881453434515237,
+    1882691323598017, 1891118793877451, 1906187637331165, 1911544946194974,
+    1914764440261855, 1930988646859497, 1937139776658930, 1941135407531681,
+    1946697066339314, 1949191511086281, 1962452643498475, 1965826382474877,
+    1978478394862211, 1990711882845367, 1993315379725817, 2019331681948910,
+    2044824337440954, 2057551016153916, 2067349988940938, 2120291361166149,
+    2143946832067083, 2162208643393047, 2167450022961802, 2230003702159965,
+    2271642950569064, 2292494504452336, 2312055116863566, 2318472491060962,
+    2318621823712218, 2329982894627510, 2330491804583764, 2344185215723278,
+    2346257206259455, 2356294914798156, 2389555815510471, 2446870080043185,
+    2470459726683368, 2532161620298380, 2609394109644016, 2651205287092390,
+    2666087464732034, 2668624154900080, 2670831723764371, 2677326711642354,
+    2691476962800513, 2696846061084158, 2711910974731260, 2719470940201095,
+    2724156252134252, 2732736984871554, 2748554456484854, 2772694276050717,
+    2792471027825607, 2797812617462968, 2866897635250275, 2880713852846414,
+    2894288387335915, 2902820979942220, 2912067814900256, 2975328859288579,
+    2991052997994844, 3029983627196622, 3036814613539931, 3096980935221304,
+    3107801657727446, 3115355728487363, 3118254943315366, 3128960718978107,
+    3178450738546329, 3194114947701761, 3202747750376672, 3255521123369220,
+    3345741623567516, 3352393803709979, 3369519900925075, 3381923353044200,
+    3402012524444744, 3408251092441294, 3415138078528824, 3424581403817460,
+    3472418939705336, 3500081830898857, 3505688682482528, 3515551514626995,
+    3532954663208237, 3571404873551698, 3579415784330260, 3589034968464783,
+    3608515019840467, 3657039289433790, 3668869972239216, 3674344192849732,
+    3683843517882589, 3788418619182868, 3853330182348231, 3865849563762410,
+    3869173197704509, 3875026644653083, 3920077947653051, 3948084696925122,
+    3949338305234350, 3977383370120888, 4023886736663990, 4043552471402091,
+    4055046173290538, 4110607744074300, 4115537686469054, 4124624381807651,
+    4135235842111704, 4162115719337784, 4163136486605251, 4228632608504149,
+    4240782588096800, 4248969357721249, 4268223445216743, 4273324334199119,
+    4323270211334333, 4341702969247919, 4349107707356580, 4360852916868195,
+    4374042330309720, 4391898389102995, 4405967096773955, 4421160711587600,
+    4437717969295609, 4492435614109444, 4503542230046609,   49619288058405,
+      59052788830511,   74531314623526,   76855795234524,   78984767966575,
+      92998458344967,   95847568138667,  140725959647707,  142701669921890,
+     168308289805682,  184845898480631,  216189053566290,  225359257295392,
+     241645358347173,  270035263763914,  294554309667835,  354969191617417,
+     373557176892177,  388747844350863,  397825697624583,  399135305678032,
+     410905076480972,  414234605132989,  417463923716400,  456542903117781,
+     465061741400944,  473503948455173,  476442461349296,  511381389484935,
+     524395926532226,  593109577594699,  605759625078727,  691320357086844,
+     709159714202018,  711008688212964,  736008152975202,  737996635120874,
+     763954972696440,  777921505395488,  783112779929940,  789466294769891,
+     790476255069175,  805844746277054,  834783842167822,  865159282375276,
+     876847509691017,  916886344357379,  972986298646985, 1006558636533301,
+    1045196495142485, 1097216327717182, 1099633158783562, 1173888819620608,
+    1230602806193339, 1328811620782178, 1385620905165250, 1534787522091893,
+    1547098623755636, 1548885703312443, 1588730012551511, 1591282421946005,
+    1656015178910522, 1684571118656620, 1703951942415351, 1741359771554979,
+    1743380674605200, 1755186796119301, 1761898905812597, 1773391625249391,
+    1796957693049902, 1852991823078757, 1864644682134990, 1869618834854129,
+    1885762480416225, 1911072399900811, 1947655952103572, 1969126450528234,
+    1987629944960836, 2038926693118925, 2051724108350903, 2107079364287535,
+    2125626086592869, 2153607292121962, 2168101401358671, 2182560142335872,
+    2282908736624945, 2288304361103221, 2291285119418423, 2328028811240623,
+    2342805866553396, 2370003075663395, 2378875596541638, 2405788877123920,
+    2413007931870164, 2440805418940146, 2447297459463170, 2454304304652366,
+    2459340351825644, 2460004489143072, 2473524988791658, 2477190515909001,
+    2507330167719735, 2507648482055307, 2517626261043175, 2524247002732742,
+    2530992515782240, 2538419850278920, 2563034193993182, 2594663242509864,
+    2605733507735868, 2607339816188523, 2621717996130178, 2636566770413620,
+    2640874820912025, 2658949966423919, 2674979647594267, 2744780872636611,
+    2833865658724039, 2900277425563533, 2978524787466718, 3003964390569500,
+    3022797988757510, 3030967074252205, 3034168884189332, 3051892312009452,
+    3060299150804173, 3104981051982613, 3112111686080087, 3146521072774759,
+    3158876384762887, 3159515960788990, 3174393226170369, 3186331978657625,
+    3217229100692822, 3308183492275909, 3334231232951898, 3351177541514495,
+    3366094104961994, 3388666914141322, 3403507828811540, 3429165536181288,
+    3437505568263605, 3446832964257187, 3487356147588478, 3507159425557999,
+    3533856566776621, 3558945434639282, 3659922797986275, 3682798202274727,
+    3708984468633789, 3719176422082011, 3747994676419460, 3841709757147477,
+    3841909194833036, 3874510870486188, 3876413879338049, 3903476492571587,
+    3957131124696876, 3991964930505644, 3995995929637903, 4036349148833225,
+    4051716657589451, 4097047959310524, 4146648995915535, 4170173800746620,
+    4197062520037577, 4234580383762341, 4243655289681102, 4294674986473045,
+    4304770659659273, 4315437137255894, 4332563234470990, 4336388901207772,
+    4337820333266193, 4373929361320555, 4433686000662627, 4456392423266763,
+    4474340832515420, 4487774876418542,    6428761440149,   31202262322197,
+      61115950252741,   72594915366393,  161868332171197,  194430709758229,
+     196135263376184,  219530384589073,  225351410633098,  240706147517835,
+     272726211792411,  309080852760023,  317489656458050,  330329317111107,
+     336275114515063,  343561958425364,  348884881481318,  351885288829580,
+     353470965797522,  392902645342330,  412868027834596,  443594203282171,
+     496067928520554,  508356433796278,  527695012133930,  536284586936109,
+     550282558088053,  551991041512620,  629489775256219,  630633348980311,
+     682378095092168,  689101994205300,  697012694915256,  722458192727956,
+     773494591198000,  807630856333138,  838722503795629,  850715291123800,
+     860427808165358,  868529104495996,  868784541925776,  921045075155458,
+     929280967362557,  945664331127985,  965612029490497, 1091654681860479,
+    1094104916283061, 1119118135368441, 1234700624988932, 1372348978735803,
+    1404501673983534, 1440626421264344, 1580787886339589, 1623205236457864,
+    1638435201982670, 1702500874274800, 1710609047767009, 1760507767222879,
+    1797100161490517, 1824213862210011, 1886245859580431, 1950602337561849,
+    1959651699737632, 1983452573483210, 2006425240485309, 2033788483924665,
+    2052072891638648, 2053268535300349, 2092487022836456, 2096956195405953,
+    2104947457151455, 2215203093909380, 2288364303604984, 2291438394827221,
+    2367359311216102, 2430229339846531, 2524500488210146, 2598713903626113,
+    2639268525410300, 2640800167537327, 2651859625795148, 2712744119634864,
+    2713529098505611, 2731578700274527, 2738135582606649, 2745027480952444,
+    2792654772186578, 2824807467434309, 2880523283127592, 2900760804727739,
+    2949857843634761, 3015751858453124, 3029247796810385, 3039287470252392,
+    3078388063590139, 3091789686588712, 3139575152538979, 3158795836678271,
+    3165549209340993, 3237546213827792, 3240149710708242, 3346715259057499,
+    3367751513851535, 3367893969341220, 3389470572544406, 3471055322712832,
+    3493208625036329, 3500356943263557, 3525353460670836, 3528277236790164,
+    3559126218694364, 3575860317699913, 3587453247205313, 3596543872350522,
+    3608488519547696, 3622231053269860, 3642370329704707, 3642458750353477,
+    3669403469388655, 3671128654491323, 3719353276330481, 3762278553953357,
+    3786634512882880, 3800164837047996, 3810077774226766, 3835106712538594,
+    3870610550374708, 3888123733541086, 3913257794179785, 3919614256374695,
+    3945442910332065, 3959798493885701, 4004414570803390, 4008203886829011,
+    4017937035355282, 4021635965828827, 4025774052191263, 4032688546925077,
+    4098954910919927, 4116617426737561, 4133214964963343, 4138599782473436,
+    4148337843257972, 4150678043095418, 4154259079370603, 4161394625726342,
+    4204648042201320, 4221708315155665, 4225989839459439, 4237459962508214,
+    4250272114514987, 4251662283603982, 4272450960581462, 4292164835450560,
+    4310688961367875, 4335119586623026, 4336237616604140, 4384239221918067,
+    4389351900320279, 4393608863332728, 4416114865799652, 4419352043996287,
+    4438751534336425, 4483551329713225, 4484806902925759, 4485539811858897,
+    4493157741976259,   28936741761309,  103483208287643,  110820157232247,
+     125581493318572,  148767352329372,  226575558343666,  229577930595234,
+     243818567305469,  256975559842445,  264525700795750,  277073573307866,
+     301701671345270,  328816354516417,  343180780134930,  349485172392231,
+     349800539372844,  357190540706710,  357686678791475,  367407055446257,
+     370536163961062,  403213488391495,  432195812154995,  437011790158001,
+     475014984999347,  500088133635560,  516041183576974,  550601867826208,
+     602621700400905,  639832056758280,  641883415809744,  680655870295389,
+     690077581647659,  720731055672912,  729794172171837,  782412317803211,
+     786922753342134,  805293616801581,  826229661527011,  845632099222108,
+     972561905434749,  973005973581905,  991091926061982,  999093012324014,
+    1003421694307132, 1063549700374038, 1073185586186662, 1086163772522792,
+    1100820968733899, 1112974878133162, 1240603207471086, 1391093186774320,
+    1405229683609337, 1413146279029211, 1548358152676642, 1570583173970808,
+    1586670819788683, 1594308398939105, 1599421077341317, 1623499984904694,
+    1657401444094765, 1662106405060982, 1664724638716227, 1755428505127799,
+    1800505351870745, 1800947455114595, 1832350539751087, 1879258676375225,
+    1881593963954406, 1883627638876116, 1885773313286268, 1895259866447636,
+    1901275417918955, 1912555927798701, 1930786300671769, 1974416978581499,
+    1991968477362344, 2000862612176953, 2032949483163933, 2081067017772914,
+    2089263611913893, 2146622086770992, 2152080588155060, 2155174328410357,
+    2163341449001746, 2225814567164363, 2227637997432331, 2247163241584053,
+    2357450316794874, 2394086921386897, 2430466123645834, 2436064133164628,
+    2436536692409721, 2516656607163524, 2534387894596868, 2570136362894579,
+    2585933203023166, 2599217914275032, 2621043077746427, 2645376440287931,
+    2648258953437833, 2659518831832866, 2674380377987797, 2680946102384796,
+    2693376080698552, 2702004953566851, 2739100363080825, 2776252754790673,
+    2814509422158493, 2818076704110536, 2840131761268733, 2874760234682024,
+    2877968921780722, 2896357469369923, 2924426113096133, 2943753884465602,
+    2963946213289711, 3005562865310791, 3012534342240479, 3019175715414759,
+    3026199262282056, 3068457455232545, 3140862177155339, 3185797550860253,
+    3188805817821739, 3220416199757014, 3225091687173641, 3248617474456379,
+    3253526785366420, 3254056326807387, 3262884637361245, 3270859197428646,
+    3287915540576379, 3298731350824256, 3331687691524141, 3373185466895208,
+    3400401342586614, 3429864085208431, 3454545235635097, 3455665230519517,
+    3476463732013527, 3482788755755541, 3487357155941991, 3511609957447949,
+    3519816376105458, 3552252999880906, 3586147581909406, 3641489083522896,
+    3644951243148068, 3652965101281589, 3658486479571449, 3672588590598611,
+    3679958942899417, 3687309646167163, 3707321203887120, 3712581250037282,
+    3732750982473372, 3741714871355344, 3762447548588603, 3839851966973514,
+    3850235498494071, 3872573501728332, 3875032578215791, 3909921401317127,
+    3936810120608084, 3997087459326246, 4007754919374520, 4048907854215384,
+    4057791182061810, 4072625219570457, 4080739287772584, 4094706802923285,
+    4107146605753571, 4223716459285327, 4236336050768112, 4284619619706450,
+    4345387201799459, 4353344077737106, 4362444527398845, 4369925896736440,
+    4400683510636911, 4406572325844993, 4496560967453181, 4503073639460918,
+       4876513730269,    7536992806593,   29141104656063,   31755408504696,
+      44222719981266,   45213031247490,  112923599172250,  139581442324752,
+     198722084480393,  233137365884983,  263501016672601,  305512614258187,
+     307130712130678,  313587384394194,  321573733881431,  329804713830265,
+     351498228780158,  354677442329266,  365542375159793,  431050286478527,
+     476770639054188,  503093466193017,  504658511676246,  532142596668921,
+     549602727446037,  566684614336748,  585066284764378,  612165248709077,
+     641669254300320,  642201743096246,  646984317743050,  657505392495027,
+     670650595612167,  686503435484975,  697437139931212,  705458857677957,
+     709649013978002,  744827660316973,  765599635616352,  767682433120712,
+     816892453967829,  825673606842343,  849346761873031,  884809336739719,
+     904877876655550,  932024980731246,  947832627828016,  997941591937628,
+    1021297415084397, 1041955425992028, 1043266016497130, 1055468066027390,
+    1055951432240666, 1073516685344653, 1075212396897731, 1102764271054463,
+    1241703566273376, 1292671193127710, 1360410252150407, 1376143232921549,
+    1391876213692691, 1490884743927072, 1520170645251349, 1568888457820313,
+    1622938035510108, 1659310360607474, 1673615839126807, 1719891276886413,
+    1740352797463444, 1770387326947307, 1781808327413432, 1782007765098991,
+    1783509933676428, 1842824469774650, 1855367430028501, 1867861267699702,
+    1869849749845374, 1890582427078633, 1937164389753975, 1950364610163683,
+    1971898967945790, 1980583840558310, 2012459484439895, 2036372357673915,
+    2059780250758293, 2079435178528211, 2133454300216763, 2140966125555601,
+    2208287642625773, 2237344632714901, 2315425960288729, 2315829747918112,
+    2328871793611687, 2347657251668700, 2359670670481584, 2371301915601451,
+    2419944079393134, 2633104649641238, 2658382148221275, 2694566825052918,
+    2754067044513557, 2801514547095192, 2801743458330341, 2816891880367948,
+    2846873357462549, 2864973064265768, 2877852022984945, 2886680333538803,
+    2911386045256794, 2920077795030885, 2940762332133147, 2988369974334221,
+    3021337122002289, 3036559227913871, 3058562215134459, 3062957703829981,
+    3095731308522408, 3178802508041823, 3198159752960882, 3214342696589098,
+    3226360045208594, 3249430957375993, 3265069622788447, 3285267846322474,
+    3324457842760644, 3327484776303537, 3383193714835249, 3408919211369054,
+    3432325139550126, 3456749870095359, 3466599930368337, 3468443992121018,
+    3483876342686342, 3491115046465646, 3506344029538799, 3511077481602953,
+    3544635082714474, 3553491884366269, 3577692615934618, 3577913667556543,
+    3598489152525322, 3639925033442250, 3672388183412329, 3692553003590154,
+    3698879992235474, 3704519264723694, 3704885719190263, 3725515238999957,
+    3793217947311493, 3812870910178105, 3854835350084347, 3885084054028564,
+    3902033309946120, 3926354883067788, 3943914241461857, 3954388158534490,
+    4010091202356284, 4112944065908854, 4114096481697823, 4186729132404113,
+    4192565877674586, 4220715082436342, 4255335696236409, 4289794205513731,
+    4321540165777120, 4328135363723709, 4413971182194666, 4439008962571371,
+       7804232607139,   29071363539630,   55951240765710,  111912669372243,
+     148934395012242,  158981928067473,  176469567490873,  201612470194449,
+     218491972044642,  262267070347363,  285214193606484,  295127130785254,
+     297575400304530,  378889976268381,  381657542574882,  439791171786198,
+     483170342072760,  538871420991248,  553111075249830,  585724540322818,
+     586172538276586,  605059188853858,  622938826486805,  664000393323940,
+     721504271025683,  726490213164658,  728521923183062,  765148703258555,
+     859921884416853,  866320592032842,  875653882736342,  995182880646934,
+    1008177768661165, 1020985990861326, 1057121545110319, 1125875476690565,
+    1290502935280469, 1348587441909135, 1418281579721302, 1439099730248372,
+    1448268951525821, 1457031437818928, 1476169596019368, 1493969655068422,
+    1520905532038723, 1528169779561005, 1538777310058446, 1575495458137668,
+    1633386421790693, 1637944997460613, 1686882879199849, 1768772189380705,
+    1769696676386178, 1886255722949751, 1893090639099672, 1913747667555650,
+    1932820001495339, 1951288127668433, 2077453586495040, 2115481342627711,
+    2117965962858148, 2171925154995867, 2176982816105511, 2180740693678236,
+    2200388744286583, 2438198044056804, 2467824856104672, 2472921815280436,
+    2519222796783020, 2628004756061445, 2637586607033154, 2644858714168660,
+    2712932789205030, 2755286279965860, 2777907229276185, 2789842051956829,
+    2802057855810231, 2819325426063359, 2864088870728998, 2973098758790919,
+    2989342614421621, 3011404548741389, 3091499902203867, 3117200837446347,
+    3118509463048143, 3167271485941492, 3201075682417916, 3207803511337660,
+    3221638395515206, 3265994122744850, 3312507313804482, 3329170676291015,
+    3416902626452262, 3421048572427922, 3487308059261201, 3500690033226714,
+    3522225373460474, 3554201227410665, 3555666062825288, 3559133134708725,
+    3625762023363532, 3632896587267618, 3666282259339864, 3672091495964053,
+    3679059043087129, 3683056638863186, 3684298457752578, 3709752797630155,
+    3754602698041258, 3757680719070107, 3774075872255371, 3801816377129479,
+    3859153238050212, 3879321005582996, 3885180347241488, 3909806480375586,
+    3920498501715185, 3944443795853754, 3992876697443348, 4010689528363891,
+    4101919988861471, 4103099913296724, 4110892719808320, 4136544532468150,
+    4176443858999786, 4195150720924559, 4209181112981052, 4247276658277780,
+    4387011739335623,  119143526489253,  127960047623275,  135156505981500,
+     146527401413322,  148526690527177,  273265629553628,  281822783451258,
+     292661190087154,  303218615550292,  329652459725910,  332972163861397,
+     386585533017260,  438442278617559,  465182647708913,  466799763129751,
+     474073835168563,  509444059579869,  558830921724526,  585861114053515,
+     588593312100508,  705192639181854,  735479658740538,  752242248844024,
+     801123148387386,  837567192455421,  870694479742928,  883984103253059,
+     892708273931699,  911405311339942,  932967177768333,  939467077904581,
+    1064005596793820, 1078642161520214, 1078836686947508, 1094672825142215,
+    1100153922914302, 1123063713010609, 1327421516447833, 1375356315049356,
+    1397436915950531, 1478202301440355, 1480410852756299, 1521253332874815,
+    1538151501306415, 1575238068755512, 1590876734167966, 1620389581824086,
+    1631357672078178, 1712966001086276, 1812190670684317, 1824702192485272,
+    1827228075685135, 1845759078764021, 1893234089991940, 1926345658052999,
+    1951152562291249, 1976917356891174, 2010912148988280, 2032461243545182,
+    2045158448708554, 2093478368358053, 2107111849946734, 2118511236476493,
+    2148170469428910, 2152920623171165, 2199081114087370, 2299507322057030,
+    2305060138799786, 2501248856742315, 2558143614419198, 2602486569777353,
+    2688725193426040, 2694133589775805, 2708519629330684, 2782599431321843,
+    2847368538997521, 2861305598146979, 2905924622419627, 2911969647440536,
+    2933261339664352, 3010474179976928, 3034333018370033, 3075587145731156,
+    3222988297037358, 3238855873684961, 3268298967273718, 3296717363788396,
+    3328751182386114, 3357157789480956, 3383624054561123, 3395026388445841,
+    3427294030536973, 3468660157386538, 3471685126026125, 3550093610000402,
+    3575254196833732, 3584045174224776, 3584651346894677, 3600885378008849,
+    3604086205494323, 3690047777776864, 3693820392124384, 3701321410495039,
+    3772467611850340, 3781877533782774, 3791803242833033, 3817256600258957,
+    3894865368587692, 3900515448044095, 3902293685536025, 3939383200340081,
+    3991932574355745, 4003251399849958, 4011460765862426, 4019507044900496,
+    4090299563660717, 4126694485146102, 4144975945505126, 4156222069577017,
+    4165891358745843, 4259173178294887, 4300543234951064, 4322152259058799,
+    4386947892929108, 4390517139784457, 4436173633002673, 4444618787411861,
+    4475328261181335, 4497040442712635,    2879228372510,   11914836225151,
+      43801287074919,   73166766983089,  103970556111251,  104438203098079,
+     106019950259409,  134942344472076,  136943598489237,  179953366954271,
+     190252407632670,  198814473788565,  241058912415912,  292454888190954,
+     334090206793441,  341296489668196,  366051323968837,  416695724229334,
+     429902821800613,  490570193825016,  510805750521857,  526398240706620,
+     527338446938541,  549101715955797,  581844864646981,  620434583125168,
+     638251343852323,  643725564462839,  675485279049370,  702971328945351,
+     861970322015218,  874546685625271,  912677599181507,  916796036510883,
+     942768128409591, 1031684915264356, 1037649379249719, 1040800101700890,
+    1064106802265009, 1071962485682397, 1072377080279963, 1094480277569157,
+    1105763734803862, 1217340769035072, 1263762592090975, 1294575223284014,
+    1425696168248353, 1480624057715930, 1497550717245467, 1524988627010451,
+    1576381655430534, 1597427734741100, 1635714858110163, 1651036191638698,
+    1676308777960470, 1743320822759947, 1803271005077660, 1805172049026215,
+    1806578919793311, 1820968889154802, 1830719721810827, 1873391526907229,
+    1886665431190912, 1899776248500197, 1955055855659548, 1980868790390470,
+    2021313377589521, 2034763140719091, 2051230012875024, 2052677164159893,
+    2055667746991625, 2056533286897918, 2057283879960810, 2122798668441115,
+    2136374185382269, 2160772389732871, 2166883239014531, 2270525993696113,
+    2294041956462321, 2297409800728805, 2299868877216264, 2343255907116050,
+    2363728234661264, 2439810273121237, 2442936434281083, 2465787277278210,
+    2483129513856966, 2533607879788106, 2552096637445913, 2612181415640087,
+    2653035685178439, 2694489250225121, 2708883149393224, 2716834130620953,
+    2748467108944247, 2887546894749539, 2941177948035156, 2956457036142612,
+    2969034382204318, 3000006170565143, 3021883403974147, 3037434631189484,
+    3135527516483269, 3159307758744134, 3173461939708905, 3226074190630361,
+    3226626328459347, 3229385052700971, 3253060172634965, 3260654523912655,
+    3325476683977595, 3330490134762854, 3334140925105402, 3418057033046397,
+    3466625512964105, 3602195981464881, 3608274409841992, 3619082360476645,
+    3626676711754335, 3632056617006163, 3709802928566318, 3975832169617311,
+    3977002269536034, 3979679450290459, 4038834829220895, 4137889534682967,
+    4173299057160393, 4292600126287489, 4303058324133674, 4323261459925966,
+    4333986884621767, 4433745025467387, 4446748755546495, 4499435672793579,
+      23112833116975,   23114798020281,   45048031173506,   65287517676959,
+     152713925374123,  155679946914530,  158575231935921,  166833720531039,
+     240902715554015,  287790220693440,  322857849995622,  337952237192314,
+     341300432425738,  366495405066923,  383321854527854,  407914584305750,
+     420655999793507,  430486411033425,  460946342083037,  474734068581239,
+     477931948711754,  498949536924383,  573255302795732,  644646134612630,
+     670289105207583,  696444915565402,  708469141346469,  716113597658462,
+     751496593941257,  818091096788209,  821909886363420,  838207776835037,
+     847584295411269,  857630846014847,  879422606130040,  897098876270816,
+     939043667143998,  986824220836000, 1062360998628558, 1063905412627074,
+    1185972083157365, 1327572839904255, 1353844579557128, 1421632761162475,
+    1453306020003542, 1491257144907279, 1497422029029854, 1499897807195414,
+    1594567830930147, 1600738609762640, 1677096717137106, 1680190457392403,
+    1742483786902521, 1763397235239932, 1811941153866315, 1852374934097183,
+    1879533827592715, 1922145703138284, 1923662608490516, 1941298598113519,
+    1967014270130794, 1975826861458204, 2026224666353798, 2032516286739610,
+    2035619851511437, 2074789216464894, 2086633653593462, 2111413049185428,
+    2179128529368453, 2202020635335006, 2216899865619691, 2234953397195219,
+    2244814264436380, 2279902525223275, 2290986544772421, 2342625186105754,
+    2372242173637092, 2380121435894152, 2461631519736950, 2508554393135883,
+    2516122218218942, 2518191261400160, 2589262796429833, 2687809574387304,
+    2710208489624051, 2800745339254613, 2879083086709874, 2886873928318164,
+    2926092415854271, 2987446521584121, 3023073165876860, 3023629233512458,
+    3043158407470792, 3050415777831503, 3076044011651661, 3118873991464196,
+    3187506099039470, 3187822448471736, 3211277499235458, 3251098229634854,
+    3270440737779118, 3279219925750326, 3281400968419986, 3330373235967077,
+    3334213639478654, 3357214797578690, 3358146161745734, 3430029201840785,
+    3443665630784425, 3448080768513007, 3454507967226933, 3515855195795212,
+    3540832064169431, 3584702460282493, 3611802406678845, 3654747333334781,
+    3708847016058879, 3758900962875923, 3774473804027626, 3778550978387576,
+    3858073562536355, 3862680278337272, 3890085767197707, 3894701325063501,
+    3981992154432551, 4021589868306716, 4068965651917682, 4085866767704241,
+    4124222662689014, 4124631362576662, 4184814385936136, 4191375198074870,
+    4286890130231183, 4291666810168069, 4347650835162621, 4371433042326792,
+    4444299516526496, 4446880417018927, 4460325267890232, 4493889746163324,
+       4358826463788,   14101799506589,   22826952636882,   26205603871549,
+      31365439953105,   69030671425819,   70293121799924,   95793636905192,
+      99774531003148,  136818853031166,  157925844344218,  158253983196320,
+     164559357905274,  184270285419413,  299595372706818,  307522775094875,
+     307889229561444,  345288216636195,  381598647279422,  525922760008428,
+     534846368372627,  558130472548727,  572093075441163,  586154905950552,
+     639355645412155,  640249676416385,  744943654366677,  764991562797795,
+     803281633521817,  840499849492416,  849200441331384,  857465807088073,
+     859345237100262,  898779864000029,  899317265054220, 1032909075925854,
+    1061694909358754, 1304904745865516, 1360992910735286, 1361997958776305,
+    1481645833336910, 1519999763418377, 1594340897549234, 1655517179529891,
+    1704279202423240, 1826899975685823, 1859363125655902, 1859403406173675,
+    1865696991462793, 1873018221180949, 1927527586244348, 1927590463150140,
+    1959735298784647, 2037085682328643, 2041232610755956, 2051236915938455,
+    2108748653253422, 2140388508738287, 2180166993716604, 2188064922555071,
+    2263043667808725, 2339852720491918, 2358501617769164, 2496886810255785,
+    2509154684046796, 2512468493472365, 2525472223551473, 2535276108596760,
+    2566347124574538, 2611867057012987, 2615489356257598, 2641005590589314,
+    2665174883704767, 2666400983367711, 2714727780178781, 2847520857856526,
+    2977966858535254, 3034879300341891, 3067927991497158, 3071558150354993,
+    3130638862959801, 3168506479473033, 3190970236518878, 3231647664759690,
+    3281580770023415, 3286338783378894, 3286628606616529, 3290216520053285,
+    3295564004400564, 3298190097669033, 3319153651040747, 3322081356966687,
+    3353229986625052, 3376663423452408, 3409932183777947, 3469574858728271,
+    3504578628673008, 3510955722352631, 3582873148255537, 3604813258570333,
+    3640141237560560, 3649555089299606, 3706504864269057, 3821333813471697,
+    3846267453973184, 3852968756698297, 3929575424340972, 3950953572310252,
+    3998443320312966, 4099098439517775, 4100362854795186, 4191878226272136,
+    4207147489863062, 4258938411202610, 4289416026381976, 4347035833378773,
+    4357721960008454, 4371378037985154, 4398564440126970, 4405063357811565,
+    4421049811109181,      85174724168,    3955051785335,   25817548419544,
+      51084240031398,   58323926262355,   63423832793078,   86265833725328,
+     108611696572813,  120861886234070,  134005124447904,  159861287051558,
+     211198315727420,  242613189783748,  262735764540494,  313729917589459,
+     388175191595534,  542101786780962,  564316983558598,  597057184894823,
+     643412201238322,  658174519776300,  660957805309249,  661101243250587,
+     671418950510393,  692878641966872,  789490972619586,  862557866956502,
+     915935447715645,  944163248609641, 1004321710677790, 1009910878131707,
+    1052182825405338, 1094601157975266, 1108268042920149, 1121490859717876,
+    1123972532593354, 1204914759380718, 1250878759966323, 1265272659134426,
+    1266360233114297, 1348418524979469, 1459519070158974, 1573456935712343,
+    1627727565024063, 1669880635647681, 1688482375245583, 1814612465812682,
+    1848506065389529, 1850718546512085, 1852583239749479, 1962176704093282,
+    1984617864751108, 2017665573454722, 2073956123365010, 2094125855801100,
+    2102759640927664, 2139399192874646, 2152653448125269, 2226363848294900,
+    2241846303894527, 2299522110635545, 2344487940341702, 2405135663333045,
+    2413906991691029, 2413961026531944, 2430741300765184, 2435703664064487,
+    2558166262610937, 2654050596588778, 2702581743343672, 2750440893167914,
+    2837333829617499, 2842870927133807, 2868929474777979, 2871464200042719,
+    2877110349692510, 2964164408213187, 2999950209673712, 3012240679852742,
+    3026336896169986, 3092728031524767, 3099008844942396, 3203234193454207,
+    3272460684279546, 3293787744762870, 3294922476422085, 3428413094773460,
+    3439335992251514, 3478272516163210, 3508508448235938, 3534897099635518,
+    3632506618716027, 3672075841492255, 3674292252421423, 3726891731471390,
+    3762351358983119, 3860742909579416, 3879664928416196, 3921786560586918,
+    3931333043299119, 3948633034456796, 3950910357388450, 4064711662162052,
+    4127345902395761, 4149484467944463, 4201893351373748, 4204259094954172,
+    4204683514068268, 4239152830313773, 4254516409263387, 4262844651925868,
+    4270616826952751, 4283803293039317, 4304459339043642, 4376893534516026,
+    4439668265336114, 4448238191105233, 4490286139401980, 4498305892245419,
+       6814981498148,   61454030179743,  121987788779338,  158130220189902,
+     179049563237231,  188110714832850,  241286893003128,  251528951485653,
+     267277651483243,  367412071311962,  377098062158889,  502284999139108,
+     586542017803694,  643990878212869,  689272074899639,  702317067948173,
+     710595205576351,  721287226915950,  806732029629013,  822886482159292,
+     993691596743260, 1010574045948412, 1070599877043406, 1211951091070434,
+    1393620156033276, 1430493531473672, 1437495464404603, 1472555234093561,
+    1484262127990709, 1486087523161983, 1567727290622977, 1639251735864683,
+    1685703032470176, 1688146389731187, 1691433672962125, 1754604331798372,
+    1790122906409281, 1798243851772979, 1836751044312314, 1991132514613081,
+    2005237572995202, 2022927597459120, 2224836148925415, 2247706640955602,
+    2363044500114496, 2378037694790929, 2421764652962653, 2452724651903642,
+    2459930934778397, 2463972740878839, 2526726840214214, 2555840812499216,
+    2563563864943449, 2616534710718250, 2667718476936244, 2711237155357532,
+    2733732350856273, 2740224391379297, 2749947715389038, 2787885085969633,
+    2800728676429302, 2837991102724286, 2860670016682138, 2872804277048341,
+    2919583712505936, 2941569998048423, 3027520763362781, 3078480530603891,
+    3103887712802124, 3149097190518225, 3165189748594365, 3255471160795147,
+    3259870579297281, 3263012459683575, 3288125888837561, 3302731997562712,
+    3347549477069266, 3356899469450867, 3411292905219212, 3427415919296595,
+    3459496895573657, 3482200370822834, 3483805696823836, 3505185809696422,
+    3515161623780984, 3543495529453504, 3612168899998204, 3626647290008465,
+    3637285276507149, 3710506415753586, 3728981419088251, 3771683680185896,
+    3776133203722333, 3783378784663208, 3831164250613475, 3874983559240581,
+    3926174202620146, 3933903149774297, 4035941560906530, 4065800231544506,
+    4088898652358189, 4163016769963815, 4231540807857259, 4246938772614728,
+    4247100877137473, 4253942670448965, 4258004125582467, 4314219026715474,
+    4315133689204417, 4319556686546223, 4334689389357382, 4391811093366108,
+      76275308767831,   85147829646074,  102799538495525,  195976235717698,
+     249426517899163,  251705805734123,  260536081191287,  265022937890538,
+     331085934393217,  406366292304342,  455214770943155,  463963502913120,
+     470908453648177,  488352865198845,  491376851386779,  496891352515068,
+     524163227950695,  533822692602991,  561391268437824,  570222526346641,
+     580191463269632,  633026730716319,  735692928454819,  758719630297833,
+     824764942670758,  864756619657776,  985775014274316, 1000958804571431,
+    1020333733620244, 1123979435656785, 1205604466342984, 1224386977045038,
+    1293109470172388, 1353741473937283, 1359229448870941, 1408041576798593,
+    1482607692357987, 1597839446738357, 1611129070248488, 1634695138048999,
+    1692558593055740, 1779122408201570, 1799357964898411, 1882634496813303,
+    1911750434001611, 1942086576142945, 2077976341430946, 2095244894135727,
+    2097048675370635, 2112231483216097, 2121151161773684, 2148735456834965,
+    2168483717511918, 2174538567049357, 2409629423095727, 2427602393635709,
+    2433738786660347, 2434387204751327, 2441844995249250, 2483310349715768,
+    2513273160228962, 2527062851630470, 2541839906943243, 2566373689621959,
+    2587197734858947, 2615456974205839, 2797074951682725, 2803679974145844,
+    2806639118524680, 2879687346280189, 2997678807353836, 2999108274508951,
+    3006548380877120, 3106622871155006, 3141785798267529, 3214996130545783,
+    3249311201881767, 3295223132529763, 3328580313504072, 3339709525829256,
+    3373248460359370, 3383609395491908, 3390096523756667, 3494100820646553,
+    3535573052274642, 3538777809566728, 3572686145918370, 3580003445829914,
+    3623193002947447, 3623361002180110, 3693593523498121, 3697117577577432,
+    3697328804682827, 3704223650383581, 3735802593865960, 3761916158802700,
+    3762533138440784, 3878953659321284, 3883359954984989, 3908319139229454,
+    3944079396947001, 3944347606248270, 4012450172382577, 4033043341481110,
+    4051594976044709, 4075945040264314, 4082018556383160, 4092341175901231,
+    4101805132674580, 4107131985537146, 4162806538261003, 4172200740966989,
+    4315562051076055, 4324758780999788, 4437239670751758, 4440825619285208,
+    4498490619058043,     919315030355,   25013941820180,   39830295199073,
+      81584490451573,  176278093025978,  285488401225111,  322756722230013,
+     359962166329123,  373919856963294,  477208929049796,  604054244420279,
+     634107440485549,  667627708434256,  667703357211537,  688070562429880,
+     694192218679723,  733961861593163,  842117999168627,  846094963459971,
+     866933745471754,  869849661977858,  942686662627972,  963140323591779,
+    1071600038728020, 1072863471553778, 1211365575787106, 1302112670071410,
+    1327012907216695, 1427661149259933, 1457648521064452, 1615948991009036,
+    1616899021757487, 1706180298175515, 1719712587243937, 1741549540135168,
+    1748745998493393, 1752359455673127, 1770392355763942, 1799306890363385,
+    1843659670238070, 1845152996750630, 1871429648661768, 1898686787322600,
+    1904844794283604, 1969464569308026, 1973671427286172, 2013959804672396,
+    2028270195449994, 2039457372422705, 2139946457298157, 2177200041528264,
+    2198591943820686, 2205331562160266, 2208629652359387, 2218068065389758,
+    2287759255846966, 2299807060467705, 2520273141207517, 2564704517214442,
+    2580288165334328, 2599352639660793, 2656480238379437, 2732934626015897,
+    2774844048629571, 2821631343700390, 2967272924096069, 2990337941553550,
+    3114766425857653, 3125206939574084, 3216009051150956, 3359008819051718,
+    3409433150141943, 3501455466671841, 3516833782396250, 3520350959313990,
+    3532382062256628, 3619140402829752, 3732017220597881, 3822862560047485,
+    3827476153009973, 3876042668024375, 3882865794754460, 3884749154573261,
+    3893809323717227, 3897554429418463, 3926318648914997, 3928911338827264,
+    3960299686688961, 3976080807591100, 4016787709381502, 4045349543837518,
+    4085819674779547, 4145968312331166, 4160101861811224, 4161625644325027,
+    4196644151044559, 4237482701356463, 4311830712648891, 4328982353606965,
+    4369200976925826, 4482020812498081, 4496169098752934,   28227554128073,
+      29200181264543,   74431272917010,   89980535229041,   99860069051609,
+     125953984955289,  169197576913737,  185663466618017,  210674720800091,
+     228510148108653,  246543048199468,  256253600337720,  260949719239060,
+     288627347207376,  300032628447053,  325022268692761,  327517695891381,
+     369686485741447,  395383491177315,  434597066455157,  439081958251102,
+     450827167762717,  471583423835648,  511697907279291,  531533606153361,
+     593392692032853,  599300173822342,  639501113011449,  699971994705252,
+     714080000442332,  744853333569251,  802870051033860,  818108858623543,
+     819568781779901,  829684103999189,  868822030499750,  933735558568419,
+     935381165087194, 1068013120693847, 1085441813018067, 1123400797535028,
+    1241630011908701, 1249109416342990, 1403572430130956, 1608718159793886,
+    1681546318379123, 1683898307636405, 1693977279144532, 1743012423597415,
+    1807147849956908, 1833745763558577, 2166690769147053, 2173687789819719,
+    2211234144642420, 2463915797535755, 2471484605070467, 2515897314495985,
+    2564514916996343, 2565981717314272, 2630056231671279, 2784870963151019,
+    2787307443250459, 2810229022766602, 2811826489154380, 2872392668658524,
+    2919308664895886, 3032261131441296, 3034756558639916, 3079446319431580,
+    3186900986526802, 3211497646111310, 3285826008370678, 3351955811585761,
+    3360029599270115, 3370476990148117, 3377199906809596, 3405684127585025,
+    3471946561773263, 3478093761766084, 3488047961914280, 3509035094125666,
+    3524960635420796, 3535199746548362, 3547625795055506, 3643468866063921,
+    3647100989825062, 3673425781867197, 3798399526838715, 3809195688053532,
+    3810146701253636, 3839756811623403, 3853696818127820, 3899480047609273,
+    3909598317183520, 3975410788514684, 3982272230859236, 3999385556202843,
+    4042474903251770, 4081029253470449, 4120756650962810, 4198032368181178,
+    4222375555239212, 4367631032135262, 4382378613898445, 4387702519406052,
+    4429796642930490, 4446685969297213,    4232167906131,   89822373463838,
+     102437052688358,  143690197597828,  204522621499935,  262755478328204,
+     295331610238378,  356478418669445,  384537237879125,  517119088451475,
+     523021657982699,  582825455004115,  639941264302923,  642530024408578,
+     690740891924594,  734179991761989,  771579961288393,  821016928467353,
+     839415300573084,  855506876197571,  892496180933021,  980340130582710,
+    1002705642463255, 1009461962480936, 1075092680256295, 1087503009536991,
+    1196059987386879, 1343075035193452, 1383123694376344, 1390884079983391,
+    1421332221613167, 1461837720814704, 1470424348261924, 1536433309923688,
+    1537199622213028, 1687992196625386, 1702746655550140, 1737388883286573,
+    1797552257612987, 1849632019738517, 1869527648163420, 1966202855721926,
+    2150543206729275, 2173258471398288, 2177155857105739, 2188112157939995,
+    2322772876210093, 2331017610482069, 2397309518219897, 2447390973683225,
+    2455083570126215, 2459893653419303, 2473063417827768, 2736179689727616,
+    2747178235982951, 2873241519837646, 2883962032275182, 2913378599669308,
+    2942019030257564, 2944841613856633, 3047002831445491, 3120939195495312,
+    3151982702826806, 3222577748804774, 3295419661713153, 3319383622433129,
+    3324479599157240, 3329149191863949, 3377568339130401, 3388669060357648,
+    3391914098167507, 3660258977764621, 3681906317486823, 3687381520548992,
+    3702114365537380, 3747003564014603, 3806929185040991, 3893718964067011,
+    3929195293256841, 3948600678306897, 3963778573894094, 3993061527863412,
+    4105511961614139, 4123492791767345, 4131778789008747, 4170118964767072,
+    4178684960729579, 4248913552240978, 4282170523146681, 4298656061884021,
+    4342475370511127, 4344384274072906, 4406630445903680, 4448111519596646,
+    4503556178633701,   40934609709835,  111776251052706,  130829918410988,
+     137219783962100,  148939449730737,  235017933759985,  249901093851282,
+     314968866829472,  349717199344429,  353063429674547,  408509071163255,
+     428584488240657,  471667940579666,  535719858548654,  550724842644923,
+     563153838507026,  651134348936482,  788653019065157,  836079890162079,
+     839358331328140,  846343562580970,  874446592115035,  902389482029661,
+     915448229401337,  973806840041190,  978904781668607,  992009704267974,
+    1009559238145513, 1207131248015466, 1264794282884995, 1336885602730482,
+    1357427684343059, 1412865466218543, 1621524442992601, 1627214802966777,
+    1661392331071341, 1792222470346392, 1802228740432197, 1843946584973536,
+    1844827844106277, 1850534905758554, 1861949029063108, 1906217318095635,
+    1912680867520722, 1922484752566009, 1967589107955239, 1971065021903553,
+    1986650634926745, 1997134376515908, 2027800622412650, 2045337384418700,
+    2075816964501372, 2107415557016811, 2158700515755064, 2237435173678137,
+    2248525087937201, 2314527172437394, 2369346009771488, 2377472849845104,
+    2379900487879667, 2510564592825361, 2540874208772064, 2566166444126896,
+    2611467289846726, 2624119302234060, 2633942836312407, 2654966319234954,
+    2691514503178207, 2779318172310123, 2892396392667117, 2924969577222332,
+    2994220629338996, 3076860532582744, 3217390417027864, 3223518950439278,
+    3228587418517105, 3257420409629349, 3299239446690947, 3350741527244513,
+    3374461839954545, 3454668210453812, 3459196330122489, 3506389377725997,
+    3517581466956973, 3553502846745612, 3569746702376314, 3621878534439453,
+    3743416645980430, 3751864747744577, 3815951051521420, 3840566377687335,
+    3878950763770045, 3909225993908893, 3981434225501087, 4036404360389743,
+    4041023848062149, 4064562407216376, 4127031595572381, 4173632224829130,
+    4230920945618866, 4273869802081414, 4407208140426574, 4415094279845205,
+    4452978598036538, 4497407026688504,    7573511879844,   21556746256993,
+      40309783409457,   49979072578283,   56717708466210,   84630142379593,
+     107955509525119,  132162135803386,  190392045276696,  207916035411257,
+     244873901693811,  258653768578789,  278372555706152,  313333097778157,
+     340463500175752,  406064744401521,  456335813033878,  457697491024936,
+     459643727749529,  509672130823595,  517569077210409,  590800040973376,
+     656662617338843,  658103873913794,  708653958815603,  719653487522591,
+     730817085655630,  736337481493837,  736982952229858,  777050277994157,
+     858569203901832,  991229650606422, 1141465174931529, 1174789935001289,
+    1279904402259065, 1320592637468060, 1327377448583678, 1369561957660192,
+    1406904944990722, 1444855087442806, 1451444390679477, 1501748862668036,
+    1581237061008960, 1739386233398982, 1758799478062262, 1819268394852759,
+    1833088542255510, 1843700985011216, 1905483439661774, 1918821203302902,
+    2040371104263715, 2081222426447108, 2099966621534695, 2136961820980063,
+    2166124915847715, 2173618074605146, 2240353068038477, 2461387988285376,
+    2501967171360888, 2504878175608727, 2510135274403930, 2624503453781313,
+    2696010214893265, 2778173629085308, 2787010781704043, 2803305724820701,
+    2811446319217459, 2814114657907007, 2866488173076784, 2930039040702742,
+    3014689040028528, 3056000149585525, 3078149522102410, 3114251672995201,
+    3186286993096467, 3190195185772101, 3217263693715557, 3224727378923398,
+    3261440614744355, 3338334158269706, 3353849034773882, 3391479880438741,
+    3408551942812922, 3414393600341660, 3605315394972456, 3764217125328676,
+    3836911670489105, 3866133712455937, 3896443328402640, 3912285361307265,
+    3921378933807433, 3941295193717049, 3951941039828957, 4002892947456843,
+    4028306024364994, 4052799526525937, 4141680945121194, 4163110180576430,
+    4195747224489090, 4246207906290476, 4299076577093365, 4352711560185594,
+    4364580558605487, 4374699810631387, 4385331902420153, 4448019195043124,
+    4493394707088582,   34028000491105,  156159512830494,  188606943574125,
+     196166909043960,  203084351132733,  246724853558993,  268201246693573,
+     338940713064532,  527876972904615,  573531501219525,  576258787008253,
+     596575887192293,  652499982636012,  656674419709609,  771091721669642,
+     779529998917259,  829246964817324,  836078933612286,  846167729636943,
+     874165636844137,  892745762505673,  914942292701902,  954727654841790,
+     979602348244097, 1017579999342465, 1021404683627594, 1296338866461379,
+    1333928449156812, 1467306085568092, 1476154045155010, 1509719505879755,
+    1511470234725401, 1564268169009274, 1594669152959706, 1655380735308494,
+    1688097357805047, 1694753467754122, 1790253663135640, 1878047507751026,
+    1881987138879556, 1897595348290767, 1916714839909800, 2041764233658599,
+    2074126191108419, 2086548309808951, 2149921353685716, 2276821686348767,
+    2303456933113250, 2336936920544184, 2571709462254982, 2582207940618940,
+    2611274755224598, 2644145622630672, 2675732425726275, 2699537229278465,
+    2854587749154925, 2903996225235948, 2924923427896501, 2925873458644952,
+    2976261439024016, 2999926734441480, 3060622597563820, 3213888985238432,
+    3404542570567959, 3451142217373055, 3492514238932538, 3561053013600777,
+    3619622851346025, 3641693627730670, 3663689737789687, 3725550788572485,
+    3796860076902184, 3850743620262622, 3851611125072221, 3854448445446085,
+    3929689505291090, 3963574262803060, 3984878726898365, 4093867983475573,
+    4175288664217948, 4226610956119015, 4247461527550634, 4296363058578709,
+    4361514339947404, 4375569293295222, 4401367491251349, 4439164371245565,
+    4442663864033551, 4464929165845490, 4474747787665572, 4484763882267907,
+      34726536567318,   82921684856886,  105080881890301,  184435466806417,
+     217701279776997,  272917027578903,  292457008505420,  325140227645771,
+     366327548294490,  432474053187674,  552384242339630,  676420728434186,
+     693487878550102,  866982945759984,  909060367606321,  943402947588589,
+     954851456700998,  967141926880028,  996438635172488, 1014961778638150,
+    1015408794140265, 1040397451934320, 1069293319952356, 1085086230274331,
+    1300949525019838, 1324922327804691, 1406903988440929, 1411243477392230,
+    1451218452701147, 1489001578372221, 1571964708209806, 1737000866687358,
+    1784656649019429, 1789109119910825, 1799534896852461, 1816141277143120,
+    1824445940965929, 1891735037131552, 1966116451780182, 1987793265051974,
+    1993327415213323, 2058615257361785, 2086228043521003, 2094045411323924,
+    2104430907747787, 2321315952212414, 2334260735192342, 2439068677534382,
+    2544253881311174, 2565600590827558, 2617127232672449, 2631740218559171,
+    2743024482197787, 2829309281074165, 2848578105344454, 2879690384291658,
+    2965604798894855, 3004654304746646, 3091180786729662, 3095672555687178,
+    3147798493040399, 3170483301708169, 3192963760432115, 3229597417669179,
+    3238290149894923, 3292276850678926, 3295586730297883, 3344642506235479,
+    3380817358550592, 3384456359473304, 3441934693432069, 3522602816208246,
+    3534232096424807, 3591410782629407, 3651470017080603, 3662759369025226,
+    3707474673559868, 3808022705534500, 3815247654990662, 3845431517125781,
+    3908783929517833, 4049486725453881, 4086683327488114, 4086864098592266,
+    4099770565957727, 4177474632096803, 4194803114352417, 4329225096870836,
+    4340323853194777, 4426262829089299, 4436370291695363,   23055023880608,
+     130699304144859,  166666859161189,  172024168024998,  298980500432270,
+     484463442712058,  513123522333374,  574952152211623,  633523954860177,
+     714343375190916,  796027352976295,  839318102614087,  894434622799040,
+     912498961342751, 1125271463187920, 1266498888306670, 1390063797607786,
+    1462703325475647, 1522786138766515, 1621221899687197, 1799825715492679,
+    1806901332297585, 1852335791442223, 1853301541417122, 1919065872617289,
+    2053816976439463, 2056167000793439, 2060887680986104, 2089146920332996,
+    2197846353672569, 2398403051664336, 2460624662203785, 2495129346708798,
+    2525881065899351, 2606400838475925, 2627942073419603, 2705806279178118,
+    2732831559248842, 2856750151145038, 2898372697876036, 2929353328301738,
+    2952538204860885, 2953521638965538, 3134867477334543, 3141864498007209,
+    3216354964789322, 3218375867839543, 3310636920121120, 3331279211802303,
+    3357534249777075, 3423926367583509, 3468606303858643, 3547469662948259,
+    3564035762721145, 3583736865718754, 3647850678141881, 3685415699545989,
+    3696015370430206, 3763268115884668, 3779478568159168, 3790669674938491,
+    3828954833404248, 3833835653216352, 3837617109628749, 3901862570573378,
+    3913967357389991, 3973528488853116, 4009820252914936, 4043172521630980,
+    4092711663781852, 4108752151920383, 4110053900360608, 4180202913288114,
+    4381787255708919, 4408603273577554,  128610624881511,  144068519189813,
+     209359308693234,  255364572248265,  278518010354516,  283729916373681,
+     287043725799250,  334643508387100,  371046289485709,  382092975872041,
+     507057878778682,  526361088856826,  564161898657654,  606988931115230,
+     675673108628113,  679259057161563,  687854526673660,  712771465497046,
+     817426145381218,  898805563154167,  947949759740533, 1506005877484205,
+    1530289134991406, 1539418075751082, 1604383673757360, 1629836048731631,
+    1687808555871855, 1697223390062554, 1769044535703466, 1851215809508733,
+    1862816598627357, 1900000428790101, 1902891784004880, 1972428729552567,
+    2020192581566468, 2207259235910892, 2276491621446149, 2277040811920176,
+    2329998885823488, 2410925393384404, 2427688965939543, 2492511126004483,
+    2497710260152159, 2519560967366532, 2607579793410455, 2634851668846082,
+    2687075851364603, 2810149534839219, 2824533609490792, 2845512882088954,
+    2991529759016079, 3083786881491044, 3170364450960016, 3181699978132330,
+    3255088134159777, 3279638618516594, 3351244607245499, 3387504932854423,
+    3477212593289853, 3601052589053809, 3635954184026634, 3643351062522071,
+    3682766040388778, 3733894789314204, 3747167711146234, 3825631212413079,
+    3944481336231448, 4066024360030690, 4071797245943718, 4078069217296470,
+    4156369631588917, 4168884100744831, 4197650285144671, 4243869723160056,
+    4266037762258348, 4277721077315824, 4301957177143681, 4308147605009234,
+    4360318735138493,   17305742709112,  176491401593049,  294196969235673,
+     379377492454079,  397636356425084,  418826856128641,  496807973633863,
+     506764138685365,  525008265881575,  554965181684851,  572269102649140,
+     575675262530091,  743095831169474,  749742116602019,  849059136655442,
+     972211416262298,  984702306578540, 1010678328283860, 1055093002612684,
+    1086038264778878, 1256447451346687, 1335040636231728, 1598990027133320,
+    1602938500326727, 1634361233996279, 1723469598923379, 1746974754721404,
+    1891256622029331, 1909241381989149, 1955162154702022, 1988000601203547,
+    2061003636183018, 2066132033811678, 2128519678680484, 2194093414259969,
+    2271114676500210, 2380880069883288, 2391165356238545, 2416997940002527,
+    2440252570629037, 2494613585492833, 2590295534430156, 2737134723390842,
+    2786667970831796, 2862988745043448, 2865339751849077, 2889037468171090,
+    2948587792666032, 2951274797936987, 3099003105643638, 3133007722257274,
+    3143142693509622, 3152269669365992, 3188915116022892, 3225216704601242,
+    3228243638144135, 3327323887349185, 3364914452496271, 3367770439451542,
+    3380968694957944, 3452784928340591, 3462126078657315, 3592468921912478,
+    3640004845142883, 3693608389782216, 3727982408217380, 3737214506400621,
+    3791507732100360, 3844546367039218, 3882564298655359, 3925052385292650,
+    3925892381455965, 3955457299049694, 3958998054807106, 3979051857948142,
+    3986804383941965, 4023151165296353, 4055569122490394, 4196437952755799,
+    4200617302087661, 4253550814699648, 4300953124505245, 4310649922320355,
+    4359559312961654, 4360763798688232, 4454041688430664, 4488311566990610,
+     169644708974222,  244162684402619,  320329213704750,  431109479645377,
+     438775549893736,  504741283682768,  513888891023851,  519937845851372,
+     546948389147301,  624087545585902,  637888043955593,  638160183063474,
+     668152467126258,  711840127231862,  730038079200381,  768451938832681,
+     841424517810909,  849824479444059, 1063305328931041, 1098905447029149,
+    1436950359148348, 1590338570827932, 1819045456033108, 1832771288077171,
+    1859650182851598, 1907501473062616, 1949761630916411, 1968396773870515,
+    1968544141618465, 1983652283138299, 2053572384830656, 2071144515096214,
+    2141847630756012, 2171006795817052, 2222325157911507, 2250972465661334,
+    2302390055372742, 2336631442834751, 2344033233588453, 2389077659426850,
+    2405513093129887, 2671336019333750, 2695483698512837, 2715114064991430,
+    2740175424207807, 2874145478965846, 2916128585453495, 3030219713464732,
+    3184010730322046, 3334170605869872, 3380053062968278, 3409881277605011,
+    3532343876151461, 3591265431588483, 3617303347747942, 3700887387030223,
+    3738146865970248, 3783892762288887, 3789119405082847, 3856995024885311,
+    3897319752982696, 3899730689339158, 3966621892585316, 3996541475225778,
+    4210607865897948, 4303574318466379, 4324101663304161, 4341437022721346,
+      11527970439679,   34645057834769,   46539599997640,   87245519336389,
+      92050690371212,   98481818891750,  173792632804118,  252943850228063,
+     322099604534386,  334118918057188,  354902682776403,  388637125185464,
+     418037973353142,  448906604290402,  467139924518429,  563719834266594,
+     614831881513919,  666712205953890,  684877737017860,  730016478214945,
+     768207321322014,  782879254307916,  815420017958582,  839073523956210,
+     860394689729616,  933032252694171, 1039800186083946, 1289237783619075,
+    1320589780769611, 1326597472627706, 1339653272644423, 1406383353819489,
+    1503453506942501, 1516504394700953, 1634848555918027, 1677880920771080,
+    1693380078048808, 1821760965352930, 1828099743418086, 1838376187708466,
+    1894259020182759, 1955490319455984, 2117367895869141, 2167737209666798,
+    2418252556665268, 2568100012587440, 2571690873379155, 2607529727228942,
+    2674204791111440, 2755228561386003, 2795929568466487, 2865275918393492,
+    2884793302931990, 2885987964142038, 2941189957620802, 3017274943435734,
+    3039071615809192, 3149054131007583, 3162865436345457, 3346284247702292,
+    3613808780169151, 3661389896175594, 3662169962788076, 3745083970043011,
+    3765749840563866, 3842837909516511, 3843092364494638, 3844648567912990,
+    3860091725446497, 3882272536416278, 3952217199399960, 3961378561064185,
+    4016503923314015, 4037560809592764, 4063621322140242, 4239077362849512,
+    4240588373491826, 4262253397343782, 4357036403018610, 4459926599733994,
+       5568431663511,   15957857893986,   45846002081552,   65700367537029,
+     141112373969656,  178467150720022,  281509627441621,  286357043897523,
+     317319990193471,  385973711705111,  537331195817944,  613841583198625,
+     614500808257788,  724220026413175,  765720749139201,  919943062272182,
+     947939004576070,  991681681974242, 1002301984343172, 1002778473394877,
+    1033397561612275, 1169020100050660, 1260263332419729, 1298764630249146,
+    1310401770078931, 1319171133533609, 1328645897275141, 1346759358401502,
+    1353392871962558, 1362315497875104, 1409009460038888, 1499453959214068,
+    1550882355893659, 1561163712442304, 1625070227566648, 1666735019718725,
+    1894156858161777, 2034862601452784, 2045142975549776, 2060454484561781,
+    2134351550545482, 2222715217119608, 2240539837459987, 2248330679068277,
+    2282936556093549, 2319492599650026, 2319692037335585, 2385895524424643,
+    2429864165703005, 2502596044026248, 2520004104865755, 2570526681121280,
+    2682738379120328, 2888145440922956, 2895782037621725, 2910882319528335,
+    2932065942070321, 2972232495451573, 3005026731628713, 3023710997165467,
+    3062635731657327, 3094961338395986, 3214449073337152, 3326551719202717,
+    3429100022742857, 3463245129942872, 3469151629280708, 3585301975956633,
+    3589263221021529, 3651434726526675, 3736721354523605, 3739951655558669,
+    3868663629069852, 3976626223669675, 4000061625400337, 4002305544975789,
+    4017856772191126, 4069925727348473, 4076952221570729, 4119388238270411,
+    4165056520908463, 4169960919560239, 4197766266243445, 4212029499341699,
+    4255905790164679, 4344151562540445, 4436655280380313, 4445022821108914,
+    4458369426814919,   12864130589619,  105545672178680,  121128337846913,
+     138305522547965,  193148921173384,  207269698781953,  245556822151016,
+     250682272424717,  332548003765901,  363304635214719,  438380643182020,
+     503261750346140,  516526812564946,  557113855253682,  615735762936539,
+     622604082442662,  640342247037577,  700268850515618,  873423007001909,
+     960343452097778,  981407215538098,  995496554693771, 1078325088654895,
+    1123176953969304, 1421940501646604, 1426857672169869, 1463373435208573,
+    1577863438590928, 1660035694847848, 1666730120411390, 1677553790272491,
+    1783198781422887, 1820475944492666, 1895344655161184, 1955518836455781,
+    1971762692086483, 2181850153564003, 2265250474387173, 2289135838974909,
+    2318863843543036, 2376004214133169, 2413411060821144, 2533679844826445,
+    2535498362836148, 2573420996641948, 2586367744525182, 2612125661963536,
+    2646429926331337, 2690029165788171, 2742722960196826, 2815529504845697,
+    2859587549224482, 2911961064394259, 2948936614806567, 2949084965006170,
+    3015714836112630, 3066304219080559, 3070294937695045, 3131272764441796,
+    3149459909442132, 3150480676709599, 3211131347055901, 3250872498871404,
+    3277730762161118, 3302183983804288, 3410348963444629, 3430592379754694,
+    3433437559741782, 3449187242191025, 3493077287337147, 3585543672014201,
+    3590606245382110, 3606863855335954, 3783641293518509, 3933992747138670,
+    3939979807512052, 4068707500249683, 4078714752787141, 4098770520831483,
+    4157963232924733, 4249339096266957, 4286137805381725, 4320595332207394,
+    4334155129922100, 4338866968049888, 4385336931236788, 4397337578178183,
+    4428333927830333, 4485553877004359, 4490886624576843,   37074699625428,
+      54444444850468,  109084475983716,  184202729372096,  202332892176558,
+     414047293591446,  439785561996740,  461664760309050,  545936515748431,
+     579976500621575,  669469986596651,  893359911347168,  893396262058329,
+     946144091307899,  946929070178646,  973105512021178, 1047860258297948,
+    1163878956452371, 1167100415422558, 1183559427965267, 1293626434005816,
+    1300732506811965, 1314300164139895, 1497336801803713, 1544648726057234,
+    1592183666835986, 1665004948259652, 1720376905874385, 1771732601131654,
+    1832394078446139, 1842316840141439, 1918260352918339, 2026321192683462,
+    2093879480602007, 2142291750706888, 2205039955332345, 2256914385062398,
+    2354032678316407, 2435977988241484, 2446095275364078, 2477132887985654,
+    2493511339492817, 2606845979731244, 2654697269942262, 2675556683438758,
+    2681077079276965, 2745416855580282, 2823371446890873, 2984518079274198,
+    3096530339587687, 3098665207029656, 3295243958278426, 3343148300878706,
+    3352592608618995, 3452806607031607, 3456076206132791, 3486497821567936,
+    3523881089416239, 3565558653439805, 3592713617128725, 3696664861629349,
+    3836467731851249, 3970604803390298, 4219323246315431, 4272536757648523,
+    4303150933607656, 4398856461384651, 4410624267284285, 4441977246886474,
+    4470510590244553,    1740838876213,   42460512538104,   57916441943100,
+      81295843929541,   95676971226155,  175199555374934,  222788530994601,
+     230719863189270,  400656490511986,  432662800463420,  455751396760573,
+     459698887502327,  459770606472996,  719435525715855,  752310322928541,
+     937632143137237,  947809359810664,  963480446127667,  970871429913186,
+    1011223666656855, 1020697447946734, 1270264729100059, 1336516356320114,
+    1384757679837373, 1497918426133219, 1572420682335168, 1612291517768867,
+    1630211435919587, 1681439412461966, 1942167344393371, 1946624727543032,
+    1969236834788480, 1998354736880094, 2092700551569337, 2128403827091010,
+    2144076878311319, 2177491041481502, 2215304623153819, 2241548854160408,
+    2320033969363619, 2376939534008685, 2418267345243783, 2508683353321026,
+    2564070047710554, 2585296898125272, 2698051891888429, 2805496734467121,
+    2808764368664999, 2833473027737949, 2877840544387429, 3023223739998369,
+    3031744543184838, 3041815655079741, 3109123417826771, 3202020116327839,
+    3206290833663430, 3296377720436918, 3394250536560431, 3437240655992405,
+    3486913411568085, 3613507219315400, 3663735060075025, 3684088510970226,
+    3691097321062728, 3807884296409797, 3836014834590146, 3852392303645656,
+    3903374667274785, 3957950839050588, 3963548848569382, 4129750194707392,
+    4147525692465121, 4318350456082149, 4333894806135915, 4348563791766858,
+    4380348067644714,    5821955993705,   53679140914641,  180499894993799,
+     236713813675153,  285352047660224,  285580958895373,  484584400823747,
+     527419292894547,  534763119000722,  540360146067863,  586426321625380,
+     621889878943721,  656423054546671,  719745993389133,  730483207504770,
+     781619816043420,  799615382971421,  857930765738542,  950142695437469,
+    1078987299921807, 1271071334858102, 1342316763830356, 1462833125652213,
+    1485010989267035, 1547242424323014, 1606344750864188, 1694106174575025,
+    1755382666624288, 1775790152360404, 1865600970219399, 2005038368426383,
+    2080524058732985, 2166460087272548, 2179748728331026, 2188302934873697,
+    2238255689170482, 2281246791054109, 2376069094795057, 2414220639836006,
+    2424205295985445, 2972160828284624, 3205120746695637, 3378751392233633,
+    3476735225393935, 3499378771092279, 3527174293258955, 3543620533930175,
+    3561421575430882, 3592826624970680, 3644163653646542, 3713722213130595,
+    3813900843283699, 3846736342430265, 3906198246276437, 4054662410271185,
+    4071227527592418, 4174301442766913, 4237657784965577, 4394672225696384,
+    4420518563783508, 4426260993695293, 4433078225715460, 4435455758715720,
+    4458232917838872, 4461207781444156, 4478698368222515, 4499732658113245,
+      25070161730211,  102145458811367,  138816449211245,  142067381731022,
+     193504620475490,  197780250069346,  234230188847299,  373283448457960,
+     646489498834118,  699230450922117,  794808259984222,  797995333146554,
+     819804777391501,  873830776241624,  948407698769201, 1054305179994418,
+    1295413552415413, 1490916519104148, 1516527068794552, 1604190247340089,
+    1686852746971856, 1715689667890712, 1717127977110704, 1878410187822143,
+    1967797569018695, 2057437440290068, 2231423733326450, 2235835923700073,
+    2305127256334510, 2384184158399767, 2495103932475120, 2618530316093163,
+    2998797070451690, 3076898047058578, 3192966850247304, 3283802365180378,
+    3418751924236458, 3457871184155612, 3495534450725020, 3574124688255102,
+    3599590817552515, 3637236399992169, 3659197141791678, 3676078608545177,
+    3765543603422316, 3811653006852565, 3850531566116734, 3884985163135791,
+    3887905991900160, 4008112881451322, 4111051218297703, 4191983620568537,
+    4208298212718255, 4331278563285836, 4421465660127930, 4430848073414080,
+      33601784835793,   85233549007555,  129919379992607,  227467987070630,
+     387151748942638,  421221207365372,  439526246564068,  478054070588116,
+     550099215205912,  720014228592262,  727265704243055,  857400267747782,
+     870223226722738,  884977685647492,  891197587062635,  940721009987059,
+     956077711775102, 1008697822309782, 1117614377464668, 1392472911521172,
+    1401187257683282, 1475429164197186, 1530345264244927, 1597548887116739,
+    1754389433904965, 1769538838394225, 1802147391208948, 1859990214730976,
+    1895198334619537, 1921727476605496, 1983878350625929, 2006708562138343,
+    2015754976959167, 2066451447157273, 2073601730287807, 2080576154572454,
+    2148967561492743, 2207883222219847, 2442386572177723, 2448834402376362,
+    2602264859477025, 2689583197492359, 2696434815320381, 2713830104288399,
+    2732212757167682, 2802916855279133, 2820679581165373, 2821741611402266,
+    2856004612800641, 3065384709088001, 3136050491584985, 3156322398992987,
+    3238171428656070, 3300154303443840, 3358423510983270, 3409222156153288,
+    3446059180882523, 3472533305575914, 3516348684396408, 3558218808943962,
+    3645957636266780, 3667346591204243, 3731042861674845, 4121099746755517,
+    4132412677539812, 4133370567901487, 4174418380415480, 4175901882411510,
+    4230914262721245, 4241871546007154, 4320001013711979, 4400679943456339,
+    4423655557813397, 4430239948791803,   83992725520746,   98866061095513,
+     109373381524348,  114552866638964,  123544264167220,  275137536676773,
+     278002365696921,  448462639750686,  467352237682917,  549215021669142,
+     563402605990115,  670185276154685,  791456160846046,  819289998627189,
+     920198590358472,  985625940641660, 1015307769982096, 1079196600976686,
+    1311357786193810, 1337947840182255, 1540025373332866, 1657625818648619,
+    1867713280126139, 1871079159489317, 1916831932969527, 1924775054584032,
+    1942797147706664, 1958522268864582, 1979307998487103, 2130842323897476,
+    2202827538964439, 2308182706877200, 2334362096074691, 2335662862063263,
+    2365172762364424, 2484655585047325, 2534047359450247, 2617856380161065,
+    2647613858278782, 2690199207629720, 2757125779135386, 2781113318695034,
+    2827998858931153, 2884661758017928, 2926850196901054, 2964315008236556,
+    2977551579357425, 2985469157228952, 2987031255357222, 3079404307127241,
+    3203859317625975, 3264547321135091, 3302402165776834, 3409790026159652,
+    3591209548402632, 3600739329436732, 3631269997005360, 3669752628253370,
+    3795882718820469, 3808259644744963, 4111902047331061, 4118709454834698,
+    4138837924301362, 4177792132342812, 4245290490710524, 4270674094069085,
+    4292000172100756, 4354449711423701, 4387801980139745, 4410519209712064,
+    4446434694790785,    2036621578416,   10718546835977,   59963935942602,
+     193367116096860,  206325653399930,  337261897453505,  350352083278077,
+     368098107486216,  411154051178941,  435419624556388,  521544283813327,
+     552393265717527,  558192677825186,  652526703094593,  712123202817226,
+     759941089672042,  830950730247576,  832961808781267,  887940785734800,
+    1107340906431107, 1260206453831295, 1314446627141772, 1390472665857524,
+    1396937197734264, 1423715882440085, 1430548833686700, 1460596135042052,
+    1487588011756574, 1576988164824615, 1580298044443572, 1703420850500838,
+    1770157808837475, 1776188097083589, 1780870461661787, 1855831522785687,
+    1919663371584403, 1942535828517896, 2002832816269118, 2238774475446986,
+    2369416966456314, 2428608696097911, 2467301571999663, 2468103252548511,
+    2475021677088937, 2477817734493375, 2623841488582071, 2759608447413447,
+    2992613558600498, 3041767579703394, 3120527781369445, 3230243069718220,
+    3283139249167393, 3290340619783883, 3503972766825427, 3562571095668612,
+    3573326976365656, 3578205831274454, 3654249554119960, 3684198610310012,
+    3695829855429879, 3713318477304932, 3732533266734306, 3784907764355736,
+    3787811891442004, 3793836284978200, 3826777888903290, 3931744005961463,
+    3942344659297333, 4015600184351625, 4028630440625364, 4109815332971019,
+    4124508879893287, 4135044691420059, 4149788343376630, 4161503096887002,
+    4181490093315634, 4224946877282783, 4227431497513220, 4414026575064204,
+      29313422223238,   77402465734282,  146777306759224,  243761004136772,
+     269144607495333,  270026849079727,  323959515022815,  334315537897088,
+     344120405394028,  348498209959796,  350418902941411,  532542843019592,
+     537033629525455,  564665082266080,  617264561316047,  637862642672845,
+     674562124170660,  766841843033644,  779133295664327,  858052654498164,
+     938770882308644,  947987261265437, 1112027213766847, 1118935813790743,
+    1398222257447318, 1508395368266391, 1514714497298487, 1657681844294700,
+    1718023042370307, 1741135217507132, 1789003209396251, 1808266138956622,
+    1930494914009658, 1937244356865768, 1960612951884026, 1977707610646226,
+    2022280459691183, 2064063146041620, 2115280315615816, 2162755326843735,
+    2395635666670855, 2416277958352038, 2435549729977286, 2516768025679143,
+    2570991497311519, 2661628557010687, 2776272805302563, 2810010195066583,
+    2901155182270352, 2953125892262399, 2983860909774851, 3012535726170962,
+    3059725826419511, 3098717367623775, 3137393541847426, 3144800244859393,
+    3205148320096571, 3251358916047079, 3397603721757700, 3474449125152054,
+    3568977675848755, 3574337932067523, 3636799260810304, 3709604823007522,
+    3712001022589189, 3713650558914576, 3762997140541460, 3767539996984932,
+    3782124491773717, 3789984104997717, 3865882424998579, 3909960118410424,
+    3968028905812642, 3979800641518888, 4145103044394403, 4183694727775896,
+    4233638640007804, 4360202974205529, 4467051468630850,   49476290448687,
+      63558752442789,  174806665370244,  181650423585042,  343338386829170,
+     491994128896253,  492016725284272,  550949087689477,  574003298178775,
+     612050703344506,  641988952566375,  643871329933523,  644052101037675,
+     867545115320380,  903711125570616, 1091565706140746, 1491032513153852,
+    1493912078948795, 1602670459387548, 1721318198165399, 1867968756408709,
+    1929864192999362, 1978853162224554, 1995547963163983, 2026522698879767,
+    2101997582218186, 2211143048608221, 2258037430909217, 2286402775034633,
+    2327081185727098, 2624551827029050, 2655094284017514, 2723567234425002,
+    2784034186312193, 2805819069265815, 2815188710680476, 2936426192015635,
+    2973504899851508, 3045891937644548, 3052595205272967, 3082587489335751,
+    3172619358816671, 3236506224907955, 3282611698531592, 3406118643185181,
+    3424524874904136, 3425776518310058, 3440756941115002, 3654536455904496,
+    3679886655906855, 3827586472515569, 3976199969161573, 4048515287983944,
+    4088170966505636, 4163127115371271, 4363475533612602, 4381485837315398,
+    4426061633715314, 4431859080919667, 4436883338673109,  162811926089697,
+     188118898219324,  259776956885838,  306029798257425,  547427011464402,
+     641630370663960,  743903587741260,  771141077369032,  941134686887622,
+     992124910129975, 1088013173914428, 1254717535298774, 1329992980951634,
+    1379533105554159, 1425112967543441, 1475232738621236, 1683585190479558,
+    1821536139335553, 1914339504929586, 1933537592680859, 1938808445799204,
+    1963312754928330, 2045221714142246, 2083623784354710, 2194273384225488,
+    2308992298842992, 2507140025381603, 2530117604641967, 2598420590913486,
+    2715372618138259, 2744485607971608, 2767124241411687, 2868271568894649,
+    2898297256313635, 2909895098077300, 2918325515711693, 2928866239496730,
+    2939852013880576, 2966752522591369, 2994360396492322, 3000588157520689,
+    3015051810756155, 3054011913507523, 3252604690644943, 3382889569252579,
+    3433459303187448, 3499100827930990, 3875242267798570, 3931267555762548,
+    3968771665164170, 3985747447276357, 4013132304652079, 4027199047419733,
+    4092390609306201, 4118048316675949, 4145621804769047, 4181473430490323,
+    4235076975129656, 4261713204345792, 4290406687323310, 4366288305646071,
+    4401900213164015, 4471915612666713,   15568962450558,  108725028188018,
+     144076586017917,  177128224528143,  352748334663464,  463842002681398,
+     470999162973503,  550295783242092,  602813718804860,  631112256217872,
+     634871116242250,  745933345807288,  766737742011216,  840055161519647,
+     901035935621357,  942874621716015, 1139268672054021, 1163475298332288,
+    1354017866625026, 1373222831537870, 1469283024361598, 1489287704919984,
+    1518937113355871, 1574291386840850, 1835972296875665, 1968146430012020,
+    2002704166906295, 2048593501166272, 2130978949431893, 2248307255639765,
+    2295301848009367, 2327949698890210, 2355885711643265, 2402164096757830,
+    2434413072267555, 2501873115020800, 2540902971839531, 2679619250533213,
+    2852498320556664, 2959617971638213, 3073565661708112, 3130441752803588,
+    3215033787481847, 3297870181056195, 3619955166072409, 3763029600298799,
+    3801408091671591, 3864800784581416, 3986702403234003, 4109806542709862,
+    4340674821551638, 4458816597728194,    4198079969666,   28721055680199,
+      30184908643169,   67239055187717,   78736686882776,  117997419839962,
+     141873942362821,  233029736534773,  362602337693984,  418241522158333,
+     467868102506322,  471735032212530,  560507398674304,  625062331889628,
+     669587040803588,  784018097086763,  805814769460221, 1045400341819066,
+    1252204449872260, 1396932350230649, 1605914553598544, 1730167179056760,
+    1905403150595758, 1909747551805324, 1932038397360241, 2106700617133887,
+    2153277667550964, 2240040920382353, 2334712909020392, 2357537225822888,
+    2431515835293788, 2469605485880598, 2520480762279550, 2565423995597688,
+    2666806129025717, 2734889046126964, 2963430879454436, 3383078225871815,
+    3412220689254754, 3475489593256301, 3523242638302019, 3561065062039213,
+    3754120741660325, 3785328318417870, 3803129359918577, 3810368063697881,
+    3820524648886595, 3850567037983682, 3974761698794371, 4012364053361293,
+    4131706385457815, 4153823337070151, 4188833001724806, 4244024188235387,
+    4367360186301354, 4436353836084932,   52748888708550,   96442443524072,
+     165862477325052,  191909235549388,  269503267103328,  282938293458103,
+     330163761966160,  441546270770076,  499944179476049,  530502355690961,
+     566716506072194,  576232532783152,  588413950828699,  629468640504263,
+     664095149014248,  733583954430938,  745296743038004,  758703278294842,
+     785489822613887,  887425076322555,  988125388303402, 1324302659830248,
+    1339760554138550, 1401121537029971, 1463849110170715, 1584310454700004,
+    1595144931529288, 1602980965913616, 1647899637940429, 1657274191613355,
+    1695466017172077, 1943666708076079, 1963550547081146, 2042319590812074,
+    2220189515232765, 2267638000266053, 2319869059946145, 2430367362262361,
+    2430762307826867, 2528032881087091, 2703338606693452, 2777249427000295,
+    2801592614058329, 2810647870944030, 2845655570695379, 3205720171713267,
+    3414505884750562, 3458190597501207, 3565741544858423, 3689120770797122,
+    3745537074518994, 3839672644554495, 4015092334552604, 4039754818397863,
+    4067162272161604, 4131903871190998, 4497198062357805,  123436298092830,
+     342326526381230,  441777159859461,  523229279054732,  681824484495216,
+     722656157645549,  868066861902773,  973570380015137,  997148237235484,
+    1465070310526324, 1550761708604290, 1671480455466665, 1760407066837960,
+    1832586807332217, 1838169097624563, 1868331345823316, 2086505366856679,
+    2140392840023729, 2159786435653949, 2180807953673190, 2243402895840779,
+    2306744501264648, 2324911997231924, 2522955583895317, 2554740842224826,
+    2624727750629587, 2817570238241998, 2848182449297825, 2908973610230506,
+    2966890117626509, 3028836641703118, 3120354960535027, 3240230763879128,
+    3278166169556417, 3285389154109273, 3309361956894126, 3392839891397883,
+    3402325462107598, 3438429577903695, 3495791000115753, 3496856960159258,
+    3590191832097564, 3753223789202996, 3759250147642498, 3789019415180051,
+    3972424472213744, 4044729966519585, 4085437850761640, 4155795143439582,
+    4238450765909778, 4334300714079764, 4359797299378420, 4419574570205205,
+    4461310098876298, 4472826397152764,  159491304257207,  305876600554207,
+     316081325873918,  336892599239417,  430987888757145,  490418354150421,
+     513019654427686,  608840129048082,  612161798086875,  702111141628943,
+     747336338571492,  888994077514256,  964197804196447, 1245181924309406,
+    1368213362362943, 1679445203968466, 1707017709609911, 1751078701343655,
+    1912604560065038, 1930974441072832, 1956623306377703, 2007292267929525,
+    2441654775205538, 2616598958603595, 2633200426635989, 2711184491496170,
+    2753921138401670, 2757830313528957, 2809126079235393, 2848245339154547,
+    2940616426021260, 3024315412146942, 3331858147795450, 3365777291115275,
+    3515234713731206, 3675812506607444, 3692340290765863, 3744039844101682,
+    3751356161561573, 3769688709406553, 3838071274261965, 3970453687148756,
+    4108401688649792, 4117716312771885, 4206754923631622, 4228971102860911,
+    4244437839234090, 4397867313883100, 4442476513639218,   52808857112173,
+     294794558859297,  325725084250696,  422532922782357,  458408127343305,
+     568000609235455,  625205821634686,  625720626300858,  708103127211520,
+     744316295141100,  865742407193635, 1108738069240043, 1382881378493163,
+    1600403051628934, 1676500809315355, 1722889229015056, 2149398017776987,
+    2493544937112969, 2673059485600782, 2686892404875022, 2806346736459986,
+    2906257157311821, 3029932101198073, 3258784389247893, 3346248130107871,
+    3467747926034381, 3481738037573101, 3588993266982764, 3613729434701998,
+    3635499580880825, 3692023954284527, 3711115937257276, 3713375576059176,
+    3918461376171273, 4001427453363817, 4062002474932838, 4124172015534678,
+    4187210043397770, 4256884532176877, 4473328455849307,  103929759375305,
+     208343738604492,  470634751115820,  516760856224170,  533560779490470,
+     534303512940138,  690851289135770,  827872838728027,  928635045163013,
+    1060207917887732, 1062929308966542, 1082812165519956, 1178058888375000,
+    1601315762165501, 1740554705138579, 1807980362083969, 1818863961495903,
+    1903318452942742, 1915378046983317, 1935264833343343, 2036092864039080,
+    2046447904461700, 2086987789471092, 2169239624311905, 2270756353616395,
+    2369000536464742, 2648758556914757, 2688252130913704, 2725691398506228,
+    2990847240037704, 3022231658092789, 3036085208851742, 3238428986400316,
+    3346055582534813, 3539645715855157, 3644057730181038, 3677037649720595,
+    3783461725031820, 3823094807165493, 3890841725801414, 3940853427197379,
+    3941302407602800, 4101419430653781, 4134902365439674, 4273764046978000,
+    4352211829018397, 4473468959386616,   62882942263895,  115834139005636,
+     160531759410524,  230375229873947,  311971769462209,  451018151911299,
+     490169832735002,  517524234109481,  757044964659228,  913560319950311,
+     955315497654464,  966909409611517, 1044545686586536, 1098804526478420,
+    1117883737579680, 1228689547263285, 1249840748900722, 1402509805970310,
+    1610607802850505, 1790974136921469, 1949016222081314, 1965611795403790,
+    2154260196909544, 2156749729398246, 2275462309985195, 2735231999459441,
+    2806676866117254, 3238532156774811, 3238836716787241, 3356755476438566,
+    3359593779264083, 3404357223929722, 3416854991407535, 3449367263960264,
+    3505349324051510, 3611679084004047, 3683864719208222, 3773753150747804,
+    3807190892757659, 3913198408568012, 3962018396108888, 4053702749270154,
+    4060014018689026, 4213910157873211, 4332859509308533, 4436391246953326,
+     106038126524503,  125774597781620,  388360345788848,  464994522077807,
+     473454413261790,  556510876006410,  790266580256353, 1014853063228847,
+    1046167727216569, 1305347315342846, 1379709080958416, 1512937384718440,
+    1563594556850426, 1569045198621270, 1650324406325613, 1894656202620101,
+    2226490055389034, 2258446260306165, 2460169128410043, 2464131355926592,
+    2719246541564408, 2721462952493576, 2784435156095917, 2986749460094901,
+    3000127504253802, 3153806521622674, 3184255645704103, 3241493279007883,
+    3376475258968512, 3434974360194744, 3464242577389267, 3503041558069543,
+    3591684240913121, 3749511169160959, 3832644263134513, 3835907967525779,
+    3880497518248837, 3887143803681382, 3945440519867096, 3969286586388712,
+    4053166343618546, 4294741380574716,    3892641113023,   18321908540634,
+      73663410154124,  145348977466922,  255265668404562,  290869716309282,
+     363536752823427,  479948431639050,  482201193279379,  547973384092770,
+     619802389346906,  636140560336296,  664465623943939,  716004055208666,
+     766945155868369,  802514817965234,  845909707478244,  864235378161653,
+     909632504143477, 1022877741281481, 1028249786920085, 1050359861370850,
+    1067752202983909, 1100550368967661, 1459940025699938, 1498340131009096,
+    1517291623395466, 1592839208156207, 1863776777665588, 1867994442611917,
+    2116505588238267, 2191876331701468, 2316761656024216, 2324775514157737,
+    2412003466620995, 2534245995997173, 2564438700197169, 2627442342252406,
+    2675416438920049, 2800369552406854, 2911384624292548, 3000718953099838,
+    3023064815947323, 3335728089611267, 3484326849482476, 3575014996667600,
+    3613057489575066, 3824297366841555, 3843705699246570, 3952922884607274,
+    3962770979976946, 3985836979886080, 4054488736494414, 4150419245699946,
+    4348207394933559,  142443880879931,  205529066422367,  211213531686625,
+     321193099530057,  341953285409600,  381998014785880,  702329323601489,
+     784882771099773,  800938978464752,  945083302541259,  975178744027608,
+     983125795448725,  986397359453215, 1013290008550784, 1372229702425987,
+    1431650343302733, 1461909854215133, 1492280382164322, 1494706055295579,
+    1499413963616755, 1529085968440661, 1680116296153045, 1869251011227034,
+    1938012802420504, 1956610612211794, 1979867207741610, 2021381684790778,
+    2195570362867678, 2358897055469010, 2430630762912805, 2491298134937208,
+    2503902007193545, 2614171398274612, 2788992775216044, 2846221566454947,
+    2955136156706527, 2978081315062342, 3092373845662444, 3210386920672457,
+    3241305656644020, 3245138200542373, 3253122585126304, 3375360202244217,
+    3447963379400917, 3468307005779588, 3514928266521050, 3571569551671459,
+    3595030497145099, 3610138638664933, 3640493447387674, 3704912802274884,
+    3729653882252383, 3779646917066941, 3780690280722427, 3785994537196974,
+    3787316917121912, 3811608034242337, 3813849006462830, 3926648210550372,
+    3932862217255597, 3941638457871846, 4002680143976042, 4005046870008119,
+    4012149013007656, 4018337475969903, 4056611827467477, 4067555356430244,
+    4126124211723839, 4167581706577133, 4307177279500250, 4329188126334062,
+    4426432173399655, 4427106135233613, 4491137421717888,   35084524545980,
+     266633642383285,  297068029689919,  333569055953828,  499189773164915,
+     519591364191113,  551886514928529,  858203150914093,  912511113388627,
+    1031304255011122, 1037990820961440, 1038479099432981, 1089579357260470,
+    1394747507263677, 1471367929229494, 1776396571097975, 1873204409629636,
+    1939777298540222, 1989492299536981, 2111945073566901, 2304904472926019,
+    2387726129725572, 2405797345430854, 2419120372297187, 2501522522240909,
+    2669100283144772, 2798175763767565, 2857587562579434, 2875758005901669,
+    2951726079969894, 2972553072561841, 3097339169267636, 3117501042090502,
+    3187409354363023, 3503503349199243, 3507715119435654, 3545148492318260,
+    3603984574461471, 3690499267024651, 3707498627976510, 3755390198705301,
+    3777053257653951, 3820809689375265, 4163173458961052, 4234610466005641,
+    4235219586030501, 4235464216492098, 4265449623393311, 4360600065986361,
+    4413019756383829,   42808572392796,  131423746590090,  199285612069412,
+     205009375399790,  330606977170963,  449711555967459,  615555289703777,
+     646965251501840,  683176454528114,  796704637742182,  797627159844349,
+     972297239231219,  976488377982917, 1217068191414598, 1256747448775962,
+    1309064964201518, 1320024212390733, 1724642013420518, 2118599231563600,
+    2154491137802649, 2163862744120616, 2255671868641813, 2272948280959818,
+    2346200858659151, 2360903247646296, 2464734633045254, 2510137653736996,
+    2513538901359682, 2576698753227746, 2590888302452025, 2720121957790951,
+    2876912399544874, 3019528028849313, 3232034286296519, 3298139528220277,
+    3399710292365682, 3522422433631994, 3547379652973153, 3548696138188173,
+    3570913299869115, 3607637342658255, 3627874864258402, 3676271415136835,
+    3793631159797603, 3810035155047744, 3997801314969104, 4024211580305050,
+    4060127065383771, 4142220725508451, 4191223449056785, 4204209495006139,
+    4272491849792945, 4475247274584126, 4482364154358458, 4485198527377363,
+      39953580840108,   73568164147503,  167583875081338,  338001903714024,
+     347919753151059,  490463663484829,  534599321544201,  659549487676047,
+     702312660776178,  841377709806675,  933535604664687, 1200906874673678,
+    1225185219922614, 1281578927256467, 1296869804783759, 1628597552774168,
+    1724307010357775, 1834837733578540, 2036384742836531, 2086261848356035,
+    2179114336532718, 2216048623975600, 2496838218661265, 2514664803904950,
+    3186095842404822, 3248413733206265, 3283586484835318, 3303984146054904,
+    3558602211156302, 3568886515059906, 3725873447144429, 3744293433186526,
+    3761336022011117, 3778546610068371, 3792532791800479, 3894212608079367,
+    3904970453679717, 3957271267427172, 4048432956309042, 4200856400465380,
+    4301291450499917, 4340402850805847, 4372283406945697, 4479417794802041,
+      43857856660060,   86797871057731,  118628322163278,  231438333219003,
+     239163350566542,  288770281881471,  307119531404552,  516882783836582,
+     519785928471197,  541224005991310,  555859588266051,  576822159186112,
+     685408610585590,  706832933782561,  800188437205580,  826335405498522,
+     856993791782040,  974215993211388,  975312409256136, 1085500256850004,
+    1455629092601224, 1477637974531730, 1481951919740053, 1616717760337022,
+    1984002398552807, 2075121842013598, 2128562299678533, 2144375841485221,
+    2211583394163645, 2597461912364108, 2678180140174588, 3018923846984578,
+    3062646875349690, 3068121095960206, 3463653184103047, 3475557550782448,
+    3564898756751309, 3644013623464093, 3738332911958705, 3786009325775489,
+    4275178880965760, 4467540949719854,   67112500237500,   93104241169268,
+     411792890821061,  428078009421189,  510462475235157,  526131596648854,
+     743097202149027,  753039612877387,  913627230270155, 1005848002033959,
+    1059710913909684, 1635679090190852, 1640227841344242, 1648684785173266,
+    1727535372391393, 1757170044052485, 1956168573722594, 1964300326054475,
+    2014843533794713, 2049576147083222, 2153796583336768, 2169889141412908,
+    2302304957655901, 2307660301616404, 2322665285712673, 2721142763911208,
+    2761238580773444, 2933003550622393, 2974925745107556, 3098705811320679,
+    3376716050280007, 3487155405497043, 3706016160235853, 3733904032857911,
+    4093220988167866, 4128358371537411,   72057192357979,  161307030323111,
+     244664123273549,  377543656696758,  467811314574398,  688538727453908,
+     762470179245464,  875957099490106, 1046513653805865, 1053044992395009,
+    1069147374987679, 1360282185579434, 1582592328071927, 1702890585626818,
+    1746683368059293, 1805212925286768, 1888964963801712, 1934228476358728,
+    1953891263741870, 2006742250415005, 2041195847434062, 2052568707769190,
+    2082241695044749, 2189442889613497, 2218996017787390, 2239254170872250,
+    2305877164817139, 2583960105198789, 2584599681224892, 2698262460315421,
+    2791908769427728, 2849463734615427, 2922264384554380, 3025472895605336,
+    3077355184948613, 3186493774177077, 3323510411511069, 3334058994909330,
+    3369199325633834, 3445230276607851, 3512707021039197, 3532056406345032,
+    3614049856401106, 3626378642194603, 3659061861334954, 3799657570040825,
+    3903352394660016, 4026146079413527, 4028300595888556, 4032473068058847,
+    4090644030432977, 4100862510075830, 4123186758986949, 4400169353517239,
+    4404209194714375, 4432951800274543,  107797813993396,  203831480622493,
+     707798722610250,  754578158067845,  757585442577678,  814648199487224,
+     882328311410741,  908062650009423,  987114639816415, 1051440661796590,
+    1074166733433786, 1218100812856551, 1220057856549327, 1268305074776504,
+    1285489136639127, 1463483832419749, 1542888522370168, 1580817050885886,
+    1693047415466341, 1725971335261677, 1730600647450613, 1760080091750531,
+    1827870238259184, 2008166818262785, 2201897442169508, 2233371263325016,
+    2311436871672396, 2969659830149336, 3130338815545833, 3152685660844971,
+    3248846063737305, 3266854402536795, 3272640060321312, 3355076596072889,
+    3430534777733207, 3585954699431195, 3675297870303362, 3678248172617321,
+    3732149400107513, 3926049988150205, 3939933994910401, 4046487753839822,
+    4067126115714393, 4143712151872355, 4171319043321655, 4234930822950099,
+    4358304154178880, 4384480596021412,   52384606360167,  299010427264410,
+     343459487401089,  383664356396808,  463397185199329,  530353230254585,
+     700909784570344,  712044891605446,  805108606887524,  908710098599680,
+     989499062929176, 1000609608672953, 1080440682640774, 1330484452845804,
+    1414727717187248, 1736986596146043, 1864466575284364, 1896519060463489,
+    1943376109601671, 2069496375652240, 2161269149462276, 2188751269551645,
+    2255809489578813, 2301471877506947, 2304846598935002, 2532112227565227,
+    2562139879887519, 2588764319683819, 2606897429843240, 2617853730677496,
+    2722404270686450, 2907339987395517, 3097809854265933, 3125670218241707,
+    3369536332452673, 3565496139160053, 3619414068328346, 3651695464742620,
+    3870314536374792, 3917028147571636, 3936242937001010, 3972260597051643,
+    3980029824723567, 3982063499645277, 3982789531416844, 4031400256755631,
+    4069326820368043, 4264636244081137, 4371438563278767, 4405293847241147,
+     172450035824227,  211594839486359,  214126617396140,  282117184042005,
+     393409307293845,  457622347333925,  805690231217030,  853632889431777,
+    1002496911249296, 1006455208959233, 1022126295276236, 1579355188728082,
+    1904426826769416, 1932517084431992, 1939850103569984, 2000925193030382,
+    2240261222669365, 2268059692191000, 2468478846951347, 2494178799742174,
+    2667572674431797, 2749368651705618, 2886238903743313, 2916312731293296,
+    2960898352209742, 3414956067773446, 3451620181011753, 3460608631185050,
+    3577104800842831, 3675376492337462, 3848339070751418, 3853147189141200,
+    4125958293952852, 4182009125659808, 4225667312215822, 4249370923247753,
+    4306348206863488, 4439413423649114,   73369799570107,  194066932496116,
+     248286474321880,  314972345172561,  321606841185270,  515681323170543,
+     617389630547368,  749190432055583,  907385779673296,  921479048635581,
+     939865631321476,  986437769480288, 1005474735160469, 1020076914079008,
+    1062812578532855, 1279696640545829, 1899528335778488, 1902382357830453,
+    2052149252717079, 2097038451194302, 2214795106322882, 2247440992300419,
+    2441773859070431, 2476601770169281, 2822172261950460, 3045315523444697,
+    3185741268014599, 3247802738934609, 3319506972828814, 3568146819621707,
+    3883599273528518, 3911202235171206, 3952587028602178, 4008129932804533,
+    4312014018497269, 4325903919967383, 4364892513816688, 4462915645043110,
+      35019812246182,  427407072647770,  432213226134246,  501943714657574,
+     575556852113558,  657425530809701,  667228433403335,  707640599697837,
+     715504142728449,  972073356812705, 1105027556561542, 1239054593515455,
+    1281880643521378, 1515020350584890, 1563217463777764, 1742785064654839,
+    2421875296241499, 2526755940005861, 2788559656497301, 2896200989406593,
+    3148535836866419, 3203550182079460, 3242716599677958, 3299554375158967,
+    3316460403203791, 3440305311226012, 3551803749324982, 3557980422867393,
+    3594801728370180, 3629869357672362, 3646583807644851, 3672688530516714,
+    3828835466437922, 3848584709566528, 3860061709776874, 3892812718081282,
+    4222414440694599, 4241925930523179, 4302193444724811, 4310839019271211,
+    4352689494785705, 4416919236503886, 4448269268751116, 4463834250289595,
+     269729490002118,  306188270844948,  335889749218444,  541620037614909,
+     720683640793995,  759985636720607,  918200633371380, 1053275946239044,
+    1099185911983734, 1351412689761730, 1404333430502228, 1546656289214073,
+    1556070140953119, 1786202563506798, 1863366281236724, 1992434884697946,
+    2061284114088533, 2088703357272110, 2405679593692724, 2455113613516725,
+    2733816480891418, 2883705199783016, 2968426918079471, 3010987706139084,
+    3035021420926423, 3200289437994083, 3224221960261163, 3517731356498219,
+    3532802164855239, 3542447875184393, 3665646330018940, 3875192460635657,
+    3891283053808491, 3922301999848660, 4120044956306235, 4316638444329800,
+    4340117073933194, 4415317853260426, 4477647533481705,   75791581747542,
+     137442387876598,  270586200794464,  276730453432326,  636664388380365,
+     670202340458826,  900319043786057,  938966726911771,  960505996952143,
+    1069406832880581, 1115383605337675, 1465706215764415, 1487918465187092,
+    1509586436394007, 1509931276924210, 1577273425479095, 1608365072941586,
+    1818456464225718, 2044565747260362, 2323858085626855, 2383173604176730,
+    2663631130203681, 2712289013221812, 2730591105065549, 2783933317565184,
+    2895086915133951, 2959575041636871, 2991487036229617, 3114246335175273,
+    3550832130542025, 3721925103460322, 3866038971535586, 3929548576192118,
+    4079023682937803, 4237495099470009, 4427085672110990,  131088885987577,
+     529316821466250,  671291892292933,  749943041825501,  830311516847513,
+     989003985001644, 1183945971796516, 1394170976505456, 1488448019578989,
+    1503618055552962, 1523463578943562, 1534074056795962, 1617129537088929,
+    1631890873175254, 1673239315895065, 1700056316215353, 1711256265059553,
+    1832376834648005, 1868009373650662, 1881128050573171, 1893512836110889,
+    1915939259993920, 1979594267495096, 2091030811139927, 2121028989912629,
+    2202925177255056, 2415204488370419, 2416001256661002, 2471732791580733,
+    2549691312697936, 2594357494649928, 2651005656961908, 2654609289625112,
+    2673574536334624, 2680529311586211, 2708782656223185, 2793139884956377,
+    2902587946455536, 2954212833465727, 3013683579376776, 3037995327981914,
+    3144143334378646, 3277031709866732, 3529854835798099, 3851489857957239,
+    3976385006796517, 4382603221569039,   47525491140939,  292605915595013,
+     334500601433892,  611062706656698,  616729487791202,  782816869537464,
+     910486461844814,  926411020688291, 1039548188144465, 1044714901387592,
+    1081938029616456, 1324653692941759, 1327995993465265, 1538874328523450,
+    1544087216994268, 1965972588277181, 2069626149926946, 2104722270327065,
+    2173221746929184, 2340231650777613, 2432451440089764, 2533455329631388,
+    2675228015417553, 2860211872257617, 2916229300608371, 2988425742780729,
+    2993959892942078, 3064812341253132, 3086106980831907, 3094458802334060,
+    3174221104686171, 3353008638950764, 3717784095644787, 3770105540876955,
+    3777968101455914, 3940550095704272, 4011872155905460, 4055953779123917,
+    4178702271101390, 4240190972707701, 4270865078217667, 4278323851167243,
+    4491874454721588,    6351937766292,    8874873611196,   46019405707820,
+     107428528730238,  119219913469544,  226760053858577,  251642606874108,
+     568127617468222,  660512458658077,  717870933515176,  986501706543313,
+    1435683514553178, 1452426455623604, 1769700374895077, 2023438163315387,
+    2108713984344134, 2264784289036408, 2476612654843044, 2502307695375606,
+    2536847748140127, 2540318749830176, 2674051051288189, 2926685546502180,
+    3047607660856726, 3052484550862218, 3279663723746979, 3340353692159401,
+    3342707646319989, 3400324505961827, 3568723596447598, 3665507856139587,
+    3705940653918802, 3754032644784805, 3794922282582665, 3918378139750298,
+    4183590963477648, 4498124825088904,   40651302826758,  227985184020798,
+     291251140667386,  335364202338739,  373473501958609,  446748676045961,
+     447577865241093,  550111432006438,  584647554964347,  763548071169035,
+     792004783298180, 1008293479609436, 1058360198297969, 1283654046460623,
+    1303827708703325, 1550972264080352, 1599497516125328, 1718334868072208,
+    1722306920105287, 1931148632886803, 1980470653222362, 1986779957737928,
+    2102756410471272, 2108364244506596, 2296745419162734, 2369768103175265,
+    2462975256398681, 2719260541955220, 2743851306829810, 2799602490782601,
+    2819672995601738, 2915622171388677, 2999828102567307, 3197181025718641,
+    3291266490719839, 3304114010986120, 3465086749426864, 3496665692909243,
+    3549785871335300, 3628847685658822, 3656203069484954, 3773055869092774,
+    3874671826014217, 4191091012347580, 4484850933756151,    2985101853321,
+      23826831220063,  223652585181998,  284266904817139,  316855808598802,
+     450204953912145,  465598006411349,  472877973160079,  488249411722917,
+     527951265472300,  755199209972771,  841811165249598,  854076091685650,
+     991650761558546, 1028712767716318, 1075406729880102, 1090993325354947,
+    1160552867290653, 1781875924132566, 1783366303290167, 1923766504117091,
+    2153228894643424, 2277189731960699, 2547017131102190, 2589672234520491,
+    2917833683010510, 2954153938170267, 2998536191594542, 3044489385211964,
+    3189707528945200, 3482569489542929, 3665284865516216, 3667498329090425,
+    3691086993278955, 3774513840296756, 3902373045773135, 4003522338159403,
+    4014451130347375, 4085803646549806, 4242479141460328, 4410248480436526,
+    4475144324375441,  145072185119376,  150393143272024,  534905071223164,
+     586247994608944,  845009058331043,  967147447832003, 1011492368093464,
+    1113381446574441, 1475847159432261, 1610336931114737, 1636432811921723,
+    1774447620135163, 2178336442038422, 2252945785470548, 2281549865347643,
+    2389925089641726, 2496134008040944, 2630787849149471, 2801485876503262,
+    2856379380162984, 2945520165994633, 2977259249096451, 2986118015651552,
+    3111434636249967, 3185069387642317, 3285393420640065, 3381318035135679,
+    3490056766541372, 3630371494074485, 3639675311228395, 4208889132395188,
+    4288066876013764, 4297407043878835,   71919830439579,  469052332325157,
+     545838788620331,  765045366340997,  887148387582449, 1034989677229195,
+    1125647368413076, 1146476325908329, 1417923787825617, 1471814208347626,
+    1514598995384123, 1527345323130145, 1885221021865149, 1889573282687939,
+    1891125556299679, 1891636431159239, 1998927028828410, 2108359371101121,
+    2360694218560947, 2593852592205866, 2632663362305978, 2739018666001493,
+    2820260540543022, 2853497862415665, 3049326020601543, 3262788203507118,
+    3326892191413715, 3394905354447599, 3518015388633376, 3533847597021471,
+    3546007401130652, 3706007512434926, 3865205943190352, 3872432857549820,
+    3955443145066749, 4123410939276853, 4219290360996429, 4385336479773265,
+    4454541356662238, 4457523097429093, 4469888233933751,  110014406235584,
+     113057059004925,  164413736713847,  257293733536814,  301773249674736,
+     319625378661399,  477369780970385,  624170654316604,  648196509490719,
+     714424557871102,  748688541721130,  915660129955092, 1024667070662054,
+    1032853840286503, 1112975719943612, 1450851650378495, 1624908679933893,
+    1890146064952568, 1935778979331112, 1994262361330896, 2079523445584848,
+    2081082596358159, 2096071861227980, 2139393066867015, 2292390262788705,
+    2527385821024734, 2762244818480996, 2811487260232662, 3041379964583009,
+    3430218609613961, 3548588314574013, 3792010360637823, 3822909447576326,
+    3936519174277593, 3982069562717285, 4006269311833981, 4173464899044827,
+    4440932449315812, 4446464634573855,   26874448051389,   64213505575307,
+     288059220001439,  314970535680415,  367170156907611,  528556507494268,
+     560590326091986,  585156529675251, 1067309415159796, 1348195290107455,
+    1486171782706428, 1579912407177423, 1604176015651564, 1648677145725852,
+    1747667009378826, 1847233572152111, 2031800869491303, 2187938963347634,
+    2239692551524368, 2428902915375638, 2441042088000106, 2644080458867351,
+    2797337022025433, 2850759795560614, 2874320951102860, 3292060376410113,
+    3419536425741822, 3455895978967699, 3819570107358709, 3877926753095256,
+    4042647544592195, 4078860712521775, 4202523866988191, 4258322208620326,
+    4292141141871545, 4427708663017362,  310413937864731,  321439010314697,
+     491063218011759,  510357586025026,  546631665957092,  616578293844080,
+     666194067223886,  687557478418371,  776852509159541, 1069640785883295,
+    1281543818015559, 1313148305240916, 1403748031777270, 1538127768874610,
+    1546678045610669, 1782231636385602, 1787116386004318, 1993507864363252,
+    2213298018365800, 2549481184602564, 2552028681738793, 2958808858856831,
+    2992885194441136, 3056174729927396, 3064250482515056, 3146921824211700,
+    3164754304165303, 3302567709789878, 3443866853879297, 3568730564265679,
+    3679095253157087, 3699597054251891, 3944555654700993, 3953275895573021,
+    3965187139413993, 4059682286754492, 4096486890579178, 4110978052460928,
+    4243890006788686, 4316912690801217, 4323628730301125, 4477088660951378,
+    4481283729509688,   52847548303610,  306323022132569,  387315353954236,
+     474091378657114,  506186109257318,  981285974324976, 1056567314687754,
+    1129832664258576, 1313028459090180, 1323976900311212, 1595864500569044,
+    1629224628898312, 2051723050012697, 2191191886672577, 2619576175640085,
+    2844395499654340, 2869471595645512, 2895420108704548, 2917944777752879,
+    2925333796635092, 2981098734911025, 3467162760426163, 3592378188504319,
+    3673234941997872, 3925513789713477, 4058071078994502, 4173277289631894,
+    4312879739716582,   14567315047048,  346967059968109,  355970246916201,
+     402441192554754,  446877480819944,  464907433555800,  598577840559674,
+     669846848371600,  687279470502432,  738583095822092,  773942513265215,
+     795141855033649,  911168412801296,  962567335931297, 1119336163748854,
+    1808417928646524, 1844746043419505, 1848870375458799, 1898511692581583,
+    2066816467708666, 2111910998581366, 2757806153716462, 2842348083360418,
+    3198916138945279, 3211196784607779, 3274050111560107, 3938054798014952,
+    3944594978668973, 3987777658624935, 4142970633991080, 4181211582132452,
+    4218637095401834, 4241108712060903, 4443110596434233, 4493634155141411,
+       3409624574857,  316765526654166,  410156398336693,  447789208904858,
+     518041379255929,  816692927444787,  881034668651410,  986193346233571,
+    1060677918305766, 1069165318136033, 1232281766083623, 1376474230291127,
+    1397129293843799, 1741645615001309, 1865286173079706, 1988565188949799,
+    2059082621247180, 2085993936926156, 2269939342368999, 2311084417596639,
+    2452678297181958, 2541965468309904, 2570332777338626, 2862676003463571,
+    2891554187351853, 2900976881155776, 2959580122257226, 3039160671053532,
+    3113636401060850, 3177123409329363, 3296653389691608, 3335208722361940,
+    3336018262524012, 3369939370747143, 3405764470273788, 3584458671631346,
+    3685429157816768, 3776608530828392, 3972517250049816, 4042799876402130,
+    4076708212753772, 4183115586386896, 4187360759979509, 4202084762903020,
+    4285052804998870, 4480501736846690,   27888402911935,  163231925080868,
+     216343261442048,  225448623362052,  323305720259117,  723505436205361,
+     755763253779963,  818652931443452, 1478143252552762, 1540966123503847,
+    1703114856573232, 1854051851378581, 1864380365606570, 2062083041546372,
+    2271535839256054, 2777885526502336, 2863360785216642, 2865294250069746,
+    3137703532155321, 3203932562987357, 3251385960278910, 3346439140158313,
+    3599780018110811, 3883790089315010, 3919163261081275, 3989594237633192,
+    4035954166234956, 4154033065505720, 4177720957311203, 4469753728713800,
+      87609686945429,  180324631890692,  215395208547833,  232046781614530,
+     347555587361046,  401054992125161,  478304183148898,  543505569551896,
+     863801510107997,  902013967151432, 1144980155648250, 1201131197423812,
+    1366653669469599, 1472124766677414, 1602605153163997, 1696263251696140,
+    1979334099120071, 2236650958912260, 2393457119892631, 2674212328770441,
+    2759515658445472, 2969315261588663, 3109619182153593, 3441417666663018,
+    3449011035489055, 3494638055157681, 3516063360806305, 3622807715356408,
+    3653227365888247, 3968058910350362, 3969232940075697, 4112962669554638,
+    4269053605731625,  142275274772585,  169336905554470,  230293118364855,
+     243876494919233,  412222533015742,  488775165817502,  755851700330593,
+     757496324397715,  968016064602555, 1503590741170628, 1645844828266763,
+    1856145481752984, 1885639662827697, 2185497661646439, 2290337042441375,
+    2318585474820084, 2362768290558800, 2654758816540318, 2969010714527163,
+    2986686002216286, 3138530782349007, 3486286246606458, 3506435347557835,
+    3522455204211653, 3779370241277765, 3934579918322011, 3979115434204154,
+    3983530571932736, 4038392637139562, 4081469212317000, 4291464323339138,
+    4326370830570228, 4451880011692631, 4455832414692650, 4487082236871274,
+     127577810994635,  176321167306577,  293545333639231,  680851354091503,
+     889709768551120, 1070030909846046, 1245409336874729, 1446306945590087,
+    1525640899021490, 1580481350291950, 1904346537703400, 2040995738119170,
+    2528964737389475, 2576092943183885, 2896033236241600, 3201691629619654,
+    3264756183677377, 3276358937699307, 3280243551535269, 3400903351298464,
+    3866979397933317, 3970963063338490, 4033235761363895, 4172188810905950,
+    4389575888165260,   29198062769104,  240183485057466,  255677730076929,
+     324621274826204,  338207598735541,  364902775050857,  446679103291618,
+     529032130652690,  960591703362694, 1526421961036555, 1535188377136274,
+    1775942084510536, 1815926884335983, 1994022772636864, 2039999545093958,
+    2133868870731496, 2253701446202865, 2279052628656877, 2312722229256840,
+    2534494970695142, 2801844626767767, 3081836470711196, 3412865695576322,
+    3442078895478277, 3442825558734557, 3601139783002283, 4037025090340446,
+    4059996774890892, 4200684834052145,  391859296456801,  412083063733806,
+     606903226523706,  685910023554660,  703860397706623,  735818567527060,
+     782557722466882,  980812536235670, 1618165274247931, 1633781343272366,
+    2091454480919110, 2453809176740141, 2656288532616829, 2826188809228384,
+    3153711664143953, 3259669074920003, 3426203472168380, 3585063939555174,
+    3624751056529762, 3868985590110603, 4263668840025252, 4400770950653055,
+     149985607149049,  216787407294784,  424224214212510,  680290413050430,
+     780349184101868,  854189267889695, 1090179067198560, 1746324140429405,
+    1762144559397664, 1938339403749990, 1939590064704259, 1962397679828654,
+    2006973476228570, 2017239113550767, 2094196516433563, 2153892243773149,
+    2506893999857620, 2532724618718296, 2596153662339282, 2812459060328639,
+    2839981460935781, 2926180786518348, 3211657658040476, 3389927440283938,
+    3410132540979536, 3563510928142590, 3898083856120087, 3977866789956911,
+    4021636976001367, 4129286168523883, 4496084493171433,  200300899056721,
+     230217534342224,  358230002276471,  387181870038728,  394174960904782,
+     533364781295210,  586983062709338,  793680083532355,  800547420586825,
+     883293428609097, 1334278035402217, 1358828519759034, 1659185604017500,
+    1676724330926856, 1994579861576905, 2078396741900947, 2247119058980555,
+    2348488420537095, 2432840737012022, 2499832150326786, 2561779656855048,
+    2729954748363935, 2853885129679967, 2918008766619624, 2993352983888194,
+    3036476716744976, 3163994029046111, 3191126396347012, 3373985210261637,
+    3428024963434902, 3575910463406033, 3652463096207793, 3660616462476040,
+    3680915878530326, 3689053525572125, 3720211979747020, 3729028500881042,
+    3807506738922682, 3826329530142509, 3953826210958931, 4024237538477788,
+    4081825907021689, 4485483852786493,  129392463952376,  158717663342773,
+     282358221421170,  445960982936995,  580040089828517,  624822201075563,
+     730229438925933,  858054258594457,  877472415516002, 1226188717490087,
+    1501449074272668, 1503585906617943, 1595045278350672, 1807091748424274,
+    2039511318426137, 2086713208094522, 2128144176753185, 2289104143322440,
+    2673182810094607, 2705279505598117, 2777654753971321, 2857123303279185,
+    2881883049838091, 2949469828854573, 3124823694591931, 3554388890446345,
+    3563915724125486, 3572796104616953, 3848338425023945, 3987079265008952,
+    4131747235816508, 4207853835567806, 4214871487725185, 4264376244068202,
+    4339025868018101,   91134827083833,  454081941251623,  559111917667241,
+     858885425643825, 1264173258700956, 1329132961997316, 1467551557840139,
+    1840710274489211, 1944248889295575, 2317588377048799, 2327742014882554,
+    2369637683173086, 2423440665497978, 2656987107545832, 2673167103819089,
+    2737623791869113, 2768311651702221, 2874715095528733, 2909524340046176,
+    3037928806189970, 3389198500010202, 3393527181993320, 3545660802912023,
+    3627031378620095, 4422481219084769,   41134928058634,  297434950389968,
+     332017248575568,  415854760384323,  625399908549387,  642135972458242,
+     723423039775809, 1052902938384154, 1660674057124585, 1759680622455660,
+    2105576305733982, 2150761222158758, 2177165592784786, 2366751253167502,
+    2406163283679250, 2667819632422740, 2714927206732437, 2762006289944197,
+    2858681497502703, 3025927189747852, 3031151867638506, 3041384101604501,
+    3253284186381806, 3336538121908679, 3341526028950960, 3418911780754464,
+    3443287388717047, 3975786991611230, 3994714905157928, 4177081510794400,
+    4217079082491336, 4407425160453474,   68212291017897,  170403964607998,
+     211782863329052,  305565733221126,  398333730555651,  755026557500443,
+     766263839507457, 1063728586099491, 1219330261353284, 1344744144665346,
+    1391137476623312, 1573782116077583, 1650035101125178, 1915627151190586,
+    1949273172950877, 2074606495227393, 2712089899309503, 2715534374804921,
+    2984234901900421, 2985203599230279, 3469453036542326, 3489216033994074,
+    3599912791544196, 3654175561242692, 3668195146331002, 3741077339757154,
+    3955936568913295, 4061930330400506, 4137581072584812, 4198869354053911,
+    4220367361124857,  207635947852669,  288981962269416,  514193284493218,
+     601922287299506,  738129384471426,  890919283094333, 1091248052302604,
+    1117689756091446, 1319205326896541, 1754031551352770, 1784330360331290,
+    1864323538821856, 1947402597954495, 2103918935697231, 2400001372813494,
+    2442565108228066, 2481095879607073, 2781458858575457, 2964254795584290,
+    3154473154831538, 3418292862114934, 3429449583086402, 3573073207786819,
+    3811519136228184, 3919430643342051, 4063632932066085, 4230480731391769,
+    4336784947601328,   15295505985871,   89781060509719,  214911015294064,
+     758483830769210, 1526145026228779, 1640765695680983, 1663285452471049,
+    1885595594963542, 1944900306545234, 2066631961061852, 2648131340149410,
+    2728839743443360, 2802239688890643, 3000606502147873, 3127047047437320,
+    3174400234660267, 3178033340873061, 3208567938248301, 3230024682349821,
+    3247318778797580, 3307878081140153, 3408112711037478, 3555987404040426,
+    3559022197196543, 3705161880580293, 3991169275995041,   26828518891368,
+      66636477419275,  100727549778375,  163637858926577,  262479372379948,
+     309143860994142,  564259046631958,  572234589151012,  630961619160740,
+     667429242068447, 1265927982107864, 1669749014847066, 1830094949133196,
+    1983736633339254, 2208538273223755, 2271088022615306, 2462045185505610,
+    2718249910026603, 3006691820637485, 3129976731217496, 3542602495670884,
+    3552530169624449, 3700120934099680, 4180338389827815, 4218520390870007,
+       4441406025387,  295492708226637,  593761100270825,  816614538527427,
+    1121014411337988, 1518939751707537, 2008156464577152, 2576651131133949,
+    2856861079344344, 3376059269308560, 3715971822020112, 3820321941891854,
+    3830680912121086, 3877789468882436, 3880541315962489, 4002485180036155,
+    4034081807648288, 4091451089473570, 4181926044649993, 4228270254025309,
+    4330447190840615, 4501940021581683,   46597436954894,  111787033938056,
+     604941413032895,  607440770038127,  747045185026121,  748814580453174,
+    1390042107784867, 1493397004132120, 1546822725022260, 1578882087362956,
+    1583639118266782, 2268307710339176, 2309617837444520, 2314052624206162,
+    2440611063693969, 2620159997989637, 2848697901510497, 2878833623514619,
+    3158881467202269, 3335209924979403, 3395781016741812, 3680565259848575,
+    3925392211776175, 4045197278601260, 4120266409406990, 4347201934281807,
+    4434048695503701,   32105305572421,   43197184734791,   54606395781080,
+      85407237554283,  170970916917359,  365576905246905,  420694407883511,
+     483762891747846,  538844043673291, 1390164927192422, 1411489040320787,
+    1645707479299293, 1759831028215079, 1980907211431404, 2420341134140203,
+    2450300997298438, 2489200188047320, 2540404585750027, 2717479706783441,
+    2729980421616213, 2985697850117318, 2988671731270949, 3038391644525973,
+    3178206304167709, 3516682512562575, 3533113034007347, 3660497715335327,
+    3869223498821789,   23019605636407,  178300019199669,  282063615434570,
+     292099359069965,  393168090420687,  742600599649809,  811165900512679,
+     996177265999027, 1017606501454263, 1080286916915663, 1124321382454776,
+    1386353027729712, 1391146409344699, 1503946595883894, 1580058107893457,
+    1636673849300888, 1657547017120526, 1869729065522242, 2309216040620303,
+    2394590106814350, 2421897350509485, 2631061307433185, 2673917813440351,
+    2890458999826428, 3127951950164383, 3241253187046608, 3400864247496294,
+    3535600614543673, 3583212186551359, 3640155084359239, 3767438573166960,
+    3772749706803078, 3860870707818913, 3910319464417709, 3950627490836993,
+    3965482159830353, 4485017330711548,   28526260102402,  121891588041951,
+     288543879488688,  320250541685957,  386720273172978,  405153031086564,
+     572856545802011,  710412549093500,  828791096118429,  877779082891968,
+     945769649537833, 1131782133258588, 1496535976016245, 1665120749864433,
+    1785766795304486, 2039331672233868, 2657404779007657, 2727183407661982,
+    3145049569232472, 3149807582587951, 3343109857573966, 3387861512819769,
+    3389538557791440, 3433803899469008, 3926435614284451, 3975823458880761,
+    3990013990556693, 4101563516141619, 4301901127414767, 4423302678175977,
+    4434016313451942,   83597600462247,  218379160285664,  253292544678325,
+     259876935656731,  410479896900060,  457422419332053,  633847157371181,
+     657767890218425,  697988478440592,  903135190555175, 1303712167936171,
+    1335187953994985, 1593255406850066, 1905915733159051, 1961429163811816,
+    2132674416736328, 2294859500516874, 2311204548667835, 2411057987323796,
+    2627953838756606, 2667542710565894, 2669028177465230, 2712261944907148,
+    2731304805297247, 2968373336521106, 3004497101350263, 3205048887083765,
+    3285497923141323, 3780004155710690, 3822464733701697, 3977905286884398,
+    4057089907664545, 4282374913762322,   17367600129488,   79377001111889,
+     351105444201935,  434877131749939,  788019378420789,  833561907247257,
+     842425586060623,  959375648382090,  976709042895969, 1035944982861951,
+    1244581363247990, 1345827918347905, 1365199900041759, 1403719864452583,
+    1489372946916082, 1656210921725236, 1922476933624602, 2048100079138753,
+    2642709293083943, 3207776185823423, 3406564470839790, 3510011717642425,
+    3596465498203119, 3735870475505554, 3829261347188081, 3848791503598068,
+    4073358337537502, 4104234828087986, 4354976138966646, 4406010572533384,
+    4494714167379448,   93317020567236,  186142000097635,  276531481980247,
+     401369648623651,  409696908834479,  624859715551397,  824148068459141,
+     919466510284854,  972719319684066,  982408257885952, 1263752937755562,
+    1540730620027587, 1568089933660331, 1624218379047874, 1641243283742711,
+    1843597868259468, 1862081713659010, 1875793791379931, 1971370617990383,
+    2036671232010334, 2362203639876925, 2516950582192608, 2545183295344869,
+    2586042477141486, 2757988218094587, 2835201058407163, 2929511504836898,
+    3063433419463940, 3322438131195983, 3352478555389764, 3654698367982318,
+    3793751627592979, 3794818570088137, 3829602270862602, 4378538289911475,
+    4400762328753988,  286747027219071,  287820846875800,  422065005645026,
+     665807330947714,  712793081252439,  722675562429966, 1058834167375405,
+    1062186292415441, 1122520613329477, 1481886691222082, 1520403708277947,
+    1931849548296082, 1944045703116424, 2227283567321365, 2374215106737433,
+    2405179035485034, 2493726420518271, 2707247550523026, 3060998917218736,
+    3449072232412001, 3813438006766723, 3911580997094811, 4111142471562089,
+    4131675711109789,   74351799759584,   87295600287859,  163292165454021,
+     427730817278807,  478742654457526,  561889502754222,  568519086508666,
+     746009784591299, 1095730152154750, 1110780329027057, 1328708737147170,
+    1476107923550066, 1476873253387753, 1637444169102420, 1994625274518753,
+    2274702591755993, 2547340785076717, 2771697374362409, 2785669801771375,
+    3000449452343623, 3140779899103184, 3223612362870920, 3271273057461256,
+    3442058523012164, 3484804011982541, 3765526799955802, 3944068721307145,
+    4023227798344314, 4065157852442701, 4068613134906302, 4441238380307795,
+     224463548127343,  389011428133354,  611592727282075,  720749000640293,
+     906690747842032,  937336362254061, 1438042847205511, 1540070451369561,
+    1569048845326449, 1655082136579659, 1759729939302260, 1772935071970233,
+    1781906820465429, 1792172457787626, 1802275008135425, 1825046272548659,
+    2055958761714820, 2601550515336881, 2796872710921464, 3002172685493915,
+    3457713903053649, 3458012568356161, 3918426746114775, 4064792393378715,
+    4106435571594426, 4124608962271620, 4128918977673331,   91188099638905,
+     128514385291334,  587976567398191,  632680082512997,  774796626377712,
+     783393078341462,  807288267445728, 1264871019540396, 1689679184490984,
+    2011515609238989, 2136829282482445, 2227609780122951, 2827104726138510,
+    3245350114047058, 3341909392310510, 3413423030584033, 3458797560177838,
+    3725803358171913, 3803827703549867, 3872113005691632, 3962764802165595,
+    3996739945229641, 4141738019792605, 4196411454282055, 4298400742831638,
+    4473860713347520,   49510548566269,  163923920719690,  714312003311697,
+     802308232967601, 1664006753297371, 1723573779470414, 1930679500181079,
+    1980171484652607, 2692886124063192, 2722842057414815, 2735915541561286,
+    3212614837920028, 3267555499259094, 3331941450790102, 3507120440133226,
+    3528697043336412, 3531348680347859, 3636015149651867, 3641999262670290,
+    3675517565715691, 3852621177847042, 3897536902518896, 4229371737739482,
+    4312127570278284, 4362520462915613, 4435441954407885, 4477028150427722,
+    4495480557374368,  142849363262117,  263833372070802,  446446573072177,
+     553849170229790,  784538642870720,  818807538979013,  948402736526243,
+    1242981040161763, 1491744675862934, 1635737702384879, 1647283474210935,
+    1892362916213356, 2101285190030418, 2142591387329150, 2146307019480796,
+    2179078659269917, 2231392244888861, 2250401701922758, 2281044368979828,
+    2293261155284883, 2415863261966059, 2656507917206838, 2658620188260788,
+    2813893724662479, 2883995579910641, 2888857733141338, 2910675036999509,
+    3030476174017982, 3395085596382648, 3479913419457627, 3664205630333979,
+    3764919696637968, 3885624689177201, 3988313483303720, 4331709809576810,
+    4455953592970149,  246348692953291,  276755571613641,  633104540479883,
+     642228568981294,  665235621791248,  734993618960860,  772967340252616,
+     971143557889164, 1264076255005909, 1455040295057784, 1471232080750877,
+    1839425486745687, 1866432100235004, 2127351610238744, 2569077592653992,
+    2596752273267349, 3173262762860973, 3190310263943829, 3265600446371484,
+    3605612226699989, 3762533334523761, 3806569764966180, 3850348793075513,
+    4128108506863326, 4311432020409820, 4332802308765876, 4415592527112533,
+     248229118368063,  513743554752884,  706626322883068,  752446885527335,
+     768946178587817,  856570059067234, 1372294299986442, 1375898915101299,
+    1501605569005955, 2165674114818245, 2207550134075717, 2438068660129025,
+    2684746550970877, 2862655773457688, 2870909349794541, 2956451415221251,
+    3001102860398448, 3253089919973112, 3332831590840510, 3474942239995307,
+    3496372457902196, 3544112731076425, 3594766955853452, 3774519257641291,
+    4018071969774950, 4400027558524984, 4497726480705916,   18278462321119,
+     235185120722112,  307305914117189,  669578083999368, 1098313108206997,
+    1396920446071470, 1550017852061766, 1592106080876286, 1617135019188114,
+    1871591962218420, 2122055239279281, 2477333335843753, 2515369934041301,
+    2608489649067600, 2735046123652101, 2956881741996195, 3139054804657026,
+    3244438463667724, 3296874855743293, 3325270655869952, 3373934433598001,
+    3380265352049933, 3764232019333658, 3843084571455091, 4066274008177019,
+    4109103005537901, 4138731782489075,  121678564395340,  507747678216485,
+     602189773167722,  623365536096484,  625310790369424,  741332435878806,
+     754484516157517,  793499636201453,  835357971329171,  946769953682677,
+    1455229947079603, 1579649589318829, 1586637767926618, 1759188699097967,
+    2120038278986602, 2172306671829508, 2204672559085940, 2302814566962375,
+    2348822777872365, 2436399500672438, 2491049356322216, 2678721200884888,
+    2746162577056726, 3025200460445092, 3088305295020588, 3217627371008284,
+    3308940357444716, 3337332227764763, 3359011005939861, 3570125129394766,
+    3691057068265842, 3763429369284087, 3775778786562297, 3784608079567808,
+    3835317321637403, 4213538611654384, 4228068089150601,  726493499062824,
+     759579523380905,  767782994683455,  954775965153904,  966356122787815,
+    1125518202832080, 1281595384685925, 1503032127658901, 1534607141334668,
+    1821052710186654, 2049866682622007, 2453924486209582, 2483219229598736,
+    2658953304125805, 2982625931013267, 3173439655962233, 3477342408236376,
+    3498703854527555, 3604692703756501, 3649621200299844, 4069317669299873,
+    4076869775156484, 4350081720242560, 4355090258769554,   69753073081191,
+     363071873697565,  395449550373833,  398216134228681,  788258282534558,
+     951048556533352, 1707597241345838, 1725861017575108, 1845396892647271,
+    1959201144775832, 2288000204388648, 2423178674679877, 2493288389541263,
+    2740727680414190, 2906306152204198, 3061117936328979, 3182593170964164,
+    3421354466386142, 3432293083090644, 3674271907676197, 3680656860969044,
+    3685661469689426, 3718303425860351, 4331930938904315, 4423108347012633,
+    4430991539076305,  187691494716506,  219849102222502,  330778700814385,
+     471009919956993,  707401541991935, 1040208021897338, 1131554411689972,
+    1389996178624846, 1472136996428870, 1655972367286577, 2114945288470240,
+    2214118870582325, 2273135723829688, 2288725266659492, 2322237674994975,
+    2447216332224758, 2533275167220946, 2710574287231244, 2862366997426356,
+    2873580700593698, 3176162055394556, 3193692922690688, 3333910387510154,
+    3417099481227929, 3526874699127537, 3600469170002114, 4176043383170429,
+      25243126843886,  100250363195477,  136961634113128,  232563022014905,
+     339642392578681,  756015227636611,  767911734702788,  844346473306188,
+     897881246329811, 1251890015358607, 1463451154315627, 2246175298518992,
+    2310429601528498, 2329916530065753, 2453712315505324, 2567741549062422,
+    2580918190632458, 2903867733103312, 3369417185652157, 3574145373362562,
+    3665652885226288, 4236753013566841, 4403166569261899,  137708310320338,
+     145420555796388,  381068461930009,  445796306636261,  514377326725579,
+     517526084273444,  545546587868657,  666041335754148,  691865077453253,
+     734493654676923,  951898416065987, 1046632299158165, 1049505970243190,
+    1439680749522222, 1777821941903415, 1818906105128569, 2113069814166523,
+    2255468321655649, 2379798560794452, 2571868841407605, 2632170741417092,
+    3289828790193557, 3439610421854978, 3495729042725991, 3891975373220563,
+    4131542278998001, 4312571767934740, 4351975938833264, 4427998047742404,
+      95608305036912,  131266387782547,  198329520067980,  359058610498780,
+     518495777005885,  610604549281247,  834761700881380, 1031022137794578,
+    1390019796317308, 1553893714489361, 1739212587343098, 2548171138036522,
+    2624075352747302, 2655376262411882, 2667882871954572, 2689587193872648,
+    2823523845274485, 2829133644213115, 2900083355237816, 2960869603912232,
+    3451911711953121, 3860669564248648, 3917051482162665, 3917833513678453,
+    4177156539746068,  118466038146540,  199108617179739,  367175639006796,
+     381839712379474,  396940976737737,  503619507027089,  580479647196238,
+     706708965380290,  866530270483718,  909223689516486, 1412923704654627,
+    2174035877395298, 2687466093704751, 2769516525956699, 2890484815538936,
+    3108351329204910, 3132944058982806, 3138828944384276, 4238331852226002,
+    4243497583017476, 4272698993499595, 4424303072977331,  115243609675630,
+     119797273087285,  170144972948576,  440033284092553,  955436263321230,
+    1558418912704939, 1584461741122663, 1638311881126899, 1758121860210249,
+    1999972966080912, 2132432992648290, 2149351792564603, 2251172099429870,
+    2712676798523314, 2894154285413821, 3551805457028715, 3662542495096610,
+    3693940667474837, 3823814881291519, 3848937152510382, 3885108075018883,
+    3953896392406984, 3975672433295729, 3986111964560507, 4431140949433141,
+      30616219688793,  183113347719106,  530083833105810,  901440733423280,
+     905821485344007, 1364027111786677, 1415324842396419, 1530376808124290,
+    1589924185264273, 1698971406489008, 2133274966665841, 2469601570843943,
+    2497705582829661, 2889220426163385, 3004728249458248, 3038780023751228,
+    3040204578648078, 3164455239202988, 3237640027738264, 3598104486578923,
+    3619934562308583, 3791080587616142, 3936595431748584, 4057309266352694,
+     427263403408719,  835460275810383,  841423757344093, 1676758187565608,
+    1993263829644435, 2120473634578181, 2514663693763024, 2662159160427914,
+    3051253242888646, 3107637125705969, 3117852657993863, 3243726328679529,
+    3548662620092628, 3871301707841134, 4385777252709379,  100512768443338,
+     131758660815350,  521239829227364,  530008210230389,  695070894902572,
+     906069089062423,  960869259815110,  971626122963807,  990957824139888,
+    1493670475366764, 1684370235923982, 1815724021930082, 1848723590502699,
+    1902595344443301, 2181168528199798, 2504983610576945, 2531064754609136,
+    2786466816129628, 2834390807762968, 2916951132422823, 2937551178682927,
+    3071794355000500, 3109620708544306, 3295070247467892, 3525383441125723,
+    3785059167336765, 4001697616436489, 4080584554365777, 4107989060774559,
+    4297530510832890, 4381507530776371,  207922255713452,  330182469219384,
+     733566310973001,  925869432627915, 1123176180551558, 1395090307004021,
+    1530323794587818, 1815026494207382, 1855369888886174, 1876753931565372,
+    1998156464778235, 2120367555701517, 2247286554945975, 2350355557862205,
+    2364611913798888, 2672404192167258, 2681001626582661, 2888471836856589,
+    3067316353317056, 3215420940006806, 3372527731192995, 3495238890007654,
+    3520490844844713, 3868873113256778, 3939892578348842, 4133177169205103,
+    4197855891328705,   20174775324429,  332582611558593,  672534462336265,
+     845414514811369,  882816449241079,  953322092118624, 1081093859397886,
+    1698602056471200, 2018047193895803, 2613969945701054, 2869036991207873,
+    3294768660712281, 3602014695961583, 3788805281391514, 3851674327570290,
+    4178160695991944, 4232115958323051, 4410717809225227,   25340519066833,
+     387477110620898,  577460663923079,  770881815559107,  798515233203038,
+    1327924043196883, 1640260160460378, 1642161204408933, 1734760220059142,
+    1910085594698563, 2287273345576588, 2447685104123469, 2825653904065629,
+    2844329327537506, 2862961523136651, 3007830896533072, 3222565354329282,
+    3329595602310408, 3499463458017414, 3797403711209500, 3984746434468417,
+    4078296463318730, 4313903106482925, 4367684474871451, 4375534263578921,
+    4478295759127762,  106110829765852,  213595952862317,  349265648980046,
+     899288259557137,  939481339133020, 1225518208097358, 1607724322018907,
+    1970233262749459, 2116162701479467, 2289489769456686, 2330889299662453,
+    2400279859913843, 2458177700728439, 2607221511198457, 2675030324288517,
+    2970133257106739, 3054627046619698, 3171923914374674, 3701193216233793,
+    3800159501047095, 3813349896940273, 3888855236279935, 3910363067867411,
+    4320461966669283,  272766081518478,  341779380335116,  384204590066615,
+     385275462368385,  444061439477293,  672973657077946,  718762781269317,
+     862908087797477, 1209421733165536, 1221202310936659, 1351294629020307,
+    1561461669081720, 1962851133979747, 2040092465390260, 2060621775131348,
+    2439926709321588, 2445545350325095, 2734518767280250, 3014855451753882,
+    3024553232020645, 3247989264107476, 3407557096684430, 3472570834821705,
+    3684880601938311,    5787805121617,   17041788806732,   92470496917460,
+     428519067277763,  709080733179932,  732096628054763,  800320035742389,
+     988579386393555, 1473650153287510, 1689876955144627, 1693773358400425,
+    1762753253860861, 1891215684652182, 2190935157787851, 2389508285892211,
+    3033800079929611, 3289056738605459, 3592165669943978, 3650375930384228,
+    3670264681647560, 3693713837701364, 3706863953076769, 4063655025186861,
+    4337708931339558,   71322255585912,  419495261795888,  593646606709974,
+     666431537422479,  671107024839106,  980280630231594, 1027190731759038,
+    1209331373515320, 1276781591752035, 1747721756520891, 1762185409756357,
+    1806800504222393, 1862558565336755, 2025410733789688, 2360684149795774,
+    2621676361221836, 2666172579037859, 2814114078753211, 2857006935471538,
+    3046029651057085, 3354935047148304, 3570424027814018, 3646332172331410,
+    3691901227352509, 3779303073758348, 4005576426219043, 4248725350723319,
+    4409023144878452, 4430420941880792,   48300478952093,  138076911003233,
+     328660742265397,  723223150626727,  778789633668754, 2098158344290309,
+    2149666319553793, 2645009600931516, 2726881226982618, 2789680519094031,
+    2844444339135557, 3113655741090617, 3195234596549125, 3369380046753293,
+    3433544946662376, 3499911520725832, 3758423041728069, 3808986880953020,
+    3813206510802655, 3860989029397963, 4429746010546111,   90050757348911,
+      94658455601481,  460662959313407,  544621312675481,  551168370491073,
+     583657064204130,  740963293075878,  799238395325226,  847040562953594,
+     889361632809875,  971909185598241,  976019763314393, 1002042942699057,
+    1083314290790176, 1108959226288435, 1667373938885452, 2246371996064472,
+    2593587111912773, 2661086452732138, 2763311529678441, 2785199570055628,
+    2876012488600683, 2911513379081838, 3327338988569047, 3528650209430318,
+    3627184215516300, 4034311198067847,   36347015591902,  220951646093908,
+     285710929253056,  414437639539034,  504898840392315,  573479860481633,
+     587115306973620,  637682093553530,  688129021031774,  856727549203104,
+     897547432933601, 1644876791434335, 1664578876883597, 2034820694574912,
+    2051182444403974, 2885211196378652, 3024968873824514, 3411534125730424,
+    3483747269580883, 3635409313706146, 3674957904997661, 3839355469900763,
+    3998907583251269, 4197783306464753, 4295853595370519, 4409666689563957,
+     291308951722780,  428019064141036, 1847523177042963, 1946445251531880,
+    1985574335967564, 2102129452724525, 2251120210805281, 2301264543174401,
+    2320243544207055, 2369831808940577, 2453402093899716, 2545650374309804,
+    2781939821372834, 2793051349568264, 3204765398887668, 3220850097350584,
+    3272823754697590, 3401498395045959, 3502782283308688, 3555114535509039,
+    4155414109428405, 4214561628745617, 4360731768130610, 4418556907522884,
+     272568699392735,  287989260538223,  298821772464201,  322502787108113,
+     374611040331580,  586156460062152,  784813096557017,  890319562024340,
+    1055336071468832, 1314582466307513, 1413438716535679, 1693049369237744,
+    1758870682633785, 1792602177687887, 1895333217235485, 1991179235598859,
+    2039068841424344, 2048812796918798, 2082374327836931, 2083663304405667,
+    2199353863707988, 2238293334974643, 2507928173592146, 2689107977631794,
+    2762990306840700, 3009612197938331, 3204906884876630, 3282320145326418,
+    4004663793388056, 4126477973843526,  241165627707173,  336144141260948,
+     393067390035768,  792127462064532,  984442373139282, 1589966624949302,
+    1737516126455107, 2095396737448376, 2468908154240875, 2591014122837286,
+    2755681861944963, 3463000876877272, 3643637385152811, 3888304197460972,
+    3889745454035923, 4110152587676555, 4254400069176627, 4377698734079780,
+    4392048422923498,  258746612988538,  363773642049197,  420681171597569,
+     545663758633964,  863050659845532, 1617544055799942, 1732753213792293,
+    1791054842236272, 1852157440342954, 1980968641471090, 2027418955624930,
+    2334459658478755, 2336815577542649, 2584232272027557, 2831739352064541,
+    3042943861071522, 3251131261052140, 3490155853517122, 3577883873871757,
+     382489358989777,  569636574369747,  737570965223649,  744318443176453,
+     744852896875685, 1054001940976848, 1120467742657257, 1357109889863714,
+    1531193445613743, 1627740934457359, 1722691939364850, 2174356402701846,
+    2531850910195486, 2697707415803293, 3089662397477561, 3163797216761288,
+    3356274232358783, 4017276546562060, 4056704296300256, 4085340797081900,
+    4090529124261393, 4104387587278611, 4158895969890357, 4173511903132038,
+    4272953694545392, 4429301050603812,  131495570987226,  293654128573141,
+     308279886331352,  346525746730989,  632226617230001,  667297193887142,
+     890848172817374,  933904116510099,  961084623941997, 1062136653614618,
+    1836964833882822, 1877097983907872, 1972776985490236, 2288214702622252,
+    2346223560473637, 2642454347789503, 2670648745327297, 2991678577268189,
+    3198646754747434, 3280225610205942, 3336142828488090, 3517799121579443,
+    3659609140528422, 3718667256745211, 4101421578689134, 4458506403843473,
+     135811494049785,  174973981841671,  184733656562573,  311776444715309,
+     329501837438735,  355552525469683,  384553515814590, 1314617899321671,
+    1597182784144307, 1614246986905264, 1672075073652497, 2275059687939512,
+    2927858530840239, 2944364701062292, 3276939322377587, 3378872611182949,
+    3487113239600571, 3704446282018966, 3740094540248071, 3783169150522203,
+    3877988506908192, 4475234621090034,   17348469133628,  243335928163300,
+     245270375468057,  726254143485532,  740486920582543, 1429868333234378,
+    1875523139809973, 2081701426160206, 2204964722803851, 2516934385600777,
+    2536848680607087, 2759645136667815, 2767756257514983, 2842444197079349,
+    2856577746559407, 3015565932661091, 3443097453593795, 3713224500489451,
+    3828951410502933, 3965037666121534,  108240979213506,  141503844829127,
+     224486623699772,  310587704117039,  328234500708225,  369228278381303,
+     569017681632077, 1120604355240744, 1292737744459568, 1486858401672532,
+    1701359035975328, 1852413924979037, 2091303921567558, 2413471432522624,
+    2678620396892529, 3074102380001067, 3890968684049488, 4049912659826787,
+    4463512033868298,  368059173865163,  412564233746063,  450729533110154,
+     621772400994148,  912707773900344,  963341367192658,  964921149450682,
+    1057530972069074, 1549805074482825, 1699709512600871, 1869575403404571,
+    1924062172079951, 2617842020782267, 2645156141638973, 2722347368015183,
+    2765632222943057, 2774009588188188, 2805662215544542, 3577958617902965,
+    4237547184177575, 4268643743898331, 4280861512655039, 4481842629760902,
+      33271101926589,  281582809867380,  761970229731484, 1244652656656996,
+    1453562158602569, 1720996305517352, 1885646360495275, 2382811107237660,
+    2664711854742868, 2681647356337282, 3386510242137091, 3455456734241325,
+    3604134090244774, 3903131461415488, 4046502596041084, 4096654788023428,
+    4124534801032262, 4175338358460545, 4278592062287539, 4356239146230741,
+    4400380699000031,  167052538918182,  173668368349484,  820461484295422,
+     903748823178497,  908609011505888,  966384045863859, 1236241901006593,
+    1272964961344080, 1505311829923621, 2383625572608927, 2703050078548817,
+    3376419494160058, 3811783119670478, 4259843950344270,  156441091565059,
+     360005074066659,  575939105331182, 1507131343335907, 1714178116947392,
+    1809343296315237, 2018074974511617, 2112686051147170, 2253640354706386,
+    2576059373284620, 2771001360079492, 2893621150890422, 3253728979781042,
+    3411215979756942, 3473173310801734, 3475712948324739, 3751469443192085,
+    3808839707469020, 3883820417625980, 3995075207715006, 4167543612947503,
+    4328227510602265, 4378138019477971, 4458322776040872,   42180012368783,
+     108759778440940,  843376212551854,  972966497840819, 1234420461543791,
+    1517099310758175, 1537552971721982, 1877366296816581, 1988489438384105,
+    2561829556493498, 2723715974971532, 3094664175401354, 3625207717054414,
+    3750169672606096, 3823318110430211, 3855496349420920, 3934845039627118,
+    3951318788944622, 4236284785607190,   89462476023644,  107426604498749,
+     800876349445657,  842259177973323, 1035611557993641, 1416630870318366,
+    1743879621222748, 1755651356928994, 2393654477935541, 2535706179991158,
+    2607709079187875, 3374440875383706, 3382280839574646, 3511135268575514,
+    3760833215798688, 3852951812590580, 3943526977835609, 4110965230604746,
+    4111428947784962, 4200026437852502, 4292037947414217,  285102998894729,
+     312368979620438,  328517537440799,  781387468956026, 1250543501523034,
+    1365906904424906, 1905084231204530, 2220881525641544, 2655023963747285,
+    2916501506289929, 2968148989688139, 3218713459269259, 3255553431353453,
+    3798506319835556, 4071610195239802, 4088550609092481,   10184548238322,
+     247110623972496,  273690853444411,  445040246244141,  527178116693206,
+     596189450606538,  661948869548440,  975482595376942, 1175793680455459,
+    1333602924573543, 1433230399349314, 1534444533544680, 2981859115456684,
+    3606799559284943, 3626481995701145, 4053263906022610,  501437896335536,
+     563653612165067,  651425842844087,  901989329973554,  950718931962354,
+    1567776183726941, 1660666987518091, 1696116790513290, 2003168300335298,
+    2243637096730190, 2375572494114866, 2769768448009627, 3262443390697802,
+    3439991070976309, 3532287491517394, 3550236883217704, 3660954272252539,
+    3764797447071333, 3916690367335051, 4107394057698881,   46939072930565,
+      72363939258552,  319657827286835,  485631244641349,  533719305700740,
+     993457556722090, 1058342593692822, 1451369430120513, 2530096432856248,
+    3379035853568507, 3520266226042216, 3704106509158188, 3771559674749862,
+    3821538955241278, 3982626640525423, 4306763967044754,  335968502580871,
+     836415620295929, 1236268530808664, 1278932476291842, 1443555022623481,
+    1738262027425544, 1892325183390739, 1940934926277873, 1985383986414552,
+    2391739744418494, 2496150776292722, 2636073505616288, 2657754248694692,
+    3101700571845720, 4415028187252978,    2809335482971,   52953667852091,
+     348391616683986,  607811905465248,  662314393367076,  962988809509461,
+    1266419984990164, 1460813763762662, 1595580586811284, 1654737930645026,
+    2376231758026819, 2764287389090330, 2913407830789282, 3314695120715397,
+    3695785169559138, 3939131566845667, 4313660803550674, 4420797156310324,
+    4486052577554237,   25482367681835,   47383179930511,   53085329324523,
+     359219229302629,  416515809705589,  473050990077474,  679713625092636,
+    1080303374345121, 1508518681628313, 1528331784114364, 1971502952911175,
+    2227587818330502, 2655977019556275, 2751623600234090, 2923359096533449,
+    2958635005586067, 3565807811269821, 3885926910528382, 4125840621739329,
+    4254952453073283, 4265332054787228, 4364366128764587,  143823594494880,
+     285817331902970,  496471667984271, 2152029439552508, 2204356779494594,
+    2345122452336434, 2490658910405242, 2545916903628227, 2590953469853400,
+    2785440581532933, 2890542276919220, 2988540846854317, 3199272796616205,
+    3347116051166257, 3462493208391271, 3494866955260927, 3519547123235940,
+    3690558552667038, 4091875316142743, 4284010438564994,  168488056194375,
+     462266644184353,  468434475661887,  588442909979143,  672648841157773,
+     674862304731982,  721081742747367, 1081850761600456, 1539398145435616,
+    1829684117799179, 2162183090337193, 2481542754467985, 2794469325174933,
+    3101705535907705, 3375809547094705, 3539227607699766, 3829686491554257,
+    3999260594217016, 4006124001464874, 4149799696102900, 4263407457900861,
+      24072588412570,  103005701569549,  253348313124833,  786171142612853,
+     960096523646749, 1225055874847625, 1459391225572838, 1573543265586561,
+    1718180780392874, 1879153518833618, 1965090529824834, 1999892897180706,
+    2519365191156109, 2998403704900644, 3017310004510976, 3391542540816777,
+    3637180997809755, 4288127919344577, 4316282036364598, 4408527369419727,
+      55734070784731,  442233498429890, 1573191560845717, 1587934230350635,
+    1917726548584634, 1945670420950913, 1964943175027814, 2002958159288996,
+    2012304221863985, 2031305819284658, 2215956625014355, 2573577868771232,
+    2607674835840250, 2937037822701888, 3046112552572907, 3138212482783392,
+    3427099443993083, 3864426007906197, 4477756820550955,  576066022907439,
+     718820177894951,  848584357126497,  999473211800849, 1519400380891591,
+    1619036697732239, 1790339915304278, 1940464422592596, 2312662301525034,
+    2320030688922534, 2388720761145335, 2463269192574975, 3214305341162478,
+    3434640755832441, 3465019143394854, 3478426661103345, 3769497612337655,
+    3971641952200670, 4049664332675318, 4294153321234286,   90631761852804,
+     106853021095487,  175266041952142,  665213698851589,  836891230503421,
+     906001792033706, 1047197778699560, 1358276357847215, 1589616213482431,
+    1946975142647957, 2238363425766186, 2268782093846372, 2513653256098357,
+    2916742362356074, 3597467393493326, 3614205422305487, 3672456945715163,
+    3713507705584115, 4076808502346985,  112359675561482,  182022374920753,
+     425377614272159,  748706383081071, 1032081790517432, 1314922744254561,
+    1887199849675408, 2116036418498780, 2141900440715658, 2183170287303229,
+    2315296280308587, 2378661464572128, 2589907236548535, 2783323475926298,
+    2906135827261216, 2936652740506702, 4251141613767806, 4369742194866313,
+     294101454897576,  479254293421956, 1079670779088029, 1392990330456177,
+    2070752287407981, 2174809636687129, 2302405545120504, 2715846744445882,
+    2825794873836418, 2853364432122904, 2952528189718459, 3071248629918632,
+    3509280594118600, 3786519608530323, 4021838393360189, 4085017894261313,
+     112023702998016,  143657663772963,  192426563827883,  351566047484129,
+     622474143443920,  933758054987052, 1050381943359723, 1277450099207695,
+    1428172919552690, 2195952009210619, 2377650547723051, 2500929563593144,
+    2504025268751747, 2675552485300670, 2769627143333685, 2819946352097039,
+    3561358404291754, 3614389179617388, 3845161178197170,   65355297528140,
+     322989489204248,  457347612365222,  520855252118448,  849325190427509,
+    1193385654018027, 1620992823728012, 1634035851873240, 1747025651581464,
+    2198918043701956, 2390420467259675, 2413521835428317, 2462107999475779,
+    2882488254826296, 3066875763512989, 3266810569608407, 3466600955310834,
+    3541103211512783, 3557672258640628, 3773083625625755, 3996276009702642,
+    4086469001254654, 4304596847060326,  113066110103709,  181375973536799,
+     574271161442988,  628752035408450,  654452970650930,  701710860063536,
+    1381812034401133, 1493145420622399, 2032493693989645, 2061876858027569,
+    2303348737560174, 3007433521650807, 3079999365644693, 3179488314737391,
+    3494380771201992, 3689491739681180, 3962493440113514, 3970948419039232,
+    4128284121460570,  147249545869121,  151399421651393,  244225383633445,
+     312799526561192,  661995264941941,  672671567055092, 1897197342450986,
+    2042648327225983, 2267461756530320, 2536111196139864, 2547145110654707,
+    2735846564549723, 2772395730944629, 2868439222090256, 2875308524048032,
+    2878117353323959, 3307548935753565, 3341385553134538, 3777960541533107,
+    3861971947284443, 4018198461789544, 4181398418127639, 4200896153633077,
+    4290660796264381, 4306267040772286, 4400544083845819,   24705416786402,
+     792278191597201,  820649430432535,  897936937070739, 1464457858256659,
+    1528704301652941, 1811647430361982, 1957685921225473, 2281780019872072,
+    2629438221415876, 2867306468285277, 3154614629688597, 3295742827190394,
+    3388349702453827, 3689707904946700, 3722721227842459, 3739797220023252,
+     355256200647447, 1310056751873762, 1869639599486196, 2017323696868462,
+    2203883406159938, 3022931735329672, 3105648269802354, 3113219042240372,
+    3275748966551121, 3545242332130592, 3727998971073305, 3821392790110791,
+    3872343715287024, 3904572059312036, 3923735761255454, 4154507759835236,
+     449074451749959,  577794284874366,  874518262920038, 1302429010190800,
+    1783872565581879, 1826511949773732, 1868918492923824, 2261171157448951,
+    2324284834089324, 2373067488467386, 3186207353395978, 3245559222657014,
+    3385788476896316, 3422777781631766, 3439449003731523, 3568472414416707,
+    3959009718355696, 4074696347851405, 4342542142008795,  149967058945039,
+     212311475941113,  282922241145529,  283414449423682,  285939350171892,
+     366321579517046,  510491447336531,  708475104449091,  713712554211234,
+     859383608156503,  995882493469364, 1428949147015070, 1617319514703025,
+    1756545685804614, 1875894895062707, 2076043875618479, 2686793787770806,
+    2804261602113404, 3011001850809153, 3177044039779377, 3461661266105130,
+    3589865312111712, 3678839081162351,  372675107307927,  617080587476390,
+    1260509788962456, 1388098820234260, 1869294797808783, 2039314933522004,
+    2121295611706589, 2139430686769316, 2343740350075543, 2489075405555486,
+    3103990912716533, 3512775291206691, 3558607643270794, 3707645559030894,
+    3721976581293205, 3740044849643528, 3963051550357998, 3994641300808560,
+      11512138292915,  395143789562967,  532847160602406,  681936163848462,
+    1783276256281298, 1836520223615633, 1969965649190970, 2143353629170675,
+    2427351910955038, 3010291564165894, 3592774377358105, 3729450103968506,
+    3865418465388747, 4010412610145099, 4384786619488932, 4493505701861565,
+     171491631063406,  371214227601776,  469440726320369,  523282024259728,
+     645661114415673,  748630889714950, 1051059964509593, 1056586255057718,
+    2262852184030954, 2338658153576434, 2516364991022727, 2602311826530473,
+    2625073266427177, 2655078322361450, 3139154848182569, 3230185870994590,
+    3727310337219202, 3800866492479312, 4290782710925863, 4322206427047068,
+    4385605997118464,  119690898158258,  394316591173001,  456252308281427,
+     475563377972795,  724313259350824, 1331208031216779, 1792972097546615,
+    1959733441126835, 2231420621247455, 2294634507956434, 3008692158776670,
+    3111840740276793, 3544104730821998, 3681534980301903, 3705635501801646,
+    3743750696131434, 3862371908714654, 3985372890766948, 4082833077196201,
+      67737885246895,  448872144415021,  703925435598698,  804536344479122,
+    1411574554286415, 1830322957441299, 2206498783116734, 2330168814744721,
+    2397029561989636, 3305532079068326, 3650845168516419, 3950657974559123,
+      39056204640143,   63043744199791,  254442027882292,  324409287253993,
+     433963453531676,  541569418181460,  640574018609229, 1113937891606036,
+    1355122895255965, 1923587105811519, 2154997697965751, 2358433961752461,
+    2364018216948113, 2627667960095540, 2811381506948275, 3665357957285465,
+    3835009673628811, 4034141816723728, 4042368866865950, 4160007627796170,
+    4357897952001695, 4397899453505243, 4457207112441894,  583826641631226,
+     747724138642951,  873994719796429,  897158964870863, 1224390031645491,
+    1879539881351847, 2340967949216357, 2655831914583021, 3188017132948244,
+    3628646699318744, 3648573766196543, 3714116063323132, 3860206624124232,
+      69332443132504,  978126748352135, 1776981766246107, 1983808470687320,
+    2024018251941304, 2150401815034877, 2628363561767724, 2647944805663667,
+    2674453316164913, 2726481990804487, 3529826812752669, 3540718271777827,
+    4374246955861128,   40217501346779,  239496029737993,  587317318256195,
+     933554894709761, 1069272730958487, 1676158660759565, 1927536600309369,
+    1939561808542089, 2152670308852584, 2245862725301205, 2303012920407868,
+    2619372177190398, 2722264338809088, 3397394307782505, 3846138924806785,
+    4114805066094430,  320365568053965,  385909830083860,  405159987772742,
+     539685127714726, 1449631848723326, 1624011122420908, 2636203551860524,
+    2643782183911766, 2784314033260192, 3076993257850463, 3317734193353236,
+    3407151048099378, 3428931018794735, 3496536464392624, 3508245323193078,
+    3587710925145983, 3798652137109960, 3880335132443686, 4053221079628708,
+    4435270001285777, 4480063901952659,    6684487428443,   30955955515808,
+     199027889601130,  313117052709061,  594762362784489,  648040715926679,
+     721546766152486,  881594035136104, 1150852594770508, 1819662452260176,
+    2457677362686559, 2542963008231836, 2650242798932824, 2668316961993065,
+    2809061020898539, 2891569275620785, 3014179241915185, 3800368493098681,
+    3838744037116514, 4073164861135538, 4242747805863174,  117474590836530,
+     566054155983106, 1035562888693541, 1090934846308274, 1836652984098088,
+    1970817564283421, 2034447028041619, 2330661113679384, 2534944250790980,
+    2834343444687771, 3378354433600155, 3565591052080548, 3718563686710913,
+    3729955213627448, 3734111966571291, 4298008759392048,  115686541779000,
+     476480121923414,  486316427873250,  511673505037180, 1072033471907137,
+    1348471788221665, 1503827850562208, 1576600009403224, 1635431179288170,
+    1694789925710777, 2470382453365015, 2637168358236560, 2912230259785235,
+    3731944691175703, 4158909337504626,  440816157418791,  798119086840096,
+     922971007806642, 1096687126638449, 1293215772852916, 1461608968628769,
+    2193141487000916, 2494672600984717, 2959834967582280, 3350998093224230,
+    3865732022877214, 4476978073114306, 1220939765047595, 1292900418823233,
+    1590130359470200, 2023000522685306, 2083917437429571, 2235057799727091,
+    2271006688162014, 2357216820712764, 2573697095096355, 2868082838207887,
+    2963579103782793, 3171523838205120, 3534390391337364, 3594214819843493,
+    3651669574962586, 3710759129632271, 3897231400726630, 4078710852520443,
+    4144183395579669, 4164641968801741, 4365920768758463, 4417471971894679,
+    4434821085635006,  113291363501776,  167580659394903,  492509841946552,
+     495333407997274,  790538515787408,  921100445761190, 1097843498135890,
+    1422848329464820, 2162193303381623, 2812012370418801, 3103521495090349,
+    3305990043998854, 3966912779618238, 4124137465002787,   65641488830553,
+     293598763424490,  800881779741122, 1283754802287316, 1508522056363962,
+    1950248038779210, 2035061125079394, 2769797418291974, 2771339867387184,
+    3022913314815935, 3473197433580466, 3522830891090026, 3595689505676506,
+    3731929023752975, 3829217281142953, 3942209045754483, 4087469434908798,
+    4344100543447193,  276975694406660,  380758939674621,  500500147142261,
+     763058386503205,  952974150641329,  962120775530759, 1478126980074378,
+    2005037415514644, 2109398342354569, 2963898439422808, 3167769929291797,
+    3687079136292802, 4209761099818556,  175832309681240,  511876950234931,
+     634122426966068,  674834241014735, 1032105731983144, 1828068412210684,
+    1938554925107064, 1945744506303718, 2659650859569392, 3625383150338638,
+    3690233801501515, 3936715202012767, 3990785411187275, 4104795978162966,
+    4329534741141675, 4492367260561548,  135543945245946,  182394117222557,
+     888645207208055,  890334041599562, 1104748220156894, 1516254972177515,
+    1742812253165927, 1817142580328601, 2632252260821458, 2788105443698419,
+    3046631701475451, 3192963945383189, 3353978929245012, 3787830561661465,
+    4061884467814162, 4214308894422153, 4373875744547454,  117906986122220,
+     184684224976630,  453022227412173,  975557805641630, 1098607910276574,
+    2447692836046420, 2633768196672967, 2650717452590523, 2873762468919460,
+    3246620555362714, 3450784815824297, 3474050253418990, 4238178552734371,
+     119917095155188,  251441827748910,  370293916470585,  673769302275673,
+     718170222281355,  718785237016133, 1016389491742893, 1340788150401922,
+    1639926012159015, 1958211856633078, 2226775822948811, 2763283828587234,
+    2802998454208106, 3521823942900351, 3929711343031320, 4026912162224181,
+    4079999919745689,   83647940432317,  428182928171234,  428646645351450,
+     944046677225168, 1387105846533537, 1502183356004386, 2116840478380694,
+    2155210127688609, 2342180501771039, 2457875973331625, 2952159189375761,
+    3495387164320704, 3931914012588276, 4216937973898371,    3944598130316,
+     981853394686844, 1028412760974167, 1362847163268591, 1368531628532849,
+    1950637180290308, 3122079127971306, 3128139872218663, 3229761723850024,
+    3458816396940362, 3640378374673027, 1535545475138820, 1727621650461891,
+    2033622919466842, 2347564362731339, 2583925528765038, 2691234793015616,
+    2863223761841449, 3268645208323388, 3527698060186428, 3934272904908989,
+     119291325255947,  398734961177002,  712524124435284, 1735165909656208,
+    2000674451331111, 2251360744917203, 2260367861671907, 2636530915475853,
+    3109503772714766, 3197435160561572, 3366599580885030, 3461358025268533,
+    4271608499885652, 4404394700401826, 4498867251354306,  109366611608271,
+     332961800862888,  447839872648178,  660748935273114, 1100082647913307,
+    1611057717541913, 1745583839935550, 1850728763194569, 2095298312789083,
+    2212262129433692, 2241017506865349, 2920949699518630, 3586460484357524,
+    3709857394425977, 4012400433612368, 4090171306463848, 4311188542580993,
+    4424155745901198,  138037511148700,  561870101607859,  735795482641755,
+    1702576049324752, 1926967024418299, 1981648318520973, 2324351033927045,
+    2339782402040716, 2754799453817506, 3352215532135317, 3565163892826373,
+    3763537583245174,  832733017742542,  890090510147988,  936551631270011,
+    1682169558991219, 2318667564065370, 2616046837363593, 2964699279980233,
+    3116894795353075, 3123625571627778, 3151093937394005, 3372295874421914,
+    3398570561429746, 3472287838760948,  325440189860310,  623948300107830,
+    1103580214650777, 2026050246880168, 2098993352308806, 2700234114911746,
+    3383411345374886, 3805648434349573, 4333157182846516,   15582811068264,
+     332442135742171,  453083268923959, 1065198788873956, 1096245243560409,
+    1305885689535814, 1797889617744990, 2386501946800268, 2584906093220312,
+    2802698923012311, 2803997724097577, 3066265157769233, 3087873199425315,
+    3099646900034867, 3820872518115391, 3907462859455852,  442772451776654,
+     850467291383635, 1031942813370836, 1565361008560574, 1694856978490851,
+    1771306453869046, 1879949887464398, 2236358785881473, 2511543493886773,
+    3001221959033298, 3001550097885400, 3398450741180870, 3540599705950134,
+    3716850550046681, 3793889496416676, 4077757112131190, 4153127855594391,
+     260514911224595, 1328857399988120, 1427188038581931, 2340486884630567,
+    2619120980389550, 2650126172106577, 2851356831932302, 2945690857201709,
+    3156665472521888, 3430124995424520, 3470407478100826, 3548774699105677,
+    3720017987126883, 3997040862174946, 4434576688290149,  463862767313465,
+     607501128788677,  873838859658712, 1339225225233159, 1814784877674421,
+    1940883529788624, 2050248082897278, 2646332939225274, 2755655246912849,
+    3491164729576340, 4239916678070618,   89945610939207,  215495072579383,
+    1073979011100537, 1773080800407154, 2115546744964853, 2134950165111603,
+    2607564427497171, 3056607709823963, 3436595447913038, 3500947013636191,
+    3635369978606263, 3973520995503986,   72641702925848,  308092136277216,
+    1021417860615967, 1710670572101259, 1871410469500242, 2108772753768348,
+    2150541685795643, 2832745306670660, 3604801008374098, 3806375526278373,
+    4140378632297130,    5245532480978,   37503350055580,  156237544578895,
+     222351628567530,  744399910777099,  854842213349094, 1313461451937677,
+    1608920032254285, 1788292125252413, 1809984657750653, 1835881100872080,
+    1858677909028292, 2189326925103707, 2211533279816466, 2230517193107385,
+    2846830728970651, 2862922304595138, 3508975634446367, 1590937250148703,
+    1736112166009207, 2404925953305487, 2462188147900592, 3627290335064781,
+    3714928952318993, 4016859924125565, 4424172590039529,  222166953558626,
+     575389761265022,  688314719164148,  706738635012857,  768882631871719,
+     840642865510145, 1063381356923346, 1427895481477671, 1832244090754534,
+    1959980489774288, 2013968173009944, 2041847203567125, 3747748745190041,
+    4296986376896385, 4303580592391321, 4325949051626825, 4455409653297594,
+     108528748710352,  466017361494074,  865327958694353, 1405347246540598,
+    1408555933639296, 1503314378022799, 1544276717242981, 1943795594193696,
+    2203946827004790, 2775475304266051, 3002081707837113, 3422590664354173,
+    3528200287245061, 3603022822685888, 3797346847391023, 4196244814897042,
+    4284861953997642, 4494198822412270,  305853193714679,  401838720212779,
+     863006438389244, 1312730559711565, 1401778995087832, 1507947632969277,
+    1573679543264895, 1680809018862974, 2215318717839195, 2764216421273601,
+    3377735864635735, 3424986876886770, 3572704377625238, 4235100790724122,
+    4263376731749115, 4407379582787590, 1260219514261298, 1545473369258195,
+    1988718221928981, 2327555972532151, 2589810634332318, 3156638080433974,
+    3167756485790975, 3629041115714147, 3870885344423239, 3986500254948279,
+    4165329052182298,   55615906435868,  278593133600748,  541417617359655,
+     758598379771835, 1112691639642789, 1891980150415613, 2123197199594204,
+    2424900242617280, 2477024215067195, 2569743089819070, 2793354980751788,
+    2833071571275966, 3501460939458150, 3726674226585258, 3858131170014923,
+    3986440338348376,  130642804771449,  169857362500944,  513745897052187,
+     596189309965335,  902452893561637, 1681421125095583, 2359217467855242,
+    2366622205963903, 2491673564616008,  879582414482380, 1120421595150453,
+    2602346756277388, 3052065965341444, 3299068065228786, 3790175997530426,
+    4010412184583436, 4165373301359473, 4334075969406021,   64723272111968,
+     125010435346660,  354008126241124,  627823296642142,  766590662821780,
+    1742650485367362, 2509528666859490, 2616015619076507, 2855220982645641,
+    3737506777364026,  296102438764234,  331686837635894,  682118500196117,
+     764102125735661, 1028530953043917, 1640125773617824, 1871775101523735,
+    2246601038627948, 2359229296127868, 2516112088337173, 2970153102222776,
+    3002972882142894, 3787896735597326, 3826504138205267, 4483124718036164,
+      19408697970856,  722946256490768,  993923124066269, 1247590175967563,
+    1358329178938764, 1631161897686782, 1808003195226782, 2495017881846540,
+    2630046037034860, 3535179727395413, 3932736648395087, 4481369089883183,
+    1220560979041157, 1396805928427786, 1469516192814663, 1598274341553537,
+    1685830432868897, 1995696666676750, 3349486553412813, 3763493644890319,
+     137073028545914,  425248694758833, 1154668942077030, 1555904162065536,
+    2557875164507340, 3029238765938639, 3111894388408835, 4229868369778614,
+    4337834894185049,   56446181690093,  137731284104354,  169670787343384,
+     272101196685164,  641433264145801,  776031105510107, 1500372077782294,
+    1534595781114549, 1635413987293756, 2563553748012610, 3106045866669456,
+    3547538025591290, 3815051751089966, 3952841577874869, 4197998633557877,
+    4369473780169191, 4398807821624465,  180439452862847,  906102805059972,
+    1052073506759406, 1079055558957398, 1114535817953840, 1542355197220873,
+    1709479065461050, 1844858938341144, 2804044171294798, 3162808006324114,
+    3299510259129146,   58197918889252,  168350398223612,  393129441720094,
+     627556160449036,  785181686108009, 1794046551798914, 1952393196971189,
+    2357613240864263, 2645310453122171, 2908289181790599, 3129830064638793,
+    3158685652139056, 3274298597760790, 4183858232818108,  633770180105191,
+    1198613073867787, 1530697451808235, 2127588900943344, 2167773138454350,
+    2612285353757506, 2734212516153071, 2820347982378193, 3408323682762327,
+    4031063434887866, 4031750168593313, 4110193038375445, 4160937648704548,
+    4296058154348250, 4466022290317250,   35421768119613,   65358052438176,
+      80166546203845,  163242657981525,  315765329754816, 1422832132872989,
+    2528203224438084, 2613457431530465, 3450974869324946, 3556135511810413,
+    4100593516224912, 4136970753580543,   71655580484836,  281055325805256,
+     309559195613745,  603702273166986,  896182060071698, 1072198098223178,
+    1382697030895563, 1814108253405964, 3270948476476850, 3535502075145037,
+    3711554464007678, 3740800084814182,  138116482857910,  342448742552156,
+     434053517129529,  838814756100652, 1149025830438708, 1949244491227044,
+    1950174872942435, 2633732312195286, 2951257739089927, 3031668459533018,
+    3119316901303760, 3170405369711413, 3301315087570357, 3713412293034431,
+    3838393897619173, 3906421797427852, 3939734768077776, 4224157468976235,
+    4405508219603505, 4485573117064740,  314345725969021,  338850035098147,
+     677162174066962, 1001684621634269, 1537074597291578, 1652591262651318,
+    2048634225653719, 2568594798100663, 2872017131516489, 3271782603832107,
+    3340822428843376, 3456569970341571, 3588847260901491, 3940505023124658,
+     291814192710049, 1177353867303170, 2722572493722977, 3789973793802928,
+    4143288951964706,  150373588533528,  206369402947916,  322043260572136,
+     829243750949916, 1812760381541768, 2157503649031121, 2568012230172294,
+    2624798918167347, 2728245182518329, 2844425002743844, 3005235636661843,
+    3074359952515270, 3353298608286683, 3508040638344101, 3700837933180474,
+    4378863187175282,   87858237900762,  377907439415952,  420422052247874,
+    1059917517315328, 1120471907399636, 1250778399943638, 1498166603330609,
+    1709397638532221, 1788190261102821, 2329598735586408, 2947605035647793,
+    3325216223188261, 3346220057077748, 3433506956640186, 3677736577962762,
+    3691215814641922, 4300318155372168,   56032712004410,  241957757528048,
+     345603459564589,  826332772603937,  867053428717481,  910848176053262,
+    1544576649917606, 1671163580503350, 2204442267558362, 2217779048747837,
+    2483205064483888, 2584530215716043, 2754169160187900, 3080798966550892,
+    3485342101255049, 3578566938608219, 3851492990263272, 4400353360534864,
+     247593113160586,  685526832195254,  942992042187046,  964646259070819,
+    1181679653735049, 2434939192626234, 2572744738637585, 2704310734200733,
+    2827665398848107, 2874935077680549, 3098907528369918, 3148703090402223,
+    3227670589367057, 3554225729404421, 3734711922577051, 4059647982290271,
+    4183294435078586, 4390256717847913, 4466321072178132,  137310963158960,
+     141749679727214,  268719766457628,  519956232969400,  581951879628659,
+    1875481129324661, 1947040942825875, 2737870313166490, 2921055301029911,
+    3554987142386426, 3988296461490423, 4003780881993356,  341489973346268,
+     902805865674594,  912496768779786, 1298957880810478, 1513923214745143,
+    1526410175254773, 1558130591775184, 1663447444073478, 2081528762555975,
+    2271961296263577, 2410623540116344, 2448969610584587, 2647194950803785,
+    2665683708461592, 2690793207808966, 2755783367106569, 2815231516629599,
+    2986900206216554, 3679267289441856, 4204311066741422, 4209007185642762,
+    4244137691850736, 4273886327903576,  262472382298061,  342737699896508,
+     622649965256044, 1016839041989234, 1287423911355188, 1556919241837965,
+    1627798216343650, 1850884495642013, 2166398844002963, 3183486830029478,
+    3194162149690976, 3625397513355490, 3731613308916279, 3846446187925531,
+    4174781530358131, 4177820253320860,   75885177311231,  812207147491753,
+     814651487204417,  890440755071796, 1210963641959740, 1546794108053077,
+    2230359406919152, 2927417696787529, 3375831227604748, 3691725784755409,
+    3863257913562597, 4017639383863364,  376100801482595,  638311252958377,
+     700975949193329, 2937836609518524, 3133991924104851, 3984359829520572,
+    4000160599455771, 4234388862950807,  147942137250723,  196796510599454,
+     227853772254090,  323287160923204,  435140264068907,  831138034295270,
+     909188905867855,  956377041213098, 1041841492959221, 1269535470510154,
+    1505012430056153, 1608170836072806, 2225025702796875, 3156519553459071,
+    3374197436407669, 3416450717099893, 3420840311085497, 4037673563873200,
+    4398518231503570,   95890879174060,  340846532268203, 1770165337183600,
+    2140659644949736, 2545692040577087, 2720532084099926, 3208797783769437,
+    3342390577092724, 4029095791441787,  225547984126359,  280384505590207,
+     301872688144623,  421228774564287,  608887847255470, 1022475431877145,
+    1396086076286597, 2182478694962264, 2263773621893055, 3176021244672981,
+    3258445008553069, 3449798099459532, 3544353176350864, 3594861998283247,
+    3682068336810139, 4037314994921715, 4154492986026678, 4165805916810973,
+    4210348309854687, 4370547858844520,  444809592090523,  564654939433381,
+     697706401895865, 1393773398046365, 1416769643888136, 2307161647195424,
+    2450716500280131, 2585380165905188, 3429040873885878,  130553777247996,
+     566739714791977,  701335591252977, 1563703161158440, 3091654217325119,
+    3320156752586471, 3587143883999139, 4093336378980941, 4112629764542555,
+    4224619428468025, 4493148026524250,  113143764456113,  547209571332920,
+     667303478943987,  675629756703162,  918660787009078, 1575604593433812,
+    1763349139418806, 2125879694085724, 3154526223809784, 3535051362953050,
+    3547636568627980,  164925856681714,  245052648597088,  697955983468517,
+     727758654362272, 1824088243364808, 2044945339862514, 2198777619689254,
+    2417897741664456, 3006132809284982, 3181076992683039, 3469859814017512,
+    3558763829000788, 3827768916108718, 4281937648709211, 4460638727228340,
+      21162736797501, 1097398242141228, 1832266183875310, 1954868290556486,
+    2074992654168796, 2082913179395282, 2228791530639334, 2502852313953602,
+    2723926532266621, 3039143197776712, 3923250457859759, 4173122299025514,
+      23153196797409,   61251689449096,   87441885614770,  106200817477152,
+     260670708426689,  434770965854819,  779566303281781,  954016313498379,
+    1214275615991303, 2210053243212595, 2244945013668890, 3258260385347885,
+    3314978301727228, 3468340969663834, 4018922595231482, 4036816969639224,
+    4463564494152834, 4464067509399170,  426915295388361,  601170780177665,
+     701827864285780,  736948545977224,  916409059624122,  982923001435528,
+    1239477478744989, 1311430272907403, 1354754425901397, 1612424968288666,
+    4395630922653773,   96027556512197,  258420920043179,  353537959280027,
+     456775943880573, 2042473543307286, 2549227018182951, 2852262265647495,
+    2877211625375430, 3021249844673413, 3214203349322613, 3357278766000656,
+    3516888843998689, 4389222403472184,  315597654295403,  325503714312602,
+     368924147568590,  579881078759015,  830444565888482, 2320439584893159,
+    2357778642417077, 2477420622267764, 2592630762711768, 2783146804809875,
+    2940629874979163, 2941128960418887, 3032578507635086, 3243587508763120,
+    3447462928438721, 4109907481668602, 4121422797493415, 4187270637084087,
+    4300012858975755,  521094132149384,  781622626395230, 1036403778471026,
+    2760092607271507, 2980100865541021, 3024613785035145, 3197816081652433,
+    3446474595026733, 3530131335731336, 3837756597318696, 3882370709333079,
+    3935720781445938, 4102954684271251, 4194437634843652,  255771286787828,
+     525963175492582,  533849314911213,  549635348071617, 1002029772986792,
+    1096438464581827, 1612832737528381, 1929315783219189, 2268985670372450,
+    2846689838724469, 3021701811286583, 3289520096797689, 3575198370908682,
+    4009831052389270,  399772185873927,  587416521790315,  948515644398812,
+    1269628002278556, 1329886674415311, 1558269350574997, 2502499704466685,
+    2520272254869455, 2581631272857570, 3174503512280256, 3549840324244029,
+    3920892664549069, 4022894724970141, 4269036197209455, 4487001938492382,
+     700107669146957,  720767644957894,  794912288758151,  816166647819153,
+    1333189689823627, 1835750061044288, 2368483487431885, 2589497776194071,
+    2798912258289286, 2813567489597087, 3147277825023250, 3547789960595148,
+    3784073512948260, 4220174959650083, 4358900080408642,  785560344424174,
+    1539468199193396, 1888055800000938, 2079299838773918, 2146693074814759,
+    3019319404880848, 3078668326786925, 3277341664959891, 3717284497624944,
+      67160270248608,  652866507314312,  962747477896960, 1475992010843996,
+    2104936927609883, 2309038311165674, 2319084861769252, 2576152178841579,
+    2674785412544514, 3213141409742230, 3488948009643879, 4208037777830781,
+    4448631345585604,    6569542404069,  247116934931201,  284370519161308,
+     606138172293603,  827597511654598, 1534558914185215, 2234137192543537,
+    2488172663814706, 3358549179595425, 3614892429799491,  207466181618704,
+     412979348199856, 1691941787236827, 1803262401586604, 2073106502406196,
+    2107944238021576, 2457006362977517, 2683690380229166, 2909172859109196,
+    3162022511235194, 3311148847644064, 3862584223698169,   76000409075092,
+      86464501631195,  150208912232794,  245110794557635,  509883479944441,
+     553069107255362,  658697396727657,  663184253426908, 1114551033913045,
+    1943572229812382, 1994301120915037, 2570090490995359, 2676578425664029,
+    2807999018382533, 3177554102368401, 3863087251895435, 3987388999936301,
+    4416603460550594,  253015613508603,  605406284664908,  835482707474366,
+     965336289806335,  982487930764409, 1613093190823866, 1983555077685453,
+    3175854473959641, 3409916703125320, 3540420668451575, 3932799086788286,
+    3962052567208014, 4309955399213415,  399555167666054, 1751674886592017,
+    1843196152778885, 2252065022111201, 2750076642178472, 2988735762628538,
+    3210039874628359, 3321478383176496, 3365332077611457,  193615630018430,
+     714748267287403,  769007107179287, 1324848778897097, 1427945290459611,
+    1859216022383633, 2623686214874258, 2978118420565497, 3641934476302966,
+    3912877940522265, 4000591224102105, 4083028742305335, 4242371593453752,
+      26639142477133,   33788443156014,  103989526021129,  107566632489702,
+     176139792965796,  309936936232907,  708735676121973, 1304410850110668,
+    1423725673560906, 1617009281965514, 1889138565329984, 2032139315682399,
+    2175260907588133, 2564079903586025, 2855111556754215, 3363768040481741,
+    3461085771421309, 3969308011518209,  147146675185043,  416241165393396,
+    1988804368671152, 1989339804822037, 2455707639597831, 2555525709994284,
+    2748190373857502, 3439165323090503,  105821824255834,  299066134594322,
+    1918537474496216, 2247362077852010, 2652682331813690, 2744653560857632,
+    2992156711088004, 4273772752039728, 4329737128001220, 4473138718628059,
+      34610794042365,  331083264464869,  886218553444172, 2265121869334221,
+    2392213780069607, 3028663645012761, 3032748678985935, 3047168121897016,
+    3443203225286193, 3621284376812279, 3684400018355958, 3912090066100279,
+    3984035983101122, 4198866721159326,  165537226530340, 1007564117412091,
+    1619905601242278, 3218355423124931, 3260896562151484, 3463548829519100,
+    3522529332055302, 3776938134954611, 3997493618794846, 4198481613062280,
+      30139682471422,  383567822573295,  610245945115026, 1794693587778438,
+    3992362286762157,  250881845076657,  509497505954112,  735757104091665,
+     816869295014998,  827084827302892, 1008800067493425, 1308038139319124,
+    1537515266620252, 1596021245008055, 2082793618165006, 2701793176847574,
+    3310367941040159, 3746574510068853, 3773150809734156,  244090169750398,
+     388552808162477,  694777093692659,  791161495561877,  909881935762050,
+    1381469536170233, 1412707568929021, 1574502619403326, 1713562756175558,
+    2629956324931144, 3029421167040944, 3079740375804298,   72681842802418,
+     243979165664539,  338039086922759,  456459879368767, 1582489964293146,
+    1782084842116626, 2082708170773055, 2501927168269726, 2866395117596360,
+    3009750532995508, 3623237555453093, 3683743805406404, 4380415991775152,
+     159174934380162, 1916554977716972, 3166791512871876, 3442087237913965,
+    3536054808716803, 3603674008637834, 3876489043256098,  156794466975873,
+     364495553388256,  781803682419842, 1326573124008342, 1594566285913682,
+    1664752632004002, 1866110028092964, 1988759292453484, 2094937754851459,
+    2386209126222981, 2656533645900890, 3608237509516949, 3655550416222123,
+    4161655473006808,  396927277856369,  446003685278678,  897257483824720,
+     967480180626201, 1370181218480983, 1412900181256729, 1711279590337706,
+    2084741884547555, 3205319362797784, 1011825113838592, 2112306543526706,
+    2702535005551169, 3312992147110902, 3596734009013832, 3657988887126729,
+    3803339661833120, 4456971623735591,  127680533543661,  283698768298326,
+     667315682793583, 1337397815173886, 1905163502604157, 2339345238776018,
+    2433093722860237, 3311808305819967, 3640638803885679, 4037900006937800,
+    4175563097459466, 4193596980001934,   81430652477963,  130715339650708,
+     617706799526278,  726138023564582, 1599924629032864, 2373868602813368,
+    3812555084240120, 4036649358934461,  215475386512560,  219127159306761,
+     266580556598314, 1277939867036186, 2728054226090634, 3572581456429270,
+    3623470487151364, 3945524033714682, 4020762146204728, 4133120229500073,
+    4367431018933961,  105520392911383, 1763424259026984, 1869388546964605,
+    2022562584183835, 2118055902403782, 2394287903871180, 2724963446141226,
+    2804264978668080, 2911724558021567, 3441204104534428, 3726218241327993,
+     516770738001501,  698460434449056, 1070967785652189, 1991989684145058,
+    2233611878780572, 2345016001520854, 2433588930297069, 2544126530679405,
+    3844461222975738, 3917673520157298, 3968531112426496, 4345851494277676,
+     433940252088001, 1541800876141798, 2031615902068090, 3740176940315677,
+    4094853776468513,  848733317838837, 1055294760333740, 1798463436084019,
+    1923290795759240, 3848716246986741, 3937966084951873, 4001705583295207,
+     313619424046951,  631305973012684,  752067947747791,  917316315782391,
+    1099869587232933, 1264355572784805, 1488827108913898, 1568692568689574,
+    1595189289770984, 1632625610008549, 3328412811863830, 4075415996415768,
+      23807006331064,  168405223071257,  303007976693828,  355277351988387,
+     451863156446470, 1779075761065577, 2073043962224584, 2773276553383804,
+    2853706922859955, 3496208689985589, 3702441993628390, 3813813695464123,
+    3857910055457375, 3886925782577077, 4140870868296170, 4412063875235331,
+      53594953400954,  624058453070363,  822487160781732,  899467160052547,
+    1445949997323879, 2324933772036531, 2346842443898431, 2962432895345089,
+    3177158511076422,  633491966793399,  994939859738711, 1530495869725377,
+    2277175827683478, 2511349073885946, 2716268839668686, 2865189843682079,
+    3211913733703880, 3671385740327267, 3688817380006446, 4246318412566173,
+     311723344160381,  469957007392561,  931375250740541, 1817140889213852,
+    1891147989782689, 2140742779582298, 3171999683348379, 3682909911167887,
+     334138974026159,  566625350740426, 2736121266122717, 2934028292006343,
+    3728886696575911, 3824591241901253, 4163114607975463, 4286837691992712,
+     931626784265569, 1662519726611613, 1752509350671454, 2233175786805010,
+    2448633329017828, 3635935976184858, 3672365283478098, 3692815997086946,
+    3880919137925285, 3986025745569837,   53127371168776,   54161892759385,
+     264136372296810,  611893801457567,  763213952407586, 1401624790850128,
+    1688048745765748, 2105248805115504, 3714810155193587, 3738398819382117,
+    4090808157119829, 4230133555838371, 4232496352063836,  365528343192299,
+     419849077538322,  497695599167083,  867544436197198, 1010938167210813,
+    1084696707511441, 2466115099633170, 2786553495678956, 2961171505128217,
+    2983215755318231, 3044084529931499, 3170249988758106, 3240350861554615,
+    3279364016695245, 3426350573403881, 3645285012016666, 3761680971605841,
+    4224857803412691, 4365819966585131,  307197254149660,  845196621397337,
+    1183540198819048, 1538208192907007, 1551094028787755, 1612600414523820,
+    2587562838573001, 2645794712949617, 3247233930786463, 3433002766497274,
+    3438706880794592,  374701520178220,  637150707405681,  859810602686642,
+    1043502535603011, 1053006772894133, 1647982441305892, 1664750926119296,
+    2145193363275968, 2775178696342382, 2892017741627060, 3162755873450882,
+    3220615398651011, 3380849333448699, 3959677426491750,  330970645150814,
+     454857798594114,  765825360704980,  960850855890357, 1319911391318879,
+    2026271533437860, 2196282827086204, 3252164881534730, 3635500814857229,
+    3737584418765500, 3829411227416451, 4258704284162984, 4502181347519362,
+     113629756070125,  314771012795427,  603107801079438, 1628208662787821,
+    1658836593070096, 2260874123963619, 2714967207786831, 3105085987321642,
+    3281616830139294, 3430499518538220, 4174177104244753,  275816817704907,
+     297196930577493,  372672796367565,  447684944977421, 2280627309849767,
+    2870000056484467, 2885094443681159, 3342056286331131, 4023477875690360,
+    4439851693199943,   84436231103090,  632509657600629, 1664441505652321,
+    2447760033105751, 2585033090321176, 2858022018882021, 3538095684573334,
+    4496861410671157,  403958995159210, 1185841178295954, 1865729160624850,
+    2049581233160658, 2547594818131235, 2618966983366726, 2741726282312382,
+    2960444581561507, 3546231379662757, 3794268001140708, 3796943216991827,
+    3900848286264760, 4419501215561561,  301530563671666,  961776390102133,
+    1458024509452269, 1548618341278705, 1566466540458756, 1802301112406447,
+    1879437321490089, 1985194312128927, 2592928097706544, 4468125708174420,
+     450019301908669,  932324467399429, 1316248889262075, 1704757430537619,
+    1721849141944860, 2042051749593926, 3041223747276333, 3362340034962689,
+    3514504111882635, 3627756226182210, 3872360161584579, 4289139731626851,
+     524635535453296, 1711459728665315, 1957545201160408, 2086503770036494,
+    3534117789634057, 3585174819588814, 3937417140545516, 4010014422992298,
+    4471585928798146,  407604925047213,  410135720505341,  733777891391560,
+     764073753015121, 1701532067662680, 1819229775692080, 2094047046779158,
+    2532743148296554, 2820485553330500, 2897940076749714, 3087436334032007,
+    3109481566673674, 4019703374145114, 4182100667482708, 4219903442186842,
+    4231995457131966, 4371373908239770,  506894449405282,  740264050155596,
+     767560486882548,  777544160580334, 1993750535378624, 2314562263052550,
+    2520282726932485, 3544885485652797, 3645681095443985, 3830252322589789,
+      96380968956774,  372095218403041,  439699681549277, 2265009379529466,
+    3121244486216903, 3358340526087046, 3661978016414879, 3758921433274654,
+    3780804561393576, 4001866990286759, 4500411099149956,  193787133496042,
+    2651442013413151, 2689187805921411, 3049357529266170, 3117969987808384,
+    3312670291496618, 4162540093924268,  604935303614248,  817293210861851,
+    1522845777722066, 2546999556036957, 2723821204543897, 2944660616911849,
+    3438012468788941,   64219470496956,   95298346087958,  828487277947063,
+    1553843122776799, 2349354857695612, 2503071208227298, 2755107390384612,
+    3197150704683779, 4240221589577185, 1012127153876753, 1316002397504612,
+    1344201707300671, 1506170651717557, 1782895843914761, 2518494729678675,
+    2691391483831880, 3285373893622456, 3414568250895262, 3584931262235380,
+    3599108039588170, 4447601427249967,  167922180631231,  602015496154322,
+     802476896335748,  850374361774457,  860585964255739,  929893016116624,
+    1095250436284707, 1290616842193675, 1989089862442372, 3049095266478539,
+    3296748731811820, 3894158915419713,  167773843382558,  524319302579400,
+     692406955891170, 1356980481853102, 1576737232499448, 1610355745613455,
+    1937264568245899, 2254858766756250, 3818904114202496,  319138217607892,
+     338456164460831,  401265281088774,  806558026404170, 1286051415264044,
+    2754365691190317, 4055829220435947, 4189736398288194, 4489909764087549,
+     805911586167426,  924968024832925, 1479262335197260, 3038408196249995,
+    3190957394217917, 3294038186553983,  450338767057984, 1406160085551766,
+    1747773262074661, 1816260949256944, 1900026742095030, 2188286899150107,
+    2289702435934338, 2639739152930055, 2751272959288533, 3714155157812426,
+    4494292506813442,  190600176892080,  248560894612468,  578098757868340,
+     889588984258602, 1005087965488588, 1113386558553737, 1263571977844541,
+    1975011395009532, 2040018255985236, 2329352232697042, 2449515894375472,
+    2764010457920608, 2916412288140580, 3383984608642952, 3647600948434177,
+      13118333825254,  916931609164175,  925312904215918, 1562995745983587,
+    2516238128888244, 2953960620817517, 3140736469472653, 3818178147185579,
+    4108110436954062, 4175739461391623, 4440889408213181,  812663045730562,
+     854153943940058, 1586460634214769, 1784840219343488, 1897633528721112,
+    3140069397751196, 4203092893265379,  154279960585113,  595071631478358,
+     596820395420698, 1487096469432932, 1521676802715226, 1539140863298954,
+    2298726306170863, 2485656399735520, 2766684730172864, 4191329030123287,
+    4381558196338718,  304479147149989, 1763979849297199, 1792730314470591,
+    2578888127201191, 2945246313508197, 3122627959457347, 3359292703051823,
+    3695870814853093,   19313725752792,  152025259943338,  200649739605267,
+     386259418148292,  919420211004944, 1239330048061416, 1990667809306390,
+    2537002296378119, 3092607197247556, 3163515645302831, 3471109468437295,
+    3688904263132600, 3837046182985164, 3965905524244297, 4234957769031571,
+     646306524035098,  804722923274736, 1081084608360330, 2045088766671949,
+    2700369282448154, 2863145802123806, 2889617961913891, 3071427517463112,
+    3432748674145187, 3670729902954683, 3762466326053558, 4002938069803409,
+     562836462095495,  877537340487761, 1013868226567959, 1019291359692519,
+    1469955687246761, 1790651485625633, 1941815426762825, 2209410695748700,
+    2241773635650173, 2617047570708154, 2874743656838401, 3684283818910401,
+     381977932697308, 3810043538155249,   86057613054720,  180112622054675,
+     583861935823208,  840360413388448, 2767360734615708, 2965886705040724,
+    3409915554130604, 3788230177054620, 4139772009982733, 1028405602530190,
+    1081798902515781, 1426987220603943, 2096208725336218, 2654660771096049,
+    4328319231919158,  421677860396175, 1349526815425741, 1585905665589194,
+    1754242861620826, 3113687740071557, 3242745536564596, 3631702075793908,
+     103796798954078,  308944493520314,  344601593814296,  752048855604721,
+    1636600183834924, 2376220244214567, 2850537095314784, 2889060007080567,
+    4382601676552574,  402206676987228,  416491524021848, 2530179273255474,
+    2686964802751132, 2821658924377432, 3490210397082361, 3563066064313882,
+    3569279088567454, 3708723363936009, 3867384393637244, 3959729936760979,
+     209271851870365,  494603320547849, 1245762275591977, 1471194649437704,
+    2300443774120537, 2412351894558808, 2653690160666605, 3216498397055838,
+    3678980635544017, 3884789520072722, 3964302279704971, 4111880272308713,
+      14809314433087,   23006891025719, 1624015999464437, 1636252434802552,
+    1957154548028554, 2591458738561556, 2720674709770728, 3196251063890091,
+    3849018468337922, 4424934574681481,  547076089236439, 1500853908291981,
+    1574149713864046, 2275034652920858, 2730072855234256, 2858995073399181,
+    3346752845564091, 4008106877459142, 4155090486812819, 4313982392652509,
+    4457123633591303,   97034648981129,  823625435538686, 1828344355253931,
+    1966286462045049, 2814103922969582, 3341484952751635, 4077967062551596,
+    4176999171625649, 1043316374875211, 1307384642426816, 1707060711642011,
+    1814267800920677, 2347378488743026, 3142593523262633, 3420286430338042,
+    3739613673564285,  493598337261480, 1086313384419686, 2035676065746646,
+    2727005680026380, 3102297299214115, 3340283440281876, 3704033217450167,
+    3953341131367100, 4275208012116348,  213163420573478,  273270795155671,
+    1593009890050407, 2071191705953526, 2285206026688087, 3218891726988126,
+    3687274570104223,  378037487479215,  881111680914395,  902608705533688,
+    1311451048671373, 1646260747497243, 1649559820148017, 1803216241128870,
+    2249102906294573, 2749351568775725, 3732010519074508, 3985546904905953,
+    1884058270798558, 2514392374201787, 2725697093277374, 3205795672355496,
+    3351847917542129, 3986840806683884, 4173058622800116,  374511494398458,
+    1634717948928006, 1951982043682949, 2760054422985367, 3081393727196954,
+    3862535141787336, 3889394387528703, 4402121168454608,   96592636393789,
+     102598363348578,  191005257795436, 1263014256127957, 1267776199290048,
+    1384310684562296, 2546032255588512, 2577779198303554, 2789659634047799,
+    2906829765539538, 3071294137155044, 3233887920823238, 3560765305002786,
+    3741963775623841, 1195520822969440, 1692163887884082, 2407707988055307,
+    2523587178075004, 2528457190918925, 2778845801654158, 3239039910242500,
+    3569177069006702, 3774383710672118, 4025258634975586, 4312074588100753,
+    4321959034181586,   59830303892042,  627202028209460, 1225055297512856,
+    1483870396075870, 1578078667533693, 1586882416796226, 1754442493570335,
+    2010883988939701, 2422428074123136, 3079530055264003, 3205695514090610,
+    3836406878928591, 3916113181536481,  359555684688559, 2981791847967881,
+    3752577239683990,  236707978045063,  751486118022432,  936996568948504,
+    1083976248495569, 1292732487983274, 1469990345024146, 1821005583866251,
+    2322098172317330, 3038764232276281, 3473236774137430, 3919812137911886,
+    4003703684561556, 4122968402977491,   97253891110908, 1300586219448286,
+    1736182686000467, 3768158948802430, 4251380741685439,   96496433837375,
+     471375423330850,  749877870568331, 1580798145512917, 2796664592039269,
+    2994541161921652, 3044910475719309, 3406909524041954, 4044140438049243,
+     225087578746169,  259185528266840,  338547972796180,  890694643847057,
+    1265849702255025, 1275591692846173, 1380544055581204, 1814211969538546,
+    1976445193450089, 2552145160429988, 2777183571162862, 3237157610580932,
+    3344253682822809, 3529890870012118, 3618447097110232, 3753281709322911,
+     231320264983731,  265042917972956,  622568863919492, 1792670747545798,
+    2281690970084813, 3950358576510682, 4309373919163166,  166400855156074,
+     216363433969389,  225092516906294, 1546162833790845, 1629607364938400,
+    1631834582835751, 2969382578844418, 3072596984605292, 3168472476518256,
+     505310337680843,  873881987562058, 1522346253769749, 1754226457814115,
+    1798668640789223, 2030615651545993, 2081931066285489, 2199916632649218,
+    2555477675289754, 2935956639205329, 4403420112000104,  575766870926668,
+     861556162074450, 2118122633229633, 2534185013565215, 2825925014375218,
+    2870335758897430, 3963561783128139, 4031920769143879, 4323637191114210,
+     145819514330167,  325714271607691,  329840568550291,  579125886079205,
+    1085572836039134, 2336297649665579, 2402226050291797, 2431882335889255,
+    2624045949409443, 2713518803899806, 3481333261817243, 3798939249747430,
+    4061882610156350, 4307202752813756,  635795675278481,  868115035211738,
+     974233568058880, 1083969487892368, 1267852016429419, 2030649093754985,
+    2263745572945765, 3195425106970378, 4441451836792177,  122182735912500,
+     415598799242521,  476783923288055,  482941930249059,  696809865687323,
+    1646822930008569, 1929246341793173, 2207264440365725, 2539288888755340,
+    3064897575755381, 4020097984804467, 4247421578082219, 4328437488743558,
+     270290282908139,  747941574918638, 1049447145159461, 1643020855062389,
+    1895331141230890, 2067958703631173, 2711899762428452, 4057637475416527,
+      21623922871545,  846891171004769, 1676244435562820, 2884198216475910,
+    3019650790778326, 3580678824772323, 3601934166284978,   86262377428304,
+     154369855820876,  175819722760825, 1920172457178855, 2487134499156972,
+    2885571696837734, 3193044678418879, 3318279755530095, 3563476109279223,
+    3886614282467453, 3980615256626493,   24690180382418,  346009835560945,
+    1339220316612948, 1690712044506758, 1824032698722164, 2243602431458956,
+    2504897237994142, 2525379390055886, 2788772713321880, 3961225603753815,
+    4025005382614922, 4030645637554795,   68878903781982,  514797007400581,
+     559549645098037,  744113995082270, 1268153706792470, 1562386187446134,
+    1923373310566189, 2049052455824561, 3332614733500878, 3603533636428852,
+    4056555847950294, 1412007238130619, 1432100339337775, 1631498726830655,
+    1723284272512180, 1969748971345331, 2684171111728830, 2837603533732799,
+    3167572693264338, 3250660594461854, 3735517186895455, 4142800379259829,
+    4483620717298753, 1320904509298859, 2445437337904066, 2785735011663594,
+    3255226060244275, 3427338817978386, 3489901339241426, 3739626795110884,
+     986504505763220, 1516967486380734, 1883860126386972, 2832106742636124,
+    3008746637587259, 3171956418441884,   49328167691002,  830168952075566,
+     931051017612218,  960323164613353, 1052717830319738, 1357790682512604,
+    1721756599044555, 2003493277123712, 2418598749549272, 3199327534445394,
+    3262037423456384, 3626774564535940, 4297145537702225, 4297933463927931,
+     285150963169787, 1490306721775133, 1912452442746091, 2637688428474161,
+    2654330177024328, 3583619406177192, 4349341242073739,  244843932153086,
+     786386020061481,  797123234177118,  856177420587295,  883559330608058,
+    1402774222250375, 1943588313483897, 2038187600699614, 2585218645993320,
+    3333206247101564, 3452938612504327, 3925618699150646, 4080254624429540,
+    4263014210727212, 4344663802704736, 1105624855589884, 1632748483038851,
+    3253796657843810, 3381776704873508, 4229659007607139,  345523638551080,
+     576989247997880,  680218390533549,  915380965550588, 2357265327107855,
+    2631777055730850, 3470600171772168,   86068976912893, 1012149518967059,
+    2069997459173238, 2204800632933021, 2419980141328040, 2811157021293132,
+    3133155550563882, 3699576261681196, 3763640951521673, 3790207426670446,
+    3868001878361598, 4329551770231080,  308239624221590,  345915662662487,
+     552080194689578,  923440042362007, 1725934061178691, 1829585657925150,
+    2001519609458415, 2337064182120729, 2376476212632477, 2879472792387070,
+    3534454642860763, 3943486599167477,  306046805083024,  351896841276881,
+     567709048536432, 1697139398631844, 2064762982667914, 2370605094505079,
+    2611084697868154, 2778512143669108, 3226092555484583, 3797092473756530,
+    3846364389057786, 3850421914384676, 1920409409340248, 1974109234241575,
+    2511769655668967, 3007174831500829, 3616541451725732, 3619606700883092,
+    4160635949028621, 4340872599481389, 4425593335326191,  178846850567668,
+     926247928039071, 1945651552611707, 1946934634470525, 2015889968639636,
+    2838737438351521,   20543446219055, 1064806044967550, 1382153648112998,
+    1717478151605040, 1839194086895210, 3824304458494114, 4096419005083789,
+     989527664910661, 1372169987366142, 2087401667911713, 2104517940610279,
+    2474041586143251, 3119953442956448, 3557715232951841, 3683777534354883,
+    4107503037583865,   51804101267722, 1482365707524164, 2303439852002384,
+    3612668679113326, 3828307974881949,  473700292469748,  492441540202376,
+     575085373252736,  615283365086884,  736185830408370, 1539401951190009,
+    1575357716786503, 2230518373461042, 3277402153219741, 3696134837148177,
+    3848969928547122, 3953722853596594,  336981350937545, 1801684134587390,
+    2025795110959832, 3341913871513263, 3418811344845226, 3773447900480289,
+    4171533380469277, 2025848176300024, 2148976877067208, 2811798691731841,
+    3846732912035101, 4146112456898832, 4340272412177916, 1006034882339017,
+    1310151809073514, 1340093988101995, 2266390669519821, 2302361171891110,
+    3536235957216952, 3632335448106800, 3957133964588600,  193781201752361,
+     266027748959022,  317852073654772,  341148949702361,  494786704101807,
+     518225053187428,  915708290313127, 1330355940268389, 1742661425482899,
+    2459501379384431, 2504371911280247, 2765463350323262, 3275316460164142,
+    4044392386004031, 4286084334706908, 4353403886873774, 4382365579152561,
+      53295540582556, 1724747414993027, 2454627449684828, 2683441422120181,
+    3482139247749673, 3638373621867998, 4039248282099853,  252706712897855,
+     564112448445959,  592286214499040,  692057127216149,  717480028640830,
+     732192242144505, 1082680886900602, 1746912519687290, 2078642232581005,
+    2171025108867554, 2646943356162161, 3701255452869193, 3997796694907407,
+    4209318535798307,  629111462759369, 1341124631689712, 2071603961889843,
+    2153969761122404, 3204247911342218, 3379149849319196, 3389453802255860,
+    3948578827397996, 4395717135969621,  308742833731876,  674077305416456,
+     996757656134388, 2416202821937135, 3118630210089157, 3335413079581872,
+     193698740568159,  370438845587900,  728217281609257,  918622306670575,
+    1673475279929983, 2680138471466515, 3325584646196190, 3493116231872362,
+     774858204334709,  868930897464418, 1085735380893499, 1107069318538394,
+    1666201220842101, 2630211273863638, 2835744089477850, 3234391531812354,
+    3391512077321685, 3568432953445578, 4041415635201021,   60983302472861,
+     630112632797496,  771870581808866,  888052366937687, 2404299476562177,
+    2419337864014648, 2778028997621642, 2787205096060662, 3315185421351045,
+     461918887658132,  758486655890977, 3963843243485307, 1648002325274719,
+    2016299871144747, 3455264386389298, 3659054332771088, 3811894336428298,
+    4377772734233156,  314187658498382,  744108501851182, 1059292746456724,
+    1399548174795173, 1641266649692681, 2286326720922727, 3148562642306688,
+    4325893405195768,  375400304141130,  814934436918535, 2122040186007344,
+    2515631932135510, 3077012666272934, 3078832166734290,  194992720051670,
+     295267630466768, 1553950302482513, 2136044064820136, 2480410070874737,
+    2986293093585844, 3299217699389486,  781522264553518, 1541118514393610,
+    2151146324580982, 2572341032351836, 3872836846719261,  544966586043455,
+     636587079847276,  808610434480964, 1497214727875276, 2210395049369383,
+    3388399774092421, 3551626256625147, 3570365539454469, 3714848809351261,
+    4173767695694009, 4253994697677989, 4359951126002386, 2661111459812164,
+    2867138448607835, 3517653091415337,  659587281397519,  713945348906356,
+    2420628922045060, 2431566556297909, 2647125291030986, 3207902799853468,
+    3225527000056635,  108610723216295,  321687785073894,  907457881497043,
+    1624714394899447, 2238021628704533, 2777435726332530, 2903048064878498,
+       5372751566679, 1332243463010542, 1702551104962608, 1994690963595382,
+    2448618995540890, 3123637947477518, 3361294967241524, 3718302178715276,
+    4031002785542034, 4253555593592818, 4337621034185069, 4424819513098737,
+     230062404913668,  524234956016499,  588455855669803, 1061908149315380,
+    1234941464248352, 3718656856712939, 4027339236278927, 4054279043055840,
+    4320901684905245,  937025785298521, 1841299830462699, 1885308752258834,
+    2347077730846935, 2937840646570630, 3420511284076306, 4320767101979714,
+     178355017866485,  200941581368955,  678800170678237, 1108980381267429,
+    2127641272390397, 2207156979377605, 2435574041345146, 2846971741232284,
+    3060194205934527, 3743323296266670, 3868470935180769, 3988732842024499,
+    4052842724641014,  712046347566687,  775320163826499, 1094614003696540,
+    1770414004697303, 1954341726010392, 2245961867718729, 2726034903053873,
+    2785303263924404, 2899490672197635, 2908961506132555, 3597872324442603,
+     823498623388896, 1073034466089325, 1563022403506545, 3487568560504611,
+    3851437214322915, 3982313528825657, 4444256401356339,  682692682980213,
+    3160523190043330, 3587431836628032, 3641131661529359, 3893128545620553,
+    3942343478725935, 4360684164444824,     152094139147,  225917519095241,
+     292878476408762,  481600561788491, 1046984786411890, 1752554054950206,
+    2111322802237787, 2817823434943147, 2954288916899806, 3351923451580067,
+    2175919011373467, 2685583490496971, 2733549727551390,  124107062648364,
+     265231330343549,  883645347840929, 1576382815339412, 1722605024662014,
+    1806976007718348, 2379317954948293, 2827245172197277, 2958984079251353,
+    3525847876064170, 3754177499834594, 3831942477976156, 4007651008760247,
+    4048450261006031, 1344261923590991, 1824244573374059, 2226875857161478,
+    2633161861098057, 2652516158662157, 3111157011187106, 3304603706566112,
+    3317838312783675, 4354342490828429,  138332006034011,  152912571016184,
+     228598681459998, 1528997233113776, 1684937854187854, 2694982644314012,
+    3634979614032923,  172091022685327,  215842542148376, 1246313484592057,
+    2167213559079954, 2527796894570626, 3994055881638823,   29843825701693,
+     539286270751619,  824905597763432, 2463915936140190, 2716983692533154,
+    2726760068932157, 3456289368383837, 3812433004854602,  754279126283498,
+    2837721118927866, 3059261019324407, 3839764822791992,  352220624760708,
+    1335753957030661, 2438060781890049, 3206514815833589, 3342634474808392,
+     366119381246629, 1347556161689080, 2257959722716325, 3992638255707264,
+    3993871245482709,  960494797600265, 2166569148501166, 3446493407706424,
+     121237450879251,  146617124431200, 1732515143995125, 2204184287890507,
+    2533438222618662, 2785126616890814, 2873572809403792, 3200909980956944,
+    3600923031089118, 3799674965394324, 4350955114087255,  989172587253195,
+    1529532785823031, 1637941413473316, 1866422334798302, 2240129259469748,
+    2387829076078462, 2500528070097398, 2623654805961276, 3913512633830017,
+    4481744003343810,  623130763329385,  744298490597181, 1301149140162622,
+    2596026313526540, 2971837649638712, 3443244478942743, 3527868934525551,
+    1986151778747966, 2327354290479907, 3356453730416000, 3992097002552041,
+     649110740743177,  821381673193421,  858696169426014,  933308460213099,
+    1090597004955093, 1242644170128332, 1462003027855213, 2075645277673972,
+    2308889124612702, 3765940574788983, 4084193998358497,  253374315607442,
+     434219103633417, 1935354141931461, 2272954003451851, 3581381150013945,
+    4290020579967886,  359869140388613, 2777162941497176, 3748670083082756,
+     351894593272142,  487429693513410,  720232419659943, 3722609583486208,
+    3741824372915582, 3792493334467404, 4097265556454452,  275170044382037,
+     291817687642122,  293264838926991,  484401790469794,  510719705350358,
+    1304608430138415, 1391406068777659, 1494200967682702, 1791917221897904,
+    2252552451278443, 2462350089518328, 2528271612982975, 3407896928625036,
+    3924288254216631, 2442533070176595, 2698612040886004, 2908836063143291,
+     315673604582128,  474463335449906,  538234272246136,  580800955015667,
+     923693283590768, 1446200370722288, 2318586982585045,  171423188678027,
+     611350302116632, 2207116066083289, 2424703563479811, 3456251272935180,
+    3628561503451544, 4125349971210830, 4440245375030390,  360466548699217,
+    1399755977180226, 1725779623824247, 1948824640153184, 2558257084638838,
+    4501822523187331,  144512894303494,  696178164044401,  901728663788367,
+    1605565870062444, 1947552378213479, 2057158614428771, 2193935533559431,
+    3570408264059958, 3815186093404908, 3987771410384112,   65428496542883,
+    1322572649270030, 1549896242547782, 1614804858358186, 2035665532567020,
+    2613417841050036, 2965117848694282, 3718661213900241, 4153506104937877,
+     126491809534375,  494347252160553,  662609781866557, 1695963237460140,
+    1832268579797360, 1876712727675774, 1993521316959209, 2962096822812237,
+    3407137597104707,  560441700067058,  753642782532814,  811496413023025,
+     811943428525140, 1350746441224971, 1715714458442982, 1878419259147965,
+    2033243815144235, 2494478340033104, 2828005939451809, 4143516562432033,
+     108431426699136,  361076728881310,  716583736680931, 1819354278720535,
+    1831031699068093, 1988867469380808, 2259303006095506, 2826737607318716,
+    2900217131349892, 3526877848022554,  295201393595284,  300084178310694,
+     823033368686064, 4007392017100825, 4388816099506586,  801311375589164,
+    1408371199332823, 1487631468890251, 2856020587189894, 3682780179384025,
+    3925822999109777,  260275263232994,  634138397717267, 1859070908097503,
+    1876087953179116, 2204624698200581, 2950828167106977, 3158294447574293,
+    3981684230598634, 4287588236889938, 1368673301291912, 2108558623617865,
+    3059500104751196, 4178342573382227, 4352430058938868,   86630889273716,
+     857974313528729, 1750930515650347, 2482668366417971, 2895296095774665,
+      81252961876124,  181202680794079, 1682682559622326, 2387063061660512,
+    3216773938620255, 3473157469342094,   33807437148725,  618104838544027,
+    1534592722658301, 2110835002950656,  206895782325195,  314526308266304,
+    1116263839310178, 1716637097103519, 3003967076869091, 3218865604091352,
+    4004660892161995,  192282809389403,  193451926856473,  952626704937428,
+    2165040816355138, 2368147958838093, 2605357963099984, 2628869996059580,
+    4033347510928872, 4166944234058771, 4373977253347114, 2223629333632723,
+    3143310185619247, 3851901475442191, 4025075280961542, 1717858323360988,
+    1748827164366854, 1987939195028953, 2625658387507783, 3261822359019914,
+    3379405120205913, 3437790257040397, 3472126942312747,  156555011429195,
+     589052825467814, 1584260630730366, 2300916866172787, 2954986019060843,
+    4033868249157752,  755376008561726, 1821965803576299, 2010355820297314,
+    2724758311647753, 3061608562556904, 3112061384745066,  298339512537056,
+     880806606502819, 3214883805247323,  807369340843652, 1548884550461932,
+    1663706622503001, 1966268328270799, 3474266773816701, 3709512857224245,
+    1403103474882457, 1516861551783327, 1774338551194955, 2356518769278042,
+    2824697262450315, 3286895572410777, 3521715271800919, 3591447725227553,
+      54389928788375,  401579500893698, 1299166980107558, 1453603467700893,
+    1987687765111365, 3679377161121983, 3795869400973152, 4399055417849032,
+        386539277201,  618271015333614, 2237278638055292, 2360060533388967,
+    2551422466360307, 3086493145230391, 4080409026569248, 4344270979273723,
+     350474356709804, 1023647281990445, 1037869252119273, 2194244321249863,
+    3212537775454609, 3429888502002758, 3482192263105172, 3786985116576933,
+     716688135574749, 2528947928248139, 2622224835538918, 2730684550675159,
+    3579465796671285, 3723460788096536, 3960105882657952, 4052137041252727,
+    4154541906851529, 4185728852124361, 4428100657371114, 4462358746511224,
+     217266710336353,  250866556868953,  458043014098634,  695281509458462,
+     973371327001683, 1785509091244215, 1792151446870148, 2339132387129551,
+    2597502435093756, 2994446306261958, 4247716652121326,  122984767955485,
+    2830559629169346,  191537309897796,  876423023785503, 1853670630379562,
+    2100541081745402, 2875858522936800, 3272922253206668, 3732214471177556,
+    4066598768437677,  241120675323983, 1858042553186342, 1888489712364465,
+    2249772553432073, 2376810429326544, 2696507074374315, 3730546281221692,
+    3952648143963749,  444996107950514, 1909302963584200, 2261682827772322,
+    2344587992962380, 3091457564089510, 3473307048061020, 4207836043974817,
+     925890485818496, 2619333558029719,  226901691402904, 1569013175339756,
+    2146837202793441, 2766665950671141, 3221474259297737, 3331751509992028,
+    3470728138373755, 2235610564657798, 2271158612818297, 2388783619425375,
+    2572819410420294, 2968580409798284, 3764573546027067, 1455876033785442,
+    2155471013821865, 3746110345063168,  151461232725130,  315344975413713,
+     704110919022343,  829920730350564, 1083469888053498, 2150171682556513,
+    2941578734469092, 3584695516329504,  485521333890691,  527199880365910,
+     950150229240675, 1195919352303502, 1459304815956272, 1922024807744477,
+    1929733123413915, 2316759145145082,  397974097591138, 1000093171971860,
+    2414660465317462, 3083195236344290, 3209337116331225, 1078172547593312,
+    1254801635576264, 1353941814332147, 1853748373569449, 2027188423486763,
+    2173917577862313, 3164973542439287, 3942636095726396,  818404483877818,
+    1313294855043508, 1708463436074739, 2598476213043969, 3145979817582768,
+    3649292746769627,  681098036257121, 3688650755391390, 4093284275647623,
+    2536813643396937, 2760825392152426, 3455941375102822, 3496481260112214,
+    3564281231137397, 3751826339436832, 4223481729009072,  670273409846227,
+     992719937070745, 2422987790282940, 4251591648655638, 1652637637600040,
+    3250228796737971, 4191038253973913, 4487786793310910,  194183259414205,
+     475599658254484, 1966870881956408, 3049674076600440, 3222557076430503,
+    4036969216249730, 1054907200655129, 2484314426219296, 2940911779306005,
+    3313285517084330, 3600130943759087,  520370988435207,  546955147713734,
+     563381739351894,  781209937403401,  947311073472805, 1528783926365732,
+    3950931038640115, 4099495412703469, 4311834653369665,  108327830762978,
+     354043901436543, 2182986705629526, 2804152570206959, 2865765060721548,
+    2980211836231171, 4463477061412798, 1044000061256313, 1903840765510260,
+    1912019675521485, 1984809518492255, 2325196595352206, 2559697980406776,
+    3710240234073505, 4041318581521281, 4350395906651775,  715026138200193,
+    1486246755998581, 2462369455449955, 3746965272265228,  332538073605163,
+     812890125209759, 1286622417576441, 1662183228517098, 2423277717128015,
+    4018119976540852,   35003585896696,  228865858324921,  306115049348658,
+    1093974468342254, 1901160676253666, 2276744083582342, 2451180339475798,
+    3239592878750033,  813437376682340, 1053737191392916, 2588781548494255,
+    2994189240653052, 3632332852245978, 3944403707563163,   55118326942078,
+     268210125574472, 2112647174786918, 3850602184040612,  721389542311324,
+     848061946190879,  874943788320265,  992749566031495, 2656535370193607,
+    3486262948831451, 1141472127761912, 3318660639587193, 3989436382834514,
+    1055071412541410, 1423197029372163, 1924000777037260, 2874700575063953,
+    4360233298660704,  159832005729150,  460542772582696,  870871565071370,
+    1000358692936770, 2819854242034505,  188082415962095,  870962946026029,
+    1084076358594789, 2253250807860663, 2432311463684790, 3621141823172235,
+    4029836798561970,  637225921308423,  998992128589307, 1124486572936915,
+    1313650761560494, 1784384611482220, 3646630561808597, 3735038438707108,
+    4014785652188940, 4178646798489504, 2088268710125886, 2268669430004705,
+    2917559097778145, 4292186784074338, 4292519835184705, 1277320707786067,
+    1494349190192032, 1935372719675385, 2923989256797960, 1708560014208123,
+    1788950103166501, 1927110314224585, 3271534489352599, 3469751969958573,
+    3606058294747446, 4081771209646576, 1748401389043162, 2967960871333141,
+    3228772311655051, 3419414107564742, 3843358697512343, 3855987131060005,
+    4061072931172102,  922543700417415, 1473420061480963, 1569834919351424,
+    2622198814430557, 3447185081391023, 3539330204377546, 3710264020128057,
+    3999001648686492, 4272077032992801,  418727768935150, 2989277150750142,
+    3022652015854205, 4156427749610836,  447938034433076, 1255256873317643,
+    1704685276692411, 1740701954291391, 2006301863970023, 2304605624273719,
+    2831067079308564, 3036310071685141, 3315107254418522,  785369909671733,
+    2985043792479225, 3917170342005953,  278115397403968,  796635695727614,
+    1587737205176110, 2804967294596826, 2899718861818758, 2910334251929423,
+    3368366966881165, 3818070456718773, 4178875826283023,   42273961245636,
+     349475786170553, 2641910789150999, 3340781702319161, 3362329814424410,
+    3643407267444404, 4201881909592254, 4242050427876812, 4452270520327487,
+    1752111217141400, 1995875156380454, 3965129740751591,    9809854725764,
+    1097150011103350, 1634823204402231, 2292037185031540, 2978003595737547,
+    3084110339164853,   42523542818288,  950833499372990, 1248164632540216,
+    1379399541896303, 1997676998613916, 3409155463962486, 4113488808321328,
+    2030923175502366, 2667352408960807,  217688803740130,  383855764070285,
+     668745129503919,  682429698578556, 2039443009188112, 2279736929188770,
+    2361210662320407, 3753590267534657, 4362341873024782, 4417144991132428,
+     452948654421746,  551695852516429,  768434511684759,  995426036303797,
+    1145352088358209, 1652568297962437, 2672859076377732, 2853759864147928,
+    3996098646512107,   61589051400126,  256013286173867, 1741403554280933,
+    1832348121347490, 2641264426619835, 2838587876221579, 3396799221326425,
+     736552998995116,  803128835260661, 2098927548275093, 2510084547507246,
+    2948813069929191, 3487722187407546, 2297480057845670,  785257083142798,
+    1568117788125930, 2029303190432149, 2257255552767821, 3080594248306206,
+    3386562113954955, 3611196737058446,  223804642985635,  552183213290967,
+     635082483771107,  778668775308710, 1075714015044913, 1379536206283510,
+    2582200467496848, 3997317933768130,   63760334307906,  803146571194135,
+     930418270582020, 2159778690562327, 3518077047934007,  774931555122558,
+     784088004528518, 1747650059596287, 3548451518640738,  204023017805270,
+     390432411993837,  919368662742589,  931538291368300, 2554391229859820,
+    2916909012655249, 4081717446775191,  127713063512731, 3349907889987828,
+    4476631585779225,  570450984081499,  897808787119364, 2591385472755395,
+    4290025714210988, 4381985153835094,  397561120040795,  717916990147729,
+    1079887547372437, 1742543327707713, 2802397434189318, 3679497849083169,
+    4247366693937005, 4456689808028491,  263617604428317,  638136034165141,
+     890083795673685,  987566578490957,  995266052095518, 2119218251773802,
+    2535207930687062, 3530843102418669, 3549029264967352,  585816566787209,
+     737245769870711,  908089200069146, 1059859313876239, 3382843651006720,
+    2249265402983272, 3032855087092930,  419409914155582, 1796867061212415,
+    3178753100320972, 4431039029624034,  237039391663428,  822907733251877,
+     846258644140381, 1372974554153353, 1791510747751189, 3184769647194874,
+    4121011686696019, 4485302794725113, 1518089553413954, 3549513678386931,
+    3608505970342969, 3822443659848596, 4132594804635819,  207259343059552,
+     867389240194965, 1514792458617416, 2209052726178049, 2838216729662555,
+    4105808273267704, 4259175853462575,  294950043202303,  846317630092351,
+     910013900562953,  943252204887249, 1111682733825916, 1716843478524326,
+    2327861599977922, 2762163195251449, 3692657892582544, 4084091192429069,
+     535414922741513,  997534636569735, 2306354763793029, 2619704771162420,
+    2664345409371434, 3646922816183018, 4301557861223202,   45348402142983,
+      50995534244427,  847682281340228, 1265544513104106, 2288536051113498,
+    2985554060464102,   77861670098295, 1847068466433919, 2279465087952279,
+    2397193251982922, 2790487315260229, 4281633767602222,   90888009516352,
+     255729642566610,  397715520361476, 2257660568916527, 1849422446496367,
+    1917965150971218, 3667638843541896, 3769472904730305, 3829588138925722,
+    3910340752544057, 4273485339494100, 4370503422679503,    1299251632795,
+     219113695361160,  427690146196366, 1460069992201826, 1650542806427201,
+     140542137363415,  622954390084352,  898397482874391, 1322171126234370,
+    1649193913258562, 2536571754894446, 3433968638487624, 3456136677585916,
+    3608072825722366, 3611945650138492, 3646301984443902, 3923387736397757,
+    3928356976858631,  475227077780284,  891445667928693,   79270583474277,
+     296637029248874,  662651357477330, 1353659710066533, 1603848883116207,
+    1622066484117786, 2467865989347057, 2723301454223751, 4001696036205288,
+     138487869809782,  409076668982348,  678357825004771,  950024373640678,
+    2057281772379533, 2101452798698413, 2693878022618984, 2742891553135501,
+    2879508332646722, 3516275529473795, 3942583898098514, 4167317748818958,
+     198133505065995,  371006680379528,  546044196684620, 1412055679771060,
+     315427438416942,  632403674837556, 1617729981374234, 1678305002943255,
+    2673523615173990, 3594040533517217, 3609913022423085, 4069246503363399,
+    4381534480495897,  194410039202985,  251251744490606, 1362419300808401,
+    1431693931764737, 1618401991255816, 1830634144691835, 2211200546804527,
+    2560289197955099,  235835126102660, 1943924587556807, 2043319221290817,
+    2101003870096712, 2121417250542746, 2790406896684913, 3285374881531190,
+    3714898814416178, 3757503812800176,  172635989119406,  466315349492431,
+     620451206879948,  714170217414577, 1061194737642196, 1415955082185537,
+    2671668785305916, 4124058502388712, 2259200226067938, 3640346479081786,
+    3797878671833724, 4053560732075321, 1617962887170645, 2877388292636058,
+    4262542948394146,  176124713791999,  391221696248166,  639070669460394,
+    1903949664051610, 2641323839952495, 3154031954296993, 3420668350469540,
+    2390392928855736, 2937035905746507, 3126291462373815, 4460408737210206,
+     284695471866889,  419906363062667, 1965229129357692, 3645986785825379,
+    4098198474733096, 1622573584581894, 2614407650868044, 3955691910513070,
+    4258185827116811,  844263608606388, 1183872583757163, 4167367026812768,
+    4335266049407162,   10148413837176,   48328449976062,  197128612436136,
+     340360238927006,  766778642136861, 2038582938430074, 4048524775558553,
+    4118446842154216, 1372930693504078, 1554694073825608, 1766846648677734,
+    2263008312282406, 2508703751471258, 3577373396635232, 4136587824877791,
+    4190176632742329,  347237426399866,  407139468586582, 2285174399428322,
+    2698900509733070, 2830821170342951, 1705326534520387, 1738909679374886,
+    1824368236411091, 3035444248677415, 3044263717166396, 3542796036609757,
+    3958372103377104, 4380422526537721, 1658800584540196, 3677161049883232,
+    3843204221305109, 3922149123881924, 4498450351273459,  302921036089132,
+    1451620210454833, 3080266666344094, 3410177861228106,  489365811488137,
+    3579310856049598,  397053684671534, 1289888062788180, 1529647529089606,
+    2951687349707926,   24217225115576,  961455470592863, 1793034970596612,
+    2646226442063055, 3259674166454520,  453881661537873,  735279393796745,
+     783349770726382,  923930742657458, 1786052699649671, 3020786147720235,
+    3606908944286811, 3655655247953712, 4466708385571332,  238609815638402,
+     908647737694320, 2056323197437595, 2287606070876937, 2576168823041138,
+    3507364008400822, 4474012926562317,  414404815118887,  657761036921946,
+     725744726406240, 1611397382939456, 1747933601415131, 4166939815754873,
+    1641532135442855, 1674680054215075, 1678755263671719, 1792155728170897,
+      30613050131256,  862261321750715, 3449437715341079, 4249157290689691,
+     327449040616300,  564561782164544,  745117729404537,  931873929026613,
+    1386996622182169, 1578643466132879, 2902967662892166, 3163030475054490,
+    3255234545140193, 3519264497077331, 4008457631107274,  129610799299314,
+     281738525508099, 1878553547840160, 3987448897910452,   86806376180687,
+     390699303938300,  919160048087000, 2024748261467408, 4031116921933926,
+     191340227462470, 1103229255389051, 3363763070744934, 1647180327701532,
+    1801484184321712, 2352487281648497, 3370624526040416,  147435471443413,
+     311820264475026,  568079023836934, 2443573334704016, 3247979204437438,
+    3418050427636615, 3711793647367085, 4183481457843874,  183151531787505,
+     682372549839619, 1982312296571446, 3106614249038198, 3751995581958775,
+    3759439618133556, 4112294006470077,   45892241846152, 2286309390106137,
+    3238648899941687, 4012657715531289, 4097200627626898, 4127623225513696,
+    4268803492953102,   14796690478909,  837218758625045,  846720048561208,
+    1971100597160200, 2279805573114207, 4335756603604329, 4339069430578245,
+     323878940818598,  743536111752507, 2161380863812517, 3046931345373821,
+    1629115097362678, 3806458836549133,   88219271167001,  441479412036211,
+     560571218961218, 1513104271772409, 1942753958468981, 3520230402463390,
+    3542940754874138, 3786778377987167,   17296099287861,  256375709045411,
+    1506030632821739, 2093130968783050, 2131446583250050, 2965368247994551,
+    3209001521163756, 3284794718837747, 3568073846012114, 4212038483649065,
+    4368595101909574,  601299760589846, 1839383368797140, 2224309891345846,
+    2359440221506078, 2390203730116467, 2427650857322215, 3197976461664720,
+     263646859631124, 1039376930516782, 1221979324549974, 2691189596383783,
+    2787251754110817, 3808527931534071,  213781556482386, 1484528734796971,
+    2706962870623628, 2806278908225398, 3063543698079978, 4249979822889062,
+     107334897734072,  221485955296142, 3042507456236872, 3706242950938795,
+    3977263046387028, 4104563236872850, 4371754718229342,  547717881690599,
+     763506510110478,  846335044071602,  914970099001835, 1034099239089656,
+    2872863612457680, 2984858188641415, 3368726610759840,   99332854922247,
+     137719205908263, 1505309591014017, 2222733121197431, 2236721267832845,
+    2744502387137548, 3393440195041985, 4479278182842134,  217611204829500,
+     886812078077062, 3126866688726160, 3884755437593338,   71027466249524,
+     372606720364322, 1052162634034504, 2417496327693317, 3312162978142808,
+    3507414437208375,  813550684214712, 1247715718708472, 1792972456316860,
+    2225656936169549, 4471827308358572,  470564053231853, 1955376639766955,
+    2245537840770587, 1099424492106512, 1962242024869049, 1993834722674570,
+    3621388692325695, 1565040764318691, 2068504991060112, 2096273987032157,
+    3016388100197654, 2015647140176336, 2162483381782063, 2167467359017732,
+    1239690135909765, 1350747453216538, 1783091022345636, 2467895192746144,
+    4160918758101536,  128329049567259,  501612537576262, 1613400020887100,
+    2402051295913014, 3995393351651720,  567705020579461,  826305944682121,
+    2613787324215198,  193236708827870,  636578824212303, 1511676020185687,
+    1620961977162101, 2130149967233900, 2897903513148851, 3473006149523726,
+    4182747890232333, 4351135191298268, 4457377513053688,  721390941011764,
+    1067642271788472, 1395510949685897, 2760990046189354, 3249309780699780,
+    3520165806721962, 3628652048052834, 4460034075274330,  611700239856174,
+    2184582739921155, 3009414761972100, 3164623456564693,  144981682791189,
+     791279643104015, 1874999465140296, 3502722416475737,  397587699858173,
+    1567303479984850, 3274343683073593, 3505071471328990,  709674287352736,
+    3567436532760707, 3626128194510927, 1410338187738553, 1466321230281452,
+    3310113791209530, 3836818894254319,  324988491360875,  533653362844851,
+    2678293251406242, 3397627650054741,  598352729404096,  794618078575559,
+    2015664010216527, 2417864980179932, 2596593567345345, 3599824072806295,
+    4348546547750983,  679711528643262, 1530914517964033, 3846750536867286,
+    4397949142073018, 2839641325231222, 3105845442676449, 3357262680292373,
+    3392088626487917, 3620116637600870,
+];
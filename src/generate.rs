@@ -0,0 +1,171 @@
+use crate::custom::CustomFamily;
+use crate::decode;
+use crate::family;
+
+use std::{error, fmt};
+
+const PRIME: u64 = 982_451_653;
+const MAX_UNBOUNDED_GRID: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct Generator {
+    pub grid: usize,
+    pub min_hamming: u32,
+    pub min_complexity: usize,
+    pub seed: u64,
+    pub limit: usize,
+    pub candidates: u64,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenError {
+    Grid(usize),
+    Unbounded(usize),
+}
+
+impl fmt::Display for GenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenError::Grid(grid) => write!(f, "a {grid}x{grid} grid must hold between 1 and 64 bits"),
+            GenError::Unbounded(grid) => {
+                write!(f, "a {grid}x{grid} search is too large to run unbounded; pass --candidates")
+            }
+        }
+    }
+}
+
+impl error::Error for GenError {}
+
+impl Generator {
+    pub fn new(grid: usize, min_hamming: u32) -> Self {
+        Self {
+            grid,
+            min_hamming,
+            min_complexity: grid * grid / 4 + 1,
+            seed: 0,
+            limit: usize::MAX,
+            candidates: u64::MAX,
+            name: None,
+        }
+    }
+
+    pub fn generate(&self) -> Result<CustomFamily, GenError> {
+        self.generate_with(|_| {})
+    }
+
+    pub fn generate_with(&self, mut progress: impl FnMut(&[u64])) -> Result<CustomFamily, GenError> {
+        let n = self.grid * self.grid;
+
+        if n == 0 || n > 64 {
+            return Err(GenError::Grid(self.grid));
+        }
+
+        if self.grid > MAX_UNBOUNDED_GRID && self.candidates == u64::MAX {
+            return Err(GenError::Unbounded(self.grid));
+        }
+
+        let bits = family::grid_bits(self.grid, 1);
+        let perm = decode::rot90_perm(&bits, self.grid + 2);
+
+        let mask = if n == 64 { u64::MAX } else { (1 << n) - 1 };
+        let start = splitmix(self.seed ^ (n as u64 * 10000 + self.min_hamming as u64 * 100 + 7)) & mask;
+
+        let mut codes = Vec::new();
+        let mut rotated = Vec::new();
+
+        for i in 0..=mask {
+            if i >= self.candidates {
+                break;
+            }
+
+            let v = start.wrapping_add(i.wrapping_mul(PRIME)) & mask;
+
+            if complexity(v, self.grid) < self.min_complexity {
+                continue;
+            }
+
+            if rotated.iter().any(|&c: &u64| (v ^ c).count_ones() < self.min_hamming) {
+                continue;
+            }
+
+            let r1 = decode::rot90_code(v, &perm);
+            let r2 = decode::rot90_code(r1, &perm);
+            let r3 = decode::rot90_code(r2, &perm);
+
+            let rots = [v, r1, r2, r3];
+
+            if rots[1..].iter().any(|&r| (v ^ r).count_ones() < self.min_hamming) {
+                continue;
+            }
+
+            codes.push(v);
+            rotated.extend(rots);
+
+            progress(&codes);
+
+            if codes.len() >= self.limit {
+                break;
+            }
+        }
+
+        Ok(CustomFamily {
+            name: self.name.clone().unwrap_or_else(|| format!("custom{}h{}_{}", n, self.min_hamming, self.seed)),
+            grid: self.grid,
+            border: 1,
+            bits,
+            codes,
+            min_hamming: self.min_hamming,
+            reversed_border: false,
+        })
+    }
+}
+
+fn complexity(code: u64, grid: usize) -> usize {
+    let n = grid * grid;
+    let bit = |x: usize, y: usize| (code >> (n - 1 - (y * grid + x))) & 1;
+
+    let mut covered = 0_u64;
+    let free = |covered: u64, x: usize, y: usize| covered & (1 << (y * grid + x)) == 0;
+
+    let mut rects = 0;
+
+    for y in 0..grid {
+        for x in 0..grid {
+            if !free(covered, x, y) {
+                continue;
+            }
+
+            let color = bit(x, y);
+
+            let mut w = 1;
+            while x + w < grid && free(covered, x + w, y) && bit(x + w, y) == color {
+                w += 1;
+            }
+
+            let mut h = 1;
+            while y + h < grid
+                && (x..x + w).all(|xx| free(covered, xx, y + h) && bit(xx, y + h) == color)
+            {
+                h += 1;
+            }
+
+            for yy in y..y + h {
+                for xx in x..x + w {
+                    covered |= 1 << (yy * grid + xx);
+                }
+            }
+
+            rects += 1;
+        }
+    }
+
+    rects
+}
+
+fn splitmix(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,127 @@
+use crate::process::DetectError;
+
+pub trait Pixel: Copy + Send + Sync {
+    fn lightness(self) -> f32;
+
+    fn is_nan(self) -> bool {
+        false
+    }
+}
+
+impl Pixel for u8 {
+    fn lightness(self) -> f32 {
+        self as f32 / 255.0
+    }
+}
+
+impl Pixel for u16 {
+    fn lightness(self) -> f32 {
+        self as f32 / 65535.0
+    }
+}
+
+impl Pixel for f32 {
+    fn lightness(self) -> f32 {
+        self
+    }
+
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+}
+
+impl Pixel for [u8; 2] {
+    fn lightness(self) -> f32 {
+        self[0] as f32 / 255.0
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ImageView<'a, T> {
+    data: &'a [T],
+    width: usize,
+    height: usize,
+    stride: usize,
+    origin: (usize, usize),
+    frame: (usize, usize),
+}
+
+impl<'a, T: Pixel> ImageView<'a, T> {
+    pub fn new(data: &'a [T], width: usize, height: usize) -> Result<Self, DetectError> {
+        Self::with_stride(data, width, height, width)
+    }
+
+    pub fn with_stride(data: &'a [T], width: usize, height: usize, stride: usize) -> Result<Self, DetectError> {
+        if width == 0 || height == 0 || stride < width {
+            return Err(DetectError::Dimensions { w: width, h: height });
+        }
+
+        let expected =
+            (height - 1)
+                .checked_mul(stride)
+                .and_then(|l| l.checked_add(width))
+                .ok_or(DetectError::Dimensions { w: width, h: height })?;
+
+        if data.len() < expected {
+            return Err(DetectError::Length { expected, actual: data.len() });
+        }
+
+        Ok(Self {
+            data,
+            width,
+            height,
+            stride,
+            origin: (0, 0),
+            frame: (width, height),
+        })
+    }
+
+    pub fn roi(&self, x: usize, y: usize, width: usize, height: usize) -> Result<Self, DetectError> {
+        let fits =
+            width > 0
+                && height > 0
+                && x.checked_add(width).is_some_and(|r| r <= self.width)
+                && y.checked_add(height).is_some_and(|b| b <= self.height);
+
+        if !fits {
+            return Err(DetectError::Dimensions { w: width, h: height });
+        }
+
+        Ok(Self {
+            data: &self.data[y * self.stride + x..],
+            width,
+            height,
+            stride: self.stride,
+            origin: (self.origin.0 + x, self.origin.1 + y),
+            frame: self.frame,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    pub fn origin(&self) -> (usize, usize) {
+        self.origin
+    }
+
+    pub fn frame(&self) -> (usize, usize) {
+        self.frame
+    }
+
+    pub(crate) fn row(&self, y: usize) -> &'a [T] {
+        &self.data[y * self.stride..y * self.stride + self.width]
+    }
+
+    pub(crate) fn get(&self, x: usize, y: usize) -> f32 {
+        self.data[y * self.stride + x].lightness()
+    }
+}
@@ -0,0 +1,107 @@
+use crate::process::DetectError;
+use crate::types::Lightness;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Gray,
+    Nv12,
+    Yuyv,
+    Rgb,
+    Bgr,
+    Bayer(Bayer),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bayer {
+    Rggb,
+    Bggr,
+    Grbg,
+    Gbrg,
+}
+
+pub(crate) const R: usize = 0;
+pub(crate) const G: usize = 1;
+pub(crate) const B: usize = 2;
+
+const LUMA: [f32; 3] = [0.299, 0.587, 0.114];
+
+impl Format {
+    pub fn bytes_per_pixel(self) -> usize {
+        match self {
+            Format::Gray | Format::Nv12 | Format::Bayer(_) => 1,
+            Format::Yuyv => 2,
+            Format::Rgb | Format::Bgr => 3,
+        }
+    }
+}
+
+impl Bayer {
+    fn color(self, x: usize, y: usize) -> usize {
+        let pattern = match self {
+            Bayer::Rggb => [R, G, G, B],
+            Bayer::Bggr => [B, G, G, R],
+            Bayer::Grbg => [G, R, B, G],
+            Bayer::Gbrg => [G, B, R, G],
+        };
+
+        pattern[(y % 2) * 2 + x % 2]
+    }
+}
+
+pub(crate) fn check(format: Format, data: &[u8], w: usize, h: usize, stride: usize) -> Result<(), DetectError> {
+    let row =
+        w.checked_mul(format.bytes_per_pixel())
+            .filter(|&r| w > 0 && h > 0 && stride >= r)
+            .ok_or(DetectError::Dimensions { w, h })?;
+
+    let expected =
+        (h - 1)
+            .checked_mul(stride)
+            .and_then(|l| l.checked_add(row))
+            .ok_or(DetectError::Dimensions { w, h })?;
+
+    if data.len() < expected {
+        return Err(DetectError::Length { expected, actual: data.len() });
+    }
+
+    Ok(())
+}
+
+pub(crate) fn rgb(order: [usize; 3], data: &[u8], w: usize, h: usize, stride: usize, out: &mut Lightness) {
+    out.clear();
+
+    for y in 0..h {
+        let row = &data[y * stride..y * stride + 3 * w];
+
+        out.extend(row.chunks_exact(3).map(|p| {
+            (0..3).map(|c| LUMA[order[c]] * p[c] as f32).sum::<f32>() / 255.0
+        }));
+    }
+}
+
+pub(crate) fn bayer(pattern: Bayer, data: &[u8], w: usize, h: usize, stride: usize, out: &mut Lightness) {
+    out.clear();
+
+    for y in 0..h {
+        for x in 0..w {
+            let mut sum = [0.0; 3];
+            let mut n = [0; 3];
+
+            for ny in y.saturating_sub(1)..=(y + 1).min(h - 1) {
+                for nx in x.saturating_sub(1)..=(x + 1).min(w - 1) {
+                    let c = pattern.color(nx, ny);
+
+                    sum[c] += data[ny * stride + nx] as f32;
+                    n[c] += 1;
+                }
+            }
+
+            let l =
+                (0..3)
+                    .map(|c| if n[c] > 0 { LUMA[c] * sum[c] / n[c] as f32 } else { 0.0 })
+                    .sum::<f32>();
+
+            out.push(l / 255.0);
+        }
+    }
+}
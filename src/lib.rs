@@ -1,16 +1,41 @@
 mod candidates;
 mod config;
+mod custom;
 mod decode;
 mod detector;
+mod family;
 mod filters;
+mod generate;
 mod hm;
+mod image;
+mod input;
 mod mask;
 mod post;
+mod prefilter;
 mod process;
+mod refine;
+mod render;
 mod shapes;
+mod table;
+mod threshold;
 mod types;
 mod uf;
 
-pub use config::Config;
+pub use config::{Blur, Config, ConfigError, Hysteresis, Prefilter, Sampling, Segmentation};
+pub use custom::{CustomFamily, FamilyError};
 pub use detector::Detector;
-pub use types::{Tag, Point2D, Point3D};
+pub use family::{
+    builtin_family, Family, TagFamily,
+    TAG16H5, TAG25H9, TAG36H11, TAG_CIRCLE21H7, TAG_STANDARD41H12, TAG_STANDARD52H13,
+    DICT_4X4_50, DICT_4X4_100, DICT_4X4_250, DICT_4X4_1000,
+    DICT_5X5_50, DICT_5X5_100, DICT_5X5_250, DICT_5X5_1000,
+    DICT_6X6_50, DICT_6X6_100, DICT_6X6_250, DICT_6X6_1000,
+    DICT_7X7_50, DICT_7X7_100, DICT_7X7_250, DICT_7X7_1000,
+    DICT_ARUCO_ORIGINAL,
+};
+pub use generate::{GenError, Generator};
+pub use image::{ImageView, Pixel};
+pub use input::{Bayer, Format};
+pub use process::DetectError;
+pub use render::{tag_cells, Cells, Render, RenderError};
+pub use types::{Tag, Point2D, Point3D, Thresholds};
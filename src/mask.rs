@@ -1,10 +1,64 @@
-use crate::config::Config;
+use crate::config::{Blur, Config, Hysteresis, Segmentation};
 use crate::detector::Workspace;
-use crate::post;
+use crate::image::{ImageView, Pixel};
+use crate::{post, prefilter, threshold};
 use crate::types::{Dim, Lightness};
 
-pub fn canny(config: &Config, dim: Dim, img: &Lightness, ws: &mut Workspace) {
-    blur(dim, img, &mut ws.bh, &mut ws.blur);
+use std::mem;
+
+const BINOMIAL: [f32; 5] = [1.0 / 16.0, 4.0 / 16.0, 6.0 / 16.0, 4.0 / 16.0, 1.0 / 16.0];
+
+pub fn segment<T: Pixel>(config: &Config, dim: Dim, img: &ImageView<T>, ws: &mut Workspace) {
+    if config.prefilters.is_empty() {
+        return edges(config, dim, img, ws);
+    }
+
+    let mut pre = mem::take(&mut ws.pre);
+
+    pre.clear();
+
+    for y in 0..dim.h {
+        pre.extend(img.row(y).iter().map(|p| p.lightness()));
+    }
+
+    prefilter::apply(config, dim, &mut pre, &mut ws.bh, &mut ws.blur);
+
+    if let Ok(view) = ImageView::new(&pre, dim.w, dim.h) {
+        edges(config, dim, &view, ws);
+    }
+
+    ws.pre = pre;
+}
+
+fn edges<T: Pixel>(config: &Config, dim: Dim, img: &ImageView<T>, ws: &mut Workspace) {
+    match config.segmentation {
+        Segmentation::Canny => canny(config, dim, img, ws),
+        Segmentation::Adaptive => {
+            ws.thresholds = None;
+            ws.smooth = None;
+
+            threshold::adaptive(config, dim, img, ws);
+
+            if config.subpixel {
+                gradient(config, dim, img, ws);
+            }
+        }
+    }
+}
+
+pub fn gradient<T: Pixel>(config: &Config, dim: Dim, img: &ImageView<T>, ws: &mut Workspace) {
+    let gaussian;
+    let kernel: &[f32] =
+        match config.blur {
+            Blur::Binomial => &BINOMIAL,
+            Blur::Gaussian { sigma, radius } => {
+                gaussian = kernel(sigma, radius);
+                &gaussian
+            }
+            Blur::Off => &[1.0],
+        };
+
+    blur(dim, img, kernel, false, &mut ws.bh, &mut ws.blur);
     sobel(dim, &ws.blur, &mut ws.gx, &mut ws.gy);
 
     for i in 0..dim.len() {
@@ -12,6 +66,10 @@ pub fn canny(config: &Config, dim: Dim, img: &Lightness, ws: &mut Workspace) {
         let gy = ws.gy[i];
         ws.mag[i] = (gx * gx + gy * gy).sqrt();
     }
+}
+
+pub fn canny<T: Pixel>(config: &Config, dim: Dim, img: &ImageView<T>, ws: &mut Workspace) {
+    gradient(config, dim, img, ws);
 
     for i in 0..dim.len() {
         let gx = ws.gx[i];
@@ -32,46 +90,100 @@ pub fn canny(config: &Config, dim: Dim, img: &Lightness, ws: &mut Workspace) {
     }
 
     post::nms(dim, &ws.mag, &ws.orient, &mut ws.supp);
-    post::hysteresis(config, dim, &ws.supp, &mut ws.strong, &mut ws.weak, &mut ws.dq, &mut ws.edges);
-}
 
-pub fn blur(dim: Dim, img: &Lightness, bh: &mut Lightness, out: &mut Lightness) {
-    let w = dim.w;
-    let h = dim.h;
+    let prev =
+        match ws.smooth {
+            Some((w, h, t)) if (w, h) == (dim.w, dim.h) && config.hyst_mode == Hysteresis::Otsu => Some(t),
+            _ => None,
+        };
 
-    for y in 0..h {
-        let r = y * w;
+    let refs = post::reference(config, dim, &ws.mag, &ws.supp, prev);
+    ws.thresholds = refs.thresholds();
 
-        for x in 2..w - 2 {
-            let i = x + r;
+    ws.smooth =
+        match config.hyst_mode {
+            Hysteresis::Otsu => ws.thresholds.map(|t| (dim.w, dim.h, t)),
+            _ => None,
+        };
 
-            let p = (
-                img[i - 2],
-                img[i - 1],
-                img[i],
-                img[i + 1],
-                img[i + 2],
-            );
+    post::hysteresis(dim, &ws.supp, &refs, &mut ws.strong, &mut ws.weak, &mut ws.dq, &mut ws.edges);
+}
 
-            bh[i] = (p.0 + 4.0 * p.1 + 6.0 * p.2 + 4.0 * p.3 + p.4) / 16.0;
+pub fn decimate<T: Pixel>(img: &ImageView<T>, factor: usize, out: &mut Lightness) -> Dim {
+    let w = img.width() / factor;
+    let h = img.height() / factor;
+
+    out.clear();
+    out.resize(w * h, 0.0);
+
+    for y in 0..h * factor {
+        let row = img.row(y);
+        let r = (y / factor) * w;
+
+        for x in 0..w * factor {
+            out[r + x / factor] += row[x].lightness();
         }
     }
 
-    for y in 2..h - 2 {
-        let r = y * w;
+    let n = (factor * factor) as f32;
+    out.iter_mut().for_each(|l| *l /= n);
+
+    Dim { w, h }
+}
+
+pub fn kernel(sigma: f32, radius: usize) -> Lightness {
+    if sigma.is_nan() || sigma <= 0.0 {
+        return vec![1.0];
+    }
+
+    let r = if radius == 0 { (3.0 * sigma).ceil() as isize } else { radius as isize };
+
+    let k =
+        (-r..=r)
+            .map(|i| (-((i * i) as f32) / (2.0 * sigma * sigma)).exp())
+            .collect::<Lightness>();
+
+    let sum = k.iter().sum::<f32>();
+
+    k.into_iter().map(|v| v / sum).collect()
+}
+
+pub fn blur<T: Pixel>(dim: Dim, img: &ImageView<T>, kernel: &[f32], clamp: bool, bh: &mut Lightness, out: &mut Lightness) {
+    let w = dim.w;
+    let h = dim.h;
+    let r = kernel.len() / 2;
+
+    for y in 0..h {
+        let row = img.row(y);
 
         for x in 0..w {
-            let i = x + r;
+            bh[x + y * w] =
+                if clamp || (x >= r && x + r < w) {
+                    kernel
+                        .iter()
+                        .enumerate()
+                        .map(|(k, &wt)| wt * row[(x + k).saturating_sub(r).min(w - 1)].lightness())
+                        .sum()
+                } else {
+                    0.0
+                };
+        }
+    }
 
-            let p = (
-                bh[i - 2 * w],
-                bh[i - w],
-                bh[i],
-                bh[i + w],
-                bh[i + 2 * w],
-            );
+    for y in 0..h {
+        let inside = clamp || (y >= r && y + r < h);
 
-            out[i] = (p.0 + 4.0 * p.1 + 6.0 * p.2 + 4.0 * p.3 + p.4) / 16.0;
+        for x in 0..w {
+            out[x + y * w] =
+                if inside {
+                    kernel
+                        .iter()
+                        .enumerate()
+                        .map(|(k, &wt)| wt * bh[(y + k).saturating_sub(r).min(h - 1) * w + x])
+                        .sum()
+                } else {
+                    0.0
+                };
         }
     }
 }
@@ -80,10 +192,10 @@ pub fn sobel(dim: Dim, img: &Lightness, gx: &mut Lightness, gy: &mut Lightness)
     let w = dim.w;
     let h = dim.h;
 
-    for y in 1..h - 1 {
+    for y in 1..h.saturating_sub(1) {
         let r = y * w;
 
-        for x in 1..w - 1 {
+        for x in 1..w.saturating_sub(1) {
             let i = x + r;
 
             let p = (
@@ -1,8 +1,10 @@
-use crate::config::Config;
-use crate::types::{Dim, Lightness, Mask};
+use crate::config::{Config, Hysteresis};
+use crate::types::{Dim, Lightness, Mask, Thresholds};
 
 use std::collections::VecDeque;
 
+const BINS: usize = 1024;
+
 pub fn nms(dim: Dim, mag: &Lightness, orient: &[(i8, i8)], supp: &mut Lightness) {
     let w = dim.w;
     let h = dim.h;
@@ -30,25 +32,40 @@ pub fn nms(dim: Dim, mag: &Lightness, orient: &[(i8, i8)], supp: &mut Lightness)
     }
 }
 
+pub struct Reference {
+    tile: usize,
+    cols: usize,
+    high: Lightness,
+    low: Lightness,
+}
+
+impl Reference {
+    pub fn thresholds(&self) -> Option<Thresholds> {
+        match (self.high.as_slice(), self.low.as_slice()) {
+            (&[high], &[low]) => Some(Thresholds { high, low }),
+            _ => None,
+        }
+    }
+}
+
 pub fn hysteresis(
-    config: &Config,
     dim: Dim,
     edges: &Lightness,
+    refs: &Reference,
     strong: &mut Mask,
     weak: &mut Mask,
     dq: &mut VecDeque<(usize, usize)>,
     out: &mut Mask,
 ) {
-    let max = edges.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
-
-    let high = config.hyst_high * max;
-    let low = config.hyst_low * max;
-
     let w = dim.w;
     let h = dim.h;
 
     dq.clear();
 
+    if w == 0 || h == 0 {
+        return;
+    }
+
     for y in 0..h {
         let r = y * w;
 
@@ -56,6 +73,10 @@ pub fn hysteresis(
             let i = x + r;
 
             let v = edges[i];
+            let t = (y / refs.tile) * refs.cols + x / refs.tile;
+
+            let high = refs.high[t];
+            let low = refs.low[t];
 
             let str = v > high;
             let wk = v > low && v <= high;
@@ -85,3 +106,156 @@ pub fn hysteresis(
         }
     }
 }
+
+pub fn reference(config: &Config, dim: Dim, mag: &Lightness, edges: &Lightness, prev: Option<Thresholds>) -> Reference {
+    let w = dim.w;
+    let h = dim.h;
+
+    let max = edges.iter().fold(0.0, |a: f32, &b| a.max(b));
+
+    let (tile, cols, refs) = match config.hyst_mode {
+        Hysteresis::Global => (w.max(h), 1, vec![max]),
+        Hysteresis::Percentile => {
+            let mut hist = [0_usize; BINS];
+            let mut n = 0;
+
+            for &v in edges {
+                if v > 0.0 {
+                    hist[((v / max * BINS as f32) as usize).min(BINS - 1)] += 1;
+                    n += 1;
+                }
+            }
+
+            let target = (config.hyst_percentile.clamp(0.0, 1.0) * n as f32).ceil() as usize;
+            let mut seen = 0;
+            let mut bin = BINS - 1;
+
+            for (b, &count) in hist.iter().enumerate() {
+                seen += count;
+
+                if seen >= target {
+                    bin = b;
+                    break;
+                }
+            }
+
+            let value = (bin + 1) as f32 / BINS as f32 * max;
+
+            (w.max(h), 1, vec![value.max(config.hyst_floor)])
+        }
+        Hysteresis::Tiled => {
+            let tile = config.hyst_tile.max(1);
+            let cols = w.div_ceil(tile);
+            let rows = h.div_ceil(tile);
+
+            let mut tiles = vec![0.0_f32; cols * rows];
+
+            for y in 0..h {
+                for x in 0..w {
+                    let t = (y / tile) * cols + x / tile;
+                    tiles[t] = tiles[t].max(edges[y * w + x]);
+                }
+            }
+
+            let mut refs = vec![0.0; cols * rows];
+
+            for ty in 0..rows {
+                for tx in 0..cols {
+                    let mut m = config.hyst_floor;
+
+                    for ny in ty.saturating_sub(1)..=(ty + 1).min(rows - 1) {
+                        for nx in tx.saturating_sub(1)..=(tx + 1).min(cols - 1) {
+                            m = m.max(tiles[ny * cols + nx]);
+                        }
+                    }
+
+                    refs[ty * cols + tx] = m;
+                }
+            }
+
+            (tile, cols, refs)
+        }
+        Hysteresis::Otsu => return otsu(config, dim, mag, edges, prev),
+    };
+
+    Reference {
+        tile,
+        cols,
+        high: refs.iter().map(|r| config.hyst_high * r).collect(),
+        low: refs.iter().map(|r| config.hyst_low * r).collect(),
+    }
+}
+
+fn otsu(config: &Config, dim: Dim, mag: &Lightness, edges: &Lightness, prev: Option<Thresholds>) -> Reference {
+    let (lo, hi) =
+        mag.iter()
+            .zip(edges)
+            .filter(|&(_, &e)| e > 0.0)
+            .fold((f32::INFINITY, 0.0_f32), |(lo, hi), (&v, _)| (lo.min(v), hi.max(v)));
+
+    let (lo, hi) = (lo.ln(), hi.ln());
+    let span = hi - lo;
+
+    let mut hist = [0_usize; BINS];
+    let mut total = 0.0;
+
+    if span > 0.0 {
+        for (&v, &e) in mag.iter().zip(edges) {
+            if e > 0.0 {
+                hist[((((v.ln() - lo) / span) * BINS as f32) as usize).min(BINS - 1)] += 1;
+                total += 1.0;
+            }
+        }
+    }
+
+    let sum = hist.iter().enumerate().map(|(b, &c)| (b * c) as f64).sum::<f64>();
+
+    let (mut wb, mut sb) = (0.0, 0.0);
+    let (mut best, mut split) = (0.0, 0);
+
+    for (b, &count) in hist.iter().enumerate() {
+        wb += count as f64;
+        sb += (b * count) as f64;
+
+        let wf = total - wb;
+
+        if wb == 0.0 || wf == 0.0 {
+            continue;
+        }
+
+        let diff = sb / wb - (sum - sb) / wf;
+        let var = wb * wf * diff * diff;
+
+        if var > best {
+            best = var;
+            split = b;
+        }
+    }
+
+    let ratio =
+        if config.hyst_high > 0.0 {
+            (config.hyst_low / config.hyst_high).min(1.0)
+        } else {
+            0.5
+        };
+
+    let high = if span > 0.0 { (lo + (split + 1) as f32 / BINS as f32 * span).exp() } else { 0.0 };
+    let high = high.max(config.hyst_high * config.hyst_floor);
+    let low = high * ratio;
+
+    let (high, low) =
+        match prev {
+            Some(p) => {
+                let s = config.hyst_smoothing.clamp(0.0, 1.0);
+                (s * p.high + (1.0 - s) * high, s * p.low + (1.0 - s) * low)
+            }
+            None => (high, low),
+        };
+
+    Reference {
+        tile: dim.w.max(dim.h),
+        cols: 1,
+        high: vec![high],
+        low: vec![low],
+    }
+}
@@ -0,0 +1,130 @@
+use crate::config::{Config, Prefilter};
+use crate::image::ImageView;
+use crate::mask;
+use crate::types::{Dim, Lightness};
+
+use std::mem;
+
+const BINS: usize = 256;
+
+pub fn apply(config: &Config, dim: Dim, img: &mut Lightness, bh: &mut Lightness, tmp: &mut Lightness) {
+    for &filter in &config.prefilters {
+        match filter {
+            Prefilter::Median { radius } => median(dim, img, radius, tmp),
+            Prefilter::Unsharp { sigma, amount } => unsharp(dim, img, sigma, amount, bh, tmp),
+            Prefilter::Clahe { tile, clip } => clahe(dim, img, tile, clip, tmp),
+            Prefilter::Gamma(g) => img.iter_mut().for_each(|l| *l = l.max(0.0).powf(g)),
+        }
+    }
+}
+
+fn median(dim: Dim, img: &mut Lightness, radius: usize, tmp: &mut Lightness) {
+    if radius == 0 {
+        return;
+    }
+
+    let w = dim.w;
+    let h = dim.h;
+
+    let mut window = Vec::with_capacity((2 * radius + 1) * (2 * radius + 1));
+
+    for y in 0..h {
+        for x in 0..w {
+            window.clear();
+
+            for ny in y.saturating_sub(radius)..=(y + radius).min(h - 1) {
+                let r = ny * w;
+                window.extend(&img[r + x.saturating_sub(radius)..=r + (x + radius).min(w - 1)]);
+            }
+
+            let mid = window.len() / 2;
+            let (_, m, _) = window.select_nth_unstable_by(mid, f32::total_cmp);
+
+            tmp[y * w + x] = *m;
+        }
+    }
+
+    mem::swap(img, tmp);
+}
+
+fn unsharp(dim: Dim, img: &mut Lightness, sigma: f32, amount: f32, bh: &mut Lightness, tmp: &mut Lightness) {
+    let Ok(view) = ImageView::new(img.as_slice(), dim.w, dim.h) else {
+        return;
+    };
+
+    mask::blur(dim, &view, &mask::kernel(sigma, 0), true, bh, tmp);
+
+    for (l, &b) in img.iter_mut().zip(tmp.iter()) {
+        *l += amount * (*l - b);
+    }
+}
+
+fn clahe(dim: Dim, img: &mut Lightness, tile: usize, clip: f32, tmp: &mut Lightness) {
+    let w = dim.w;
+    let h = dim.h;
+
+    let tile = tile.max(1);
+    let cols = w.div_ceil(tile);
+    let rows = h.div_ceil(tile);
+
+    let bin = |l: f32| ((l.clamp(0.0, 1.0) * BINS as f32) as usize).min(BINS - 1);
+
+    let mut luts = vec![[0.0_f32; BINS]; cols * rows];
+
+    for ty in 0..rows {
+        for tx in 0..cols {
+            let mut hist = [0.0_f32; BINS];
+            let mut n = 0.0;
+
+            for y in ty * tile..((ty + 1) * tile).min(h) {
+                for x in tx * tile..((tx + 1) * tile).min(w) {
+                    hist[bin(img[y * w + x])] += 1.0;
+                    n += 1.0;
+                }
+            }
+
+            let limit = clip * n / BINS as f32;
+            let mut excess = 0.0;
+
+            for c in hist.iter_mut() {
+                if *c > limit {
+                    excess += *c - limit;
+                    *c = limit;
+                }
+            }
+
+            let mut sum = 0.0;
+            let lut = &mut luts[ty * cols + tx];
+
+            for (b, c) in hist.iter().enumerate() {
+                sum += c + excess / BINS as f32;
+                lut[b] = sum / n;
+            }
+        }
+    }
+
+    let center = |p: usize, n: usize| {
+        let t = (p as f32 + 0.5) / tile as f32 - 0.5;
+        let i = (t.floor().max(0.0) as usize).min(n - 1);
+
+        (i, (i + 1).min(n - 1), (t - i as f32).clamp(0.0, 1.0))
+    };
+
+    for y in 0..h {
+        let (y0, y1, fy) = center(y, rows);
+
+        for x in 0..w {
+            let (x0, x1, fx) = center(x, cols);
+
+            let b = bin(img[y * w + x]);
+            let at = |tx: usize, ty: usize| luts[ty * cols + tx][b];
+
+            let top = at(x0, y0) * (1.0 - fx) + at(x1, y0) * fx;
+            let bottom = at(x0, y1) * (1.0 - fx) + at(x1, y1) * fx;
+
+            tmp[y * w + x] = top * (1.0 - fy) + bottom * fy;
+        }
+    }
+
+    mem::swap(img, tmp);
+}
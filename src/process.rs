@@ -1,39 +1,352 @@
-use crate::{candidates, decode, mask};
-use crate::config::Config;
-use crate::detector::Detector;
-use crate::types::{Corners, Dim, Lightness, Mask, Point2D, Point3D, Tag};
+use crate::{candidates, decode, mask, refine};
+use crate::config::{Config, ConfigError};
+use crate::detector::{Detector, Workspace};
+use crate::image::{ImageView, Pixel};
+use crate::input::{self, Format, B, G, R};
+use crate::types::{Corners, Dim, Lightness, Mask, Point2D, Point3D, Quads, Tag};
 
-use std::mem;
+use std::{error, fmt, mem};
 
 const TAG_M: f64 = 0.2;
+const MERGE_OVERLAP: f64 = 0.5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DetectError {
+    Dimensions { w: usize, h: usize },
+    Length { expected: usize, actual: usize },
+    NaN { index: usize },
+    Config(ConfigError),
+    Family { family: String, reason: String },
+}
+
+impl fmt::Display for DetectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DetectError::Dimensions { w, h } => write!(f, "invalid image dimensions {w}x{h}"),
+            DetectError::Length { expected, actual } => {
+                write!(f, "expected {expected} pixels but got {actual}")
+            }
+            DetectError::NaN { index } => write!(f, "pixel {index} is NaN"),
+            DetectError::Config(e) => write!(f, "invalid config: {e}"),
+            DetectError::Family { family, reason } => write!(f, "invalid family {family}: {reason}"),
+        }
+    }
+}
+
+impl error::Error for DetectError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            DetectError::Config(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ConfigError> for DetectError {
+    fn from(e: ConfigError) -> Self {
+        DetectError::Config(e)
+    }
+}
 
 impl Detector {
+    #[deprecated(note = "errors, including invalid configs, are dropped; use try_tags")]
     pub fn tags(&mut self, w: usize, h: usize, config: &Config, data: &Lightness) -> Vec<Tag> {
-        let dim = Dim { w, h };
-        self.ws.ensure(dim);
+        self.try_tags(w, h, config, data).unwrap_or_default()
+    }
+
+    pub fn try_tags(&mut self, w: usize, h: usize, config: &Config, data: &Lightness) -> Result<Vec<Tag>, DetectError> {
+        let expected =
+            w.checked_mul(h)
+                .filter(|&l| l > 0)
+                .ok_or(DetectError::Dimensions { w, h })?;
+
+        if data.len() != expected {
+            return Err(DetectError::Length { expected, actual: data.len() });
+        }
+
+        self.detect(config, &ImageView::new(data, w, h)?)
+    }
+
+    pub fn detect_raw(
+        &mut self,
+        config: &Config,
+        format: Format,
+        data: &[u8],
+        w: usize,
+        h: usize,
+        stride: usize,
+    ) -> Result<Vec<Tag>, DetectError> {
+        input::check(format, data, w, h, stride)?;
+
+        match format {
+            Format::Gray | Format::Nv12 => self.detect(config, &ImageView::with_stride(data, w, h, stride)?),
+            Format::Yuyv => {
+                if !stride.is_multiple_of(2) {
+                    return Err(DetectError::Dimensions { w, h });
+                }
+
+                let (pairs, _) = data.as_chunks::<2>();
+                self.detect(config, &ImageView::with_stride(pairs, w, h, stride / 2)?)
+            }
+            Format::Rgb => self.detect_converted(config, w, h, |buf| input::rgb([R, G, B], data, w, h, stride, buf)),
+            Format::Bgr => self.detect_converted(config, w, h, |buf| input::rgb([B, G, R], data, w, h, stride, buf)),
+            Format::Bayer(pattern) => {
+                self.detect_converted(config, w, h, |buf| input::bayer(pattern, data, w, h, stride, buf))
+            }
+        }
+    }
+
+    fn detect_converted(
+        &mut self,
+        config: &Config,
+        w: usize,
+        h: usize,
+        convert: impl FnOnce(&mut Lightness),
+    ) -> Result<Vec<Tag>, DetectError> {
+        let mut buf = mem::take(&mut self.ws.input);
+        convert(&mut buf);
+
+        let tags = self.detect(config, &ImageView::new(&buf, w, h)?);
+        self.ws.input = buf;
+
+        tags
+    }
+
+    pub fn detect<T: Pixel>(&mut self, config: &Config, img: &ImageView<T>) -> Result<Vec<Tag>, DetectError> {
+        config.validate()?;
+
+        for y in 0..img.height() {
+            if let Some(x) = img.row(y).iter().position(|p| p.is_nan()) {
+                return Err(DetectError::NaN { index: y * img.stride() + x });
+            }
+        }
+
+        let tables = self.ensure_tables(config)?;
+
+        let levels = config.pyramid;
+        let mut candidates = Vec::new();
+        let mut run = 0;
+
+        for level in 0..levels {
+            let factor = config.decimate << level;
 
-        mask::canny(config, dim, data, &mut self.ws);
+            if factor > img.width().min(img.height()) {
+                break;
+            }
+
+            candidates.extend(self.quads(config, img, level, factor).into_iter().map(|q| (level, q)));
+            run += 1;
+        }
+
+        self.reset_levels(run);
 
-        let candidates = candidates::candidates(config, dim, &self.ws.edges);
         let half_fov_tan = (config.fov.to_radians() / 2.0).tan();
 
-        candidates
-            .into_iter()
-            .map(|corners| {
-                let id = decode::decode(dim, data, corners);
+        let (fw, fh) = img.frame();
+        let frame = Dim { w: fw, h: fh };
+
+        let tags =
+            candidates
+                .into_iter()
+                .map(|(level, corners)| {
+                    let decoded =
+                        config.families
+                            .iter()
+                            .zip(&tables)
+                            .filter_map(|(family, &table)| {
+                                decode::decode(config, img, corners, self.table(table))
+                                    .map(|d| (family, d))
+                            })
+                            .min_by_key(|(_, d)| d.hamming);
+
+                    let corners = translate(corners, img.origin());
+
+                    let rot = rotation(corners);
+                    let pos = position(corners, frame, half_fov_tan);
+
+                    let Some((family, d)) = decoded else {
+                        return (level, Tag {
+                            id: None,
+                            family: None,
+                            hamming: 0,
+                            decision_margin: 0.0,
+                            border_score: 0.0,
+                            rotation: 0,
+                            inverted: false,
+                            mirrored: false,
+                            rot,
+                            pos,
+                            corners,
+                        });
+                    };
+
+                    (level, Tag {
+                        id: Some(d.id),
+                        family: Some(family.name().to_string()),
+                        hamming: d.hamming,
+                        decision_margin: d.margin,
+                        border_score: d.border,
+                        rotation: d.rotation,
+                        inverted: d.inverted,
+                        mirrored: d.mirrored,
+                        rot,
+                        pos,
+                        corners,
+                    })
+                })
+                .collect::<Vec<_>>();
+
+        if levels > 1 {
+            Ok(merge(tags))
+        } else {
+            Ok(tags.into_iter().map(|(_, tag)| tag).collect())
+        }
+    }
+
+    fn quads<T: Pixel>(&mut self, config: &Config, img: &ImageView<T>, level: usize, factor: usize) -> Quads {
+        let ws = self.workspace(level);
+
+        if factor == 1 {
+            let dim = Dim { w: img.width(), h: img.height() };
+            ws.ensure(dim);
+
+            mask::segment(config, dim, img, ws);
+            return candidates(config, img, ws);
+        }
+
+        let mut small = mem::take(&mut ws.small);
+        let dim = mask::decimate(img, factor, &mut small);
 
-                let rot = rotation(corners);
-                let pos = position(corners, dim, half_fov_tan);
+        let quads =
+            match ImageView::new(&small, dim.w, dim.h) {
+                Ok(view) => {
+                    ws.ensure(dim);
 
-                Tag { id, rot, pos, corners }
+                    mask::segment(config, dim, &view, ws);
+                    candidates(config, &view, ws)
+                }
+                Err(_) => Vec::new(),
+            };
+
+        ws.small = small;
+
+        let scale = |p: Point2D| {
+            let f = factor as f64;
+            Point2D(p.0 * f + (f - 1.0) / 2.0, p.1 * f + (f - 1.0) / 2.0)
+        };
+
+        quads
+            .into_iter()
+            .map(|(tl, tr, bl, br)| {
+                let corners = (scale(tl), scale(tr), scale(bl), scale(br));
+                refine::refine(img, corners, 2.0 * factor as f64)
             })
             .collect()
     }
 
     pub fn process(&mut self, w: usize, h: usize, config: &Config, data: &Lightness) -> (Vec<Tag>, Mask) {
-        let tags = self.tags(w, h, config, data);
-        (tags, mem::take(&mut self.ws.edges))
+        let Ok(tags) = self.try_tags(w, h, config, data) else {
+            return (Vec::new(), Vec::new());
+        };
+
+        let mut mask = vec![0; w * h];
+
+        for level in 0..config.pyramid {
+            let factor = config.decimate << level;
+
+            if factor > w.min(h) {
+                break;
+            }
+
+            let edges = &self.workspace(level).edges;
+            let (sw, sh) = (w / factor, h / factor);
+
+            if edges.len() != sw * sh {
+                continue;
+            }
+
+            for y in 0..sh * factor {
+                let row = (y / factor) * sw;
+
+                for x in 0..sw * factor {
+                    mask[y * w + x] |= edges[row + x / factor];
+                }
+            }
+        }
+
+        (tags, mask)
+    }
+}
+
+fn candidates<T: Pixel>(config: &Config, img: &ImageView<T>, ws: &Workspace) -> Quads {
+    let dim = Dim { w: img.width(), h: img.height() };
+
+    candidates::candidates(config, dim, &ws.edges)
+        .into_iter()
+        .map(|q| if config.subpixel { refine::lines(img, &ws.edges, &ws.mag, &ws.gx, &ws.gy, q) } else { q })
+        .collect()
+}
+
+fn merge(mut tags: Vec<(usize, Tag)>) -> Vec<Tag> {
+    tags.sort_by(|(la, a), (lb, b)| {
+        b.id.is_some()
+            .cmp(&a.id.is_some())
+            .then(a.hamming.cmp(&b.hamming))
+            .then(b.decision_margin.total_cmp(&a.decision_margin))
+            .then(la.cmp(lb))
+    });
+
+    let mut kept: Vec<Tag> = Vec::new();
+
+    for (_, tag) in tags {
+        let duplicate =
+            kept.iter().any(|k| {
+                let same = tag.id.is_none() || (k.id == tag.id && k.family == tag.family);
+                same && overlap(k.corners, tag.corners) > MERGE_OVERLAP
+            });
+
+        if !duplicate {
+            kept.push(tag);
+        }
     }
+
+    kept
+}
+
+fn overlap(a: Corners, b: Corners) -> f64 {
+    let bounds = |(tl, tr, bl, br): Corners| {
+        let xs = [tl.0, tr.0, bl.0, br.0];
+        let ys = [tl.1, tr.1, bl.1, br.1];
+
+        (
+            xs.iter().copied().fold(f64::INFINITY, f64::min),
+            ys.iter().copied().fold(f64::INFINITY, f64::min),
+            xs.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            ys.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        )
+    };
+
+    let (ax0, ay0, ax1, ay1) = bounds(a);
+    let (bx0, by0, bx1, by1) = bounds(b);
+
+    let iw = (ax1.min(bx1) - ax0.max(bx0)).max(0.0);
+    let ih = (ay1.min(by1) - ay0.max(by0)).max(0.0);
+
+    let inter = iw * ih;
+    let union = (ax1 - ax0) * (ay1 - ay0) + (bx1 - bx0) * (by1 - by0) - inter;
+
+    if union > 0.0 { inter / union } else { 0.0 }
+}
+
+fn translate((tl, tr, bl, br): Corners, (x, y): (usize, usize)) -> Corners {
+    let (x, y) = (x as f64, y as f64);
+
+    (
+        Point2D(tl.0 + x, tl.1 + y),
+        Point2D(tr.0 + x, tr.1 + y),
+        Point2D(bl.0 + x, bl.1 + y),
+        Point2D(br.0 + x, br.1 + y),
+    )
 }
 
 fn rotation((tl, tr, bl, br): Corners) -> f32 {
@@ -0,0 +1,280 @@
+use crate::decode;
+use crate::image::{ImageView, Pixel};
+use crate::types::{Corners, Dim, Lightness, Mask, Point2D};
+
+const SAMPLES: usize = 12;
+const STEP: f64 = 0.5;
+
+const BAND: f64 = 2.0;
+const MIN_PIXELS: usize = 4;
+const MIN_ALIGN: f64 = 0.8;
+
+struct Line {
+    p: Point2D,
+    d: Point2D,
+}
+
+pub fn refine<T: Pixel>(img: &ImageView<T>, corners: Corners, range: f64) -> Corners {
+    let (tl, tr, bl, br) = corners;
+
+    let top = edge(img, tl, tr, range);
+    let right = edge(img, tr, br, range);
+    let bottom = edge(img, br, bl, range);
+    let left = edge(img, bl, tl, range);
+
+    corners_from(corners, (top, right, bottom, left), 2.0 * range + 1.0)
+}
+
+pub fn lines<T: Pixel>(
+    img: &ImageView<T>,
+    edges: &Mask,
+    mag: &Lightness,
+    gx: &Lightness,
+    gy: &Lightness,
+    corners: Corners,
+) -> Corners {
+    let (tl, tr, bl, br) = corners;
+    let dim = Dim { w: img.width(), h: img.height() };
+
+    let side = |a: Point2D, b: Point2D| {
+        let dx = b.0 - a.0;
+        let dy = b.1 - a.1;
+        let len = dx.hypot(dy);
+
+        if len < 1.0 {
+            return None;
+        }
+
+        let (nx, ny) = (-dy / len, dx / len);
+        let band = BAND + 0.02 * len;
+
+        let mut pts = Vec::new();
+
+        let mut visit = |x: isize, y: isize| {
+            if x < 1 || y < 1 || x as usize + 1 >= dim.w || y as usize + 1 >= dim.h {
+                return;
+            }
+
+            let i = y as usize * dim.w + x as usize;
+            let m = mag[i] as f64;
+
+            if edges[i] == 0 || m <= 0.0 {
+                return;
+            }
+
+            let (px, py) = (x as f64, y as f64);
+            let t = ((px - a.0) * dx + (py - a.1) * dy) / (len * len);
+            let d = (px - a.0) * nx + (py - a.1) * ny;
+
+            let (ux, uy) = (gx[i] as f64 / m, gy[i] as f64 / m);
+            let align = ux * nx + uy * ny;
+
+            if !(0.1..=0.9).contains(&t) || d.abs() > band || align.abs() < MIN_ALIGN {
+                return;
+            }
+
+            let at = |s: f64| decode::bilinear(img, Point2D(px + ux * s, py + uy * s));
+            let grad = |s: f64| Some((at(s + STEP)? - at(s - STEP)?) as f64);
+            let profile = [-2.0, -1.0, 0.0, 1.0, 2.0].map(grad);
+
+            let offset =
+                (1..=3)
+                    .filter_map(|k| Some((k, profile[k - 1]?, profile[k]?, profile[k + 1]?)))
+                    .max_by(|p, q| p.2.total_cmp(&q.2))
+                    .map_or(0.0, |(k, lo, mid, hi)| k as f64 - 2.0 + peak(lo, mid, hi));
+
+            pts.push((Point2D(px + ux * offset, py + uy * offset), m, align.signum()));
+        };
+
+        if dx.abs() >= dy.abs() {
+            let (x0, x1) = (a.0.min(b.0).floor() as isize, a.0.max(b.0).ceil() as isize);
+            let r = (band * len / dx.abs()).ceil() as isize;
+
+            for x in x0..=x1 {
+                let yc = (a.1 + (x as f64 - a.0) * dy / dx).round() as isize;
+
+                for y in yc - r..=yc + r {
+                    visit(x, y);
+                }
+            }
+        } else {
+            let (y0, y1) = (a.1.min(b.1).floor() as isize, a.1.max(b.1).ceil() as isize);
+            let r = (band * len / dy.abs()).ceil() as isize;
+
+            for y in y0..=y1 {
+                let xc = (a.0 + (y as f64 - a.1) * dx / dy).round() as isize;
+
+                for x in xc - r..=xc + r {
+                    visit(x, y);
+                }
+            }
+        }
+
+        let sign = pts.iter().map(|&(_, m, s)| m * s).sum::<f64>().signum();
+
+        let pts =
+            pts.into_iter()
+                .filter(|&(_, _, s)| s == sign)
+                .map(|(p, m, _)| (p, m))
+                .collect::<Vec<_>>();
+
+        if pts.len() < MIN_PIXELS {
+            return None;
+        }
+
+        fit(&pts)
+    };
+
+    let top = side(tl, tr);
+    let right = side(tr, br);
+    let bottom = side(br, bl);
+    let left = side(bl, tl);
+
+    corners_from(corners, (top, right, bottom, left), 2.0 * BAND + 1.0)
+}
+
+fn corners_from(corners: Corners, sides: (Option<Line>, Option<Line>, Option<Line>, Option<Line>), reach: f64) -> Corners {
+    let (tl, tr, bl, br) = corners;
+
+    let (Some(top), Some(right), Some(bottom), Some(left)) = sides else {
+        return corners;
+    };
+
+    let refined = (
+        intersect(&left, &top),
+        intersect(&top, &right),
+        intersect(&bottom, &left),
+        intersect(&right, &bottom),
+    );
+
+    let (Some(ntl), Some(ntr), Some(nbl), Some(nbr)) = refined else {
+        return corners;
+    };
+
+    let close = |a: Point2D, b: Point2D| (a.0 - b.0).hypot(a.1 - b.1) <= reach;
+
+    if close(ntl, tl) && close(ntr, tr) && close(nbl, bl) && close(nbr, br) {
+        (ntl, ntr, nbl, nbr)
+    } else {
+        corners
+    }
+}
+
+fn edge<T: Pixel>(img: &ImageView<T>, a: Point2D, b: Point2D, range: f64) -> Option<Line> {
+    let dx = b.0 - a.0;
+    let dy = b.1 - a.1;
+    let len = dx.hypot(dy);
+
+    if len < 1.0 {
+        return None;
+    }
+
+    let (nx, ny) = (-dy / len, dx / len);
+    let at = |x: f64, y: f64| decode::bilinear(img, Point2D(x, y));
+
+    let mut rise = Vec::with_capacity(SAMPLES);
+    let mut fall = Vec::with_capacity(SAMPLES);
+
+    for k in 0..SAMPLES {
+        let t = 0.1 + 0.8 * (k as f64 + 0.5) / SAMPLES as f64;
+        let (px, py) = (a.0 + dx * t, a.1 + dy * t);
+
+        let grad = |s: f64| {
+            let hi = at(px + nx * (s + STEP), py + ny * (s + STEP))?;
+            let lo = at(px + nx * (s - STEP), py + ny * (s - STEP))?;
+
+            Some(hi - lo)
+        };
+
+        let mut up: Option<(f32, f64)> = None;
+        let mut down: Option<(f32, f64)> = None;
+        let mut s = -range;
+
+        while s <= range {
+            if let Some(g) = grad(s) {
+                if up.is_none_or(|(m, _)| g > m) {
+                    up = Some((g, s));
+                }
+                if down.is_none_or(|(m, _)| -g > m) {
+                    down = Some((-g, s));
+                }
+            }
+
+            s += STEP;
+        }
+
+        let point = |sign: f32| {
+            move |(g, s): (f32, f64)| {
+                let offset =
+                    match (grad(s - STEP), grad(s + STEP)) {
+                        (Some(lo), Some(hi)) => peak((sign * lo) as f64, g as f64, (sign * hi) as f64),
+                        _ => 0.0,
+                    };
+
+                let s = s + offset * STEP;
+
+                (g, Point2D(px + nx * s, py + ny * s))
+            }
+        };
+
+        rise.extend(up.map(point(1.0)));
+        fall.extend(down.map(point(-1.0)));
+    }
+
+    let strength = |v: &[(f32, Point2D)]| v.iter().map(|&(g, _)| g).sum::<f32>();
+    let best = if strength(&rise) >= strength(&fall) { rise } else { fall };
+
+    let pts = best.into_iter().map(|(g, p)| (p, g.max(0.0) as f64)).collect::<Vec<_>>();
+
+    fit(&pts)
+}
+
+fn peak(lo: f64, mid: f64, hi: f64) -> f64 {
+    let den = lo - 2.0 * mid + hi;
+
+    if den < 0.0 {
+        (0.5 * (lo - hi) / den).clamp(-0.5, 0.5)
+    } else {
+        0.0
+    }
+}
+
+fn fit(pts: &[(Point2D, f64)]) -> Option<Line> {
+    let n = pts.iter().map(|&(_, w)| w).sum::<f64>();
+
+    if pts.len() < 2 || n <= 0.0 {
+        return None;
+    }
+
+    let mx = pts.iter().map(|(p, w)| w * p.0).sum::<f64>() / n;
+    let my = pts.iter().map(|(p, w)| w * p.1).sum::<f64>() / n;
+
+    let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+
+    for (p, w) in pts {
+        let (x, y) = (p.0 - mx, p.1 - my);
+
+        sxx += w * x * x;
+        sxy += w * x * y;
+        syy += w * y * y;
+    }
+
+    let angle = 0.5 * (2.0 * sxy).atan2(sxx - syy);
+
+    Some(Line {
+        p: Point2D(mx, my),
+        d: Point2D(angle.cos(), angle.sin()),
+    })
+}
+
+fn intersect(a: &Line, b: &Line) -> Option<Point2D> {
+    let det = a.d.0 * b.d.1 - a.d.1 * b.d.0;
+
+    if det.abs() < 1e-6 {
+        return None;
+    }
+
+    let t = ((b.p.0 - a.p.0) * b.d.1 - (b.p.1 - a.p.1) * b.d.0) / det;
+
+    Some(Point2D(a.p.0 + a.d.0 * t, a.p.1 + a.d.1 * t))
+}
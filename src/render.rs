@@ -0,0 +1,521 @@
+use crate::family::TagFamily;
+
+use std::{error, fmt};
+use std::fmt::Write;
+
+const GLYPHS: [u16; 10] = [
+    0b111_101_101_101_111,
+    0b010_110_010_010_111,
+    0b111_001_111_100_111,
+    0b111_001_111_001_111,
+    0b101_101_111_001_001,
+    0b111_100_111_001_111,
+    0b111_100_111_101_111,
+    0b111_001_010_010_010,
+    0b111_101_111_101_111,
+    0b111_101_111_001_111,
+];
+
+const LETTERS: [u16; 26] = [
+    0b010_101_111_101_101,
+    0b110_101_110_101_110,
+    0b011_100_100_100_011,
+    0b110_101_101_101_110,
+    0b111_100_110_100_111,
+    0b111_100_110_100_100,
+    0b011_100_101_101_011,
+    0b101_101_111_101_101,
+    0b111_010_010_010_111,
+    0b001_001_001_101_010,
+    0b101_101_110_101_101,
+    0b100_100_100_100_111,
+    0b101_111_111_101_101,
+    0b110_101_101_101_101,
+    0b010_101_101_101_010,
+    0b110_101_110_100_100,
+    0b010_101_101_110_011,
+    0b110_101_110_101_101,
+    0b011_100_010_001_110,
+    0b111_010_010_010_010,
+    0b101_101_101_101_111,
+    0b101_101_101_101_010,
+    0b101_101_111_111_101,
+    0b101_101_010_101_101,
+    0b101_101_010_010_010,
+    0b111_001_010_100_111,
+];
+
+const UNDERSCORE: u16 = 0b000_000_000_000_111;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Render {
+    pub cell: usize,
+    pub quiet: usize,
+    pub border: usize,
+    pub columns: usize,
+    pub captions: bool,
+}
+
+impl Default for Render {
+    fn default() -> Self {
+        Self {
+            cell: 10,
+            quiet: 1,
+            border: 0,
+            columns: 1,
+            captions: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenderError {
+    Empty,
+    Id { id: u32, count: usize },
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::Empty => f.write_str("no tag ids to render"),
+            RenderError::Id { id, count } => write!(f, "tag id {id} is out of range for a family of {count} codes"),
+        }
+    }
+}
+
+impl error::Error for RenderError {}
+
+pub struct Cells {
+    pub size: usize,
+    pub white: Vec<bool>,
+}
+
+struct Rect {
+    x: usize,
+    y: usize,
+    w: usize,
+    h: usize,
+    shade: u8,
+}
+
+struct Label {
+    x: usize,
+    y: usize,
+    size: usize,
+    text: String,
+}
+
+struct Scene {
+    w: usize,
+    h: usize,
+    rects: Vec<Rect>,
+    labels: Vec<Label>,
+}
+
+pub fn tag_cells(family: &dyn TagFamily, id: u32) -> Option<Cells> {
+    let code = *family.codes().get(id as usize)?;
+
+    let bits = family.bits();
+    let n = bits.len();
+    let width = family.width() as i32;
+
+    let lo = bits.iter().map(|&(x, y)| x.min(y)).min().unwrap_or(0).min(0);
+    let hi = bits.iter().map(|&(x, y)| x.max(y)).max().unwrap_or(0).max(width - 1);
+
+    let border = family.border() as i32;
+    let reversed = family.reversed_border();
+
+    let size = (hi - lo + 1) as usize;
+    let mut white = vec![false; size * size];
+
+    for y in lo..=hi {
+        for x in lo..=hi {
+            let depth = x.min(y).min(width - 1 - x).min(width - 1 - y);
+
+            let val = match bits.iter().position(|&b| b == (x, y)) {
+                Some(i) => (code >> (n - 1 - i)) & 1 == 1,
+                None if depth < 0 => !reversed || depth < -1,
+                None => reversed && depth < border,
+            };
+
+            white[(y - lo) as usize * size + (x - lo) as usize] = val;
+        }
+    }
+
+    Some(Cells { size, white })
+}
+
+impl Render {
+    pub fn png(&self, family: &dyn TagFamily, ids: &[u32]) -> Result<Vec<u8>, RenderError> {
+        let scene = self.scene(family, ids)?;
+        let mut px = vec![255_u8; scene.w * scene.h];
+
+        for r in &scene.rects {
+            for y in r.y..r.y + r.h {
+                px[y * scene.w + r.x..y * scene.w + r.x + r.w].fill(r.shade);
+            }
+        }
+
+        for label in &scene.labels {
+            let dot = (label.size / 5).max(1);
+
+            for (k, c) in label.text.chars().enumerate() {
+                let glyph = glyph(c);
+                let gx = label.x + k * 4 * dot;
+
+                for gy in 0..5 {
+                    for dx in 0..3 {
+                        if glyph >> (14 - (gy * 3 + dx)) & 1 == 0 {
+                            continue;
+                        }
+
+                        for y in label.y + gy * dot..label.y + (gy + 1) * dot {
+                            let x = gx + dx * dot;
+
+                            if y < scene.h && x + dot <= scene.w {
+                                px[y * scene.w + x..y * scene.w + x + dot].fill(0);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(png(scene.w, scene.h, &px))
+    }
+
+    pub fn svg(&self, family: &dyn TagFamily, ids: &[u32]) -> Result<String, RenderError> {
+        let scene = self.scene(family, ids)?;
+        let mut out = String::new();
+
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" shape-rendering="crispEdges">"#,
+            w = scene.w,
+            h = scene.h,
+        );
+        let _ = writeln!(out, r#"<rect width="{}" height="{}" fill="white"/>"#, scene.w, scene.h);
+
+        for r in &scene.rects {
+            let _ = writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="rgb({s},{s},{s})"/>"#,
+                r.x, r.y, r.w, r.h,
+                s = r.shade,
+            );
+        }
+
+        for label in &scene.labels {
+            let _ = writeln!(
+                out,
+                r#"<text x="{}" y="{}" font-family="sans-serif" font-size="{}">{}</text>"#,
+                label.x,
+                label.y + label.size,
+                label.size,
+                xml_escape(&label.text),
+            );
+        }
+
+        out.push_str("</svg>\n");
+
+        Ok(out)
+    }
+
+    pub fn pdf(&self, family: &dyn TagFamily, ids: &[u32]) -> Result<Vec<u8>, RenderError> {
+        let scene = self.scene(family, ids)?;
+        let mut content = String::new();
+
+        for r in &scene.rects {
+            let _ = writeln!(
+                content,
+                "{g:.3} g {} {} {} {} re f",
+                r.x,
+                scene.h - r.y - r.h,
+                r.w,
+                r.h,
+                g = r.shade as f32 / 255.0,
+            );
+        }
+
+        for label in &scene.labels {
+            let _ = writeln!(
+                content,
+                "0 g BT /F1 {} Tf {} {} Td ({}) Tj ET",
+                label.size,
+                label.x,
+                scene.h - label.y - label.size,
+                pdf_escape(&label.text),
+            );
+        }
+
+        let objects = [
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Contents 4 0 R /Resources << /Font << /F1 5 0 R >> >> >>",
+                scene.w, scene.h,
+            ),
+            format!("<< /Length {} >>\nstream\n{}endstream", content.len(), content),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+        ];
+
+        let mut out = String::from("%PDF-1.4\n");
+        let mut offsets = Vec::new();
+
+        for (i, obj) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            let _ = writeln!(out, "{} 0 obj\n{}\nendobj", i + 1, obj);
+        }
+
+        let xref = out.len();
+
+        let _ = writeln!(out, "xref\n0 {}\n0000000000 65535 f ", objects.len() + 1);
+        for off in offsets {
+            let _ = writeln!(out, "{:010} 00000 n ", off);
+        }
+        let _ = write!(
+            out,
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref,
+        );
+
+        Ok(out.into_bytes())
+    }
+
+    fn scene(&self, family: &dyn TagFamily, ids: &[u32]) -> Result<Scene, RenderError> {
+        if ids.is_empty() {
+            return Err(RenderError::Empty);
+        }
+
+        let tags =
+            ids.iter()
+                .map(|&id| tag_cells(family, id).ok_or(RenderError::Id { id, count: family.codes().len() }))
+                .collect::<Result<Vec<_>, _>>()?;
+
+        let cell = self.cell.max(1);
+        let size = tags.first().map_or(0, |t| t.size);
+
+        let block = (size + 2 * self.quiet) * cell;
+        let caption = if self.captions { cell.max(10) } else { 0 };
+
+        let columns = self.columns.clamp(1, ids.len().max(1));
+        let rows = ids.len().div_ceil(columns);
+
+        let mut scene = Scene {
+            w: columns * block,
+            h: rows * (block + caption),
+            rects: Vec::new(),
+            labels: Vec::new(),
+        };
+
+        for (k, (tag, &id)) in tags.iter().zip(ids).enumerate() {
+            let ox = (k % columns) * block;
+            let oy = (k / columns) * (block + caption);
+
+            let b = self.border.min(self.quiet * cell);
+
+            if b > 0 {
+
+                scene.rects.push(Rect { x: ox, y: oy, w: block, h: b, shade: 160 });
+                scene.rects.push(Rect { x: ox, y: oy + block - b, w: block, h: b, shade: 160 });
+                scene.rects.push(Rect { x: ox, y: oy, w: b, h: block, shade: 160 });
+                scene.rects.push(Rect { x: ox + block - b, y: oy, w: b, h: block, shade: 160 });
+            }
+
+            let tx = ox + self.quiet * cell;
+            let ty = oy + self.quiet * cell;
+
+            for y in 0..tag.size {
+                let mut x = 0;
+
+                while x < tag.size {
+                    if tag.white[y * tag.size + x] {
+                        x += 1;
+                        continue;
+                    }
+
+                    let start = x;
+                    while x < tag.size && !tag.white[y * tag.size + x] {
+                        x += 1;
+                    }
+
+                    scene.rects.push(Rect {
+                        x: tx + start * cell,
+                        y: ty + y * cell,
+                        w: (x - start) * cell,
+                        h: cell,
+                        shade: 0,
+                    });
+                }
+            }
+
+            if self.captions {
+                scene.labels.push(Label {
+                    x: tx,
+                    y: oy + block,
+                    size: caption * 4 / 5,
+                    text: format!("{} {}", family.name(), id),
+                });
+            }
+        }
+
+        Ok(scene)
+    }
+}
+
+fn glyph(c: char) -> u16 {
+    match c.to_ascii_uppercase() {
+        d @ '0'..='9' => GLYPHS[d as usize - '0' as usize],
+        l @ 'A'..='Z' => LETTERS[l as usize - 'A' as usize],
+        '_' => UNDERSCORE,
+        _ => 0,
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+fn pdf_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        if matches!(c, '\\' | '(' | ')') {
+            out.push('\\');
+        }
+
+        out.push(c);
+    }
+
+    out
+}
+
+fn png(w: usize, h: usize, px: &[u8]) -> Vec<u8> {
+    let mut raw = Vec::with_capacity((w + 1) * h);
+    for row in px.chunks_exact(w.max(1)) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(65535).peekable();
+
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+
+    while let Some(block) = blocks.next() {
+        let len = block.len() as u16;
+
+        zlib.push(if blocks.peek().is_none() { 1 } else { 0 });
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&(w as u32).to_be_bytes());
+    ihdr.extend_from_slice(&(h as u32).to_be_bytes());
+    ihdr.extend_from_slice(&[8, 0, 0, 0, 0]);
+
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+
+    chunk(&mut out, b"IHDR", &ihdr);
+    chunk(&mut out, b"IDAT", &zlib);
+    chunk(&mut out, b"IEND", &[]);
+
+    out
+}
+
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+
+    let crc = crc32(kind.iter().chain(data));
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32<'a>(data: impl Iterator<Item = &'a u8>) -> u32 {
+    let mut crc = !0_u32;
+
+    for &b in data {
+        crc ^= b as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+
+    for &x in data {
+        a = (a + x as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tag_cells;
+    use crate::config::Config;
+    use crate::detector::Detector;
+    use crate::family::{TagFamily, BUILTINS};
+
+    use std::sync::Arc;
+
+    const CELL: usize = 6;
+    const QUIET: usize = 2;
+
+    #[test]
+    fn builtin_families_decode() {
+        for family in BUILTINS {
+            let config = Config { families: vec![Arc::new(family)], max_hamming: 0, ..Default::default() };
+            let mut detector = Detector::new();
+
+            for id in [0, family.codes().len() as u32 - 1] {
+                let cells = tag_cells(&family, id).unwrap();
+                let side = (cells.size + 2 * QUIET) * CELL;
+
+                let img =
+                    (0..side * side)
+                        .map(|i| {
+                            let (x, y) = ((i % side) / CELL, (i / side) / CELL);
+                            let inside = (QUIET..QUIET + cells.size).contains(&x) && (QUIET..QUIET + cells.size).contains(&y);
+
+                            if !inside || cells.white[(y - QUIET) * cells.size + x - QUIET] { 1.0 } else { 0.0 }
+                        })
+                        .collect::<Vec<f32>>();
+
+                let tags = detector.try_tags(side, side, &config, &img).unwrap();
+
+                assert!(
+                    tags.iter().any(|t| t.id == Some(id) && t.family.as_deref() == Some(family.name)),
+                    "{} id {id} did not decode",
+                    family.name,
+                );
+            }
+        }
+    }
+}
@@ -12,10 +12,10 @@ pub fn find_shapes(dim: Dim, edges: &Mask) -> Quads {
     let w = dim.w;
     let h = dim.h;
 
-    for y in 2..h - 2 {
+    for y in 2..h.saturating_sub(2) {
         let r = y * w;
 
-        for x in 2..w - 2 {
+        for x in 2..w.saturating_sub(2) {
             let i = x + r;
 
             if edges[i] == 0 {
@@ -0,0 +1,206 @@
+use crate::config::Config;
+use crate::decode;
+use crate::family::TagFamily;
+
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::mem;
+
+type Layout = Vec<(i32, i32)>;
+
+const MAX_ENTRIES: usize = 1 << 21;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Entry {
+    pub id: u32,
+    pub rotation: u8,
+    pub hamming: u8,
+}
+
+pub struct CodeTable {
+    pub name: String,
+    pub fingerprint: u64,
+    pub max_hamming: u32,
+    pub width: usize,
+    pub layout: Layout,
+    pub perm: Vec<usize>,
+    pub mirror: Vec<usize>,
+    pub dark: Layout,
+    pub light: Layout,
+    lookup: Lookup,
+}
+
+enum Lookup {
+    Map(HashMap<u64, Entry>),
+    Scan(Vec<(u64, Entry)>),
+}
+
+impl CodeTable {
+    pub fn new(family: &dyn TagFamily, max_hamming: u32) -> Self {
+        let width = family.width();
+        let layout = family.bits();
+        let perm = decode::rot90_perm(&layout, width);
+        let mirror = decode::mirror_perm(&layout, width);
+
+        let n = layout.len();
+
+        let mut rotations = Vec::with_capacity(4 * family.codes().len());
+
+        for (id, &code) in family.codes().iter().enumerate() {
+            let mut rotated = code;
+
+            for turns in 0..4 {
+                let rotation = ((4 - turns) % 4) as u8;
+                rotations.push((rotated, Entry { id: id as u32, rotation, hamming: 0 }));
+
+                rotated = decode::rot90_code(rotated, &perm);
+            }
+        }
+
+        let lookup =
+            if entries(rotations.len(), n, max_hamming) <= MAX_ENTRIES {
+                let mut map = HashMap::new();
+
+                for &(code, base) in &rotations {
+                    neighbors(code, n, 0, max_hamming, 0, &mut |pattern, hamming| {
+                        let entry = Entry { hamming: hamming as u8, ..base };
+
+                        map.entry(pattern)
+                            .and_modify(|e: &mut Entry| {
+                                if hamming < e.hamming as u32 {
+                                    *e = entry;
+                                }
+                            })
+                            .or_insert(entry);
+                    });
+                }
+
+                Lookup::Map(map)
+            } else {
+                Lookup::Scan(rotations)
+            };
+
+        let (dark, light) = rings(family, &layout);
+
+        Self {
+            name: family.name().to_string(),
+            fingerprint: fingerprint(family),
+            max_hamming,
+            width,
+            layout,
+            perm,
+            mirror,
+            dark,
+            light,
+            lookup,
+        }
+    }
+
+    pub fn get(&self, code: u64) -> Option<Entry> {
+        match &self.lookup {
+            Lookup::Map(map) => map.get(&code).copied(),
+            Lookup::Scan(rotations) => {
+                let mut best: Option<Entry> = None;
+
+                for &(c, entry) in rotations {
+                    let hamming = (c ^ code).count_ones();
+
+                    if hamming <= self.max_hamming && best.is_none_or(|b| hamming < b.hamming as u32) {
+                        best = Some(Entry { hamming: hamming as u8, ..entry });
+
+                        if hamming == 0 {
+                            break;
+                        }
+                    }
+                }
+
+                best
+            }
+        }
+    }
+
+    pub fn memory(&self) -> usize {
+        let lookup =
+            match &self.lookup {
+                Lookup::Map(map) => map.capacity() * (mem::size_of::<(u64, Entry)>() + 1),
+                Lookup::Scan(rotations) => rotations.capacity() * mem::size_of::<(u64, Entry)>(),
+            };
+
+        lookup
+            + self.layout.capacity() * mem::size_of::<(i32, i32)>()
+            + (self.perm.capacity() + self.mirror.capacity()) * mem::size_of::<usize>()
+            + (self.dark.capacity() + self.light.capacity()) * mem::size_of::<(i32, i32)>()
+    }
+}
+
+pub fn fingerprint(family: &dyn TagFamily) -> u64 {
+    let mut h = DefaultHasher::new();
+
+    family.name().hash(&mut h);
+    family.grid().hash(&mut h);
+    family.border().hash(&mut h);
+    family.reversed_border().hash(&mut h);
+    family.min_hamming().hash(&mut h);
+    family.bits().hash(&mut h);
+    family.codes().hash(&mut h);
+
+    h.finish()
+}
+
+pub fn budget(config: &Config, family: &dyn TagFamily) -> u32 {
+    config.max_hamming.min(family.max_correctable())
+}
+
+fn rings(family: &dyn TagFamily, layout: &[(i32, i32)]) -> (Layout, Layout) {
+    let width = family.width() as i32;
+    let border = family.border() as i32;
+
+    let mut inside = Vec::new();
+    let mut outside = Vec::new();
+
+    for y in -1..=width {
+        for x in -1..=width {
+            if layout.contains(&(x, y)) {
+                continue;
+            }
+
+            let depth = x.min(y).min(width - 1 - x).min(width - 1 - y);
+
+            if depth == -1 {
+                outside.push((x, y));
+            } else if depth < border {
+                inside.push((x, y));
+            }
+        }
+    }
+
+    if family.reversed_border() {
+        (outside, inside)
+    } else {
+        (inside, outside)
+    }
+}
+
+fn entries(codes: usize, n: usize, max_hamming: u32) -> usize {
+    let mut choose = 1_usize;
+    let mut total = 1_usize;
+
+    for k in 1..=(max_hamming as usize).min(n) {
+        choose = choose.saturating_mul(n + 1 - k) / k;
+        total = total.saturating_add(choose);
+    }
+
+    codes.saturating_mul(total)
+}
+
+fn neighbors(code: u64, n: usize, start: usize, left: u32, hamming: u32, f: &mut impl FnMut(u64, u32)) {
+    f(code, hamming);
+
+    if left == 0 {
+        return;
+    }
+
+    for bit in start..n {
+        neighbors(code ^ (1 << bit), n, bit + 1, left - 1, hamming + 1, f);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::config::Config;
+use crate::detector::Workspace;
+use crate::image::{ImageView, Pixel};
+use crate::types::Dim;
+
+const BLACK: u8 = 0;
+const UNKNOWN: u8 = 127;
+const WHITE: u8 = 255;
+
+pub fn adaptive<T: Pixel>(config: &Config, dim: Dim, img: &ImageView<T>, ws: &mut Workspace) {
+    let w = dim.w;
+    let h = dim.h;
+
+    let ts = config.adaptive_tile.max(1);
+    let tw = w.div_ceil(ts);
+    let th = h.div_ceil(ts);
+
+    ws.tmin.clear();
+    ws.tmin.resize(tw * th, f32::INFINITY);
+    ws.tmax.clear();
+    ws.tmax.resize(tw * th, f32::NEG_INFINITY);
+
+    for y in 0..h {
+        let row = img.row(y);
+        let r = (y / ts) * tw;
+
+        for (x, p) in row.iter().enumerate() {
+            let l = p.lightness();
+            let t = r + x / ts;
+
+            ws.tmin[t] = ws.tmin[t].min(l);
+            ws.tmax[t] = ws.tmax[t].max(l);
+        }
+    }
+
+    ws.lmin.clear();
+    ws.lmin.resize(tw * th, 0.0);
+    ws.lmax.clear();
+    ws.lmax.resize(tw * th, 0.0);
+
+    for ty in 0..th {
+        for tx in 0..tw {
+            let mut lo = f32::INFINITY;
+            let mut hi = f32::NEG_INFINITY;
+
+            for ny in ty.saturating_sub(1)..=(ty + 1).min(th - 1) {
+                for nx in tx.saturating_sub(1)..=(tx + 1).min(tw - 1) {
+                    lo = lo.min(ws.tmin[ny * tw + nx]);
+                    hi = hi.max(ws.tmax[ny * tw + nx]);
+                }
+            }
+
+            ws.lmin[ty * tw + tx] = lo;
+            ws.lmax[ty * tw + tx] = hi;
+        }
+    }
+
+    for y in 0..h {
+        let row = img.row(y);
+        let r = (y / ts) * tw;
+
+        for (x, p) in row.iter().enumerate() {
+            let t = r + x / ts;
+
+            let lo = ws.lmin[t];
+            let hi = ws.lmax[t];
+
+            ws.binary[y * w + x] =
+                if hi - lo < config.adaptive_contrast {
+                    UNKNOWN
+                } else if p.lightness() > lo + (hi - lo) / 2.0 {
+                    WHITE
+                } else {
+                    BLACK
+                };
+        }
+    }
+
+    for y in 0..h {
+        for x in 0..w {
+            let i = y * w + x;
+
+            let boundary =
+                ws.binary[i] == BLACK
+                    && ((x > 0 && ws.binary[i - 1] == WHITE)
+                        || (x + 1 < w && ws.binary[i + 1] == WHITE)
+                        || (y > 0 && ws.binary[i - w] == WHITE)
+                        || (y + 1 < h && ws.binary[i + w] == WHITE));
+
+            ws.edges[i] = if boundary { 1 } else { 0 };
+        }
+    }
+}
@@ -1,12 +1,26 @@
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tag {
     pub id: Option<u32>,
+    pub family: Option<String>,
+    pub hamming: u32,
+    pub decision_margin: f32,
+    pub border_score: f32,
+    pub rotation: u8,
+    pub inverted: bool,
+    pub mirrored: bool,
     pub rot: f32,
     pub pos: Point3D,
     pub corners: Corners,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Thresholds {
+    pub high: f32,
+    pub low: f32,
+}
+
 #[derive(Clone, Copy)]
 pub struct Dim {
     pub w: usize,